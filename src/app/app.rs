@@ -20,6 +20,7 @@ use crate::monitor::Monitor;
 use crate::profiles::*;
 use crate::registry::RegistryIndex;
 use crate::util::*;
+use crate::wm::pure::session::session_warnings;
 
 // Re-export types from ui module (migrated)
 pub use crate::ui::{ActiveDropdown, FocusPane, InstanceFocus, MenuPage, ProfileBuilderFocus, RegistryFocus, SettingsCategory, SettingsFocus};
@@ -37,16 +38,27 @@ pub struct Splitux {
     pub device_monitor: Option<DeviceMonitor>,
     pub permission_status: crate::input::PermissionStatus, // Input device permission check
     pub permission_banner_dismissed: bool, // User dismissed the permission warning
+    pub session_env: crate::wm::types::SessionEnv, // Display-server environment at startup
+    pub session_banner_dismissed: bool, // User dismissed the session mismatch warning
     pub instances: Vec<Instance>,
+    pub mangohud_available: bool, // mangohud found in PATH at startup
+    pub window_geometry: Option<crate::config::WindowGeometry>, // Launcher geometry, saved on exit
     pub instance_add_dev: Option<usize>,
+    pub claim_mode: bool, // Join button on any unassigned device adds a player, regardless of focus
+    pub layout_preview: bool, // Show the monitor/region preview on the instance page
     pub instance_focus: InstanceFocus,
-    pub launch_option_index: usize, // 0=Split style, 1=KB/Mouse support
+    pub launch_option_index: usize, // Index into ui::focus::pure::launch_options()
+    pub copy_command_player: usize, // Player whose launch command A copies next
     pub profiles: Vec<String>,
     pub game_profiles: HashMap<String, usize>, // Maps handler path -> selected profile index
+    pub player_leds: HashMap<String, usize>, // Controller path -> player shown on its LEDs
 
     pub handlers: Vec<Handler>,
     pub selected_handler: usize,
     pub handler_edit: Option<Handler>,
+    pub handler_edit_lint: Option<Vec<LintWarning>>, // Cached lint results for handler_edit
+    pub handler_about: Option<(String, Vec<(&'static str, String)>)>, // Resolved summary while "About this handler" is open, keyed by storage_key
+    pub dll_override_buffer: String, // New DLL name in the handler editor's overrides table
     pub handler_lite: Option<Handler>,
     pub show_edit_modal: bool,
 
@@ -54,7 +66,7 @@ pub struct Splitux {
 
     // Pane-based focus for Games page
     pub focus_pane: FocusPane,
-    pub action_bar_index: usize, // 0=Play, 1=Profile, 2=Edit
+    pub action_bar_index: usize, // 0=Play, 1=Profile, 2=Edit, 3=Open folder, 4=Test (KB/M), 5=Replay
     pub info_pane_index: usize,  // Index of focused element in info pane
     pub info_pane_scroll: f32,   // Scroll offset for info pane
     pub game_panel_bottom_focused: bool, // True if focused on Add Game/Import Handler
@@ -74,9 +86,14 @@ pub struct Splitux {
     pub registry_index: Option<RegistryIndex>,
     pub registry_loading: bool,
     pub registry_error: Option<String>,
-    pub registry_search: String,
+    pub registry_filter: crate::registry::RegistryFilter, // Search text + backend/player chips
     pub registry_selected: Option<usize>,
     pub registry_installing: Option<String>,
+    /// Events from the background registry download, polled each frame
+    pub registry_download_rx: Option<std::sync::mpsc::Receiver<crate::registry::DownloadEvent>>,
+    pub registry_download_progress: Option<crate::registry::DownloadProgress>,
+    /// Set to abort the registry download in flight
+    pub registry_download_cancel: std::sync::Arc<std::sync::atomic::AtomicBool>,
     pub registry_focus: RegistryFocus,
 
     // Settings state
@@ -85,7 +102,9 @@ pub struct Splitux {
     pub settings_panel_collapsed: bool,          // Left panel collapsed state
     pub settings_button_index: usize, // 0=Save, 1=Restore
     pub settings_option_index: usize, // Index of focused option in settings
+    pub settings_sub_index: usize, // Focused control within a multi-control settings row
     pub settings_scroll_to_focus: bool, // Set true when focus changes to trigger scroll
+    pub nav_rebind_action: Option<crate::config::NavAction>, // Nav action waiting for a key press
 
     // Audio state
     pub audio_system: AudioSystem,
@@ -98,8 +117,15 @@ pub struct Splitux {
     pub controller_warnings: Vec<String>,
     /// Warnings for profiles with missing preferred audio devices
     pub audio_warnings: Vec<String>,
+    /// Warnings for profiles whose preferred KB/mouse profile no longer exists
+    pub gptokeyb_warnings: Vec<String>,
+    /// Warnings for instances moved off a disconnected monitor
+    pub monitor_warnings: Vec<String>,
     /// Audio preferences from profiles (instance index -> sink name)
     pub profile_audio_prefs: HashMap<usize, String>,
+    /// Playtime per profile name, loaded when the profile is first shown
+    /// and dropped when a session records more or profiles are renamed/deleted
+    pub profile_playtime: HashMap<String, PlayStats>,
 
     /// Session-only audio overrides (instance index -> sink name or None for mute)
     /// These do NOT persist to profile preferences, only apply to current launch
@@ -116,6 +142,8 @@ pub struct Splitux {
     pub profile_rename_buffer: String,
     /// Show confirmation dialog for profile deletion
     pub profile_delete_confirm: Option<usize>,
+    /// Profile to duplicate once a name for the copy is entered
+    pub profile_clone_source: Option<usize>,
     /// Which profile has preferences expanded (None = all collapsed)
     pub profile_prefs_expanded: Option<usize>,
     /// Sub-focus within expanded profile (0 = header/toggle, 1 = controller, 2 = audio)
@@ -130,16 +158,33 @@ pub struct Splitux {
     pub device_rename_index: Option<usize>,
     /// Text buffer for device rename operation
     pub device_rename_buffer: String,
+    pub device_rule_buffer: String, // Rule being typed in the device filter settings
 
     // Panel collapse/resize state
     pub games_panel_collapsed: bool,
     pub games_panel_width: f32,
     pub devices_panel_collapsed: bool,
     pub devices_panel_width: f32,
+    /// Input test window (live device state) is open
+    pub show_input_test: bool,
 
     // Monitor polling state
     pub last_monitor_poll: std::time::Instant,
 
+    // Audio sink polling state
+    pub last_audio_poll: std::time::Instant,
+    /// Pending background sink rescan (polled without blocking the UI)
+    pub audio_scan_rx: Option<std::sync::mpsc::Receiver<Vec<AudioSink>>>,
+
+    /// Kill switch of the session the launch task runs, for the End session button
+    pub session_kill: Option<std::sync::Arc<crate::launch::KillSwitch>>,
+    /// Whether gamepad presses may end the session (set after a drain once the launcher is focused)
+    pub session_pad_armed: bool,
+    /// Handler dir name and start time of a launch that succeeded, sent by the launch task
+    pub launch_played_rx: Option<std::sync::mpsc::Receiver<(String, std::time::SystemTime)>>,
+    /// Handler dir name of the last launched session, if one can be replayed
+    pub last_session_handler: Option<String>,
+
     // Layout customization state
     pub layout_custom_mode: bool,        // True when in custom assignment mode
     pub layout_focused_region: usize,    // Which region is currently focused
@@ -156,7 +201,7 @@ pub struct Splitux {
 impl Splitux {
     pub fn new(monitors: Vec<Monitor>, handler_lite: Option<Handler>) -> Self {
         let options = load_cfg();
-        let input_devices = scan_input_devices(&options.pad_filter_type);
+        let input_devices = scan_input_devices(&options.pad_filter_type, &options.device_filter);
         let device_display_names =
             crate::input::generate_display_names(&input_devices, &options.device_aliases);
 
@@ -177,11 +222,11 @@ impl Splitux {
         // Initialize device hotplug monitor
         let device_monitor = match DeviceMonitor::new() {
             Ok(m) => {
-                println!("[splitux] udev: Device hotplug monitor initialized");
+                log_info!("udev: Device hotplug monitor initialized");
                 Some(m)
             }
             Err(e) => {
-                eprintln!("[splitux] udev: Failed to initialize device monitor: {}", e);
+                log_error!("udev: Failed to initialize device monitor: {}", e);
                 None
             }
         };
@@ -192,19 +237,24 @@ impl Splitux {
         let audio_system = resolve_audio_system(options.audio.system);
         let audio_devices = if audio_system != AudioSystem::None {
             scan_sinks(audio_system).unwrap_or_else(|e| {
-                eprintln!("[splitux] audio: Failed to scan audio devices: {}", e);
+                log_error!("audio: Failed to scan audio devices: {}", e);
                 Vec::new()
             })
         } else {
             Vec::new()
         };
         if !audio_devices.is_empty() {
-            println!(
-                "[splitux] audio: Found {} audio output devices",
+            log_info!(
+                "audio: Found {} audio output devices",
                 audio_devices.len()
             );
         }
 
+        let session_env = crate::wm::operations::session::read_session_env();
+        for warning in session_warnings(&session_env, options.gamescope_sdl_backend) {
+            log_info!("session: {}", warning);
+        }
+
         let app = Self {
             installed_steamapps: get_installed_steamapps(),
             needs_update: Arc::new(AtomicBool::new(false)),
@@ -217,15 +267,26 @@ impl Splitux {
             device_monitor,
             permission_status: crate::input::check_permissions(),
             permission_banner_dismissed: false,
+            session_env,
+            session_banner_dismissed: false,
             instances: Vec::new(),
+            mangohud_available: crate::util::command_in_path("mangohud"),
+            window_geometry: None,
             instance_add_dev: None,
+            claim_mode: false,
+            layout_preview: false,
             instance_focus: InstanceFocus::default(),
             launch_option_index: 0,
+            copy_command_player: 0,
             profiles,
             game_profiles: HashMap::new(),
+            player_leds: HashMap::new(),
             handlers,
             selected_handler: 0,
             handler_edit: None,
+            handler_edit_lint: None,
+            handler_about: None,
+            dll_override_buffer: String::new(),
             handler_lite,
             show_edit_modal: false,
             activate_focused: false,
@@ -246,9 +307,12 @@ impl Splitux {
             registry_index: None,
             registry_loading: false,
             registry_error: None,
-            registry_search: String::new(),
+            registry_filter: crate::registry::RegistryFilter::default(),
             registry_selected: None,
             registry_installing: None,
+            registry_download_rx: None,
+            registry_download_progress: None,
+            registry_download_cancel: Default::default(),
             registry_focus: RegistryFocus::default(),
 
             // Settings state
@@ -257,7 +321,9 @@ impl Splitux {
             settings_panel_collapsed: false,
             settings_button_index: 0,
             settings_option_index: 0,
+            settings_sub_index: 0,
             settings_scroll_to_focus: false,
+            nav_rebind_action: None,
 
             // Audio state
             audio_system,
@@ -267,7 +333,10 @@ impl Splitux {
             prev_profile_selections: Vec::new(),
             controller_warnings: Vec::new(),
             audio_warnings: Vec::new(),
+            monitor_warnings: Vec::new(),
+            gptokeyb_warnings: Vec::new(),
             profile_audio_prefs: HashMap::new(),
+            profile_playtime: HashMap::new(),
             audio_session_overrides: HashMap::new(),
             gptokeyb_instance_overrides: HashMap::new(),
 
@@ -275,6 +344,7 @@ impl Splitux {
             profile_edit_index: None,
             profile_rename_buffer: String::new(),
             profile_delete_confirm: None,
+            profile_clone_source: None,
             profile_prefs_expanded: None,
             profile_prefs_focus: 0,
             active_dropdown: None,
@@ -283,15 +353,23 @@ impl Splitux {
             // Device naming state
             device_rename_index: None,
             device_rename_buffer: String::new(),
+            device_rule_buffer: String::new(),
 
             // Panel collapse/resize state (loaded from config above)
             games_panel_collapsed,
             games_panel_width,
             devices_panel_collapsed,
             devices_panel_width,
+            show_input_test: false,
 
             // Monitor polling state
             last_monitor_poll: std::time::Instant::now(),
+            last_audio_poll: std::time::Instant::now(),
+            audio_scan_rx: None,
+            session_kill: None,
+            session_pad_armed: false,
+            launch_played_rx: None,
+            last_session_handler: crate::last_session::LastSession::load().map(|s| s.handler),
 
             // Layout customization state
             layout_custom_mode: false,
@@ -306,12 +384,14 @@ impl Splitux {
             profile_builder_focus: ProfileBuilderFocus::default(),
         };
 
-        let needs_update = app.needs_update.clone();
-        std::thread::spawn(move || {
-            if check_for_splitux_update() {
-                needs_update.store(true, Ordering::Relaxed);
-            }
-        });
+        if !app.options.offline_mode {
+            let needs_update = app.needs_update.clone();
+            std::thread::spawn(move || {
+                if check_for_splitux_update(false) {
+                    needs_update.store(true, Ordering::Relaxed);
+                }
+            });
+        }
 
         app
     }
@@ -320,4 +400,18 @@ impl Splitux {
     pub fn cur_handler(&self) -> &Handler {
         &self.handlers[self.selected_handler]
     }
+
+    /// Get the handler the instance page will launch (lite handler or selected handler)
+    pub fn active_handler(&self) -> Option<&Handler> {
+        self.handler_lite
+            .as_ref()
+            .or_else(|| self.handlers.get(self.selected_handler))
+    }
+
+    /// gptokeyb profiles bundled with the active handler
+    pub fn bundled_gptokeyb_profiles(&self) -> Vec<String> {
+        self.active_handler()
+            .map(|h| crate::gptokeyb::list_bundled_profiles(&h.path_handler))
+            .unwrap_or_default()
+    }
 }
@@ -6,7 +6,16 @@ use eframe::egui;
 
 impl eframe::App for Splitux {
     fn raw_input_hook(&mut self, ctx: &egui::Context, raw_input: &mut egui::RawInput) {
-        if !raw_input.focused || self.task.is_some() {
+        if !raw_input.focused {
+            self.session_pad_armed = false;
+            return;
+        }
+        if self.task.is_some() {
+            self.handle_session_pad_input();
+            return;
+        }
+        if self.show_input_test {
+            self.handle_input_test_input(raw_input);
             return;
         }
         match self.cur_page {
@@ -27,6 +36,21 @@ impl eframe::App for Splitux {
         // Poll for monitor changes (hotplug, resolution changes)
         self.poll_monitor_events();
 
+        // Poll for audio sink changes (throttled, scanned off the UI thread)
+        self.poll_audio_devices();
+
+        // Remember when a game was last played once its launch succeeds
+        self.poll_launch_played();
+
+        // Pick up registry download progress and completion
+        self.poll_registry_download();
+
+        // Match controller LEDs to the player colors on the instance cards
+        self.sync_player_leds();
+
+        // Remember launcher geometry for the next start
+        self.track_window_geometry(ctx);
+
         // Enable keyboard focus navigation
         ctx.options_mut(|opt| {
             opt.input_options.line_scroll_speed = 40.0;
@@ -152,10 +176,11 @@ impl eframe::App for Splitux {
                     ui.disable();
                 }
 
-                // Show permission banner at top if needed (only on Games/Instances pages)
+                // Show permission and session banners at top if needed (only on Games/Instances pages)
                 if matches!(self.cur_page, MenuPage::Games | MenuPage::Instances) {
                     ui.add_space(8.0);
                     self.display_permission_banner(ui);
+                    self.display_session_banner(ui);
                 }
 
                 match self.cur_page {
@@ -171,6 +196,11 @@ impl eframe::App for Splitux {
             self.display_edit_handler_modal(ctx);
         }
 
+        // Input test window
+        if self.show_input_test {
+            self.display_input_test_window(ctx);
+        }
+
         // Profile dropdown overlay
         if self.profile_dropdown_open {
             self.display_profile_dropdown(ctx);
@@ -186,6 +216,7 @@ impl eframe::App for Splitux {
                 let _ = handle.join();
                 self.loading_since = None;
                 self.loading_msg = None;
+                self.session_kill = None;
             } else {
                 self.task = Some(handle);
             }
@@ -201,10 +232,12 @@ impl eframe::App for Splitux {
                 self.loading_msg = Some("Operation timed out".to_string());
             }
         }
-        if let Some(msg) = &self.loading_msg {
+        if let Some(msg) = self.loading_msg.clone() {
+            // Offer a way out once the games are running
+            let session_running = self.session_kill.as_ref().is_some_and(|kill| kill.is_running());
             egui::Area::new("loading".into())
                 .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
-                .interactable(false)
+                .interactable(session_running)
                 .show(ctx, |ui| {
                     egui::Frame::NONE
                         .fill(egui::Color32::from_rgba_premultiplied(0, 0, 0, 192))
@@ -215,6 +248,17 @@ impl eframe::App for Splitux {
                                 ui.add(egui::widgets::Spinner::new().size(40.0));
                                 ui.add_space(8.0);
                                 ui.label(msg);
+                                if session_running {
+                                    ui.add_space(8.0);
+                                    // Focused so Enter, Space or A on a controller ends it too
+                                    let end = ui.button("End session");
+                                    if !end.has_focus() {
+                                        end.request_focus();
+                                    }
+                                    if end.clicked() {
+                                        self.end_session();
+                                    }
+                                }
                             });
                         });
                 });
@@ -227,4 +271,8 @@ impl eframe::App for Splitux {
             ctx.request_repaint_after(std::time::Duration::from_millis(500)); // 2 fps when unfocused
         }
     }
+
+    fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        self.save_window_geometry();
+    }
 }
@@ -1,8 +1,12 @@
 //! Helper methods for Splitux
 
-use super::Splitux;
-use crate::input::{open_device, DeviceEvent};
-use crate::monitor::get_monitors_sdl;
+use super::{ActiveDropdown, Splitux};
+use crate::audio::{rebind_sinks, scan_sinks, sinks_changed, AudioSystem};
+use crate::config::{load_cfg, save_cfg, WindowGeometry};
+use crate::input::pure::grouping::group_sibling_nodes;
+use crate::input::{device_listed, open_device, scan_input_devices, DeviceEvent, InputDevice};
+use crate::monitor::{get_monitors_sdl, remap_monitor_index, MonitorRemap};
+use crate::wm::pure::session::session_warnings;
 use eframe::egui::{self, RichText};
 use egui_phosphor::regular as icons;
 use std::time::Duration;
@@ -31,28 +35,28 @@ impl Splitux {
         for event in monitor.poll_events() {
             match event {
                 DeviceEvent::Added(path) => {
-                    println!("[splitux] udev: Add event for {}", path);
+                    log_info!("udev: Add event for {}", path);
+                    for device in &mut self.input_devices {
+                        device.remove_sibling(&path);
+                    }
                     // Remove any stale entry with the same path first
                     if let Some(idx) = self.input_devices.iter().position(|d| d.path() == path) {
-                        println!("[splitux] udev: Removing stale entry for {}", path);
-                        // Clean up instances referencing this device
-                        for instance in &mut self.instances {
-                            instance.devices.retain(|&d| d != idx);
-                        }
-                        self.instances.retain(|i| !i.devices.is_empty());
-                        for instance in &mut self.instances {
-                            for dev_idx in &mut instance.devices {
-                                if *dev_idx > idx {
-                                    *dev_idx -= 1;
-                                }
-                            }
-                        }
-                        self.input_devices.remove(idx);
+                        log_info!("udev: Removing stale entry for {}", path);
+                        self.remove_input_device(idx);
                     }
                     // Try to open the device
-                    if let Some(device) = open_device(&path, &self.options.pad_filter_type) {
-                        println!(
-                            "[splitux] udev: Device connected: {} ({})",
+                    if let Some(device) = open_device(&path, &self.options.pad_filter_type, &self.options.device_filter) {
+                        if let Some(parent) = self.sibling_parent(&device) {
+                            log_info!(
+                                "udev: Merging {} into {}",
+                                path,
+                                self.input_devices[parent].path()
+                            );
+                            self.input_devices[parent].add_sibling(path);
+                            continue;
+                        }
+                        log_info!(
+                            "udev: Device connected: {} ({})",
                             device.fancyname(),
                             path
                         );
@@ -65,35 +69,73 @@ impl Splitux {
                     // Find and remove the device
                     if let Some(idx) = self.input_devices.iter().position(|d| d.path() == path) {
                         let device = &self.input_devices[idx];
-                        println!(
-                            "[splitux] udev: Device disconnected: {} ({})",
+                        log_info!(
+                            "udev: Device disconnected: {} ({})",
                             device.fancyname(),
                             path
                         );
-
-                        // Also remove from any instances
-                        for instance in &mut self.instances {
-                            instance.devices.retain(|&d| d != idx);
-                        }
-                        // Remove empty instances
-                        self.instances.retain(|i| !i.devices.is_empty());
-                        // Update device indices in instances (since we're removing one)
-                        for instance in &mut self.instances {
-                            for dev_idx in &mut instance.devices {
-                                if *dev_idx > idx {
-                                    *dev_idx -= 1;
-                                }
-                            }
-                        }
-
-                        self.input_devices.remove(idx);
+                        self.remove_input_device(idx);
                         self.refresh_device_display_names();
+                    } else if self.input_devices.iter_mut().any(|d| d.remove_sibling(&path)) {
+                        log_info!("udev: Controller node disconnected: {}", path);
                     }
                 }
             }
         }
     }
 
+    /// Drop a device from the list, keeping instance device indices in step
+    pub(crate) fn remove_input_device(&mut self, idx: usize) {
+        // Also remove from any instances
+        for instance in &mut self.instances {
+            instance.devices.retain(|&d| d != idx);
+        }
+        // Remove empty instances
+        self.instances.retain(|i| !i.devices.is_empty());
+        // Update device indices in instances (since we're removing one)
+        for instance in &mut self.instances {
+            for dev_idx in &mut instance.devices {
+                if *dev_idx > idx {
+                    *dev_idx -= 1;
+                }
+            }
+        }
+        self.input_devices.remove(idx);
+    }
+
+    /// Apply an edited device filter without rescanning, so devices that stay
+    /// listed keep their indices and instance assignments
+    pub(crate) fn apply_device_filter(&mut self) {
+        let filter = self.options.device_filter.clone();
+        for idx in (0..self.input_devices.len()).rev() {
+            let device = &self.input_devices[idx];
+            let (vendor, product) = device.usb_id();
+            if !device_listed(&filter, device.device_type(), vendor, product, device.name()) {
+                log_info!("Device filter: hiding {} ({})", device.fancyname(), device.path());
+                self.remove_input_device(idx);
+            }
+        }
+        // Devices the filter now lets through go at the end so existing indices hold
+        for device in scan_input_devices(&self.options.pad_filter_type, &filter) {
+            if !self.input_devices.iter().any(|d| d.has_node(device.path())) {
+                log_info!("Device filter: showing {} ({})", device.fancyname(), device.path());
+                self.input_devices.push(device);
+            }
+        }
+        self.refresh_device_display_names();
+    }
+
+    /// Index of the listed gamepad a newly connected node belongs to, if any
+    fn sibling_parent(&self, device: &InputDevice) -> Option<usize> {
+        let mut nodes: Vec<_> = self
+            .input_devices
+            .iter()
+            .map(|d| (d.group_key().map(str::to_string), d.device_type()))
+            .collect();
+        nodes.push((device.group_key().map(str::to_string), device.device_type()));
+        group_sibling_nodes(&nodes).pop().flatten()
+    }
+
     /// Poll for monitor changes (throttled to every 2 seconds)
     /// Similar to device hotplug but for display outputs
     pub(crate) fn poll_monitor_events(&mut self) {
@@ -117,29 +159,47 @@ impl Splitux {
                     new.name() != old.name()
                         || new.width() != old.width()
                         || new.height() != old.height()
+                        || new.scale() != old.scale()
                 })
         };
 
         if changed {
-            println!("[splitux] Monitor change detected:");
+            log_info!("Monitor change detected:");
             for monitor in &current_monitors {
-                println!(
-                    "[splitux]   {} ({}x{})",
+                log_info!(
+                    "  {} ({}x{} @ {}x)",
                     monitor.name(),
                     monitor.width(),
-                    monitor.height()
+                    monitor.height(),
+                    monitor.scale()
                 );
             }
 
-            // Update instances if their monitor index is now out of bounds
-            let max_monitor = current_monitors.len().saturating_sub(1);
-            for instance in &mut self.instances {
-                if instance.monitor > max_monitor {
-                    println!(
-                        "[splitux] Instance monitor {} out of bounds, resetting to {}",
-                        instance.monitor, max_monitor
-                    );
-                    instance.monitor = max_monitor;
+            // SDL can briefly report no displays mid-hotplug; keep the old list
+            if current_monitors.is_empty() {
+                log_warn!("No monitors detected, keeping the previous list");
+                return;
+            }
+
+            // Follow each instance's monitor by name so unplugging one output
+            // doesn't silently shift the others onto a different display
+            let old_names: Vec<&str> = self.monitors.iter().map(|m| m.name()).collect();
+            let new_names: Vec<&str> = current_monitors.iter().map(|m| m.name()).collect();
+            for (i, instance) in self.instances.iter_mut().enumerate() {
+                match remap_monitor_index(&old_names, &new_names, instance.monitor) {
+                    MonitorRemap::Kept(idx) => instance.monitor = idx,
+                    MonitorRemap::Reassigned(idx) => {
+                        let old = self
+                            .monitors
+                            .get(instance.monitor)
+                            .map(|m| m.display_name())
+                            .unwrap_or_else(|| format!("monitor {}", instance.monitor + 1));
+                        let new = current_monitors[idx].display_name();
+                        log_warn!("P{}'s monitor {} was disconnected, moved to {}", i + 1, old, new);
+                        self.monitor_warnings
+                            .push(format!("P{}'s monitor {} was disconnected, moved to {}", i + 1, old, new));
+                        instance.monitor = idx;
+                    }
                 }
             }
 
@@ -147,6 +207,159 @@ impl Splitux {
         }
     }
 
+    /// Record the last-played time a finished launch task reported
+    pub(crate) fn poll_launch_played(&mut self) {
+        let Some(rx) = &self.launch_played_rx else {
+            return;
+        };
+        match rx.try_recv() {
+            Ok((dir, started)) => {
+                self.launch_played_rx = None;
+                // The session added to the players' playtime
+                self.profile_playtime.clear();
+                self.options.games.entry(dir).or_default().last_played = Some(started);
+                if let Err(e) = save_cfg(&self.options) {
+                    log_error!("Failed to save settings: {}", e);
+                }
+            }
+            Err(std::sync::mpsc::TryRecvError::Empty) => {}
+            Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                self.launch_played_rx = None;
+            }
+        }
+    }
+
+    /// Periodically rescan audio sinks and pick up added/removed devices
+    ///
+    /// The scan shells out to pactl/wpctl, so it runs on a background thread
+    /// and the result is picked up on a later frame.
+    pub(crate) fn poll_audio_devices(&mut self) {
+        const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+        if self.audio_system == AudioSystem::None || self.task.is_some() {
+            return;
+        }
+
+        if let Some(rx) = &self.audio_scan_rx {
+            match rx.try_recv() {
+                Ok(sinks) => {
+                    self.audio_scan_rx = None;
+                    self.apply_audio_rescan(sinks);
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => {}
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    self.audio_scan_rx = None;
+                }
+            }
+            return;
+        }
+
+        if self.last_audio_poll.elapsed() < POLL_INTERVAL {
+            return;
+        }
+        self.last_audio_poll = std::time::Instant::now();
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let system = self.audio_system;
+        std::thread::spawn(move || {
+            // A failed scan is dropped; the next poll tries again
+            if let Ok(sinks) = scan_sinks(system) {
+                let _ = tx.send(sinks);
+            }
+        });
+        self.audio_scan_rx = Some(rx);
+    }
+
+    /// Replace the sink list, re-binding overrides and preferences by sink name
+    fn apply_audio_rescan(&mut self, sinks: Vec<crate::audio::AudioSink>) {
+        if !sinks_changed(&self.audio_devices, &sinks) {
+            return;
+        }
+        log_info!(
+            "audio: Sink list changed ({} -> {} devices)",
+            self.audio_devices.len(),
+            sinks.len()
+        );
+
+        // Session overrides: mute entries (None) have no sink to re-bind
+        let overridden: Vec<(usize, String)> = self
+            .audio_session_overrides
+            .iter()
+            .filter_map(|(i, sink)| sink.clone().map(|s| (*i, s)))
+            .collect();
+        let (kept, lost) = rebind_sinks(overridden, &self.audio_devices, &sinks);
+        for (i, name) in kept {
+            self.audio_session_overrides.insert(i, Some(name));
+        }
+        for (i, description) in lost {
+            self.audio_session_overrides.remove(&i);
+            self.audio_warnings.push(format!(
+                "P{}'s audio override ({}) was disconnected",
+                i + 1,
+                description
+            ));
+        }
+
+        // Profile preferences: keep the stored name if the device is gone,
+        // so it re-binds when plugged back in
+        let prefs: Vec<(usize, String)> = self
+            .profile_audio_prefs
+            .iter()
+            .map(|(i, s)| (*i, s.clone()))
+            .collect();
+        let (kept, _) = rebind_sinks(prefs, &self.audio_devices, &sinks);
+        for (i, name) in kept {
+            self.profile_audio_prefs.insert(i, name);
+        }
+
+        // Open audio dropdowns index into the old list
+        if matches!(
+            self.active_dropdown,
+            Some(ActiveDropdown::InstanceAudioOverride(_)) | Some(ActiveDropdown::InstanceAudioPreference(_))
+        ) {
+            self.active_dropdown = None;
+        }
+
+        self.audio_devices = sinks;
+    }
+
+    /// Remember the launcher window's geometry so it can be restored next start
+    ///
+    /// Stored in logical pixels (egui points times the zoom factor) to match
+    /// what the viewport builder expects. Fullscreen windows aren't tracked.
+    pub(crate) fn track_window_geometry(&mut self, ctx: &egui::Context) {
+        let zoom = ctx.zoom_factor();
+        let geometry = ctx.input(|i| {
+            let viewport = i.viewport();
+            if viewport.fullscreen == Some(true) || viewport.minimized == Some(true) {
+                return None;
+            }
+            let inner = viewport.inner_rect?;
+            Some(WindowGeometry {
+                position: viewport.outer_rect.map(|r| [r.min.x * zoom, r.min.y * zoom]),
+                size: [inner.width() * zoom, inner.height() * zoom],
+            })
+        });
+        if geometry.is_some() {
+            self.window_geometry = geometry;
+        }
+    }
+
+    /// Save the tracked window geometry on shutdown
+    ///
+    /// Re-reads the config from disk so unsaved settings changes aren't persisted.
+    pub(crate) fn save_window_geometry(&mut self) {
+        let Some(geometry) = self.window_geometry else {
+            return;
+        };
+        self.options.window_geometry = Some(geometry);
+        let mut cfg = load_cfg();
+        cfg.window_geometry = Some(geometry);
+        if let Err(e) = save_cfg(&cfg) {
+            log_error!("Failed to save window geometry: {}", e);
+        }
+    }
+
     /// Regenerate display names for all input devices (handles duplicates)
     pub fn refresh_device_display_names(&mut self) {
         self.device_display_names =
@@ -212,10 +425,10 @@ impl Splitux {
                             .on_hover_text("Install udev rules (requires password)")
                             .clicked()
                         {
-                            println!("[splitux] Attempting to install udev rules via pkexec...");
+                            log_info!("Attempting to install udev rules via pkexec...");
                             match crate::input::install_udev_rules() {
                                 Ok(true) => {
-                                    println!("[splitux] Udev rules installed successfully");
+                                    log_info!("Udev rules installed successfully");
                                     // Refresh permission status
                                     self.permission_status = crate::input::check_permissions();
                                     self.infotext =
@@ -223,11 +436,11 @@ impl Splitux {
                                             .to_string();
                                 }
                                 Ok(false) => {
-                                    println!("[splitux] User cancelled pkexec dialog");
+                                    log_info!("User cancelled pkexec dialog");
                                     self.infotext = "Installation cancelled.".to_string();
                                 }
                                 Err(e) => {
-                                    println!("[splitux] Failed to install udev rules: {}", e);
+                                    log_error!("Failed to install udev rules: {}", e);
                                     self.infotext = format!("Failed: {}", e);
                                 }
                             }
@@ -239,4 +452,50 @@ impl Splitux {
         ui.add_space(8.0);
         true
     }
+
+    /// Show display-session warning banner if needed, returns true if banner was shown
+    pub fn display_session_banner(&mut self, ui: &mut egui::Ui) -> bool {
+        if self.session_banner_dismissed {
+            return false;
+        }
+        let warnings = session_warnings(&self.session_env, self.options.gamescope_sdl_backend);
+        if warnings.is_empty() {
+            return false;
+        }
+
+        let banner_color = egui::Color32::from_rgb(180, 120, 40); // Orange/amber warning
+        egui::Frame::NONE
+            .fill(banner_color.gamma_multiply(0.3))
+            .stroke(egui::Stroke::new(1.0, banner_color))
+            .corner_radius(4.0)
+            .inner_margin(8.0)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new(icons::MONITOR).size(18.0).color(banner_color));
+                    ui.vertical(|ui| {
+                        ui.label(
+                            RichText::new("Display session may cause problems")
+                                .strong()
+                                .color(egui::Color32::WHITE),
+                        );
+                        for warning in &warnings {
+                            ui.label(
+                                RichText::new(warning)
+                                    .small()
+                                    .color(egui::Color32::LIGHT_GRAY),
+                            );
+                        }
+                    });
+
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Min), |ui| {
+                        if ui.small_button("X").on_hover_text("Dismiss").clicked() {
+                            self.session_banner_dismissed = true;
+                        }
+                    });
+                });
+            });
+
+        ui.add_space(8.0);
+        true
+    }
 }
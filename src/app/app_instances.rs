@@ -1,6 +1,10 @@
 // Instance device management functions
 
 use super::app::Splitux;
+use super::pages_instances::PLAYER_COLORS;
+use crate::input::DeviceType;
+use crate::instance::swap_instance_keys;
+use std::collections::HashMap;
 
 impl Splitux {
     pub(super) fn is_device_in_any_instance(&self, dev: usize) -> bool {
@@ -64,4 +68,91 @@ impl Splitux {
             }
         }
     }
+
+    /// Set each assigned controller's LEDs to its player's card color
+    ///
+    /// Only controllers whose player changed since the last call are written,
+    /// so creating or reordering instances updates the LEDs right away.
+    /// Controllers without addressable LEDs are skipped silently.
+    pub(super) fn sync_player_leds(&mut self) {
+        let mut wanted: HashMap<String, usize> = HashMap::new();
+        for (player, instance) in self.instances.iter().enumerate().take(PLAYER_COLORS.len()) {
+            for &dev in &instance.devices {
+                if let Some(device) = self.input_devices.get(dev)
+                    && device.device_type() == DeviceType::Gamepad
+                {
+                    wanted.entry(device.path().to_string()).or_insert(player);
+                }
+            }
+        }
+        if wanted == self.player_leds {
+            return;
+        }
+
+        for (path, &player) in &wanted {
+            if self.player_leds.get(path) == Some(&player) {
+                continue;
+            }
+            let Some(device) = self.input_devices.iter().find(|d| d.path() == path) else {
+                continue;
+            };
+            let [r, g, b, _] = PLAYER_COLORS[player].to_array();
+            if let Err(e) = device.set_player_leds(player, (r, g, b)) {
+                log_debug!("No player LEDs set for {}: {}", path, e);
+            }
+        }
+        self.player_leds = wanted;
+    }
+
+    /// Append a copy of an instance without its devices
+    ///
+    /// Session audio and gptokeyb overrides follow the copy to its new index.
+    /// Returns the index of the new instance.
+    pub fn clone_instance(&mut self, instance_index: usize) -> Option<usize> {
+        let mut instance = self.instances.get(instance_index)?.clone();
+        instance.devices.clear();
+        self.instances.push(instance);
+        let new_index = self.instances.len() - 1;
+
+        // Overwrite any stale entry left behind by a removed instance
+        match self.gptokeyb_instance_overrides.get(&instance_index).cloned() {
+            Some(profile) => { self.gptokeyb_instance_overrides.insert(new_index, profile); }
+            None => { self.gptokeyb_instance_overrides.remove(&new_index); }
+        }
+        match self.audio_session_overrides.get(&instance_index).cloned() {
+            Some(sink) => { self.audio_session_overrides.insert(new_index, sink); }
+            None => { self.audio_session_overrides.remove(&new_index); }
+        }
+        // Treat the copied profile as already applied so preferred
+        // controllers and audio aren't re-assigned to the clone
+        if new_index == self.prev_profile_selections.len() {
+            self.prev_profile_selections.push(self.instances[new_index].profselection);
+        }
+
+        Some(new_index)
+    }
+
+    /// Swap two instances' player numbers
+    ///
+    /// Devices, profile and monitor travel with the instance, as do its
+    /// session audio and KB/mouse overrides and profile audio preference.
+    pub fn swap_instances(&mut self, a: usize, b: usize) {
+        if a == b || a >= self.instances.len() || b >= self.instances.len() {
+            return;
+        }
+        self.instances.swap(a, b);
+        swap_instance_keys(&mut self.audio_session_overrides, a, b);
+        swap_instance_keys(&mut self.gptokeyb_instance_overrides, a, b);
+        swap_instance_keys(&mut self.profile_audio_prefs, a, b);
+        if a < self.prev_profile_selections.len() && b < self.prev_profile_selections.len() {
+            self.prev_profile_selections.swap(a, b);
+        }
+        self.instance_add_dev = self.instance_add_dev.map(|i| match i {
+            i if i == a => b,
+            i if i == b => a,
+            i => i,
+        });
+        // Dropdowns are keyed by instance index
+        self.active_dropdown = None;
+    }
 }
@@ -1,18 +1,18 @@
 // Game setup and launch functions
 
-use std::thread::sleep;
-
 use super::app::{InstanceFocus, MenuPage, Splitux};
-use crate::config::save_cfg;
+use crate::config::{save_cfg, SplituxConfig};
 use crate::audio::AUDIO_MUTED_SENTINEL;
 use crate::input::*;
 use crate::instance::*;
+use crate::last_session::{LastSession, SessionDevice};
 use crate::launch::*;
 use crate::monitor::get_monitors_sdl;
 use crate::profiles::*;
-use crate::save_sync;
 use crate::util::*;
 
+use eframe::egui;
+
 impl Splitux {
     pub fn start_game_setup(&mut self) {
         let h = &self.handlers[self.selected_handler];
@@ -25,7 +25,8 @@ impl Splitux {
             self.show_edit_modal = true;
         } else {
             self.instances.clear();
-            self.input_devices = scan_input_devices(&self.options.pad_filter_type);
+            self.monitor_warnings.clear();
+            self.input_devices = scan_input_devices(&self.options.pad_filter_type, &self.options.device_filter);
             self.refresh_device_display_names();
             self.monitors = get_monitors_sdl();
             self.profiles = scan_profiles(true);
@@ -36,7 +37,202 @@ impl Splitux {
         }
     }
 
+    /// Set up a single instance on the first keyboard and mouse and launch it
+    ///
+    /// For quickly testing a handler without a controller. Input holding is
+    /// forced on for this session only, since keyboards and mice can't be
+    /// assigned otherwise; the saved setting is left alone.
+    pub fn start_kbm_test_launch(&mut self) {
+        self.start_game_setup();
+        if self.cur_page != MenuPage::Instances {
+            // Setup was redirected to the handler editor
+            return;
+        }
+
+        // One keyboard and one mouse: a device can only belong to one instance
+        let first_of = |ty: DeviceType| {
+            self.input_devices
+                .iter()
+                .position(|d| d.enabled() && d.device_type() == ty)
+        };
+        let devices: Vec<usize> = [first_of(DeviceType::Keyboard), first_of(DeviceType::Mouse)]
+            .into_iter()
+            .flatten()
+            .collect();
+        if devices.is_empty() {
+            msg(
+                "No keyboard or mouse found",
+                "A keyboard/mouse test launch needs at least one enabled keyboard or mouse.",
+            );
+            self.cur_page = MenuPage::Games;
+            return;
+        }
+
+        log_info!(
+            "Keyboard/mouse test launch with devices {:?}",
+            devices
+        );
+        self.instances.push(Instance {
+            devices,
+            profname: String::new(),
+            profselection: 0,
+            monitor: 0,
+            width: 0,
+            height: 0,
+            grab_cursor: None,
+            mangohud: false,
+            resolution_override: (0, 0),
+            args_override: String::new(),
+        });
+        self.prepare_game_launch_with(false, |cfg| cfg.input_holding = true);
+    }
+
+    /// Whether the last launch was for the selected game, so it can be replayed
+    pub fn can_replay_last_session(&self) -> bool {
+        self.handlers
+            .get(self.selected_handler)
+            .is_some_and(|h| self.last_session_handler.as_deref() == Some(h.handler_dir_name()))
+    }
+
+    /// Restore the players of the last launch and start it again
+    ///
+    /// Devices are re-bound by identity, so controllers that reconnected are
+    /// still found. If any device is missing the instance page is shown with
+    /// the players restored, so the gaps can be filled in before launching.
+    pub fn replay_last_session(&mut self) {
+        let Some(session) = LastSession::load() else {
+            return;
+        };
+        let Some(index) = self
+            .handlers
+            .iter()
+            .position(|h| h.handler_dir_name() == session.handler)
+        else {
+            msg(
+                "Game not found",
+                &format!("The last session's game ({}) is no longer installed.", session.handler),
+            );
+            return;
+        };
+        self.selected_handler = index;
+        self.start_game_setup();
+        if self.cur_page != MenuPage::Instances {
+            // Setup was redirected to the handler editor
+            return;
+        }
+
+        let devices: Vec<Option<SessionDevice>> = self
+            .input_devices
+            .iter()
+            .map(|d| d.enabled().then(|| SessionDevice::from_device(d)))
+            .collect();
+        let monitor_names: Vec<&str> = self.monitors.iter().map(|m| m.name()).collect();
+        let rebound = session.rebind(&devices, &self.profiles, &monitor_names);
+
+        for profile in &rebound.missing_profiles {
+            log_warn!("Last session's profile '{}' no longer exists, using a guest", profile);
+        }
+        for monitor in &rebound.missing_monitors {
+            self.monitor_warnings
+                .push(format!("Last session's monitor {} is not connected, using the first monitor", monitor));
+        }
+        // Mark profiles as applied so preferred devices don't override the replay
+        self.prev_profile_selections = rebound.instances.iter().map(|i| i.profselection).collect();
+        let complete = rebound.is_complete();
+        self.instances = rebound.instances;
+
+        if complete {
+            log_info!("Replaying last session of {} with {} players", session.handler, self.instances.len());
+            self.prepare_game_launch();
+        } else {
+            msg(
+                "Devices missing",
+                &format!(
+                    "These devices from the last session aren't connected:\n\n{}\n\nConnect them or assign other devices to the players, then launch.",
+                    rebound.missing_devices.join("\n")
+                ),
+            );
+        }
+    }
+
+    /// Copy one instance's launch command to the clipboard, quoted for a shell
+    ///
+    /// Resolved from the current instances and settings like a launch would,
+    /// but nothing is mounted or spawned.
+    pub fn copy_launch_command(&mut self, ctx: &egui::Context, instance_idx: usize) {
+        let mut instances = self.instances.clone();
+        if self.options.gamescope_sdl_backend {
+            set_instance_resolutions_multimonitor(&mut instances, &self.monitors, &self.options);
+        } else {
+            set_instance_resolutions(&mut instances, &self.monitors[0], &self.options);
+        }
+        set_instance_names(&mut instances, &self.profiles);
+
+        let handler = match &self.handler_lite {
+            Some(h) => h.clone(),
+            None => self.cur_handler().to_owned(),
+        };
+        let dev_infos: Vec<DeviceInfo> = self.input_devices.iter().map(|p| p.info()).collect();
+
+        match launch_command_line(&handler, &dev_infos, &instances, &self.monitors, &self.options, instance_idx) {
+            Ok(line) => {
+                ctx.copy_text(line);
+                self.infotext = format!("Copied P{}'s launch command to the clipboard", instance_idx + 1);
+            }
+            Err(e) => msg("Couldn't build the launch command", &e.to_string()),
+        }
+    }
+
     pub fn prepare_game_launch(&mut self) {
+        self.prepare_game_launch_with(true, |_| {});
+    }
+
+    /// `session_overrides` is applied to the launch config after it's saved,
+    /// so its changes only last for this session. `remember` saves the
+    /// players for "Replay last session".
+    fn prepare_game_launch_with(
+        &mut self,
+        remember: bool,
+        session_overrides: impl FnOnce(&mut SplituxConfig),
+    ) {
+        let shared_profiles = duplicate_profiles(&self.instances, &self.profiles);
+        if !shared_profiles.is_empty() {
+            let list = describe_duplicate_profiles(&shared_profiles).join("\n");
+            // Syncing saves from a profile two players wrote to would lose progress
+            let syncs_saves = self
+                .active_handler()
+                .is_some_and(|h| h.save_sync_back || !h.original_save_paths.is_empty());
+            if syncs_saves {
+                msg(
+                    "Profile used twice",
+                    &format!(
+                        "These profiles are selected for more than one player:\n\n{}\n\nThis game syncs saves with profiles, so sharing one would corrupt them. Pick a different profile or a guest for each player.",
+                        list
+                    ),
+                );
+                return;
+            }
+            if !yesno(
+                "Profile used twice",
+                &format!(
+                    "These profiles are selected for more than one player:\n\n{}\n\nThe players will overwrite each other's saves and settings. Launch anyway?",
+                    list
+                ),
+            ) {
+                return;
+            }
+        }
+
+        if self.active_handler().is_some_and(|h| h.emulates_steam())
+            && steam_client_running()
+            && !yesno(
+                "Steam is running",
+                "The Steam client is running. Goldberg and Facepunch games can collide with the live Steam API and fail to start or connect.\n\nClose Steam first for the most reliable launch. Continue anyway?",
+            )
+        {
+            return;
+        }
+
         if self.options.gamescope_sdl_backend {
             set_instance_resolutions_multimonitor(
                 &mut self.instances,
@@ -54,105 +250,88 @@ impl Splitux {
             self.cur_handler().to_owned()
         };
 
+        if remember && handler.is_saved_handler() {
+            let monitor_names: Vec<&str> = self.monitors.iter().map(|m| m.name()).collect();
+            let session = LastSession::capture(
+                handler.handler_dir_name(),
+                &self.instances,
+                &self.input_devices,
+                &self.profiles,
+                &monitor_names,
+            );
+            match session.save() {
+                Ok(()) => self.last_session_handler = Some(session.handler),
+                Err(e) => log_warn!("Failed to save last session: {}", e),
+            }
+        }
+
         let instances = self.instances.clone();
         let monitors = self.monitors.clone();
         let dev_infos: Vec<DeviceInfo> = self.input_devices.iter().map(|p| p.info()).collect();
 
-        // Resolve audio assignments: session overrides take precedence over profile preferences
+        // Resolve audio assignments: session override > profile preference > guest default
         let mut cfg = self.options.clone();
         for i in 0..self.instances.len() {
-            // Check session override first
-            if let Some(override_opt) = self.audio_session_overrides.get(&i) {
-                match override_opt {
-                    Some(sink_name) => {
-                        cfg.audio.default_assignments.insert(i, sink_name.clone());
-                        println!(
-                            "[splitux] Applied session audio override for instance {}: {}",
-                            i, sink_name
-                        );
-                    }
-                    None => {
-                        // Explicit mute - use sentinel value so audio routes to null sink
-                        cfg.audio
-                            .default_assignments
-                            .insert(i, AUDIO_MUTED_SENTINEL.to_string());
-                        println!(
-                            "[splitux] Instance {} audio muted (session override)",
-                            i
-                        );
-                    }
+            // Effective audio re-binds renumbered sinks to their current names
+            match self.get_effective_audio(i) {
+                Some((_, _, true)) if self.audio_session_overrides.get(&i) == Some(&None) => {
+                    // Explicit mute - use sentinel value so audio routes to null sink
+                    cfg.audio
+                        .default_assignments
+                        .insert(i, AUDIO_MUTED_SENTINEL.to_string());
+                    log_info!(
+                        "Instance {} audio muted (session override)",
+                        i
+                    );
                 }
-                continue;
-            }
-
-            // Fall back to profile preference
-            if let Some(sink_name) = self.profile_audio_prefs.get(&i) {
-                cfg.audio.default_assignments.insert(i, sink_name.clone());
-                println!(
-                    "[splitux] Applied profile audio preference for instance {}: {}",
-                    i, sink_name
-                );
+                Some((sink_name, _, true)) => {
+                    log_info!(
+                        "Applied session audio override for instance {}: {}",
+                        i, sink_name
+                    );
+                    cfg.audio.default_assignments.insert(i, sink_name);
+                }
+                Some((sink_name, _, false)) if self.profile_audio_prefs.contains_key(&i) => {
+                    log_info!(
+                        "Applied profile audio preference for instance {}: {}",
+                        i, sink_name
+                    );
+                    cfg.audio.default_assignments.insert(i, sink_name);
+                }
+                Some((sink_name, _, false)) => {
+                    log_info!(
+                        "Applied guest default audio for instance {}: {}",
+                        i, sink_name
+                    );
+                    cfg.audio.default_assignments.insert(i, sink_name);
+                }
+                None => {}
             }
         }
         let _ = save_cfg(&cfg);
+        session_overrides(&mut cfg);
 
-        // Capture master profile for use in launch thread
-        let master_profile = cfg.master_profile.clone();
+        // Saved handlers remember when they were last played
+        let played_tx = if handler.is_saved_handler() {
+            let (tx, rx) = std::sync::mpsc::channel();
+            self.launch_played_rx = Some(rx);
+            Some((tx, handler.handler_dir_name().to_string()))
+        } else {
+            None
+        };
+
+        let kill = std::sync::Arc::new(KillSwitch::default());
+        self.session_kill = Some(std::sync::Arc::clone(&kill));
 
         self.cur_page = MenuPage::Games;
         self.spawn_task(
             "Launching...\n\nDon't press any buttons or move any analog sticks or mice.",
             move || {
-                // Clean up any orphaned processes from previous sessions
-                cleanup_orphaned_processes();
-
-                sleep(std::time::Duration::from_secs_f32(1.5));
-
-                if let Err(err) = setup_profiles(&handler, &instances) {
-                    println!("[splitux] Error setting up profiles: {}", err);
-                    msg("Failed setting up profiles", &format!("{err}"));
-                    return;
-                }
-
-                // Initialize profile saves with master-based inheritance
-                if !handler.original_save_path.is_empty() {
-                    if let Err(err) = save_sync::initialize_profile_saves(
-                        &handler,
-                        &instances,
-                        master_profile.as_deref(),
-                    ) {
-                        println!("[splitux] Warning: Failed to initialize saves: {}", err);
-                        // Continue anyway - this is non-fatal
-                    }
-                }
-
-                // Note: fuse_overlayfs_mount_gamedirs is now called inside launch_cmds
-                // with proper Goldberg overlay support
-                if let Err(err) = launch_game(&handler, &dev_infos, &instances, &monitors, &cfg) {
-                    println!("[splitux] Error launching instances: {}", err);
-                    msg("Launch Error", &format!("{err}"));
-                }
-
-                // Sync master profile's saves back to original location
-                if handler.save_sync_back {
-                    if let Err(err) = save_sync::sync_master_saves_back(
-                        &handler,
-                        &instances,
-                        master_profile.as_deref(),
-                    ) {
-                        println!("[splitux] Error syncing saves back: {}", err);
-                        msg("Save Sync Error", &format!("Failed to sync saves back: {err}"));
-                    }
-                }
-
-                // WM teardown is now handled inside launch_game
-                if let Err(err) = remove_guest_profiles() {
-                    println!("[splitux] Error removing guest profiles: {}", err);
-                    msg("Failed removing guest profiles", &format!("{err}"));
-                }
-                if let Err(err) = clear_tmp() {
-                    println!("[splitux] Error removing tmp directory: {}", err);
-                    msg("Failed removing tmp directory", &format!("{err}"));
+                let started = std::time::SystemTime::now();
+                if run_session(&handler, &dev_infos, &instances, &monitors, &cfg, &kill, msg) != SessionOutcome::Failed
+                    && let Some((tx, dir)) = played_tx
+                {
+                    let _ = tx.send((dir, started));
                 }
             },
         );
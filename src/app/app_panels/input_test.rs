@@ -0,0 +1,93 @@
+use crate::app::app::Splitux;
+
+use eframe::egui::RichText;
+use eframe::egui::{self, Ui};
+use egui_phosphor::regular as icons;
+
+use crate::ui::theme;
+
+impl Splitux {
+    /// Live view of what each input device is sending, for diagnosing controllers
+    ///
+    /// Devices are polled every frame by `handle_input_test_input`, which keeps
+    /// them from navigating while the window is open, so this only reads
+    /// their state.
+    pub fn display_input_test_window(&mut self, ctx: &egui::Context) {
+        let mut open = self.show_input_test;
+        egui::Window::new(format!("{} Input Test", icons::PULSE))
+            .open(&mut open)
+            .collapsible(false)
+            .default_size(egui::vec2(420.0, 480.0))
+            .show(ctx, |ui| {
+                ui.label(
+                    RichText::new("Press buttons and move sticks. Held buttons and axis values update live. B or Start closes this window.")
+                        .small()
+                        .color(theme::colors::TEXT_MUTED),
+                );
+                ui.separator();
+
+                if self.input_devices.is_empty() {
+                    ui.label(RichText::new("No devices detected").italics().weak());
+                    return;
+                }
+
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    for (idx, device) in self.input_devices.iter().enumerate() {
+                        self.display_input_test_device(ui, idx, device);
+                        ui.separator();
+                    }
+                });
+            });
+        self.show_input_test = open;
+    }
+
+    fn display_input_test_device(&self, ui: &mut Ui, idx: usize, device: &crate::input::InputDevice) {
+        let mut title = RichText::new(format!("{} {}", device.emoji(), device.fancyname())).strong();
+        if !device.enabled() {
+            title = title.weak();
+        }
+        ui.label(title);
+
+        egui::Grid::new(("input_test_info", idx)).num_columns(2).show(ui, |ui| {
+            ui.label("Type:");
+            ui.label(format!("{:?}", device.device_type()));
+            ui.end_row();
+            ui.label("Unique ID:");
+            ui.label(if device.uniq().is_empty() { "(none)" } else { device.uniq() });
+            ui.end_row();
+            ui.label("Node:");
+            ui.label(RichText::new(device.path()).monospace());
+            ui.end_row();
+            if self.device_display_name(idx) != device.fancyname() {
+                ui.label("Shown as:");
+                ui.label(self.device_display_name(idx));
+                ui.end_row();
+            }
+        });
+
+        if !device.enabled() {
+            ui.label(RichText::new("Disabled: not being polled").color(theme::colors::WARNING));
+            return;
+        }
+
+        let state = device.live_state();
+        ui.horizontal_wrapped(|ui| {
+            ui.label("Held:");
+            if state.pressed.is_empty() {
+                ui.label(RichText::new("nothing").weak());
+            }
+            for key in &state.pressed {
+                ui.label(RichText::new(key).monospace().color(theme::colors::ACCENT));
+            }
+        });
+        if !state.axes.is_empty() {
+            egui::Grid::new(("input_test_axes", idx)).num_columns(2).show(ui, |ui| {
+                for (axis, value) in &state.axes {
+                    ui.label(RichText::new(axis).small());
+                    ui.label(RichText::new(value.to_string()).monospace());
+                    ui.end_row();
+                }
+            });
+        }
+    }
+}
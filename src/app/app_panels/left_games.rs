@@ -1,7 +1,8 @@
 use crate::app::app::{FocusPane, MenuPage, Splitux};
 use crate::ui::theme;
 use crate::Handler;
-use crate::handler::{import_handler, scan_handlers};
+use crate::config::{save_cfg, GameSort};
+use crate::handler::{game_order, import_handler, scan_handlers, HandlerPackageFormat};
 use crate::util::*;
 
 use eframe::egui::Popup;
@@ -24,6 +25,17 @@ impl Splitux {
                 {
                     self.games_panel_collapsed = true;
                 }
+                let current = self.options.layout.games_sort;
+                ui.menu_button(icons::SORT_ASCENDING, |ui| {
+                    for sort in GameSort::ALL {
+                        if ui.selectable_label(sort == current, sort.label()).clicked() {
+                            self.set_games_sort(sort);
+                            ui.close();
+                        }
+                    }
+                })
+                .response
+                .on_hover_text(format!("Sort: {} (LT)", current.label()));
             });
         });
         ui.add_space(4.0);
@@ -91,6 +103,7 @@ impl Splitux {
                     msg("Error", &format!("Error importing handler: {}", e));
                 } else {
                     self.handlers = scan_handlers();
+                    self.handler_about = None;
                 }
             }
             if import_focused {
@@ -109,7 +122,7 @@ impl Splitux {
 
         let is_game_list_focused = self.focus_pane == FocusPane::GameList;
 
-        for i in 0..self.handlers.len() {
+        for i in self.game_list_order() {
             // Skip if index is out of bounds to catch for removing/rescanning handlers
             if i >= self.handlers.len() {
                 return;
@@ -150,6 +163,25 @@ impl Splitux {
                             .selectable(false)
                             .sense(egui::Sense::click()),
                     );
+
+                    if self.handlers[i].is_saved_handler() {
+                        let favorite = self.is_favorite(i);
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            let color = if favorite {
+                                theme::colors::WARNING
+                            } else {
+                                theme::colors::TEXT_MUTED
+                            };
+                            let hover = if favorite { "Remove from favorites (RT)" } else { "Add to favorites (RT)" };
+                            if ui
+                                .add(egui::Button::new(RichText::new(icons::STAR).color(color)).frame(false))
+                                .on_hover_text(hover)
+                                .clicked()
+                            {
+                                self.toggle_favorite(i);
+                            }
+                        });
+                    }
                     label
                 }).inner;
 
@@ -165,19 +197,70 @@ impl Splitux {
         }
     }
 
+    /// Handler indices in the order the games list shows them
+    pub(crate) fn game_list_order(&self) -> Vec<usize> {
+        game_order(&self.handlers, &self.options.games, self.options.layout.games_sort)
+    }
+
+    pub(crate) fn is_favorite(&self, i: usize) -> bool {
+        self.options
+            .games
+            .get(self.handlers[i].handler_dir_name())
+            .is_some_and(|g| g.favorite)
+    }
+
+    /// Change how the games list is sorted and save the choice
+    pub(crate) fn set_games_sort(&mut self, sort: GameSort) {
+        self.options.layout.games_sort = sort;
+        if let Err(e) = save_cfg(&self.options) {
+            log_error!("Failed to save settings: {}", e);
+        }
+    }
+
+    /// Star or unstar a game and save the choice
+    pub(crate) fn toggle_favorite(&mut self, i: usize) {
+        let dir = self.handlers[i].handler_dir_name().to_string();
+        let entry = self.options.games.entry(dir).or_default();
+        entry.favorite = !entry.favorite;
+        if let Err(e) = save_cfg(&self.options) {
+            log_error!("Failed to save settings: {}", e);
+        }
+    }
+
+    /// Whether a handler has a folder of its own (not the `--exec` lite handler)
+    pub(crate) fn can_open_handler_folder(&self, i: usize) -> bool {
+        self.handler_lite.is_none() && self.handlers.get(i).is_some_and(|h| h.is_saved_handler())
+    }
+
+    /// Open a handler's folder in the file manager
+    pub(crate) fn open_handler_folder(&self, i: usize) {
+        if !self.can_open_handler_folder(i) {
+            return;
+        }
+        if std::process::Command::new("xdg-open")
+            .arg(self.handlers[i].path_handler.clone())
+            .status()
+            .is_err()
+        {
+            msg("Error", "Couldn't open handler folder!");
+        }
+    }
+
     pub fn handler_ctx_menu(&mut self, ui: &mut Ui, i: usize) {
+        if self.handlers[i].is_saved_handler() {
+            let label = if self.is_favorite(i) { "Unfavorite" } else { "Favorite" };
+            if ui.button(label).clicked() {
+                self.toggle_favorite(i);
+            }
+        }
+
         if ui.button("Edit").clicked() {
             self.handler_edit = Some(self.handlers[i].clone());
             self.show_edit_modal = true;
         }
 
         if ui.button("Open Folder").clicked() {
-            if let Err(_) = std::process::Command::new("xdg-open")
-                .arg(self.handlers[i].path_handler.clone())
-                .status()
-            {
-                msg("Error", "Couldn't open handler folder!");
-            }
+            self.open_handler_folder(i);
         }
 
         if ui.button("Remove").clicked() {
@@ -189,11 +272,12 @@ impl Splitux {
                 ),
             ) {
                 if let Err(err) = self.handlers[i].remove_handler() {
-                    println!("[splitux] Failed to remove handler: {}", err);
+                    log_error!("Failed to remove handler: {}", err);
                     msg("Error", &format!("Failed to remove handler: {}", err));
                 }
 
                 self.handlers = scan_handlers();
+                self.handler_about = None;
                 if self.handlers.is_empty() {
                     self.cur_page = MenuPage::Games;
                 }
@@ -203,11 +287,16 @@ impl Splitux {
             }
         }
 
-        if ui.button("Export").clicked() {
-            if let Err(err) = self.handlers[i].export() {
-                println!("[splitux] Failed to export handler: {}", err);
-                msg("Error", &format!("Failed to export handler: {}", err));
+        ui.menu_button("Export", |ui| {
+            for format in HandlerPackageFormat::ALL {
+                if ui.button(format.label()).clicked() {
+                    if let Err(err) = self.handlers[i].export(format) {
+                        log_error!("Failed to export handler: {}", err);
+                        msg("Error", &format!("Failed to export handler: {}", err));
+                    }
+                    ui.close();
+                }
             }
-        }
+        });
     }
 }
@@ -72,8 +72,7 @@ impl Splitux {
             // Click on inner label or the frame
             if frame_resp.response.clicked() || frame_resp.inner.clicked() {
                 self.settings_category = cat;
-                self.settings_focus = SettingsFocus::Options;
-                self.settings_option_index = 0;
+                self.focus_first_settings_option();
             }
         }
 
@@ -86,7 +85,7 @@ impl Splitux {
 
     /// Settings bottom buttons for left panel
     pub(super) fn display_settings_bottom_buttons_panel(&mut self, ui: &mut Ui) {
-        use crate::config::{save_cfg, SplituxConfig};
+        use crate::config::SplituxConfig;
         use crate::input::scan_input_devices;
 
         let is_buttons_focused = self.settings_focus == SettingsFocus::BottomButtons;
@@ -101,7 +100,7 @@ impl Splitux {
             || (is_buttons_focused && self.settings_button_index == 1 && self.activate_focused)
         {
             self.options = SplituxConfig::default();
-            self.input_devices = scan_input_devices(&self.options.pad_filter_type);
+            self.input_devices = scan_input_devices(&self.options.pad_filter_type, &self.options.device_filter);
             self.refresh_device_display_names();
         }
 
@@ -116,12 +115,41 @@ impl Splitux {
         if save_response.clicked()
             || (is_buttons_focused && self.settings_button_index == 0 && self.activate_focused)
         {
-            if let Err(e) = save_cfg(&self.options) {
-                crate::util::msg("Error", &format!("Couldn't save settings: {}", e));
-            }
+            self.save_settings();
         }
 
         ui.add_space(4.0);
         ui.separator();
     }
+
+    /// Save settings after checking the scratch directory
+    fn save_settings(&mut self) {
+        use crate::config::save_cfg;
+        use crate::paths::{on_different_filesystem, validate_tmp_dir, TMP_DIR_NAME};
+
+        if let Some(dir) = self.options.tmp_dir_path() {
+            if let Err(e) = validate_tmp_dir(&dir) {
+                crate::util::msg("Scratch directory", &e);
+                return;
+            }
+            if !self.options.disable_mount_gamedirs
+                && let Some(h) = self.handlers.get(self.selected_handler)
+                && let Ok(game_root) = h.get_game_rootpath()
+                && on_different_filesystem(&dir.join(TMP_DIR_NAME), std::path::Path::new(&game_root))
+            {
+                crate::util::msg(
+                    "Scratch directory",
+                    &format!(
+                        "{} is on a different drive than {}.\n\nGames will still run, but overlay mounts may be slower. Consider a folder on the same drive as your games.",
+                        dir.display(),
+                        game_root
+                    ),
+                );
+            }
+        }
+
+        if let Err(e) = save_cfg(&self.options) {
+            crate::util::msg("Error", &format!("Couldn't save settings: {}", e));
+        }
+    }
 }
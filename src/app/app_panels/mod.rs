@@ -3,6 +3,7 @@ mod left_games;
 mod left_settings;
 mod right_devices;
 mod collapsed;
+mod input_test;
 
 use crate::app::app::{MenuPage, Splitux};
 
@@ -1,9 +1,38 @@
 use crate::app::app::Splitux;
+use crate::input::{Battery, DeviceType};
+use crate::ui::theme;
 
 use eframe::egui::RichText;
 use eframe::egui::{self, Ui};
 use egui_phosphor::regular as icons;
 
+/// How long the identify rumble plays
+const RUMBLE_TEST_MS: u16 = 400;
+
+/// How often controller batteries are re-read
+const BATTERY_REFRESH: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Charge at or below which the battery is shown as low
+const BATTERY_LOW_PERCENT: u8 = 20;
+
+/// Icon and percentage for a controller's battery, red when running low
+fn battery_text(battery: Battery) -> RichText {
+    let icon = match battery.percent {
+        _ if battery.charging => icons::BATTERY_CHARGING,
+        p if p > 85 => icons::BATTERY_FULL,
+        p if p > 60 => icons::BATTERY_HIGH,
+        p if p > 35 => icons::BATTERY_MEDIUM,
+        p if p > BATTERY_LOW_PERCENT => icons::BATTERY_LOW,
+        _ => icons::BATTERY_WARNING,
+    };
+    let text = RichText::new(format!("{} {}%", icon, battery.percent)).small();
+    if battery.percent <= BATTERY_LOW_PERCENT && !battery.charging {
+        text.color(theme::colors::ERROR)
+    } else {
+        text.color(theme::colors::TEXT_SECONDARY)
+    }
+}
+
 impl Splitux {
     pub fn display_panel_right(&mut self, ui: &mut Ui, ctx: &egui::Context) {
         ui.add_space(8.0);
@@ -18,6 +47,13 @@ impl Splitux {
                 {
                     self.devices_panel_collapsed = true;
                 }
+                if ui
+                    .add(egui::Button::new(icons::PULSE).min_size(egui::vec2(20.0, 20.0)).frame(false))
+                    .on_hover_text("Input test: show what each device is sending")
+                    .clicked()
+                {
+                    self.show_input_test = !self.show_input_test;
+                }
             });
         });
         ui.add_space(4.0);
@@ -33,6 +69,11 @@ impl Splitux {
             ui.label(RichText::new(format!("{} device(s) ready", enabled_count)).small());
             ui.add_space(8.0);
 
+            for pad in self.input_devices.iter_mut() {
+                pad.refresh_battery(BATTERY_REFRESH);
+            }
+
+            let mut rumble_idx: Option<usize> = None;
             egui::ScrollArea::vertical()
                 .max_height(ui.available_height() - 80.0)
                 .show(ui, |ui| {
@@ -50,9 +91,37 @@ impl Splitux {
                             dev_text = dev_text.strong();
                         }
 
-                        ui.label(dev_text);
+                        ui.horizontal(|ui| {
+                            ui.label(dev_text);
+                            if let Some(battery) = pad.battery() {
+                                let status = if battery.charging { "charging" } else { "discharging" };
+                                ui.label(battery_text(battery))
+                                    .on_hover_text(format!("Battery {}%, {}", battery.percent, status));
+                            }
+                            if pad.device_type() == DeviceType::Gamepad {
+                                let supports_rumble = pad.supports_rumble();
+                                let disabled_reason = if supports_rumble {
+                                    "Device is disabled"
+                                } else {
+                                    "This device doesn't support force feedback"
+                                };
+                                let rumble_btn = ui
+                                    .add_enabled(pad.enabled() && supports_rumble, egui::Button::new(icons::VIBRATE).small())
+                                    .on_hover_text("Rumble to identify this controller")
+                                    .on_disabled_hover_text(disabled_reason);
+                                if rumble_btn.clicked() {
+                                    rumble_idx = Some(idx);
+                                }
+                            }
+                        });
                     }
                 });
+
+            if let Some(idx) = rumble_idx
+                && let Err(e) = self.input_devices[idx].rumble(RUMBLE_TEST_MS)
+            {
+                log_error!("Rumble failed for {}: {}", self.input_devices[idx].path(), e);
+            }
         }
 
         ui.with_layout(egui::Layout::bottom_up(egui::Align::Center), |ui| {
@@ -1,60 +1,57 @@
 use crate::app::app::{MenuPage, Splitux};
+use crate::config::PageTab;
 
 use eframe::egui::RichText;
 use eframe::egui::{self, Ui};
 use egui_phosphor::regular as icons;
 
 impl Splitux {
+    /// Top-bar tabs in configured order (Registry is never shown in lite mode)
+    pub(crate) fn visible_page_tabs(&self) -> Vec<PageTab> {
+        let mut tabs = self.options.layout.visible_page_tabs();
+        if self.is_lite() {
+            tabs.retain(|&t| t != PageTab::Registry);
+        }
+        tabs
+    }
+
+    /// Page opened by a tab (Games opens the instance screen in lite mode)
+    pub(crate) fn page_for_tab(&self, tab: PageTab) -> MenuPage {
+        match tab {
+            PageTab::Games if self.is_lite() => MenuPage::Instances,
+            PageTab::Games => MenuPage::Games,
+            PageTab::Registry => MenuPage::Registry,
+            PageTab::Settings => MenuPage::Settings,
+        }
+    }
+
     pub fn display_panel_top(&mut self, ui: &mut Ui) {
         ui.horizontal(|ui| {
-            // === Main Navigation Tabs (2 tabs: Games, Settings) ===
-            let games_text = match self.is_lite() {
-                true => "Play",
-                false => "Games",
-            };
-            let games_page = match self.is_lite() {
-                true => MenuPage::Instances,
-                false => MenuPage::Games,
-            };
-
-            // Games/Play tab
-            let games_selected = self.cur_page == MenuPage::Games || (self.is_lite() && self.cur_page == MenuPage::Instances);
+            // === Main Navigation Tabs (configured order and visibility) ===
             ui.add_space(4.0);
-            let games_btn = ui.add(
-                egui::Button::new(games_text)
-                    .min_size(egui::vec2(70.0, 28.0))
-                    .selected(games_selected),
-            );
-            if games_btn.clicked() {
-                self.cur_page = games_page;
-            }
-
-            // Registry tab (only show in full mode, not lite)
-            if !self.is_lite() {
-                let registry_btn = ui.add(
-                    egui::Button::new("Registry")
+            for tab in self.visible_page_tabs() {
+                let page = self.page_for_tab(tab);
+                let label = match tab {
+                    PageTab::Games if self.is_lite() => "Play",
+                    _ => tab.label(),
+                };
+                let tab_btn = ui.add(
+                    egui::Button::new(label)
                         .min_size(egui::vec2(70.0, 28.0))
-                        .selected(self.cur_page == MenuPage::Registry),
+                        .selected(self.cur_page.tab() == tab),
                 );
-                if registry_btn.clicked() {
-                    self.cur_page = MenuPage::Registry;
+                if tab_btn.clicked() {
+                    self.cur_page = page;
                     // Fetch registry if not already loaded
-                    if self.registry_index.is_none() && !self.registry_loading {
+                    if page == MenuPage::Registry
+                        && self.registry_index.is_none()
+                        && !self.registry_loading
+                    {
                         self.fetch_registry();
                     }
                 }
             }
 
-            // Settings tab
-            let settings_btn = ui.add(
-                egui::Button::new("Settings")
-                    .min_size(egui::vec2(70.0, 28.0))
-                    .selected(self.cur_page == MenuPage::Settings),
-            );
-            if settings_btn.clicked() {
-                self.cur_page = MenuPage::Settings;
-            }
-
             // === Right Side: Version & Close ===
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                 let close_btn = ui.add(
@@ -106,23 +106,23 @@ impl Splitux {
         // Use the dialog crate for input (same as existing profile creation)
         use dialog::DialogBox;
 
-        if let Some(name) = dialog::Input::new("Enter name (must be alphanumeric):")
+        if let Some(name) = dialog::Input::new("Enter name (letters, numbers, _ or -):")
             .title("New Profile")
             .show()
             .expect("Could not display dialog box")
         {
-            if !name.is_empty() && name.chars().all(char::is_alphanumeric) {
-                if let Err(e) = create_profile(&name) {
-                    msg("Error", &format!("Failed to create profile: {}", e));
-                } else {
-                    self.profiles = scan_profiles(false);
-                    // Select the new profile
-                    if let Some(idx) = self.profiles.iter().position(|p| p == &name) {
-                        self.set_current_profile(idx);
-                    }
+            if name.is_empty() {
+                // Cancelled with an empty name
+            } else if let Err(e) = validate_profile_name(&name) {
+                msg("Error", &format!("Invalid name - {}", e));
+            } else if let Err(e) = create_profile(&name) {
+                msg("Error", &format!("Failed to create profile: {}", e));
+            } else {
+                self.profiles = scan_profiles(false);
+                // Select the new profile
+                if let Some(idx) = self.profiles.iter().position(|p| p == &name) {
+                    self.set_current_profile(idx);
                 }
-            } else if !name.is_empty() {
-                msg("Error", "Invalid name - must be alphanumeric");
             }
         }
         self.show_new_profile_dialog = false;
@@ -57,9 +57,7 @@ impl Splitux {
             match self.settings_focus {
                 SettingsFocus::CategoryList => {
                     // A on category = enter options
-                    self.settings_focus = SettingsFocus::Options;
-                    self.settings_option_index = 0;
-                    self.settings_scroll_to_focus = true;
+                    self.focus_first_settings_option();
                 }
                 SettingsFocus::Options => {
                     self.activate_focused = true;
@@ -3,6 +3,7 @@
 use crate::app::app::{MenuPage, Splitux};
 use crate::input::*;
 use crate::ui::focus::pipelines::handle_input::handle_direction;
+use crate::ui::focus::pure::cycle_page_tab;
 use crate::ui::focus::types::NavDirection;
 use eframe::egui::{self, Key, Vec2};
 
@@ -25,33 +26,25 @@ impl Splitux {
         let on_games_page = self.cur_page == MenuPage::Games;
         let on_registry_page = self.cur_page == MenuPage::Registry;
         let on_settings_page = self.cur_page == MenuPage::Settings;
-        let on_instances_page = self.cur_page == MenuPage::Instances;
         let has_handlers = !self.handlers.is_empty();
         let registry_needs_fetch = self.registry_index.is_none() && !self.registry_loading;
 
         // Process keyboard navigation
-        let kb_nav_consumed = self.process_keyboard_nav(
-            raw_input, on_instances_page, on_settings_page, &mut key, &mut page_changed
-        );
-        if kb_nav_consumed {
-            raw_input.events.retain(|event| {
-                !matches!(event, egui::Event::Key { key: k, pressed: true, .. }
-                    if matches!(k, Key::ArrowUp | Key::ArrowDown | Key::ArrowLeft | Key::ArrowRight | Key::Enter | Key::Escape))
-            });
-        }
+        self.process_keyboard_nav(ctx, raw_input, &mut key, &mut page_changed);
 
         // Collect poll results
+        let stick_nav = &self.options.stick_nav;
         let results: Vec<PollResult> = self.input_devices
             .iter_mut()
             .filter(|pad| pad.enabled())
-            .map(|pad| pad.poll())
+            .map(|pad| pad.poll(stick_nav))
             .collect();
 
         // Process each result
         for result in results {
             match result {
                 PollResult::DeviceDisabled(reason) => {
-                    eprintln!("[splitux] evdev: {}", reason);
+                    log_warn!("evdev: {}", reason);
                     continue;
                 }
                 PollResult::None => continue,
@@ -61,6 +54,22 @@ impl Splitux {
                 PollResult::Button(b) => b,
                 _ => unreachable!(),
             };
+            // The handler editor is a plain egui form: the d-pad walks widget focus
+            // and A presses the focused widget, so the page behind it doesn't react
+            if self.show_edit_modal && !matches!(btn, PadButton::ScrollUp | PadButton::ScrollDown) {
+                let (form_key, modifiers) = match btn {
+                    PadButton::Down | PadButton::Right => (Some(Key::Tab), egui::Modifiers::default()),
+                    PadButton::Up | PadButton::Left => (Some(Key::Tab), egui::Modifiers::SHIFT),
+                    PadButton::ABtn => (Some(Key::Enter), egui::Modifiers::default()),
+                    _ => (None, egui::Modifiers::default()),
+                };
+                if let Some(form_key) = form_key {
+                    raw_input.events.push(egui::Event::Key {
+                        key: form_key, physical_key: None, pressed: true, repeat: false, modifiers,
+                    });
+                }
+                continue;
+            }
             match btn {
                 PadButton::ABtn => {
                     self.handle_a_button(
@@ -127,6 +136,25 @@ impl Splitux {
                     }
                 }
                 PadButton::SelectBtn => key = Some(Key::Tab),
+                // Games list: RT stars the selected game, LT cycles the sort
+                PadButton::RT
+                    if on_games_page
+                        && has_handlers
+                        && !dropdown_open
+                        && self.handlers[self.selected_handler].is_saved_handler() =>
+                {
+                    self.toggle_favorite(self.selected_handler);
+                }
+                PadButton::LT if on_games_page && !dropdown_open => {
+                    self.set_games_sort(self.options.layout.games_sort.next());
+                }
+                // Registry: LT and RT step the backend and player filter chips
+                PadButton::LT if on_registry_page => {
+                    if let Some(index) = &self.registry_index {
+                        self.registry_filter.cycle_backend(&index.backends());
+                    }
+                }
+                PadButton::RT if on_registry_page => self.registry_filter.cycle_min_players(),
                 PadButton::StartBtn => start_pressed = true,
                 PadButton::Up => {
                     self.handle_direction_input(NavDirection::Up, &mut key);
@@ -143,18 +171,18 @@ impl Splitux {
                 PadButton::RB => {
                     self.active_dropdown = None;
                     self.profile_dropdown_open = false;
-                    if let Some((new_page, needs_fetch)) = self.cycle_page_forward(registry_needs_fetch) {
+                    if let Some(new_page) = self.cycle_page(true) {
                         self.cur_page = new_page;
-                        fetch_registry_needed = needs_fetch;
+                        fetch_registry_needed = new_page == MenuPage::Registry && registry_needs_fetch;
                         page_changed = true;
                     }
                 }
                 PadButton::LB => {
                     self.active_dropdown = None;
                     self.profile_dropdown_open = false;
-                    if let Some((new_page, needs_fetch)) = self.cycle_page_backward(registry_needs_fetch) {
+                    if let Some(new_page) = self.cycle_page(false) {
                         self.cur_page = new_page;
-                        fetch_registry_needed = needs_fetch;
+                        fetch_registry_needed = new_page == MenuPage::Registry && registry_needs_fetch;
                         page_changed = true;
                     }
                 }
@@ -207,21 +235,21 @@ impl Splitux {
         let _ = page_changed;
     }
 
-    fn cycle_page_forward(&self, registry_needs_fetch: bool) -> Option<(MenuPage, bool)> {
-        match self.cur_page {
-            MenuPage::Games => Some((MenuPage::Registry, registry_needs_fetch)),
-            MenuPage::Registry => Some((MenuPage::Settings, false)),
-            MenuPage::Settings => Some((MenuPage::Games, false)),
-            MenuPage::Instances => Some((MenuPage::Registry, registry_needs_fetch)),
-        }
+    /// Page reached by LB/RB, following the configured tab order
+    pub(crate) fn cycle_page(&self, forward: bool) -> Option<MenuPage> {
+        let tab = cycle_page_tab(&self.visible_page_tabs(), self.cur_page.tab(), forward)?;
+        Some(self.page_for_tab(tab))
     }
 
-    fn cycle_page_backward(&self, registry_needs_fetch: bool) -> Option<(MenuPage, bool)> {
-        match self.cur_page {
-            MenuPage::Games => Some((MenuPage::Settings, false)),
-            MenuPage::Registry => Some((MenuPage::Games, false)),
-            MenuPage::Settings => Some((MenuPage::Registry, registry_needs_fetch)),
-            MenuPage::Instances => Some((MenuPage::Settings, false)),
+    /// Switch to the next/previous page tab (keyboard LB/RB)
+    pub(crate) fn go_to_adjacent_page(&mut self, forward: bool) {
+        self.active_dropdown = None;
+        self.profile_dropdown_open = false;
+        if let Some(new_page) = self.cycle_page(forward) {
+            self.cur_page = new_page;
+            if new_page == MenuPage::Registry && self.registry_index.is_none() && !self.registry_loading {
+                self.fetch_registry();
+            }
         }
     }
 
@@ -337,7 +365,7 @@ impl Splitux {
         // Special case: entering bottom panel from last handler
         if self.focus_pane == FocusPane::GameList
             && direction == NavDirection::Down
-            && self.selected_handler >= self.handlers.len().saturating_sub(1)
+            && self.game_list_order().last().is_none_or(|&i| i == self.selected_handler)
         {
             self.game_panel_bottom_focused = true;
             self.game_panel_bottom_index = 0;
@@ -358,4 +386,61 @@ impl Splitux {
         let actions = handle_direction(&ctx, direction);
         self.apply_nav_actions(actions);
     }
+
+    /// Gamepad input while a launch task runs: A or Start ends a running session
+    ///
+    /// Presses queued while a game had focus are drained first, so only
+    /// presses made after the launcher is focused count.
+    pub(crate) fn handle_session_pad_input(&mut self) {
+        let armed = self.session_pad_armed;
+        self.session_pad_armed = true;
+        let stick_nav = &self.options.stick_nav;
+        // Every pad is polled, so none keeps stale presses queued
+        let results: Vec<PollResult> = self
+            .input_devices
+            .iter_mut()
+            .filter(|pad| pad.enabled())
+            .map(|pad| pad.poll(stick_nav))
+            .collect();
+        let pressed = results
+            .iter()
+            .any(|result| matches!(result, PollResult::Button(PadButton::ABtn | PadButton::StartBtn)));
+        if armed && pressed {
+            self.end_session();
+        }
+    }
+
+    /// Input while the input test window is open: B, Start or Escape closes it
+    ///
+    /// Navigation is suppressed so testing buttons doesn't also drive the
+    /// page behind the window. Pads are still polled, which updates the live
+    /// state the window shows.
+    pub(crate) fn handle_input_test_input(&mut self, raw_input: &egui::RawInput) {
+        let stick_nav = &self.options.stick_nav;
+        let results: Vec<PollResult> = self
+            .input_devices
+            .iter_mut()
+            .filter(|pad| pad.enabled())
+            .map(|pad| pad.poll(stick_nav))
+            .collect();
+        let pad_close = results
+            .iter()
+            .any(|result| matches!(result, PollResult::Button(PadButton::BBtn | PadButton::StartBtn)));
+        let key_close = raw_input
+            .events
+            .iter()
+            .any(|e| matches!(e, egui::Event::Key { key: Key::Escape, pressed: true, .. }));
+        if pad_close || key_close {
+            self.show_input_test = false;
+        }
+    }
+
+    /// End the session the launch task is running, if its games have started
+    pub(crate) fn end_session(&mut self) {
+        if let Some(kill) = &self.session_kill
+            && kill.is_running()
+        {
+            kill.request_end();
+        }
+    }
 }
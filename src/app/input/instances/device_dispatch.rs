@@ -3,21 +3,26 @@
 use crate::app::app::{ActiveDropdown, InstanceFocus, MenuPage, Splitux};
 use crate::input::*;
 use crate::ui::focus::types::InstanceCardFocus;
+use crate::wm::presets::MAX_PRESET_PLAYERS;
 use eframe::egui;
 
 impl Splitux {
-    pub(crate) fn handle_devices_instance_menu(&mut self, _ctx: &egui::Context, raw_input: &mut egui::RawInput) {
+    pub(crate) fn handle_devices_instance_menu(&mut self, ctx: &egui::Context, raw_input: &mut egui::RawInput) {
         self.activate_focused = false;
 
+        let mut key: Option<egui::Key> = None;
+        let mut page_changed = false;
+        self.process_keyboard_nav(ctx, raw_input, &mut key, &mut page_changed);
+
         let mut i = 0;
         while i < self.input_devices.len() {
             if !self.input_devices[i].enabled() {
                 i += 1;
                 continue;
             }
-            match self.input_devices[i].poll() {
+            match self.input_devices[i].poll(&self.options.stick_nav) {
                 PollResult::DeviceDisabled(reason) => {
-                    eprintln!("[splitux] evdev: {}", reason);
+                    log_warn!("evdev: {}", reason);
                     i += 1;
                     continue;
                 }
@@ -33,21 +38,25 @@ impl Splitux {
                         continue;
                     }
 
+                    // Claim mode: an unassigned device joins as the next player,
+                    // whatever the menu focus is
+                    if self.claim_mode
+                        && self.instance_add_dev.is_none()
+                        && claims_slot(
+                            i,
+                            self.input_devices[i].device_type(),
+                            &self.instances,
+                            self.options.input_holding,
+                        )
+                    {
+                        self.push_instance_for_device(i);
+                        i += 1;
+                        continue;
+                    }
+
                     match &self.instance_focus {
                         InstanceFocus::LaunchOptions => {
-                            let player_count = self.instances.len();
-                            let has_carousel = player_count >= 2;
-                            let max_options = if has_carousel { 2 } else { 1 };
-                            match self.launch_option_index {
-                                0 if has_carousel => {
-                                    // A button on carousel cycles to next preset
-                                    self.options.layout_presets.cycle_next(player_count);
-                                }
-                                idx if idx == max_options - 1 => {
-                                    self.options.input_holding = !self.options.input_holding;
-                                }
-                                _ => {}
-                            }
+                            self.activate_launch_option();
                             i += 1;
                             continue;
                         }
@@ -68,21 +77,13 @@ impl Splitux {
                     }
 
                     // Normal device handling
-                    if self.input_devices[i].device_type() != DeviceType::Gamepad
-                        && !self.options.input_holding
-                    {
-                        i += 1;
-                        continue;
-                    }
-                    if !self.options.allow_multiple_instances_on_same_device
-                        && self.is_device_in_any_instance(i)
-                    {
-                        i += 1;
-                        continue;
-                    }
-                    if self.input_devices[i].device_type() != DeviceType::Gamepad
-                        && self.is_device_in_any_instance(i)
-                    {
+                    if !can_assign_device(
+                        i,
+                        self.input_devices[i].device_type(),
+                        &self.instances,
+                        self.options.input_holding,
+                        self.options.allow_multiple_instances_on_same_device,
+                    ) {
                         i += 1;
                         continue;
                     }
@@ -97,16 +98,7 @@ impl Splitux {
                                 continue;
                             }
                         }
-                        None => {
-                            self.instances.push(crate::instance::Instance {
-                                devices: vec![i],
-                                profname: String::new(),
-                                profselection: 0,
-                                monitor: 0,
-                                width: 0,
-                                height: 0,
-                            });
-                        }
+                        None => self.push_instance_for_device(i),
                     }
                 }
                 PollResult::Button(PadButton::BBtn) | PollResult::Button(PadButton::XKey) => {
@@ -157,6 +149,19 @@ impl Splitux {
                     }
                 }
                 PollResult::Button(PadButton::YBtn) | PollResult::Button(PadButton::AKey) => {
+                    // Y in custom layout mode derives a layout for one more player
+                    if self.layout_custom_mode {
+                        let target = self.instances.len() + 1;
+                        if target <= MAX_PRESET_PLAYERS
+                            && let Some(preset) = self.get_current_preset()
+                        {
+                            self.exit_custom_layout_mode();
+                            self.derive_layout_preset(&preset, target);
+                        }
+                        i += 1;
+                        continue;
+                    }
+
                     // Y button enters custom layout mode when on carousel
                     if self.instance_focus == InstanceFocus::LaunchOptions
                         && self.launch_option_index == 0
@@ -179,6 +184,15 @@ impl Splitux {
                         }
                     }
                 }
+                PollResult::Button(PadButton::SelectBtn) if !self.layout_custom_mode => {
+                    self.toggle_instance_table_view();
+                }
+                PollResult::Button(PadButton::RT) | PollResult::Button(PadButton::RKey) if !self.layout_custom_mode => {
+                    self.layout_preview = !self.layout_preview;
+                }
+                PollResult::Button(PadButton::LT) if !self.layout_custom_mode => {
+                    self.claim_mode = !self.claim_mode;
+                }
                 PollResult::Button(PadButton::StartBtn) => {
                     if self.instances.len() > 0 && self.is_device_in_any_instance(i) {
                         self.prepare_game_launch();
@@ -226,8 +240,9 @@ impl Splitux {
                             ActiveDropdown::InstanceAudioOverride(_) => self.audio_devices.len() + 2, // devices + mute + reset
                             ActiveDropdown::InstanceAudioPreference(_) => self.audio_devices.len() + 1, // devices + clear
                             ActiveDropdown::InstanceGptokeyb(_) => {
-                                // 2 (default + disabled) + builtin profiles + user profiles
-                                2 + crate::gptokeyb::list_builtin_profiles().len()
+                                // 2 (default + disabled) + bundled + builtin profiles + user profiles
+                                2 + self.bundled_gptokeyb_profiles().len()
+                                  + crate::gptokeyb::list_builtin_profiles().len()
                                   + crate::gptokeyb::list_user_profiles().len()
                             }
                             _ => 0,
@@ -255,15 +270,16 @@ impl Splitux {
                     }
                     self.handle_instance_right();
                 }
-                PollResult::Button(PadButton::LB) => {
-                    self.active_dropdown = None;
-                    self.cur_page = MenuPage::Settings;
-                }
-                PollResult::Button(PadButton::RB) => {
+                PollResult::Button(btn @ (PadButton::LB | PadButton::RB)) => {
                     self.active_dropdown = None;
-                    self.cur_page = MenuPage::Registry;
-                    if self.registry_index.is_none() && !self.registry_loading {
-                        self.fetch_registry();
+                    if let Some(new_page) = self.cycle_page(matches!(btn, PadButton::RB)) {
+                        self.cur_page = new_page;
+                        if new_page == MenuPage::Registry
+                            && self.registry_index.is_none()
+                            && !self.registry_loading
+                        {
+                            self.fetch_registry();
+                        }
                     }
                 }
                 _ => {}
@@ -271,4 +287,20 @@ impl Splitux {
             i += 1;
         }
     }
+
+    /// Start a new instance driven by one device
+    fn push_instance_for_device(&mut self, device: usize) {
+        self.instances.push(crate::instance::Instance {
+            devices: vec![device],
+            profname: String::new(),
+            profselection: 0,
+            monitor: 0,
+            width: 0,
+            height: 0,
+            grab_cursor: None,
+            mangohud: false,
+            resolution_override: (0, 0),
+            args_override: String::new(),
+        });
+    }
 }
@@ -7,6 +7,16 @@ use crate::ui::focus::types::InstanceCardFocus;
 impl Splitux {
     /// Process keyboard navigation for instance page
     pub(crate) fn process_instance_nav_key(&mut self, btn: PadButton) {
+        if self.layout_custom_mode {
+            match btn {
+                PadButton::Up => self.navigate_custom_layout_up(),
+                PadButton::Down => self.navigate_custom_layout_down(),
+                PadButton::Left => self.navigate_custom_layout_left(),
+                PadButton::Right => self.navigate_custom_layout_right(),
+                _ => {}
+            }
+            return;
+        }
         match btn {
             PadButton::Up => self.handle_instance_up(),
             PadButton::Down => self.handle_instance_down(),
@@ -18,22 +28,12 @@ impl Splitux {
 
     /// Process keyboard activation for instance page
     pub(crate) fn process_instance_activate_key(&mut self) {
+        if self.layout_custom_mode {
+            self.cycle_instance_in_region(self.instances.len());
+            return;
+        }
         match &self.instance_focus {
-            InstanceFocus::LaunchOptions => {
-                let player_count = self.instances.len();
-                let has_carousel = player_count >= 2;
-                let max_options = if has_carousel { 2 } else { 1 };
-                match self.launch_option_index {
-                    0 if has_carousel => {
-                        // Enter/A on carousel cycles to next preset
-                        self.options.layout_presets.cycle_next(player_count);
-                    }
-                    idx if idx == max_options - 1 => {
-                        self.options.input_holding = !self.options.input_holding;
-                    }
-                    _ => {}
-                }
-            }
+            InstanceFocus::LaunchOptions => self.activate_launch_option(),
             InstanceFocus::StartButton => {
                 if self.instances.len() > 0 {
                     self.prepare_game_launch();
@@ -48,6 +48,10 @@ impl Splitux {
 
     /// Process keyboard back for instance page
     pub(crate) fn process_instance_back_key(&mut self) {
+        if self.layout_custom_mode {
+            self.exit_custom_layout_mode();
+            return;
+        }
         match &self.instance_focus {
             InstanceFocus::LaunchOptions | InstanceFocus::StartButton => {
                 if self.instances.len() > 0 {
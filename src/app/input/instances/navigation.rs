@@ -1,16 +1,52 @@
 //! Instance page directional navigation
 
 use crate::app::app::{InstanceFocus, Splitux};
-use crate::ui::focus::types::InstanceCardFocus;
+use crate::ui::focus::pure::{launch_options, navigate_instance_table};
+use crate::ui::focus::types::{InstanceCardFocus, NavDirection};
 
 impl Splitux {
+    /// Navigate table cells when the compact table view is active
+    ///
+    /// Returns true if the input was consumed.
+    fn handle_instance_table_nav(&mut self, direction: NavDirection) -> bool {
+        if !self.options.layout.instance_table_view {
+            return false;
+        }
+        let columns = self.instance_table_columns();
+        match &self.instance_focus {
+            InstanceFocus::InstanceCard(row, element) => {
+                let focus = navigate_instance_table(*row, element, direction, self.instances.len(), &columns);
+                if focus == InstanceFocus::LaunchOptions {
+                    self.launch_option_index = 0;
+                }
+                self.instance_focus = focus;
+                true
+            }
+            InstanceFocus::LaunchOptions if direction == NavDirection::Up && !self.instances.is_empty() => {
+                self.instance_focus = InstanceFocus::InstanceCard(self.instances.len() - 1, columns[0]);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether a card shows the star that remembers its KB/mouse profile
+    ///
+    /// Only named profiles get one, and only for a handler with a directory to key on.
+    fn gptokeyb_pref_visible(&self, is_named: bool) -> bool {
+        is_named && self.active_handler().is_some_and(|h| !h.handler_dir_name().is_empty())
+    }
+
     pub(super) fn handle_instance_up(&mut self) {
+        if self.handle_instance_table_nav(NavDirection::Up) {
+            return;
+        }
         match &self.instance_focus {
             InstanceFocus::LaunchOptions => {
                 if self.instances.len() > 0 {
                     self.instance_focus = InstanceFocus::InstanceCard(
                         self.instances.len() - 1,
-                        InstanceCardFocus::AudioOverride
+                        InstanceCardFocus::Args
                     );
                 } else {
                     self.instance_focus = InstanceFocus::Devices;
@@ -29,13 +65,19 @@ impl Splitux {
                 let is_named = profile_name.is_some_and(|p| !p.starts_with("Guest"));
                 let is_master = profile_name.is_some_and(|p| self.options.master_profile.as_ref() == Some(p));
                 let set_master_visible = is_named && !is_master;
+                // Reorder buttons follow Clone when there's someone to swap with
+                let last_button = if self.instances.len() > 1 {
+                    InstanceCardFocus::MoveDown
+                } else {
+                    InstanceCardFocus::CloneInstance
+                };
 
                 let new_element = match element {
                     InstanceCardFocus::Profile => {
                         if idx > 0 {
                             self.instance_focus = InstanceFocus::InstanceCard(
                                 idx - 1,
-                                InstanceCardFocus::AudioOverride
+                                InstanceCardFocus::Args
                             );
                             return;
                         } else {
@@ -60,18 +102,38 @@ impl Splitux {
                             InstanceCardFocus::Profile
                         }
                     }
-                    InstanceCardFocus::Device(0) => InstanceCardFocus::InviteDevice,
+                    InstanceCardFocus::CloneInstance => InstanceCardFocus::InviteDevice,
+                    InstanceCardFocus::MoveUp => InstanceCardFocus::CloneInstance,
+                    InstanceCardFocus::MoveDown => InstanceCardFocus::MoveUp,
+                    InstanceCardFocus::Device(0) => last_button,
                     InstanceCardFocus::Device(d) => InstanceCardFocus::Device(d - 1),
                     InstanceCardFocus::AudioOverride => {
                         let dev_count = self.instances.get(idx).map(|inst| inst.devices.len()).unwrap_or(0);
                         if dev_count > 0 {
                             InstanceCardFocus::Device(dev_count - 1)
                         } else {
-                            InstanceCardFocus::InviteDevice
+                            last_button
                         }
                     }
                     InstanceCardFocus::AudioPreference => InstanceCardFocus::AudioOverride,
                     InstanceCardFocus::GptokeybProfile => InstanceCardFocus::AudioPreference,
+                    InstanceCardFocus::GptokeybPref => InstanceCardFocus::GptokeybProfile,
+                    InstanceCardFocus::GrabCursor => {
+                        if self.gptokeyb_pref_visible(is_named) {
+                            InstanceCardFocus::GptokeybPref
+                        } else {
+                            InstanceCardFocus::GptokeybProfile
+                        }
+                    }
+                    InstanceCardFocus::MangoHud => InstanceCardFocus::GrabCursor,
+                    InstanceCardFocus::Resolution => {
+                        if self.mangohud_available {
+                            InstanceCardFocus::MangoHud
+                        } else {
+                            InstanceCardFocus::GrabCursor
+                        }
+                    }
+                    InstanceCardFocus::Args => InstanceCardFocus::Resolution,
                 };
                 self.instance_focus = InstanceFocus::InstanceCard(idx, new_element);
             }
@@ -80,6 +142,9 @@ impl Splitux {
     }
 
     pub(super) fn handle_instance_down(&mut self) {
+        if self.handle_instance_table_nav(NavDirection::Down) {
+            return;
+        }
         match &self.instance_focus {
             InstanceFocus::Devices => {
                 if self.instances.len() > 0 {
@@ -119,7 +184,10 @@ impl Splitux {
                         }
                     }
                     InstanceCardFocus::Monitor => InstanceCardFocus::InviteDevice,
-                    InstanceCardFocus::InviteDevice => {
+                    InstanceCardFocus::InviteDevice => InstanceCardFocus::CloneInstance,
+                    InstanceCardFocus::CloneInstance if self.instances.len() > 1 => InstanceCardFocus::MoveUp,
+                    InstanceCardFocus::MoveUp => InstanceCardFocus::MoveDown,
+                    InstanceCardFocus::CloneInstance | InstanceCardFocus::MoveDown => {
                         if dev_count > 0 {
                             InstanceCardFocus::Device(0)
                         } else {
@@ -136,6 +204,23 @@ impl Splitux {
                     InstanceCardFocus::AudioOverride => InstanceCardFocus::AudioPreference,
                     InstanceCardFocus::AudioPreference => InstanceCardFocus::GptokeybProfile,
                     InstanceCardFocus::GptokeybProfile => {
+                        if self.gptokeyb_pref_visible(is_named) {
+                            InstanceCardFocus::GptokeybPref
+                        } else {
+                            InstanceCardFocus::GrabCursor
+                        }
+                    }
+                    InstanceCardFocus::GptokeybPref => InstanceCardFocus::GrabCursor,
+                    InstanceCardFocus::GrabCursor => {
+                        if self.mangohud_available {
+                            InstanceCardFocus::MangoHud
+                        } else {
+                            InstanceCardFocus::Resolution
+                        }
+                    }
+                    InstanceCardFocus::MangoHud => InstanceCardFocus::Resolution,
+                    InstanceCardFocus::Resolution => InstanceCardFocus::Args,
+                    InstanceCardFocus::Args => {
                         if idx + 1 < self.instances.len() {
                             self.instance_focus = InstanceFocus::InstanceCard(
                                 idx + 1,
@@ -155,6 +240,9 @@ impl Splitux {
     }
 
     pub(super) fn handle_instance_left(&mut self) {
+        if self.handle_instance_table_nav(NavDirection::Left) {
+            return;
+        }
         match &self.instance_focus {
             InstanceFocus::LaunchOptions => {
                 let player_count = self.instances.len();
@@ -177,11 +265,14 @@ impl Splitux {
     }
 
     pub(super) fn handle_instance_right(&mut self) {
+        if self.handle_instance_table_nav(NavDirection::Right) {
+            return;
+        }
         match &self.instance_focus {
             InstanceFocus::LaunchOptions => {
                 let player_count = self.instances.len();
                 let has_carousel = player_count >= 2;
-                let max_options = if has_carousel { 2 } else { 1 };
+                let max_options = launch_options(player_count).len();
 
                 // If on carousel (index 0), cycle preset
                 if has_carousel && self.launch_option_index == 0 {
@@ -1,47 +1,99 @@
 //! Keyboard navigation handling
 
 use crate::app::app::{MenuPage, SettingsFocus, Splitux};
+use crate::config::NavAction;
 use crate::input::PadButton;
+use crate::ui::focus::pure::step_settings_option;
 use eframe::egui::{self, Key};
 
 impl Splitux {
     /// Process keyboard navigation events, returns true if events were consumed
-    pub(super) fn process_keyboard_nav(
+    ///
+    /// Keys are looked up in the configured nav bindings; consumed key events
+    /// are removed from `raw_input` so egui doesn't act on them too.
+    pub(crate) fn process_keyboard_nav(
         &mut self,
-        raw_input: &egui::RawInput,
-        on_instances_page: bool,
-        on_settings_page: bool,
+        ctx: &egui::Context,
+        raw_input: &mut egui::RawInput,
         key: &mut Option<Key>,
         page_changed: &mut bool,
     ) -> bool {
-        let mut kb_nav_consumed = false;
+        let on_instances_page = self.cur_page == MenuPage::Instances;
+        let on_settings_page = self.cur_page == MenuPage::Settings;
+        let on_games_page = self.cur_page == MenuPage::Games;
+        let mut consumed_keys: Vec<Key> = Vec::new();
 
         for event in &raw_input.events {
-            if let egui::Event::Key { key: k, pressed: true, .. } = event {
-                match k {
-                    Key::ArrowUp | Key::ArrowDown | Key::ArrowLeft | Key::ArrowRight | Key::Enter | Key::Escape => {
-                        if on_instances_page {
-                            kb_nav_consumed |= self.handle_instances_keyboard(*k);
-                        }
-                        if on_settings_page {
-                            kb_nav_consumed |= self.handle_settings_keyboard(*k, key, page_changed);
-                        }
+            let egui::Event::Key { key: k, pressed: true, .. } = event else {
+                continue;
+            };
+
+            // Waiting for a key to bind in settings
+            if let Some(action) = self.nav_rebind_action.take() {
+                if *k != Key::Escape {
+                    self.rebind_nav_key(action, *k);
+                }
+                consumed_keys.push(*k);
+                continue;
+            }
+
+            // Leave keys alone while typing into a text field
+            if ctx.wants_keyboard_input() {
+                continue;
+            }
+
+            let Some(action) = self.options.nav_bindings.action_for(*k) else {
+                continue;
+            };
+
+            let consumed = match action {
+                NavAction::OpenFolder => {
+                    let open = on_games_page && self.can_open_handler_folder(self.selected_handler);
+                    if open {
+                        self.open_handler_folder(self.selected_handler);
                     }
-                    _ => {}
+                    open
                 }
+                NavAction::NextTab | NavAction::PrevTab => {
+                    self.go_to_adjacent_page(action == NavAction::NextTab);
+                    *page_changed = true;
+                    true
+                }
+                _ if on_instances_page => self.handle_instances_keyboard(action),
+                _ if on_settings_page => self.handle_settings_keyboard(action, key, page_changed),
+                _ => false,
+            };
+            if consumed {
+                consumed_keys.push(*k);
             }
         }
-        kb_nav_consumed
+
+        if !consumed_keys.is_empty() {
+            raw_input.events.retain(|event| {
+                !matches!(event, egui::Event::Key { key: k, pressed: true, .. } if consumed_keys.contains(k))
+            });
+        }
+        !consumed_keys.is_empty()
+    }
+
+    /// Bind a key to an action, swapping with any action that already used it
+    fn rebind_nav_key(&mut self, action: NavAction, k: Key) {
+        let bindings = &mut self.options.nav_bindings;
+        if let Some(other) = bindings.action_for(k) {
+            let previous = bindings.key(action);
+            bindings.set(other, previous);
+        }
+        bindings.set(action, Some(k));
     }
 
-    fn handle_instances_keyboard(&mut self, k: Key) -> bool {
-        let kb_action = match k {
-            Key::ArrowUp => Some(PadButton::Up),
-            Key::ArrowDown => Some(PadButton::Down),
-            Key::ArrowLeft => Some(PadButton::Left),
-            Key::ArrowRight => Some(PadButton::Right),
-            Key::Enter => Some(PadButton::ABtn),
-            Key::Escape => Some(PadButton::BBtn),
+    fn handle_instances_keyboard(&mut self, action: NavAction) -> bool {
+        let kb_action = match action {
+            NavAction::Up => Some(PadButton::Up),
+            NavAction::Down => Some(PadButton::Down),
+            NavAction::Left => Some(PadButton::Left),
+            NavAction::Right => Some(PadButton::Right),
+            NavAction::Confirm => Some(PadButton::ABtn),
+            NavAction::Back => Some(PadButton::BBtn),
             _ => None,
         };
 
@@ -67,31 +119,36 @@ impl Splitux {
 
     fn handle_settings_keyboard(
         &mut self,
-        k: Key,
+        action: NavAction,
         key: &mut Option<Key>,
         page_changed: &mut bool,
     ) -> bool {
         // Profile Builder has its own navigation
         if self.is_profile_builder_active() {
-            return self.handle_profile_builder_keyboard(k);
+            return self.handle_profile_builder_keyboard(action);
         }
 
-        match k {
-            Key::ArrowUp => {
-                if self.settings_focus == SettingsFocus::Options && self.settings_option_index > 0 {
-                    self.settings_option_index -= 1;
-                    self.settings_scroll_to_focus = true;
+        match action {
+            NavAction::Up => {
+                if self.settings_focus == SettingsFocus::Options {
+                    let order = self.settings_option_order();
+                    if let Some(idx) = step_settings_option(&order, self.settings_option_index, false) {
+                        self.settings_option_index = idx;
+                        self.settings_sub_index = 0;
+                        self.settings_scroll_to_focus = true;
+                    }
                 } else if self.settings_focus == SettingsFocus::BottomButtons {
                     self.settings_focus = SettingsFocus::Options;
                     self.settings_scroll_to_focus = true;
                 }
                 true
             }
-            Key::ArrowDown => {
-                const SETTINGS_MAX_OPTIONS: usize = 19;
+            NavAction::Down => {
                 if self.settings_focus == SettingsFocus::Options {
-                    if self.settings_option_index < SETTINGS_MAX_OPTIONS {
-                        self.settings_option_index += 1;
+                    let order = self.settings_option_order();
+                    if let Some(idx) = step_settings_option(&order, self.settings_option_index, true) {
+                        self.settings_option_index = idx;
+                        self.settings_sub_index = 0;
                         self.settings_scroll_to_focus = true;
                     } else {
                         self.settings_focus = SettingsFocus::BottomButtons;
@@ -102,21 +159,22 @@ impl Splitux {
                 }
                 true
             }
-            Key::ArrowLeft | Key::ArrowRight => {
+            NavAction::Left | NavAction::Right => {
+                // Options react to the arrow keys regardless of the binding
                 if self.settings_focus == SettingsFocus::Options {
-                    *key = Some(k);
-                } else if k == Key::ArrowLeft && self.settings_button_index > 0 {
+                    *key = Some(if action == NavAction::Left { Key::ArrowLeft } else { Key::ArrowRight });
+                } else if action == NavAction::Left && self.settings_button_index > 0 {
                     self.settings_button_index -= 1;
-                } else if k == Key::ArrowRight && self.settings_button_index < 1 {
+                } else if action == NavAction::Right && self.settings_button_index < 1 {
                     self.settings_button_index += 1;
                 }
                 true
             }
-            Key::Enter => {
+            NavAction::Confirm => {
                 self.activate_focused = true;
                 true
             }
-            Key::Escape => {
+            NavAction::Back => {
                 self.cur_page = MenuPage::Games;
                 *page_changed = true;
                 true
@@ -125,32 +183,32 @@ impl Splitux {
         }
     }
 
-    fn handle_profile_builder_keyboard(&mut self, k: Key) -> bool {
+    fn handle_profile_builder_keyboard(&mut self, action: NavAction) -> bool {
         use crate::ui::focus::types::NavDirection;
 
-        match k {
-            Key::ArrowUp => {
+        match action {
+            NavAction::Up => {
                 self.handle_profile_builder_direction(NavDirection::Up);
                 true
             }
-            Key::ArrowDown => {
+            NavAction::Down => {
                 self.handle_profile_builder_direction(NavDirection::Down);
                 true
             }
-            Key::ArrowLeft => {
+            NavAction::Left => {
                 self.handle_profile_builder_direction(NavDirection::Left);
                 true
             }
-            Key::ArrowRight => {
+            NavAction::Right => {
                 self.handle_profile_builder_direction(NavDirection::Right);
                 true
             }
-            Key::Enter => {
+            NavAction::Confirm => {
                 self.handle_profile_builder_a_button();
                 self.activate_focused = true;
                 true
             }
-            Key::Escape => {
+            NavAction::Back => {
                 if !self.handle_profile_builder_b_button() {
                     // Go back to category list
                     self.settings_focus = SettingsFocus::CategoryList;
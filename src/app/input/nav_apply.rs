@@ -19,8 +19,15 @@ impl Splitux {
             // Games page state
             focus_pane: self.focus_pane,
             action_bar_index: self.action_bar_index,
+            // Play, Profile, Edit, Open folder, Test (KB/M), then Replay when offered
+            action_bar_count: if self.can_replay_last_session() { 6 } else { 5 },
             info_pane_index: self.info_pane_index,
-            selected_handler: self.selected_handler,
+            // Games are navigated in display order, not scan order
+            selected_handler: self
+                .game_list_order()
+                .iter()
+                .position(|&i| i == self.selected_handler)
+                .unwrap_or(0),
             handlers_count: self.handlers.len(),
             // Instances page state
             instance_focus: self.instance_focus.clone(),
@@ -39,7 +46,7 @@ impl Splitux {
             settings_category: self.settings_category,
             settings_option_index: self.settings_option_index,
             settings_button_index: self.settings_button_index,
-            settings_max_options: self.settings_max_option_index(),
+            settings_options: self.settings_option_order(),
             // Dropdown state
             dropdown_open: self.profile_dropdown_open,
             dropdown_selection: self.profile_dropdown_selection,
@@ -65,8 +72,8 @@ impl Splitux {
                 NavAction::SetInfoPaneIndex(idx) => {
                     self.info_pane_index = idx;
                 }
-                NavAction::SetSelectedHandler(idx) => {
-                    if idx < self.handlers.len() {
+                NavAction::SetSelectedHandler(pos) => {
+                    if let Some(&idx) = self.game_list_order().get(pos) {
                         self.selected_handler = idx;
                     }
                 }
@@ -93,6 +100,7 @@ impl Splitux {
                 }
                 NavAction::SetSettingsOptionIndex(idx) => {
                     self.settings_option_index = idx;
+                    self.settings_sub_index = 0;
                 }
                 NavAction::SetSettingsButtonIndex(idx) => {
                     self.settings_button_index = idx;
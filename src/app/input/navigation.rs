@@ -6,25 +6,86 @@
 //! - Helper methods used by the pipeline
 
 use crate::app::app::{ActiveDropdown, SettingsCategory, SettingsFocus, Splitux};
+use crate::config::NavAction;
+use crate::input::DeviceType;
+use crate::ui::focus::pure::step_settings_option;
 use eframe::egui::Key;
 
+/// Last sub-item of an expanded profile (header, controller, audio, duplicate)
+const PROFILE_PREFS_LAST: usize = 3;
+
 impl Splitux {
     // =========================================================================
     // Settings helpers (used by new pipeline via build_nav_context)
     // =========================================================================
 
-    /// Get the maximum settings option index (dynamic based on profile count)
-    /// Base options: 0-19 (General, Gamescope, Audio)
-    /// Profile section: 20 = "New Profile" button, 21+ = profile entries
-    pub fn settings_max_option_index(&self) -> usize {
-        const BASE_OPTIONS: usize = 19; // indices 0-19
-        // +1 for "New Profile" button, +N for profile entries
-        BASE_OPTIONS + 1 + self.profiles.len()
+    /// Option indices of the shown settings category, top to bottom
+    ///
+    /// These are the indices the settings pages pass to `settings_option_frame`:
+    /// General 0-12 and 22-79, Audio 13-19 and 80-81, Profiles 20 ("New
+    /// Profile") and 21+ (entries), Controllers 90-92, 100+, 200+ and 300+. Rows
+    /// that aren't shown, like the buttons of a disabled combo, are left out.
+    pub fn settings_option_order(&self) -> Vec<usize> {
+        match self.settings_category {
+            SettingsCategory::General => {
+                let mut order: Vec<usize> = (0..=5).chain(22..=28).collect();
+                if self.options.kill_combo.enabled {
+                    order.extend([29, 30]);
+                }
+                order.push(31);
+                if self.options.summon_combo.enabled {
+                    order.extend([32, 33]);
+                }
+                order.extend([34, 35, 36, 37, 38, 6, 7, 39, 40, 41, 8, 9, 10, 11, 12]);
+                order.extend((0..self.options.layout.page_tabs.len()).map(|i| 60 + i));
+                order.extend((0..NavAction::ALL.len()).map(|i| 70 + i));
+                order.push(79);
+                order
+            }
+            SettingsCategory::Audio => {
+                let mut order = vec![13, 14, 15, 80, 81];
+                if !self.audio_devices.is_empty() {
+                    order.extend(16..=19);
+                }
+                order
+            }
+            SettingsCategory::Profiles => (20..=20 + self.profiles.len()).collect(),
+            SettingsCategory::Controllers => {
+                let gamepads = self
+                    .input_devices
+                    .iter()
+                    .filter(|d| d.device_type() == DeviceType::Gamepad)
+                    .count();
+                // Named rows need an ID to store the name and mapping under
+                let named = self
+                    .input_devices
+                    .iter()
+                    .filter(|d| d.device_type() == DeviceType::Gamepad && !d.uniq().is_empty())
+                    .count();
+                let rules = self.options.device_filter.block.len() + self.options.device_filter.allow.len();
+                let mut order = vec![90, 91];
+                order.extend((0..named).map(|i| 100 + i));
+                order.extend((0..gamepads).map(|i| 200 + i));
+                order.extend((0..rules).map(|i| 300 + i));
+                order.push(92);
+                order
+            }
+            // The Profile Builder has its own navigation
+            SettingsCategory::ProfileBuilder => Vec::new(),
+        }
+    }
+
+    /// Focus the first option of the shown settings category
+    pub fn focus_first_settings_option(&mut self) {
+        self.settings_focus = SettingsFocus::Options;
+        self.settings_option_index = self.settings_option_order().first().copied().unwrap_or(0);
+        self.settings_sub_index = 0;
+        self.settings_scroll_to_focus = true;
     }
 
     /// Check if the current settings option index is in the profile section
     pub fn is_in_profile_section(&self) -> bool {
-        self.settings_option_index >= 20
+        self.settings_category == SettingsCategory::Profiles && self.settings_option_index >= 20
     }
 
     // =========================================================================
@@ -50,7 +111,7 @@ impl Splitux {
             }
             SettingsFocus::Options => {
                 // Check if we're in an expanded profile and need to navigate sub-items
-                if self.settings_option_index >= 21 {
+                if self.is_in_profile_section() && self.settings_option_index >= 21 {
                     let profile_idx = self.settings_option_index - 21;
                     if self.profile_prefs_expanded == Some(profile_idx) && self.profile_prefs_focus > 0 {
                         // Move up within expanded profile sub-items
@@ -60,18 +121,20 @@ impl Splitux {
                     }
                 }
 
-                if self.settings_option_index > 0 {
+                let order = self.settings_option_order();
+                if let Some(idx) = step_settings_option(&order, self.settings_option_index, false) {
                     // Close any open dropdowns when leaving profile
                     self.active_dropdown = None;
 
-                    self.settings_option_index -= 1;
+                    self.settings_option_index = idx;
+                    self.settings_sub_index = 0;
                     self.settings_scroll_to_focus = true;
 
                     // If entering an expanded profile from below, start at bottom sub-item
-                    if self.settings_option_index >= 21 {
+                    if self.is_in_profile_section() && self.settings_option_index >= 21 {
                         let profile_idx = self.settings_option_index - 21;
                         if self.profile_prefs_expanded == Some(profile_idx) {
-                            self.profile_prefs_focus = 2; // Start at audio (bottom)
+                            self.profile_prefs_focus = PROFILE_PREFS_LAST;
                         } else {
                             self.profile_prefs_focus = 0;
                         }
@@ -110,7 +173,6 @@ impl Splitux {
             return;
         }
 
-        let max_options = self.settings_max_option_index();
         match self.settings_focus {
             SettingsFocus::CategoryList => {
                 // Move down in category list, then to bottom buttons
@@ -124,9 +186,9 @@ impl Splitux {
             }
             SettingsFocus::Options => {
                 // Check if we're in an expanded profile and need to navigate sub-items
-                if self.settings_option_index >= 21 {
+                if self.is_in_profile_section() && self.settings_option_index >= 21 {
                     let profile_idx = self.settings_option_index - 21;
-                    if self.profile_prefs_expanded == Some(profile_idx) && self.profile_prefs_focus < 2 {
+                    if self.profile_prefs_expanded == Some(profile_idx) && self.profile_prefs_focus < PROFILE_PREFS_LAST {
                         // Move down within expanded profile sub-items
                         self.profile_prefs_focus += 1;
                         self.settings_scroll_to_focus = true;
@@ -135,8 +197,10 @@ impl Splitux {
                 }
 
                 // Move to next option
-                if self.settings_option_index < max_options {
-                    self.settings_option_index += 1;
+                let order = self.settings_option_order();
+                if let Some(idx) = step_settings_option(&order, self.settings_option_index, true) {
+                    self.settings_option_index = idx;
+                    self.settings_sub_index = 0;
                     self.settings_scroll_to_focus = true;
                     self.profile_prefs_focus = 0; // Reset to header when moving to new item
                     // Close any open dropdowns when leaving profile
@@ -160,7 +224,7 @@ impl Splitux {
             }
             SettingsFocus::Options => {
                 // Return to category list or handle option left/right
-                if self.settings_option_index == 0 {
+                if self.settings_option_order().first() == Some(&self.settings_option_index) {
                     self.settings_focus = SettingsFocus::CategoryList;
                 } else {
                     *key = Some(Key::ArrowLeft);
@@ -181,9 +245,7 @@ impl Splitux {
         match self.settings_focus {
             SettingsFocus::CategoryList => {
                 // Enter options panel
-                self.settings_focus = SettingsFocus::Options;
-                self.settings_option_index = 0;
-                self.settings_scroll_to_focus = true;
+                self.focus_first_settings_option();
             }
             SettingsFocus::Options => {
                 *key = Some(Key::ArrowRight);
@@ -195,5 +257,4 @@ impl Splitux {
             }
         }
     }
-
 }
@@ -1,9 +1,12 @@
 // Handler editing page display functions
 
 use super::app::Splitux;
-use crate::handler::{scan_handlers, SDL2Override, HANDLER_SPEC_CURRENT_VERSION};
+use crate::backend::goldberg::{LanSubnet, DEFAULT_LISTEN_PORT, MIN_LISTEN_PORT};
+use crate::backend::LobbyType;
+use crate::config::save_cfg;
+use crate::handler::{scan_handlers, HandlerTemplate, SDL2Override, StoreKind, HANDLER_SPEC_CURRENT_VERSION};
 use crate::paths::PATH_HOME;
-use crate::util::{dir_dialog, file_dialog_relative, msg};
+use crate::util::{dir_dialog, file_dialog, file_dialog_relative, msg};
 use eframe::egui::{self, RichText, Ui};
 use rfd::FileDialog;
 use std::path::PathBuf;
@@ -21,6 +24,11 @@ impl Splitux {
     }
 
     pub fn display_page_edit_handler(&mut self, ui: &mut Ui) {
+        if self.handler_edit_lint.is_none() {
+            self.handler_edit_lint = self.handler_edit.as_ref().map(|h| h.lint());
+        }
+        let mut relint = false;
+
         let h = match &mut self.handler_edit {
             Some(handler) => handler,
             None => {
@@ -38,6 +46,20 @@ impl Splitux {
         ui.label("Configure how this game should be launched for split-screen play");
         ui.add_space(8.0);
 
+        // New games can start from an engine template
+        if !h.is_saved_handler() {
+            ui.horizontal_wrapped(|ui| {
+                ui.label("New from template:");
+                for template in HandlerTemplate::ALL {
+                    if ui.button(template.label()).on_hover_text(template.description()).clicked() {
+                        h.apply_template(template);
+                        relint = true;
+                    }
+                }
+            });
+            ui.add_space(4.0);
+        }
+
         // Platform info (read-only)
         ui.horizontal(|ui| {
             ui.label("Platform:");
@@ -71,13 +93,44 @@ impl Splitux {
                 {
                     let dest = h.path_handler.join("icon.png");
                     if let Err(e) = std::fs::copy(file, dest) {
-                        eprintln!("Failed to copy icon: {}", e);
+                        log_error!("Failed to copy icon: {}", e);
                         msg("Error copying icon", &format!("{}", e));
                     }
                 }
             }
         });
 
+        ui.horizontal(|ui| {
+            ui.label("Launch notes:")
+                .on_hover_text("Shown in a dialog each time Play is pressed, for setup the game needs (e.g. \"set windowed mode in-game first\")");
+            ui.add(
+                egui::TextEdit::multiline(&mut h.launch_notes)
+                    .desired_rows(2)
+                    .desired_width(400.0)
+                    .hint_text("Optional"),
+            );
+        });
+        let notes_hidden = self
+            .options
+            .games
+            .get(h.handler_dir_name())
+            .is_some_and(|g| g.hide_launch_notes);
+        if notes_hidden && !h.launch_notes.is_empty() {
+            let mut show_again = false;
+            ui.horizontal(|ui| {
+                ui.label(RichText::new("Launch notes are hidden for this game.").weak().small());
+                show_again = ui.small_button("Show again").clicked();
+            });
+            if show_again
+                && let Some(game) = self.options.games.get_mut(h.handler_dir_name())
+            {
+                game.hide_launch_notes = false;
+                if let Err(e) = save_cfg(&self.options) {
+                    log_error!("Failed to save settings: {}", e);
+                }
+            }
+        }
+
         ui.separator();
 
         let mut selected_index = self
@@ -90,6 +143,7 @@ impl Splitux {
             })
             .unwrap_or(0);
 
+        let prev_index = selected_index;
         ui.horizontal(|ui| {
             ui.label("Steam App:");
             egui::ComboBox::from_id_salt("appid")
@@ -111,6 +165,7 @@ impl Splitux {
             // Goldberg checkbox
             let mut goldberg_enabled = h.has_goldberg();
             if ui.checkbox(&mut goldberg_enabled, "Goldberg (Steam)").changed() {
+                relint = true;
                 if goldberg_enabled {
                     h.enable_goldberg();
                 } else {
@@ -121,6 +176,7 @@ impl Splitux {
             // Photon checkbox
             let mut photon_enabled = h.has_photon();
             if ui.checkbox(&mut photon_enabled, "Photon (BepInEx)").changed() {
+                relint = true;
                 if photon_enabled {
                     h.enable_photon();
                 } else {
@@ -131,6 +187,7 @@ impl Splitux {
             // Facepunch checkbox
             let mut facepunch_enabled = h.has_facepunch();
             if ui.checkbox(&mut facepunch_enabled, "Facepunch").changed() {
+                relint = true;
                 if facepunch_enabled {
                     h.enable_facepunch();
                 } else {
@@ -141,6 +198,7 @@ impl Splitux {
             // EOS checkbox
             let mut eos_enabled = h.has_eos();
             if ui.checkbox(&mut eos_enabled, "EOS (Epic)").changed() {
+                relint = true;
                 if eos_enabled {
                     h.enable_eos();
                 } else {
@@ -149,6 +207,10 @@ impl Splitux {
             }
         });
 
+        if selected_index != prev_index {
+            relint = true;
+        }
+
         // Update platform based on dropdown selection
         match &self.installed_steamapps[selected_index] {
             Some(app) => {
@@ -170,6 +232,7 @@ impl Splitux {
                         let path_str = path.to_string_lossy().to_string();
                         h.path_gameroot = path_str.clone();
                         h.set_platform_manual(path_str);
+                        relint = true;
                     }
                 }
             });
@@ -178,13 +241,38 @@ impl Splitux {
         ui.horizontal(|ui| {
             ui.label("Executable:");
             ui.add_enabled(false, egui::TextEdit::singleline(&mut h.exec));
-            if ui.button("...").clicked() {
-                if let Ok(base_path) = h.get_game_rootpath()
-                    && let Ok(path) = file_dialog_relative(&PathBuf::from(base_path))
-                {
+            if ui.button("...").clicked()
+                && let Ok(base_path) = h.get_game_rootpath()
+            {
+                let base_path = PathBuf::from(base_path);
+                let picked = if h.external_exec {
+                    file_dialog(&base_path)
+                } else {
+                    file_dialog_relative(&base_path)
+                };
+                if let Ok(path) = picked {
                     h.exec = path.to_string_lossy().to_string();
+                    relint = true;
                 }
             }
+            ui.checkbox(&mut h.external_exec, "Outside game root")
+                .on_hover_text("Allow an executable that lives outside the game folder");
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("User mods folder:");
+            let mut display = h.user_mods_dir.clone().unwrap_or_default();
+            ui.add_enabled(false, egui::TextEdit::singleline(&mut display).hint_text("None"));
+            if ui.button("...").on_hover_text("Folder overlaid over the game files at launch").clicked()
+                && let Ok(path) = dir_dialog()
+            {
+                h.user_mods_dir = Some(path.to_string_lossy().to_string());
+                relint = true;
+            }
+            if h.user_mods_dir.is_some() && ui.button("x").on_hover_text("Clear").clicked() {
+                h.user_mods_dir = None;
+                relint = true;
+            }
         });
 
         // Photon-specific settings (shown when Photon backend is enabled)
@@ -213,6 +301,74 @@ impl Splitux {
                 ui.checkbox(&mut goldberg_settings.disable_networking, "Disable networking");
                 ui.label(RichText::new("(Forces LAN discovery mode)").small().weak());
             });
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut goldberg_settings.shared_saves, "Shared saves")
+                    .on_hover_text("All players' Goldberg saves go to one folder instead of each profile. Only for games whose progress everyone shares; players saving at the same time can overwrite each other.");
+                ui.label(RichText::new("(One save for all players)").small().weak());
+            });
+            ui.horizontal(|ui| {
+                ui.label("Lobby type:");
+                let current = goldberg_settings.lobby_type();
+                let selected_text = match (current, goldberg_settings.raw_lobby_type()) {
+                    (Some(t), _) => t.label().to_string(),
+                    (None, Some(raw)) => format!("Unrecognized ({})", raw.trim()),
+                    (None, None) => "Game default".to_string(),
+                };
+                let mut choice = None;
+                egui::ComboBox::from_id_salt("goldberg_lobby_type")
+                    .selected_text(selected_text)
+                    .show_ui(ui, |ui| {
+                        let unset = goldberg_settings.raw_lobby_type().is_none();
+                        if ui.selectable_label(unset, "Game default").clicked() {
+                            choice = Some(None);
+                        }
+                        for lobby_type in LobbyType::ALL {
+                            if ui.selectable_label(current == Some(lobby_type), lobby_type.label()).clicked() {
+                                choice = Some(Some(lobby_type));
+                            }
+                        }
+                    });
+                if let Some(lobby_type) = choice {
+                    goldberg_settings.set_lobby_type(lobby_type);
+                }
+            })
+            .response
+            .on_hover_text("Forces the lobby type via force_lobby_type.txt");
+            ui.horizontal(|ui| {
+                let mut custom_port = goldberg_settings.listen_port.is_some();
+                if ui.checkbox(&mut custom_port, "Listen port:").changed() {
+                    goldberg_settings.listen_port = custom_port.then_some(DEFAULT_LISTEN_PORT);
+                }
+                match &mut goldberg_settings.listen_port {
+                    Some(port) => {
+                        ui.add(egui::DragValue::new(port).range(MIN_LISTEN_PORT..=u16::MAX));
+                        ui.label(RichText::new("(later instances use the next ports)").small().weak());
+                    }
+                    None => {
+                        ui.label(RichText::new(format!("Default ({})", DEFAULT_LISTEN_PORT)).weak());
+                    }
+                }
+            })
+            .response
+            .on_hover_text("Port the first instance listens on, also written to listen_port.txt");
+            ui.horizontal(|ui| {
+                ui.label("LAN subnet:");
+                let mut subnet = goldberg_settings.lan_subnet.clone().unwrap_or_default();
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut subnet)
+                        .hint_text("All interfaces")
+                        .desired_width(140.0),
+                );
+                if response.changed() {
+                    let subnet = subnet.trim();
+                    goldberg_settings.lan_subnet = (!subnet.is_empty()).then(|| subnet.to_string());
+                }
+                if let Some(Err(e)) = goldberg_settings.lan_subnet.as_deref().map(LanSubnet::parse) {
+                    ui.label(RichText::new(e).small().color(egui::Color32::YELLOW));
+                }
+            })
+            .response
+            .on_hover_text("Also broadcast LAN discovery to this subnet (e.g. 192.168.1.0/24), for machines with VPNs or several network cards");
         }
 
         // Facepunch-specific settings (shown when Facepunch backend is enabled)
@@ -235,6 +391,92 @@ impl Splitux {
             ui.add(egui::TextEdit::singleline(&mut h.args));
         });
 
+        ui.horizontal(|ui| {
+            ui.label("Working directory:");
+            ui.add(
+                egui::TextEdit::singleline(&mut h.working_dir)
+                    .hint_text("Executable folder"),
+            )
+            .on_hover_text("Folder to start the game in, relative to the game root (e.g. bin)");
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Pre-launch script:");
+            ui.add(egui::TextEdit::singleline(&mut h.pre_launch).hint_text("None"))
+                .on_hover_text("Runs once before the first instance starts, relative to the handler folder. A nonzero exit cancels the launch.\nHook scripts run outside the sandbox as you; only use handlers you trust.");
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Post-exit script:");
+            ui.add(egui::TextEdit::singleline(&mut h.post_exit).hint_text("None"))
+                .on_hover_text("Runs once after the last instance exits, relative to the handler folder.\nHook scripts run outside the sandbox as you; only use handlers you trust.");
+        });
+
+        if h.win() {
+            ui.horizontal(|ui| {
+                ui.label("Proton version:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut h.proton_version)
+                        .hint_text("Global setting"),
+                )
+                .on_hover_text("Pin a Proton version for this game, e.g. GE-Proton8-32");
+            });
+
+            ui.horizontal(|ui| {
+                ui.label("Store:");
+                egui::ComboBox::from_id_salt("store")
+                    .selected_text(h.store.map_or("None", |store| store.label()))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut h.store, None, "None");
+                        for store in StoreKind::ALL {
+                            ui.selectable_value(&mut h.store, Some(store), store.label());
+                        }
+                    });
+            })
+            .response
+            .on_hover_text("Where the game was bought. umu-run uses this to apply per-store fixes.");
+
+            // DLL overrides, sorted so rows don't jump around between frames
+            let mut dlls: Vec<String> = h.wine_dll_overrides.keys().cloned().collect();
+            dlls.sort();
+            let mut remove = None;
+            ui.label("Wine DLL overrides:")
+                .on_hover_text("Merged into WINEDLLOVERRIDES with the overrides backends need.\nMode: n = native, b = builtin, n,b = native then builtin, empty = disabled");
+            egui::Grid::new("wine_dll_overrides").num_columns(3).show(ui, |ui| {
+                for dll in &dlls {
+                    ui.label(RichText::new(dll).monospace());
+                    if let Some(mode) = h.wine_dll_overrides.get_mut(dll)
+                        && ui
+                            .add(egui::TextEdit::singleline(mode).hint_text("disabled").desired_width(60.0))
+                            .lost_focus()
+                    {
+                        relint = true;
+                    }
+                    if ui.button("Remove").clicked() {
+                        remove = Some(dll.clone());
+                    }
+                    ui.end_row();
+                }
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.dll_override_buffer)
+                        .hint_text("e.g. xaudio2_9")
+                        .desired_width(140.0),
+                );
+                let name = self.dll_override_buffer.trim().to_lowercase();
+                let can_add = !name.is_empty() && !h.wine_dll_overrides.contains_key(&name);
+                if ui.add_enabled(can_add, egui::Button::new("Add")).clicked() {
+                    h.wine_dll_overrides.insert(name, "n,b".to_string());
+                    self.dll_override_buffer.clear();
+                    relint = true;
+                }
+                ui.end_row();
+            });
+            if let Some(dll) = remove {
+                h.wine_dll_overrides.remove(&dll);
+                relint = true;
+            }
+        }
+
         if !h.win() {
             ui.horizontal(|ui| {
                 ui.label("SDL2 Override:");
@@ -261,11 +503,62 @@ impl Splitux {
             });
         }
 
-        if h.spec_ver != HANDLER_SPEC_CURRENT_VERSION {
-            if ui.button("Update Handler Specification Version").clicked() {
-                h.spec_ver = HANDLER_SPEC_CURRENT_VERSION;
-                msg("Handler Specification Version Updated", "Remember to save your changes.");
-            }
+        if !h.win() {
+            ui.checkbox(&mut h.disable_steam_overlay, "Disable Steam overlay and Steam Input")
+                .on_hover_text("Strip the Steam overlay and Steam Input environment from each instance");
+        }
+
+        ui.checkbox(&mut h.no_gamescope, "Run without gamescope")
+            .on_hover_text("Launch instances directly under the window manager, which still places each window; resolution overrides and input holding don't apply");
+
+        if h.legacy_backends {
+            ui.add_space(4.0);
+            ui.label(
+                RichText::new(format!(
+                    "{} This handler used the old backend fields (backend, use_goldberg, goldberg_settings...). They were converted to the backend checkboxes above; saving writes the new format.",
+                    egui_phosphor::regular::INFO
+                ))
+                .small()
+                .weak(),
+            );
+        }
+
+        if let Some(from) = h.spec_migrated_from {
+            ui.label(
+                RichText::new(format!(
+                    "{} Upgraded from spec version {} to {} when loaded; saving writes the new format.",
+                    egui_phosphor::regular::INFO, from, HANDLER_SPEC_CURRENT_VERSION
+                ))
+                .small()
+                .weak(),
+            );
+        } else if h.spec_ver != HANDLER_SPEC_CURRENT_VERSION
+            && ui.button("Update Handler Specification Version").clicked()
+        {
+            h.spec_ver = HANDLER_SPEC_CURRENT_VERSION;
+            msg("Handler Specification Version Updated", "Remember to save your changes.");
+        }
+
+        if let Some(warnings) = &self.handler_edit_lint
+            && !warnings.is_empty()
+        {
+            ui.add_space(8.0);
+            egui::CollapsingHeader::new(
+                RichText::new(format!("{} Handler warnings ({})", egui_phosphor::regular::WARNING, warnings.len()))
+                    .color(egui::Color32::YELLOW),
+            )
+            .id_salt("handler_lint")
+            .show(ui, |ui| {
+                for w in warnings {
+                    ui.horizontal_wrapped(|ui| {
+                        ui.label(RichText::new(w.field).monospace().weak());
+                        ui.label(&w.message);
+                    });
+                }
+                if ui.button("Re-check").clicked() {
+                    relint = true;
+                }
+            });
         }
 
         ui.add_space(8.0);
@@ -280,6 +573,10 @@ impl Splitux {
             }
         });
 
+        if relint {
+            self.handler_edit_lint = None;
+        }
+
         // Handle button clicks outside closure to avoid borrow issues
         if save_clicked {
             if let Some(ref mut h) = self.handler_edit {
@@ -287,14 +584,17 @@ impl Splitux {
                     msg("Error saving handler", &format!("{}", e));
                 } else {
                     self.handlers = scan_handlers();
+                    self.handler_about = None;
                     self.show_edit_modal = false;
                     self.handler_edit = None;
+                    self.handler_edit_lint = None;
                 }
             }
         }
         if cancel_clicked {
             self.show_edit_modal = false;
             self.handler_edit = None;
+            self.handler_edit_lint = None;
         }
     }
 }
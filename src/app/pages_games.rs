@@ -3,13 +3,16 @@
 //! Submodules:
 //! - `welcome` - Welcome screen when no games configured
 //! - `game_info` - Game detail view with action bar and metadata
+//! - `about` - "About this handler" panel with resolved paths and config
 
+mod about;
 mod game_info;
 mod welcome;
 
 use super::app::Splitux;
+use crate::config::save_cfg;
 use crate::handler::HANDLER_SPEC_CURRENT_VERSION;
-use crate::util::msg;
+use crate::util::{msg, yesno};
 use eframe::egui::Ui;
 
 impl Splitux {
@@ -26,22 +29,47 @@ impl Splitux {
 
     fn check_and_start_game(&mut self) {
         let h = self.cur_handler();
-        if h.spec_ver != HANDLER_SPEC_CURRENT_VERSION {
-            let mismatch = match h.spec_ver < HANDLER_SPEC_CURRENT_VERSION {
-                true => "an older",
-                false => "a newer",
-            };
-            let mismatch2 = match h.spec_ver < HANDLER_SPEC_CURRENT_VERSION {
-                true => "Up-to-date handlers can be found by clicking the download button on the top bar of the launcher.",
-                false => "It is recommended to update Splitux to the latest version.",
-            };
+        if let Some(from) = h.spec_migrated_from {
+            msg(
+                "Handler upgraded",
+                &format!("This handler was written for spec version {} and was upgraded to version {} when it was loaded. Edit the handler and save it to keep the upgrade and stop this message appearing.",
+                    from, HANDLER_SPEC_CURRENT_VERSION
+                )
+            );
+        } else if h.spec_ver > HANDLER_SPEC_CURRENT_VERSION {
             msg(
                 "Handler version mismatch",
-                &format!("This handler was meant for use with {} version of Splitux; you may experience issues or the game may not work at all. {} If everything still works fine, you can prevent this message appearing in the future by editing the handler, updating the spec version and saving.",
-                    mismatch, mismatch2
+                &format!("This handler uses spec version {}, but this version of Splitux only knows up to version {}; you may experience issues or the game may not work at all. It is recommended to update Splitux to the latest version.",
+                    h.spec_ver, HANDLER_SPEC_CURRENT_VERSION
                 )
             );
         }
+        self.show_launch_notes();
         self.start_game_setup();
     }
+
+    /// Show the selected handler's launch notes, unless hidden for this game
+    fn show_launch_notes(&mut self) {
+        let h = self.cur_handler();
+        if h.launch_notes.is_empty() {
+            return;
+        }
+        let dir = h.handler_dir_name().to_string();
+        if self.options.games.get(&dir).is_some_and(|g| g.hide_launch_notes) {
+            return;
+        }
+        let hide = yesno(
+            &format!("{} - Launch notes", h.display()),
+            &format!(
+                "{}\n\nDon't show these notes again for this game? They stay in the handler and can be shown again from its edit page.",
+                h.launch_notes
+            ),
+        );
+        if hide && !dir.is_empty() {
+            self.options.games.entry(dir).or_default().hide_launch_notes = true;
+            if let Err(e) = save_cfg(&self.options) {
+                log_error!("Failed to save settings: {}", e);
+            }
+        }
+    }
 }
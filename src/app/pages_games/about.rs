@@ -0,0 +1,56 @@
+//! "About this handler" diagnostics - the handler's fully resolved paths and
+//! effective launch config, read-only, for debugging and support screenshots
+
+use crate::app::SplituxConfig;
+use crate::handler::Handler;
+use eframe::egui::{self, RichText, Ui};
+use egui_phosphor::regular as icons;
+
+/// Collapsible panel listing the resolved values, with a button to copy them
+///
+/// `cache` holds the values while the panel stays open on the same handler;
+/// they are resolved again when it is reopened or another handler is shown.
+pub(super) fn display_handler_about(
+    ui: &mut Ui,
+    h: &Handler,
+    cfg: &SplituxConfig,
+    cache: &mut Option<(String, Vec<(&'static str, String)>)>,
+) {
+    let response = egui::CollapsingHeader::new(RichText::new("About this handler").strong())
+        .id_salt("handler_about")
+        .show(ui, |ui| {
+            // Only resolved while open: finding the game root may scan Steam libraries
+            if cache.as_ref().is_none_or(|(key, _)| key != h.storage_key()) {
+                *cache = Some((h.storage_key().to_string(), h.resolved_summary(cfg)));
+            }
+            let Some((_, lines)) = cache.as_ref() else {
+                return;
+            };
+            egui::Grid::new("handler_about_grid")
+                .num_columns(2)
+                .spacing([12.0, 4.0])
+                .show(ui, |ui| {
+                    for (label, value) in lines {
+                        ui.label(RichText::new(*label).weak());
+                        ui.label(RichText::new(value).monospace());
+                        ui.end_row();
+                    }
+                });
+            ui.add_space(4.0);
+            if ui
+                .small_button(format!("{} Copy", icons::COPY))
+                .on_hover_text("Copy these values to paste into a bug report")
+                .clicked()
+            {
+                let text = lines
+                    .iter()
+                    .map(|(label, value)| format!("{}: {}", label, value))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                ui.ctx().copy_text(format!("{}\n{}", h.display(), text));
+            }
+        });
+    if response.body_returned.is_none() {
+        *cache = None;
+    }
+}
@@ -1,11 +1,14 @@
 //! Game info detail view - displays selected game information, action bar, and metadata
 
 use crate::app::app::{FocusPane, Splitux};
+use crate::config::NavAction;
 use crate::ui::theme;
 use crate::paths::PATH_HOME;
 use crate::ui::responsive::LayoutMode;
-use crate::util::msg;
+use crate::save_sync;
+use crate::util::{format_local_time, msg, yesno};
 use eframe::egui::{self, RichText, Ui};
+use egui_phosphor::regular as icons;
 use rfd::FileDialog;
 
 impl Splitux {
@@ -81,6 +84,9 @@ impl Splitux {
         ui.add_space(8.0);
         let mut play_clicked = false;
         let mut edit_clicked = false;
+        let mut kbm_test_clicked = false;
+        let mut folder_clicked = false;
+        let mut replay_clicked = false;
 
         // Pane-based focus for action bar
         let is_action_bar_focused = self.focus_pane == FocusPane::ActionBar;
@@ -189,6 +195,59 @@ impl Splitux {
                 edit_clicked = true;
             }
 
+            // Open the handler folder (action_bar_index = 3, also bound to a key)
+            let folder_focused = is_action_bar_focused && self.action_bar_index == 3;
+            let folder_hint = match self.options.nav_bindings.key(NavAction::OpenFolder) {
+                Some(key) => format!("Open handler folder ({})", key.name()),
+                None => "Open handler folder".to_string(),
+            };
+            let folder_btn = ui.add_enabled(
+                self.can_open_handler_folder(self.selected_handler),
+                egui::Button::new(icons::FOLDER_OPEN)
+                    .min_size(egui::vec2(36.0, 36.0))
+                    .corner_radius(8)
+                    .stroke(if folder_focused { focus_stroke } else { egui::Stroke::NONE }),
+            ).on_hover_text(folder_hint);
+            if folder_btn.clicked() || (folder_focused && activate && folder_btn.enabled()) {
+                folder_clicked = true;
+            }
+
+            // Keyboard/mouse test launch (action_bar_index = 4)
+            let kbm_focused = is_action_bar_focused && self.action_bar_index == 4;
+            let kbm_text = if is_narrow {
+                icons::KEYBOARD.to_string()
+            } else {
+                format!("{} Test (KB/M)", icons::KEYBOARD)
+            };
+            let kbm_btn = ui.add(
+                egui::Button::new(kbm_text)
+                    .min_size(egui::vec2(36.0, 36.0))
+                    .corner_radius(8)
+                    .stroke(if kbm_focused { focus_stroke } else { egui::Stroke::NONE }),
+            ).on_hover_text("Launch a single instance on your keyboard and mouse, skipping instance setup");
+            if kbm_btn.clicked() || (kbm_focused && activate) {
+                kbm_test_clicked = true;
+            }
+
+            // Replay the last session (action_bar_index = 5, shown for the game it was for)
+            if self.can_replay_last_session() {
+                let replay_focused = is_action_bar_focused && self.action_bar_index == 5;
+                let replay_text = if is_narrow {
+                    icons::ARROW_COUNTER_CLOCKWISE.to_string()
+                } else {
+                    format!("{} Replay last session", icons::ARROW_COUNTER_CLOCKWISE)
+                };
+                let replay_btn = ui.add(
+                    egui::Button::new(replay_text)
+                        .min_size(egui::vec2(36.0, 36.0))
+                        .corner_radius(8)
+                        .stroke(if replay_focused { focus_stroke } else { egui::Stroke::NONE }),
+                ).on_hover_text("Launch again with the same players, devices, profiles and monitors as last time");
+                if replay_btn.clicked() || (replay_focused && activate) {
+                    replay_clicked = true;
+                }
+            }
+
             // Platform indicator and metadata (hide in narrow mode)
             if !is_narrow {
                 ui.add(egui::Separator::default().vertical());
@@ -237,6 +296,15 @@ impl Splitux {
         if play_clicked {
             self.check_and_start_game();
         }
+        if kbm_test_clicked {
+            self.start_kbm_test_launch();
+        }
+        if folder_clicked {
+            self.open_handler_folder(self.selected_handler);
+        }
+        if replay_clicked {
+            self.replay_last_session();
+        }
         if edit_clicked {
             self.handler_edit = Some(self.handlers[self.selected_handler].clone());
             self.show_edit_modal = true;
@@ -246,11 +314,18 @@ impl Splitux {
         let required_mods = self.handlers[self.selected_handler].required_mods.clone();
         let handler_path = self.handlers[self.selected_handler].path_handler.clone();
 
+        // Save backups section (only shown if handler has original_save_path)
+        let save_handler = Some(&self.handlers[self.selected_handler])
+            .filter(|h| !h.original_save_paths.is_empty())
+            .cloned();
+        let save_backup_keep = self.options.save_backup_keep;
+
         // Track interactive elements for d-pad navigation
         let is_info_pane_focused = self.focus_pane == FocusPane::InfoPane;
         let activate = self.activate_focused && is_info_pane_focused;
         let mut info_element_idx = 0usize;
         let mut total_info_elements = 0usize;
+        let mut fetch_request: Option<crate::handler::RequiredMod> = None;
 
         // Count total interactive elements first
         for rm in &required_mods {
@@ -258,6 +333,9 @@ impl Splitux {
             if !rm.url.is_empty() {
                 total_info_elements += 1; // Download link
             }
+            if !rm.sources().is_empty() {
+                total_info_elements += 1; // Fetch button
+            }
         }
 
         // Clamp info_pane_index
@@ -360,6 +438,22 @@ impl Splitux {
                             }
                             info_element_idx += 1;
                         }
+
+                        // Automatic download, falling back to mirrors
+                        if !required_mod.sources().is_empty() {
+                            let fetch_focused = is_info_pane_focused && self.info_pane_index == info_element_idx;
+                            let fetch_btn = ui
+                                .add(
+                                    egui::Button::new(format!("{} Fetch", icons::DOWNLOAD_SIMPLE))
+                                        .small()
+                                        .stroke(if fetch_focused { focus_stroke } else { egui::Stroke::NONE }),
+                                )
+                                .on_hover_text("Download the file into place, trying each mirror if the main link fails");
+                            if fetch_btn.clicked() || (fetch_focused && activate) {
+                                fetch_request = Some(required_mod.clone());
+                            }
+                            info_element_idx += 1;
+                        }
                     });
 
                     // Description
@@ -369,6 +463,44 @@ impl Splitux {
                 }
             }
 
+            if let Some(h) = &save_handler {
+                ui.add_space(8.0);
+                ui.separator();
+                egui::CollapsingHeader::new(RichText::new("Save Backups").strong())
+                    .id_salt("save_backups")
+                    .show(ui, |ui| {
+                        let backups = save_sync::list_save_backups(h);
+                        if backups.is_empty() {
+                            ui.label(RichText::new("No backups yet. Saves are backed up before each sync.").weak().small());
+                        }
+                        for backup in &backups {
+                            ui.horizontal(|ui| {
+                                ui.label(format_local_time(backup.timestamp));
+                                if ui.small_button("Restore").clicked()
+                                    && yesno(
+                                        "Restore Save Backup?",
+                                        "This replaces the game's current saves with this backup. The current saves are backed up first.",
+                                    )
+                                {
+                                    match save_sync::restore_save_backup(h, backup, save_backup_keep) {
+                                        Ok(()) => msg("Save Restored", "The backup was restored."),
+                                        Err(e) => msg("Error", &format!("Failed to restore backup: {}", e)),
+                                    }
+                                }
+                            });
+                        }
+                    });
+            }
+
+            ui.add_space(8.0);
+            ui.separator();
+            super::about::display_handler_about(
+                ui,
+                &self.handlers[self.selected_handler],
+                &self.options,
+                &mut self.handler_about,
+            );
+
             // Game images (responsive height)
             if !img_paths.is_empty() {
                 ui.add_space(8.0);
@@ -446,5 +578,21 @@ impl Splitux {
 
         // Sync scroll offset back from the scroll area state
         self.info_pane_scroll = scroll_output.state.offset.y;
+
+        if let Some(required_mod) = fetch_request {
+            let offline = self.options.offline_mode;
+            self.spawn_task(&format!("Downloading {}...", required_mod.name), move || {
+                match required_mod.download(&handler_path, offline) {
+                    Ok(source) => msg(
+                        "Download complete",
+                        &format!("{} installed from:\n{}", required_mod.name, source),
+                    ),
+                    Err(e) => msg(
+                        "Download failed",
+                        &format!("Couldn't download {}:\n\n{}", required_mod.name, e),
+                    ),
+                }
+            });
+        }
     }
 }
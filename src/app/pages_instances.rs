@@ -3,18 +3,26 @@
 //! This module is split into submodules:
 //! - `audio` - Audio conflict detection and effective audio resolution
 //! - `cards` - Main instance card rendering and the `display_page_instances()` entry point
+//! - `dropdowns` - Dropdown builders and action handlers shared by cards and table
 //! - `focus` - Focus helper functions for instance card elements
 //! - `help_bar` - Controls help bar UI
 //! - `launch_options` - Bottom bar with start button and launch options
+//! - `layout_preview` - To-scale preview of the monitors and instance regions
 //! - `profile_changes` - Profile selection change handling and auto-assignment
+//! - `table` - Compact table view (one row per player)
 //! - `types` - Dropdown action enum types
 //! - `warnings` - Instance warning display
 
 mod audio;
 mod cards;
+mod dropdowns;
 mod focus;
 mod help_bar;
 mod launch_options;
+mod layout_preview;
 mod profile_changes;
+mod table;
 mod types;
 mod warnings;
+
+pub(crate) use cards::PLAYER_COLORS;
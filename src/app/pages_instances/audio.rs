@@ -1,6 +1,7 @@
 //! Audio helper functions for instance page
 
 use crate::app::app::Splitux;
+use crate::audio::{find_matching_sink, AudioSink};
 use std::collections::{HashMap, HashSet};
 
 impl Splitux {
@@ -9,17 +10,12 @@ impl Splitux {
         let mut sink_usage: HashMap<String, Vec<usize>> = HashMap::new();
 
         for (i, _instance) in self.instances.iter().enumerate() {
-            // Check session override first
-            if let Some(override_opt) = self.audio_session_overrides.get(&i) {
-                if let Some(sink) = override_opt {
-                    sink_usage.entry(sink.clone()).or_default().push(i);
-                }
-                continue; // Session override takes precedence
-            }
-
-            // Check profile_audio_prefs (populated from ProfilePreferences)
-            if let Some(sink) = self.profile_audio_prefs.get(&i) {
-                sink_usage.entry(sink.clone()).or_default().push(i);
+            // Session override takes precedence, then profile preference
+            // (resolved so renumbered sinks are compared by their current name)
+            if let Some((sink, _, _)) = self.get_effective_audio(i)
+                && !sink.is_empty()
+            {
+                sink_usage.entry(sink).or_default().push(i);
             }
         }
 
@@ -35,20 +31,30 @@ impl Splitux {
         conflicts
     }
 
-    /// Get the effective audio sink for an instance (session override or profile preference)
+    /// Resolve a stored sink name against the currently available sinks
+    ///
+    /// Re-binds sinks that were renumbered (e.g. after a reboot) by matching
+    /// their description or stable name. Returns None if the device is truly absent.
+    pub(crate) fn resolve_audio_sink(
+        &self,
+        sink: &str,
+        description: Option<&str>,
+    ) -> Option<&AudioSink> {
+        find_matching_sink(&self.audio_devices, sink, description)
+    }
+
+    /// Get the effective audio sink for an instance
+    ///
+    /// Session override, then profile preference, then the guest default from
+    /// the audio settings; None means the system default.
     /// Returns: (sink_name, display_name, is_override)
-    pub(super) fn get_effective_audio(&self, instance_idx: usize) -> Option<(String, String, bool)> {
+    pub(crate) fn get_effective_audio(&self, instance_idx: usize) -> Option<(String, String, bool)> {
         // Check session override first
         if let Some(override_opt) = self.audio_session_overrides.get(&instance_idx) {
             return match override_opt {
                 Some(sink) => {
-                    let name = self
-                        .audio_devices
-                        .iter()
-                        .find(|d| &d.name == sink)
-                        .map(|d| d.description.clone())
-                        .unwrap_or_else(|| sink.clone());
-                    Some((sink.clone(), name, true))
+                    let (name, desc) = self.resolve_effective_sink(sink);
+                    Some((name, desc, true))
                 }
                 None => Some(("".to_string(), "Muted".to_string(), true)), // Explicit mute
             };
@@ -56,15 +62,30 @@ impl Splitux {
 
         // Check profile preference
         if let Some(sink) = self.profile_audio_prefs.get(&instance_idx) {
-            let name = self
-                .audio_devices
-                .iter()
-                .find(|d| &d.name == sink)
-                .map(|d| d.description.clone())
-                .unwrap_or_else(|| sink.clone());
-            return Some((sink.clone(), name, false));
+            let (name, desc) = self.resolve_effective_sink(sink);
+            return Some((name, desc, false));
+        }
+
+        // Guest default for everyone without a preference
+        if let Some(sink) = &self.options.audio.guest_sink {
+            let description = self.options.audio.guest_sink_description.as_deref();
+            let (name, desc) = self.resolve_described_sink(sink, description);
+            return Some((name, format!("{} (guest default)", desc), false));
         }
 
         None
     }
+
+    /// Resolve a sink to (current_name, display_name), keeping the stored name if absent
+    fn resolve_effective_sink(&self, sink: &str) -> (String, String) {
+        self.resolve_described_sink(sink, None)
+    }
+
+    /// resolve_effective_sink, also matching by a stored description
+    fn resolve_described_sink(&self, sink: &str, description: Option<&str>) -> (String, String) {
+        match self.resolve_audio_sink(sink, description) {
+            Some(device) => (device.name.clone(), device.description.clone()),
+            None => (sink.to_string(), sink.to_string()),
+        }
+    }
 }
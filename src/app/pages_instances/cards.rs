@@ -1,62 +1,63 @@
 //! Main instance cards display - the core of the instance setup page
 
+use super::dropdowns::{
+    apply_audio_override_action, apply_gptokeyb_action, args_override_button, audio_override_items, grab_cursor_button,
+    gptokeyb_items, gptokeyb_label, mangohud_button, monitor_dropdown, resolution_override_button,
+};
 use super::focus::{element_focus_stroke, is_element_focused};
-use super::types::{AudioOverrideAction, AudioPrefAction, GptokeybAction};
+use super::types::{AudioPrefAction, DropdownState};
 use crate::app::app::{ActiveDropdown, InstanceFocus, Splitux};
 use crate::config::save_cfg;
 use crate::ui::theme;
-use crate::gptokeyb::{list_builtin_profiles, list_user_profiles};
 use crate::profile_prefs::ProfilePreferences;
 use crate::ui::components::dropdown::{render_gamepad_dropdown, DropdownItem};
 use crate::ui::focus::types::InstanceCardFocus;
 use crate::ui::responsive::{combo_width, LayoutMode};
 use eframe::egui::{self, RichText, Ui};
+use std::collections::HashSet;
 use egui_phosphor::fill as icons_fill;
 use egui_phosphor::regular as icons;
 
 /// Player colors for visual distinction
-const PLAYER_COLORS: [egui::Color32; 4] = [
+pub(crate) const PLAYER_COLORS: [egui::Color32; 4] = [
     egui::Color32::from_rgb(80, 180, 255),  // P1: Blue
     egui::Color32::from_rgb(255, 100, 100), // P2: Red
     egui::Color32::from_rgb(100, 220, 100), // P3: Green
     egui::Color32::from_rgb(255, 200, 80),  // P4: Yellow
 ];
 
-/// Render a monitor dropdown and return the selected index (if any) and whether it was toggled.
-fn monitor_dropdown(
-    ui: &mut Ui,
-    id_salt: &str,
-    monitors: &[crate::monitor::Monitor],
-    current_idx: usize,
-    width: f32,
-    is_focused: bool,
-    is_open: bool,
-    selection_idx: usize,
-    activate: bool,
-) -> (Option<usize>, bool) {
-    let items: Vec<DropdownItem<usize>> = monitors
-        .iter()
-        .enumerate()
-        .map(|(idx, mon)| DropdownItem::new(idx, &mon.display_name(), idx == current_idx))
-        .collect();
-
-    let current_name = monitors
-        .get(current_idx)
-        .map(|m| m.display_name())
-        .unwrap_or_else(|| "Select".to_string());
-
-    let resp = render_gamepad_dropdown(
-        ui, id_salt, &current_name, width, &items,
-        is_focused, is_open, selection_idx, activate,
-    );
-
-    (resp.selected, resp.toggled || (is_focused && activate))
-}
-
 impl Splitux {
     pub fn display_page_instances(&mut self, ui: &mut Ui) {
         ui.add_space(8.0);
-        ui.heading("Instance Setup");
+        ui.horizontal(|ui| {
+            ui.heading("Instance Setup");
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                let table_view = self.options.layout.instance_table_view;
+                let (icon, tip) = if table_view {
+                    (icons::CARDS, "Switch to card view")
+                } else {
+                    (icons::TABLE, "Switch to compact table view")
+                };
+                let tip = format!("{tip} (Select)");
+                if ui.button(icon).on_hover_text(tip).clicked() {
+                    self.toggle_instance_table_view();
+                }
+                if ui
+                    .selectable_label(self.claim_mode, format!("{} Claim mode", icons::HAND_POINTING))
+                    .on_hover_text("Pressing A / Z / Right-click on any unassigned device adds it as the next player, whatever is focused (LT)")
+                    .clicked()
+                {
+                    self.claim_mode = !self.claim_mode;
+                }
+                if ui
+                    .selectable_label(self.layout_preview, format!("{} Preview layout", icons::SQUARES_FOUR))
+                    .on_hover_text("Show where each instance will be placed on your monitors (RT / R)")
+                    .clicked()
+                {
+                    self.layout_preview = !self.layout_preview;
+                }
+            });
+        });
         ui.add_space(4.0);
         ui.label("Connect your controllers and assign them to player instances");
         ui.add_space(8.0);
@@ -64,6 +65,9 @@ impl Splitux {
 
         self.display_instance_help_bar(ui);
         self.display_instance_warnings(ui);
+        if self.layout_preview {
+            self.display_layout_preview(ui);
+        }
 
         // Ensure prev_profile_selections matches instances count
         while self.prev_profile_selections.len() < self.instances.len() {
@@ -76,6 +80,7 @@ impl Splitux {
 
         // Pre-compute state before mutable iteration
         let audio_conflicts = self.detect_audio_conflicts();
+        let profile_conflicts = self.detect_profile_conflicts();
         let effective_audio: Vec<Option<(String, String, bool)>> = (0..self.instances.len())
             .map(|i| self.get_effective_audio(i))
             .collect();
@@ -84,12 +89,54 @@ impl Splitux {
             ui.add_space(16.0);
             ui.label(RichText::new("No instances yet").italics());
             ui.add_space(4.0);
-            ui.label("Press A or Right-click on a controller to create a player instance");
+            ui.label(if self.claim_mode {
+                "Claim mode: press A on any controller to join as the next player"
+            } else {
+                "Press A or Right-click on a controller to create a player instance"
+            });
+        }
+
+        if self.options.layout.instance_table_view {
+            self.display_instance_table(
+                ui, &audio_conflicts, &profile_conflicts, &effective_audio,
+                &mut devices_to_remove, &mut profile_changes,
+            );
+        } else {
+            self.display_instance_cards(
+                ui, &audio_conflicts, &profile_conflicts, &effective_audio,
+                &mut devices_to_remove, &mut profile_changes,
+            );
         }
 
+        // Post-processing
+        for (i, d) in devices_to_remove {
+            self.remove_device_instance(i, d);
+        }
+        self.handle_profile_changes(profile_changes);
+        self.display_launch_options(ui);
+    }
+
+    /// Render one card per instance (default view)
+    fn display_instance_cards(
+        &mut self,
+        ui: &mut Ui,
+        audio_conflicts: &HashSet<usize>,
+        profile_conflicts: &HashSet<usize>,
+        effective_audio: &[Option<(String, String, bool)>],
+        devices_to_remove: &mut Vec<(usize, usize)>,
+        profile_changes: &mut Vec<(usize, usize)>,
+    ) {
         let current_focus = self.instance_focus.clone();
         let activate_focused = self.activate_focused;
         let display_names = self.device_display_names.clone();
+        let bundled_gptokeyb = self.bundled_gptokeyb_profiles();
+        let gptokeyb_pref_key = self
+            .active_handler()
+            .map(|h| h.handler_dir_name().to_string())
+            .filter(|k| !k.is_empty());
+        let mut clone_request: Option<usize> = None;
+        let mut move_request: Option<(usize, usize, InstanceCardFocus)> = None;
+        let instance_count = self.instances.len();
 
         // ── Render instance cards ──────────────────────────────────────────
         for (i, instance) in &mut self.instances.iter_mut().enumerate() {
@@ -153,6 +200,11 @@ impl Splitux {
                             }
                         }
 
+                        if profile_conflicts.contains(&i) {
+                            ui.label(RichText::new(icons::WARNING).size(14.0).color(egui::Color32::YELLOW))
+                                .on_hover_text("Profile conflict: another player uses this profile");
+                        }
+
                         if instance.profselection != self.prev_profile_selections.get(i).copied().unwrap_or(usize::MAX) {
                             profile_changes.push((i, instance.profselection));
                         }
@@ -190,9 +242,13 @@ impl Splitux {
                                 let monitor_open = self.active_dropdown == Some(ActiveDropdown::InstanceMonitor(i));
 
                                 let (selected, toggled) = monitor_dropdown(
-                                    ui, &format!("monitor_{i}"), &self.monitors,
-                                    instance.monitor, monitor_width, monitor_focused, monitor_open,
-                                    self.dropdown_selection_idx, monitor_focused && activate_focused,
+                                    ui, &format!("monitor_{i}"), &self.monitors, instance.monitor, monitor_width,
+                                    DropdownState {
+                                        focused: monitor_focused,
+                                        open: monitor_open,
+                                        selection_idx: self.dropdown_selection_idx,
+                                        activate: monitor_focused && activate_focused,
+                                    },
                                 );
 
                                 if let Some(new_idx) = selected {
@@ -228,6 +284,20 @@ impl Splitux {
                                     self.instance_add_dev = None;
                                 }
                             }
+
+                            let clone_focused = is_element_focused(&current_focus, i, InstanceCardFocus::CloneInstance);
+                            let clone_btn = egui::Button::new(format!("{} Clone", icons::COPY))
+                                .min_size(egui::vec2(0.0, 26.0))
+                                .stroke(element_focus_stroke(&current_focus, i, InstanceCardFocus::CloneInstance));
+                            if ui.add(clone_btn)
+                                .on_hover_text("Add a new player with these settings (without devices)")
+                                .clicked() || (clone_focused && activate_focused)
+                            {
+                                clone_request = Some(i);
+                            }
+                            if let Some(target) = reorder_buttons(ui, &current_focus, i, instance_count, activate_focused) {
+                                move_request = Some(target);
+                            }
                         }
                     });
 
@@ -240,9 +310,13 @@ impl Splitux {
                                 let monitor_open = self.active_dropdown == Some(ActiveDropdown::InstanceMonitor(i));
 
                                 let (selected, toggled) = monitor_dropdown(
-                                    ui, &format!("monitor_narrow_{i}"), &self.monitors,
-                                    instance.monitor, monitor_width, monitor_focused, monitor_open,
-                                    self.dropdown_selection_idx, monitor_focused && activate_focused,
+                                    ui, &format!("monitor_narrow_{i}"), &self.monitors, instance.monitor, monitor_width,
+                                    DropdownState {
+                                        focused: monitor_focused,
+                                        open: monitor_open,
+                                        selection_idx: self.dropdown_selection_idx,
+                                        activate: monitor_focused && activate_focused,
+                                    },
                                 );
 
                                 if let Some(new_idx) = selected {
@@ -273,6 +347,17 @@ impl Splitux {
                                         self.instance_add_dev = None;
                                     }
                                 }
+
+                                let clone_focused = is_element_focused(&current_focus, i, InstanceCardFocus::CloneInstance);
+                                let clone_btn = egui::Button::new(icons::COPY)
+                                    .min_size(egui::vec2(26.0, 26.0))
+                                    .stroke(element_focus_stroke(&current_focus, i, InstanceCardFocus::CloneInstance));
+                                if ui.add(clone_btn).on_hover_text("Clone instance").clicked() || (clone_focused && activate_focused) {
+                                    clone_request = Some(i);
+                                }
+                                if let Some(target) = reorder_buttons(ui, &current_focus, i, instance_count, activate_focused) {
+                                    move_request = Some(target);
+                                }
                             });
                         });
                     }
@@ -330,7 +415,7 @@ impl Splitux {
                                                 let mut prefs = ProfilePreferences::load(prof_name);
                                                 prefs.set_controller(dev_uniq, self.input_devices[dev].fancyname());
                                                 if let Err(e) = prefs.save(prof_name) {
-                                                    eprintln!("[splitux] Failed to save profile preferences: {}", e);
+                                                    log_error!("Failed to save profile preferences: {}", e);
                                                 }
                                             }
                                         }
@@ -385,17 +470,7 @@ impl Splitux {
                             let audio_override_open = self.active_dropdown == Some(ActiveDropdown::InstanceAudioOverride(i));
                             let has_override = self.audio_session_overrides.contains_key(&i);
 
-                            let is_muted = effective.as_ref().map_or(false, |(s, _, _)| s.is_empty());
-                            let mut items: Vec<DropdownItem<AudioOverrideAction>> = self.audio_devices.iter()
-                                .map(|sink| {
-                                    let is_current = effective.as_ref().map_or(false, |(s, _, _)| s == &sink.name);
-                                    DropdownItem::new(AudioOverrideAction::SetDevice(sink.name.clone()), &sink.description, is_current)
-                                })
-                                .collect();
-                            items.push(DropdownItem::new(AudioOverrideAction::Mute, &format!("{} None (mute)", icons::SPEAKER_SLASH), is_muted));
-                            if has_override {
-                                items.push(DropdownItem::new(AudioOverrideAction::Reset, "↩ Reset to profile", false));
-                            }
+                            let items = audio_override_items(&self.audio_devices, &effective, has_override);
 
                             let button_text = if card_mode.is_narrow() {
                                 ""
@@ -412,11 +487,7 @@ impl Splitux {
                             );
 
                             if let Some(action) = audio_response.selected {
-                                match action {
-                                    AudioOverrideAction::SetDevice(name) => { self.audio_session_overrides.insert(i, Some(name)); }
-                                    AudioOverrideAction::Mute => { self.audio_session_overrides.insert(i, None); }
-                                    AudioOverrideAction::Reset => { self.audio_session_overrides.remove(&i); }
-                                }
+                                apply_audio_override_action(&mut self.audio_session_overrides, i, action);
                                 self.active_dropdown = None;
                             } else if audio_response.toggled || (audio_override_focused && activate_focused) {
                                 if audio_override_open {
@@ -461,7 +532,7 @@ impl Splitux {
                                                 let mut new_prefs = ProfilePreferences::load(&prof_name_owned);
                                                 new_prefs.set_audio(&name, &desc);
                                                 if let Err(e) = new_prefs.save(&prof_name_owned) {
-                                                    eprintln!("[splitux] Failed to save audio preference: {}", e);
+                                                    log_error!("Failed to save audio preference: {}", e);
                                                 } else {
                                                     self.profile_audio_prefs.insert(i, name);
                                                     self.audio_session_overrides.remove(&i);
@@ -471,7 +542,7 @@ impl Splitux {
                                                 let mut new_prefs = ProfilePreferences::load(&prof_name_owned);
                                                 new_prefs.clear_audio();
                                                 if let Err(e) = new_prefs.save(&prof_name_owned) {
-                                                    eprintln!("[splitux] Failed to clear audio preference: {}", e);
+                                                    log_error!("Failed to clear audio preference: {}", e);
                                                 } else {
                                                     self.profile_audio_prefs.remove(&i);
                                                 }
@@ -505,31 +576,12 @@ impl Splitux {
                         let has_override = self.gptokeyb_instance_overrides.contains_key(&i);
                         let current_gptokeyb = self.gptokeyb_instance_overrides.get(&i);
 
-                        let mut items: Vec<DropdownItem<GptokeybAction>> = Vec::new();
-                        items.push(DropdownItem::new(GptokeybAction::Default, "Default (handler)", !has_override));
-                        items.push(DropdownItem::new(GptokeybAction::Disabled, format!("{} Disabled", icons::PROHIBIT), current_gptokeyb == Some(&String::new())));
-
-                        for profile in list_builtin_profiles() {
-                            items.push(DropdownItem::new(
-                                GptokeybAction::Profile(profile.to_string()),
-                                format!("{} {} (built-in)", icons::GAME_CONTROLLER, profile),
-                                current_gptokeyb == Some(&profile.to_string()),
-                            ));
-                        }
-                        for profile in list_user_profiles() {
-                            items.push(DropdownItem::new(
-                                GptokeybAction::Profile(profile.clone()),
-                                format!("{} {} (custom)", icons::USER, profile),
-                                current_gptokeyb == Some(&profile),
-                            ));
-                        }
+                        let items = gptokeyb_items(current_gptokeyb, &bundled_gptokeyb);
 
                         let button_text = if card_mode.is_narrow() {
                             ""
                         } else if has_override {
-                            current_gptokeyb
-                                .map(|p| if p.is_empty() { "Disabled" } else { p.as_str() })
-                                .unwrap_or("Default")
+                            gptokeyb_label(current_gptokeyb)
                         } else {
                             "Default"
                         };
@@ -543,11 +595,7 @@ impl Splitux {
                         );
 
                         if let Some(action) = gptokeyb_response.selected {
-                            match action {
-                                GptokeybAction::Default => { self.gptokeyb_instance_overrides.remove(&i); }
-                                GptokeybAction::Disabled => { self.gptokeyb_instance_overrides.insert(i, String::new()); }
-                                GptokeybAction::Profile(name) => { self.gptokeyb_instance_overrides.insert(i, name); }
-                            }
+                            apply_gptokeyb_action(&mut self.gptokeyb_instance_overrides, i, action);
                             self.active_dropdown = None;
                         } else if gptokeyb_response.toggled || (gptokeyb_focused && activate_focused) {
                             if gptokeyb_open {
@@ -557,17 +605,114 @@ impl Splitux {
                                 self.dropdown_selection_idx = 0;
                             }
                         }
+
+                        // Remember this player's KB/mouse profile for the current game
+                        if is_named_profile
+                            && let (Some(prof_name), Some(key)) = (&profile_name, &gptokeyb_pref_key)
+                        {
+                            let mut prefs = ProfilePreferences::load(prof_name);
+                            let current = self.gptokeyb_instance_overrides.get(&i);
+                            let stored = prefs.gptokeyb_for(key);
+                            let is_preferred = stored.is_some() && stored == current;
+                            let (star, tip) = if is_preferred {
+                                (icons_fill::STAR, format!("{}'s preferred KB/mouse profile for this game\nClick to forget", prof_name))
+                            } else if current.is_some() {
+                                (icons::STAR, format!("Always use this KB/mouse profile for {} in this game", prof_name))
+                            } else {
+                                (icons::STAR, format!("Pick a KB/mouse profile to remember it for {}", prof_name))
+                            };
+
+                            let pref_focused = is_element_focused(&current_focus, i, InstanceCardFocus::GptokeybPref);
+                            let star_btn = ui.add_enabled(
+                                is_preferred || current.is_some(),
+                                egui::Button::new(star)
+                                    .small()
+                                    .stroke(element_focus_stroke(&current_focus, i, InstanceCardFocus::GptokeybPref)),
+                            );
+                            if (star_btn.on_hover_text(&tip).on_disabled_hover_text(&tip).clicked()
+                                || (pref_focused && activate_focused))
+                                && (is_preferred || current.is_some())
+                            {
+                                match current {
+                                    Some(profile) if !is_preferred => prefs.set_gptokeyb(key, profile),
+                                    _ => prefs.clear_gptokeyb(key),
+                                }
+                                if let Err(e) = prefs.save(prof_name) {
+                                    log_error!("Failed to save KB/mouse preference: {}", e);
+                                }
+                            }
+                        }
+
+                        // Cursor confinement for this player's gamescope window
+                        let focus = |element| {
+                            (
+                                element_focus_stroke(&current_focus, i, element),
+                                is_element_focused(&current_focus, i, element) && activate_focused,
+                            )
+                        };
+                        let (stroke, activate) = focus(InstanceCardFocus::GrabCursor);
+                        grab_cursor_button(
+                            ui, &mut instance.grab_cursor,
+                            self.options.gamescope_force_grab_cursor, card_mode.is_narrow(), stroke, activate,
+                        );
+                        let (stroke, activate) = focus(InstanceCardFocus::MangoHud);
+                        mangohud_button(
+                            ui, &mut instance.mangohud, self.mangohud_available, card_mode.is_narrow(), stroke, activate,
+                        );
+                        let scale = self.monitors.get(instance.monitor).map_or(1.0, |m| m.scale());
+                        let (stroke, activate) = focus(InstanceCardFocus::Resolution);
+                        resolution_override_button(
+                            ui, &mut instance.resolution_override,
+                            self.options.gamescope_fix_lowres, scale, card_mode.is_narrow(), stroke, activate,
+                        );
+                        let (stroke, activate) = focus(InstanceCardFocus::Args);
+                        args_override_button(ui, &mut instance.args_override, card_mode.is_narrow(), stroke, activate);
                     });
                 });
             ui.add_space(4.0);
         }
 
-        // Post-processing
-        for (i, d) in devices_to_remove {
-            self.remove_device_instance(i, d);
+        if let Some(i) = clone_request
+            && let Some(new_idx) = self.clone_instance(i)
+        {
+            self.instance_focus = InstanceFocus::InstanceCard(new_idx, InstanceCardFocus::InviteDevice);
+        }
+        if let Some((from, to, element)) = move_request {
+            self.swap_instances(from, to);
+            // Keep focus on the moved card so repeated presses keep moving it
+            self.instance_focus = InstanceFocus::InstanceCard(to, element);
         }
-        self.handle_profile_changes(profile_changes);
-        self.display_launch_options(ui);
     }
+}
 
+/// Up/down buttons that swap a card with its neighbour (shown for 2+ players)
+///
+/// Returns the instance to swap with and the button that was used.
+fn reorder_buttons(
+    ui: &mut Ui,
+    focus: &InstanceFocus,
+    i: usize,
+    count: usize,
+    activate_focused: bool,
+) -> Option<(usize, usize, InstanceCardFocus)> {
+    if count < 2 {
+        return None;
+    }
+    let mut request = None;
+    for (element, icon, tip, target) in [
+        (InstanceCardFocus::MoveUp, icons::ARROW_UP, "Move up (swap with the previous player)", i.checked_sub(1)),
+        (InstanceCardFocus::MoveDown, icons::ARROW_DOWN, "Move down (swap with the next player)", Some(i + 1).filter(|&j| j < count)),
+    ] {
+        let focused = is_element_focused(focus, i, element);
+        let btn = egui::Button::new(icon)
+            .min_size(egui::vec2(26.0, 26.0))
+            .stroke(element_focus_stroke(focus, i, element));
+        let clicked = ui.add_enabled(target.is_some(), btn).on_hover_text(tip).clicked();
+        if let Some(j) = target
+            && (clicked || (focused && activate_focused))
+        {
+            request = Some((i, j, element));
+        }
+    }
+    request
 }
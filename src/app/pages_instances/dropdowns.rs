@@ -0,0 +1,291 @@
+//! Dropdown builders and action handlers shared by the card and table views
+
+use super::types::{AudioOverrideAction, DropdownState, GptokeybAction};
+use crate::audio::AudioSink;
+use crate::gptokeyb::{list_builtin_profiles, list_user_profiles};
+use crate::ui::components::dropdown::{render_gamepad_dropdown, DropdownItem};
+use eframe::egui::{self, containers::menu::MenuButton, Ui};
+use egui_phosphor::regular as icons;
+use std::collections::HashMap;
+
+/// Render a monitor dropdown and return the selected index (if any) and whether it was toggled.
+pub(super) fn monitor_dropdown(
+    ui: &mut Ui,
+    id_salt: &str,
+    monitors: &[crate::monitor::Monitor],
+    current_idx: usize,
+    width: f32,
+    state: DropdownState,
+) -> (Option<usize>, bool) {
+    let items: Vec<DropdownItem<usize>> = monitors
+        .iter()
+        .enumerate()
+        .map(|(idx, mon)| DropdownItem::new(idx, mon.display_name(), idx == current_idx))
+        .collect();
+
+    let current_name = monitors
+        .get(current_idx)
+        .map(|m| m.display_name())
+        .unwrap_or_else(|| "Select".to_string());
+
+    let resp = render_gamepad_dropdown(
+        ui, id_salt, &current_name, width, &items,
+        state.focused, state.open, state.selection_idx, state.activate,
+    );
+
+    (resp.selected, resp.toggled || (state.focused && state.activate))
+}
+
+/// Build the audio session override dropdown items for an instance
+pub(super) fn audio_override_items(
+    sinks: &[AudioSink],
+    effective: &Option<(String, String, bool)>,
+    has_override: bool,
+) -> Vec<DropdownItem<AudioOverrideAction>> {
+    let is_muted = effective.as_ref().is_some_and(|(s, _, _)| s.is_empty());
+    let mut items: Vec<DropdownItem<AudioOverrideAction>> = sinks.iter()
+        .map(|sink| {
+            let is_current = effective.as_ref().is_some_and(|(s, _, _)| s == &sink.name);
+            DropdownItem::new(AudioOverrideAction::SetDevice(sink.name.clone()), &sink.description, is_current)
+        })
+        .collect();
+    items.push(DropdownItem::new(AudioOverrideAction::Mute, format!("{} None (mute)", icons::SPEAKER_SLASH), is_muted));
+    if has_override {
+        items.push(DropdownItem::new(AudioOverrideAction::Reset, "↩ Reset to profile", false));
+    }
+    items
+}
+
+/// Apply an audio session override selection
+pub(super) fn apply_audio_override_action(
+    overrides: &mut HashMap<usize, Option<String>>,
+    instance_idx: usize,
+    action: AudioOverrideAction,
+) {
+    match action {
+        AudioOverrideAction::SetDevice(name) => { overrides.insert(instance_idx, Some(name)); }
+        AudioOverrideAction::Mute => { overrides.insert(instance_idx, None); }
+        AudioOverrideAction::Reset => { overrides.remove(&instance_idx); }
+    }
+}
+
+/// Build the gptokeyb profile dropdown items for an instance
+///
+/// `bundled` lists profiles shipped with the active handler.
+pub(super) fn gptokeyb_items(current: Option<&String>, bundled: &[String]) -> Vec<DropdownItem<GptokeybAction>> {
+    let mut items: Vec<DropdownItem<GptokeybAction>> = Vec::new();
+    items.push(DropdownItem::new(GptokeybAction::Default, "Default (handler)", current.is_none()));
+    items.push(DropdownItem::new(GptokeybAction::Disabled, format!("{} Disabled", icons::PROHIBIT), current == Some(&String::new())));
+
+    for profile in bundled {
+        items.push(DropdownItem::new(
+            GptokeybAction::Profile(profile.clone()),
+            format!("{} {} (bundled)", icons::PACKAGE, profile),
+            current == Some(profile),
+        ));
+    }
+    for profile in list_builtin_profiles() {
+        items.push(DropdownItem::new(
+            GptokeybAction::Profile(profile.to_string()),
+            format!("{} {} (built-in)", icons::GAME_CONTROLLER, profile),
+            current == Some(&profile.to_string()),
+        ));
+    }
+    for profile in list_user_profiles() {
+        items.push(DropdownItem::new(
+            GptokeybAction::Profile(profile.clone()),
+            format!("{} {} (custom)", icons::USER, profile),
+            current == Some(&profile),
+        ));
+    }
+    items
+}
+
+/// Short label for the current gptokeyb selection
+pub(super) fn gptokeyb_label(current: Option<&String>) -> &str {
+    current
+        .map(|p| if p.is_empty() { "Disabled" } else { p.as_str() })
+        .unwrap_or("Default")
+}
+
+/// Apply a gptokeyb profile selection
+pub(super) fn apply_gptokeyb_action(
+    overrides: &mut HashMap<usize, String>,
+    instance_idx: usize,
+    action: GptokeybAction,
+) {
+    match action {
+        GptokeybAction::Default => { overrides.remove(&instance_idx); }
+        GptokeybAction::Disabled => { overrides.insert(instance_idx, String::new()); }
+        GptokeybAction::Profile(name) => { overrides.insert(instance_idx, name); }
+    }
+}
+
+/// Resolutions A steps through on the resolution override; a height alone
+/// keeps the layout's aspect ratio
+const RESOLUTION_PRESETS: [(u32, u32); 5] = [(0, 0), (0, 720), (0, 1080), (0, 1440), (0, 2160)];
+
+/// Render the per-instance cursor grab toggle
+///
+/// Clicking or A cycles Default (global setting) -> On -> Off.
+pub(super) fn grab_cursor_button(
+    ui: &mut Ui,
+    grab_cursor: &mut Option<bool>,
+    global: bool,
+    compact: bool,
+    stroke: egui::Stroke,
+    activate: bool,
+) {
+    let state = match grab_cursor {
+        None => "Default",
+        Some(true) => "On",
+        Some(false) => "Off",
+    };
+    let text = if compact {
+        icons::CURSOR.to_string()
+    } else {
+        format!("{} Grab: {}", icons::CURSOR, state)
+    };
+    let effective = if grab_cursor.unwrap_or(global) { "confined" } else { "free" };
+    let tip = format!("Cursor grab: {} (cursor {})\nClick to cycle Default / On / Off", state, effective);
+
+    let button = egui::Button::new(text).small().stroke(stroke);
+    if ui.add(button).on_hover_text(tip).clicked() || activate {
+        *grab_cursor = match grab_cursor {
+            None => Some(true),
+            Some(true) => Some(false),
+            Some(false) => None,
+        };
+    }
+}
+
+/// Render the per-instance MangoHud toggle
+///
+/// Disabled with an explanation when mangohud isn't installed.
+pub(super) fn mangohud_button(
+    ui: &mut Ui,
+    mangohud: &mut bool,
+    available: bool,
+    compact: bool,
+    stroke: egui::Stroke,
+    activate: bool,
+) {
+    let text = if compact {
+        icons::GAUGE.to_string()
+    } else {
+        format!("{} FPS", icons::GAUGE)
+    };
+    let button = egui::Button::new(text)
+        .small()
+        .selected(*mangohud && available)
+        .stroke(stroke);
+    let response = ui.add_enabled(available, button);
+
+    if !available {
+        response.on_disabled_hover_text("MangoHud is not installed (mangohud not found in PATH)");
+    } else if response
+        .on_hover_text("Show the MangoHud performance overlay for this player")
+        .clicked()
+        || activate
+    {
+        *mangohud = !*mangohud;
+    }
+}
+
+/// Render the per-instance resolution override menu
+///
+/// 0 means auto (use the layout size); setting only one side keeps the
+/// layout's aspect ratio. A steps through common heights instead of opening
+/// the menu. `fix_lowres` and `scale` are only used to warn that the low-res
+/// fix will raise a small override.
+pub(super) fn resolution_override_button(
+    ui: &mut Ui,
+    resolution: &mut (u32, u32),
+    fix_lowres: bool,
+    scale: f32,
+    compact: bool,
+    stroke: egui::Stroke,
+    activate: bool,
+) {
+    if activate {
+        let next = RESOLUTION_PRESETS.iter().position(|&r| r == *resolution).map_or(0, |i| i + 1);
+        *resolution = RESOLUTION_PRESETS[next % RESOLUTION_PRESETS.len()];
+    }
+    let state = match *resolution {
+        (0, 0) => "Auto".to_string(),
+        (w, 0) => format!("{}w", w),
+        (0, h) => format!("{}p", h),
+        (w, h) => format!("{}x{}", w, h),
+    };
+    let text = if compact {
+        icons::ARROWS_OUT.to_string()
+    } else {
+        format!("{} Res: {}", icons::ARROWS_OUT, state)
+    };
+
+    let button = egui::Button::new(text).stroke(stroke);
+    MenuButton::from_button(button)
+        .ui(ui, |ui| {
+            ui.label("Game resolution (0 = auto)");
+            ui.horizontal(|ui| {
+                ui.add(egui::DragValue::new(&mut resolution.0).range(0..=7680).speed(8));
+                ui.label("x");
+                ui.add(egui::DragValue::new(&mut resolution.1).range(0..=4320).speed(8));
+            });
+            if fix_lowres && crate::instance::below_lowres_minimum(resolution.1, scale) {
+                ui.label(format!("{} Raised to 600p by the low resolution fix", icons::WARNING));
+            }
+            if ui.add_enabled(*resolution != (0, 0), egui::Button::new("↩ Auto")).clicked() {
+                *resolution = (0, 0);
+                ui.close();
+            }
+        })
+        .0
+        .on_hover_text(format!(
+            "Game resolution: {}\nThe game renders at this size and is scaled to fit its window. A steps through 720p, 1080p, 1440p and 2160p",
+            state
+        ));
+}
+
+/// Render the per-instance extra arguments menu
+///
+/// The arguments are appended to the handler's; `{player}` is replaced with
+/// the player number at launch. A opens the menu with the text field focused.
+pub(super) fn args_override_button(ui: &mut Ui, args: &mut String, compact: bool, stroke: egui::Stroke, activate: bool) {
+    let text = match (compact, args.trim().is_empty()) {
+        (true, _) => icons::TERMINAL.to_string(),
+        (false, true) => format!("{} Args", icons::TERMINAL),
+        (false, false) => format!("{} Args *", icons::TERMINAL),
+    };
+    let hover = if args.trim().is_empty() {
+        "Extra game arguments for this player".to_string()
+    } else {
+        format!("Extra game arguments: {}", args.trim())
+    };
+
+    let button = egui::Button::new(text).stroke(stroke);
+    let (response, _) = MenuButton::from_button(button).ui(ui, |ui| {
+        ui.label("Appended to the handler's arguments");
+        let edit = ui.add(
+            egui::TextEdit::singleline(args)
+                .hint_text("-player {player}")
+                .desired_width(220.0),
+        );
+        if ui.memory(|m| m.focused().is_none()) {
+            edit.request_focus();
+        }
+        ui.label(
+            egui::RichText::new("{player} becomes the player number (1, 2, ...). Quote words with spaces.")
+                .small()
+                .weak(),
+        );
+        if ui.add_enabled(!args.is_empty(), egui::Button::new("Clear")).clicked() {
+            args.clear();
+            ui.close();
+        }
+    });
+    if activate {
+        egui::Popup::toggle_id(ui.ctx(), egui::Popup::default_response_id(&response));
+    }
+    response.on_hover_text(hover);
+}
@@ -53,6 +53,24 @@ impl Splitux {
                                 .max_height(16.0),
                         )
                         .on_hover_text("Right Stick: Scroll");
+
+                        ui.add(
+                            egui::Image::new(egui::include_image!("../../../assets/BTN_SELECT.png"))
+                                .max_height(16.0),
+                        )
+                        .on_hover_text("Select: Toggle Table View");
+
+                        ui.add(
+                            egui::Image::new(egui::include_image!("../../../assets/BTN_RT.png"))
+                                .max_height(16.0),
+                        )
+                        .on_hover_text("RT / R: Toggle Layout Preview");
+
+                        ui.add(
+                            egui::Image::new(egui::include_image!("../../../assets/BTN_LT.png"))
+                                .max_height(16.0),
+                        )
+                        .on_hover_text("LT: Toggle Claim Mode");
                     });
                 } else {
                     // Full mode: icons with labels
@@ -119,6 +137,32 @@ impl Splitux {
                                 .max_height(16.0),
                         );
                         ui.label(RichText::new("Scroll").strong());
+
+                        ui.add_space(8.0);
+
+                        ui.add(
+                            egui::Image::new(egui::include_image!("../../../assets/BTN_SELECT.png"))
+                                .max_height(16.0),
+                        );
+                        let view_text = if self.options.layout.instance_table_view { "Cards" } else { "Table" };
+                        ui.label(RichText::new(view_text).strong());
+
+                        ui.add_space(8.0);
+
+                        ui.add(
+                            egui::Image::new(egui::include_image!("../../../assets/BTN_RT.png"))
+                                .max_height(16.0),
+                        );
+                        ui.label(" / R:");
+                        ui.label(RichText::new("Preview").strong());
+
+                        ui.add_space(8.0);
+
+                        ui.add(
+                            egui::Image::new(egui::include_image!("../../../assets/BTN_LT.png"))
+                                .max_height(16.0),
+                        );
+                        ui.label(RichText::new("Claim").strong());
                     });
                 }
             });
@@ -1,12 +1,18 @@
 //! Launch options bar for instance page
 
 use crate::app::app::{InstanceFocus, Splitux};
+use crate::audio::{solo_overrides, solo_player};
 use crate::ui::theme;
 use crate::ui::components::layout_carousel::{
     navigate_preset, render_custom_assignment, render_layout_carousel,
 };
-use crate::wm::presets::{get_preset_by_id, get_presets_for_count};
+use crate::config::save_cfg;
+use crate::profiles::scan_profiles;
+use crate::ui::focus::pure::launch_options;
+use crate::ui::focus::types::LaunchOption;
+use crate::wm::presets::{get_presets_for_count, LayoutPreset};
 use eframe::egui::{self, RichText, Ui};
+use egui_phosphor::regular as icons;
 
 impl Splitux {
     /// Display the bottom bar with launch options and start button
@@ -53,6 +59,7 @@ impl Splitux {
                 egui::Stroke::NONE
             };
 
+            let mut copy_command_for: Option<usize> = None;
             theme::card_frame()
                 .fill(theme::colors::BG_DARK)
                 .stroke(frame_stroke)
@@ -70,6 +77,7 @@ impl Splitux {
 
                             ui.label("Layout:");
 
+                            let presets = self.options.layout_presets.presets_for_count(player_count);
                             let current_index =
                                 self.options.layout_presets.get_index_for_count(player_count);
 
@@ -82,7 +90,7 @@ impl Splitux {
 
                             let response = render_layout_carousel(
                                 ui,
-                                player_count,
+                                &presets,
                                 current_index,
                                 layout_focused,
                                 custom_order.as_deref(),
@@ -90,7 +98,6 @@ impl Splitux {
 
                             // Handle mouse-based navigation from carousel
                             if response.changed {
-                                let presets = get_presets_for_count(player_count);
                                 let new_index =
                                     navigate_preset(current_index, response.direction, presets.len());
                                 if let Some(preset) = presets.get(new_index) {
@@ -131,13 +138,156 @@ impl Splitux {
                         if checkbox_response.hovered() || kb_focused {
                             self.infotext = "Uses gamescope-splitux with input device holding support. This allows assigning keyboards and mice to specific players. Press A to toggle.".to_string();
                         }
+
+                        // Solo audio - mute all but one player
+                        if player_count >= 2 {
+                            option_idx += 1;
+                            let solo_focused =
+                                is_launch_options_focused && self.launch_option_index == option_idx;
+
+                            ui.add_space(16.0);
+                            ui.add(egui::Separator::default().vertical());
+                            ui.add_space(16.0);
+
+                            let label = match solo_player(&self.audio_session_overrides, player_count) {
+                                Some(i) => format!("{} Audio: only P{}", icons::SPEAKER_HIGH, i + 1),
+                                None => format!("{} Audio: all players", icons::SPEAKER_HIGH),
+                            };
+                            let text = if solo_focused {
+                                RichText::new(label).color(theme::colors::ACCENT)
+                            } else {
+                                RichText::new(label)
+                            };
+
+                            let solo_response = ui.button(text);
+                            if solo_response.clicked() {
+                                self.cycle_solo_audio();
+                            }
+                            if solo_response.secondary_clicked() {
+                                self.audio_session_overrides.clear();
+                            }
+                            if solo_response.hovered() || solo_focused {
+                                self.infotext = "Mutes every player but one for this session. Press A to cycle which player is heard; \"all players\" clears the session audio overrides (right-click clears them directly).".to_string();
+                            }
+
+                            // Guest saves - profile every guest starts from
+                            option_idx += 1;
+                            let guest_focused =
+                                is_launch_options_focused && self.launch_option_index == option_idx;
+
+                            ui.add_space(16.0);
+                            ui.add(egui::Separator::default().vertical());
+                            ui.add_space(16.0);
+
+                            let label = match &self.options.guest_save_base {
+                                Some(base) => format!("{} Guest saves: {}", icons::USERS, base),
+                                None => format!("{} Guest saves: default", icons::USERS),
+                            };
+                            let text = if guest_focused {
+                                RichText::new(label).color(theme::colors::ACCENT)
+                            } else {
+                                RichText::new(label)
+                            };
+
+                            let guest_response = ui.button(text);
+                            if guest_response.clicked() {
+                                self.cycle_guest_save_base();
+                            }
+                            if guest_response.secondary_clicked() {
+                                self.options.guest_save_base = None;
+                            }
+                            if guest_response.hovered() || guest_focused {
+                                self.infotext = "Copies one profile's saves into every guest before launch, so everyone starts from the same progress. Guest saves are thrown away afterwards and never synced back. Press A to cycle profiles; \"default\" uses the master profile or the game's own saves (right-click resets).".to_string();
+                            }
+                        }
+
+                        // Launch command for reproducing issues by hand
+                        option_idx += 1;
+                        let copy_focused =
+                            is_launch_options_focused && self.launch_option_index == option_idx;
+
+                        ui.add_space(16.0);
+                        ui.add(egui::Separator::default().vertical());
+                        ui.add_space(16.0);
+
+                        let next_player = self.copy_command_player % player_count;
+                        let label = if copy_focused {
+                            RichText::new(format!("{} Copy command: P{}", icons::TERMINAL, next_player + 1))
+                                .color(theme::colors::ACCENT)
+                        } else {
+                            RichText::new(format!("{} Copy command", icons::TERMINAL))
+                        };
+                        let copy_response = ui.menu_button(label, |ui| {
+                            for i in 0..player_count {
+                                if ui.button(format!("P{}", i + 1)).clicked() {
+                                    copy_command_for = Some(i);
+                                    ui.close();
+                                }
+                            }
+                        });
+                        if copy_focused && self.activate_focused {
+                            copy_command_for = Some(next_player);
+                            self.copy_command_player = next_player + 1;
+                        }
+                        if copy_response.response.hovered() || copy_focused {
+                            self.infotext = "Copies a player's launch command to the clipboard, quoted for a shell, to run it outside Splitux. Audio routing and gptokeyb aren't included. Press A to copy the shown player's command; each press moves to the next player.".to_string();
+                        }
                     });
                 });
+            if let Some(i) = copy_command_for {
+                self.copy_launch_command(ui.ctx(), i);
+            }
             ui.add_space(8.0);
             ui.separator();
         });
     }
 
+    /// Activate the focused launch option (A/Enter)
+    pub(crate) fn activate_launch_option(&mut self) {
+        let player_count = self.instances.len();
+        match launch_options(player_count).get(self.launch_option_index) {
+            // A on the carousel cycles to the next preset
+            Some(LaunchOption::Layout) => self.options.layout_presets.cycle_next(player_count),
+            Some(LaunchOption::InputHolding) => {
+                self.options.input_holding = !self.options.input_holding;
+            }
+            Some(LaunchOption::SoloAudio) => self.cycle_solo_audio(),
+            Some(LaunchOption::GuestSaves) => self.cycle_guest_save_base(),
+            // Copying needs the egui context, so the bar handles it while drawing
+            Some(LaunchOption::CopyCommand) => self.activate_focused = true,
+            None => {}
+        }
+    }
+
+    /// Cycle solo audio: all players -> only P1 -> ... -> only Pn -> all players
+    ///
+    /// Returning to "all players" clears every session audio override.
+    pub(crate) fn cycle_solo_audio(&mut self) {
+        let player_count = self.instances.len();
+        let next = match solo_player(&self.audio_session_overrides, player_count) {
+            None => Some(0),
+            Some(i) if i + 1 < player_count => Some(i + 1),
+            Some(_) => None,
+        };
+        match next {
+            Some(solo) => solo_overrides(&mut self.audio_session_overrides, player_count, solo),
+            None => self.audio_session_overrides.clear(),
+        }
+    }
+
+    /// Cycle the guest save base: default -> each named profile -> default
+    pub(crate) fn cycle_guest_save_base(&mut self) {
+        let profiles = scan_profiles(false);
+        let next = match &self.options.guest_save_base {
+            None => profiles.first(),
+            Some(base) => profiles
+                .iter()
+                .position(|p| p == base)
+                .and_then(|i| profiles.get(i + 1)),
+        };
+        self.options.guest_save_base = next.cloned();
+    }
+
     /// Enter custom layout mode
     pub(crate) fn enter_custom_layout_mode(&mut self, player_count: usize, preset_id: &str) {
         self.layout_custom_mode = true;
@@ -154,13 +304,12 @@ impl Splitux {
     /// Display the custom layout assignment mode
     fn display_custom_layout_mode(&mut self, ui: &mut Ui, player_count: usize, preset_id: &str) {
         // Get preset by ID, or fall back to first preset for this player count
-        let presets = get_presets_for_count(player_count);
-        let preset = match get_preset_by_id(preset_id) {
+        let preset = match self.options.layout_presets.resolve(preset_id) {
             Some(p) => p,
             None => {
                 // Preset ID not found (possibly empty), use first available preset
-                match presets.first() {
-                    Some(p) => *p,
+                match get_presets_for_count(player_count).first() {
+                    Some(p) => (*p).clone(),
                     None => {
                         self.layout_custom_mode = false;
                         return;
@@ -169,6 +318,10 @@ impl Splitux {
             }
         };
 
+        // Set before rendering so messages from the actions below win
+        self.infotext =
+            "D-pad: navigate regions | A: cycle player | Y: split for more players | B: done".to_string();
+
         ui.with_layout(egui::Layout::bottom_up(egui::Align::Center), |ui| {
             ui.add_space(12.0);
 
@@ -178,7 +331,7 @@ impl Splitux {
                 .show(ui, |ui| {
                     let response = render_custom_assignment(
                         ui,
-                        preset,
+                        &preset,
                         &self.layout_edit_order,
                         self.layout_focused_region,
                         player_count,
@@ -200,14 +353,35 @@ impl Splitux {
                     if response.cycle_instance {
                         self.cycle_instance_in_region(player_count);
                     }
+
+                    if let Some(target) = response.derive_count {
+                        self.exit_custom_layout_mode();
+                        self.derive_layout_preset(&preset, target);
+                    }
                 });
 
             ui.add_space(8.0);
             ui.separator();
         });
+    }
 
-        self.infotext =
-            "D-pad: navigate regions | A: cycle player | B: done".to_string();
+    /// Create a layout for `target_count` players by subdividing `source`
+    pub(crate) fn derive_layout_preset(&mut self, source: &LayoutPreset, target_count: usize) {
+        match self.options.layout_presets.derive_preset(source, target_count) {
+            Some(id) => {
+                log_info!("Layout: derived '{}' from '{}'", id, source.id);
+                if let Err(e) = save_cfg(&self.options) {
+                    log_error!("Failed to save settings: {}", e);
+                }
+                self.infotext = format!(
+                    "Created a {}-player layout from \"{}\" (selected for {} players)",
+                    target_count, source.name, target_count
+                );
+            }
+            None => {
+                self.infotext = format!("Can't split \"{}\" into {} players", source.name, target_count);
+            }
+        }
     }
 
     /// Cycle the instance assigned to the currently focused region
@@ -236,18 +410,18 @@ impl Splitux {
     }
 
     /// Get the current preset, falling back to first available if ID is empty/invalid
-    fn get_current_preset(&self) -> Option<&'static crate::wm::presets::LayoutPreset> {
+    pub(crate) fn get_current_preset(&self) -> Option<LayoutPreset> {
         let player_count = self.instances.len();
         let preset_id = self.options.layout_presets.get_for_count(player_count);
 
-        get_preset_by_id(preset_id)
-            .or_else(|| get_presets_for_count(player_count).first().copied())
+        self.options.layout_presets.resolve(preset_id)
+            .or_else(|| get_presets_for_count(player_count).first().map(|p| (*p).clone()))
     }
 
     /// Navigate up in custom layout mode (find region above current)
     pub(crate) fn navigate_custom_layout_up(&mut self) {
         if let Some(preset) = self.get_current_preset() {
-            if let Some(new_region) = find_region_in_direction(preset, self.layout_focused_region, Direction::Up) {
+            if let Some(new_region) = find_region_in_direction(&preset, self.layout_focused_region, Direction::Up) {
                 self.layout_focused_region = new_region;
             }
         }
@@ -256,7 +430,7 @@ impl Splitux {
     /// Navigate down in custom layout mode
     pub(crate) fn navigate_custom_layout_down(&mut self) {
         if let Some(preset) = self.get_current_preset() {
-            if let Some(new_region) = find_region_in_direction(preset, self.layout_focused_region, Direction::Down) {
+            if let Some(new_region) = find_region_in_direction(&preset, self.layout_focused_region, Direction::Down) {
                 self.layout_focused_region = new_region;
             }
         }
@@ -265,7 +439,7 @@ impl Splitux {
     /// Navigate left in custom layout mode
     pub(crate) fn navigate_custom_layout_left(&mut self) {
         if let Some(preset) = self.get_current_preset() {
-            if let Some(new_region) = find_region_in_direction(preset, self.layout_focused_region, Direction::Left) {
+            if let Some(new_region) = find_region_in_direction(&preset, self.layout_focused_region, Direction::Left) {
                 self.layout_focused_region = new_region;
             }
         }
@@ -274,7 +448,7 @@ impl Splitux {
     /// Navigate right in custom layout mode
     pub(crate) fn navigate_custom_layout_right(&mut self) {
         if let Some(preset) = self.get_current_preset() {
-            if let Some(new_region) = find_region_in_direction(preset, self.layout_focused_region, Direction::Right) {
+            if let Some(new_region) = find_region_in_direction(&preset, self.layout_focused_region, Direction::Right) {
                 self.layout_focused_region = new_region;
             }
         }
@@ -296,7 +470,7 @@ fn find_region_in_direction(
     current_idx: usize,
     direction: Direction,
 ) -> Option<usize> {
-    let regions = preset.regions;
+    let regions = &preset.regions;
     if current_idx >= regions.len() {
         return None;
     }
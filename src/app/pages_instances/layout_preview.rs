@@ -0,0 +1,92 @@
+//! Layout preview - each monitor drawn to scale with the region every
+//! instance will get at launch, computed by the same geometry code the
+//! window manager uses
+
+use super::PLAYER_COLORS;
+use crate::app::app::Splitux;
+use crate::ui::theme;
+use crate::wm::layout::{instance_geometry, WindowGeometry};
+use eframe::egui::{self, RichText, Stroke, StrokeKind, Ui};
+
+/// Height of the preview area
+const PREVIEW_HEIGHT: f32 = 140.0;
+
+impl Splitux {
+    /// Draw the monitors and the region of every instance on them
+    pub(super) fn display_layout_preview(&self, ui: &mut Ui) {
+        if self.instances.is_empty() || self.monitors.is_empty() {
+            return;
+        }
+
+        let player_count = self.instances.len();
+        let instance_monitors: Vec<usize> = self
+            .instances
+            .iter()
+            .map(|i| i.monitor.min(self.monitors.len() - 1))
+            .collect();
+
+        let monitors: Vec<WindowGeometry> = self
+            .monitors
+            .iter()
+            .map(|m| {
+                let [x, y, w, h] = m.rect();
+                WindowGeometry { x: x as i32, y: y as i32, width: w as u32, height: h as u32 }
+            })
+            .collect();
+        let regions: Vec<WindowGeometry> = (0..player_count)
+            .map(|i| {
+                let monitor = &monitors[instance_monitors[i]];
+                instance_geometry(&self.options.layout_presets, &instance_monitors, i, monitor)
+            })
+            .collect();
+
+        // Fit the bounding box of all monitors into the available space
+        let min_x = monitors.iter().map(|m| m.x).min().unwrap_or(0) as f32;
+        let min_y = monitors.iter().map(|m| m.y).min().unwrap_or(0) as f32;
+        let max_x = monitors.iter().map(|m| m.x + m.width as i32).max().unwrap_or(1) as f32;
+        let max_y = monitors.iter().map(|m| m.y + m.height as i32).max().unwrap_or(1) as f32;
+        let (desk_w, desk_h) = ((max_x - min_x).max(1.0), (max_y - min_y).max(1.0));
+        let scale = (ui.available_width() / desk_w).min(PREVIEW_HEIGHT / desk_h);
+
+        let (area, _) = ui.allocate_exact_size(egui::vec2(desk_w * scale, desk_h * scale), egui::Sense::hover());
+        if !ui.is_rect_visible(area) {
+            return;
+        }
+        let painter = ui.painter_at(area);
+        let to_screen = |g: &WindowGeometry| {
+            egui::Rect::from_min_size(
+                area.min + egui::vec2((g.x as f32 - min_x) * scale, (g.y as f32 - min_y) * scale),
+                egui::vec2(g.width as f32 * scale, g.height as f32 * scale),
+            )
+        };
+
+        for (monitor, m) in monitors.iter().zip(&self.monitors) {
+            let rect = to_screen(monitor).shrink(1.0);
+            painter.rect_filled(rect, 3.0, theme::colors::BG_DARK);
+            painter.rect_stroke(rect, 3.0, Stroke::new(1.5, theme::colors::TEXT_MUTED), StrokeKind::Inside);
+            painter.text(
+                rect.left_bottom() + egui::vec2(4.0, -3.0),
+                egui::Align2::LEFT_BOTTOM,
+                m.display_name(),
+                egui::FontId::proportional(10.0),
+                theme::colors::TEXT_MUTED,
+            );
+        }
+
+        for (i, region) in regions.iter().enumerate() {
+            let rect = to_screen(region).shrink(3.0);
+            let color = PLAYER_COLORS[i % PLAYER_COLORS.len()];
+            painter.rect_filled(rect, 2.0, color.gamma_multiply(0.25));
+            painter.rect_stroke(rect, 2.0, Stroke::new(1.5, color), StrokeKind::Inside);
+            painter.text(
+                rect.center(),
+                egui::Align2::CENTER_CENTER,
+                format!("P{}", i + 1),
+                egui::FontId::proportional(12.0),
+                theme::colors::TEXT_PRIMARY,
+            );
+        }
+
+        ui.label(RichText::new("Regions as they will be placed at launch").weak().small());
+    }
+}
@@ -1,14 +1,33 @@
 //! Profile change handling for instance page
 
+use std::collections::HashSet;
+
 use crate::app::app::Splitux;
+use crate::gptokeyb::{list_builtin_profiles, list_user_profiles};
 use crate::input::{find_device_by_uniq, is_device_assigned};
+use crate::instance::duplicate_profiles;
 use crate::profile_prefs::ProfilePreferences;
 
 impl Splitux {
-    /// Handle profile selection changes - auto-assign preferred controllers and audio
+    /// Instances whose named profile is also selected by another instance
+    pub(super) fn detect_profile_conflicts(&self) -> HashSet<usize> {
+        duplicate_profiles(&self.instances, &self.profiles)
+            .into_iter()
+            .flat_map(|(_, users)| users)
+            .collect()
+    }
+
+    /// Handle profile selection changes - auto-assign preferred controllers, audio
+    /// and KB/mouse profiles
     pub(super) fn handle_profile_changes(&mut self, profile_changes: Vec<(usize, usize)>) {
         self.controller_warnings.clear();
         self.audio_warnings.clear();
+        self.gptokeyb_warnings.clear();
+
+        let handler_key = self
+            .active_handler()
+            .map(|h| h.handler_dir_name().to_string())
+            .filter(|k| !k.is_empty());
 
         for (instance_idx, new_selection) in profile_changes {
             if instance_idx < self.prev_profile_selections.len() {
@@ -24,7 +43,7 @@ impl Splitux {
                 continue;
             }
 
-            let prefs = ProfilePreferences::load(profile_name);
+            let mut prefs = ProfilePreferences::load(profile_name);
 
             // Try to auto-assign preferred controller
             if let Some(ref preferred_uniq) = prefs.preferred_controller {
@@ -33,8 +52,8 @@ impl Splitux {
                         if instance_idx < self.instances.len() {
                             if !self.instances[instance_idx].devices.contains(&dev_idx) {
                                 self.instances[instance_idx].devices.push(dev_idx);
-                                println!(
-                                    "[splitux] Auto-assigned {} to profile '{}'",
+                                log_info!(
+                                    "Auto-assigned {} to profile '{}'",
                                     self.device_display_name(dev_idx),
                                     profile_name
                                 );
@@ -57,22 +76,59 @@ impl Splitux {
             }
 
             // Store audio preference for use at launch
-            if let Some(ref preferred_audio) = prefs.preferred_audio {
-                self.profile_audio_prefs.insert(instance_idx, preferred_audio.clone());
+            if let Some(preferred_audio) = prefs.preferred_audio.clone() {
+                // Re-bind by description/stable name if the sink was renumbered
+                let resolved = self
+                    .resolve_audio_sink(&preferred_audio, prefs.preferred_audio_name.as_deref())
+                    .map(|d| (d.name.clone(), d.description.clone()));
 
-                let sink_available = self
-                    .audio_devices
-                    .iter()
-                    .any(|d| d.name == *preferred_audio);
+                match resolved {
+                    Some((name, description)) => {
+                        if name != preferred_audio {
+                            log_info!(
+                                "audio: Re-bound {}'s preferred audio {} -> {}",
+                                profile_name, preferred_audio, name
+                            );
+                            // Persist the new name so the next lookup is an exact match
+                            prefs.set_audio(&name, &description);
+                            if let Err(e) = prefs.save(profile_name) {
+                                log_error!("Failed to save profile preferences: {}", e);
+                            }
+                        }
+                        self.profile_audio_prefs.insert(instance_idx, name);
+                    }
+                    None => {
+                        self.profile_audio_prefs.insert(instance_idx, preferred_audio);
+                        self.audio_warnings.push(format!(
+                            "{}'s preferred audio ({}) is not available",
+                            profile_name,
+                            prefs.preferred_audio_name.as_deref().unwrap_or("unknown")
+                        ));
+                    }
+                }
+            }
 
-                if !sink_available {
-                    self.audio_warnings.push(format!(
-                        "{}'s preferred audio ({}) is not available",
-                        profile_name,
-                        prefs.preferred_audio_name.as_deref().unwrap_or("unknown")
+            // Apply the KB/mouse profile this player prefers for the current game
+            if let Some(key) = &handler_key
+                && let Some(preferred) = prefs.gptokeyb_for(key).cloned()
+            {
+                if self.gptokeyb_profile_exists(&preferred) {
+                    self.gptokeyb_instance_overrides.insert(instance_idx, preferred);
+                } else {
+                    self.gptokeyb_warnings.push(format!(
+                        "{}'s preferred KB/mouse profile ({}) no longer exists",
+                        profile_name, preferred
                     ));
                 }
             }
         }
     }
+
+    /// Whether a gptokeyb profile name can still be used ("" means disabled)
+    fn gptokeyb_profile_exists(&self, name: &str) -> bool {
+        name.is_empty()
+            || self.bundled_gptokeyb_profiles().iter().any(|p| p == name)
+            || list_builtin_profiles().iter().any(|p| p == name)
+            || list_user_profiles().iter().any(|p| p == name)
+    }
 }
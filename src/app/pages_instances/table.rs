@@ -0,0 +1,300 @@
+//! Compact table view for the instance page
+//!
+//! Renders each player as one row with a cell per card element, so four
+//! players fit on small screens without scrolling. Shares dropdown builders
+//! and action handlers with the card view.
+
+use super::cards::PLAYER_COLORS;
+use super::dropdowns::{
+    apply_audio_override_action, apply_gptokeyb_action, audio_override_items, grab_cursor_button,
+    gptokeyb_items, gptokeyb_label, mangohud_button, monitor_dropdown,
+};
+use super::focus::{element_focus_stroke, is_element_focused};
+use super::types::DropdownState;
+use crate::app::app::{ActiveDropdown, InstanceFocus, Splitux};
+use crate::config::save_cfg;
+use crate::ui::components::dropdown::{render_gamepad_dropdown, DropdownItem};
+use crate::ui::focus::pure::{instance_table_columns, table_column_for};
+use crate::ui::focus::types::InstanceCardFocus;
+use crate::ui::responsive::combo_width;
+use crate::ui::theme;
+use eframe::egui::{self, RichText, Ui};
+use egui_phosphor::regular as icons;
+use std::collections::HashSet;
+
+impl Splitux {
+    /// Columns shown in the compact table, matching its focus order
+    pub(crate) fn instance_table_columns(&self) -> Vec<InstanceCardFocus> {
+        instance_table_columns(
+            self.options.gamescope_sdl_backend,
+            !self.audio_devices.is_empty() && self.options.audio.enabled,
+        )
+    }
+
+    /// Switch between card and table view, keeping focus on the same player
+    pub(crate) fn toggle_instance_table_view(&mut self) {
+        self.options.layout.instance_table_view = !self.options.layout.instance_table_view;
+        self.active_dropdown = None;
+
+        if self.options.layout.instance_table_view
+            && let InstanceFocus::InstanceCard(i, element) = &self.instance_focus
+        {
+            let columns = self.instance_table_columns();
+            let col = table_column_for(element, &columns);
+            self.instance_focus = InstanceFocus::InstanceCard(*i, columns[col]);
+        }
+
+        if let Err(e) = save_cfg(&self.options) {
+            log_error!("Failed to save settings: {}", e);
+        }
+    }
+
+    /// Render all instances as rows of a compact table
+    pub(super) fn display_instance_table(
+        &mut self,
+        ui: &mut Ui,
+        audio_conflicts: &HashSet<usize>,
+        profile_conflicts: &HashSet<usize>,
+        effective_audio: &[Option<(String, String, bool)>],
+        devices_to_remove: &mut Vec<(usize, usize)>,
+        profile_changes: &mut Vec<(usize, usize)>,
+    ) {
+        if self.instances.is_empty() {
+            return;
+        }
+
+        let current_focus = self.instance_focus.clone();
+        let activate_focused = self.activate_focused;
+        let columns = self.instance_table_columns();
+        let show_monitor = columns.contains(&InstanceCardFocus::Monitor);
+        let show_audio = columns.contains(&InstanceCardFocus::AudioOverride);
+        let bundled_gptokeyb = self.bundled_gptokeyb_profiles();
+        let mut clone_request: Option<usize> = None;
+
+        let profile_width = combo_width(ui, 110.0, 70.0);
+        let monitor_width = combo_width(ui, 90.0, 60.0);
+        let audio_width = combo_width(ui, 110.0, 60.0);
+        let gptokeyb_width = combo_width(ui, 90.0, 50.0);
+
+        theme::card_frame().show(ui, |ui| {
+            egui::Grid::new("instance_table")
+                .num_columns(columns.len() + 1)
+                .striped(true)
+                .spacing(egui::vec2(12.0, 6.0))
+                .show(ui, |ui| {
+                    // ── Header ──
+                    ui.label("");
+                    ui.label(RichText::new("Profile").small().color(theme::colors::TEXT_MUTED));
+                    ui.label(RichText::new("Devices").small().color(theme::colors::TEXT_MUTED));
+                    if show_monitor {
+                        ui.label(RichText::new("Monitor").small().color(theme::colors::TEXT_MUTED));
+                    }
+                    if show_audio {
+                        ui.label(RichText::new("Audio").small().color(theme::colors::TEXT_MUTED));
+                    }
+                    ui.label(RichText::new("KB/Mouse").small().color(theme::colors::TEXT_MUTED));
+                    ui.label(RichText::new("Cursor").small().color(theme::colors::TEXT_MUTED));
+                    ui.label(RichText::new("FPS").small().color(theme::colors::TEXT_MUTED));
+                    ui.end_row();
+
+                    for i in 0..self.instances.len() {
+                        let player_color = PLAYER_COLORS.get(i).copied().unwrap_or(theme::colors::ACCENT);
+                        let row_focused = matches!(&current_focus, InstanceFocus::InstanceCard(idx, _) if *idx == i);
+                        let mut player_label = RichText::new(format!("P{}", i + 1)).strong().size(16.0).color(player_color);
+                        if row_focused {
+                            player_label = player_label.underline();
+                        }
+                        ui.label(player_label);
+
+                        // ── Profile ──
+                        let profile_focused = is_element_focused(&current_focus, i, InstanceCardFocus::Profile);
+                        let profile_open = self.active_dropdown == Some(ActiveDropdown::InstanceProfile(i));
+                        let profselection = self.instances[i].profselection;
+
+                        let profile_items: Vec<DropdownItem<usize>> = self.profiles.iter()
+                            .enumerate()
+                            .map(|(idx, name)| DropdownItem::new(idx, name.clone(), idx == profselection))
+                            .collect();
+                        let current_profile = self.profiles.get(profselection)
+                            .cloned()
+                            .unwrap_or_else(|| "Select".to_string());
+
+                        let profile_response = ui.horizontal(|ui| {
+                            if profile_conflicts.contains(&i) {
+                                ui.label(RichText::new(icons::WARNING).color(egui::Color32::YELLOW))
+                                    .on_hover_text("Profile conflict: another player uses this profile");
+                            }
+                            render_gamepad_dropdown(
+                                ui, &format!("table_profile_{i}"), &current_profile, profile_width,
+                                &profile_items, profile_focused, profile_open,
+                                self.dropdown_selection_idx, profile_focused && activate_focused,
+                            )
+                        }).inner;
+
+                        if let Some(new_idx) = profile_response.selected {
+                            self.instances[i].profselection = new_idx;
+                            self.active_dropdown = None;
+                        } else if profile_response.toggled || (profile_focused && activate_focused) {
+                            if profile_open {
+                                self.active_dropdown = None;
+                            } else {
+                                self.active_dropdown = Some(ActiveDropdown::InstanceProfile(i));
+                                self.dropdown_selection_idx = profselection;
+                            }
+                        }
+
+                        let profselection = self.instances[i].profselection;
+                        if profselection != self.prev_profile_selections.get(i).copied().unwrap_or(usize::MAX) {
+                            profile_changes.push((i, profselection));
+                        }
+
+                        // ── Devices ──
+                        ui.horizontal(|ui| {
+                            for &dev in &self.instances[i].devices {
+                                let mut dev_text = RichText::new(self.input_devices[dev].emoji());
+                                if self.input_devices[dev].has_button_held() {
+                                    dev_text = dev_text.strong();
+                                }
+                                ui.label(dev_text).on_hover_text(self.device_display_name(dev));
+                                if ui.small_button(icons::X).on_hover_text("Remove device").clicked() {
+                                    devices_to_remove.push((i, dev));
+                                }
+                            }
+
+                            if self.instance_add_dev.is_none() {
+                                let invite_focused = is_element_focused(&current_focus, i, InstanceCardFocus::InviteDevice);
+                                let invite_btn = egui::Button::new("+")
+                                    .min_size(egui::vec2(24.0, 24.0))
+                                    .stroke(element_focus_stroke(&current_focus, i, InstanceCardFocus::InviteDevice));
+                                if ui.add(invite_btn).on_hover_text("Invite Device").clicked() || (invite_focused && activate_focused) {
+                                    self.instance_add_dev = Some(i);
+                                }
+                            } else if self.instance_add_dev == Some(i) {
+                                ui.label(RichText::new("...").italics());
+                                if ui.small_button(icons::X).clicked() {
+                                    self.instance_add_dev = None;
+                                }
+                            }
+
+                            if ui.small_button(icons::COPY).on_hover_text("Clone instance").clicked() {
+                                clone_request = Some(i);
+                            }
+                        });
+
+                        // ── Monitor ──
+                        if show_monitor {
+                            let monitor_focused = is_element_focused(&current_focus, i, InstanceCardFocus::Monitor);
+                            let monitor_open = self.active_dropdown == Some(ActiveDropdown::InstanceMonitor(i));
+
+                            let (selected, toggled) = monitor_dropdown(
+                                ui, &format!("table_monitor_{i}"), &self.monitors, self.instances[i].monitor, monitor_width,
+                                DropdownState {
+                                    focused: monitor_focused,
+                                    open: monitor_open,
+                                    selection_idx: self.dropdown_selection_idx,
+                                    activate: monitor_focused && activate_focused,
+                                },
+                            );
+
+                            if let Some(new_idx) = selected {
+                                self.instances[i].monitor = new_idx;
+                                self.active_dropdown = None;
+                            } else if toggled {
+                                if monitor_open {
+                                    self.active_dropdown = None;
+                                } else {
+                                    self.active_dropdown = Some(ActiveDropdown::InstanceMonitor(i));
+                                    self.dropdown_selection_idx = self.instances[i].monitor;
+                                }
+                            }
+                        }
+
+                        // ── Audio ──
+                        if show_audio {
+                            ui.horizontal(|ui| {
+                                if audio_conflicts.contains(&i) {
+                                    ui.label(RichText::new(icons::WARNING).color(egui::Color32::YELLOW))
+                                        .on_hover_text("Audio conflict: multiple players using same device");
+                                }
+
+                                let effective = effective_audio.get(i).cloned().flatten();
+                                let has_override = self.audio_session_overrides.contains_key(&i);
+                                let button_text = match &effective {
+                                    Some((sink, _, _)) if sink.is_empty() => "Muted".to_string(),
+                                    Some((_, name, _)) => name.clone(),
+                                    None => "System default".to_string(),
+                                };
+
+                                let audio_focused = is_element_focused(&current_focus, i, InstanceCardFocus::AudioOverride);
+                                let audio_open = self.active_dropdown == Some(ActiveDropdown::InstanceAudioOverride(i));
+                                let items = audio_override_items(&self.audio_devices, &effective, has_override);
+
+                                let audio_response = render_gamepad_dropdown(
+                                    ui, &format!("table_audio_{i}"), &button_text, audio_width,
+                                    &items, audio_focused, audio_open,
+                                    self.dropdown_selection_idx, audio_focused && activate_focused,
+                                );
+
+                                if let Some(action) = audio_response.selected {
+                                    apply_audio_override_action(&mut self.audio_session_overrides, i, action);
+                                    self.active_dropdown = None;
+                                } else if audio_response.toggled || (audio_focused && activate_focused) {
+                                    if audio_open {
+                                        self.active_dropdown = None;
+                                    } else {
+                                        self.active_dropdown = Some(ActiveDropdown::InstanceAudioOverride(i));
+                                        self.dropdown_selection_idx = 0;
+                                    }
+                                }
+                            });
+                        }
+
+                        // ── KB/Mouse ──
+                        let gptokeyb_focused = is_element_focused(&current_focus, i, InstanceCardFocus::GptokeybProfile);
+                        let gptokeyb_open = self.active_dropdown == Some(ActiveDropdown::InstanceGptokeyb(i));
+                        let current_gptokeyb = self.gptokeyb_instance_overrides.get(&i);
+                        let items = gptokeyb_items(current_gptokeyb, &bundled_gptokeyb);
+                        let button_text = gptokeyb_label(current_gptokeyb).to_string();
+
+                        let gptokeyb_response = render_gamepad_dropdown(
+                            ui, &format!("table_gptokeyb_{i}"), &button_text, gptokeyb_width,
+                            &items, gptokeyb_focused, gptokeyb_open,
+                            self.dropdown_selection_idx, gptokeyb_focused && activate_focused,
+                        );
+
+                        if let Some(action) = gptokeyb_response.selected {
+                            apply_gptokeyb_action(&mut self.gptokeyb_instance_overrides, i, action);
+                            self.active_dropdown = None;
+                        } else if gptokeyb_response.toggled || (gptokeyb_focused && activate_focused) {
+                            if gptokeyb_open {
+                                self.active_dropdown = None;
+                            } else {
+                                self.active_dropdown = Some(ActiveDropdown::InstanceGptokeyb(i));
+                                self.dropdown_selection_idx = 0;
+                            }
+                        }
+
+                        // ── Cursor grab and MangoHud ──
+                        grab_cursor_button(
+                            ui, &mut self.instances[i].grab_cursor,
+                            self.options.gamescope_force_grab_cursor, true,
+                            element_focus_stroke(&current_focus, i, InstanceCardFocus::GrabCursor),
+                            is_element_focused(&current_focus, i, InstanceCardFocus::GrabCursor) && activate_focused,
+                        );
+                        mangohud_button(
+                            ui, &mut self.instances[i].mangohud, self.mangohud_available, true,
+                            element_focus_stroke(&current_focus, i, InstanceCardFocus::MangoHud),
+                            is_element_focused(&current_focus, i, InstanceCardFocus::MangoHud) && activate_focused,
+                        );
+
+                        ui.end_row();
+                    }
+                });
+        });
+        ui.add_space(4.0);
+
+        if let Some(i) = clone_request {
+            self.clone_instance(i);
+        }
+    }
+}
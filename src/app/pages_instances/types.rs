@@ -1,5 +1,15 @@
 //! Type definitions for instance page dropdown actions
 
+/// Gamepad focus and open state of one dropdown
+pub(super) struct DropdownState {
+    pub focused: bool,
+    pub open: bool,
+    /// Highlighted item while the list is open
+    pub selection_idx: usize,
+    /// The focused dropdown was activated this frame
+    pub activate: bool,
+}
+
 /// Audio override dropdown action
 #[derive(Clone, PartialEq)]
 pub(super) enum AudioOverrideAction {
@@ -1,13 +1,61 @@
 //! Instance warning display
 
 use crate::app::app::Splitux;
+use crate::capabilities::capabilities;
+use crate::instance::{describe_duplicate_profiles, duplicate_profiles};
+use crate::launch::overlay_only_features;
 use crate::ui::theme;
 use eframe::egui::{self, RichText, Ui};
 use egui_phosphor::regular as icons;
 
 impl Splitux {
-    /// Display controller and audio warnings
+    /// Display shared profile, controller, audio, KB/mouse profile and monitor warnings
     pub(super) fn display_instance_warnings(&self, ui: &mut Ui) {
+        if !self.options.disable_mount_gamedirs && !capabilities().overlay_mounts() {
+            theme::card_frame()
+                .fill(egui::Color32::from_rgb(80, 60, 20))
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new(icons::WARNING).size(16.0));
+                        ui.label(RichText::new("fuse-overlayfs is not available").strong());
+                    });
+                    let needs_overlay = self.active_handler().map(overlay_only_features).unwrap_or_default();
+                    if needs_overlay.is_empty() {
+                        ui.label(
+                            RichText::new("Games will run from their original folder, shared by all players, without mods or per-player game files. Install fuse-overlayfs to fix this.")
+                                .small(),
+                        );
+                    } else {
+                        ui.label("This game can't launch without it, since it uses:");
+                        for feature in &needs_overlay {
+                            ui.label(format!("  \u{2022} {}", feature));
+                        }
+                        ui.label(RichText::new("Install fuse-overlayfs to launch.").small());
+                    }
+                });
+            ui.add_space(4.0);
+        }
+
+        let shared_profiles = duplicate_profiles(&self.instances, &self.profiles);
+        if !shared_profiles.is_empty() {
+            theme::card_frame()
+                .fill(egui::Color32::from_rgb(80, 60, 20))
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new(icons::USERS).size(16.0));
+                        ui.label(RichText::new("Profiles used by more than one player:").strong());
+                    });
+                    for line in describe_duplicate_profiles(&shared_profiles) {
+                        ui.label(format!("  \u{2022} {}", line));
+                    }
+                    ui.label(
+                        RichText::new("These players would overwrite each other's saves. Pick a different profile or a guest.")
+                            .small(),
+                    );
+                });
+            ui.add_space(4.0);
+        }
+
         if !self.controller_warnings.is_empty() {
             theme::card_frame()
                 .fill(egui::Color32::from_rgb(80, 60, 20))
@@ -29,7 +77,7 @@ impl Splitux {
                 .show(ui, |ui| {
                     ui.horizontal(|ui| {
                         ui.label(RichText::new(icons::SPEAKER_SLASH).size(16.0));
-                        ui.label(RichText::new("Missing preferred audio devices:").strong());
+                        ui.label(RichText::new("Missing audio devices:").strong());
                     });
                     for warning in &self.audio_warnings {
                         ui.label(format!("  \u{2022} {}", warning));
@@ -37,5 +85,35 @@ impl Splitux {
                 });
             ui.add_space(4.0);
         }
+
+        if !self.monitor_warnings.is_empty() {
+            theme::card_frame()
+                .fill(egui::Color32::from_rgb(80, 60, 20))
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new(icons::MONITOR).size(16.0));
+                        ui.label(RichText::new("Disconnected monitors:").strong());
+                    });
+                    for warning in &self.monitor_warnings {
+                        ui.label(format!("  \u{2022} {}", warning));
+                    }
+                });
+            ui.add_space(4.0);
+        }
+
+        if !self.gptokeyb_warnings.is_empty() {
+            theme::card_frame()
+                .fill(egui::Color32::from_rgb(80, 60, 20))
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new(icons::KEYBOARD).size(16.0));
+                        ui.label(RichText::new("Missing KB/mouse profiles:").strong());
+                    });
+                    for warning in &self.gptokeyb_warnings {
+                        ui.label(format!("  \u{2022} {}", warning));
+                    }
+                });
+            ui.add_space(4.0);
+        }
     }
 }
@@ -3,7 +3,11 @@
 use super::app::{RegistryFocus, Splitux};
 use crate::ui::theme;
 use crate::handler::scan_handlers;
-use crate::registry::{download_handler, fetch_registry, RegistryEntry};
+use crate::registry::{
+    download_handler_with_progress, fetch_registry, load_cached_registry, reset_handler, DownloadEvent,
+    RegistryEntry, RegistryFilter,
+};
+use crate::util::{msg, yesno};
 use crate::ui::responsive::LayoutMode;
 use eframe::egui::{self, RichText, Ui};
 
@@ -49,6 +53,15 @@ impl Splitux {
         // Clone handlers list for display to avoid borrow issues
         let handlers: Vec<RegistryEntry> = index.handlers.clone();
 
+        if self.options.offline_mode {
+            ui.add_space(4.0);
+            ui.label(
+                RichText::new("Offline mode: showing the cached registry. Installing is disabled.")
+                    .small()
+                    .color(theme::colors::TEXT_MUTED),
+            );
+        }
+
         if handlers.is_empty() {
             ui.add_space(16.0);
             ui.vertical_centered(|ui| {
@@ -59,17 +72,12 @@ impl Splitux {
             return;
         }
 
-        // Filter handlers based on search
-        let search_lower = self.registry_search.to_lowercase();
+        // Filter handlers on search text AND the backend/player chips
+        let backends = index.backends();
         let filtered_handlers: Vec<(usize, &RegistryEntry)> = handlers
             .iter()
             .enumerate()
-            .filter(|(_, h)| {
-                search_lower.is_empty()
-                    || h.name.to_lowercase().contains(&search_lower)
-                    || h.description.to_lowercase().contains(&search_lower)
-                    || h.author.to_lowercase().contains(&search_lower)
-            })
+            .filter(|(_, h)| self.registry_filter.matches(h))
             .collect();
 
         // Calculate available height for the scroll area
@@ -85,11 +93,12 @@ impl Splitux {
             ui.horizontal(|ui| {
                 ui.label("🔍");
                 ui.add(
-                    egui::TextEdit::singleline(&mut self.registry_search)
+                    egui::TextEdit::singleline(&mut self.registry_filter.search)
                         .desired_width(ui.available_width() - 30.0)
                         .hint_text("Filter..."),
                 );
             });
+            self.display_registry_filter_chips(ui, &backends);
             ui.add_space(8.0);
             ui.separator();
             ui.add_space(4.0);
@@ -133,11 +142,12 @@ impl Splitux {
                     ui.horizontal(|ui| {
                         ui.label("Search:");
                         ui.add(
-                            egui::TextEdit::singleline(&mut self.registry_search)
+                            egui::TextEdit::singleline(&mut self.registry_filter.search)
                                 .desired_width(140.0)
                                 .hint_text("Filter..."),
                         );
                     });
+                    self.display_registry_filter_chips(ui, &backends);
                     ui.add_space(8.0);
                     ui.separator();
                     ui.add_space(4.0);
@@ -171,6 +181,38 @@ impl Splitux {
         }
     }
 
+    /// Toggle chips for backend and player count (used by both layouts)
+    ///
+    /// Each group selects at most one chip; clicking the active chip clears it.
+    fn display_registry_filter_chips(&mut self, ui: &mut Ui, backends: &[String]) {
+        if !backends.is_empty() {
+            ui.add_space(4.0);
+            ui.horizontal_wrapped(|ui| {
+                for backend in backends {
+                    let selected = self.registry_filter.backend.as_ref() == Some(backend);
+                    if ui.selectable_label(selected, backend).on_hover_text("LT cycles backends").clicked() {
+                        self.registry_filter.backend = if selected { None } else { Some(backend.clone()) };
+                    }
+                }
+            });
+        }
+
+        ui.add_space(4.0);
+        ui.horizontal_wrapped(|ui| {
+            ui.label(RichText::new("Players:").small().color(theme::colors::TEXT_MUTED));
+            for count in RegistryFilter::PLAYER_CHIPS {
+                let selected = self.registry_filter.min_players == Some(count);
+                if ui
+                    .selectable_label(selected, format!("{}+", count))
+                    .on_hover_text("RT cycles player counts")
+                    .clicked()
+                {
+                    self.registry_filter.min_players = if selected { None } else { Some(count) };
+                }
+            }
+        });
+    }
+
     /// Helper to display the handler list (used by both layouts)
     fn display_registry_handler_list(
         &mut self,
@@ -203,13 +245,15 @@ impl Splitux {
             let frame_resp = frame.show(ui, |ui| {
                 let response = ui.horizontal(|ui| {
                     // Icon from registry CDN
-                    let icon_url = entry.icon_url();
-                    ui.add(
-                        egui::Image::new(&icon_url)
-                            .max_width(18.0)
-                            .corner_radius(3),
-                    );
-                    ui.add_space(4.0);
+                    if !self.options.offline_mode {
+                        let icon_url = entry.icon_url();
+                        ui.add(
+                            egui::Image::new(&icon_url)
+                                .max_width(18.0)
+                                .corner_radius(3),
+                        );
+                        ui.add_space(4.0);
+                    }
 
                     // Name with installed indicator
                     let mut name_text = RichText::new(&entry.name);
@@ -241,14 +285,16 @@ impl Splitux {
         let is_installing = self.registry_installing.as_ref() == Some(&entry.id);
 
         // Header image
-        let header_url = entry.header_url();
-        ui.add(
-            egui::Image::new(&header_url)
-                .max_width(ui.available_width())
-                .max_height(140.0)
-                .corner_radius(4),
-        );
-        ui.add_space(8.0);
+        if !self.options.offline_mode {
+            let header_url = entry.header_url();
+            ui.add(
+                egui::Image::new(&header_url)
+                    .max_width(ui.available_width())
+                    .max_height(140.0)
+                    .corner_radius(4),
+            );
+            ui.add_space(8.0);
+        }
 
         // Name and metadata
         ui.heading(&entry.name);
@@ -262,6 +308,11 @@ impl Splitux {
                 ui.label(RichText::new(format!("Steam: {}", appid)).small().color(theme::colors::TEXT_MUTED));
             }
 
+            if let Some(max_players) = entry.max_players {
+                ui.add_space(8.0);
+                ui.label(RichText::new(format!("Up to {} players", max_players)).small().color(theme::colors::TEXT_MUTED));
+            }
+
             if let Some(backend) = &entry.backend {
                 ui.add_space(8.0);
                 let backend_color = match backend.as_str() {
@@ -290,40 +341,97 @@ impl Splitux {
         // Install/Installed button
         let is_button_focused = self.registry_focus == RegistryFocus::InstallButton;
         ui.horizontal(|ui| {
-            if is_installed {
+            if is_installing {
+                let mut btn = egui::Button::new("Cancel").min_size(egui::vec2(100.0, 32.0));
+                if is_button_focused {
+                    btn = btn.stroke(theme::focus_stroke());
+                }
+                let cancel_btn = ui.add(btn);
+                if cancel_btn.clicked() || (is_button_focused && self.activate_focused) {
+                    self.registry_download_cancel.store(true, std::sync::atomic::Ordering::Relaxed);
+                }
+                ui.add_space(8.0);
+                let bar = match &self.registry_download_progress {
+                    Some(p) => {
+                        let text = match p.total {
+                            Some(total) => format!("{}: {} / {} KB", p.file, p.received / 1024, total.div_ceil(1024)),
+                            None => format!("{}: {} KB", p.file, p.received / 1024),
+                        };
+                        match p.fraction() {
+                            Some(fraction) => egui::ProgressBar::new(fraction).text(text),
+                            None => egui::ProgressBar::new(0.0).animate(true).text(text),
+                        }
+                    }
+                    None => egui::ProgressBar::new(0.0).animate(true).text("Connecting..."),
+                };
+                ui.add(bar.desired_width(ui.available_width()));
+            } else if is_installed {
                 let mut btn = egui::Button::new("Installed").min_size(egui::vec2(100.0, 32.0));
                 if is_button_focused {
                     btn = btn.stroke(theme::focus_stroke());
                 }
                 ui.add_enabled(false, btn);
                 ui.add_space(8.0);
+                if !self.options.offline_mode {
+                    let idle = self.registry_download_rx.is_none();
+                    let reset_btn = ui
+                        .add_enabled(idle, egui::Button::new("Reset to registry").min_size(egui::vec2(100.0, 32.0)))
+                        .on_hover_text("Re-download this handler, discarding local changes");
+                    if reset_btn.clicked()
+                        && yesno(
+                            "Reset Handler?",
+                            &format!(
+                                "This replaces your local {} handler with the registry version. Your changes to it will be lost; your game folder and extra files like icon.png are kept.",
+                                entry.name
+                            ),
+                        )
+                    {
+                        self.reset_registry_handler(entry.clone());
+                    }
+                    ui.add_space(8.0);
+                }
                 ui.label(RichText::new("This handler is already installed").small().color(theme::colors::SUCCESS));
-            } else if is_installing {
-                ui.add_enabled(false, egui::Button::new("Installing...").min_size(egui::vec2(100.0, 32.0)));
+            } else if self.options.offline_mode {
+                ui.add_enabled(false, egui::Button::new("Install").min_size(egui::vec2(100.0, 32.0)));
+                ui.add_space(8.0);
+                ui.label(RichText::new("Unavailable in offline mode").small().color(theme::colors::TEXT_MUTED));
             } else {
                 let mut btn = egui::Button::new("Install").min_size(egui::vec2(100.0, 32.0));
                 if is_button_focused {
                     btn = btn.stroke(theme::focus_stroke());
                 }
-                let install_btn = ui.add(btn);
-                if install_btn.clicked() || (is_button_focused && self.activate_focused) {
+                // One download at a time
+                let idle = self.registry_download_rx.is_none();
+                let install_btn = ui.add_enabled(idle, btn);
+                if install_btn.clicked() || (idle && is_button_focused && self.activate_focused) {
                     self.install_registry_handler(entry.clone());
                 }
             }
         });
     }
 
-    /// Fetch the registry index from GitHub
+    /// Fetch the registry index from GitHub (or the cached copy in offline mode)
     pub fn fetch_registry(&mut self) {
-        self.registry_loading = true;
         self.registry_error = None;
 
+        if self.options.offline_mode {
+            self.registry_index = load_cached_registry();
+            if self.registry_index.is_none() {
+                self.registry_error =
+                    Some("Offline mode is enabled and no cached registry is available.".to_string());
+            }
+            return;
+        }
+
+        self.registry_loading = true;
+
         // We can't use spawn_task because we need to update registry_index
         // Instead, we'll do a blocking fetch in a thread and poll for completion
         let (tx, rx) = std::sync::mpsc::channel();
+        let offline = self.options.offline_mode;
 
         std::thread::spawn(move || {
-            let result = fetch_registry();
+            let result = fetch_registry(offline);
             let _ = tx.send(result);
         });
 
@@ -345,21 +453,99 @@ impl Splitux {
         }
     }
 
+    /// Reset an installed handler to its registry version
+    ///
+    /// Runs like an install: the result arrives through `registry_download_rx`,
+    /// so handlers are rescanned only once the reset has finished.
+    fn reset_registry_handler(&mut self, entry: RegistryEntry) {
+        let (tx, rx) = std::sync::mpsc::channel();
+        self.registry_installing = Some(entry.id.clone());
+        self.registry_download_rx = Some(rx);
+        self.registry_download_progress = None;
+        let offline = self.options.offline_mode;
+
+        std::thread::spawn(move || {
+            let event = match reset_handler(&entry, offline) {
+                Ok(true) => DownloadEvent::Finished,
+                Ok(false) => DownloadEvent::Cancelled,
+                Err(e) => {
+                    log_error!("Failed to reset handler: {}", e);
+                    DownloadEvent::Failed(format!("Failed to reset handler: {}", e))
+                }
+            };
+            let _ = tx.send(event);
+        });
+    }
+
     /// Install a handler from the registry
+    ///
+    /// Downloads on a background thread; progress and the result are picked
+    /// up by `poll_registry_download`, so the page stays usable meanwhile.
     fn install_registry_handler(&mut self, entry: RegistryEntry) {
-        let entry_id = entry.id.clone();
-        self.registry_installing = Some(entry_id.clone());
+        let (tx, rx) = std::sync::mpsc::channel();
+        let cancel = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        self.registry_installing = Some(entry.id.clone());
+        self.registry_download_rx = Some(rx);
+        self.registry_download_progress = None;
+        self.registry_download_cancel = cancel.clone();
+        let offline = self.options.offline_mode;
 
-        self.spawn_task(&format!("Installing {}", entry.name), move || {
-            if let Err(e) = download_handler(&entry) {
-                eprintln!("[splitux] Failed to install handler: {}", e);
-            }
+        std::thread::spawn(move || {
+            let progress_tx = tx.clone();
+            let result = download_handler_with_progress(
+                &entry,
+                offline,
+                |p| {
+                    let _ = progress_tx.send(DownloadEvent::Progress(p));
+                },
+                &cancel,
+            );
+            let event = match result {
+                Ok(true) => DownloadEvent::Finished,
+                Ok(false) => DownloadEvent::Cancelled,
+                Err(_) if cancel.load(std::sync::atomic::Ordering::Relaxed) => DownloadEvent::Cancelled,
+                Err(e) => {
+                    log_error!("Failed to install handler: {}", e);
+                    DownloadEvent::Failed(format!("Failed to install handler: {}", e))
+                }
+            };
+            let _ = tx.send(event);
         });
+    }
 
-        // Refresh handlers list after installation
-        // Note: This happens immediately but the spawn_task is async
-        // The UI will update on next frame after task completes
-        self.handlers = scan_handlers();
+    /// Apply progress and the result of a background registry download
+    pub(crate) fn poll_registry_download(&mut self) {
+        let Some(rx) = &self.registry_download_rx else {
+            return;
+        };
+        let mut finished = None;
+        loop {
+            match rx.try_recv() {
+                Ok(DownloadEvent::Progress(p)) => self.registry_download_progress = Some(p),
+                Ok(event) => {
+                    finished = Some(event);
+                    break;
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => break,
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => {
+                    finished = Some(DownloadEvent::Failed("Download stopped unexpectedly".to_string()));
+                    break;
+                }
+            }
+        }
+        let Some(event) = finished else {
+            return;
+        };
+
+        self.registry_download_rx = None;
+        self.registry_download_progress = None;
         self.registry_installing = None;
+        match event {
+            DownloadEvent::Failed(e) => msg("Download Failed", &e),
+            DownloadEvent::Cancelled => log_info!("Registry download cancelled"),
+            _ => {}
+        }
+        self.handlers = scan_handlers();
+        self.handler_about = None;
     }
 }
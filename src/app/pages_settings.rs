@@ -1,10 +1,10 @@
 //! Settings page display functions
 //!
 //! This module is split into submodules for better organization:
-//! - `general` - General and Gamescope settings (options 0-12)
-//! - `audio` - Audio routing settings (options 13-19)
+//! - `general` - General and Gamescope settings (options 0-12, 22-79)
+//! - `audio` - Audio routing settings (options 13-19, 80-81)
 //! - `profiles` - Profile management (options 20+)
-//! - `devices` - Controller naming
+//! - `devices` - Controller naming and device filter (options 90-92, 200+, 300+)
 //! - `profile_builder` - gptokeyb KB/Mouse Mapper
 
 mod audio;
@@ -23,6 +23,14 @@ impl Splitux {
         self.settings_focus == SettingsFocus::Options && self.settings_option_index == index
     }
 
+    /// -1 or 1 when Left or Right is pressed on a focused settings option, else 0
+    pub(crate) fn settings_option_step(&self, ui: &Ui, index: usize) -> i32 {
+        if !self.is_settings_option_focused(index) {
+            return 0;
+        }
+        ui.input(|i| i.key_pressed(egui::Key::ArrowRight) as i32 - i.key_pressed(egui::Key::ArrowLeft) as i32)
+    }
+
     /// Scroll to focused option only when focus changed (clears the flag after scrolling)
     pub(crate) fn scroll_to_settings_option_if_needed(&mut self, index: usize, response: &egui::Response) {
         if self.settings_scroll_to_focus && self.is_settings_option_focused(index) {
@@ -62,6 +70,18 @@ impl Splitux {
                         ui.label(RichText::new("Gamescope").strong().size(14.0));
                         ui.add_space(4.0);
                         self.display_settings_gamescope(ui);
+                        ui.add_space(16.0);
+                        ui.separator();
+                        ui.add_space(8.0);
+                        ui.label(RichText::new("Pages").strong().size(14.0));
+                        ui.add_space(4.0);
+                        self.display_settings_pages(ui);
+                        ui.add_space(16.0);
+                        ui.separator();
+                        ui.add_space(8.0);
+                        ui.label(RichText::new("Keyboard Navigation").strong().size(14.0));
+                        ui.add_space(4.0);
+                        self.display_settings_nav_bindings(ui);
                     }
                     SettingsCategory::Audio => {
                         self.display_settings_audio(ui);
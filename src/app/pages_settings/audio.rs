@@ -1,7 +1,7 @@
-//! Audio settings section (options 13-19)
+//! Audio settings section (options 13-19 and 80-81)
 
 use crate::app::app::Splitux;
-use crate::audio::{resolve_audio_system, scan_sinks, AudioSystem, AudioSystemPreference};
+use crate::audio::{resolve_audio_system, scan_sinks, AudioSink, AudioSystem, AudioSystemPreference};
 use crate::ui::responsive::LayoutMode;
 use eframe::egui::{self, Ui};
 use egui_phosphor::regular as icons;
@@ -74,6 +74,71 @@ impl Splitux {
         });
         self.scroll_to_settings_option_if_needed(15, &r.response);
 
+        // Option 80: Per-player sinks
+        let r = self.settings_option_frame(80).show(ui, |ui| {
+            let check = ui.add_enabled(
+                self.options.audio.enabled,
+                egui::Checkbox::new(&mut self.options.audio.player_sinks, "Create a sink per player"),
+            );
+            if check.hovered() || self.is_settings_option_focused(80) {
+                self.infotext = "DEFAULT: Disabled\n\nRequires \"Enable per-instance audio routing\" above.\n\nInstances without an assigned output get their own virtual sink named after the player (e.g. \"Splitux P1 (Alice)\") instead of the default output. The sinks play nowhere until routed in a mixer such as pavucontrol or qpwgraph, and are removed when the session ends.".to_string();
+            }
+            if self.is_settings_option_focused(80) && self.activate_focused && self.options.audio.enabled {
+                self.options.audio.player_sinks = !self.options.audio.player_sinks;
+            }
+        });
+        self.scroll_to_settings_option_if_needed(80, &r.response);
+
+        // Option 81: Guest default output (Left/Right cycles through the outputs)
+        let step = self.settings_option_step(ui, 81);
+        if step != 0 {
+            let choices: Vec<Option<&AudioSink>> =
+                std::iter::once(None).chain(self.audio_devices.iter().map(Some)).collect();
+            let pos = choices
+                .iter()
+                .position(|d| d.map(|d| &d.name) == self.options.audio.guest_sink.as_ref())
+                .unwrap_or(0);
+            let next = choices[(pos as i32 + step).rem_euclid(choices.len() as i32) as usize];
+            self.options.audio.guest_sink = next.map(|d| d.name.clone());
+            self.options.audio.guest_sink_description = next.map(|d| d.description.clone());
+        }
+        let r = self.settings_option_frame(81).show(ui, |ui| {
+            ui.horizontal(|ui| {
+                let label = ui.label("Guest default output:");
+                let current = self.options.audio.guest_sink.clone();
+                let current_label = current
+                    .as_deref()
+                    .map(|name| {
+                        let description = self.options.audio.guest_sink_description.as_deref();
+                        self.resolve_audio_sink(name, description)
+                            .map(|d| d.description.clone())
+                            .or_else(|| description.map(str::to_string))
+                            .unwrap_or_else(|| name.to_string())
+                    })
+                    .unwrap_or_else(|| "System default".to_string());
+
+                let combo = egui::ComboBox::from_id_salt("audio_guest_default")
+                    .selected_text(&current_label)
+                    .show_ui(ui, |ui| {
+                        if ui.selectable_label(current.is_none(), "System default").clicked() {
+                            self.options.audio.guest_sink = None;
+                            self.options.audio.guest_sink_description = None;
+                        }
+                        for device in &self.audio_devices {
+                            let is_selected = current.as_ref() == Some(&device.name);
+                            if ui.selectable_label(is_selected, &device.description).clicked() {
+                                self.options.audio.guest_sink = Some(device.name.clone());
+                                self.options.audio.guest_sink_description = Some(device.description.clone());
+                            }
+                        }
+                    });
+                if label.hovered() || combo.response.hovered() || self.is_settings_option_focused(81) {
+                    self.infotext = "DEFAULT: System default\n\nOutput for guests and for profiles without a preferred audio device. A profile's preference and a session override on the instance page both take priority. Left and Right pick an output.".to_string();
+                }
+            });
+        });
+        self.scroll_to_settings_option_if_needed(81, &r.response);
+
         ui.add_space(8.0);
 
         // Show available audio devices (read-only list, not navigable)
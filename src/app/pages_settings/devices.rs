@@ -1,14 +1,38 @@
-//! Devices/Controllers settings section
+//! Devices/Controllers settings section (options 90-92, 200+, 300+)
 
 use crate::app::app::Splitux;
+use crate::input::{device_id_rule, export_aliases, merge_aliases, read_alias_file, DeviceRule, DeviceType};
+use crate::paths::PATH_HOME;
 use crate::ui::theme;
+use crate::util::msg;
 use eframe::egui::{self, RichText, Ui};
 use egui_phosphor::regular as icons;
+use rfd::FileDialog;
 use std::collections::HashSet;
+use std::path::Path;
 
 impl Splitux {
     pub fn display_settings_devices(&mut self, ui: &mut Ui) {
-        ui.label("Assign custom names to your controllers for easy identification.");
+        ui.label("Assign custom names to your controllers for easy identification, or an SDL mapping for pads games misdetect.");
+        ui.horizontal(|ui| {
+            // Options 90-91: Export and import names
+            let export = self.settings_option_frame(90).show(ui, |ui| {
+                ui.button(format!("{} Export names", icons::EXPORT))
+                    .on_hover_text("Save all controller names to a file")
+            });
+            if export.inner.clicked() || (self.is_settings_option_focused(90) && self.activate_focused) {
+                self.export_device_aliases();
+            }
+            self.scroll_to_settings_option_if_needed(90, &export.response);
+            let import = self.settings_option_frame(91).show(ui, |ui| {
+                ui.button(format!("{} Import names", icons::DOWNLOAD_SIMPLE))
+                    .on_hover_text("Add controller names from a file, keeping existing ones")
+            });
+            if import.inner.clicked() || (self.is_settings_option_focused(91) && self.activate_focused) {
+                self.import_device_aliases();
+            }
+            self.scroll_to_settings_option_if_needed(91, &import.response);
+        });
         ui.add_space(8.0);
 
         // Collect connected gamepad info (avoiding borrow issues)
@@ -75,7 +99,10 @@ impl Splitux {
             // Pre-compute display names
             let display_names = self.device_display_names.clone();
 
-            for gp in gamepads {
+            // Options 100+: Connected gamepads (listed first), A picks an SDL mapping
+            for (k, gp) in gamepads.into_iter().enumerate() {
+                let option = 100 + k;
+                let focused = gp.is_online && self.is_settings_option_focused(option);
                 let current_alias = self.options.device_aliases.get(&gp.uniq).cloned();
                 let display_name = if let Some(idx) = gp.idx {
                     display_names.get(idx).cloned().unwrap_or_else(|| gp.hw_name.clone())
@@ -84,13 +111,15 @@ impl Splitux {
                 };
                 let is_renaming = gp.idx.is_some() && self.device_rename_index == gp.idx;
 
-                let frame = if gp.is_online {
+                let frame = if focused {
+                    theme::card_frame().stroke(theme::focus_stroke())
+                } else if gp.is_online {
                     theme::card_frame()
                 } else {
                     theme::card_frame().fill(theme::colors::BG_DARK)
                 };
 
-                frame.show(ui, |ui| {
+                let row = frame.show(ui, |ui| {
                     ui.horizontal(|ui| {
                         if is_renaming {
                             // Rename mode
@@ -142,10 +171,42 @@ impl Splitux {
                                         };
                                         if ui.button(btn_text).on_hover_text(hover).clicked() {
                                             self.options.device_aliases.remove(&gp.uniq);
+                                            if !gp.is_online {
+                                                self.options.controller_mappings.remove(&gp.uniq);
+                                            }
                                             self.refresh_device_display_names();
                                         }
                                     }
 
+                                    // SDL mapping file (only for online devices)
+                                    if gp.is_online {
+                                        let mapping = self.options.controller_mappings.get(&gp.uniq).cloned();
+                                        let (text, hover) = match &mapping {
+                                            Some(path) => (
+                                                format!(
+                                                    "{} {}",
+                                                    icons::FILE_TEXT,
+                                                    Path::new(path)
+                                                        .file_name()
+                                                        .map(|n| n.to_string_lossy().to_string())
+                                                        .unwrap_or_else(|| path.clone())
+                                                ),
+                                                format!("SDL mapping: {}\n\nClick to change, right-click to remove", path),
+                                            ),
+                                            None => (
+                                                format!("{} Mapping", icons::FILE_TEXT),
+                                                "Pick a gamecontrollerdb.txt with an SDL mapping for this controller, for pads games misdetect".to_string(),
+                                            ),
+                                        };
+                                        let response = ui.button(text).on_hover_text(hover);
+                                        if response.clicked() || (focused && self.activate_focused) {
+                                            self.pick_controller_mapping(&gp.uniq);
+                                        }
+                                        if response.secondary_clicked() {
+                                            self.options.controller_mappings.remove(&gp.uniq);
+                                        }
+                                    }
+
                                     // Rename button (only for online devices)
                                     if gp.is_online {
                                         if ui.button("Rename").clicked() {
@@ -168,6 +229,9 @@ impl Splitux {
                         }
                     });
                 });
+                if gp.is_online {
+                    self.scroll_to_settings_option_if_needed(option, &row.response);
+                }
                 ui.add_space(4.0);
             }
         }
@@ -178,5 +242,208 @@ impl Splitux {
                 .weak()
                 .small(),
         );
+
+        ui.add_space(8.0);
+        ui.separator();
+        self.display_device_filter_lists(ui);
+    }
+
+    /// Allow and block lists for devices that shouldn't show up (e.g. a
+    /// flight throttle detected as a gamepad)
+    fn display_device_filter_lists(&mut self, ui: &mut Ui) {
+        ui.label(RichText::new("Device Filter").strong());
+        ui.label(
+            RichText::new("Hide devices that aren't controllers, or list only specific gamepads. Entries are a USB vendor:product like 044f:b10a, a whole vendor like 044f:*, or a regex matched against the device name.")
+                .weak()
+                .small(),
+        );
+        ui.add_space(4.0);
+
+        let mut changed = false;
+
+        // One-click hiding of connected gamepads
+        let gamepads: Vec<(String, String)> = self
+            .input_devices
+            .iter()
+            .filter(|d| d.device_type() == DeviceType::Gamepad)
+            .map(|d| {
+                let (vendor, product) = d.usb_id();
+                (format!("{} {}", d.emoji(), d.fancyname()), device_id_rule(vendor, product))
+            })
+            .collect();
+        // Options 200+: Hide a connected gamepad
+        for (i, (name, rule)) in gamepads.into_iter().enumerate() {
+            let option = 200 + i;
+            let activate = self.is_settings_option_focused(option) && self.activate_focused;
+            let r = self.settings_option_frame(option).show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(name);
+                    ui.label(RichText::new(&rule).weak().monospace());
+                    if (ui
+                        .small_button(format!("{} Hide", icons::EYE_SLASH))
+                        .on_hover_text("Add this device to the block list")
+                        .clicked()
+                        || activate)
+                        && !self.options.device_filter.block.contains(&rule)
+                    {
+                        self.options.device_filter.block.push(rule.clone());
+                        changed = true;
+                    }
+                });
+            });
+            self.scroll_to_settings_option_if_needed(option, &r.response);
+        }
+
+        // Options 300+: Current entries, block list first
+        let mut remove: Option<(bool, usize)> = None;
+        let block_len = self.options.device_filter.block.len();
+        let entries: Vec<(bool, usize, String)> = self
+            .options
+            .device_filter
+            .block
+            .iter()
+            .enumerate()
+            .map(|(i, rule)| (true, i, rule.clone()))
+            .chain(self.options.device_filter.allow.iter().enumerate().map(|(i, rule)| (false, i, rule.clone())))
+            .collect();
+        for (is_block, i, rule) in entries {
+            let option = 300 + if is_block { i } else { block_len + i };
+            let activate = self.is_settings_option_focused(option) && self.activate_focused;
+            let r = self.settings_option_frame(option).show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    let kind = if is_block { "Blocked" } else { "Allowed" };
+                    ui.label(format!("{}:", kind));
+                    ui.label(RichText::new(&rule).monospace());
+                    if ui.small_button(icons::X).on_hover_text("Remove this entry").clicked() || activate {
+                        remove = Some((is_block, i));
+                    }
+                });
+            });
+            self.scroll_to_settings_option_if_needed(option, &r.response);
+        }
+        if let Some((is_block, i)) = remove {
+            match is_block {
+                true => self.options.device_filter.block.remove(i),
+                false => self.options.device_filter.allow.remove(i),
+            };
+            changed = true;
+        }
+
+        // Option 92: Manual entry (Left/Right picks Block or Allow)
+        let step = self.settings_option_step(ui, 92);
+        let cursor = self.is_settings_option_focused(92).then(|| {
+            self.settings_sub_index = self.settings_sub_index.saturating_add_signed(step as isize).min(1);
+            self.settings_sub_index
+        });
+        let activate = self.activate_focused;
+        let r = self.settings_option_frame(92).show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.device_rule_buffer)
+                        .desired_width(180.0)
+                        .hint_text("044f:b10a or name regex"),
+                );
+                let mut block_btn = egui::Button::new("Block");
+                let mut allow_btn = egui::Button::new("Allow");
+                match cursor {
+                    Some(0) => block_btn = block_btn.stroke(theme::focus_stroke()),
+                    Some(_) => allow_btn = allow_btn.stroke(theme::focus_stroke()),
+                    None => {}
+                }
+                let block = ui.add(block_btn).on_hover_text("Never list matching devices").clicked()
+                    || (cursor == Some(0) && activate);
+                let allow = ui
+                    .add(allow_btn)
+                    .on_hover_text("Once any entry is allowed, only matching gamepads are listed")
+                    .clicked()
+                    || (cursor == Some(1) && activate);
+                if block || allow {
+                    let rule = self.device_rule_buffer.trim().to_string();
+                    match DeviceRule::parse(&rule) {
+                        Ok(_) => {
+                            let list = match block {
+                                true => &mut self.options.device_filter.block,
+                                false => &mut self.options.device_filter.allow,
+                            };
+                            if !list.contains(&rule) {
+                                list.push(rule);
+                                changed = true;
+                            }
+                            self.device_rule_buffer.clear();
+                        }
+                        Err(e) => msg("Invalid entry", &e),
+                    }
+                }
+            });
+        });
+        self.scroll_to_settings_option_if_needed(92, &r.response);
+
+        if changed {
+            self.apply_device_filter();
+        }
+    }
+
+    /// Choose the SDL mapping file games use for a controller
+    fn pick_controller_mapping(&mut self, uniq: &str) {
+        let Some(file) = FileDialog::new()
+            .set_title("Select gamecontrollerdb.txt")
+            .set_directory(&*PATH_HOME)
+            .add_filter("SDL mappings", &["txt"])
+            .pick_file()
+        else {
+            return;
+        };
+        self.options
+            .controller_mappings
+            .insert(uniq.to_string(), file.to_string_lossy().to_string());
+    }
+
+    /// Export saved controller names to a user-chosen JSON file
+    fn export_device_aliases(&mut self) {
+        if self.options.device_aliases.is_empty() {
+            msg("Export names", "No controller names to export.");
+            return;
+        }
+        let Some(file) = FileDialog::new()
+            .set_title("Save controller names to:")
+            .set_directory(&*PATH_HOME)
+            .set_file_name("splitux-controller-names.json")
+            .add_filter("JSON", &["json"])
+            .save_file()
+        else {
+            return;
+        };
+        if let Err(e) = export_aliases(&file, &self.options.device_aliases) {
+            msg("Error", &format!("Couldn't export controller names: {}", e));
+        }
+    }
+
+    /// Merge controller names from a JSON file into the saved names
+    fn import_device_aliases(&mut self) {
+        let Some(file) = FileDialog::new()
+            .set_title("Select File")
+            .set_directory(&*PATH_HOME)
+            .add_filter("JSON", &["json"])
+            .pick_file()
+        else {
+            return;
+        };
+        let imported = match read_alias_file(&file) {
+            Ok(imported) => imported,
+            Err(e) => {
+                msg("Error", &format!("Couldn't import controller names: {}", e));
+                return;
+            }
+        };
+
+        let connected: Vec<&str> = self
+            .input_devices
+            .iter()
+            .map(|d| d.uniq())
+            .filter(|u| !u.is_empty())
+            .collect();
+        let applied = merge_aliases(&mut self.options.device_aliases, imported, &connected);
+        self.refresh_device_display_names();
+        log_info!("Imported {} controller names from {}", applied, file.display());
     }
 }
@@ -1,14 +1,22 @@
-//! General settings section (options 0-12)
+//! General settings section (options 0-12 and 22-79)
 //!
 //! Includes: Window Manager, Controller filter, Proton settings, Photon networking, Gamescope
 
 use crate::app::app::Splitux;
-use crate::config::{PadFilterType, WindowManagerType};
+use crate::capabilities::capabilities;
+use crate::config::{
+    ComboButton, KillCombo, NavAction, NavBindings, PadFilterType, SplituxConfig, StartFullscreen,
+    WindowManagerType,
+};
+use crate::diagnostics::{describe_device, describe_monitor, export_diagnostics};
 use crate::input::scan_input_devices;
-use crate::paths::PATH_PARTY;
+use crate::paths::{PATH_HOME, PATH_PARTY, path_tmp};
 use crate::ui::responsive::LayoutMode;
-use crate::util::{msg, yesno};
+use crate::ui::theme;
+use crate::util::{dir_dialog, msg, yesno};
 use eframe::egui::{self, RichText, Ui};
+use egui_phosphor::regular as icons;
+use rfd::FileDialog;
 
 impl Splitux {
     pub fn display_settings_general(&mut self, ui: &mut Ui) {
@@ -62,7 +70,7 @@ impl Splitux {
             }
 
             if r1.clicked() || r2.clicked() || r3.clicked() {
-                self.input_devices = scan_input_devices(&self.options.pad_filter_type);
+                self.input_devices = scan_input_devices(&self.options.pad_filter_type, &self.options.device_filter);
                 self.refresh_device_display_names();
             }
         });
@@ -76,7 +84,7 @@ impl Splitux {
                     egui::TextEdit::singleline(&mut self.options.proton_version).hint_text("GE-Proton"),
                 );
                 if proton_ver_label.hovered() || proton_ver_editbox.hovered() || self.is_settings_option_focused(2) {
-                    self.infotext = "DEFAULT: GE-Proton\n\nSpecify a Proton version.".to_string();
+                    self.infotext = "DEFAULT: GE-Proton\n\nSpecify a Proton version. When empty, Steam games use the compatibility tool Steam is set to use for them, falling back to GE-Proton.".to_string();
                 }
             });
         });
@@ -118,6 +126,172 @@ impl Splitux {
         });
         self.scroll_to_settings_option_if_needed(5, &r.response);
 
+        // Option 22: Parallel launch preparation
+        let r = self.settings_option_frame(22).show(ui, |ui| {
+            let check = ui.checkbox(&mut self.options.parallel_prepare, "Prepare instances in parallel");
+            if check.hovered() || self.is_settings_option_focused(22) {
+                self.infotext = "DEFAULT: Disabled\n\nSets up profiles, Proton prefixes, saves, and game mounts for all instances at once. Games still start one at a time.".to_string();
+            }
+            if self.is_settings_option_focused(22) && self.activate_focused {
+                self.options.parallel_prepare = !self.options.parallel_prepare;
+            }
+        });
+        self.scroll_to_settings_option_if_needed(22, &r.response);
+
+        // Option 23: Save backup rotation (Left/Right adjusts)
+        let step = self.settings_option_step(ui, 23);
+        let r = self.settings_option_frame(23).show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Save backups to keep per game:");
+                let keep = &mut self.options.save_backup_keep;
+                *keep = keep.saturating_add_signed(step as isize).clamp(1, 50);
+                let drag = ui.add(egui::DragValue::new(keep).range(1..=50));
+                if drag.hovered() || self.is_settings_option_focused(23) {
+                    self.infotext = "DEFAULT: 5\n\nSaves are snapshotted before every sync overwrites them. Only this many of the newest snapshots are kept; they can be restored from the game page.".to_string();
+                }
+            });
+        });
+        self.scroll_to_settings_option_if_needed(23, &r.response);
+
+        // Option 24: Steam overlay/input scrubbing for native games
+        let r = self.settings_option_frame(24).show(ui, |ui| {
+            let check = ui.checkbox(&mut self.options.disable_steam_overlay, "Disable Steam overlay for native games");
+            if check.hovered() || self.is_settings_option_focused(24) {
+                self.infotext = "DEFAULT: Disabled\n\nLaunches native Linux games without the Steam overlay and Steam Input, even when Steam is running. Fixes doubled input and overlay glitches. Can also be enabled per handler.".to_string();
+            }
+            if self.is_settings_option_focused(24) && self.activate_focused {
+                self.options.disable_steam_overlay = !self.options.disable_steam_overlay;
+            }
+        });
+        self.scroll_to_settings_option_if_needed(24, &r.response);
+
+        // Option 25: Offline mode
+        let r = self.settings_option_frame(25).show(ui, |ui| {
+            let check = ui.checkbox(&mut self.options.offline_mode, "Offline mode");
+            if check.hovered() || self.is_settings_option_focused(25) {
+                self.infotext = "DEFAULT: Disabled\n\nNever uses the network: skips the update check, shows the last downloaded handler registry instead of fetching it, and only uses plugins that are already cached.".to_string();
+            }
+            if self.is_settings_option_focused(25) && self.activate_focused {
+                self.options.offline_mode = !self.options.offline_mode;
+            }
+        });
+        self.scroll_to_settings_option_if_needed(25, &r.response);
+
+        // Option 26: Scratch directory location (A browses; checked on save)
+        let r = self.settings_option_frame(26).show(ui, |ui| {
+            ui.horizontal(|ui| {
+                let label = ui.label("Scratch directory");
+                let mut dir = self.options.tmp_dir.clone().unwrap_or_default();
+                let hint = path_tmp().to_string_lossy().to_string();
+                let edit = ui.add(egui::TextEdit::singleline(&mut dir).hint_text(hint));
+                let browse = ui.button("...");
+                if (browse.clicked() || (self.is_settings_option_focused(26) && self.activate_focused))
+                    && let Ok(path) = dir_dialog()
+                {
+                    dir = path.to_string_lossy().to_string();
+                }
+                self.options.tmp_dir = (!dir.trim().is_empty()).then_some(dir);
+                if label.hovered() || edit.hovered() || browse.hovered() || self.is_settings_option_focused(26) {
+                    self.infotext = "DEFAULT: Empty (the Splitux data folder)\n\nWhere game overlay mounts, their work dirs, and other temporary files go. A splitux-tmp folder is created inside the chosen location. Pick a folder on the same drive as your games to keep overlay mounts fast.".to_string();
+                }
+            });
+        });
+        self.scroll_to_settings_option_if_needed(26, &r.response);
+
+        // Option 27: Launcher fullscreen at startup (A or Left/Right cycles; applies on next start)
+        let step = self.settings_option_step(ui, 27);
+        let r = self.settings_option_frame(27).show(ui, |ui| {
+            const MODES: [StartFullscreen; 3] = [StartFullscreen::Auto, StartFullscreen::Always, StartFullscreen::Never];
+            let step = if self.is_settings_option_focused(27) && self.activate_focused { 1 } else { step };
+            if step != 0 {
+                let pos = MODES.iter().position(|&m| m == self.options.start_fullscreen).unwrap_or(0);
+                self.options.start_fullscreen = MODES[(pos as i32 + step).rem_euclid(MODES.len() as i32) as usize];
+            }
+            ui.horizontal_wrapped(|ui| {
+                let label = ui.label("Start fullscreen");
+                let r1 = ui.radio_value(&mut self.options.start_fullscreen, StartFullscreen::Auto, "Auto");
+                let r2 = ui.radio_value(&mut self.options.start_fullscreen, StartFullscreen::Always, "Always");
+                let r3 = ui.radio_value(&mut self.options.start_fullscreen, StartFullscreen::Never, "Never");
+                if label.hovered() || r1.hovered() || r2.hovered() || r3.hovered() || self.is_settings_option_focused(27) {
+                    self.infotext = "DEFAULT: Auto\n\nStart the launcher fullscreen without passing --fullscreen. Auto goes fullscreen inside a gamescope session (Steam Deck game mode, SteamOS) or when a single 4K display is connected. Takes effect the next time Splitux starts.".to_string();
+                }
+            });
+        });
+        self.scroll_to_settings_option_if_needed(27, &r.response);
+
+        // Options 28-30: Kill-session controller combo
+        if self.display_combo_setting(ui, 28, "End session with a controller combo", |o| &mut o.kill_combo) {
+            self.infotext = "DEFAULT: Disabled (Start+Select, 2s)\n\nHolding the selected buttons together on any player's controller kills all game instances and ends the session, as a way out when a game hangs or grabs the screen. Use at least two buttons a game won't ask you to hold together.".to_string();
+        }
+
+        // Options 31-33: Summon-launcher controller combo
+        if self.display_combo_setting(ui, 31, "Summon launcher with a controller combo", |o| &mut o.summon_combo) {
+            self.infotext = "DEFAULT: Disabled (Select+LB, 1s)\n\nHolding the selected buttons together on any player's controller brings the Splitux window above the games (on KWin and Hyprland) until the session ends, so its End session button can be reached. Press A or Start there to end the session.".to_string();
+        }
+
+        // Options 34-36: Launcher stick navigation (Left/Right adjusts)
+        let mut hovered = false;
+        let step = self.settings_option_step(ui, 34);
+        let r = self.settings_option_frame(34).show(ui, |ui| {
+            ui.horizontal(|ui| {
+                let nav = &mut self.options.stick_nav;
+                nav.deadzone = nav.deadzone.saturating_add_signed(5 * step as i8).clamp(5, 95);
+                hovered |= ui.label("Stick navigation deadzone").hovered();
+                hovered |= ui.add(egui::DragValue::new(&mut nav.deadzone).range(5..=95).suffix("%")).hovered();
+            });
+        });
+        self.scroll_to_settings_option_if_needed(34, &r.response);
+        let step = self.settings_option_step(ui, 35);
+        let r = self.settings_option_frame(35).show(ui, |ui| {
+            ui.horizontal(|ui| {
+                let nav = &mut self.options.stick_nav;
+                nav.repeat_delay_ms = nav.repeat_delay_ms.saturating_add_signed(50 * step).clamp(100, 1000);
+                hovered |= ui.label("Repeat after").hovered();
+                hovered |= ui.add(egui::DragValue::new(&mut nav.repeat_delay_ms).range(100..=1000).speed(5).suffix(" ms")).hovered();
+            });
+        });
+        self.scroll_to_settings_option_if_needed(35, &r.response);
+        let step = self.settings_option_step(ui, 36);
+        let r = self.settings_option_frame(36).show(ui, |ui| {
+            ui.horizontal(|ui| {
+                let nav = &mut self.options.stick_nav;
+                nav.repeat_rate_ms = nav.repeat_rate_ms.saturating_add_signed(10 * step).clamp(20, 500);
+                hovered |= ui.label("then every").hovered();
+                hovered |= ui.add(egui::DragValue::new(&mut nav.repeat_rate_ms).range(20..=500).speed(2).suffix(" ms")).hovered();
+            });
+        });
+        self.scroll_to_settings_option_if_needed(36, &r.response);
+        if hovered || (34..=36).any(|i| self.is_settings_option_focused(i)) {
+            self.infotext = "DEFAULT: 50%, repeat after 300 ms, then every 80 ms\n\nHow far a controller's left stick must be pushed to move through the launcher, and how holding it repeats like a held key. Raise the deadzone if a drifting stick moves on its own. Only affects launcher navigation, not games.".to_string();
+        }
+
+        // Option 37: Keep the launcher reachable during a session
+        let r = self.settings_option_frame(37).show(ui, |ui| {
+            let check = ui.checkbox(&mut self.options.keep_launcher_above, "Keep launcher above games");
+            if check.hovered() || self.is_settings_option_focused(37) {
+                self.infotext = "DEFAULT: Disabled\n\nWhile a game runs, keeps the Splitux window on top of the game windows (on KWin and Hyprland), so it stays reachable if a game crashes to the desktop or hangs. The launcher shows an End session button while a game runs; Enter, or A or Start on a controller, presses it. Resize the launcher to a small window so it doesn't cover the games.".to_string();
+            }
+            if self.is_settings_option_focused(37) && self.activate_focused {
+                self.options.keep_launcher_above = !self.options.keep_launcher_above;
+            }
+        });
+        self.scroll_to_settings_option_if_needed(37, &r.response);
+
+        // Option 38: Session status socket for external tools
+        let r = self.settings_option_frame(38).show(ui, |ui| {
+            let check = ui.checkbox(&mut self.options.ipc_socket_enabled, "Expose session status socket");
+            if check.hovered() || self.is_settings_option_focused(38) {
+                self.infotext = format!(
+                    "DEFAULT: Disabled\n\nWhile a game runs, serves the state of each instance (profile, devices, monitor, PID, running) as JSON on a Unix socket at {}. Send \"status\" followed by a newline to query it.",
+                    PATH_PARTY.join("session.sock").display()
+                );
+            }
+            if self.is_settings_option_focused(38) && self.activate_focused {
+                self.options.ipc_socket_enabled = !self.options.ipc_socket_enabled;
+            }
+        });
+        self.scroll_to_settings_option_if_needed(38, &r.response);
+
         ui.separator();
 
         // Option 6: Erase Proton Prefix Data
@@ -146,6 +320,87 @@ impl Splitux {
         });
         self.scroll_to_settings_option_if_needed(7, &r.response);
 
+        // Option 39: Diagnostics bundle for bug reports
+        let r = self.settings_option_frame(39).show(ui, |ui| {
+            let btn = ui.button(format!("{} Export Diagnostics", icons::FILE_ZIP));
+            if btn.hovered() || self.is_settings_option_focused(39) {
+                self.infotext = "Saves recent logs, settings (Photon App IDs removed), monitors, input devices and the selected game's handler and resolved paths into a zip to attach to a bug report.".to_string();
+            }
+            if (btn.clicked() || (self.is_settings_option_focused(39) && self.activate_focused))
+                && let Some(dest) = FileDialog::new()
+                    .set_title("Save diagnostics to:")
+                    .set_directory(&*PATH_HOME)
+                    .set_file_name("splitux-diagnostics.zip")
+                    .add_filter("Zip archive", &["zip"])
+                    .save_file()
+            {
+                let handler = self.active_handler().cloned();
+                let cfg = self.options.clone();
+                let monitors: Vec<String> = self.monitors.iter().map(describe_monitor).collect();
+                let devices: Vec<String> = self.input_devices.iter().map(describe_device).collect();
+                self.spawn_task("Exporting diagnostics", move || {
+                    match export_diagnostics(&dest, handler.as_ref(), &cfg, &monitors, &devices) {
+                        Ok(path) => msg("Diagnostics Exported", &format!("Saved to {}", path.display())),
+                        Err(e) => {
+                            log_error!("Diagnostics export failed: {}", e);
+                            msg("Export Failed", &format!("Couldn't export diagnostics: {}", e));
+                        }
+                    }
+                });
+            }
+        });
+        self.scroll_to_settings_option_if_needed(39, &r.response);
+
+        // Option 40: Goldberg data repair
+        let r = self.settings_option_frame(40).show(ui, |ui| {
+            let btn = ui.button("Repair Goldberg Data");
+            if btn.hovered() || self.is_settings_option_focused(40) {
+                self.infotext = "Checks the Goldberg settings and emulator DLLs that Steam games need for LAN play, recreating or re-downloading any that are missing. Try this when games launch but can't see each other.".to_string();
+            }
+            if btn.clicked() || (self.is_settings_option_focused(40) && self.activate_focused) {
+                let offline = self.options.offline_mode;
+                self.spawn_task("Repairing Goldberg data", move || {
+                    match crate::backend::goldberg::verify_goldberg_data(offline) {
+                        Ok(repairs) if repairs.is_empty() => {
+                            msg("Goldberg Data", "All Goldberg files are present.");
+                        }
+                        Ok(repairs) => {
+                            msg("Goldberg Data Repaired", &repairs.join("\n"));
+                        }
+                        Err(e) => {
+                            log_error!("Goldberg repair failed: {}", e);
+                            msg("Repair Failed", &e);
+                        }
+                    }
+                });
+            }
+        });
+        self.scroll_to_settings_option_if_needed(40, &r.response);
+
+        // Option 41: Host tools Splitux depends on (info only; probed at startup)
+        let r = self.settings_option_frame(41).show(ui, |ui| {
+            let mut hovered = ui.label("System tools").hovered();
+            for line in capabilities().report() {
+                let (icon, color) = if line.available {
+                    (icons::CHECK_CIRCLE, theme::colors::SUCCESS)
+                } else {
+                    (icons::X_CIRCLE, theme::colors::ERROR)
+                };
+                let row = ui.horizontal(|ui| {
+                    ui.label(RichText::new(icon).color(color));
+                    ui.label(line.name);
+                    if !line.available {
+                        ui.label(RichText::new(format!("missing: {}", line.effect)).small().color(theme::colors::TEXT_MUTED));
+                    }
+                });
+                hovered |= row.response.hovered();
+            }
+            if hovered || self.is_settings_option_focused(41) {
+                self.infotext = "Programs Splitux runs games with, checked when Splitux starts. Install missing ones through your distro's package manager and restart Splitux.".to_string();
+            }
+        });
+        self.scroll_to_settings_option_if_needed(41, &r.response);
+
         // Photon Networking section
         ui.add_space(8.0);
         ui.separator();
@@ -214,7 +469,7 @@ impl Splitux {
         let r = self.settings_option_frame(12).show(ui, |ui| {
             let check = ui.checkbox(&mut self.options.gamescope_force_grab_cursor, "Force grab cursor for Gamescope");
             if check.hovered() || self.is_settings_option_focused(12) {
-                self.infotext = "Keeps the cursor within the Gamescope window.".to_string();
+                self.infotext = "Keeps the cursor within the Gamescope window.\n\nPlayers can override this on the instance page, e.g. to grab only for the keyboard/mouse player.".to_string();
             }
             if self.is_settings_option_focused(12) && self.activate_focused {
                 self.options.gamescope_force_grab_cursor = !self.options.gamescope_force_grab_cursor;
@@ -222,4 +477,208 @@ impl Splitux {
         });
         self.scroll_to_settings_option_if_needed(12, &r.response);
     }
+
+    /// Top-bar page order and visibility (options 60+; A shows or hides a
+    /// page, Left/Right moves it; changes apply immediately)
+    pub fn display_settings_pages(&mut self, ui: &mut Ui) {
+        let count = self.options.layout.page_tabs.len();
+        let mut move_up: Option<usize> = None;
+        let mut focus_moved: Option<usize> = None;
+
+        for idx in 0..count {
+            let option = 60 + idx;
+            let focused = self.is_settings_option_focused(option);
+            let activate = focused && self.activate_focused;
+            let step = self.settings_option_step(ui, option);
+            let r = self.settings_option_frame(option).show(ui, |ui| {
+                let entry = &mut self.options.layout.page_tabs[idx];
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(idx > 0, egui::Button::new(icons::ARROW_UP).small())
+                        .on_hover_text("Move left in the top bar")
+                        .clicked()
+                        || (step < 0 && idx > 0)
+                    {
+                        move_up = Some(idx);
+                        focus_moved = step.is_negative().then_some(idx - 1);
+                    }
+                    if ui
+                        .add_enabled(idx + 1 < count, egui::Button::new(icons::ARROW_DOWN).small())
+                        .on_hover_text("Move right in the top bar")
+                        .clicked()
+                        || (step > 0 && idx + 1 < count)
+                    {
+                        move_up = Some(idx + 1);
+                        focus_moved = step.is_positive().then_some(idx + 1);
+                    }
+                    let label = entry.page.label();
+                    if entry.page.can_hide() {
+                        ui.checkbox(&mut entry.visible, label);
+                        if activate {
+                            entry.visible = !entry.visible;
+                        }
+                    } else {
+                        ui.add_enabled(false, egui::Checkbox::new(&mut entry.visible, label))
+                            .on_disabled_hover_text("This page can't be hidden");
+                    }
+                });
+            });
+            self.scroll_to_settings_option_if_needed(option, &r.response);
+            if focused {
+                self.infotext = "Order and visibility of the pages in the top bar. A shows or hides the page, Left and Right move it. Games and Settings can't be hidden.".to_string();
+            }
+        }
+
+        if let Some(idx) = move_up {
+            self.options.layout.page_tabs.swap(idx - 1, idx);
+        }
+        // Keep focus on the page that was moved
+        if let Some(idx) = focus_moved {
+            self.settings_option_index = 60 + idx;
+        }
+
+        // Leave a hidden page so its tab doesn't stay selected
+        if !self.visible_page_tabs().contains(&self.cur_page.tab()) {
+            self.cur_page = crate::app::app::MenuPage::Games;
+        }
+    }
+
+    /// Keyboard navigation bindings (options 70-79; click a key or press A,
+    /// then press the new one, or right-click to unbind)
+    pub fn display_settings_nav_bindings(&mut self, ui: &mut Ui) {
+        egui::Grid::new("nav_bindings")
+            .num_columns(2)
+            .spacing(egui::vec2(16.0, 4.0))
+            .show(ui, |ui| {
+                for (i, action) in NavAction::ALL.into_iter().enumerate() {
+                    let option = 70 + i;
+                    let focused = self.is_settings_option_focused(option);
+                    ui.label(action.label());
+                    let waiting = self.nav_rebind_action == Some(action);
+                    let text = if waiting {
+                        RichText::new("Press a key...").italics().color(theme::colors::ACCENT)
+                    } else {
+                        match self.options.nav_bindings.key(action) {
+                            Some(key) => RichText::new(key.name()),
+                            None => RichText::new("Unbound").weak(),
+                        }
+                    };
+                    let mut button = egui::Button::new(text).min_size(egui::vec2(110.0, 0.0));
+                    if focused {
+                        button = button.stroke(theme::focus_stroke());
+                    }
+                    let btn = ui
+                        .add(button)
+                        .on_hover_text("Click, then press the key to use (Esc cancels). Right-click to unbind");
+                    if btn.clicked() || (focused && self.activate_focused) {
+                        self.nav_rebind_action = if waiting { None } else { Some(action) };
+                    }
+                    if btn.secondary_clicked() {
+                        self.options.nav_bindings.set(action, None);
+                        self.nav_rebind_action = None;
+                    }
+                    self.scroll_to_settings_option_if_needed(option, &btn);
+                    if focused {
+                        self.infotext = "Press A or Enter, then the key to use for this action. Esc cancels.".to_string();
+                    }
+                    ui.end_row();
+                }
+            });
+
+        // Option 79: Reset bindings
+        ui.add_space(4.0);
+        let focused = self.is_settings_option_focused(79);
+        let mut button = egui::Button::new("Reset to defaults");
+        if focused {
+            button = button.stroke(theme::focus_stroke());
+        }
+        let btn = ui.add(button);
+        if btn.clicked() || (focused && self.activate_focused) {
+            self.options.nav_bindings = NavBindings::default();
+            self.nav_rebind_action = None;
+        }
+        self.scroll_to_settings_option_if_needed(79, &btn);
+    }
+
+    /// Enable checkbox, button toggles and hold time for a controller combo
+    ///
+    /// Takes three options from `base`: the checkbox, the button row (Left and
+    /// Right pick a button, A toggles it) and the hold time (Left and Right
+    /// adjust it). Returns whether any of its widgets is hovered or focused.
+    fn display_combo_setting(
+        &mut self,
+        ui: &mut Ui,
+        base: usize,
+        label: &str,
+        combo: fn(&mut SplituxConfig) -> &mut KillCombo,
+    ) -> bool {
+        let activate = self.activate_focused;
+        let mut hovered = (base..base + 3).any(|i| self.is_settings_option_focused(i));
+
+        let focused = self.is_settings_option_focused(base);
+        let r = self.settings_option_frame(base).show(ui, |ui| {
+            let combo = combo(&mut self.options);
+            hovered |= ui.checkbox(&mut combo.enabled, label).hovered();
+            if focused && activate {
+                combo.enabled = !combo.enabled;
+            }
+        });
+        self.scroll_to_settings_option_if_needed(base, &r.response);
+        if !combo(&mut self.options).enabled {
+            return hovered;
+        }
+
+        // Button row: the sub index is the button under the cursor
+        let step = self.settings_option_step(ui, base + 1);
+        let cursor = self.is_settings_option_focused(base + 1).then(|| {
+            self.settings_sub_index = self
+                .settings_sub_index
+                .saturating_add_signed(step as isize)
+                .min(ComboButton::ALL.len() - 1);
+            self.settings_sub_index
+        });
+        let r = self.settings_option_frame(base + 1).show(ui, |ui| {
+            let combo = combo(&mut self.options);
+            ui.horizontal_wrapped(|ui| {
+                for (i, button) in ComboButton::ALL.into_iter().enumerate() {
+                    let selected = combo.buttons.contains(&button);
+                    let r = ui.selectable_label(selected, button.label());
+                    if cursor == Some(i) {
+                        ui.painter().rect_stroke(r.rect, 2.0, theme::focus_stroke(), egui::StrokeKind::Outside);
+                    }
+                    if r.clicked() || (cursor == Some(i) && activate) {
+                        if selected {
+                            combo.buttons.retain(|&b| b != button);
+                        } else {
+                            combo.buttons.push(button);
+                        }
+                    }
+                    hovered |= r.hovered();
+                }
+            });
+            if combo.buttons.is_empty() {
+                ui.label(RichText::new("Select at least one button").small().color(theme::colors::WARNING));
+            }
+        });
+        self.scroll_to_settings_option_if_needed(base + 1, &r.response);
+
+        let step = self.settings_option_step(ui, base + 2);
+        let r = self.settings_option_frame(base + 2).show(ui, |ui| {
+            let combo = combo(&mut self.options);
+            combo.hold_secs = (combo.hold_secs + 0.5 * step as f32).clamp(0.5, 10.0);
+            ui.horizontal(|ui| {
+                ui.label("held for");
+                let drag = ui.add(
+                    egui::DragValue::new(&mut combo.hold_secs)
+                        .range(0.5..=10.0)
+                        .speed(0.1)
+                        .suffix("s"),
+                );
+                hovered |= drag.hovered();
+            });
+        });
+        self.scroll_to_settings_option_if_needed(base + 2, &r.response);
+
+        hovered
+    }
 }
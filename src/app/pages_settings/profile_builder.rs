@@ -5,15 +5,17 @@
 
 use super::Splitux;
 use crate::app::app::ProfileBuilderFocus;
+use crate::gptokeyb::keys::{is_supported_key, key_hint};
+use crate::gptokeyb::parser::serialize_gptk;
 use crate::gptokeyb::{
     delete_profile, list_user_profiles, load_user_profile, save_profile, AnalogMode,
-    GptokeybProfile,
+    ControllerButton, GptokeybProfile,
 };
 use crate::ui::components::controller_diagram::{
     render_button_legend, render_controller_diagram, DIAGRAM_BUTTONS,
 };
 use crate::ui::theme;
-use crate::util::msg;
+use crate::util::{msg, yesno};
 use eframe::egui::{self, RichText, StrokeKind, Ui};
 use egui_phosphor::regular as icons;
 
@@ -200,12 +202,15 @@ impl Splitux {
                 if ui.add(clear_btn).clicked() {
                     profile.clear_mapping(selected);
                 }
+
+                if let Some(key) = profile.get_mapping(selected)
+                    && !is_supported_key(key)
+                {
+                    ui.label(RichText::new(icons::WARNING).color(theme::colors::WARNING))
+                        .on_hover_text(format!("'{}' isn't a known gptokeyb key", key));
+                }
             });
-            ui.label(
-                RichText::new("w/a/s/d, space, ctrl, shift, mouse_left, mouse_right, mouse_wheel_up/down")
-                    .weak()
-                    .small(),
-            );
+            ui.label(RichText::new(key_hint()).weak().small());
         }
 
         ui.add_space(12.0);
@@ -278,6 +283,21 @@ impl Splitux {
             }
         });
 
+        ui.add_space(8.0);
+        egui::CollapsingHeader::new("All mappings")
+            .id_salt("profile_builder_mappings")
+            .show(ui, |ui| {
+                display_mapping_table(ui, &mut profile);
+            });
+
+        egui::CollapsingHeader::new("Preview .gptk")
+            .id_salt("profile_builder_preview")
+            .show(ui, |ui| {
+                let mut preview = profile.clone();
+                preview.name = self.profile_builder_name_buffer.trim().to_string();
+                ui.label(RichText::new(serialize_gptk(&preview)).monospace().small());
+            });
+
         self.profile_builder_editing = Some(profile);
     }
 
@@ -306,6 +326,24 @@ impl Splitux {
     }
 
     fn save_current_profile(&mut self, profile: &GptokeybProfile) {
+        let invalid = profile.invalid_mappings();
+        if !invalid.is_empty() {
+            let list: Vec<String> = invalid
+                .iter()
+                .map(|(button, key)| format!("{}: '{}'", button.display_name(), key))
+                .collect();
+            // The key list only covers common keys, so let the user keep a name it misses
+            if !yesno(
+                "Unknown Keys",
+                &format!(
+                    "These key names aren't in Splitux's list of gptokeyb keys:\n{}\n\ngptokeyb ignores names it doesn't know. Save anyway?",
+                    list.join("\n")
+                ),
+            ) {
+                return;
+            }
+        }
+
         let name = self.profile_builder_name_buffer.trim();
         if !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-') {
             let mut p = profile.clone();
@@ -320,5 +358,42 @@ impl Splitux {
             msg("Error", "Invalid profile name (use letters, numbers, _ or -)");
         }
     }
+}
 
+/// Render every button with an editable key name, flagging unknown keys
+fn display_mapping_table(ui: &mut Ui, profile: &mut GptokeybProfile) {
+    egui::Grid::new("profile_builder_mapping_table")
+        .num_columns(3)
+        .striped(true)
+        .spacing(egui::vec2(12.0, 4.0))
+        .show(ui, |ui| {
+            for button in ControllerButton::ALL {
+                ui.label(button.display_name());
+
+                let mut key = profile.get_mapping(button).unwrap_or("").to_string();
+                let valid = key.is_empty() || is_supported_key(&key);
+                let mut edit = egui::TextEdit::singleline(&mut key)
+                    .desired_width(100.0)
+                    .hint_text("unmapped");
+                if !valid {
+                    edit = edit.text_color(theme::colors::ERROR);
+                }
+                if ui.add(edit).changed() {
+                    let key = key.trim();
+                    if key.is_empty() {
+                        profile.clear_mapping(button);
+                    } else {
+                        profile.set_mapping(button, key);
+                    }
+                }
+
+                if valid {
+                    ui.label("");
+                } else {
+                    ui.label(RichText::new(icons::WARNING).color(theme::colors::WARNING))
+                        .on_hover_text("Unknown key name");
+                }
+                ui.end_row();
+            }
+        });
 }
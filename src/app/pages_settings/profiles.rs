@@ -3,7 +3,10 @@
 use crate::app::app::{ActiveDropdown, Splitux};
 use crate::ui::theme;
 use crate::profile_prefs::ProfilePreferences;
-use crate::profiles::{delete_profile, rename_profile, scan_profiles};
+use crate::profiles::{
+    clone_profile, delete_profile, format_playtime, rename_profile, scan_profiles, validate_profile_name,
+    PlayStats,
+};
 use crate::ui::components::dropdown::{render_gamepad_dropdown, DropdownItem};
 use crate::util::{msg, yesno};
 use eframe::egui::{self, RichText, Ui};
@@ -87,9 +90,15 @@ impl Splitux {
                                             if self.options.master_profile.as_ref()
                                                 == Some(profile_name)
                                             {
-                                                self.options.master_profile = Some(new_name);
+                                                self.options.master_profile = Some(new_name.clone());
+                                            }
+                                            if self.options.guest_save_base.as_ref()
+                                                == Some(profile_name)
+                                            {
+                                                self.options.guest_save_base = Some(new_name);
                                             }
                                             self.profiles = scan_profiles(false);
+                                            self.profile_playtime.clear();
                                         }
                                         Err(e) => {
                                             msg("Rename Failed", &e.to_string());
@@ -273,6 +282,41 @@ impl Splitux {
                                     if !audio_combo_open { self.dropdown_selection_idx = 0; }
                                 }
                             });
+
+                            ui.add_space(2.0);
+
+                            // Duplicate (sub_focus = 3)
+                            let dup_focused = is_focused && sub_focus == 3;
+                            let mut dup_btn = egui::Button::new(format!("{} Duplicate", icons::COPY));
+                            if dup_focused {
+                                dup_btn = dup_btn.stroke(theme::focus_stroke());
+                            }
+                            if ui.add(dup_btn)
+                                .on_hover_text("Create a new profile starting from this one's saves and preferences")
+                                .clicked()
+                                || (dup_focused && activate)
+                            {
+                                self.profile_clone_source = Some(i);
+                            }
+
+                            ui.add_space(2.0);
+
+                            // Playtime (display only)
+                            let stats = self.profile_playtime
+                                .entry(profile_name.clone())
+                                .or_insert_with(|| PlayStats::load(profile_name));
+                            ui.label(format!("{} Playtime: {}", icons::CLOCK, format_playtime(stats.total())));
+                            for (game, secs) in stats.by_game() {
+                                let game_name = self.handlers.iter()
+                                    .find(|h| h.handler_dir_name() == game)
+                                    .map(|h| h.display())
+                                    .unwrap_or(game);
+                                ui.label(
+                                    RichText::new(format!("    {}: {}", game_name, format_playtime(secs)))
+                                        .small()
+                                        .color(theme::colors::TEXT_MUTED),
+                                );
+                            }
                         });
                     }
                 });
@@ -301,7 +345,11 @@ impl Splitux {
                                 if is_master {
                                     self.options.master_profile = None;
                                 }
+                                if self.options.guest_save_base.as_ref() == Some(profile_to_delete) {
+                                    self.options.guest_save_base = None;
+                                }
                                 self.profiles = scan_profiles(false);
+                                self.profile_playtime.clear();
                             }
                             Err(e) => {
                                 msg("Delete Failed", &e.to_string());
@@ -311,6 +359,30 @@ impl Splitux {
                 }
                 self.profile_delete_confirm = None;
             }
+
+            // Handle duplication (asks for the new profile's name)
+            if let Some(clone_idx) = self.profile_clone_source.take()
+                && let Some(source) = profiles_list.get(clone_idx)
+            {
+                use dialog::DialogBox;
+
+                let name = dialog::Input::new(format!("Name for the copy of '{}':", source))
+                    .title("Duplicate Profile")
+                    .default(format!("{}_copy", source))
+                    .show()
+                    .expect("Could not display dialog box");
+                if let Some(name) = name.map(|n| n.trim().to_string())
+                    && !name.is_empty()
+                {
+                    if let Err(e) = validate_profile_name(&name) {
+                        msg("Error", &format!("Invalid name - {}", e));
+                    } else if let Err(e) = clone_profile(source, &name) {
+                        msg("Duplicate Failed", &e.to_string());
+                    } else {
+                        self.profiles = scan_profiles(false);
+                    }
+                }
+            }
         }
 
         ui.add_space(8.0);
@@ -33,6 +33,7 @@ pub use operations::scan_sinks;
 pub use pipelines::{setup_audio_session, teardown_audio_session};
 
 // Re-export pure functions
+pub use pure::{find_matching_sink, rebind_sinks, sinks_changed, solo_overrides, solo_player};
 
 /// Detect available audio system
 ///
@@ -62,7 +63,7 @@ pub fn detect_audio_system() -> AudioSystem {
         AudioSystem::None
     };
 
-    println!("[splitux] audio - Detected: {}", system.name());
+    log_info!("audio - Detected: {}", system.name());
     system
 }
 
@@ -83,7 +84,7 @@ pub fn resolve_audio_system(preference: AudioSystemPreference) -> AudioSystem {
             if has_pactl {
                 AudioSystem::PulseAudio
             } else {
-                println!("[splitux] audio - Warning: PulseAudio requested but pactl not found");
+                log_warn!("audio - PulseAudio requested but pactl not found");
                 AudioSystem::None
             }
         }
@@ -98,8 +99,8 @@ pub fn resolve_audio_system(preference: AudioSystemPreference) -> AudioSystem {
             if has_wpctl {
                 AudioSystem::PipeWireNative
             } else {
-                println!(
-                    "[splitux] audio - Warning: PipeWire native requested but wpctl not found"
+                log_warn!(
+                    "audio - PipeWire native requested but wpctl not found"
                 );
                 AudioSystem::None
             }
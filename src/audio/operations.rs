@@ -41,6 +41,19 @@ pub fn create_mute_sink(system: AudioSystem, instance_idx: usize) -> AudioResult
     }
 }
 
+/// Create a dedicated sink named after a player (null sink with no output)
+pub fn create_player_sink(
+    system: AudioSystem,
+    instance_idx: usize,
+    player: &str,
+) -> AudioResult<VirtualSink> {
+    match system {
+        AudioSystem::PulseAudio => pulseaudio::create_player_sink(instance_idx, player, &[]),
+        AudioSystem::PipeWireNative => pipewire::create_player_sink(instance_idx, player),
+        AudioSystem::None => Err("No audio system available".into()),
+    }
+}
+
 /// Cleanup virtual sinks
 pub fn cleanup_sinks(system: AudioSystem, sinks: &[VirtualSink]) -> AudioResult<()> {
     match system {
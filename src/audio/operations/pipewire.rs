@@ -118,8 +118,8 @@ pub fn create_mute_sink(instance_idx: usize) -> AudioResult<VirtualSink> {
     let sink_name = generate_virtual_sink_name(instance_idx);
     let description = format!("Splitux Instance {} (Muted)", instance_idx);
 
-    println!(
-        "[splitux] audio - Creating PipeWire mute sink '{}' (no output)",
+    log_info!(
+        "audio - Creating PipeWire mute sink '{}' (no output)",
         sink_name
     );
 
@@ -152,8 +152,8 @@ pub fn create_mute_sink(instance_idx: usize) -> AudioResult<VirtualSink> {
     ))
     .ok_or("Failed to parse mute sink module ID")?;
 
-    println!(
-        "[splitux] audio - Created PipeWire mute sink {} (module {})",
+    log_info!(
+        "audio - Created PipeWire mute sink {} (module {})",
         sink_name, module_id
     );
 
@@ -163,6 +163,13 @@ pub fn create_mute_sink(instance_idx: usize) -> AudioResult<VirtualSink> {
     })
 }
 
+/// Create a dedicated sink for a player (null sink with no output)
+///
+/// Same pactl null sink as PulseAudio, with rate/channels matching the other sinks
+pub fn create_player_sink(instance_idx: usize, player: &str) -> AudioResult<VirtualSink> {
+    super::pulseaudio::create_player_sink(instance_idx, player, &["rate=48000", "channels=2"])
+}
+
 /// Create a virtual sink for an instance using pw-cli
 ///
 /// Note: PipeWire virtual sink creation is more complex than PulseAudio.
@@ -171,8 +178,8 @@ pub fn create_virtual_sink(instance_idx: usize, target_sink: &str) -> AudioResul
     let sink_name = generate_virtual_sink_name(instance_idx);
     let description = generate_virtual_sink_description(instance_idx);
 
-    println!(
-        "[splitux] audio - Creating PipeWire virtual sink '{}' -> '{}'",
+    log_info!(
+        "audio - Creating PipeWire virtual sink '{}' -> '{}'",
         sink_name, target_sink
     );
 
@@ -217,8 +224,8 @@ pub fn create_virtual_sink(instance_idx: usize, target_sink: &str) -> AudioResul
     let cleanup_ids = match link_result {
         Ok(link_id) => vec![link_id, module_id],
         Err(e) => {
-            println!(
-                "[splitux] audio - Warning: pw-link failed ({}), falling back to module-loopback",
+            log_warn!(
+                "audio - pw-link failed ({}), falling back to module-loopback",
                 e
             );
 
@@ -261,8 +268,8 @@ pub fn create_virtual_sink(instance_idx: usize, target_sink: &str) -> AudioResul
         }
     };
 
-    println!(
-        "[splitux] audio - Created PipeWire virtual sink {} -> {}",
+    log_info!(
+        "audio - Created PipeWire virtual sink {} -> {}",
         sink_name, target_sink
     );
 
@@ -301,8 +308,8 @@ pub fn cleanup_sinks(sinks: &[VirtualSink]) -> AudioResult<()> {
     let mut errors = Vec::new();
 
     for sink in sinks {
-        println!(
-            "[splitux] audio - Cleaning up PipeWire virtual sink {}",
+        log_info!(
+            "audio - Cleaning up PipeWire virtual sink {}",
             sink.sink_name
         );
 
@@ -353,8 +360,8 @@ pub fn cleanup_all_splitux_sinks() -> AudioResult<()> {
         if line.contains("splitux_instance_") {
             let parts: Vec<&str> = line.split_whitespace().collect();
             if let Some(module_id) = parts.first() {
-                println!(
-                    "[splitux] audio - Emergency cleanup: unloading module {}",
+                log_info!(
+                    "audio - Emergency cleanup: unloading module {}",
                     module_id
                 );
                 let _ = Command::new("pactl")
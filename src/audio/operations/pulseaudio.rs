@@ -5,7 +5,8 @@
 use std::process::Command;
 
 use crate::audio::pure::{
-    classify_device, generate_virtual_sink_description, generate_virtual_sink_name,
+    classify_device, generate_player_sink_description, generate_player_sink_name,
+    generate_virtual_sink_description, generate_virtual_sink_name,
     is_splitux_sink, parse_module_id,
 };
 use crate::audio::types::{AudioResult, AudioSink, VirtualSink};
@@ -79,8 +80,8 @@ pub fn create_mute_sink(instance_idx: usize) -> AudioResult<VirtualSink> {
     let sink_name = generate_virtual_sink_name(instance_idx);
     let description = format!("Splitux Instance {} (Muted)", instance_idx);
 
-    println!(
-        "[splitux] audio - Creating mute sink '{}' (no output)",
+    log_info!(
+        "audio - Creating mute sink '{}' (no output)",
         sink_name
     );
 
@@ -108,8 +109,55 @@ pub fn create_mute_sink(instance_idx: usize) -> AudioResult<VirtualSink> {
     let module_id = parse_module_id(&String::from_utf8_lossy(&null_sink_output.stdout))
         .ok_or("Failed to parse mute sink module ID")?;
 
-    println!(
-        "[splitux] audio - Created mute sink {} (module {})",
+    log_info!(
+        "audio - Created mute sink {} (module {})",
+        sink_name, module_id
+    );
+
+    Ok(VirtualSink {
+        sink_name,
+        cleanup_ids: vec![module_id],
+    })
+}
+
+/// Create a dedicated sink for a player (null sink with no output)
+///
+/// Named after the player so it can be picked out and routed externally,
+/// e.g. into a mixer or a stream capture. `module_args` are passed through
+/// to module-null-sink.
+pub fn create_player_sink(
+    instance_idx: usize,
+    player: &str,
+    module_args: &[&str],
+) -> AudioResult<VirtualSink> {
+    let sink_name = generate_player_sink_name(instance_idx, player);
+    let description = generate_player_sink_description(instance_idx, player);
+
+    log_info!("audio - Creating player sink '{}'", sink_name);
+
+    let null_sink_output = Command::new("pactl")
+        .args(["load-module", "module-null-sink"])
+        .arg(format!("sink_name={}", sink_name))
+        .args(module_args)
+        .arg(format!(
+            "sink_properties=device.description=\"{}\"",
+            description.replace(' ', "\\ ")
+        ))
+        .output()?;
+
+    if !null_sink_output.status.success() {
+        return Err(format!(
+            "Failed to create player sink: {}",
+            String::from_utf8_lossy(&null_sink_output.stderr)
+        )
+        .into());
+    }
+
+    let module_id = parse_module_id(&String::from_utf8_lossy(&null_sink_output.stdout))
+        .ok_or("Failed to parse player sink module ID")?;
+
+    log_info!(
+        "audio - Created player sink {} (module {})",
         sink_name, module_id
     );
 
@@ -124,8 +172,8 @@ pub fn create_virtual_sink(instance_idx: usize, target_sink: &str) -> AudioResul
     let sink_name = generate_virtual_sink_name(instance_idx);
     let description = generate_virtual_sink_description(instance_idx);
 
-    println!(
-        "[splitux] audio - Creating virtual sink '{}' -> '{}'",
+    log_info!(
+        "audio - Creating virtual sink '{}' -> '{}'",
         sink_name, target_sink
     );
 
@@ -194,8 +242,8 @@ pub fn create_virtual_sink(instance_idx: usize, target_sink: &str) -> AudioResul
     let loopback_id = parse_module_id(&String::from_utf8_lossy(&loopback_output.stdout))
         .ok_or("Failed to parse loopback module ID")?;
 
-    println!(
-        "[splitux] audio - Created virtual sink {} (module {}) -> {} (loopback {})",
+    log_info!(
+        "audio - Created virtual sink {} (module {}) -> {} (loopback {})",
         sink_name, module_id, target_sink, loopback_id
     );
 
@@ -210,8 +258,8 @@ pub fn cleanup_sinks(sinks: &[VirtualSink]) -> AudioResult<()> {
     let mut errors = Vec::new();
 
     for sink in sinks {
-        println!(
-            "[splitux] audio - Cleaning up virtual sink {} (modules: {:?})",
+        log_info!(
+            "audio - Cleaning up virtual sink {} (modules: {:?})",
             sink.sink_name, sink.cleanup_ids
         );
 
@@ -262,8 +310,8 @@ pub fn cleanup_all_splitux_sinks() -> AudioResult<()> {
         if line.contains("splitux_instance_") {
             let parts: Vec<&str> = line.split_whitespace().collect();
             if let Some(module_id) = parts.first() {
-                println!(
-                    "[splitux] audio - Emergency cleanup: unloading module {}",
+                log_info!(
+                    "audio - Emergency cleanup: unloading module {}",
                     module_id
                 );
                 let _ = unload_module(module_id);
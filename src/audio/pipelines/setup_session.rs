@@ -1,11 +1,15 @@
 //! Audio session setup pipeline
 
-use crate::audio::operations::{cleanup_all_splitux_sinks, create_mute_sink, create_virtual_sink};
+use crate::audio::operations::{
+    cleanup_all_splitux_sinks, create_mute_sink, create_player_sink, create_virtual_sink,
+};
 use crate::audio::types::{AudioContext, AudioResult, VirtualSink, AUDIO_MUTED_SENTINEL};
 
 /// Set up audio routing for a game session
 ///
 /// Creates virtual sinks for each instance that has an audio assignment.
+/// With player sinks enabled, unassigned instances get a null sink named
+/// after their player instead of the default sink.
 /// Returns the created virtual sinks (for cleanup) and the sink names to use
 /// for PULSE_SINK environment variable per instance.
 pub fn setup_audio_session(ctx: &AudioContext) -> AudioResult<(Vec<VirtualSink>, Vec<String>)> {
@@ -26,8 +30,8 @@ pub fn setup_audio_session(ctx: &AudioContext) -> AudioResult<(Vec<VirtualSink>,
                         virtual_sinks.push(virtual_sink);
                     }
                     Err(e) => {
-                        println!(
-                            "[splitux] audio - Warning: Failed to create mute sink for instance {}: {}",
+                        log_warn!(
+                            "audio - Failed to create mute sink for instance {}: {}",
                             instance_idx, e
                         );
                         sink_env_vars.push(String::new());
@@ -42,22 +46,37 @@ pub fn setup_audio_session(ctx: &AudioContext) -> AudioResult<(Vec<VirtualSink>,
                     }
                     Err(e) => {
                         // Log error but continue - audio failure shouldn't block game launch
-                        println!(
-                            "[splitux] audio - Warning: Failed to create virtual sink for instance {}: {}",
+                        log_warn!(
+                            "audio - Failed to create virtual sink for instance {}: {}",
                             instance_idx, e
                         );
                         sink_env_vars.push(String::new());
                     }
                 }
             }
+        } else if let Some(player) = ctx.player_sinks.get(instance_idx) {
+            // No assignment but player sinks enabled: a dedicated sink to route externally
+            match create_player_sink(ctx.system, instance_idx, player) {
+                Ok(virtual_sink) => {
+                    sink_env_vars.push(virtual_sink.sink_name.clone());
+                    virtual_sinks.push(virtual_sink);
+                }
+                Err(e) => {
+                    log_warn!(
+                        "audio - Failed to create player sink for instance {}: {}",
+                        instance_idx, e
+                    );
+                    sink_env_vars.push(String::new());
+                }
+            }
         } else {
             // No assignment: use default sink (empty string means no override)
             sink_env_vars.push(String::new());
         }
     }
 
-    println!(
-        "[splitux] audio - Session setup complete: {} virtual sinks created",
+    log_info!(
+        "audio - Session setup complete: {} virtual sinks created",
         virtual_sinks.len()
     );
 
@@ -3,10 +3,14 @@
 //! These functions have no side effects and are deterministic.
 
 mod device_classification;
+mod sink_match;
 mod sink_name;
+mod solo;
 
 pub use device_classification::classify_device;
+pub use sink_match::{find_matching_sink, rebind_sinks, sinks_changed};
 pub use sink_name::{
-    generate_virtual_sink_description, generate_virtual_sink_name, is_splitux_sink,
-    parse_module_id,
+    generate_player_sink_description, generate_player_sink_name, generate_virtual_sink_description,
+    generate_virtual_sink_name, is_splitux_sink, parse_module_id,
 };
+pub use solo::{solo_overrides, solo_player};
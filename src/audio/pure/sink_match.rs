@@ -0,0 +1,161 @@
+//! Sink matching across renumbering
+//!
+//! Pure functions for finding a previously selected sink after the audio
+//! system renamed it (e.g. PipeWire node IDs change across reboots).
+
+use crate::audio::types::AudioSink;
+
+/// Build a renumbering-insensitive key for a sink name
+///
+/// Strips all digits so "pw_node_46" and "pw_node_51", or
+/// "alsa_output.usb-Headset-00.analog-stereo" and "...-01...", share a key.
+pub fn stable_sink_key(name: &str) -> String {
+    name.chars().filter(|c| !c.is_ascii_digit()).collect()
+}
+
+/// Find the sink that best matches a stored sink name
+///
+/// Resolution order:
+/// 1. Exact sink name match
+/// 2. Description match (case-insensitive), if a description is known
+/// 3. Stable key match, only when no description is known and exactly one
+///    sink shares the key (a known description that matches nothing means the
+///    device is gone, not renumbered)
+pub fn find_matching_sink<'a>(
+    sinks: &'a [AudioSink],
+    name: &str,
+    description: Option<&str>,
+) -> Option<&'a AudioSink> {
+    if let Some(sink) = sinks.iter().find(|s| s.name == name) {
+        return Some(sink);
+    }
+
+    if let Some(desc) = description.filter(|d| !d.is_empty()) {
+        let mut by_desc = sinks
+            .iter()
+            .filter(|s| s.description.eq_ignore_ascii_case(desc));
+        return match (by_desc.next(), by_desc.next()) {
+            (Some(sink), None) => Some(sink),
+            _ => None,
+        };
+    }
+
+    let key = stable_sink_key(name);
+    let mut by_key = sinks.iter().filter(|s| stable_sink_key(&s.name) == key);
+    match (by_key.next(), by_key.next()) {
+        (Some(sink), None) => Some(sink),
+        _ => None,
+    }
+}
+
+/// Check whether a rescanned sink list differs from the current one
+pub fn sinks_changed(old: &[AudioSink], new: &[AudioSink]) -> bool {
+    old.len() != new.len()
+        || old
+            .iter()
+            .zip(new)
+            .any(|(a, b)| a.name != b.name || a.description != b.description)
+}
+
+/// Instance-keyed sink names, e.g. session overrides
+type SinkBindings = Vec<(usize, String)>;
+
+/// Re-bind stored sink names after a rescan
+///
+/// Each name is looked up in `new` using its description from `old`, so a
+/// device that was re-enumerated keeps its assignment. Returns the re-bound
+/// entries and the `(key, description)` pairs whose sink is gone.
+pub fn rebind_sinks(
+    entries: SinkBindings,
+    old: &[AudioSink],
+    new: &[AudioSink],
+) -> (SinkBindings, SinkBindings) {
+    let mut kept = Vec::new();
+    let mut lost = Vec::new();
+    for (key, name) in entries {
+        let description = old
+            .iter()
+            .find(|s| s.name == name)
+            .map(|s| s.description.clone());
+        match find_matching_sink(new, &name, description.as_deref()) {
+            Some(sink) => kept.push((key, sink.name.clone())),
+            None => lost.push((key, description.unwrap_or(name))),
+        }
+    }
+    (kept, lost)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::types::AudioDeviceType;
+
+    fn sink(name: &str, description: &str) -> AudioSink {
+        AudioSink {
+            name: name.to_string(),
+            description: description.to_string(),
+            device_type: AudioDeviceType::Unknown,
+            is_default: false,
+        }
+    }
+
+    #[test]
+    fn test_stable_sink_key() {
+        assert_eq!(stable_sink_key("pw_node_46"), "pw_node_");
+        assert_eq!(
+            stable_sink_key("alsa_output.usb-Headset-01.analog-stereo"),
+            stable_sink_key("alsa_output.usb-Headset-00.analog-stereo")
+        );
+    }
+
+    #[test]
+    fn test_exact_name_wins() {
+        let sinks = vec![sink("pw_node_46", "Speakers"), sink("pw_node_51", "Headset")];
+        let found = find_matching_sink(&sinks, "pw_node_51", Some("Speakers")).unwrap();
+        assert_eq!(found.name, "pw_node_51");
+    }
+
+    #[test]
+    fn test_renumbered_sink_matched_by_description() {
+        let sinks = vec![sink("pw_node_60", "Speakers"), sink("pw_node_61", "Headset")];
+        let found = find_matching_sink(&sinks, "pw_node_46", Some("headset")).unwrap();
+        assert_eq!(found.name, "pw_node_61");
+    }
+
+    #[test]
+    fn test_renumbered_sink_matched_by_unique_key() {
+        let sinks = vec![
+            sink("alsa_output.usb-Headset-01.analog-stereo", "USB Headset"),
+            sink("alsa_output.pci-speakers.analog-stereo", "Built-in Audio"),
+        ];
+        let found =
+            find_matching_sink(&sinks, "alsa_output.usb-Headset-00.analog-stereo", None).unwrap();
+        assert_eq!(found.name, "alsa_output.usb-Headset-01.analog-stereo");
+    }
+
+    #[test]
+    fn test_sinks_changed() {
+        let old = vec![sink("pw_node_46", "Speakers")];
+        assert!(!sinks_changed(&old, &old.clone()));
+        assert!(sinks_changed(&old, &[]));
+        assert!(sinks_changed(&old, &[sink("pw_node_47", "Speakers")]));
+    }
+
+    #[test]
+    fn test_rebind_sinks_after_rescan() {
+        let old = vec![sink("pw_node_46", "Speakers"), sink("pw_node_50", "USB Headset")];
+        let new = vec![sink("pw_node_52", "Speakers")];
+        let entries = vec![(0, "pw_node_46".to_string()), (1, "pw_node_50".to_string())];
+
+        let (kept, lost) = rebind_sinks(entries, &old, &new);
+        assert_eq!(kept, vec![(0, "pw_node_52".to_string())]);
+        assert_eq!(lost, vec![(1, "USB Headset".to_string())]);
+    }
+
+    #[test]
+    fn test_ambiguous_key_is_not_matched() {
+        let sinks = vec![sink("pw_node_60", "Speakers"), sink("pw_node_61", "Headset")];
+        assert!(find_matching_sink(&sinks, "pw_node_46", None).is_none());
+        assert!(find_matching_sink(&sinks, "pw_node_46", Some("HDMI")).is_none());
+    }
+}
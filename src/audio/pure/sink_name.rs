@@ -12,6 +12,38 @@ pub fn generate_virtual_sink_description(instance_idx: usize) -> String {
     format!("Splitux Instance {} Audio", instance_idx + 1)
 }
 
+/// Generate the sink name of a player's dedicated sink
+///
+/// Keeps the `splitux_instance_` prefix so emergency cleanup still finds
+/// it, followed by the player name reduced to characters that are safe in
+/// a node name.
+pub fn generate_player_sink_name(instance_idx: usize, player: &str) -> String {
+    let player: String = player
+        .chars()
+        .filter_map(|c| match c {
+            'a'..='z' | '0'..='9' => Some(c),
+            'A'..='Z' => Some(c.to_ascii_lowercase()),
+            ' ' | '-' | '_' | '.' => Some('_'),
+            _ => None,
+        })
+        .collect();
+    let player = player.trim_matches('_');
+    if player.is_empty() {
+        generate_virtual_sink_name(instance_idx)
+    } else {
+        format!("{}_{}", generate_virtual_sink_name(instance_idx), player)
+    }
+}
+
+/// Generate the description of a player's dedicated sink
+pub fn generate_player_sink_description(instance_idx: usize, player: &str) -> String {
+    if player.is_empty() {
+        format!("Splitux P{}", instance_idx + 1)
+    } else {
+        format!("Splitux P{} ({})", instance_idx + 1, player)
+    }
+}
+
 /// Parse module ID from pactl load-module output
 ///
 /// pactl load-module returns just the module ID number on success
@@ -51,6 +83,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generate_player_sink_name() {
+        assert_eq!(generate_player_sink_name(0, "Alice"), "splitux_instance_0_alice");
+        assert_eq!(generate_player_sink_name(1, "Big Bob"), "splitux_instance_1_big_bob");
+        assert_eq!(generate_player_sink_name(2, "Zoë!"), "splitux_instance_2_zo");
+        assert_eq!(generate_player_sink_name(3, "ÄÖ"), "splitux_instance_3");
+        assert!(is_splitux_sink(&generate_player_sink_name(0, "Alice")));
+    }
+
+    #[test]
+    fn test_generate_player_sink_description() {
+        assert_eq!(generate_player_sink_description(0, "Alice"), "Splitux P1 (Alice)");
+        assert_eq!(generate_player_sink_description(1, ""), "Splitux P2");
+    }
+
     #[test]
     fn test_parse_module_id() {
         assert_eq!(parse_module_id("42"), Some("42".to_string()));
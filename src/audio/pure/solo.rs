@@ -0,0 +1,75 @@
+//! "Mute all but one" session overrides
+//!
+//! Pure functions over the per-instance session override map, where
+//! `Some(sink)` routes an instance to a sink and `None` mutes it.
+
+use std::collections::HashMap;
+
+/// Mute every instance except `solo`
+///
+/// `solo` keeps a device override it already had; an explicit mute on it is
+/// dropped so it falls back to its profile preference or the default sink.
+pub fn solo_overrides(
+    overrides: &mut HashMap<usize, Option<String>>,
+    player_count: usize,
+    solo: usize,
+) {
+    for i in 0..player_count {
+        if i != solo {
+            overrides.insert(i, None);
+        } else if overrides.get(&i) == Some(&None) {
+            overrides.remove(&i);
+        }
+    }
+}
+
+/// The only instance that isn't explicitly muted, if all others are
+pub fn solo_player(overrides: &HashMap<usize, Option<String>>, player_count: usize) -> Option<usize> {
+    if player_count < 2 {
+        return None;
+    }
+    let mut unmuted = (0..player_count).filter(|i| overrides.get(i) != Some(&None));
+    match (unmuted.next(), unmuted.next()) {
+        (Some(i), None) => Some(i),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solo_mutes_others_and_keeps_own_device() {
+        let mut overrides = HashMap::new();
+        overrides.insert(0, Some("headset".to_string()));
+        overrides.insert(1, None);
+        overrides.insert(2, Some("speakers".to_string()));
+
+        solo_overrides(&mut overrides, 3, 1);
+        assert_eq!(overrides.get(&0), Some(&None));
+        assert_eq!(overrides.get(&1), None);
+        assert_eq!(overrides.get(&2), Some(&None));
+        assert_eq!(solo_player(&overrides, 3), Some(1));
+
+        solo_overrides(&mut overrides, 3, 2);
+        assert_eq!(overrides.get(&1), Some(&None));
+        assert_eq!(solo_player(&overrides, 3), Some(2));
+    }
+
+    #[test]
+    fn solo_player_requires_all_others_muted() {
+        let mut overrides = HashMap::new();
+        assert_eq!(solo_player(&overrides, 3), None);
+
+        overrides.insert(0, None);
+        assert_eq!(solo_player(&overrides, 3), None);
+
+        overrides.insert(2, None);
+        assert_eq!(solo_player(&overrides, 3), Some(1));
+
+        overrides.insert(1, None);
+        assert_eq!(solo_player(&overrides, 3), None);
+        assert_eq!(solo_player(&HashMap::new(), 1), None);
+    }
+}
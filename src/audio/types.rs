@@ -80,6 +80,9 @@ pub struct AudioContext {
     pub system: AudioSystem,
     /// Target sink for each instance (None = use default)
     pub assignments: Vec<Option<String>>,
+    /// Player name per instance when unassigned instances get their own
+    /// player sink instead of the default; empty when that's disabled
+    pub player_sinks: Vec<String>,
 }
 
 /// Result type for audio operations
@@ -44,6 +44,7 @@ pub trait Backend {
     }
 
     /// Create overlay directories for all instances (batch operation)
+    /// under the session's scratch directory `tmp_dir`.
     /// Returns a vector of overlay paths, one per instance.
     fn create_all_overlays(
         &self,
@@ -51,6 +52,7 @@ pub trait Backend {
         instances: &[Instance],
         is_windows: bool,
         game_root: &Path,
+        tmp_dir: &Path,
     ) -> Result<Vec<PathBuf>, Box<dyn Error>>;
 }
 
@@ -65,7 +67,7 @@ pub mod standalone;
 // Re-export settings types for use in Handler
 pub use eos::EosSettings;
 pub use facepunch::FacepunchSettings;
-pub use goldberg::GoldbergSettings;
+pub use goldberg::{GoldbergSettings, LobbyType};
 pub use photon::PhotonSettings;
 pub use standalone::StandaloneSettings;
 
@@ -104,6 +106,15 @@ fn collect_enabled_backends(handler: &Handler) -> Vec<Box<dyn Backend>> {
     backends
 }
 
+/// Names of the handler's enabled backends that need overlay mounts
+pub fn overlay_backend_names(handler: &Handler) -> Vec<String> {
+    collect_enabled_backends(handler)
+        .iter()
+        .filter(|backend| backend.requires_overlay())
+        .map(|backend| backend.name().to_string())
+        .collect()
+}
+
 /// Create overlay directories for all instances based on the handler's backend
 ///
 /// Returns a vector of overlay path lists (one list per instance) to be added to
@@ -119,6 +130,7 @@ pub fn create_backend_overlays(
     handler: &Handler,
     instances: &[Instance],
     is_windows: bool,
+    tmp_dir: &Path,
 ) -> Result<Vec<Vec<PathBuf>>, Box<dyn Error>> {
     let num_instances = instances.len();
     let game_root = PathBuf::from(handler.get_game_rootpath()?);
@@ -131,12 +143,12 @@ pub fn create_backend_overlays(
 
     if backends.len() > 1 {
         let names: Vec<&str> = backends.iter().map(|b| b.name()).collect();
-        println!("[splitux] Multiple backends enabled: {:?}", names);
+        log_info!("Multiple backends enabled: {:?}", names);
     }
 
     for backend in &backends {
         if backend.requires_overlay() {
-            let overlays = backend.create_all_overlays(handler, instances, is_windows, &game_root)?;
+            let overlays = backend.create_all_overlays(handler, instances, is_windows, &game_root, tmp_dir)?;
 
             for (i, overlay) in overlays.into_iter().enumerate() {
                 if i < num_instances {
@@ -66,6 +66,7 @@ impl Backend for Eos {
         instances: &[Instance],
         is_windows: bool,
         game_root: &Path,
+        tmp_dir: &Path,
     ) -> Result<Vec<PathBuf>, Box<dyn Error>> {
         // Use appid from settings, fallback to handler's steam_appid if not set
         let appid = if self.settings.appid.is_empty() {
@@ -84,6 +85,7 @@ impl Backend for Eos {
             &appid,
             self.settings.enable_lan,
             self.settings.disable_online_networking,
+            tmp_dir,
         )
     }
 }
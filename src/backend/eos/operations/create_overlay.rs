@@ -23,8 +23,9 @@ pub fn create_instance_overlay(
     is_windows: bool,
     enable_lan: bool,
     disable_online_networking: bool,
+    tmp_dir: &Path,
 ) -> Result<PathBuf, Box<dyn std::error::Error>> {
-    let overlay_dir = prepare_overlay_dir("eos", instance_idx)?;
+    let overlay_dir = prepare_overlay_dir(tmp_dir, "eos", instance_idx)?;
 
     for dll in dlls {
         let dll_dir = dll.rel_path.parent().unwrap_or(Path::new(""));
@@ -50,15 +51,15 @@ pub fn create_instance_overlay(
 
         if src_path.exists() {
             fs::copy(&src_path, &dest_path)?;
-            println!(
-                "[splitux] EOS overlay {}: {} -> {}",
+            log_info!(
+                "EOS overlay {}: {} -> {}",
                 instance_idx,
                 src_path.display(),
                 dest_path.display()
             );
         } else {
-            println!(
-                "[splitux] Warning: EOS emulator DLL not found: {}",
+            log_warn!(
+                "EOS emulator DLL not found: {}",
                 src_path.display()
             );
         }
@@ -79,15 +80,15 @@ pub fn create_instance_overlay(
                 enable_lan,
                 disable_online_networking,
             )?;
-            println!(
-                "[splitux] EOS overlay {}: Also created nepice_settings at game root",
+            log_info!(
+                "EOS overlay {}: Also created nepice_settings at game root",
                 instance_idx
             );
         }
     }
 
-    println!(
-        "[splitux] EOS overlay {} created: User {}, Port {}, enable_lan: {}, disable_online: {}",
+    log_info!(
+        "EOS overlay {} created: User {}, Port {}, enable_lan: {}, disable_online: {}",
         instance_idx, config.username, config.listen_port, enable_lan, disable_online_networking
     );
 
@@ -34,8 +34,8 @@ pub fn find_eos_dlls(game_dir: &Path) -> Result<Vec<EosDll>, Box<dyn std::error:
                         rel_path: rel_path.to_path_buf(),
                         is_64bit,
                     });
-                    println!(
-                        "[splitux] Found EOS SDK: {} ({})",
+                    log_info!(
+                        "Found EOS SDK: {} ({})",
                         rel_path.display(),
                         if is_64bit { "64-bit" } else { "32-bit" }
                     );
@@ -44,8 +44,8 @@ pub fn write_eos_settings(
     let json = serde_json::to_string_pretty(&settings)?;
     fs::write(&settings_path, json)?;
 
-    println!(
-        "[splitux] EOS settings written to: {}",
+    log_info!(
+        "EOS settings written to: {}",
         settings_path.display()
     );
 
@@ -19,12 +19,13 @@ pub fn create_all_overlays(
     appid: &str,
     enable_lan: bool,
     disable_online_networking: bool,
+    tmp_dir: &Path,
 ) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
     // Find EOS SDK DLLs in the game directory
     let dlls = find_eos_dlls(game_root)?;
 
     if dlls.is_empty() {
-        println!("[splitux] Warning: EOS backend enabled but no EOS SDK DLLs found");
+        log_warn!("EOS backend enabled but no EOS SDK DLLs found");
         return Ok(vec![]);
     }
 
@@ -65,6 +66,7 @@ pub fn create_all_overlays(
             is_windows,
             enable_lan,
             disable_online_networking,
+            tmp_dir,
         )?;
 
         overlay_dirs.push(overlay_dir);
@@ -77,6 +77,7 @@ impl Backend for Facepunch {
         instances: &[Instance],
         is_windows: bool,
         game_root: &Path,
+        tmp_dir: &Path,
     ) -> Result<Vec<PathBuf>, Box<dyn Error>> {
         pipelines::create_all_overlays(
             &self.settings,
@@ -84,6 +85,7 @@ impl Backend for Facepunch {
             instances,
             is_windows,
             game_root,
+            tmp_dir,
         )
     }
 }
@@ -113,11 +113,11 @@ pub fn install_splitux_plugin(overlay_dir: &Path) -> Result<(), Box<dyn std::err
     if plugin_src.exists() {
         fs::copy(&plugin_src, plugins_dir.join("SplituxFacepunch.dll"))?;
     } else {
-        println!(
-            "[splitux] Warning: SplituxFacepunch.dll not found at {}",
+        log_warn!(
+            "SplituxFacepunch.dll not found at {}",
             plugin_src.display()
         );
-        println!("[splitux] Run ./splitux.sh build to download it");
+        log_info!("Run ./splitux.sh build to download it");
     }
 
     Ok(())
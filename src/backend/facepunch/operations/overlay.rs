@@ -3,7 +3,7 @@
 //! Creates per-instance overlay directories with BepInEx and SplituxFacepunch.
 
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::backend::operations::prepare_overlay_dir;
 
@@ -28,8 +28,9 @@ pub fn create_instance_overlay(
     config: &FacepunchConfig,
     is_windows: bool,
     backend: UnityBackend,
+    tmp_dir: &Path,
 ) -> Result<PathBuf, Box<dyn std::error::Error>> {
-    let overlay_dir = prepare_overlay_dir("facepunch", instance_idx)?;
+    let overlay_dir = prepare_overlay_dir(tmp_dir, "facepunch", instance_idx)?;
 
     // 1. Install BepInEx core
     install_bepinex_core(&overlay_dir, is_windows, backend)?;
@@ -63,8 +64,8 @@ HideManagerGameObject = true
     // 6. Install SplituxFacepunch plugin
     install_splitux_plugin(&overlay_dir)?;
 
-    println!(
-        "[splitux] Facepunch overlay {} created: Player {}, SteamID {}, Backend: {}",
+    log_info!(
+        "Facepunch overlay {} created: Player {}, SteamID {}, Backend: {}",
         instance_idx,
         config.account_name,
         config.steam_id,
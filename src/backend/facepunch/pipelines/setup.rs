@@ -20,6 +20,7 @@ pub fn create_all_overlays(
     instances: &[Instance],
     is_windows: bool,
     game_dir: &Path,
+    tmp_dir: &Path,
 ) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
     // Detect Unity backend type
     let backend = detect_unity_backend(game_dir);
@@ -44,7 +45,7 @@ pub fn create_all_overlays(
             runtime_patches: runtime_patches.to_vec(),
         };
 
-        let overlay = create_instance_overlay(i, &config, is_windows, backend)?;
+        let overlay = create_instance_overlay(i, &config, is_windows, backend, tmp_dir)?;
         overlays.push(overlay);
     }
 
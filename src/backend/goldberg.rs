@@ -4,9 +4,9 @@
 //!
 //! ## Module Structure
 //! - `types.rs`: Internal types (SteamApiDll, SteamDllType, GoldbergConfig)
-//! - `pure/`: Pure functions (bitness detection)
-//! - `operations/`: Atomic I/O operations (find DLLs, write settings, create overlay)
-//! - `pipelines/`: High-level orchestration (create_all_overlays)
+//! - `pure/`: Pure functions (bitness detection, lobby type validation, LAN ports/subnet)
+//! - `operations/`: Atomic I/O operations (find DLLs, write settings, create overlay, repair data)
+//! - `pipelines/`: High-level orchestration (create_all_overlays, verify_goldberg_data)
 
 use super::Backend;
 use std::collections::HashMap;
@@ -16,6 +16,7 @@ use std::path::{Path, PathBuf};
 use crate::handler::Handler;
 use crate::instance::Instance;
 use crate::mods::PluginSource;
+use crate::paths::PATH_PARTY;
 use crate::profiles::generate_steam_id;
 
 mod operations;
@@ -25,8 +26,14 @@ mod types;
 
 use operations::find_steam_api_dlls;
 use pipelines::create_all_overlays as pipeline_create_all_overlays;
+pub use pipelines::verify_goldberg_data;
+use pure::{instance_ports, local_save_path_value, LOBBY_TYPE_FILE};
 use types::{GoldbergConfig, SteamDllType};
 
+pub use pure::{
+    check_listen_port, shared_saves_dir, validate_setting, LanSubnet, LobbyType, DEFAULT_LISTEN_PORT, MIN_LISTEN_PORT,
+};
+
 /// Goldberg settings from handler YAML (dot-notation: goldberg.*)
 #[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, PartialEq)]
 pub struct GoldbergSettings {
@@ -42,12 +49,53 @@ pub struct GoldbergSettings {
     #[serde(default)]
     pub settings: HashMap<String, String>,
 
+    /// Listen port of the first instance; later instances count up from it
+    /// (goldberg.listen_port). Unset uses the default range.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub listen_port: Option<u16>,
+
+    /// Subnet in CIDR form that LAN discovery also broadcasts to
+    /// (goldberg.lan_subnet), for machines with VPNs or several NICs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lan_subnet: Option<String>,
+
+    /// Point every instance's emulator saves at one shared folder instead of
+    /// each profile (goldberg.shared_saves). Only for games whose progress
+    /// players want shared; instances running at the same time write to the
+    /// same files, so saves can conflict or get corrupted.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub shared_saves: bool,
+
     /// Plugin source for BepInEx-based plugins (goldberg.plugin.*)
     /// When specified, BepInEx will be installed and the plugin fetched from the source.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub plugin: Option<PluginSource>,
 }
 
+impl GoldbergSettings {
+    /// Lobby type forced via force_lobby_type.txt (None if unset or unrecognized)
+    pub fn lobby_type(&self) -> Option<LobbyType> {
+        self.settings.get(LOBBY_TYPE_FILE).and_then(|v| LobbyType::parse(v))
+    }
+
+    /// Raw force_lobby_type.txt value, if any
+    pub fn raw_lobby_type(&self) -> Option<&str> {
+        self.settings.get(LOBBY_TYPE_FILE).map(String::as_str)
+    }
+
+    /// Force a lobby type, or clear the override with None
+    pub fn set_lobby_type(&mut self, lobby_type: Option<LobbyType>) {
+        match lobby_type {
+            Some(t) => {
+                self.settings.insert(LOBBY_TYPE_FILE.to_string(), t.value().to_string());
+            }
+            None => {
+                self.settings.remove(LOBBY_TYPE_FILE);
+            }
+        }
+    }
+}
+
 /// Goldberg backend implementation
 pub struct Goldberg {
     pub settings: GoldbergSettings,
@@ -74,6 +122,7 @@ impl Backend for Goldberg {
         instances: &[Instance],
         is_windows: bool,
         game_root: &Path,
+        tmp_dir: &Path,
     ) -> Result<Vec<PathBuf>, Box<dyn Error>> {
         // Find Steam API DLLs in the game directory
         let mut dlls = find_steam_api_dlls(&game_root.to_path_buf())?;
@@ -84,15 +133,35 @@ impl Backend for Goldberg {
         }
 
         if dlls.is_empty() {
-            println!("[splitux] Warning: Goldberg backend enabled but no Steam API DLLs found");
+            log_warn!("Goldberg backend enabled but no Steam API DLLs found");
             return Ok(vec![]);
         }
 
         // Generate unique ports for each instance
-        const BASE_PORT: u16 = 47584;
-        let instance_ports: Vec<u16> = (0..instances.len())
-            .map(|i| BASE_PORT + i as u16)
-            .collect();
+        let base_port = self.settings.listen_port.unwrap_or(DEFAULT_LISTEN_PORT);
+        let instance_ports = instance_ports(base_port, instances.len())?;
+
+        // An invalid subnet is linted in the editor; skip the hint rather than fail
+        let subnet_broadcast = match self.settings.lan_subnet.as_deref().map(LanSubnet::parse) {
+            Some(Ok(subnet)) => Some(subnet.broadcast()),
+            Some(Err(e)) => {
+                log_warn!("Ignoring Goldberg LAN subnet: {}", e);
+                None
+            }
+            None => None,
+        };
+
+        let local_save_path = if self.settings.shared_saves {
+            let dir = shared_saves_dir(&PATH_PARTY, handler.storage_key());
+            std::fs::create_dir_all(&dir)?;
+            log_warn!(
+                "Goldberg saves are shared by all instances in {}; instances writing at once can conflict",
+                dir.display()
+            );
+            Some(local_save_path_value(&dir, is_windows))
+        } else {
+            None
+        };
 
         // Build configs for each instance
         let configs: Vec<GoldbergConfig> = instances
@@ -113,6 +182,9 @@ impl Backend for Goldberg {
                     account_name: instance.profname.clone(),
                     listen_port: instance_ports[i],
                     broadcast_ports,
+                    custom_port: self.settings.listen_port.is_some(),
+                    subnet_broadcast,
+                    local_save_path: local_save_path.clone(),
                 }
             })
             .collect();
@@ -121,10 +193,9 @@ impl Backend for Goldberg {
             &dlls,
             &configs,
             is_windows,
-            &self.settings.settings,
-            self.settings.disable_networking,
-            &self.settings.plugin,
+            &self.settings,
             game_root,
+            tmp_dir,
         )
     }
 }
@@ -4,7 +4,9 @@
 
 mod create_overlay;
 mod find_dlls;
+mod repair_data;
 mod write_settings;
 
 pub use create_overlay::create_instance_overlay;
 pub use find_dlls::find_steam_api_dlls;
+pub use repair_data::{download_emulator_files, restore_steam_settings};
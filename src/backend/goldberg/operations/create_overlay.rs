@@ -24,8 +24,9 @@ pub fn create_instance_overlay(
     is_windows: bool,
     handler_settings: &HashMap<String, String>,
     disable_networking: bool,
+    tmp_dir: &Path,
 ) -> Result<PathBuf, Box<dyn std::error::Error>> {
-    let overlay_dir = prepare_overlay_dir("goldberg", instance_idx)?;
+    let overlay_dir = prepare_overlay_dir(tmp_dir, "goldberg", instance_idx)?;
 
     for dll in dlls {
         let dll_dir = dll.rel_path.parent().unwrap_or(Path::new(""));
@@ -62,15 +63,15 @@ pub fn create_instance_overlay(
 
         if src_path.exists() {
             fs::copy(&src_path, &dest_path)?;
-            println!(
-                "[splitux] Goldberg overlay {}: {} -> {}",
+            log_info!(
+                "Goldberg overlay {}: {} -> {}",
                 instance_idx,
                 src_path.display(),
                 dest_path.display()
             );
         } else {
-            println!(
-                "[splitux] Warning: Goldberg DLL not found: {}",
+            log_warn!(
+                "Goldberg DLL not found: {}",
                 src_path.display()
             );
         }
@@ -93,8 +94,8 @@ pub fn create_instance_overlay(
                 handler_settings,
                 disable_networking,
             )?;
-            println!(
-                "[splitux] Goldberg overlay {}: Also created steam_settings at game root",
+            log_info!(
+                "Goldberg overlay {}: Also created steam_settings at game root",
                 instance_idx
             );
         }
@@ -105,8 +106,8 @@ pub fn create_instance_overlay(
         )?;
     }
 
-    println!(
-        "[splitux] Goldberg overlay {} created: Steam ID {}, Port {}, Broadcasts: {:?}, disable_networking: {}",
+    log_info!(
+        "Goldberg overlay {} created: Steam ID {}, Port {}, Broadcasts: {:?}, disable_networking: {}",
         instance_idx, config.steam_id, config.listen_port, config.broadcast_ports, disable_networking
     );
 
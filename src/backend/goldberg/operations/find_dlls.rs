@@ -42,8 +42,8 @@ pub fn find_steam_api_dlls(game_dir: &Path) -> Result<Vec<SteamApiDll>, Box<dyn
                         is_64bit,
                         dll_type: SteamDllType::SteamApi,
                     });
-                    println!(
-                        "[splitux] Found Steam API: {} ({})",
+                    log_info!(
+                        "Found Steam API: {} ({})",
                         rel_path.display(),
                         if is_64bit { "64-bit" } else { "32-bit" }
                     );
@@ -75,8 +75,8 @@ pub fn find_steam_api_dlls(game_dir: &Path) -> Result<Vec<SteamApiDll>, Box<dyn
             is_64bit,
             dll_type: SteamDllType::NetworkingSockets,
         });
-        println!(
-            "[splitux] Found GameNetworkingSockets: {} ({})",
+        log_info!(
+            "Found GameNetworkingSockets: {} ({})",
             rel_path.display(),
             if is_64bit { "64-bit" } else { "32-bit" }
         );
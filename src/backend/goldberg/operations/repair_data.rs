@@ -0,0 +1,100 @@
+//! Goldberg data repair
+//!
+//! Recreates missing steam_settings files and re-downloads missing emulator
+//! DLLs from the release the installer uses.
+
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use super::super::pure::{release_source, ReleaseArchive, RELEASE_URL, STEAM_SETTINGS_FILES};
+use crate::net::fetch_with_retry;
+
+/// Recreate missing files in a steam_settings folder
+///
+/// Returns a description of each file created.
+pub fn restore_steam_settings(dir: &Path) -> std::io::Result<Vec<String>> {
+    fs::create_dir_all(dir)?;
+    let mut repairs = Vec::new();
+    for file in STEAM_SETTINGS_FILES {
+        let path = dir.join(file);
+        if !path.exists() {
+            fs::write(&path, "")?;
+            repairs.push(format!("Recreated {}", path.display()));
+        }
+    }
+    Ok(repairs)
+}
+
+/// Download emulator files (relative to `dest`) from the release archives
+///
+/// Each archive is fetched once however many of its files are missing.
+/// Returns a description of each file restored.
+pub fn download_emulator_files(
+    files: &[&str],
+    dest: &Path,
+    work_dir: &Path,
+    offline: bool,
+) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut repairs = Vec::new();
+    for archive in [ReleaseArchive::Linux, ReleaseArchive::Windows] {
+        let wanted: Vec<(&str, String)> = files
+            .iter()
+            .filter_map(|f| match release_source(f) {
+                Some((a, inner)) if a == archive => Some((*f, inner)),
+                _ => None,
+            })
+            .collect();
+        if wanted.is_empty() {
+            continue;
+        }
+
+        let extract_dir = work_dir.join(archive.file_name());
+        fs::create_dir_all(&extract_dir)?;
+        let archive_path = work_dir.join(format!("{}.download", archive.file_name()));
+        download(&format!("{}/{}", RELEASE_URL, archive.file_name()), &archive_path, offline)?;
+        extract(archive, &archive_path, &extract_dir)?;
+
+        for (file, inner) in wanted {
+            let src = extract_dir.join(&inner);
+            if !src.is_file() {
+                return Err(format!("{} is missing from {}", inner, archive.file_name()).into());
+            }
+            let target = dest.join(file);
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::copy(&src, &target)?;
+            repairs.push(format!("Downloaded {}", target.display()));
+        }
+    }
+    Ok(repairs)
+}
+
+fn download(url: &str, dest: &Path, offline: bool) -> Result<(), Box<dyn Error>> {
+    log_info!("Goldberg repair: downloading {}", url);
+    let client = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(120))
+        .build()?;
+    let response = fetch_with_retry(&client, url, url, offline)?;
+    if !response.status().is_success() {
+        return Err(format!("Download failed: HTTP {}", response.status()).into());
+    }
+    fs::write(dest, response.bytes()?)?;
+    Ok(())
+}
+
+fn extract(archive: ReleaseArchive, file: &Path, dest: &Path) -> Result<(), Box<dyn Error>> {
+    match archive {
+        // No bzip2 decoder is bundled; every distro's tar has one
+        ReleaseArchive::Linux => {
+            let status = Command::new("tar").arg("-xjf").arg(file).arg("-C").arg(dest).status()?;
+            if !status.success() {
+                return Err(format!("tar failed to extract {}", file.display()).into());
+            }
+        }
+        ReleaseArchive::Windows => sevenz_rust::decompress_file(file, dest)?,
+    }
+    Ok(())
+}
@@ -6,14 +6,16 @@ use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 
+use super::super::pure::{validate_setting, LISTEN_PORT_FILE};
 use super::super::types::GoldbergConfig;
 
 /// Write Goldberg steam_settings configuration files to a directory
 ///
 /// Creates the following files:
 /// - steam_appid.txt
-/// - configs.user.ini (account name, steam id)
+/// - configs.user.ini (account name, steam id, shared save path)
 /// - configs.main.ini (networking settings)
+/// - listen_port.txt (only for a handler-set port, for older Goldberg builds)
 /// - custom_broadcasts.txt (LAN discovery ports)
 /// - auto_accept_invite.txt, auto_send_invite.txt
 /// - Any custom handler settings files
@@ -27,10 +29,13 @@ pub fn write_steam_settings(
     fs::write(dir.join("steam_appid.txt"), config.app_id.to_string())?;
 
     // configs.user.ini
-    let user_ini = format!(
+    let mut user_ini = format!(
         "[user::general]\naccount_name={}\naccount_steamid={}\n",
         config.account_name, config.steam_id
     );
+    if let Some(path) = &config.local_save_path {
+        user_ini.push_str(&format!("\n[user::saves]\nlocal_save_path={}\n", path));
+    }
     fs::write(dir.join("configs.user.ini"), user_ini)?;
 
     // configs.main.ini
@@ -55,12 +60,20 @@ share_leaderboards_over_network=0
     );
     fs::write(dir.join("configs.main.ini"), main_ini)?;
 
-    // custom_broadcasts.txt - list of other instances' ports for LAN discovery
+    if config.custom_port {
+        fs::write(dir.join(LISTEN_PORT_FILE), config.listen_port.to_string())?;
+    }
+
+    // custom_broadcasts.txt - list of other instances' ports for LAN discovery,
+    // on loopback and on the handler's LAN subnet if it set one
     if !config.broadcast_ports.is_empty() {
-        let broadcasts: String = config
-            .broadcast_ports
+        let mut hosts = vec!["127.0.0.1".to_string()];
+        if let Some(broadcast) = config.subnet_broadcast {
+            hosts.push(broadcast.to_string());
+        }
+        let broadcasts: String = hosts
             .iter()
-            .map(|p| format!("127.0.0.1:{}", p))
+            .flat_map(|host| config.broadcast_ports.iter().map(move |p| format!("{}:{}", host, p)))
             .collect::<Vec<_>>()
             .join("\n");
         fs::write(dir.join("custom_broadcasts.txt"), broadcasts)?;
@@ -72,9 +85,12 @@ share_leaderboards_over_network=0
 
     // Write handler-specific Goldberg settings files
     for (filename, content) in handler_settings {
+        if let Some(warning) = validate_setting(filename, content) {
+            log_warn!("Goldberg setting {}", warning);
+        }
         fs::write(dir.join(filename), content)?;
-        println!(
-            "[splitux] Goldberg custom setting: {} = {:?}",
+        log_info!(
+            "Goldberg custom setting: {} = {:?}",
             filename,
             if content.is_empty() {
                 "(empty)"
@@ -2,6 +2,8 @@
 //!
 //! Composes pure functions and operations into complete workflows.
 
+mod repair;
 mod setup;
 
+pub use repair::verify_goldberg_data;
 pub use setup::create_all_overlays;
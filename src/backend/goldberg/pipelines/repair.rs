@@ -0,0 +1,58 @@
+//! Goldberg data verification and repair
+
+use std::path::Path;
+
+use crate::paths::{PATH_ASSETS, PATH_PARTY, path_tmp};
+
+use super::super::operations::{download_emulator_files, restore_steam_settings};
+use super::super::pure::EMULATOR_FILES;
+
+/// Check goldberg_data and the emulator DLLs, restoring anything missing
+///
+/// Missing steam_settings files are recreated; missing DLLs are downloaded
+/// again. Returns a description of each repair, empty when all was intact.
+pub fn verify_goldberg_data(offline: bool) -> Result<Vec<String>, String> {
+    let mut repairs = restore_steam_settings(&PATH_PARTY.join("goldberg_data/steam_settings"))
+        .map_err(|e| format!("Couldn't restore goldberg_data: {}", e))?;
+
+    let goldberg_dir = PATH_ASSETS.join("goldberg");
+    let missing: Vec<&str> = EMULATOR_FILES
+        .iter()
+        .copied()
+        .filter(|f| !goldberg_dir.join(f).is_file())
+        .collect();
+    if missing.is_empty() {
+        return Ok(repairs);
+    }
+    for file in &missing {
+        log_warn!("Goldberg file missing: {}", goldberg_dir.join(file).display());
+    }
+
+    if offline {
+        return Err(format!(
+            "{} Goldberg files are missing and can't be downloaded in offline mode",
+            missing.len()
+        ));
+    }
+    if !is_writable(&PATH_ASSETS) {
+        return Err(format!(
+            "{} Goldberg files are missing from {}, which isn't writable. Reinstall Splitux to restore them.",
+            missing.len(),
+            goldberg_dir.display()
+        ));
+    }
+
+    let work_dir = path_tmp().join("goldberg_repair");
+    let downloaded = download_emulator_files(&missing, &goldberg_dir, &work_dir, offline);
+    let _ = std::fs::remove_dir_all(&work_dir);
+    repairs.extend(downloaded.map_err(|e| format!("Couldn't download Goldberg: {}", e))?);
+
+    Ok(repairs)
+}
+
+fn is_writable(dir: &Path) -> bool {
+    let probe = dir.join(".splitux_write_test");
+    let ok = std::fs::write(&probe, "").is_ok();
+    let _ = std::fs::remove_file(&probe);
+    ok
+}
@@ -2,12 +2,12 @@
 //!
 //! High-level orchestration for creating Goldberg overlays.
 
-use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
 use super::super::operations::create_instance_overlay;
 use super::super::types::{GoldbergConfig, SteamApiDll};
+use super::super::GoldbergSettings;
 
 use crate::bepinex::{install_plugin_dlls, UnityBackend};
 use crate::mods::{self, filter_dll_files, PluginSource};
@@ -41,14 +41,17 @@ fn fetch_plugin_if_needed(
 ) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
     match plugin_source {
         Some(source) if !source.is_empty() => {
-            eprintln!("[goldberg] Fetching plugin: {}", source.display_name());
+            log_info!("goldberg: Fetching plugin: {}", source.display_name());
             let cache_base = mods::cache_base();
-            let all_files = mods::fetch_plugin(source, &cache_base)?;
+            let all_files: Vec<PathBuf> = mods::fetch_plugin_with_dependencies(source, &cache_base)?
+                .into_iter()
+                .flat_map(|fetched| fetched.files)
+                .collect();
             let dlls: Vec<PathBuf> = filter_dll_files(&all_files)
                 .into_iter()
                 .cloned()
                 .collect();
-            eprintln!("[goldberg] Found {} plugin DLL(s)", dlls.len());
+            log_info!("goldberg: Found {} plugin DLL(s)", dlls.len());
             Ok(dlls)
         }
         _ => Ok(Vec::new()),
@@ -125,11 +128,12 @@ pub fn create_all_overlays(
     dlls: &[SteamApiDll],
     configs: &[GoldbergConfig],
     is_windows: bool,
-    handler_settings: &HashMap<String, String>,
-    disable_networking: bool,
-    plugin_source: &Option<PluginSource>,
+    settings: &GoldbergSettings,
     game_dir: &Path,
+    tmp_dir: &Path,
 ) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
+    let plugin_source = &settings.plugin;
+
     // Fetch plugin DLLs if specified
     let plugin_dlls = fetch_plugin_if_needed(plugin_source)?;
     let needs_bepinex = !plugin_dlls.is_empty();
@@ -156,14 +160,15 @@ pub fn create_all_overlays(
             dlls,
             config,
             is_windows,
-            handler_settings,
-            disable_networking,
+            &settings.settings,
+            settings.disable_networking,
+            tmp_dir,
         )?;
 
         // Install BepInEx + plugin if needed
         if needs_bepinex {
-            eprintln!(
-                "[goldberg] Installing BepInEx ({}) for instance {} from Thunderstore",
+            log_info!(
+                "goldberg: Installing BepInEx ({}) for instance {} from Thunderstore",
                 unity_backend.display_name(),
                 i
             );
@@ -3,5 +3,18 @@
 //! These functions have no side effects and are deterministic.
 
 mod bitness;
+mod data_files;
+mod lobby_type;
+mod network;
+mod saves;
 
 pub use bitness::detect_bitness;
+pub use data_files::{
+    release_source, ReleaseArchive, EMULATOR_FILES, RELEASE_URL, STEAM_SETTINGS_FILES,
+};
+pub use lobby_type::{validate_setting, LobbyType, LOBBY_TYPE_FILE};
+pub use network::{
+    check_listen_port, instance_ports, LanSubnet, DEFAULT_LISTEN_PORT, LISTEN_PORT_FILE,
+    MIN_LISTEN_PORT,
+};
+pub use saves::{local_save_path_value, shared_saves_dir};
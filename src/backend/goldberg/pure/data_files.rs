@@ -0,0 +1,82 @@
+//! Files Goldberg launches depend on
+//!
+//! Pure lookup tables for verifying the goldberg_data directory and the
+//! bundled emulator DLLs, and where to find each DLL in a release archive.
+
+/// Release the installer downloads the emulator from
+pub const RELEASE_URL: &str = "https://github.com/gabrielgad/gbe_fork-splitux/releases/download/nightly";
+
+/// Files under `goldberg_data/steam_settings`
+pub const STEAM_SETTINGS_FILES: &[&str] = &["auto_accept_invite.txt", "auto_send_invite.txt"];
+
+/// Emulator DLLs, relative to the `goldberg` assets folder
+pub const EMULATOR_FILES: &[&str] = &[
+    "linux64/libsteam_api.so",
+    "linux32/libsteam_api.so",
+    "win/steam_api64.dll",
+    "win/steam_api.dll",
+    "steamnetworkingsockets/x64/libsteamnetworkingsockets.dll",
+    "steamnetworkingsockets/x32/libsteamnetworkingsockets.dll",
+];
+
+/// Release archive holding an emulator file
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReleaseArchive {
+    /// emu-linux-release.tar.bz2
+    Linux,
+    /// emu-win-release.7z
+    Windows,
+}
+
+impl ReleaseArchive {
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            ReleaseArchive::Linux => "emu-linux-release.tar.bz2",
+            ReleaseArchive::Windows => "emu-win-release.7z",
+        }
+    }
+}
+
+/// Archive and in-archive path of an emulator file
+pub fn release_source(file: &str) -> Option<(ReleaseArchive, String)> {
+    let (dir, name) = file.rsplit_once('/')?;
+    let (archive, release_dir) = match dir {
+        "linux64" => (ReleaseArchive::Linux, "release/regular/x64"),
+        "linux32" => (ReleaseArchive::Linux, "release/regular/x32"),
+        "win" if name.contains("64") => (ReleaseArchive::Windows, "release/regular/x64"),
+        "win" => (ReleaseArchive::Windows, "release/regular/x32"),
+        "steamnetworkingsockets/x64" => (ReleaseArchive::Windows, "release/steamnetworkingsockets/x64"),
+        "steamnetworkingsockets/x32" => (ReleaseArchive::Windows, "release/steamnetworkingsockets/x32"),
+        _ => return None,
+    };
+    Some((archive, format!("{}/{}", release_dir, name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_emulator_file_has_a_source() {
+        for file in EMULATOR_FILES {
+            assert!(release_source(file).is_some(), "{}", file);
+        }
+    }
+
+    #[test]
+    fn sources_pick_archive_and_arch() {
+        assert_eq!(
+            release_source("linux32/libsteam_api.so"),
+            Some((ReleaseArchive::Linux, "release/regular/x32/libsteam_api.so".to_string()))
+        );
+        assert_eq!(
+            release_source("win/steam_api64.dll"),
+            Some((ReleaseArchive::Windows, "release/regular/x64/steam_api64.dll".to_string()))
+        );
+        assert_eq!(
+            release_source("win/steam_api.dll"),
+            Some((ReleaseArchive::Windows, "release/regular/x32/steam_api.dll".to_string()))
+        );
+        assert_eq!(release_source("other/steam_api.dll"), None);
+    }
+}
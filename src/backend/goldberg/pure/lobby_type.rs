@@ -0,0 +1,110 @@
+//! Lobby type override validation
+//!
+//! Pure functions for the `force_lobby_type.txt` steam_settings file, which
+//! only accepts a handful of ELobbyType values.
+
+/// Settings file that forces the lobby type of created lobbies
+pub const LOBBY_TYPE_FILE: &str = "force_lobby_type.txt";
+
+/// Steam lobby types Goldberg understands in `force_lobby_type.txt`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LobbyType {
+    Private,
+    FriendsOnly,
+    Public,
+    Invisible,
+}
+
+impl LobbyType {
+    pub const ALL: [LobbyType; 4] = [
+        LobbyType::Private,
+        LobbyType::FriendsOnly,
+        LobbyType::Public,
+        LobbyType::Invisible,
+    ];
+
+    /// ELobbyType value written to the settings file
+    pub fn value(self) -> u8 {
+        match self {
+            LobbyType::Private => 0,
+            LobbyType::FriendsOnly => 1,
+            LobbyType::Public => 2,
+            LobbyType::Invisible => 4,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            LobbyType::Private => "Private",
+            LobbyType::FriendsOnly => "Friends only",
+            LobbyType::Public => "Public",
+            LobbyType::Invisible => "Invisible",
+        }
+    }
+
+    /// Parse a settings file value, ignoring surrounding whitespace
+    pub fn parse(content: &str) -> Option<Self> {
+        let value: u8 = content.trim().parse().ok()?;
+        Self::ALL.into_iter().find(|t| t.value() == value)
+    }
+}
+
+/// Warning for a steam_settings file whose value Goldberg won't recognise
+///
+/// Unknown files are passed through untouched so new Goldberg options keep
+/// working; only files with a known set of values are checked.
+pub fn validate_setting(filename: &str, content: &str) -> Option<String> {
+    if filename == LOBBY_TYPE_FILE && LobbyType::parse(content).is_none() {
+        let known = LobbyType::ALL
+            .iter()
+            .map(|t| format!("{}={}", t.value(), t.label()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        return Some(format!(
+            "{} has unrecognized value {:?} (expected one of: {})",
+            filename, content, known
+        ));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_known_values() {
+        assert_eq!(LobbyType::parse("0"), Some(LobbyType::Private));
+        assert_eq!(LobbyType::parse("1"), Some(LobbyType::FriendsOnly));
+        assert_eq!(LobbyType::parse("2\n"), Some(LobbyType::Public));
+        assert_eq!(LobbyType::parse(" 4 "), Some(LobbyType::Invisible));
+    }
+
+    #[test]
+    fn parse_rejects_unknown_values() {
+        assert_eq!(LobbyType::parse("3"), None);
+        assert_eq!(LobbyType::parse("public"), None);
+        assert_eq!(LobbyType::parse(""), None);
+    }
+
+    #[test]
+    fn values_roundtrip() {
+        for lobby_type in LobbyType::ALL {
+            assert_eq!(LobbyType::parse(&lobby_type.value().to_string()), Some(lobby_type));
+        }
+    }
+
+    #[test]
+    fn validate_flags_bad_lobby_type() {
+        let warning = validate_setting(LOBBY_TYPE_FILE, "3").unwrap();
+        assert!(warning.contains("\"3\""));
+        assert!(warning.contains("4=Invisible"));
+        assert_eq!(validate_setting(LOBBY_TYPE_FILE, "2"), None);
+    }
+
+    #[test]
+    fn validate_passes_other_files_through() {
+        assert_eq!(validate_setting("invite_all.txt", "anything"), None);
+        assert_eq!(validate_setting("future_option.txt", "3"), None);
+    }
+}
@@ -0,0 +1,114 @@
+//! LAN networking options
+//!
+//! Pure functions for the handler's listen port and LAN subnet hint. The
+//! subnet becomes a broadcast address in `custom_broadcasts.txt`, which
+//! steers discovery onto one network on machines with VPNs or several NICs.
+
+use std::net::Ipv4Addr;
+
+/// Listen port of the first instance when the handler doesn't set one
+pub const DEFAULT_LISTEN_PORT: u16 = 47584;
+
+/// Lowest listen port a handler may set; lower ports need root
+pub const MIN_LISTEN_PORT: u16 = 1024;
+
+/// Settings file older Goldberg builds read the listen port from
+pub const LISTEN_PORT_FILE: &str = "listen_port.txt";
+
+/// Listen ports for `count` instances, counting up from `base`
+pub fn instance_ports(base: u16, count: usize) -> Result<Vec<u16>, String> {
+    (0..count)
+        .map(|i| {
+            u16::try_from(i)
+                .ok()
+                .and_then(|i| base.checked_add(i))
+                .ok_or_else(|| {
+                    format!("listen port {} leaves no room for {} instances (max 65535)", base, count)
+                })
+        })
+        .collect()
+}
+
+/// Warning for a listen port outside the usable range
+pub fn check_listen_port(port: u16) -> Option<String> {
+    if port < MIN_LISTEN_PORT {
+        return Some(format!(
+            "listen port {} is below {} and needs root",
+            port, MIN_LISTEN_PORT
+        ));
+    }
+    None
+}
+
+/// IPv4 subnet in CIDR notation, e.g. 192.168.1.0/24
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LanSubnet {
+    pub address: Ipv4Addr,
+    pub prefix: u8,
+}
+
+impl LanSubnet {
+    pub fn parse(cidr: &str) -> Result<Self, String> {
+        let cidr = cidr.trim();
+        let (address, prefix) = cidr
+            .split_once('/')
+            .ok_or_else(|| format!("'{}' is not in CIDR form (e.g. 192.168.1.0/24)", cidr))?;
+        let address: Ipv4Addr = address
+            .parse()
+            .map_err(|_| format!("'{}' is not an IPv4 address", address))?;
+        let prefix: u8 = prefix
+            .parse()
+            .ok()
+            .filter(|p| *p <= 32)
+            .ok_or_else(|| format!("'/{}' is not a prefix length between 0 and 32", prefix))?;
+        Ok(Self { address, prefix })
+    }
+
+    /// Broadcast address of the subnet
+    pub fn broadcast(&self) -> Ipv4Addr {
+        let host_mask = u32::MAX.checked_shr(self.prefix as u32).unwrap_or(0);
+        Ipv4Addr::from(u32::from(self.address) | host_mask)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ports_count_up_from_base() {
+        assert_eq!(instance_ports(47584, 3), Ok(vec![47584, 47585, 47586]));
+        assert_eq!(instance_ports(65534, 2), Ok(vec![65534, 65535]));
+    }
+
+    #[test]
+    fn ports_past_65535_are_rejected() {
+        assert!(instance_ports(65535, 2).is_err());
+    }
+
+    #[test]
+    fn low_ports_warn() {
+        assert!(check_listen_port(80).is_some());
+        assert_eq!(check_listen_port(MIN_LISTEN_PORT), None);
+    }
+
+    #[test]
+    fn subnet_broadcast() {
+        let subnet = LanSubnet::parse("192.168.1.0/24").unwrap();
+        assert_eq!(subnet.broadcast(), Ipv4Addr::new(192, 168, 1, 255));
+        let subnet = LanSubnet::parse(" 10.8.0.5/16 ").unwrap();
+        assert_eq!(subnet.broadcast(), Ipv4Addr::new(10, 8, 255, 255));
+        let subnet = LanSubnet::parse("10.0.0.7/32").unwrap();
+        assert_eq!(subnet.broadcast(), Ipv4Addr::new(10, 0, 0, 7));
+        let subnet = LanSubnet::parse("10.0.0.7/0").unwrap();
+        assert_eq!(subnet.broadcast(), Ipv4Addr::BROADCAST);
+    }
+
+    #[test]
+    fn subnet_rejects_bad_cidr() {
+        assert!(LanSubnet::parse("192.168.1.0").is_err());
+        assert!(LanSubnet::parse("192.168.1/24").is_err());
+        assert!(LanSubnet::parse("192.168.1.0/33").is_err());
+        assert!(LanSubnet::parse("eth0").is_err());
+    }
+}
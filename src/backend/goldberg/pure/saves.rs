@@ -0,0 +1,46 @@
+//! Shared save location
+//!
+//! With `goldberg.shared_saves`, every instance's emulator saves go to one
+//! folder under the data directory instead of each profile's home. Only the
+//! emulator's save folder is shared; the rest of each profile stays isolated.
+
+use std::path::{Path, PathBuf};
+
+/// Folder under the data directory holding shared saves, one subfolder per handler
+pub const SHARED_SAVES_DIR: &str = "goldberg_saves";
+
+/// Shared save folder of a handler
+pub fn shared_saves_dir(data_dir: &Path, handler_dir_name: &str) -> PathBuf {
+    data_dir.join(SHARED_SAVES_DIR).join(handler_dir_name)
+}
+
+/// `local_save_path` value for configs.user.ini
+///
+/// Windows builds of the emulator run under Proton and see the host root
+/// as drive Z:, so the path is translated for them.
+pub fn local_save_path_value(dir: &Path, is_windows: bool) -> String {
+    let path = dir.to_string_lossy();
+    if is_windows {
+        format!("Z:{}", path.replace('/', "\\"))
+    } else {
+        path.into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn save_path_is_translated_for_windows() {
+        let dir = shared_saves_dir(Path::new("/home/u/.local/share/splitux"), "MyGame");
+        assert_eq!(
+            local_save_path_value(&dir, false),
+            "/home/u/.local/share/splitux/goldberg_saves/MyGame"
+        );
+        assert_eq!(
+            local_save_path_value(&dir, true),
+            "Z:\\home\\u\\.local\\share\\splitux\\goldberg_saves\\MyGame"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 //! Goldberg backend type definitions
 
+use std::net::Ipv4Addr;
 use std::path::PathBuf;
 
 /// Information about a Steam API DLL found in the game directory
@@ -31,5 +32,12 @@ pub struct GoldbergConfig {
     pub listen_port: u16,
     /// Ports of other instances for LAN discovery
     pub broadcast_ports: Vec<u16>,
+    /// Set when the handler picked the port; also written to listen_port.txt
+    pub custom_port: bool,
+    /// Subnet broadcast address also sent discovery to (from goldberg.lan_subnet)
+    pub subnet_broadcast: Option<Ipv4Addr>,
+    /// Save folder shared by all instances (from goldberg.shared_saves),
+    /// already translated for the emulator build
+    pub local_save_path: Option<String>,
 }
 
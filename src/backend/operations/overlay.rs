@@ -1,21 +1,18 @@
 // Shared overlay directory setup for backends
 
 use std::fs;
-use std::path::PathBuf;
-
-use crate::paths::PATH_PARTY;
+use std::path::{Path, PathBuf};
 
 /// Prepare a clean overlay directory for a backend instance.
 ///
-/// Creates `{PATH_PARTY}/tmp/{backend_name}-overlay-{instance_idx}`,
+/// Creates `{tmp_dir}/{backend_name}-overlay-{instance_idx}`,
 /// removing any previous overlay at the same path.
 pub fn prepare_overlay_dir(
+    tmp_dir: &Path,
     backend_name: &str,
     instance_idx: usize,
 ) -> Result<PathBuf, Box<dyn std::error::Error>> {
-    let overlay_dir = PATH_PARTY
-        .join("tmp")
-        .join(format!("{}-overlay-{}", backend_name, instance_idx));
+    let overlay_dir = tmp_dir.join(format!("{}-overlay-{}", backend_name, instance_idx));
 
     // Clean previous overlay
     if overlay_dir.exists() {
@@ -46,6 +46,11 @@ pub struct PhotonSettings {
     /// When specified, the plugin will be downloaded and cached automatically
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub plugin: Option<crate::mods::PluginSource>,
+
+    /// BepInEx config values written into each instance's overlay, with
+    /// `{player}`/`{instance}` expanded (e.g. a per-instance room name)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub config_entries: Vec<crate::bepinex::ConfigEntry>,
 }
 
 /// Photon backend implementation
@@ -72,7 +77,8 @@ impl Backend for Photon {
         instances: &[Instance],
         is_windows: bool,
         game_root: &Path,
+        tmp_dir: &Path,
     ) -> Result<Vec<PathBuf>, Box<dyn Error>> {
-        pipelines::create_all_overlays(handler, instances, is_windows, game_root)
+        pipelines::create_all_overlays(handler, instances, is_windows, game_root, tmp_dir)
     }
 }
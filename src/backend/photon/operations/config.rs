@@ -61,8 +61,8 @@ AppIdVoice={}
     );
 
     fs::write(&config_path, config)?;
-    println!(
-        "[splitux] Photon config written: {}",
+    log_info!(
+        "Photon config written: {}",
         config_path.display()
     );
 
@@ -2,7 +2,7 @@
 //!
 //! Creates per-instance overlay directories with BepInEx configuration.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::backend::operations::prepare_overlay_dir;
 
@@ -22,8 +22,9 @@ pub fn create_instance_overlay(
     config: &PhotonConfig,
     is_windows: bool,
     backend: UnityBackend,
+    tmp_dir: &Path,
 ) -> Result<PathBuf, Box<dyn std::error::Error>> {
-    let overlay_dir = prepare_overlay_dir("photon", instance_idx)?;
+    let overlay_dir = prepare_overlay_dir(tmp_dir, "photon", instance_idx)?;
 
     // 1. Install BepInEx core
     install_bepinex_core(&overlay_dir, backend)?;
@@ -34,8 +35,8 @@ pub fn create_instance_overlay(
     // 3. Write doorstop config
     write_doorstop_config(&overlay_dir, is_windows, backend)?;
 
-    println!(
-        "[splitux] Photon overlay {} created: Player {}, Port {}, Backend: {}",
+    log_info!(
+        "Photon overlay {} created: Player {}, Port {}, Backend: {}",
         instance_idx,
         config.player_name,
         config.listen_port,
@@ -18,6 +18,7 @@ use crate::paths::PATH_PARTY;
 pub fn setup_shared_files(
     handler: &Handler,
     instances: &[Instance],
+    tmp_dir: &Path,
 ) -> Result<(), Box<dyn std::error::Error>> {
     // Get Photon settings from new optional field (Phase 7)
     let photon_settings = handler
@@ -29,7 +30,7 @@ pub fn setup_shared_files(
     }
 
     // Create shared directory for this game session
-    let shared_dir = PATH_PARTY.join("tmp").join("photon-shared");
+    let shared_dir = tmp_dir.join("photon-shared");
     fs::create_dir_all(&shared_dir)?;
 
     for shared_path_pattern in &photon_settings.shared_files {
@@ -74,8 +75,8 @@ pub fn setup_shared_files(
                 String::new()
             };
             fs::write(&shared_file, &initial_content)?;
-            println!(
-                "[splitux] Created shared file: {}",
+            log_info!(
+                "Created shared file: {}",
                 shared_file.display()
             );
         }
@@ -97,8 +98,8 @@ pub fn setup_shared_files(
 
             // Create symlink to shared file
             symlink(&shared_file, &instance_file)?;
-            println!(
-                "[splitux] {} -> {}",
+            log_info!(
+                "{} -> {}",
                 instance_file.display(),
                 shared_file.display()
             );
@@ -6,6 +6,7 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 use crate::app::load_photon_ids;
+use crate::bepinex::write_config_entries;
 use crate::handler::Handler;
 use crate::instance::Instance;
 use crate::mods::{self, filter_dll_files, PluginSource};
@@ -28,7 +29,10 @@ fn fetch_plugin_if_needed(
     match plugin_source {
         Some(source) if !source.is_empty() => {
             let cache_base = mods::cache_base();
-            let all_files = mods::fetch_plugin(source, &cache_base)?;
+            let all_files: Vec<PathBuf> = mods::fetch_plugin_with_dependencies(source, &cache_base)?
+                .into_iter()
+                .flat_map(|fetched| fetched.files)
+                .collect();
             // Filter to just DLLs and clone the paths
             let dlls: Vec<PathBuf> = filter_dll_files(&all_files)
                 .into_iter()
@@ -56,7 +60,7 @@ fn install_plugin_to_overlay(
         let filename = dll_path.file_name().ok_or("Invalid DLL path")?;
         let dest = plugins_dir.join(filename);
         fs::copy(dll_path, &dest)?;
-        eprintln!("[mods] Installed plugin: {:?}", filename);
+        log_info!("mods: Installed plugin: {:?}", filename);
     }
 
     Ok(())
@@ -68,6 +72,7 @@ pub fn create_all_overlays(
     instances: &[Instance],
     is_windows: bool,
     game_dir: &Path,
+    tmp_dir: &Path,
 ) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
     // Check if Photon App IDs are configured
     let photon_ids = load_photon_ids();
@@ -94,6 +99,11 @@ pub fn create_all_overlays(
         Some(settings) => fetch_plugin_if_needed(&settings.plugin)?,
         None => Vec::new(),
     };
+    let config_entries = handler
+        .photon_ref()
+        .map(|settings| settings.config_entries.as_slice())
+        .unwrap_or(&[]);
+    let handler_overlay = handler.path_handler.join("overlay");
 
     // Generate ports for each instance
     let instance_ports: Vec<u16> = (0..instances.len())
@@ -108,11 +118,16 @@ pub fn create_all_overlays(
             listen_port: instance_ports[i],
         };
 
-        let overlay = create_instance_overlay(i, &config, is_windows, backend)?;
+        let overlay = create_instance_overlay(i, &config, is_windows, backend, tmp_dir)?;
 
         // Install plugin DLLs to overlay
         install_plugin_to_overlay(&overlay, &plugin_dlls)?;
 
+        // Per-instance config values; shared_files stay identical across instances
+        if !config_entries.is_empty() {
+            write_config_entries(&overlay, &[&handler_overlay, game_dir], config_entries, i)?;
+        }
+
         overlays.push(overlay);
     }
 
@@ -123,6 +138,7 @@ pub fn create_all_overlays(
 pub fn generate_all_configs(
     handler: &Handler,
     instances: &[Instance],
+    tmp_dir: &Path,
 ) -> Result<(), Box<dyn std::error::Error>> {
     // Get Photon settings from new optional field (Phase 7)
     let photon_settings = handler
@@ -131,7 +147,7 @@ pub fn generate_all_configs(
 
     // Set up shared files first (before instance configs)
     if !photon_settings.shared_files.is_empty() {
-        setup_shared_files(handler, instances)?;
+        setup_shared_files(handler, instances, tmp_dir)?;
     }
 
     for (i, instance) in instances.iter().enumerate() {
@@ -14,7 +14,7 @@ use super::super::types::UnityBackend;
 pub fn detect_unity_backend(game_dir: &Path) -> UnityBackend {
     // Check for IL2CPP indicator
     if game_dir.join("GameAssembly.dll").exists() {
-        println!("[splitux] Detected Unity IL2CPP backend");
+        log_info!("Detected Unity IL2CPP backend");
         return UnityBackend::Il2Cpp;
     }
 
@@ -27,8 +27,8 @@ pub fn detect_unity_backend(game_dir: &Path) -> UnityBackend {
                 if name.ends_with("_Data") {
                     let managed_dir = path.join("Managed");
                     if managed_dir.exists() && managed_dir.is_dir() {
-                        println!(
-                            "[splitux] Detected Unity Mono backend (found {}/Managed/)",
+                        log_info!(
+                            "Detected Unity Mono backend (found {}/Managed/)",
                             name
                         );
                         return UnityBackend::Mono;
@@ -39,6 +39,6 @@ pub fn detect_unity_backend(game_dir: &Path) -> UnityBackend {
     }
 
     // Default to Mono (more common for indie games)
-    println!("[splitux] Could not detect Unity backend, defaulting to Mono");
+    log_info!("Could not detect Unity backend, defaulting to Mono");
     UnityBackend::Mono
 }
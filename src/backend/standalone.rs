@@ -12,7 +12,6 @@ use crate::bepinex::install_plugin_dlls;
 use crate::handler::Handler;
 use crate::instance::Instance;
 use crate::mods::{self, filter_plugin_files, PluginSource};
-use crate::paths::PATH_PARTY;
 
 /// Standalone backend settings
 #[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
@@ -74,6 +73,7 @@ impl Backend for Standalone {
         instances: &[Instance],
         _is_windows: bool,
         _game_dir: &Path,
+        tmp_dir: &Path,
     ) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
         // Fetch BepInEx pack from Thunderstore
         let bepinex_dir = fetch_bepinex_pack(
@@ -87,9 +87,7 @@ impl Backend for Standalone {
         let mut overlays = Vec::new();
 
         for i in 0..instances.len() {
-            let overlay_dir = PATH_PARTY
-                .join("tmp")
-                .join(format!("standalone-{}", i));
+            let overlay_dir = tmp_dir.join(format!("standalone-{}", i));
 
             // Clean previous overlay
             if overlay_dir.exists() {
@@ -104,8 +102,8 @@ impl Backend for Standalone {
             // Install plugin DLLs
             install_plugin_dlls(&overlay_dir, &plugin_dlls)?;
 
-            println!(
-                "[splitux] Standalone overlay {} created: {} plugins",
+            log_info!(
+                "Standalone overlay {} created: {} plugins",
                 i,
                 plugin_dlls.len()
             );
@@ -137,12 +135,16 @@ fn fetch_all_plugins(
         }
         // Resolve plugin with defaults (community inheritance, latest version, etc.)
         let resolved = plugin.resolve(default_community, &cache_base)?;
-        let files = mods::fetch_plugin(&resolved, &cache_base)?;
-        let plugin_files: Vec<PathBuf> = filter_plugin_files(&files)
-            .into_iter()
-            .cloned()
-            .collect();
-        all_files.extend(plugin_files);
+        // Dependencies come first so they're installed before the plugins using them
+        for fetched in mods::fetch_plugin_with_dependencies(&resolved, &cache_base)? {
+            let plugin_files = filter_plugin_files(&fetched.files);
+            log_info!(
+                "standalone: {}: {} plugin file(s)",
+                fetched.source.display_name(),
+                plugin_files.len()
+            );
+            all_files.extend(plugin_files.into_iter().cloned());
+        }
     }
 
     Ok(all_files)
@@ -188,18 +190,18 @@ fn install_bepinex_from_package(
     let winhttp_src = pack_root.join("winhttp.dll");
     if winhttp_src.exists() {
         fs::copy(&winhttp_src, overlay_dir.join("winhttp.dll"))?;
-        eprintln!("[standalone] Installed winhttp.dll (doorstop loader)");
+        log_info!("standalone: Installed winhttp.dll (doorstop loader)");
     }
 
     // Copy doorstop_config.ini
     let config_src = pack_root.join("doorstop_config.ini");
     if config_src.exists() {
         fs::copy(&config_src, overlay_dir.join("doorstop_config.ini"))?;
-        eprintln!("[standalone] Installed doorstop_config.ini");
+        log_info!("standalone: Installed doorstop_config.ini");
     }
 
-    eprintln!(
-        "[standalone] Installed BepInEx from Thunderstore package: {:?}",
+    log_info!(
+        "standalone: Installed BepInEx from Thunderstore package: {:?}",
         bepinex_src
     );
 
@@ -4,10 +4,11 @@
 //! Used by: Photon, Facepunch, Goldberg (with plugins)
 
 mod operations;
+mod pure;
 mod types;
 
 pub use operations::{
     bepinex_backend_available, install_bepinex_core, install_doorstop, install_plugin_dlls,
-    write_doorstop_config,
+    write_config_entries, write_doorstop_config,
 };
-pub use types::UnityBackend;
+pub use types::{ConfigEntry, UnityBackend};
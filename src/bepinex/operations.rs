@@ -7,7 +7,8 @@ use std::path::{Path, PathBuf};
 
 use crate::paths::PATH_ASSETS;
 
-use super::types::UnityBackend;
+use super::pure::{expand_instance_placeholders, is_game_relative, set_cfg_value};
+use super::types::{ConfigEntry, UnityBackend};
 
 /// Get BepInEx resource path, with platform-aware subdirectory
 ///
@@ -61,6 +62,48 @@ pub fn install_bepinex_core(
     Ok(())
 }
 
+/// Write templated config entries into one instance's overlay
+///
+/// Each file starts from the copy already in the overlay, else the first
+/// of `base_dirs` that has it (handler overlay, then game folder), so only
+/// the listed keys differ between instances.
+pub fn write_config_entries(
+    overlay_dir: &Path,
+    base_dirs: &[&Path],
+    entries: &[ConfigEntry],
+    instance_idx: usize,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut files: Vec<&str> = Vec::new();
+    for entry in entries {
+        if !files.contains(&entry.file.as_str()) {
+            files.push(&entry.file);
+        }
+    }
+
+    for file in files {
+        if !is_game_relative(file) {
+            return Err(format!("Config file '{}' must be relative to the game folder", file).into());
+        }
+        let dest = overlay_dir.join(file);
+        let mut content = std::iter::once(overlay_dir)
+            .chain(base_dirs.iter().copied())
+            .find_map(|dir| fs::read_to_string(dir.join(file)).ok())
+            .unwrap_or_default();
+
+        for entry in entries.iter().filter(|e| e.file == file) {
+            let value = expand_instance_placeholders(&entry.value, instance_idx);
+            content = set_cfg_value(&content, &entry.section, &entry.key, &value);
+        }
+
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&dest, content)?;
+        log_info!("Instance {} config written: {}", instance_idx, dest.display());
+    }
+    Ok(())
+}
+
 /// Install doorstop loader (platform-specific)
 ///
 /// - Windows: Copies `winhttp.dll`
@@ -134,7 +177,7 @@ pub fn install_plugin_dlls(
         let filename = dll_path.file_name().ok_or("Invalid DLL path")?;
         let dest = plugins_dir.join(filename);
         fs::copy(dll_path, &dest)?;
-        eprintln!("[bepinex] Installed plugin: {:?}", filename);
+        log_info!("bepinex: Installed plugin: {:?}", filename);
     }
 
     Ok(())
@@ -0,0 +1,94 @@
+//! BepInEx config editing (pure, no I/O)
+
+use std::path::{Component, Path};
+
+/// Substitute `{player}` (1-based) and `{instance}` (0-based)
+pub fn expand_instance_placeholders(value: &str, instance_idx: usize) -> String {
+    value
+        .replace("{player}", &(instance_idx + 1).to_string())
+        .replace("{instance}", &instance_idx.to_string())
+}
+
+/// Whether a config file path stays inside the game folder
+pub fn is_game_relative(file: &str) -> bool {
+    let path = Path::new(file);
+    !file.is_empty()
+        && !path.is_absolute()
+        && !path.components().any(|c| matches!(c, Component::ParentDir))
+}
+
+/// Set `key = value` in a section of a BepInEx `.cfg` file
+///
+/// An existing key in the section is replaced in place, keeping the
+/// comments BepInEx writes above it. A missing key is added at the end of
+/// its section, and a missing section at the end of the file.
+pub fn set_cfg_value(content: &str, section: &str, key: &str, value: &str) -> String {
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    let header = format!("[{}]", section);
+    let entry = format!("{} = {}", key, value);
+
+    let Some(start) = lines.iter().position(|l| l.trim() == header) else {
+        if lines.last().is_some_and(|l| !l.trim().is_empty()) {
+            lines.push(String::new());
+        }
+        lines.push(header);
+        lines.push(entry);
+        return lines.join("\n") + "\n";
+    };
+
+    let end = lines[start + 1..]
+        .iter()
+        .position(|l| l.trim_start().starts_with('['))
+        .map_or(lines.len(), |i| start + 1 + i);
+
+    let existing = (start + 1..end).find(|&i| {
+        let line = lines[i].trim_start();
+        !line.starts_with('#')
+            && line
+                .split_once('=')
+                .is_some_and(|(k, _)| k.trim() == key)
+    });
+    match existing {
+        Some(i) => lines[i] = entry,
+        None => {
+            // Insert after the section's last non-blank line
+            let insert_at = (start + 1..end)
+                .rev()
+                .find(|&i| !lines[i].trim().is_empty())
+                .map_or(start + 1, |i| i + 1);
+            lines.insert(insert_at, entry);
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CFG: &str = "[General]\n\n## Room to join\n# Setting type: String\nRoom = lobby\n\n[Network]\nRegion = us\n";
+
+    #[test]
+    fn replaces_existing_key_in_its_section() {
+        let out = set_cfg_value(CFG, "General", "Room", "splitux-2");
+        assert!(out.contains("## Room to join\n# Setting type: String\nRoom = splitux-2\n"));
+        assert!(out.contains("Region = us"));
+    }
+
+    #[test]
+    fn adds_missing_key_and_section() {
+        let out = set_cfg_value(CFG, "General", "Port", "5000");
+        assert!(out.contains("Room = lobby\nPort = 5000\n\n[Network]"));
+        let out = set_cfg_value(CFG, "Voice", "Enabled", "false");
+        assert!(out.ends_with("Region = us\n\n[Voice]\nEnabled = false\n"));
+        assert_eq!(set_cfg_value("", "A", "b", "c"), "[A]\nb = c\n");
+    }
+
+    #[test]
+    fn placeholders_and_paths() {
+        assert_eq!(expand_instance_placeholders("room-{player}-{instance}", 1), "room-2-1");
+        assert!(is_game_relative("BepInEx/config/x.cfg"));
+        assert!(!is_game_relative("/etc/x.cfg"));
+        assert!(!is_game_relative("BepInEx/../../x.cfg"));
+    }
+}
@@ -18,3 +18,17 @@ impl UnityBackend {
         }
     }
 }
+
+/// One value to set in a BepInEx `.cfg` file in each instance's overlay
+///
+/// `value` may use `{player}` (1-based) and `{instance}` (0-based), so
+/// each instance gets its own room name, region or port.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ConfigEntry {
+    /// Config file relative to the game folder, e.g. `BepInEx/config/com.example.mod.cfg`
+    pub file: String,
+    /// Section header without brackets
+    pub section: String,
+    pub key: String,
+    pub value: String,
+}
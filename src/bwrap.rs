@@ -5,7 +5,11 @@
 //!
 //! Structure:
 //! - `pure/matching.rs` — stateless device matching and arg building
+//! - `pure/binds.rs` — extra bind mount arg building
+//! - `pure/null_paths.rs` — glob matching for game_null_paths
 //! - `operations/base.rs` — base container args, SDL/audio/BepInEx env setup
+//! - `operations/binds.rs` — handler-declared extra bind mounts
+//! - `operations/null_paths.rs` — game_null_paths binds, expanding patterns
 //! - `operations/devices.rs` — device discovery (js, hidraw, evdev)
 //! - `operations/blocking.rs` — device blocking with permission checks
 
@@ -14,6 +18,8 @@ mod pure;
 
 // Re-export all public functions to maintain the existing API
 pub use operations::base::{add_base_args, setup_audio_env, setup_bepinex_env, setup_sdl_env};
+pub use operations::binds::add_extra_binds;
+pub use operations::null_paths::add_null_path_binds;
 pub use operations::blocking::{get_evdev_hidraw_blocking_args, get_js_blocking_args};
 pub use operations::devices::{
     get_assigned_gamepad_paths, glob_js_devices, log_assigned_devices,
@@ -1,5 +1,7 @@
 // Bwrap operations — atomic I/O functions
 
 pub mod base;
+pub mod binds;
 pub mod blocking;
 pub mod devices;
+pub mod null_paths;
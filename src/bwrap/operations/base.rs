@@ -39,13 +39,19 @@ pub fn setup_sdl_env(cmd: &mut Command, gamepad_paths: &[String]) {
 /// Set up audio routing environment variables inside the bwrap container
 ///
 /// Sets PULSE_SINK to route audio to a specific sink (works for both
-/// PulseAudio and PipeWire via pipewire-pulse compatibility layer)
-pub fn setup_audio_env(cmd: &mut Command, sink_name: &str) {
+/// PulseAudio and PipeWire via pipewire-pulse compatibility layer).
+/// With `player_sink`, also sets PIPEWIRE_NODE for clients that talk to
+/// PipeWire directly, since the instance's own sink is their only target.
+pub fn setup_audio_env(cmd: &mut Command, sink_name: &str, player_sink: bool) {
     if sink_name.is_empty() {
         return;
     }
     // PULSE_SINK works for both PulseAudio and PipeWire (via pipewire-pulse)
     cmd.args(["--setenv", "PULSE_SINK", sink_name]);
+    // Native PipeWire clients ignore PULSE_SINK; this is their stream target
+    if player_sink {
+        cmd.args(["--setenv", "PIPEWIRE_NODE", sink_name]);
+    }
 }
 
 /// Set up BepInEx environment variables for Linux native games
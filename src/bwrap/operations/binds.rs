@@ -0,0 +1,42 @@
+// Handler-declared extra bind mounts
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use super::super::pure::binds::bind_args;
+use crate::handler::BindSpec;
+use crate::save_sync::pure::expand_path;
+
+/// Add a handler's extra bind mounts to the bwrap command
+///
+/// Sources that don't exist and relative destinations are skipped with a
+/// warning, so a missing shared folder doesn't fail the launch.
+pub fn add_extra_binds(cmd: &mut Command, binds: &[BindSpec], instance_idx: usize) {
+    for bind in binds {
+        let src = expand_path(&bind.src);
+        let dest = if bind.dest.is_empty() {
+            PathBuf::new()
+        } else {
+            expand_path(&bind.dest)
+        };
+
+        if !src.is_absolute() || !src.exists() {
+            log_warn!(
+                "extra bind source {} doesn't exist, skipping",
+                src.display()
+            );
+            continue;
+        }
+        if !bind.dest.is_empty() && !dest.is_absolute() {
+            log_warn!(
+                "extra bind destination {} must be an absolute path, skipping",
+                dest.display()
+            );
+            continue;
+        }
+
+        let args = bind_args(&src, &dest, bind.readonly);
+        log_info!("Instance {}: {} {} -> {}", instance_idx, args[0], args[1], args[2]);
+        cmd.args(args);
+    }
+}
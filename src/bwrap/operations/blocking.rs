@@ -23,8 +23,8 @@ fn filter_accessible_paths(paths: &[String]) -> Vec<&str> {
 /// accessible. Gamescope may recreate js devices with different ownership.
 pub fn get_js_blocking_args(initial_js_devices: &[String], instance_idx: usize) -> Vec<String> {
     let js_to_block = filter_accessible_paths(initial_js_devices);
-    println!(
-        "[splitux] Instance {}: Blocking {} js devices: {:?}",
+    log_info!(
+        "Instance {}: Blocking {} js devices: {:?}",
         instance_idx,
         js_to_block.len(),
         js_to_block
@@ -47,8 +47,8 @@ pub fn get_evdev_hidraw_blocking_args(
     let unassigned_evdev = filter_unassigned_gamepad_evdev(input_devices, assigned_indices);
     let evdev_to_block = filter_accessible_paths(&unassigned_evdev);
 
-    println!(
-        "[splitux] Instance {}: Blocking {} evdev devices: {:?}",
+    log_info!(
+        "Instance {}: Blocking {} evdev devices: {:?}",
         instance_idx,
         evdev_to_block.len(),
         evdev_to_block
@@ -60,8 +60,8 @@ pub fn get_evdev_hidraw_blocking_args(
     let unassigned_hidraw = get_gamepad_hidraw_devices(input_devices, assigned_indices);
     let hidraw_to_block = filter_accessible_paths(&unassigned_hidraw);
 
-    println!(
-        "[splitux] Instance {}: Blocking {} hidraw devices: {:?}",
+    log_info!(
+        "Instance {}: Blocking {} hidraw devices: {:?}",
         instance_idx,
         hidraw_to_block.len(),
         hidraw_to_block
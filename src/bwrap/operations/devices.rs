@@ -109,8 +109,8 @@ pub fn log_assigned_devices(
     assigned_indices: &[usize],
     instance_idx: usize,
 ) {
-    println!(
-        "[splitux] Instance {}: Assigned device indices: {:?}",
+    log_debug!(
+        "Instance {}: Assigned device indices: {:?}",
         instance_idx, assigned_indices
     );
 }
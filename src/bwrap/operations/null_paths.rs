@@ -0,0 +1,104 @@
+// Handler game_null_paths, with glob patterns expanded against the game dir
+
+use std::path::Path;
+use std::process::Command;
+
+use super::super::pure::null_paths::{glob_matches, glob_may_match_below, is_glob, prune_nested};
+
+/// Hide the handler's null paths inside the container
+///
+/// Literal entries are used as-is. Patterns are expanded against `gamedir`
+/// when the command is built; a pattern matching nothing is logged and
+/// skipped. Files are covered with /dev/null, directories with an empty one.
+pub fn add_null_path_binds(
+    cmd: &mut Command,
+    gamedir: &Path,
+    entries: &[String],
+    instance_idx: usize,
+    tmp_dir: &Path,
+) {
+    let mut subpaths = Vec::new();
+    for entry in entries {
+        if !is_glob(entry) {
+            subpaths.push(entry.clone());
+            continue;
+        }
+        let matches = expand_glob(gamedir, entry);
+        if matches.is_empty() {
+            log_info!("Instance {}: null path pattern {} matched nothing", instance_idx, entry);
+        }
+        subpaths.extend(matches);
+    }
+
+    let null_dir = tmp_dir.join("null");
+    for subpath in prune_nested(subpaths) {
+        let game_subpath = gamedir.join(&subpath);
+        if game_subpath.is_file() {
+            cmd.args(["--bind", "/dev/null", &game_subpath.to_string_lossy()]);
+        } else if game_subpath.is_dir() {
+            cmd.args([
+                "--bind",
+                &null_dir.to_string_lossy(),
+                &game_subpath.to_string_lossy(),
+            ]);
+        }
+    }
+}
+
+/// Paths under `root` matching a pattern, relative to `root` and sorted
+fn expand_glob(root: &Path, pattern: &str) -> Vec<String> {
+    let mut matches = Vec::new();
+    walk(root, "", pattern, &mut matches);
+    matches.sort();
+    matches
+}
+
+fn walk(root: &Path, rel: &str, pattern: &str, matches: &mut Vec<String>) {
+    let Ok(entries) = std::fs::read_dir(root.join(rel)) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        let rel_path = if rel.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}/{}", rel, name)
+        };
+        if glob_matches(pattern, &rel_path) {
+            // Anything below is hidden along with it
+            matches.push(rel_path);
+            continue;
+        }
+        // Symlinked directories aren't followed, so links can't loop the walk
+        if entry.file_type().is_ok_and(|t| t.is_dir()) && glob_may_match_below(pattern, &rel_path) {
+            walk(root, &rel_path, pattern, matches);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::test_dir;
+
+    #[test]
+    fn patterns_expand_against_the_game_dir() {
+        let root = test_dir("null-paths");
+        std::fs::create_dir_all(root.join("cache/sub")).unwrap();
+        std::fs::create_dir_all(root.join("logs/old")).unwrap();
+        for file in ["cache/a.tmp", "cache/b.tmp", "cache/keep.dat", "cache/sub/c.tmp", "logs/old/1.txt"] {
+            std::fs::write(root.join(file), "").unwrap();
+        }
+
+        assert_eq!(expand_glob(&root, "cache/*.tmp"), vec!["cache/a.tmp", "cache/b.tmp"]);
+        assert_eq!(
+            expand_glob(&root, "**/*.tmp"),
+            vec!["cache/a.tmp", "cache/b.tmp", "cache/sub/c.tmp"]
+        );
+        assert_eq!(expand_glob(&root, "logs/**"), vec!["logs"]);
+        assert!(expand_glob(&root, "missing/*.tmp").is_empty());
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
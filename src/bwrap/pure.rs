@@ -1,3 +1,5 @@
 // Pure functions for bwrap — stateless, deterministic, no I/O
 
+pub mod binds;
 pub mod matching;
+pub mod null_paths;
@@ -0,0 +1,33 @@
+// Extra bind mount argument building
+
+use std::path::Path;
+
+/// bwrap arguments mounting `src` at `dest`
+///
+/// An empty `dest` mounts `src` at the same path inside the container.
+pub fn bind_args(src: &Path, dest: &Path, readonly: bool) -> [String; 3] {
+    let flag = if readonly { "--ro-bind" } else { "--bind" };
+    let dest = if dest.as_os_str().is_empty() { src } else { dest };
+    [
+        flag.to_string(),
+        src.to_string_lossy().to_string(),
+        dest.to_string_lossy().to_string(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn readonly_and_same_path_binds() {
+        assert_eq!(
+            bind_args(Path::new("/srv/assets"), Path::new("/opt/game/assets"), true),
+            ["--ro-bind", "/srv/assets", "/opt/game/assets"]
+        );
+        assert_eq!(
+            bind_args(Path::new("/home/u/.config/tool"), Path::new(""), false),
+            ["--bind", "/home/u/.config/tool", "/home/u/.config/tool"]
+        );
+    }
+}
@@ -65,6 +65,8 @@ pub fn build_blocking_args(paths_to_block: &[&str]) -> Vec<String> {
 }
 
 /// Get evdev paths for gamepads NOT assigned to this instance (pure filter).
+///
+/// Includes the sibling nodes (touchpad, motion sensors) of those gamepads.
 pub fn filter_unassigned_gamepad_evdev(
     input_devices: &[DeviceInfo],
     assigned_indices: &[usize],
@@ -73,7 +75,7 @@ pub fn filter_unassigned_gamepad_evdev(
         .iter()
         .enumerate()
         .filter(|(i, dev)| dev.device_type == DeviceType::Gamepad && !assigned_indices.contains(i))
-        .map(|(_, dev)| dev.path.clone())
+        .flat_map(|(_, dev)| std::iter::once(&dev.path).chain(&dev.siblings).cloned())
         .collect()
 }
 
@@ -106,6 +108,7 @@ mod tests {
             enabled: true,
             device_type,
             uniq: uniq.to_string(),
+            siblings: Vec::new(),
         }
     }
 
@@ -308,6 +311,18 @@ mod tests {
         assert_eq!(result, vec!["/dev/input/event0", "/dev/input/event2"]);
     }
 
+    #[test]
+    fn filter_unassigned_includes_sibling_nodes() {
+        let mut pad = make_device("/dev/input/event0", DeviceType::Gamepad, "aabb");
+        pad.siblings = vec!["/dev/input/event1".to_string(), "/dev/input/event2".to_string()];
+        let mut assigned_pad = make_device("/dev/input/event3", DeviceType::Gamepad, "ccdd");
+        assigned_pad.siblings = vec!["/dev/input/event4".to_string()];
+        let devices = vec![pad, assigned_pad];
+
+        let result = filter_unassigned_gamepad_evdev(&devices, &[1]);
+        assert_eq!(result, vec!["/dev/input/event0", "/dev/input/event1", "/dev/input/event2"]);
+    }
+
     // ── filter_assigned_gamepad_paths ───────────────────────────────
 
     #[test]
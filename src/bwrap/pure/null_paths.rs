@@ -0,0 +1,130 @@
+// Glob matching for game_null_paths entries (no I/O)
+//
+// Patterns are relative to the game root. `*` and `?` match within one path
+// component; `**` matches any number of components, including none.
+
+/// Whether a null path entry is a pattern rather than a literal path
+pub fn is_glob(pattern: &str) -> bool {
+    pattern.contains(['*', '?'])
+}
+
+/// Whether a path relative to the game root matches a pattern
+pub fn glob_matches(pattern: &str, path: &str) -> bool {
+    match_components(&components(pattern), &components(path))
+}
+
+/// Whether anything inside a directory could match a pattern
+///
+/// Used to avoid walking parts of the game root a pattern can't reach.
+pub fn glob_may_match_below(pattern: &str, dir: &str) -> bool {
+    fn go(pattern: &[&str], dir: &[&str]) -> bool {
+        match (pattern.split_first(), dir.split_first()) {
+            (Some((&"**", _)), _) => true,
+            (Some(_), None) => true,
+            (None, _) => false,
+            (Some((first, rest)), Some((name, dir_rest))) => {
+                component_matches(first, name) && go(rest, dir_rest)
+            }
+        }
+    }
+    go(&components(pattern), &components(dir))
+}
+
+/// Drop duplicates and paths inside another path of the list
+///
+/// Once a directory is nulled its contents are gone from the container, so
+/// nested mount points would fail. Order is otherwise kept.
+pub fn prune_nested(paths: Vec<String>) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for path in &paths {
+        let parts = components(path);
+        let covered = paths.iter().any(|other| {
+            let other_parts = components(other);
+            other_parts.len() < parts.len() && parts.starts_with(&other_parts)
+        });
+        if !covered && !out.iter().any(|p| components(p) == parts) {
+            out.push(path.clone());
+        }
+    }
+    out
+}
+
+fn components(path: &str) -> Vec<&str> {
+    path.split('/').filter(|c| !c.is_empty() && *c != ".").collect()
+}
+
+fn match_components(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| match_components(rest, &path[skip..])),
+        Some((first, rest)) => path
+            .split_first()
+            .is_some_and(|(name, path_rest)| component_matches(first, name) && match_components(rest, path_rest)),
+    }
+}
+
+/// Match one path component against a pattern with `*` and `?`
+fn component_matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    fn go(p: &[char], n: &[char]) -> bool {
+        match p.split_first() {
+            None => n.is_empty(),
+            Some(('*', rest)) => (0..=n.len()).any(|skip| go(rest, &n[skip..])),
+            Some(('?', rest)) => !n.is_empty() && go(rest, &n[1..]),
+            Some((c, rest)) => n.first() == Some(c) && go(rest, &n[1..]),
+        }
+    }
+    go(&pattern, &name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literal_paths_are_not_globs() {
+        assert!(!is_glob("Engine/Binaries/crashreporter.exe"));
+        assert!(is_glob("cache/*.tmp"));
+        assert!(is_glob("logs/**"));
+    }
+
+    #[test]
+    fn star_stays_in_one_component() {
+        assert!(glob_matches("cache/*.tmp", "cache/a.tmp"));
+        assert!(!glob_matches("cache/*.tmp", "cache/sub/a.tmp"));
+        assert!(!glob_matches("cache/*.tmp", "cache/a.tmp.bak"));
+        assert!(glob_matches("save?", "save1"));
+        assert!(!glob_matches("save?", "save"));
+    }
+
+    #[test]
+    fn double_star_spans_components() {
+        assert!(glob_matches("logs/**", "logs"));
+        assert!(glob_matches("logs/**", "logs/a/b.txt"));
+        assert!(glob_matches("**/*.tmp", "a.tmp"));
+        assert!(glob_matches("**/*.tmp", "x/y/a.tmp"));
+        assert!(!glob_matches("**/*.tmp", "x/y/a.log"));
+    }
+
+    #[test]
+    fn walk_only_where_pattern_reaches() {
+        assert!(glob_may_match_below("cache/*.tmp", ""));
+        assert!(glob_may_match_below("cache/*.tmp", "cache"));
+        assert!(!glob_may_match_below("cache/*.tmp", "data"));
+        assert!(!glob_may_match_below("cache/*.tmp", "cache/sub"));
+        assert!(glob_may_match_below("**/*.tmp", "any/depth"));
+    }
+
+    #[test]
+    fn nested_and_duplicate_paths_are_pruned() {
+        let paths = vec![
+            "logs/a".to_string(),
+            "logs".to_string(),
+            "cache/x.tmp".to_string(),
+            "cache/x.tmp".to_string(),
+        ];
+        assert_eq!(prune_nested(paths), vec!["logs".to_string(), "cache/x.tmp".to_string()]);
+        assert!(prune_nested(Vec::new()).is_empty());
+    }
+}
@@ -0,0 +1,128 @@
+// Host capability checks
+// Tools Splitux shells out to are probed once per process and cached, so
+// launches can fall back or fail with a clear message instead of an opaque
+// spawn error, and settings can show what's missing
+
+use std::path::Path;
+use std::sync::LazyLock;
+
+use crate::paths::BIN_GSC_SPLITUX;
+use crate::util::command_in_path;
+
+/// Which host tools and devices are available
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Capabilities {
+    /// `fuse-overlayfs` in PATH
+    pub fuse_overlayfs: bool,
+    /// `/dev/fuse` exists, so FUSE mounts can work at all
+    pub fuse_device: bool,
+    /// `bwrap` in PATH
+    pub bwrap: bool,
+    /// `gamescope` in PATH
+    pub gamescope: bool,
+    /// The bundled or installed gamescope-splitux binary exists
+    pub gamescope_splitux: bool,
+}
+
+/// One line of the capability report shown in settings
+pub struct CapabilityLine {
+    pub name: &'static str,
+    pub available: bool,
+    /// What goes wrong without it
+    pub effect: &'static str,
+}
+
+static CAPABILITIES: LazyLock<Capabilities> = LazyLock::new(|| {
+    let caps = Capabilities {
+        fuse_overlayfs: command_in_path("fuse-overlayfs"),
+        fuse_device: Path::new("/dev/fuse").exists(),
+        bwrap: command_in_path("bwrap"),
+        gamescope: command_in_path("gamescope"),
+        gamescope_splitux: BIN_GSC_SPLITUX.is_file(),
+    };
+    for line in caps.report().iter().filter(|line| !line.available) {
+        log_warn!("{} not found: {}", line.name, line.effect);
+    }
+    caps
+});
+
+/// Cached capabilities, probed on first use
+pub fn capabilities() -> &'static Capabilities {
+    &CAPABILITIES
+}
+
+impl Capabilities {
+    /// Whether game folders can be mounted with fuse-overlayfs
+    pub fn overlay_mounts(&self) -> bool {
+        self.fuse_overlayfs && self.fuse_device
+    }
+
+    /// Whether the gamescope binary the config asks for exists
+    pub fn gamescope_for(&self, input_holding: bool) -> bool {
+        if input_holding {
+            self.gamescope_splitux
+        } else {
+            self.gamescope
+        }
+    }
+
+    /// Every checked tool, with what it's needed for
+    pub fn report(&self) -> Vec<CapabilityLine> {
+        vec![
+            CapabilityLine {
+                name: "fuse-overlayfs",
+                available: self.fuse_overlayfs,
+                effect: "games run from their original folder, shared by all players",
+            },
+            CapabilityLine {
+                name: "/dev/fuse",
+                available: self.fuse_device,
+                effect: "games run from their original folder, shared by all players",
+            },
+            CapabilityLine {
+                name: "bwrap",
+                available: self.bwrap,
+                effect: "games can't be launched",
+            },
+            CapabilityLine {
+                name: "gamescope",
+                available: self.gamescope,
+                effect: "games can't be launched with input holding off",
+            },
+            CapabilityLine {
+                name: "gamescope-splitux",
+                available: self.gamescope_splitux,
+                effect: "games can't be launched with input holding on",
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all() -> Capabilities {
+        Capabilities {
+            fuse_overlayfs: true,
+            fuse_device: true,
+            bwrap: true,
+            gamescope: true,
+            gamescope_splitux: true,
+        }
+    }
+
+    #[test]
+    fn overlays_need_binary_and_device() {
+        assert!(all().overlay_mounts());
+        assert!(!Capabilities { fuse_device: false, ..all() }.overlay_mounts());
+        assert!(!Capabilities { fuse_overlayfs: false, ..all() }.overlay_mounts());
+    }
+
+    #[test]
+    fn gamescope_follows_input_holding() {
+        let caps = Capabilities { gamescope_splitux: false, ..all() };
+        assert!(caps.gamescope_for(false));
+        assert!(!caps.gamescope_for(true));
+    }
+}
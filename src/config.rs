@@ -3,8 +3,9 @@ pub mod types;
 
 // Re-export types
 pub use types::{
-    PadFilterType, SplituxConfig, WindowManagerType,
+    ComboButton, DeviceFilterList, GameEntry, GameSort, KillCombo, NavAction, NavBindings, PadFilterType, PageTab, SplituxConfig, StartFullscreen,
+    StickNav, WindowGeometry, WindowManagerType,
 };
 
 // Re-export operations
-pub use operations::{load_cfg, load_photon_ids, save_cfg};
+pub use operations::{load_cfg, load_offline_mode, load_photon_ids, save_cfg};
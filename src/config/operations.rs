@@ -1,4 +1,4 @@
 pub mod io;
 
 // Re-exports
-pub use io::{load_cfg, load_photon_ids, save_cfg};
+pub use io::{load_cfg, load_offline_mode, load_photon_ids, save_cfg};
@@ -10,6 +10,11 @@ pub fn load_photon_ids() -> PhotonAppIds {
     load_cfg().photon_app_ids
 }
 
+/// Load the offline mode setting from config (convenience function)
+pub fn load_offline_mode() -> bool {
+    load_cfg().offline_mode
+}
+
 pub fn load_cfg() -> SplituxConfig {
     let path = PATH_PARTY.join("settings.json");
 
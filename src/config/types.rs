@@ -1,7 +1,10 @@
 use crate::audio::AudioSystemPreference;
 use crate::wm::presets::LayoutPresets;
+use eframe::egui::Key;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::SystemTime;
 
 #[derive(Serialize, Deserialize, Clone, PartialEq)]
 pub enum PadFilterType {
@@ -10,6 +13,22 @@ pub enum PadFilterType {
     OnlySteamInput,
 }
 
+/// Devices shown or hidden regardless of `PadFilterType`
+///
+/// Entries are a USB `vendor:product` in hex (`044f:b10a`), a whole vendor
+/// (`044f:*`), or a regular expression matched against the evdev device name.
+/// Anything without a colon is a name, so "dead" never reads as a vendor.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct DeviceFilterList {
+    /// When not empty, only matching gamepads are listed; keyboards and mice
+    /// are unaffected so they can't all be filtered out by mistake
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow: Vec<String>,
+    /// Matching devices of any type are never listed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub block: Vec<String>,
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Default)]
 pub enum WindowManagerType {
     #[default]
@@ -19,6 +38,160 @@ pub enum WindowManagerType {
     GamescopeOnly,
 }
 
+/// Whether the launcher GUI starts fullscreen (`--fullscreen` always wins)
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug, Default)]
+pub enum StartFullscreen {
+    /// Fullscreen inside a gamescope session or on a single large display
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+/// Minimum display height (in pixels) treated as a TV in auto mode
+const AUTO_FULLSCREEN_MIN_HEIGHT: u32 = 2160;
+
+impl StartFullscreen {
+    /// Resolve the setting for the current session and monitor heights
+    pub fn resolve(self, gamescope_session: bool, monitor_heights: &[u32]) -> bool {
+        match self {
+            StartFullscreen::Always => true,
+            StartFullscreen::Never => false,
+            StartFullscreen::Auto => {
+                gamescope_session
+                    || matches!(monitor_heights, [height] if *height >= AUTO_FULLSCREEN_MIN_HEIGHT)
+            }
+        }
+    }
+}
+
+/// Controller buttons that can make up the kill-session combo
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum ComboButton {
+    Start,
+    Select,
+    Guide,
+    LB,
+    RB,
+    LeftStick,
+    RightStick,
+    A,
+    B,
+    X,
+    Y,
+}
+
+impl ComboButton {
+    pub const ALL: [ComboButton; 11] = [
+        ComboButton::Start,
+        ComboButton::Select,
+        ComboButton::Guide,
+        ComboButton::LB,
+        ComboButton::RB,
+        ComboButton::LeftStick,
+        ComboButton::RightStick,
+        ComboButton::A,
+        ComboButton::B,
+        ComboButton::X,
+        ComboButton::Y,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ComboButton::Start => "Start",
+            ComboButton::Select => "Select",
+            ComboButton::Guide => "Guide",
+            ComboButton::LB => "LB",
+            ComboButton::RB => "RB",
+            ComboButton::LeftStick => "L3",
+            ComboButton::RightStick => "R3",
+            ComboButton::A => "A",
+            ComboButton::B => "B",
+            ComboButton::X => "X",
+            ComboButton::Y => "Y",
+        }
+    }
+}
+
+/// Controller combo held during a session, to end it or summon the launcher
+///
+/// Off by default so a stray button mash can't take down a game.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct KillCombo {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Buttons that must all be held on one controller
+    #[serde(default = "default_kill_combo_buttons")]
+    pub buttons: Vec<ComboButton>,
+    /// How long the buttons must be held (seconds)
+    #[serde(default = "default_kill_combo_hold")]
+    pub hold_secs: f32,
+}
+
+fn default_kill_combo_buttons() -> Vec<ComboButton> {
+    vec![ComboButton::Start, ComboButton::Select]
+}
+
+fn default_kill_combo_hold() -> f32 {
+    2.0
+}
+
+/// Summon combo default: shorter hold, buttons games rarely hold together
+fn default_summon_combo() -> KillCombo {
+    KillCombo {
+        enabled: false,
+        buttons: vec![ComboButton::Select, ComboButton::LB],
+        hold_secs: 1.0,
+    }
+}
+
+impl Default for KillCombo {
+    fn default() -> Self {
+        KillCombo {
+            enabled: false,
+            buttons: default_kill_combo_buttons(),
+            hold_secs: default_kill_combo_hold(),
+        }
+    }
+}
+
+/// Analog stick navigation in the launcher (not passed on to games)
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct StickNav {
+    /// Stick deflection ignored around center, in percent
+    #[serde(default = "default_stick_deadzone")]
+    pub deadzone: u8,
+    /// How long a direction is held before it starts repeating
+    #[serde(default = "default_stick_repeat_delay")]
+    pub repeat_delay_ms: u32,
+    /// Interval between repeats while the direction stays held
+    #[serde(default = "default_stick_repeat_rate")]
+    pub repeat_rate_ms: u32,
+}
+
+fn default_stick_deadzone() -> u8 {
+    50
+}
+
+fn default_stick_repeat_delay() -> u32 {
+    300
+}
+
+fn default_stick_repeat_rate() -> u32 {
+    80
+}
+
+impl Default for StickNav {
+    fn default() -> Self {
+        StickNav {
+            deadzone: default_stick_deadzone(),
+            repeat_delay_ms: default_stick_repeat_delay(),
+            repeat_rate_ms: default_stick_repeat_rate(),
+        }
+    }
+}
+
 /// Photon App IDs for LocalMultiplayer mod
 /// Get free App IDs from https://dashboard.photonengine.com
 #[derive(Clone, Serialize, Deserialize, Default)]
@@ -42,6 +215,221 @@ pub struct PanelState {
     pub custom_width: Option<f32>,
 }
 
+/// Top-level page shown as a tab in the top bar
+#[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub enum PageTab {
+    Games,
+    Registry,
+    Settings,
+}
+
+impl PageTab {
+    pub const ALL: [PageTab; 3] = [PageTab::Games, PageTab::Registry, PageTab::Settings];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PageTab::Games => "Games",
+            PageTab::Registry => "Registry",
+            PageTab::Settings => "Settings",
+        }
+    }
+
+    /// Games is the home page and Settings is where tabs are configured,
+    /// so neither can be hidden
+    pub fn can_hide(self) -> bool {
+        self == PageTab::Registry
+    }
+}
+
+/// Position and visibility of a top-level page
+#[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
+pub struct PageTabEntry {
+    pub page: PageTab,
+    #[serde(default = "default_true")]
+    pub visible: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_page_tabs() -> Vec<PageTabEntry> {
+    PageTab::ALL
+        .iter()
+        .map(|&page| PageTabEntry { page, visible: true })
+        .collect()
+}
+
+/// Logical navigation action that can be bound to a keyboard key
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum NavAction {
+    Up,
+    Down,
+    Left,
+    Right,
+    Confirm,
+    Back,
+    NextTab,
+    PrevTab,
+    OpenFolder,
+}
+
+impl NavAction {
+    pub const ALL: [NavAction; 9] = [
+        NavAction::Up,
+        NavAction::Down,
+        NavAction::Left,
+        NavAction::Right,
+        NavAction::Confirm,
+        NavAction::Back,
+        NavAction::NextTab,
+        NavAction::PrevTab,
+        NavAction::OpenFolder,
+    ];
+
+    /// Key used in settings.json
+    pub fn id(self) -> &'static str {
+        match self {
+            NavAction::Up => "up",
+            NavAction::Down => "down",
+            NavAction::Left => "left",
+            NavAction::Right => "right",
+            NavAction::Confirm => "confirm",
+            NavAction::Back => "back",
+            NavAction::NextTab => "next_tab",
+            NavAction::PrevTab => "prev_tab",
+            NavAction::OpenFolder => "open_folder",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            NavAction::Up => "Up",
+            NavAction::Down => "Down",
+            NavAction::Left => "Left",
+            NavAction::Right => "Right",
+            NavAction::Confirm => "Confirm (A)",
+            NavAction::Back => "Back (B)",
+            NavAction::NextTab => "Next page (RB)",
+            NavAction::PrevTab => "Previous page (LB)",
+            NavAction::OpenFolder => "Open game's handler folder",
+        }
+    }
+}
+
+/// Keyboard keys for gamepad-style navigation
+///
+/// Stored as `{ action id: key name }` so an unknown or missing entry falls
+/// back to its default instead of discarding the whole config. An unbound
+/// action is stored as an empty name.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(from = "HashMap<String, String>", into = "HashMap<String, String>")]
+pub struct NavBindings {
+    keys: [Option<Key>; NavAction::ALL.len()],
+}
+
+impl NavBindings {
+    pub fn key(&self, action: NavAction) -> Option<Key> {
+        self.keys[action as usize]
+    }
+
+    pub fn set(&mut self, action: NavAction, key: Option<Key>) {
+        self.keys[action as usize] = key;
+    }
+
+    /// Action bound to a key, if any
+    pub fn action_for(&self, key: Key) -> Option<NavAction> {
+        NavAction::ALL.into_iter().find(|&a| self.key(a) == Some(key))
+    }
+}
+
+impl Default for NavBindings {
+    fn default() -> Self {
+        NavBindings {
+            // Page switching is left unbound: Tab and ` are too easy to hit
+            // while typing or moving focus
+            keys: [
+                Some(Key::ArrowUp),
+                Some(Key::ArrowDown),
+                Some(Key::ArrowLeft),
+                Some(Key::ArrowRight),
+                Some(Key::Enter),
+                Some(Key::Escape),
+                None,
+                None,
+                Some(Key::O),
+            ],
+        }
+    }
+}
+
+impl From<HashMap<String, String>> for NavBindings {
+    fn from(map: HashMap<String, String>) -> Self {
+        let mut bindings = NavBindings::default();
+        for action in NavAction::ALL {
+            match map.get(action.id()).map(String::as_str) {
+                Some("") => bindings.set(action, None),
+                Some(name) => {
+                    if let Some(key) = Key::from_name(name) {
+                        bindings.set(action, Some(key));
+                    }
+                }
+                None => {}
+            }
+        }
+        bindings
+    }
+}
+
+impl From<NavBindings> for HashMap<String, String> {
+    fn from(bindings: NavBindings) -> Self {
+        NavAction::ALL
+            .into_iter()
+            .map(|a| (a.id().to_string(), bindings.key(a).map_or("", |k| k.name()).to_string()))
+            .collect()
+    }
+}
+
+/// Ordering of the games list in the left panel
+#[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Debug, Default)]
+pub enum GameSort {
+    #[default]
+    FavoritesFirst,
+    RecentlyPlayed,
+    Name,
+}
+
+impl GameSort {
+    pub const ALL: [GameSort; 3] = [GameSort::FavoritesFirst, GameSort::RecentlyPlayed, GameSort::Name];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            GameSort::FavoritesFirst => "Favorites first",
+            GameSort::RecentlyPlayed => "Recently played",
+            GameSort::Name => "Name",
+        }
+    }
+
+    /// The sort after this one, wrapping around
+    pub fn next(self) -> GameSort {
+        let i = GameSort::ALL.iter().position(|&s| s == self).unwrap_or(0);
+        GameSort::ALL[(i + 1) % GameSort::ALL.len()]
+    }
+}
+
+/// Launcher-side state of one game, keyed by handler directory name
+#[derive(Clone, Serialize, Deserialize, PartialEq, Debug, Default)]
+pub struct GameEntry {
+    #[serde(default)]
+    pub favorite: bool,
+    /// Time of the last successful launch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_played: Option<SystemTime>,
+    /// The handler's launch notes were dismissed for good
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub hide_launch_notes: bool,
+}
+
 /// UI layout state (panel positions, sizes, collapse state)
 #[derive(Clone, Serialize, Deserialize)]
 pub struct LayoutState {
@@ -49,6 +437,48 @@ pub struct LayoutState {
     pub games_panel: PanelState,
     #[serde(default = "default_devices_panel")]
     pub devices_panel: PanelState,
+    /// Show the instance page as a compact table instead of cards
+    #[serde(default)]
+    pub instance_table_view: bool,
+    /// Top-level pages in tab order, with visibility
+    #[serde(default = "default_page_tabs")]
+    pub page_tabs: Vec<PageTabEntry>,
+    /// Ordering of the games list
+    #[serde(default)]
+    pub games_sort: GameSort,
+}
+
+impl LayoutState {
+    /// Pages shown in the top bar and LB/RB cycling, in order
+    pub fn visible_page_tabs(&self) -> Vec<PageTab> {
+        self.page_tabs
+            .iter()
+            .filter(|e| e.visible)
+            .map(|e| e.page)
+            .collect()
+    }
+
+    /// Drop duplicate entries, append missing pages, and unhide locked pages
+    fn normalize_page_tabs(&mut self) {
+        let mut seen: Vec<PageTab> = Vec::new();
+        self.page_tabs.retain(|e| {
+            if seen.contains(&e.page) {
+                return false;
+            }
+            seen.push(e.page);
+            true
+        });
+        for page in PageTab::ALL {
+            if !seen.contains(&page) {
+                self.page_tabs.push(PageTabEntry { page, visible: true });
+            }
+        }
+        for entry in &mut self.page_tabs {
+            if !entry.page.can_hide() {
+                entry.visible = true;
+            }
+        }
+    }
 }
 
 fn default_devices_panel() -> PanelState {
@@ -63,10 +493,58 @@ impl Default for LayoutState {
         Self {
             games_panel: PanelState::default(),
             devices_panel: default_devices_panel(),
+            instance_table_view: false,
+            page_tabs: default_page_tabs(),
+            games_sort: GameSort::default(),
         }
     }
 }
 
+/// Launcher window position and size, in logical pixels
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq)]
+pub struct WindowGeometry {
+    /// Outer position; None where the compositor doesn't report it (Wayland)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<[f32; 2]>,
+    /// Inner (content) size
+    pub size: [f32; 2],
+}
+
+impl WindowGeometry {
+    /// Fit the geometry onto the available monitors
+    ///
+    /// `monitors` are [x, y, width, height] rects. The window stays on the
+    /// monitor holding its center (or overlapping it most); if it is on none
+    /// of them, e.g. after a display was disconnected, it moves to the first.
+    /// Returns None when there are no monitors to fit against.
+    pub fn clamped_to(&self, monitors: &[[f32; 4]]) -> Option<Self> {
+        let overlap = |m: &[f32; 4], pos: [f32; 2]| {
+            let w = (pos[0] + self.size[0]).min(m[0] + m[2]) - pos[0].max(m[0]);
+            let h = (pos[1] + self.size[1]).min(m[1] + m[3]) - pos[1].max(m[1]);
+            w.max(0.0) * h.max(0.0)
+        };
+
+        let monitor = match self.position {
+            Some(pos) => monitors
+                .iter()
+                .filter(|m| overlap(m, pos) > 0.0)
+                .max_by(|a, b| overlap(a, pos).total_cmp(&overlap(b, pos)))
+                .or(monitors.first())?,
+            None => monitors.iter().max_by(|a, b| (a[2] * a[3]).total_cmp(&(b[2] * b[3])))?,
+        };
+
+        let size = [self.size[0].min(monitor[2]), self.size[1].min(monitor[3])];
+        let position = self.position.map(|pos| {
+            [
+                pos[0].clamp(monitor[0], monitor[0] + monitor[2] - size[0]),
+                pos[1].clamp(monitor[1], monitor[1] + monitor[3] - size[1]),
+            ]
+        });
+
+        Some(Self { position, size })
+    }
+}
+
 /// Audio routing configuration for per-instance audio output
 #[derive(Clone, Serialize, Deserialize, Default)]
 pub struct AudioConfig {
@@ -80,6 +558,17 @@ pub struct AudioConfig {
     /// e.g., { 0: "alsa_output.usb-headphones", 1: "alsa_output.pci-speakers" }
     #[serde(default)]
     pub default_assignments: HashMap<usize, String>,
+    /// Give each unassigned instance its own null sink named after the
+    /// player, for routing in an external mixer
+    #[serde(default)]
+    pub player_sinks: bool,
+    /// Output for instances with neither a session override nor a profile
+    /// preference (guests, and profiles that never picked one)
+    #[serde(default)]
+    pub guest_sink: Option<String>,
+    /// Description of guest_sink, to re-bind it if the sink is renumbered
+    #[serde(default)]
+    pub guest_sink_description: Option<String>,
 }
 
 /// Main application configuration
@@ -104,6 +593,9 @@ pub struct SplituxConfig {
     #[serde(default)]
     pub layout_presets: LayoutPresets,
     pub pad_filter_type: PadFilterType,
+    /// Per-device allow and block lists, applied on top of `pad_filter_type`
+    #[serde(default)]
+    pub device_filter: DeviceFilterList,
     #[serde(default)]
     pub allow_multiple_instances_on_same_device: bool,
     pub disable_mount_gamedirs: bool,
@@ -117,22 +609,77 @@ pub struct SplituxConfig {
     /// The machine owner typically sets their profile as master
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub master_profile: Option<String>,
+    /// Profile whose saves every guest starts from, for sharing one
+    /// account's progress; the copies are never synced back
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guest_save_base: Option<String>,
     /// UI layout preferences (panel collapse state, widths)
     #[serde(default)]
     pub layout: LayoutState,
     /// Custom device names (maps device unique ID -> user-assigned name)
     #[serde(default)]
     pub device_aliases: HashMap<String, String>,
+    /// SDL controller mapping files (maps device unique ID -> gamecontrollerdb.txt path)
+    #[serde(default)]
+    pub controller_mappings: HashMap<String, String>,
+    /// Favorites and last launch times (maps handler directory name -> state)
+    #[serde(default)]
+    pub games: HashMap<String, GameEntry>,
     /// Delay before each instance spawn for input initialization (seconds)
     /// Allows previous instance's SDL/libinput to complete before spawning next
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub input_init_delay: Option<f64>,
+    /// Prepare profiles, Proton prefixes, saves, and overlay mounts for all instances concurrently.
+    /// Game spawns stay sequential with input_init_delay between them.
+    #[serde(default)]
+    pub parallel_prepare: bool,
+    /// Launch all native games with the Steam overlay and Steam Input disabled
+    #[serde(default)]
+    pub disable_steam_overlay: bool,
+    /// Keyboard keys for navigation actions
+    #[serde(default)]
+    pub nav_bindings: NavBindings,
+    /// Launcher window geometry from the last session (ignored with --fullscreen)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub window_geometry: Option<WindowGeometry>,
+    /// Start the launcher fullscreen without passing --fullscreen
+    #[serde(default)]
+    pub start_fullscreen: StartFullscreen,
+    /// Controller combo that kills all instances mid-session
+    #[serde(default)]
+    pub kill_combo: KillCombo,
+    /// Controller combo that brings the launcher above the games mid-session
+    #[serde(default = "default_summon_combo")]
+    pub summon_combo: KillCombo,
+    /// Deadzone and hold-to-repeat timing for stick navigation in the launcher
+    #[serde(default)]
+    pub stick_nav: StickNav,
+    /// Keep the launcher window above the game windows while a session runs
+    #[serde(default)]
+    pub keep_launcher_above: bool,
+    /// Serve session status as JSON on a Unix socket while a game runs
+    #[serde(default)]
+    pub ipc_socket_enabled: bool,
+    /// How many timestamped save snapshots to keep per handler
+    #[serde(default = "default_save_backup_keep")]
+    pub save_backup_keep: usize,
+    /// Never use the network: no update check, registry fetches or mod downloads
+    #[serde(default)]
+    pub offline_mode: bool,
+    /// Where scratch files and overlay upper/work dirs go, instead of the
+    /// data folder; a `splitux-tmp` folder is created inside it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tmp_dir: Option<String>,
 }
 
 fn default_enable_kwin_script() -> bool {
     true
 }
 
+fn default_save_backup_keep() -> usize {
+    5
+}
+
 impl Default for SplituxConfig {
     fn default() -> Self {
         SplituxConfig {
@@ -147,19 +694,50 @@ impl Default for SplituxConfig {
             vertical_two_player: false,
             layout_presets: LayoutPresets::default(),
             pad_filter_type: PadFilterType::NoSteamInput,
+            device_filter: DeviceFilterList::default(),
             allow_multiple_instances_on_same_device: false,
             disable_mount_gamedirs: false,
             photon_app_ids: PhotonAppIds::default(),
             audio: AudioConfig::default(),
             master_profile: None,
+            guest_save_base: None,
             layout: LayoutState::default(),
             device_aliases: HashMap::new(),
+            controller_mappings: HashMap::new(),
+            games: HashMap::new(),
             input_init_delay: None,
+            parallel_prepare: false,
+            disable_steam_overlay: false,
+            nav_bindings: NavBindings::default(),
+            window_geometry: None,
+            start_fullscreen: StartFullscreen::Auto,
+            kill_combo: KillCombo::default(),
+            summon_combo: default_summon_combo(),
+            stick_nav: StickNav::default(),
+            keep_launcher_above: false,
+            ipc_socket_enabled: false,
+            save_backup_keep: default_save_backup_keep(),
+            offline_mode: false,
+            tmp_dir: None,
         }
     }
 }
 
 impl SplituxConfig {
+    /// Custom scratch location with ~ and $HOME expanded, if one is set
+    pub fn tmp_dir_path(&self) -> Option<PathBuf> {
+        self.tmp_dir
+            .as_deref()
+            .map(str::trim)
+            .filter(|dir| !dir.is_empty())
+            .map(crate::save_sync::pure::expand_path)
+    }
+
+    /// Scratch directory for a session launched with these settings
+    pub fn scratch_dir(&self) -> PathBuf {
+        crate::paths::scratch_dir(self.tmp_dir_path().as_deref())
+    }
+
     /// Migrate legacy settings to current format
     /// Call this after loading config from disk
     pub fn migrate(&mut self) {
@@ -183,6 +761,92 @@ impl SplituxConfig {
         if self.layout_presets.four_player == "4p_main_plus_3" {
             self.layout_presets.four_player = "4p_grid".to_string();
         }
+
+        // Keep page tabs complete if the config was hand-edited
+        self.layout.normalize_page_tabs();
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn start_fullscreen_auto_in_gamescope_session() {
+        assert!(StartFullscreen::Auto.resolve(true, &[1080, 1440]));
+        assert!(!StartFullscreen::Auto.resolve(false, &[1080, 1440]));
+    }
+
+    #[test]
+    fn start_fullscreen_auto_on_single_large_display() {
+        assert!(StartFullscreen::Auto.resolve(false, &[2160]));
+        assert!(!StartFullscreen::Auto.resolve(false, &[1080]));
+        assert!(!StartFullscreen::Auto.resolve(false, &[2160, 2160]));
+        assert!(!StartFullscreen::Auto.resolve(false, &[]));
+    }
+
+    #[test]
+    fn start_fullscreen_explicit_modes_ignore_session() {
+        assert!(StartFullscreen::Always.resolve(false, &[1080]));
+        assert!(!StartFullscreen::Never.resolve(true, &[2160]));
+    }
+
+    #[test]
+    fn tmp_dir_blank_means_default() {
+        let mut cfg = SplituxConfig::default();
+        assert_eq!(cfg.tmp_dir_path(), None);
+        cfg.tmp_dir = Some("  ".to_string());
+        assert_eq!(cfg.tmp_dir_path(), None);
+        cfg.tmp_dir = Some("/mnt/games".to_string());
+        assert_eq!(cfg.tmp_dir_path(), Some(PathBuf::from("/mnt/games")));
+    }
+
+    const DUAL: [[f32; 4]; 2] = [[0.0, 0.0, 1920.0, 1080.0], [1920.0, 0.0, 2560.0, 1440.0]];
+
+    #[test]
+    fn window_geometry_kept_on_its_monitor() {
+        let geom = WindowGeometry { position: Some([2000.0, 100.0]), size: [1080.0, 540.0] };
+        assert_eq!(geom.clamped_to(&DUAL), Some(geom));
+    }
+
+    #[test]
+    fn window_geometry_moved_from_disconnected_monitor() {
+        let geom = WindowGeometry { position: Some([2000.0, 100.0]), size: [1080.0, 540.0] };
+        let clamped = geom.clamped_to(&DUAL[..1]).unwrap();
+        assert_eq!(clamped.position, Some([840.0, 100.0]));
+        assert_eq!(clamped.size, [1080.0, 540.0]);
+    }
+
+    #[test]
+    fn window_geometry_shrunk_to_fit() {
+        let geom = WindowGeometry { position: None, size: [3000.0, 2000.0] };
+        let clamped = geom.clamped_to(&DUAL).unwrap();
+        assert_eq!(clamped.size, [2560.0, 1440.0]);
+        assert_eq!(clamped.position, None);
+        assert_eq!(geom.clamped_to(&[]), None);
+    }
+
+    #[test]
+    fn nav_bindings_round_trip() {
+        let mut bindings = NavBindings::default();
+        bindings.set(NavAction::NextTab, Some(Key::PageDown));
+        bindings.set(NavAction::OpenFolder, None);
+        let json = serde_json::to_string(&bindings).unwrap();
+        let loaded: NavBindings = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded, bindings);
+        assert_eq!(loaded.action_for(Key::PageDown), Some(NavAction::NextTab));
+        assert_eq!(loaded.action_for(Key::Tab), None);
+        assert_eq!(loaded.key(NavAction::OpenFolder), None);
+    }
+
+    #[test]
+    fn nav_bindings_fall_back_per_entry() {
+        let loaded: NavBindings =
+            serde_json::from_str(r#"{"up": "W", "down": "NotAKey"}"#).unwrap();
+        assert_eq!(loaded.key(NavAction::Up), Some(Key::W));
+        assert_eq!(loaded.key(NavAction::Down), Some(Key::ArrowDown));
+        assert_eq!(loaded.key(NavAction::Confirm), Some(Key::Enter));
+        assert_eq!(loaded.key(NavAction::NextTab), None);
+    }
+}
@@ -0,0 +1,187 @@
+// Diagnostics bundle for bug reports
+// Gathers the recent log files, the selected handler's handler.yaml and
+// resolved paths, the settings, monitors and input devices into one zip that
+// can be attached to an issue. Photon App IDs are redacted from the settings,
+// and the handler's custom env values from handler.yaml (as in the logs).
+
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+use crate::app::SplituxConfig;
+use crate::capabilities::capabilities;
+use crate::handler::Handler;
+use crate::input::InputDevice;
+use crate::logging::recent_logs;
+use crate::monitor::Monitor;
+use crate::paths::path_tmp;
+use crate::util::zip_dir;
+
+/// Log files included: this run's and the one before it
+const BUNDLE_LOGS: usize = 2;
+
+/// Placeholder for redacted settings values
+const REDACTED: &str = "<redacted>";
+
+/// Settings as JSON with account-specific values blanked out
+pub fn redacted_config(cfg: &SplituxConfig) -> Result<serde_json::Value, serde_json::Error> {
+    let mut value = serde_json::to_value(cfg)?;
+    if let Some(ids) = value.get_mut("photon_app_ids").and_then(|v| v.as_object_mut()) {
+        for id in ids.values_mut() {
+            if id.as_str().is_some_and(|s| !s.is_empty()) {
+                *id = serde_json::Value::from(REDACTED);
+            }
+        }
+    }
+    Ok(value)
+}
+
+/// handler.yaml with the values of its custom `env` blanked out
+///
+/// Returns None if the file doesn't parse, so it is left out rather than
+/// copied unredacted.
+pub fn redacted_handler_yaml(content: &str) -> Option<String> {
+    let mut value: serde_yaml::Value = serde_yaml::from_str(content).ok()?;
+    if let Some(env) = value.get_mut("env")
+        && let Some(vars) = env.as_str()
+    {
+        let redacted: Vec<String> = vars
+            .split_whitespace()
+            .map(|var| match var.split_once('=') {
+                Some((key, _)) => format!("{}={}", key, REDACTED),
+                None => var.to_string(),
+            })
+            .collect();
+        *env = serde_yaml::Value::from(redacted.join(" "));
+    }
+    serde_yaml::to_string(&value).ok()
+}
+
+/// One line describing a monitor
+pub fn describe_monitor(monitor: &Monitor) -> String {
+    let [x, y, _, _] = monitor.rect();
+    format!(
+        "{}: {}x{} at {},{} scale {}",
+        monitor.name(),
+        monitor.width(),
+        monitor.height(),
+        x,
+        y,
+        monitor.scale()
+    )
+}
+
+/// One line describing an input device
+pub fn describe_device(device: &InputDevice) -> String {
+    let disabled = if device.enabled() { "" } else { " (disabled)" };
+    format!(
+        "{:?}: {} [{}]{}",
+        device.device_type(),
+        device.fancyname(),
+        device.path(),
+        disabled
+    )
+}
+
+/// Write the bundle to `dest`, adding a .zip extension if it has none
+///
+/// Returns the path written. Monitors and devices are passed as lines from
+/// `describe_monitor`/`describe_device`, since devices stay on the UI thread.
+pub fn export_diagnostics(
+    dest: &Path,
+    handler: Option<&Handler>,
+    cfg: &SplituxConfig,
+    monitors: &[String],
+    devices: &[String],
+) -> Result<PathBuf, Box<dyn Error>> {
+    let mut dest = dest.to_path_buf();
+    if dest.extension().is_none() {
+        dest.set_extension("zip");
+    }
+
+    let staging = path_tmp().join("diagnostics");
+    if staging.exists() {
+        std::fs::remove_dir_all(&staging)?;
+    }
+    std::fs::create_dir_all(&staging)?;
+
+    let result = write_bundle(&staging, handler, cfg, monitors, devices).and_then(|_| {
+        if dest.is_file() {
+            std::fs::remove_file(&dest)?;
+        }
+        zip_dir(&staging, &dest)
+    });
+    let _ = std::fs::remove_dir_all(&staging);
+    result?;
+
+    if !dest.is_file() {
+        return Err("Couldn't create the zip file; check that the zip command is installed.".into());
+    }
+    log_info!("Diagnostics exported to {}", dest.display());
+    Ok(dest)
+}
+
+fn write_bundle(
+    dir: &Path,
+    handler: Option<&Handler>,
+    cfg: &SplituxConfig,
+    monitors: &[String],
+    devices: &[String],
+) -> Result<(), Box<dyn Error>> {
+    for log in recent_logs(BUNDLE_LOGS) {
+        if let Some(name) = log.file_name() {
+            std::fs::copy(&log, dir.join(name))?;
+        }
+    }
+
+    let mut system = format!("Splitux {}\n\nSystem tools:\n", env!("CARGO_PKG_VERSION"));
+    for line in capabilities().report() {
+        let status = if line.available { "found" } else { "missing" };
+        system.push_str(&format!("{}: {}\n", line.name, status));
+    }
+    std::fs::write(dir.join("system.txt"), system)?;
+
+    std::fs::write(dir.join("settings.json"), serde_json::to_string_pretty(&redacted_config(cfg)?)?)?;
+    std::fs::write(dir.join("monitors.txt"), monitors.join("\n"))?;
+    std::fs::write(dir.join("devices.txt"), devices.join("\n"))?;
+
+    if let Some(h) = handler {
+        let yaml = std::fs::read_to_string(h.path_handler.join("handler.yaml")).ok();
+        if let Some(redacted) = yaml.as_deref().and_then(redacted_handler_yaml) {
+            std::fs::write(dir.join("handler.yaml"), redacted)?;
+        }
+        let summary: Vec<String> = h
+            .resolved_summary(cfg)
+            .into_iter()
+            .map(|(label, value)| format!("{}: {}", label, value))
+            .collect();
+        std::fs::write(
+            dir.join("handler-resolved.txt"),
+            format!("{}\n{}\n", h.display(), summary.join("\n")),
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn photon_ids_are_redacted() {
+        let mut cfg = SplituxConfig::default();
+        cfg.photon_app_ids.pun_app_id = "abc-123".to_string();
+        let value = redacted_config(&cfg).unwrap();
+        assert_eq!(value["photon_app_ids"]["pun_app_id"], REDACTED);
+        // Unset IDs stay empty so the report shows they were never set
+        assert_eq!(value["photon_app_ids"]["voice_app_id"], "");
+    }
+
+    #[test]
+    fn handler_env_values_are_redacted() {
+        let yaml = "name: Test\nenv: API_KEY=secret DXVK_HUD=1\n";
+        let redacted = redacted_handler_yaml(yaml).unwrap();
+        assert!(!redacted.contains("secret"));
+        assert!(redacted.contains("API_KEY=<redacted> DXVK_HUD=<redacted>"));
+        assert!(redacted.contains("name: Test"));
+    }
+}
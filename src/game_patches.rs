@@ -241,16 +241,16 @@ pub fn generate_patched_content(
         // Read existing file and apply patches
         let content = fs::read_to_string(&full_path)?;
         let format = detect_format(&content);
-        println!(
-            "[game_patches] Detected format {:?} for {}",
+        log_info!(
+            "game_patches: Detected format {:?} for {}",
             format, file_path
         );
         apply_patches(&content, patches, format)
     } else {
         // Create new file with patches
         // Use INI style for new files as it's most common
-        println!(
-            "[game_patches] Creating new file {} with {} patches",
+        log_info!(
+            "game_patches: Creating new file {} with {} patches",
             file_path,
             patches.len()
         );
@@ -273,7 +273,7 @@ pub fn apply_game_patches(
         return Ok(());
     }
 
-    println!("[game_patches] Applying {} file patches", patches.len());
+    log_info!("game_patches: Applying {} file patches", patches.len());
 
     for (file_path, file_patches) in patches {
         // Validate path doesn't escape game root
@@ -294,7 +294,7 @@ pub fn apply_game_patches(
         }
 
         fs::write(&output_path, patched_content)?;
-        println!("[game_patches] Written patched file: {}", output_path.display());
+        log_info!("game_patches: Written patched file: {}", output_path.display());
     }
 
     Ok(())
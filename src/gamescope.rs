@@ -7,6 +7,7 @@ use std::path::Path;
 use std::process::Command;
 
 use crate::app::SplituxConfig;
+use crate::input::{DeviceInfo, DeviceType};
 use crate::instance::Instance;
 use crate::monitor::Monitor;
 use crate::paths::BIN_GSC_SPLITUX;
@@ -54,21 +55,43 @@ pub fn setup_env(cmd: &mut Command) {
     cmd.env("SDL_VIDEO_WAYLAND_SCALE", "1");
 }
 
+/// Gamescope output size (-W/-H) and nested size (-w/-h) for an instance
+///
+/// `width`/`height` are the instance's physical pixels. On a scaled output the
+/// compositor sizes windows in logical pixels, so the output window is divided
+/// by the scale to cover exactly its region while the game keeps rendering at
+/// the physical resolution. At scale 1.0 no nested size is needed.
+pub fn scaled_resolution(width: u32, height: u32, scale: f32) -> ((u32, u32), Option<(u32, u32)>) {
+    if !scale.is_finite() || scale <= 1.0 {
+        return ((width, height), None);
+    }
+    let output = (
+        (width as f32 / scale).round() as u32,
+        (height as f32 / scale).round() as u32,
+    );
+    (output, Some((width, height)))
+}
+
 /// Add gamescope command-line arguments
-pub fn add_args(cmd: &mut Command, instance: &Instance, _monitors: &[Monitor], cfg: &SplituxConfig) {
-    // Resolution
-    cmd.args([
-        "-W",
-        &instance.width.to_string(),
-        "-H",
-        &instance.height.to_string(),
-    ]);
+pub fn add_args(cmd: &mut Command, instance: &Instance, monitors: &[Monitor], cfg: &SplituxConfig) {
+    // Resolution, in logical pixels for the window and physical for the game
+    let scale = monitors.get(instance.monitor).map_or(1.0, |m| m.scale());
+    let ((out_w, out_h), mut nested) = scaled_resolution(instance.width, instance.height, scale);
+    // A resolution override renders the game at that size, scaled up to the window
+    if let Some(size) = instance.render_override(scale, cfg) {
+        log_info!("Instance resolution override: {}x{}", size.0, size.1);
+        nested = Some(size);
+    }
+    cmd.args(["-W", &out_w.to_string(), "-H", &out_h.to_string()]);
+    if let Some((w, h)) = nested {
+        cmd.args(["-w", &w.to_string(), "-h", &h.to_string()]);
+    }
 
     // Cursor hiding
     cmd.args(["--hide-cursor-delay", "1000"]);
 
-    // Force grab cursor if enabled
-    if cfg.gamescope_force_grab_cursor {
+    // Force grab cursor if enabled (per-instance, falling back to the global setting)
+    if instance.grabs_cursor(cfg) {
         cmd.arg("--force-grab-cursor");
     }
 
@@ -80,12 +103,36 @@ pub fn add_args(cmd: &mut Command, instance: &Instance, _monitors: &[Monitor], c
     }
 }
 
+/// Devices gamescope-splitux should hold for an instance
+///
+/// The keyboards and mice assigned to the instance, followed by the gptokeyb
+/// virtual device if there is one. Each instance only holds its own devices,
+/// so different keyboards can drive different instances.
+pub fn hold_device_paths(
+    input_devices: &[DeviceInfo],
+    assigned: &[usize],
+    virtual_device: Option<&Path>,
+) -> Vec<String> {
+    let mut paths: Vec<String> = assigned
+        .iter()
+        .filter_map(|&i| input_devices.get(i))
+        .filter(|d| matches!(d.device_type, DeviceType::Keyboard | DeviceType::Mouse))
+        .map(|d| d.path.clone())
+        .collect();
+    if let Some(vdev) = virtual_device {
+        paths.push(vdev.display().to_string());
+    }
+    paths
+}
+
 /// Add input device holding arguments for gamescope-splitux
 ///
-/// When a virtual device path is provided (from gptokeyb), gamescope will
-/// read exclusively from that device for keyboard/mouse input.
+/// gamescope reads keyboard/mouse input exclusively from the held devices:
+/// the instance's own keyboards and mice, plus the gptokeyb virtual device.
 pub fn add_input_holding_args(
     cmd: &mut Command,
+    input_devices: &[DeviceInfo],
+    instance: &Instance,
     virtual_device: Option<&Path>,
     cfg: &SplituxConfig,
 ) {
@@ -93,8 +140,8 @@ pub fn add_input_holding_args(
         return;
     }
 
-    if let Some(vdev) = virtual_device {
-        cmd.arg(format!("--libinput-hold-dev={}", vdev.display()));
+    for path in hold_device_paths(input_devices, &instance.devices, virtual_device) {
+        cmd.arg(format!("--libinput-hold-dev={}", path));
     }
 }
 
@@ -102,3 +149,64 @@ pub fn add_input_holding_args(
 pub fn add_separator(cmd: &mut Command) {
     cmd.arg("--");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolution_unscaled() {
+        assert_eq!(scaled_resolution(1920, 1080, 1.0), ((1920, 1080), None));
+    }
+
+    #[test]
+    fn resolution_fractional_scale() {
+        assert_eq!(
+            scaled_resolution(3840, 2160, 1.5),
+            ((2560, 1440), Some((3840, 2160)))
+        );
+        assert_eq!(
+            scaled_resolution(1920, 1080, 1.5),
+            ((1280, 720), Some((1920, 1080)))
+        );
+    }
+
+    #[test]
+    fn resolution_double_scale() {
+        assert_eq!(
+            scaled_resolution(1920, 2160, 2.0),
+            ((960, 1080), Some((1920, 2160)))
+        );
+    }
+
+    fn device(path: &str, device_type: DeviceType) -> DeviceInfo {
+        DeviceInfo {
+            path: path.to_string(),
+            enabled: true,
+            device_type,
+            uniq: String::new(),
+            siblings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn hold_devices_route_each_keyboard_to_its_instance() {
+        let devices = vec![
+            device("/dev/input/event3", DeviceType::Keyboard),
+            device("/dev/input/event4", DeviceType::Keyboard),
+            device("/dev/input/event5", DeviceType::Mouse),
+            device("/dev/input/event6", DeviceType::Gamepad),
+        ];
+        assert_eq!(hold_device_paths(&devices, &[0, 2], None), vec!["/dev/input/event3", "/dev/input/event5"]);
+        assert_eq!(
+            hold_device_paths(&devices, &[1, 3], Some(Path::new("/dev/input/event9"))),
+            vec!["/dev/input/event4", "/dev/input/event9"]
+        );
+    }
+
+    #[test]
+    fn resolution_ignores_invalid_scale() {
+        assert_eq!(scaled_resolution(1920, 1080, 0.0), ((1920, 1080), None));
+        assert_eq!(scaled_resolution(1920, 1080, f32::NAN), ((1920, 1080), None));
+    }
+}
@@ -0,0 +1,75 @@
+//! Key names accepted by gptokeyb in the [controls] section
+//!
+//! Covers the common part of gptokeyb's key table so the Profile Builder can
+//! flag likely typos; names outside it can still be saved after a warning.
+
+/// Named keys and mouse actions gptokeyb understands
+const NAMED_KEYS: &[&str] = &[
+    // Editing and navigation
+    "esc", "enter", "backspace", "tab", "space", "delete", "del", "insert",
+    "home", "end", "pageup", "pagedown", "up", "down", "left", "right",
+    // Modifiers
+    "shift", "leftshift", "rightshift", "ctrl", "leftctrl", "rightctrl",
+    "alt", "leftalt", "rightalt",
+    // Function keys
+    "f1", "f2", "f3", "f4", "f5", "f6", "f7", "f8", "f9", "f10", "f11", "f12",
+    // Mouse
+    "mouse_left", "mouse_right", "mouse_middle", "mouse_wheel_up", "mouse_wheel_down",
+];
+
+/// Punctuation keys, written as the character itself
+const SYMBOL_KEYS: &[&str] = &[",", ".", "/", ";", "'", "[", "]", "\\", "-", "=", "`"];
+
+/// Whether gptokeyb accepts `value` as a button mapping
+///
+/// Letters and digits are accepted as single lowercase characters.
+pub fn is_supported_key(value: &str) -> bool {
+    let mut chars = value.chars();
+    if let (Some(c), None) = (chars.next(), chars.next())
+        && (c.is_ascii_lowercase() || c.is_ascii_digit())
+    {
+        return true;
+    }
+    NAMED_KEYS.contains(&value) || SYMBOL_KEYS.contains(&value)
+}
+
+/// Short list of common key names for hints in the editor
+pub fn key_hint() -> String {
+    format!(
+        "a-z, 0-9, f1-f12, {}",
+        ["space", "enter", "esc", "tab", "shift", "ctrl", "alt", "mouse_left", "mouse_right", "mouse_wheel_up"]
+            .join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gptokeyb::parser::parse_gptk;
+
+    #[test]
+    fn accepts_letters_digits_and_named_keys() {
+        for key in ["w", "0", "f12", "space", "leftctrl", "mouse_wheel_down", "/"] {
+            assert!(is_supported_key(key), "{key} should be supported");
+        }
+    }
+
+    #[test]
+    fn rejects_typos_and_uppercase() {
+        for key in ["W", "spcae", "mouse_left_click", "f13", "", "ab"] {
+            assert!(!is_supported_key(key), "{key:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn builtin_profiles_only_use_supported_keys() {
+        for (name, content) in [
+            ("fps", include_str!("../../assets/gptokeyb/fps.gptk")),
+            ("mouse_only", include_str!("../../assets/gptokeyb/mouse_only.gptk")),
+            ("racing", include_str!("../../assets/gptokeyb/racing.gptk")),
+        ] {
+            let profile = parse_gptk(content, name).unwrap();
+            assert!(profile.invalid_mappings().is_empty(), "{name} has invalid mappings");
+        }
+    }
+}
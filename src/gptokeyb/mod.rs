@@ -13,10 +13,11 @@
 //! Usage in handler.yaml:
 //! ```yaml
 //! gptokeyb:
-//!   profile: fps         # Built-in: fps, mouse_only, racing
+//!   profile: fps         # Built-in: fps, mouse_only, racing; or a bundled profile
 //!   mouse_scale: 512     # Optional: cursor speed
 //! ```
 
+pub mod keys;
 mod operations;
 pub mod parser;
 pub mod profile;
@@ -26,6 +27,7 @@ mod types;
 pub use operations::{is_available, spawn_all_daemons, terminate_all};
 pub use profile::{AnalogMode, ControllerButton, GptokeybProfile};
 pub use storage::{
-    delete_profile, list_builtin_profiles, list_user_profiles, load_user_profile, save_profile,
+    delete_profile, list_builtin_profiles, list_bundled_profiles, list_user_profiles,
+    load_user_profile, save_profile,
 };
 pub use types::GptokeybSettings;
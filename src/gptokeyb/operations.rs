@@ -4,6 +4,7 @@ use std::path::{Path, PathBuf};
 use std::process::{Child, Command};
 use std::time::Instant;
 
+use super::storage::{bundled_profiles_dir, profiles_dir};
 use super::types::GptokeybSettings;
 use crate::input::DeviceInfo;
 use crate::paths::{BIN_GPTOKEYB, PATH_ASSETS};
@@ -15,31 +16,31 @@ pub fn is_available() -> bool {
 
 /// Get the config file path for a profile
 ///
-/// Returns:
-/// - For built-in profiles: assets/gptokeyb/{profile}.gptk
-/// - For "custom": handler_dir/gptokeyb.gptk
+/// Resolution order:
+/// - "custom": handler_dir/gptokeyb.gptk
+/// - Bundled with the handler: handler_dir/gptokeyb/{profile}.gptk
+/// - Built-in: assets/gptokeyb/{profile}.gptk
+/// - User-created: gptokeyb/profiles/{profile}.gptk
 pub fn get_config_path(settings: &GptokeybSettings, handler_dir: &Path) -> Option<PathBuf> {
     if settings.profile.is_empty() {
         return None;
     }
 
-    if settings.profile == super::types::PROFILE_CUSTOM {
-        // Custom profile in handler directory
-        let custom_path = handler_dir.join("gptokeyb.gptk");
-        if custom_path.exists() {
-            Some(custom_path)
-        } else {
-            None
-        }
+    let file_name = format!("{}.gptk", settings.profile);
+    let candidates = if settings.profile == super::types::PROFILE_CUSTOM {
+        vec![
+            handler_dir.join("gptokeyb.gptk"),
+            bundled_profiles_dir(handler_dir).join(&file_name),
+        ]
     } else {
-        // Built-in profile in res directory
-        let builtin_path = PATH_ASSETS.join("gptokeyb").join(format!("{}.gptk", settings.profile));
-        if builtin_path.exists() {
-            Some(builtin_path)
-        } else {
-            None
-        }
-    }
+        vec![
+            bundled_profiles_dir(handler_dir).join(&file_name),
+            PATH_ASSETS.join("gptokeyb").join(&file_name),
+            profiles_dir().join(&file_name),
+        ]
+    };
+
+    candidates.into_iter().find(|path| path.exists())
 }
 
 /// Wait for gptokeyb virtual device to appear for a specific instance
@@ -119,8 +120,8 @@ pub fn spawn_daemon(
         cmd.env("GPTOKEYB_MOUSE_DELAY", delay.to_string());
     }
 
-    println!(
-        "[splitux] gptokeyb - Instance {}: profile={}, device={}",
+    log_info!(
+        "gptokeyb - Instance {}: profile={}, device={}",
         instance_idx, settings.profile, device.path
     );
 
@@ -129,14 +130,14 @@ pub fn spawn_daemon(
     // Wait for virtual device to appear (2 second timeout)
     let virtual_device = wait_for_virtual_device(instance_idx, 2000);
     if let Some(ref vdev) = virtual_device {
-        println!(
-            "[splitux] gptokeyb - Instance {}: virtual device at {}",
+        log_info!(
+            "gptokeyb - Instance {}: virtual device at {}",
             instance_idx,
             vdev.display()
         );
     } else {
-        println!(
-            "[splitux] gptokeyb - Instance {}: warning: virtual device not detected",
+        log_warn!(
+            "gptokeyb - Instance {}: warning: virtual device not detected",
             instance_idx
         );
     }
@@ -177,16 +178,16 @@ pub fn spawn_all_daemons(
                 Some(device) => match spawn_daemon(settings, handler_dir, device, i) {
                     Ok((child, vdev)) => (Some(child), vdev),
                     Err(e) => {
-                        println!(
-                            "[splitux] gptokeyb - Instance {}: Failed to spawn: {}",
+                        log_error!(
+                            "gptokeyb - Instance {}: Failed to spawn: {}",
                             i, e
                         );
                         (None, None)
                     }
                 },
                 None => {
-                    println!(
-                        "[splitux] gptokeyb - Instance {}: No gamepad assigned, skipping",
+                    log_info!(
+                        "gptokeyb - Instance {}: No gamepad assigned, skipping",
                         i
                     );
                     (None, None)
@@ -208,7 +209,7 @@ pub fn terminate_all(handles: &mut [Option<Child>]) {
             unsafe {
                 libc::kill(pid as i32, libc::SIGTERM);
             }
-            println!("[splitux] gptokeyb - Instance {}: Terminated (pid {})", i, pid);
+            log_info!("gptokeyb - Instance {}: Terminated (pid {})", i, pid);
         }
     }
 }
@@ -5,6 +5,8 @@
 
 use std::collections::HashMap;
 
+use super::keys::is_supported_key;
+
 /// Controller button identifiers matching gptokeyb's expected keys
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ControllerButton {
@@ -43,6 +45,35 @@ pub enum ControllerButton {
 }
 
 impl ControllerButton {
+    /// All buttons in .gptk file order
+    pub const ALL: [ControllerButton; 25] = [
+        Self::Up,
+        Self::Down,
+        Self::Left,
+        Self::Right,
+        Self::A,
+        Self::B,
+        Self::X,
+        Self::Y,
+        Self::L1,
+        Self::R1,
+        Self::L2,
+        Self::R2,
+        Self::L3,
+        Self::R3,
+        Self::Start,
+        Self::Back,
+        Self::Guide,
+        Self::LeftAnalogUp,
+        Self::LeftAnalogDown,
+        Self::LeftAnalogLeft,
+        Self::LeftAnalogRight,
+        Self::RightAnalogUp,
+        Self::RightAnalogDown,
+        Self::RightAnalogLeft,
+        Self::RightAnalogRight,
+    ];
+
     /// Get the gptk file key name for this button
     pub fn gptk_key(&self) -> &'static str {
         match self {
@@ -269,4 +300,15 @@ impl GptokeybProfile {
     pub fn clear_mapping(&mut self, button: ControllerButton) {
         self.button_mappings.remove(&button);
     }
+
+    /// Mappings whose key name isn't in the known gptokeyb key list, in .gptk file order
+    pub fn invalid_mappings(&self) -> Vec<(ControllerButton, &str)> {
+        ControllerButton::ALL
+            .iter()
+            .filter_map(|&button| {
+                let key = self.get_mapping(button)?;
+                (!is_supported_key(key)).then_some((button, key))
+            })
+            .collect()
+    }
 }
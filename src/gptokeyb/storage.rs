@@ -2,13 +2,16 @@
 //!
 //! Handles loading/saving user-created profiles to:
 //! ~/.local/share/splitux/gptokeyb/profiles/
+//!
+//! Also discovers profiles bundled with a handler in its own directory.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::paths::{PATH_PARTY, PATH_ASSETS};
 
 use super::parser::{parse_gptk, serialize_gptk};
 use super::profile::GptokeybProfile;
+use super::types::PROFILE_CUSTOM;
 
 /// Get the user profiles directory path
 pub fn profiles_dir() -> PathBuf {
@@ -20,21 +23,20 @@ pub fn ensure_profiles_dir() -> std::io::Result<()> {
     std::fs::create_dir_all(profiles_dir())
 }
 
-/// List all user-created profile names (without .gptk extension)
-pub fn list_user_profiles() -> Vec<String> {
-    let dir = profiles_dir();
+/// List profile names (file stems) of all .gptk files in a directory
+fn list_gptk_stems(dir: &Path) -> Vec<String> {
     if !dir.exists() {
         return Vec::new();
     }
 
     let mut profiles = Vec::new();
-    if let Ok(entries) = std::fs::read_dir(&dir) {
+    if let Ok(entries) = std::fs::read_dir(dir) {
         for entry in entries.flatten() {
             let path = entry.path();
-            if path.extension().is_some_and(|ext| ext == "gptk") {
-                if let Some(stem) = path.file_stem() {
-                    profiles.push(stem.to_string_lossy().into_owned());
-                }
+            if path.extension().is_some_and(|ext| ext == "gptk")
+                && let Some(stem) = path.file_stem()
+            {
+                profiles.push(stem.to_string_lossy().into_owned());
             }
         }
     }
@@ -42,25 +44,34 @@ pub fn list_user_profiles() -> Vec<String> {
     profiles
 }
 
+/// List all user-created profile names (without .gptk extension)
+pub fn list_user_profiles() -> Vec<String> {
+    list_gptk_stems(&profiles_dir())
+}
+
 /// List built-in profile names from assets/gptokeyb/
 pub fn list_builtin_profiles() -> Vec<String> {
-    let dir = PATH_ASSETS.join("gptokeyb");
-    if !dir.exists() {
+    list_gptk_stems(&PATH_ASSETS.join("gptokeyb"))
+}
+
+/// Get the directory holding profiles bundled with a handler
+pub fn bundled_profiles_dir(handler_dir: &Path) -> PathBuf {
+    handler_dir.join("gptokeyb")
+}
+
+/// List profiles shipped in a handler's directory
+///
+/// Includes every `gptokeyb/*.gptk` file, plus the legacy single
+/// `gptokeyb.gptk` file which is exposed as the "custom" profile.
+pub fn list_bundled_profiles(handler_dir: &Path) -> Vec<String> {
+    if handler_dir.as_os_str().is_empty() {
         return Vec::new();
     }
 
-    let mut profiles = Vec::new();
-    if let Ok(entries) = std::fs::read_dir(&dir) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path.extension().is_some_and(|ext| ext == "gptk") {
-                if let Some(stem) = path.file_stem() {
-                    profiles.push(stem.to_string_lossy().into_owned());
-                }
-            }
-        }
+    let mut profiles = list_gptk_stems(&bundled_profiles_dir(handler_dir));
+    if handler_dir.join("gptokeyb.gptk").exists() && !profiles.iter().any(|p| p == PROFILE_CUSTOM) {
+        profiles.insert(0, PROFILE_CUSTOM.to_string());
     }
-    profiles.sort();
     profiles
 }
 
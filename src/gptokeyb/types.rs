@@ -5,8 +5,9 @@ use serde::{Deserialize, Serialize};
 /// gptokeyb configuration for controller→keyboard/mouse translation
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct GptokeybSettings {
-    /// Profile name - either built-in (fps, mouse_only, etc.) or "custom"
+    /// Profile name - built-in (fps, mouse_only, etc.), bundled, or "custom"
     /// Built-in profiles are loaded from assets/gptokeyb/{profile}.gptk
+    /// Bundled profiles are loaded from handler_dir/gptokeyb/{profile}.gptk
     /// Custom profiles are loaded from handler_dir/gptokeyb.gptk
     #[serde(default, skip_serializing_if = "String::is_empty")]
     pub profile: String,
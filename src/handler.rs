@@ -6,9 +6,11 @@ mod tests;
 mod types;
 
 // Re-export types from submodule
-pub use types::{FacepunchSettings, PhotonSettings, RequiredMod, RuntimePatch, SDL2Override, is_default_sdl2};
+pub use types::{BindSpec, FacepunchSettings, HandlerPackageFormat, LintWarning, PhotonSettings, RequiredMod, RuntimePatch, SDL2Override, StoreKind, is_default_sdl2};
+pub use pure::ordering::game_order;
+pub use pure::templates::HandlerTemplate;
 // Re-export I/O functions from submodule
-pub use io::{import_handler, scan_handlers};
+pub use io::{confirm_hook_scripts, import_handler, scan_handlers};
 
 use crate::backend::{
     EosSettings as BackendEosSettings, FacepunchSettings as BackendFacepunchSettings,
@@ -25,7 +27,8 @@ use std::fs::File;
 use std::io::BufReader;
 use std::path::{Path, PathBuf};
 
-pub const HANDLER_SPEC_CURRENT_VERSION: u16 = 3;
+/// Handler file format version; see pure::spec_migration for the history
+pub const HANDLER_SPEC_CURRENT_VERSION: u16 = 4;
 
 fn is_default_backend(b: &MultiplayerBackend) -> bool {
     *b == MultiplayerBackend::None
@@ -38,6 +41,13 @@ pub struct Handler {
     pub path_handler: PathBuf,
     #[serde(skip)]
     pub img_paths: Vec<PathBuf>,
+    /// Loaded with deprecated backend fields, converted on load; saving
+    /// rewrites the file in the new format
+    #[serde(skip)]
+    pub legacy_backends: bool,
+    /// Spec version the file was upgraded from on load, until it's saved
+    #[serde(skip)]
+    pub spec_migrated_from: Option<u16>,
 
     // Required fields
     pub name: String,
@@ -50,7 +60,12 @@ pub struct Handler {
     pub version: String,
     #[serde(default, skip_serializing_if = "String::is_empty")]
     pub info: String,
-    #[serde(default, skip_serializing_if = "is_default_spec_ver")]
+    /// Shown in a dialog when Play is pressed, for setup steps the game needs
+    /// (e.g. "set windowed mode in-game first"). Can be hidden per game.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub launch_notes: String,
+    /// Always written, so a saved file records the format it was saved in
+    #[serde(default = "current_spec_ver")]
     pub spec_ver: u16,
 
     // Game location (one of these should be set)
@@ -69,6 +84,10 @@ pub struct Handler {
     pub runtime: String,
     #[serde(default, skip_serializing_if = "String::is_empty")]
     pub args: String,
+    /// Directory the game is started in, relative to the game root (e.g. "bin").
+    /// Empty starts the game in the executable's folder.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub working_dir: String,
     #[serde(default, skip_serializing_if = "String::is_empty")]
     pub env: String,
     #[serde(default, skip_serializing_if = "is_default_sdl2")]
@@ -77,6 +96,32 @@ pub struct Handler {
     /// Example: "Proton - Experimental" or full path like "/path/to/proton"
     #[serde(default, skip_serializing_if = "String::is_empty")]
     pub proton_path: String,
+    /// Proton version for this game (e.g. "GE-Proton8-32"), overriding the global setting.
+    /// Falls back to the global version if not installed. Ignored for native games.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub proton_version: String,
+    /// Extra WINEDLLOVERRIDES entries for Proton games (DLL name -> mode, e.g.
+    /// "xaudio2_9" -> "n,b"). Merged with the overrides backends need.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub wine_dll_overrides: HashMap<String, String>,
+    /// Store the Windows game comes from, passed to umu-run as STORE so it
+    /// can apply per-store fixes. Ignored with `proton_path` and for native games.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub store: Option<StoreKind>,
+    /// Script run once before the first instance starts, relative to the
+    /// handler folder (e.g. "hooks/pre_launch.sh"). A nonzero exit cancels
+    /// the launch. Hooks run unsandboxed as the user, so only use handlers
+    /// from sources you trust.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub pre_launch: String,
+    /// Script run once after the last instance exits, relative to the
+    /// handler folder. Runs unsandboxed like `pre_launch`.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub post_exit: String,
+    /// Allow `exec` to point outside the game root (e.g. a shared launcher).
+    /// Without this, saving a handler whose exec escapes the root fails (loading only warns).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub external_exec: bool,
 
     // Multiplayer settings
     /// Multiplayer backend to use (none, goldberg, photon)
@@ -141,15 +186,43 @@ pub struct Handler {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub required_mods: Vec<RequiredMod>,
 
+    /// User-managed folder of mod files overlaid over the game root at launch.
+    /// Applied last, so its files take priority over every other overlay.
+    /// Supports ~ and $HOME.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_mods_dir: Option<String>,
+
     // Advanced
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub game_null_paths: Vec<String>,
+    /// Extra bind mounts inside the bwrap container (shared assets, configs
+    /// outside the game root). A source that doesn't exist is skipped with a
+    /// warning instead of failing the launch. Ignored with disable_bwrap.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_binds: Vec<BindSpec>,
     /// Disable bwrap container (may be needed for games with networking issues)
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub disable_bwrap: bool,
+    /// With disable_bwrap, still point HOME and XDG_* at the profile's home so
+    /// native games keep per-player saves. This only changes the environment:
+    /// there is no mount namespace, so the game can still reach the real home
+    /// through absolute paths, and controllers aren't isolated.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub isolate_home_without_bwrap: bool,
     /// Disable input device isolation (for games where mods handle input internally)
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub disable_input_isolation: bool,
+    /// Launch native games with the Steam overlay and Steam Input disabled
+    /// (strips the overlay from LD_PRELOAD and clears inherited Steam env)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub disable_steam_overlay: bool,
+    /// Run each instance directly under the window manager instead of inside
+    /// its own gamescope, for games that open a regular window and scale to
+    /// any size. The WM still moves each window to its region; gamescope-only
+    /// features (resolution overrides, input holding, scaled output, the
+    /// gamescope-only session) don't apply. Not supported on KWin.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub no_gamescope: bool,
 
     /// gptokeyb settings for controller→keyboard/mouse translation
     /// Enable for games without native controller support
@@ -165,13 +238,24 @@ pub struct Handler {
     pub game_patches: HashMap<String, HashMap<String, String>>,
 
     // Save game integration
-    /// Path to original save data location. Supports:
-    /// - ~ or $HOME for home directory
+    /// Paths to original save data locations (`original_save_path` in the
+    /// file, a single string or a list). Each path supports:
+    /// - ~, $HOME, $XDG_DATA_HOME and $XDG_CONFIG_HOME
     /// - For Windows games: relative paths like "AppData/LocalLow/Company/Game" are relative to windata
+    /// - For native games: relative paths like ".local/share/Game" are relative to HOME
     /// - Absolute paths are used as-is
+    ///
+    /// Windows vs native is decided by the executable, as with `win()`.
     /// When set, original saves are copied to each profile before launch.
-    #[serde(default, skip_serializing_if = "String::is_empty")]
-    pub original_save_path: String,
+    /// Games that split saves across locations (e.g. AppData/Local and
+    /// AppData/LocalLow) list each; every path is copied and synced on its own.
+    #[serde(
+        rename = "original_save_path",
+        default,
+        with = "pure::save_paths",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub original_save_paths: Vec<String>,
     /// Sync saves back to original location after game session ends.
     /// Uses the first named (non-guest) profile's saves.
     /// Original saves are always backed up before overwriting.
@@ -180,16 +264,30 @@ pub struct Handler {
     /// Remap Steam IDs in save file names when copying to/from profiles.
     /// Some games (like Deep Rock Galactic) tie saves to Steam IDs by embedding
     /// the ID in the filename (e.g., "76561198035859048_Player.sav").
-    /// When enabled, save files are renamed to use each profile's Goldberg Steam ID.
+    /// When enabled, save files and directories are renamed to use each profile's Goldberg Steam ID.
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub save_steam_id_remap: bool,
+    /// Bind-mount the save directory straight to the profile's storage.
+    /// For games whose saves break under fuse-overlayfs; only applies when
+    /// a save path is inside the game directory. Requires bwrap.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub save_dir_bind: bool,
+}
+
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata()
+        .map(|m| m.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
 }
 
-fn is_default_spec_ver(v: &u16) -> bool {
-    *v == HANDLER_SPEC_CURRENT_VERSION || *v == 0
+/// A file without spec_ver counts as current (see pure::spec_migration)
+fn current_spec_ver() -> u16 {
+    HANDLER_SPEC_CURRENT_VERSION
 }
 
 // Import YAML parsing functions from pure module
+use pure::spec_migration::{migrate_spec, SpecStatus};
 use pure::yaml_parser::expand_dot_notation;
 
 impl Default for Handler {
@@ -197,20 +295,30 @@ impl Default for Handler {
         Self {
             path_handler: PathBuf::new(),
             img_paths: Vec::new(),
+            legacy_backends: false,
+            spec_migrated_from: None,
             path_gameroot: String::new(),
 
             name: String::new(),
             author: String::new(),
             version: String::new(),
             info: String::new(),
+            launch_notes: String::new(),
             spec_ver: HANDLER_SPEC_CURRENT_VERSION,
 
             runtime: String::new(),
             exec: String::new(),
             args: String::new(),
+            working_dir: String::new(),
             env: String::new(),
             sdl2_override: SDL2Override::No,
             proton_path: String::new(),
+            proton_version: String::new(),
+            wine_dll_overrides: HashMap::new(),
+            store: None,
+            pre_launch: String::new(),
+            post_exit: String::new(),
+            external_exec: false,
 
             backend: MultiplayerBackend::None,
             use_goldberg: false,
@@ -231,16 +339,22 @@ impl Default for Handler {
             standalone: None,
 
             required_mods: Vec::new(),
+            user_mods_dir: None,
 
             game_null_paths: Vec::new(),
+            extra_binds: Vec::new(),
             disable_bwrap: false,
+            isolate_home_without_bwrap: false,
             disable_input_isolation: false,
+            disable_steam_overlay: false,
+            no_gamescope: false,
             gptokeyb: GptokeybSettings::default(),
             game_patches: HashMap::new(),
 
-            original_save_path: String::new(),
+            original_save_paths: Vec::new(),
             save_sync_back: false,
             save_steam_id_remap: false,
+            save_dir_bind: false,
         }
     }
 }
@@ -253,9 +367,12 @@ impl Handler {
         let raw: serde_yaml::Value = serde_yaml::from_reader(BufReader::new(file))?;
 
         // Phase 2: Expand dot notation keys (e.g., "goldberg.disable_networking" -> nested)
-        let expanded = expand_dot_notation(raw);
+        let mut expanded = expand_dot_notation(raw);
+
+        // Phase 3: Upgrade files written for an older spec version
+        let spec_status = migrate_spec(&mut expanded);
 
-        // Phase 3: Deserialize the expanded structure
+        // Phase 4: Deserialize the expanded structure
         let mut handler: Handler = serde_yaml::from_value(expanded)?;
 
         handler.path_handler = yaml_path
@@ -263,6 +380,24 @@ impl Handler {
             .ok_or_else(|| "Invalid path")?
             .to_path_buf();
         handler.img_paths = handler.get_imgs();
+        match spec_status {
+            SpecStatus::Migrated(from) => {
+                handler.spec_migrated_from = Some(from);
+                log_info!(
+                    "Handler {} upgraded from spec version {} to {}; save it to keep the upgrade",
+                    handler.path_handler.display(),
+                    from,
+                    HANDLER_SPEC_CURRENT_VERSION
+                );
+            }
+            SpecStatus::Newer(version) => log_warn!(
+                "Handler {} uses spec version {}, newer than this Splitux supports ({})",
+                handler.path_handler.display(),
+                version,
+                HANDLER_SPEC_CURRENT_VERSION
+            ),
+            SpecStatus::Current => {}
+        }
 
         // Clean up whitespace from all fields
         handler.trim_fields();
@@ -272,18 +407,36 @@ impl Handler {
             *path = path.sanitize_path();
         }
 
-        // Phase 4: Migrate old format to new optional backend fields
-        handler.migrate_legacy_backends();
+        // Phase 5: Migrate old format to new optional backend fields
+        handler.legacy_backends = handler.migrate_legacy_backends();
+        if handler.legacy_backends {
+            log_info!(
+                "Handler {} uses deprecated backend fields; they'll be converted on save",
+                handler.path_handler.display()
+            );
+        }
 
         // Validate required fields
         handler.validate()?;
 
+        // Exec outside the game root is refused when saving, but a file that
+        // already has one still loads. Only an explicit root is checked here,
+        // so loading never looks the game up in a store
+        if !handler.external_exec
+            && (!handler.path_gameroot.is_empty() || Path::new(&handler.exec).is_relative())
+            && let Err(e) =
+                pure::validation::validate_exec_in_root(&handler.exec, Path::new(&handler.path_gameroot))
+        {
+            log_warn!("Handler {}: {}", handler.path_handler.display(), e);
+        }
+
         Ok(handler)
     }
 
     /// Migrate legacy backend fields to new optional backend format
-    fn migrate_legacy_backends(&mut self) {
-        operations::migration::migrate_legacy_backends(self);
+    /// Returns whether any were found
+    fn migrate_legacy_backends(&mut self) -> bool {
+        operations::migration::migrate_legacy_backends(self)
     }
 
     /// Trim whitespace from all string fields
@@ -294,12 +447,19 @@ impl Handler {
         trim_field(&mut self.author);
         trim_field(&mut self.version);
         trim_field(&mut self.info);
+        trim_field(&mut self.launch_notes);
         trim_field(&mut self.path_gameroot);
         trim_field(&mut self.runtime);
         trim_field(&mut self.args);
         trim_field(&mut self.env);
         trim_field(&mut self.proton_path);
-        trim_field(&mut self.original_save_path);
+        trim_field(&mut self.proton_version);
+        trim_field(&mut self.pre_launch);
+        trim_field(&mut self.post_exit);
+        for path in &mut self.original_save_paths {
+            trim_field(path);
+        }
+        self.original_save_paths.retain(|p| !p.is_empty());
 
         // Trim paths in null_paths list
         for path in &mut self.game_null_paths {
@@ -314,6 +474,153 @@ impl Handler {
         pure::validation::validate_handler(&self.name, &self.exec)
     }
 
+    /// Validate that exec stays in the game root when the root is resolvable
+    fn validate_exec_root(&self) -> Result<(), Box<dyn Error>> {
+        if !self.external_exec
+            && let Ok(root) = self.get_game_rootpath()
+            && !root.is_empty()
+        {
+            pure::validation::validate_exec_in_root(&self.exec, Path::new(&root))?;
+        }
+        Ok(())
+    }
+
+    /// Check for common handler mistakes that don't prevent loading
+    ///
+    /// Checks that exec exists under the game root, Steam backends have an
+    /// app ID, Windows save paths live under windata, Goldberg settings have
+    /// known values, and required mod URLs are well-formed.
+    pub fn lint(&self) -> Vec<LintWarning> {
+        use pure::lint::{check_mod_url, check_steam_appid, check_win_save_path, check_working_dir};
+
+        let mut warnings = Vec::new();
+
+        let game_root = match self.get_game_rootpath() {
+            Ok(root) if !root.is_empty() => Some(PathBuf::from(root)),
+            Ok(_) => {
+                warnings.push(LintWarning::new("path_gameroot", "No game root is set"));
+                None
+            }
+            Err(e) => {
+                warnings.push(LintWarning::new(
+                    "path_gameroot",
+                    format!("Could not resolve game root: {}", e),
+                ));
+                None
+            }
+        };
+
+        if let Some(root) = &game_root
+            && !self.external_exec
+            && let Err(e) = pure::validation::validate_exec_in_root(&self.exec, root)
+        {
+            warnings.push(LintWarning::new("exec", e.to_string()));
+        }
+
+        if let Some(root) = &game_root
+            && !self.exec.is_empty()
+            && !root.join(&self.exec).is_file()
+        {
+            warnings.push(LintWarning::new(
+                "exec",
+                format!("'{}' does not exist under {}", self.exec, root.display()),
+            ));
+        }
+
+        if let Some(message) = check_working_dir(&self.working_dir) {
+            warnings.push(LintWarning::new("working_dir", message));
+        } else if let Some(root) = &game_root
+            && !self.working_dir.is_empty()
+            && !root.join(&self.working_dir).is_dir()
+        {
+            warnings.push(LintWarning::new(
+                "working_dir",
+                format!("'{}' is not a folder under {}", self.working_dir, root.display()),
+            ));
+        }
+
+        for (field, script) in [("pre_launch", &self.pre_launch), ("post_exit", &self.post_exit)] {
+            if script.is_empty() || !self.is_saved_handler() {
+                continue;
+            }
+            match crate::launch::resolve_hook_path(&self.path_handler, script) {
+                Err(message) => warnings.push(LintWarning::new(field, message)),
+                Ok(path) if !path.is_file() => {
+                    warnings.push(LintWarning::new(field, format!("'{}' does not exist", script)));
+                }
+                Ok(path) if !is_executable(&path) => {
+                    warnings.push(LintWarning::new(field, format!("'{}' is not executable", script)));
+                }
+                Ok(_) => {}
+            }
+        }
+
+        let steam_backend =
+            self.has_goldberg() || self.has_facepunch() || self.goldberg_networking_sockets;
+        if let Some(message) = check_steam_appid(steam_backend, self.get_steam_appid()) {
+            warnings.push(LintWarning::new("steam_appid", message));
+        }
+
+        if self.win() {
+            if !self.proton_version.is_empty()
+                && crate::util::resolve_proton_path(&self.proton_version).is_none()
+            {
+                warnings.push(LintWarning::new(
+                    "proton_version",
+                    format!("'{}' is not installed; the global Proton version will be used", self.proton_version),
+                ));
+            }
+            for save_path in &self.original_save_paths {
+                if let Some(message) = check_win_save_path(save_path, game_root.as_deref()) {
+                    warnings.push(LintWarning::new("original_save_path", message));
+                }
+            }
+            if let Some(photon) = &self.photon
+                && let Some(message) = check_win_save_path(&photon.config_path, None)
+            {
+                warnings.push(LintWarning::new("photon.config_path", message));
+            }
+            for (dll, mode) in &self.wine_dll_overrides {
+                if let Some(message) = crate::proton::check_dll_override(dll, mode) {
+                    warnings.push(LintWarning::new("wine_dll_overrides", message));
+                }
+            }
+        }
+
+        if let Some(goldberg) = &self.goldberg {
+            for (filename, content) in &goldberg.settings {
+                if let Some(message) = crate::backend::goldberg::validate_setting(filename, content) {
+                    warnings.push(LintWarning::new("goldberg.settings", message));
+                }
+            }
+            if let Some(message) = goldberg.listen_port.and_then(crate::backend::goldberg::check_listen_port) {
+                warnings.push(LintWarning::new("goldberg.listen_port", message));
+            }
+            if let Some(Err(message)) = goldberg.lan_subnet.as_deref().map(crate::backend::goldberg::LanSubnet::parse) {
+                warnings.push(LintWarning::new("goldberg.lan_subnet", message));
+            }
+        }
+
+        if let Some(dir) = &self.user_mods_dir
+            && !crate::save_sync::pure::expand_path(dir).is_dir()
+        {
+            warnings.push(LintWarning::new(
+                "user_mods_dir",
+                format!("'{}' does not exist; no user mods will be overlaid", dir),
+            ));
+        }
+
+        for m in &self.required_mods {
+            for url in std::iter::once(&m.url).chain(&m.mirrors) {
+                if let Some(message) = check_mod_url(url) {
+                    warnings.push(LintWarning::new("required_mods", format!("{}: {}", m.name, message)));
+                }
+            }
+        }
+
+        warnings
+    }
+
     pub fn from_cli(path_exec: &str, args: &str) -> Self {
         let mut handler = Self::default();
 
@@ -345,6 +652,11 @@ impl Handler {
         }
     }
 
+    /// The custom `env` as (name, value) pairs; entries without `=` are skipped
+    pub fn env_vars(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.env.split_whitespace().filter_map(|var| var.split_once('='))
+    }
+
     pub fn win(&self) -> bool {
         self.exec.ends_with(".exe") || self.exec.ends_with(".bat")
     }
@@ -360,6 +672,15 @@ impl Handler {
             .unwrap_or_default()
     }
 
+    /// Key for the handler's state kept outside its folder: the folder
+    /// name, or the handler's name while it isn't saved
+    pub fn storage_key(&self) -> &str {
+        match self.handler_dir_name() {
+            "" => self.name.as_str(),
+            dir_name => dir_name,
+        }
+    }
+
     // Persistence methods moved to operations/persistence.rs
 
     // ============= NEW BACKEND HELPER METHODS (Phase 7) =============
@@ -398,6 +719,13 @@ impl Handler {
         self.standalone.is_some()
     }
 
+    /// Check if a backend stands in for the Steam API (Goldberg or Facepunch)
+    ///
+    /// These can collide with a running Steam client.
+    pub fn emulates_steam(&self) -> bool {
+        self.has_goldberg() || self.has_facepunch()
+    }
+
     /// Get display string for enabled backends (e.g., "Goldberg", "Photon, Facepunch")
     pub fn backend_display(&self) -> String {
         let mut backends = Vec::new();
@@ -448,6 +776,25 @@ impl Handler {
         self.standalone.as_ref()
     }
 
+    /// Replace this handler's settings with a template's
+    ///
+    /// Keeps what the user already entered to identify the game: name,
+    /// author, executable, Steam app and game location.
+    pub fn apply_template(&mut self, template: HandlerTemplate) {
+        let mut h = template.handler();
+        h.path_handler = std::mem::take(&mut self.path_handler);
+        h.img_paths = std::mem::take(&mut self.img_paths);
+        h.name = std::mem::take(&mut self.name);
+        h.author = std::mem::take(&mut self.author);
+        h.steam_appid = self.steam_appid;
+        h.path_gameroot = std::mem::take(&mut self.path_gameroot);
+        h.platform = self.platform.take();
+        if !self.exec.is_empty() {
+            h.exec = std::mem::take(&mut self.exec);
+        }
+        *self = h;
+    }
+
     /// Enable Goldberg backend with default settings
     pub fn enable_goldberg(&mut self) {
         if self.goldberg.is_none() {
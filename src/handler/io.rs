@@ -4,13 +4,16 @@
 //! Handler methods (from_yaml, save, export) remain on Handler struct in handler_legacy.rs
 //! until full migration is complete.
 
-use crate::paths::{PATH_HOME, PATH_PARTY};
-use crate::util::{clear_tmp, copy_dir_recursive};
+use crate::paths::{PATH_HOME, PATH_PARTY, path_tmp};
+use crate::util::{clear_tmp, copy_dir_recursive, msg, yesno};
 
 use rfd::FileDialog;
 use std::error::Error;
-use std::fs::File;
+use std::path::Path;
 
+use super::operations::package::{extract_package, read_package_header};
+use super::pure::package::{detect_package_format, package_stem};
+use super::pure::partydeck::{is_partydeck_handler, migrate_partydeck_handler, PartydeckMigration};
 use super::Handler;
 
 /// Scan the handlers directory and load all valid handlers
@@ -39,46 +42,94 @@ pub fn scan_handlers() -> Vec<Handler> {
     out
 }
 
-/// Import a handler from a .spx package file
+/// Ask before installing a handler whose handler.yaml sets hook scripts
+///
+/// Hooks run outside the sandbox, so this returns false when the user declines.
+/// `action` names what is being done ("Import", "Install") in the prompt.
+pub fn confirm_hook_scripts(handler_yaml: &Path, action: &str) -> bool {
+    let yaml: serde_yaml::Value = std::fs::read_to_string(handler_yaml)
+        .ok()
+        .and_then(|s| serde_yaml::from_str(&s).ok())
+        .unwrap_or_default();
+    let hooks: Vec<&str> = ["pre_launch", "post_exit"]
+        .into_iter()
+        .filter_map(|key| yaml.get(key).and_then(|v| v.as_str()))
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect();
+    hooks.is_empty()
+        || yesno(
+            "Handler runs scripts",
+            &format!(
+                "This handler runs {} around every launch. Hook scripts run outside the sandbox with your permissions.\n\nOnly {} it if you trust where it came from. {} anyway?",
+                hooks.join(" and "),
+                action.to_lowercase(),
+                action
+            ),
+        )
+}
+
+/// Import a handler from a package file (.spx zip, .tar.zst or .7z)
+///
+/// The container is detected from the file's magic bytes, so a renamed
+/// archive still imports correctly.
 pub fn import_handler() -> Result<(), Box<dyn Error>> {
     let Some(file) = FileDialog::new()
         .set_title("Select File")
         .set_directory(&*PATH_HOME)
-        .add_filter("Splitux Handler Package", &["spx"])
+        .add_filter("Splitux Handler Package", &["spx", "zst", "tzst", "7z"])
+        .add_filter("PartyDeck Handler Package", &["pdh"])
         .pick_file()
     else {
         return Ok(());
     };
 
-    if !file.exists() || !file.is_file() || file.extension().unwrap_or_default() != "spx" {
+    if !file.exists() || !file.is_file() {
         return Err("Handler not valid!".into());
     }
 
+    let Some(format) = detect_package_format(&read_package_header(&file)?) else {
+        return Err("Handler not valid: unrecognized package format".into());
+    };
+
     let dir_handlers = PATH_PARTY.join("handlers");
-    let dir_tmp = PATH_PARTY.join("tmp");
+    let dir_tmp = path_tmp();
     if !dir_tmp.exists() {
         std::fs::create_dir_all(&dir_tmp)?;
     }
 
-    let mut archive = zip::ZipArchive::new(File::open(&file)?)?;
-    archive.extract(&dir_tmp)?;
+    if let Err(e) = extract_package(&file, format, &dir_tmp) {
+        clear_tmp()?;
+        return Err(e);
+    }
 
     let handler_path = dir_tmp.join("handler.yaml");
+    let migration = match migrate_partydeck_package(&dir_tmp) {
+        Ok(migration) => migration,
+        Err(e) => {
+            clear_tmp()?;
+            return Err(e);
+        }
+    };
     if !handler_path.exists() {
         clear_tmp()?;
         return Err("handler.yaml not found in archive".into());
     }
 
-    let mut fileclone = file.clone();
-    fileclone.set_extension("");
-    let name = fileclone
+    if !confirm_hook_scripts(&handler_path, "Import") {
+        clear_tmp()?;
+        return Ok(());
+    }
+
+    let file_name = file
         .file_name()
-        .ok_or_else(|| "No filename")?
+        .ok_or("No filename")?
         .to_string_lossy();
+    let name = package_stem(&file_name);
 
     let path = {
-        if !dir_handlers.join(name.as_ref()).exists() {
-            dir_handlers.join(name.as_ref())
+        if !dir_handlers.join(name).exists() {
+            dir_handlers.join(name)
         } else {
             let mut i = 1;
             while PATH_PARTY
@@ -95,5 +146,41 @@ pub fn import_handler() -> Result<(), Box<dyn Error>> {
     copy_dir_recursive(&dir_tmp, &path)?;
     clear_tmp()?;
 
+    if let Some(migration) = migration {
+        msg("PartyDeck handler imported", &migration.summary());
+    }
+
     Ok(())
 }
+
+/// Convert an extracted PartyDeck package to a Splitux one in place
+///
+/// PartyDeck ships handler.json; a handler.yaml using PartyDeck's field
+/// names is converted too. Returns what was migrated, or None for a
+/// Splitux package.
+fn migrate_partydeck_package(dir: &Path) -> Result<Option<PartydeckMigration>, Box<dyn Error>> {
+    let yaml_path = dir.join("handler.yaml");
+    let json_path = dir.join("handler.json");
+    let source = if yaml_path.exists() { &yaml_path } else { &json_path };
+    if !source.exists() {
+        return Ok(None);
+    }
+
+    // JSON is valid YAML, so one parser reads both
+    let value: serde_yaml::Value = serde_yaml::from_str(&std::fs::read_to_string(source)?)?;
+    if source == &yaml_path && !is_partydeck_handler(&value) {
+        return Ok(None);
+    }
+
+    let (migrated, migration) = migrate_partydeck_handler(&value)?;
+    for field in &migration.unknown {
+        log_warn!("PartyDeck import: ignoring unknown field '{}'", field);
+    }
+    std::fs::write(&yaml_path, serde_yaml::to_string(&migrated)?)?;
+    if json_path.exists() {
+        std::fs::remove_file(&json_path)?;
+    }
+    log_info!("Migrated PartyDeck handler from {}", source.display());
+
+    Ok(Some(migration))
+}
@@ -1,7 +1,10 @@
 // Handler operations - file I/O and Steam asset discovery
 
 pub mod migration;
+mod mod_download;
+pub mod package;
 mod persistence;
+mod resolved;
 mod steam_assets;
 
 // Methods are added to Handler via impl blocks in submodules
@@ -9,7 +9,12 @@ use crate::backend::{
 use crate::handler::Handler;
 
 /// Migrate legacy backend fields to new optional backend format
-pub fn migrate_legacy_backends(handler: &mut Handler) {
+///
+/// The legacy fields are cleared afterwards, so saving the handler writes
+/// only the new format. Returns whether any legacy field was set.
+pub fn migrate_legacy_backends(handler: &mut Handler) -> bool {
+    let had_legacy = has_legacy_backend_fields(handler);
+
     // Migrate Goldberg: old enum + flat fields -> new Optional<GoldbergSettings>
     if handler.goldberg.is_none() {
         let should_enable = handler.backend == MultiplayerBackend::Goldberg
@@ -24,6 +29,7 @@ pub fn migrate_legacy_backends(handler: &mut Handler) {
                 networking_sockets: handler.goldberg_networking_sockets,
                 settings: handler.goldberg_settings.clone(),
                 plugin: None,
+                ..Default::default()
             });
         }
     }
@@ -38,6 +44,7 @@ pub fn migrate_legacy_backends(handler: &mut Handler) {
                 config_path: handler.photon_settings.config_path.clone(),
                 shared_files: handler.photon_settings.shared_files.clone(),
                 plugin: None,
+                config_entries: Vec::new(),
             });
         }
     }
@@ -57,7 +64,26 @@ pub fn migrate_legacy_backends(handler: &mut Handler) {
     }
 
     // Clear deprecated fields after migration
+    handler.backend = MultiplayerBackend::None;
     handler.use_goldberg = false;
+    handler.goldberg_settings.clear();
+    handler.goldberg_disable_networking = false;
+    handler.goldberg_networking_sockets = false;
+    handler.photon_settings = Default::default();
+    handler.facepunch_settings = Default::default();
+
+    had_legacy
+}
+
+/// Whether any deprecated backend field is set
+fn has_legacy_backend_fields(handler: &Handler) -> bool {
+    handler.backend != MultiplayerBackend::None
+        || handler.use_goldberg
+        || !handler.goldberg_settings.is_empty()
+        || handler.goldberg_disable_networking
+        || handler.goldberg_networking_sockets
+        || !handler.photon_settings.is_empty()
+        || !handler.facepunch_settings.is_default()
 }
 
 #[cfg(test)]
@@ -126,6 +152,7 @@ mod tests {
             networking_sockets: true,
             settings: HashMap::new(),
             plugin: None,
+            ..Default::default()
         };
         h.goldberg = Some(existing);
         // Set legacy fields that would normally trigger migration
@@ -148,6 +175,25 @@ mod tests {
         assert!(h.photon.is_some());
     }
 
+    #[test]
+    fn legacy_fields_cleared_and_reported() {
+        let mut h = Handler {
+            backend: MultiplayerBackend::Goldberg,
+            goldberg_disable_networking: true,
+            ..Default::default()
+        };
+        h.goldberg_settings.insert("invite_all.txt".into(), String::new());
+        assert!(migrate_legacy_backends(&mut h));
+        assert_eq!(h.backend, MultiplayerBackend::None);
+        assert!(!h.goldberg_disable_networking);
+        assert!(h.goldberg_settings.is_empty());
+        assert!(h.goldberg.as_ref().unwrap().settings.contains_key("invite_all.txt"));
+
+        // Migrating again finds nothing left to convert
+        assert!(!migrate_legacy_backends(&mut h));
+        assert!(h.goldberg.is_some());
+    }
+
     #[test]
     fn use_goldberg_always_cleared() {
         let mut h = Handler::default();
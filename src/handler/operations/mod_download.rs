@@ -0,0 +1,63 @@
+// Required mod downloads, trying the primary URL and then each mirror
+
+use std::path::Path;
+
+use crate::handler::RequiredMod;
+use crate::handler::pure::mod_sources::{file_name_from_url, try_sources};
+use crate::net::fetch_with_retry;
+
+impl RequiredMod {
+    /// Download the mod into its destination folder
+    ///
+    /// Sources are tried in order (see `sources`); a source fails if it's
+    /// unreachable, returns an error status, serves a web page instead of a
+    /// file, or names a file that doesn't match `file_pattern`. Returns the
+    /// URL the file came from. In offline mode `fetch_with_retry` turns every
+    /// source down without touching the network.
+    pub fn download(&self, handler_path: &Path, offline: bool) -> Result<String, String> {
+        let client = reqwest::blocking::Client::new();
+        let dest_dir = self.dest_full_path(handler_path);
+        let ((), source) = try_sources(&self.sources(), |url| {
+            self.download_from(&client, url, &dest_dir, offline)
+        })?;
+        log_info!("Downloaded {} from {}", self.name, source);
+        Ok(source)
+    }
+
+    fn download_from(
+        &self,
+        client: &reqwest::blocking::Client,
+        url: &str,
+        dest_dir: &Path,
+        offline: bool,
+    ) -> Result<(), String> {
+        let file_name = file_name_from_url(url).ok_or("URL doesn't name a file")?;
+        if !self.file_pattern.is_empty() && !self.matches_pattern(&file_name) {
+            return Err(format!("{} doesn't match {}", file_name, self.file_pattern));
+        }
+
+        let response = fetch_with_retry(client, url, &file_name, offline)?;
+        if !response.status().is_success() {
+            return Err(format!("HTTP {}", response.status()));
+        }
+        let is_page = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|t| t.to_str().ok())
+            .is_some_and(|t| t.starts_with("text/html"));
+        if is_page {
+            return Err("got a web page, not a file".to_string());
+        }
+        let bytes = response.bytes().map_err(|e| e.to_string())?;
+
+        std::fs::create_dir_all(dest_dir).map_err(|e| e.to_string())?;
+        let dest = dest_dir.join(&file_name);
+        let part = dest_dir.join(format!("{}.part", file_name));
+        std::fs::write(&part, &bytes)
+            .and_then(|()| std::fs::rename(&part, &dest))
+            .map_err(|e| {
+                let _ = std::fs::remove_file(&part);
+                e.to_string()
+            })
+    }
+}
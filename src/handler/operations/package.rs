@@ -0,0 +1,122 @@
+// Handler package archive operations - pack and unpack in each container format
+
+use crate::handler::HandlerPackageFormat;
+use crate::handler::pure::package::is_contained_entry;
+use crate::util::zip_dir;
+use std::error::Error;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Read enough of a package file to identify its container by magic bytes
+pub fn read_package_header(file: &Path) -> Result<Vec<u8>, Box<dyn Error>> {
+    let mut header = Vec::with_capacity(8);
+    File::open(file)?.take(8).read_to_end(&mut header)?;
+    Ok(header)
+}
+
+/// Extract a handler package into `dest`
+///
+/// All formats produce the same layout: the handler directory's contents,
+/// with handler.yaml at the top level.
+pub fn extract_package(file: &Path, format: HandlerPackageFormat, dest: &Path) -> Result<(), Box<dyn Error>> {
+    match format {
+        HandlerPackageFormat::Spx => {
+            let mut archive = zip::ZipArchive::new(File::open(file)?)?;
+            archive.extract(dest)?;
+        }
+        HandlerPackageFormat::TarZst => {
+            let decoder = zstd::Decoder::new(File::open(file)?)?;
+            tar::Archive::new(decoder).unpack(dest)?;
+        }
+        HandlerPackageFormat::SevenZ => {
+            // Unlike zip and tar, sevenz_rust writes entries wherever their names point
+            sevenz_rust::decompress_with_extract_fn(File::open(file)?, dest, |entry, reader, path| {
+                if !is_contained_entry(entry.name()) {
+                    return Err(sevenz_rust::Error::other(format!(
+                        "Archive entry escapes the package: {}",
+                        entry.name()
+                    )));
+                }
+                sevenz_rust::default_entry_extract_fn(entry, reader, path)
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Pack the contents of `src_dir` into a handler package at `dest`
+pub fn write_package(src_dir: &PathBuf, format: HandlerPackageFormat, dest: &PathBuf) -> Result<(), Box<dyn Error>> {
+    match format {
+        HandlerPackageFormat::Spx => zip_dir(src_dir, dest)?,
+        HandlerPackageFormat::TarZst => {
+            let encoder = zstd::Encoder::new(File::create(dest)?, 0)?;
+            let mut builder = tar::Builder::new(encoder);
+            builder.append_dir_all(".", src_dir)?;
+            builder.into_inner()?.finish()?;
+        }
+        HandlerPackageFormat::SevenZ => {
+            sevenz_rust::compress_to_path(src_dir, dest)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handler::pure::package::detect_package_format;
+    use crate::util::test_dir;
+
+    fn roundtrip(format: HandlerPackageFormat) {
+        let base = test_dir(&format!("pkg-{:?}", format));
+        let src = base.join("src");
+        std::fs::create_dir_all(src.join("overlay/BepInEx")).unwrap();
+        std::fs::write(src.join("handler.yaml"), "name: Test\n").unwrap();
+        std::fs::write(src.join("overlay/BepInEx/mod.dll"), [0u8, 1, 2, 3]).unwrap();
+
+        let archive = base.join(format!("test.{}", format.extension()));
+        write_package(&src, format, &archive).unwrap();
+
+        let header = read_package_header(&archive).unwrap();
+        assert_eq!(detect_package_format(&header), Some(format));
+
+        let out = base.join("out");
+        std::fs::create_dir_all(&out).unwrap();
+        extract_package(&archive, format, &out).unwrap();
+        assert_eq!(std::fs::read_to_string(out.join("handler.yaml")).unwrap(), "name: Test\n");
+        assert_eq!(std::fs::read(out.join("overlay/BepInEx/mod.dll")).unwrap(), vec![0u8, 1, 2, 3]);
+
+        let _ = std::fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn sevenz_rejects_entries_outside_dest() {
+        let base = test_dir("pkg-escape");
+
+        let archive = base.join("evil.7z");
+        let mut writer = sevenz_rust::SevenZWriter::create(&archive).unwrap();
+        let mut entry = sevenz_rust::SevenZArchiveEntry::new();
+        entry.name = "../escaped.txt".to_string();
+        entry.has_stream = true;
+        writer.push_archive_entry(entry, Some(&b"pwned"[..])).unwrap();
+        writer.finish().unwrap();
+
+        let out = base.join("out");
+        std::fs::create_dir_all(&out).unwrap();
+        assert!(extract_package(&archive, HandlerPackageFormat::SevenZ, &out).is_err());
+        assert!(!base.join("escaped.txt").exists());
+
+        let _ = std::fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn tar_zst_roundtrip() {
+        roundtrip(HandlerPackageFormat::TarZst);
+    }
+
+    #[test]
+    fn sevenz_roundtrip() {
+        roundtrip(HandlerPackageFormat::SevenZ);
+    }
+}
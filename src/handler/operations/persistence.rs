@@ -1,11 +1,14 @@
 // Handler persistence operations - save, export, remove
 
-use crate::handler::Handler;
-use crate::paths::{PATH_HOME, PATH_PARTY};
-use crate::util::{clear_tmp, copy_dir_recursive, zip_dir};
+use crate::handler::pure::package::with_package_extension;
+use crate::handler::{Handler, HandlerPackageFormat};
+use crate::paths::{PATH_HOME, PATH_PARTY, path_tmp};
+use crate::util::{clear_tmp, copy_dir_recursive};
 use rfd::FileDialog;
 use std::error::Error;
 
+use super::package::write_package;
+
 impl Handler {
     pub fn remove_handler(&self) -> Result<(), Box<dyn Error>> {
         if !self.is_saved_handler() {
@@ -23,13 +26,14 @@ impl Handler {
     }
 
     pub fn save(&mut self) -> Result<(), Box<dyn Error>> {
+        self.validate_exec_root()?;
+
         // If handler has no path, assume we're saving a newly created handler
         if !self.is_saved_handler() {
             if self.name.is_empty() {
                 // If handler is based on a Steam game try to get the game's install dir name
                 if let Some(appid) = self.steam_appid
-                    && let Ok(dir) = steamlocate::SteamDir::locate()
-                    && let Ok(Some((app, _))) = dir.find_app(appid)
+                    && let Some((app, _)) = crate::platform::find_steam_app(appid)
                 {
                     self.name = app.install_dir;
                 } else {
@@ -59,27 +63,27 @@ impl Handler {
 
         let yaml = serde_yaml::to_string(self)?;
         std::fs::write(self.path_handler.join("handler.yaml"), yaml)?;
+        self.legacy_backends = false;
+        self.spec_migrated_from = None;
 
         Ok(())
     }
 
-    pub fn export(&self) -> Result<(), Box<dyn Error>> {
+    pub fn export(&self, format: HandlerPackageFormat) -> Result<(), Box<dyn Error>> {
         if self.name.is_empty() {
             return Err("Name cannot be empty".into());
         }
 
-        let mut file = FileDialog::new()
+        let file = FileDialog::new()
             .set_title("Save file to:")
             .set_directory(&*PATH_HOME)
-            .add_filter("Splitux Handler Package", &["spx"])
+            .add_filter(format.label(), &[format.extension()])
             .save_file()
-            .ok_or_else(|| "File not specified")?;
+            .ok_or("File not specified")?;
 
-        if file.extension().is_none() || file.extension() != Some("spx".as_ref()) {
-            file.set_extension("spx");
-        }
+        let file = with_package_extension(&file, format);
 
-        let tmpdir = PATH_PARTY.join("tmp");
+        let tmpdir = path_tmp();
         std::fs::create_dir_all(&tmpdir)?;
 
         copy_dir_recursive(&self.path_handler, &tmpdir)?;
@@ -95,7 +99,7 @@ impl Handler {
             std::fs::remove_file(&file)?;
         }
 
-        zip_dir(&tmpdir, &file)?;
+        write_package(&tmpdir, format, &file)?;
         clear_tmp()?;
 
         Ok(())
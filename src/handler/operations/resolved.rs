@@ -0,0 +1,90 @@
+// Resolved handler summary - the game root, executable, runtime, backends
+// and save locations a launch would use
+
+use crate::app::SplituxConfig;
+use crate::backend::goldberg::shared_saves_dir;
+use crate::handler::Handler;
+use crate::paths::PATH_PARTY;
+use crate::save_sync::pure::save_locations;
+use crate::util::resolve_proton_path;
+use std::path::Path;
+
+impl Handler {
+    /// Fully resolved paths and effective launch config, as (label, value)
+    ///
+    /// Read-only diagnostics for the "About this handler" panel and bug
+    /// report bundles. Values that can't be resolved say why instead.
+    pub fn resolved_summary(&self, cfg: &SplituxConfig) -> Vec<(&'static str, String)> {
+        let mut lines = Vec::new();
+
+        lines.push((
+            "Handler folder",
+            match self.is_saved_handler() {
+                true => self.path_handler.display().to_string(),
+                false => "(not saved)".to_string(),
+            },
+        ));
+
+        let root = self.get_game_rootpath();
+        lines.push((
+            "Game root",
+            match &root {
+                Ok(root) => root.clone(),
+                Err(e) => format!("unresolved: {}", e),
+            },
+        ));
+        if let Ok(root) = &root {
+            let exec = Path::new(root).join(&self.exec);
+            let missing = if exec.is_file() { "" } else { " (missing)" };
+            lines.push(("Executable", format!("{}{}", exec.display(), missing)));
+            let working_dir = match self.working_dir.is_empty() {
+                true => exec.parent().unwrap_or(Path::new(root)).to_path_buf(),
+                false => Path::new(root).join(&self.working_dir),
+            };
+            lines.push(("Working directory", working_dir.display().to_string()));
+        } else {
+            lines.push(("Executable", self.exec.clone()));
+        }
+
+        if self.win() {
+            let proton = if !self.proton_path.is_empty() {
+                match resolve_proton_path(&self.proton_path) {
+                    Some(bin) => format!("direct: {}", bin.display()),
+                    None => format!("direct: '{}' not found", self.proton_path),
+                }
+            } else {
+                format!("umu-run, PROTONPATH={}", crate::proton::resolve_proton_version(self, cfg))
+            };
+            lines.push(("Proton", proton));
+        } else {
+            let runtime = match self.runtime.as_str() {
+                "" => "None",
+                "scout" => "Steam Runtime 1.0 (scout)",
+                "soldier" => "Steam Runtime 2.0 (soldier)",
+                other => other,
+            };
+            lines.push(("Runtime", runtime.to_string()));
+        }
+
+        let mut backends = self.backend_display();
+        if self.has_goldberg_plugin() {
+            backends.push_str(" (with Goldberg plugin)");
+        }
+        lines.push(("Backends", backends));
+
+        let save_locations = save_locations("<profile>", self);
+        if save_locations.is_empty() {
+            lines.push(("Original saves", "(not set)".to_string()));
+        }
+        for location in save_locations {
+            lines.push(("Original saves", location.original.display().to_string()));
+            lines.push(("Profile saves", location.profile.display().to_string()));
+        }
+        if self.goldberg_ref().is_some_and(|g| g.shared_saves) {
+            let dir = shared_saves_dir(&PATH_PARTY, self.storage_key());
+            lines.push(("Shared Goldberg saves", dir.display().to_string()));
+        }
+
+        lines
+    }
+}
@@ -1,6 +1,13 @@
 // Pure functions for handler processing
 // No side effects - only computation and data transformation
 
+pub mod lint;
+pub mod mod_sources;
+pub mod ordering;
+pub mod package;
+pub mod partydeck;
+pub mod save_paths;
+pub mod spec_migration;
+pub mod templates;
 pub mod validation;
 pub mod yaml_parser;
-
@@ -0,0 +1,158 @@
+// Pure lint checks for handler fields (no I/O)
+//
+// Each check returns a warning message when the field looks wrong.
+// Filesystem checks (e.g. exec existence) live in Handler::lint.
+
+use std::path::{Component, Path};
+
+/// Check that a Steam backend has an app ID to emulate
+pub fn check_steam_appid(steam_backend: bool, steam_appid: Option<u32>) -> Option<String> {
+    if steam_backend && steam_appid.is_none() {
+        return Some("A Steam backend is enabled but no steam_appid is set".to_string());
+    }
+    None
+}
+
+/// Check that a path does not climb out of its base with `..`
+pub fn escapes_base(path: &str) -> bool {
+    let mut depth: i32 = 0;
+    for component in Path::new(path).components() {
+        match component {
+            Component::ParentDir => depth -= 1,
+            Component::Normal(_) => depth += 1,
+            _ => {}
+        }
+        if depth < 0 {
+            return true;
+        }
+    }
+    false
+}
+
+/// Check that a working directory is a relative path inside the game root
+pub fn check_working_dir(working_dir: &str) -> Option<String> {
+    if working_dir.is_empty() {
+        return None;
+    }
+    if Path::new(working_dir).is_absolute() {
+        return Some(format!("'{}' must be relative to the game root", working_dir));
+    }
+    if escapes_base(working_dir) {
+        return Some(format!("'{}' escapes the game root with '..'", working_dir));
+    }
+    None
+}
+
+/// Check that a Windows game's save path is under the profile's windata
+///
+/// Relative paths are resolved against windata. Absolute or home-relative
+/// paths bypass windata unless they point inside the game directory, where
+/// the gamesaves overlay picks them up instead.
+pub fn check_win_save_path(save_path: &str, game_root: Option<&Path>) -> Option<String> {
+    if save_path.is_empty() {
+        return None;
+    }
+
+    let is_home = save_path.starts_with('~') || save_path.starts_with("$HOME");
+    if is_home || Path::new(save_path).is_absolute() {
+        if let Some(root) = game_root
+            && Path::new(save_path).starts_with(root)
+        {
+            return None;
+        }
+        return Some(format!(
+            "'{}' is outside windata; use a path relative to the Windows user folder (e.g. AppData/LocalLow/...)",
+            save_path
+        ));
+    }
+
+    if escapes_base(save_path) {
+        return Some(format!("'{}' escapes windata with '..'", save_path));
+    }
+    None
+}
+
+/// Check that a required mod URL is a well-formed http(s) URL
+///
+/// An empty URL is allowed (the field is optional).
+pub fn check_mod_url(url: &str) -> Option<String> {
+    if url.is_empty() {
+        return None;
+    }
+
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"));
+    let Some(rest) = rest else {
+        return Some(format!("'{}' must start with http:// or https://", url));
+    };
+
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    if host.is_empty() || (!host.contains('.') && !host.starts_with("localhost")) {
+        return Some(format!("'{}' has no valid host", url));
+    }
+    if url.chars().any(char::is_whitespace) {
+        return Some(format!("'{}' contains whitespace", url));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steam_appid_required_for_steam_backend() {
+        assert!(check_steam_appid(true, None).is_some());
+        assert!(check_steam_appid(true, Some(480)).is_none());
+        assert!(check_steam_appid(false, None).is_none());
+    }
+
+    #[test]
+    fn escapes_base_detects_parent_dirs() {
+        assert!(escapes_base("../outside"));
+        assert!(escapes_base("a/../../outside"));
+        assert!(!escapes_base("a/../b"));
+        assert!(!escapes_base("AppData/Local/Game"));
+    }
+
+    #[test]
+    fn working_dir_must_stay_in_game_root() {
+        assert!(check_working_dir("").is_none());
+        assert!(check_working_dir("bin").is_none());
+        assert!(check_working_dir("bin/x64").is_none());
+        assert!(check_working_dir("/opt/game/bin").is_some());
+        assert!(check_working_dir("../bin").is_some());
+    }
+
+    #[test]
+    fn win_save_path_relative_is_ok() {
+        assert!(check_win_save_path("AppData/LocalLow/Company/Game", None).is_none());
+        assert!(check_win_save_path("", None).is_none());
+    }
+
+    #[test]
+    fn win_save_path_outside_windata() {
+        assert!(check_win_save_path("~/.local/share/Game", None).is_some());
+        assert!(check_win_save_path("/home/user/Game", None).is_some());
+        assert!(check_win_save_path("../../Game", None).is_some());
+    }
+
+    #[test]
+    fn win_save_path_inside_game_root_is_ok() {
+        let root = Path::new("/games/MyGame");
+        assert!(check_win_save_path("/games/MyGame/Saves", Some(root)).is_none());
+        assert!(check_win_save_path("/games/Other/Saves", Some(root)).is_some());
+    }
+
+    #[test]
+    fn mod_url_validation() {
+        assert!(check_mod_url("").is_none());
+        assert!(check_mod_url("https://github.com/user/repo/releases").is_none());
+        assert!(check_mod_url("http://example.com").is_none());
+        assert!(check_mod_url("github.com/user/repo").is_some());
+        assert!(check_mod_url("https://").is_some());
+        assert!(check_mod_url("https://nohost").is_some());
+        assert!(check_mod_url("https://example.com/a b").is_some());
+    }
+}
@@ -0,0 +1,78 @@
+// Required mod download sources (pure, no I/O)
+
+/// Try each source in order until one succeeds
+///
+/// Returns the result and the source it came from. If every source fails,
+/// the error lists each failure so the user can see what was tried.
+pub fn try_sources<T>(
+    sources: &[&str],
+    mut fetch: impl FnMut(&str) -> Result<T, String>,
+) -> Result<(T, String), String> {
+    if sources.is_empty() {
+        return Err("No download URL".to_string());
+    }
+    let mut failures = Vec::new();
+    for source in sources {
+        match fetch(source) {
+            Ok(value) => return Ok((value, source.to_string())),
+            Err(e) => failures.push(format!("{}: {}", source, e)),
+        }
+    }
+    Err(failures.join("\n"))
+}
+
+/// File name at the end of a download URL, ignoring any query or fragment
+pub fn file_name_from_url(url: &str) -> Option<String> {
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    // Skip the scheme and host, so "https://example.com" has no file name
+    let after_host = path.split("://").last()?.split_once('/')?.1;
+    let name = after_host.rsplit('/').next()?;
+    name.contains('.').then(|| name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn falls_back_to_the_next_mirror() {
+        let mut tried = Vec::new();
+        let result = try_sources(&["https://down.example/mod.dll", "https://mirror.example/mod.dll"], |url| {
+            tried.push(url.to_string());
+            if url.contains("down") { Err("HTTP 503".to_string()) } else { Ok(42) }
+        });
+        assert_eq!(result, Ok((42, "https://mirror.example/mod.dll".to_string())));
+        assert_eq!(tried.len(), 2);
+    }
+
+    #[test]
+    fn stops_at_the_first_working_source() {
+        let mut calls = 0;
+        let result = try_sources(&["https://a.example/x.dll", "https://b.example/x.dll"], |_| {
+            calls += 1;
+            Ok(())
+        });
+        assert_eq!(result.map(|(_, source)| source), Ok("https://a.example/x.dll".to_string()));
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn reports_every_failure() {
+        let result: Result<((), String), String> =
+            try_sources(&["https://a.example/x.dll", "https://b.example/x.dll"], |_| Err("offline".to_string()));
+        let err = result.unwrap_err();
+        assert!(err.contains("a.example") && err.contains("b.example"));
+        assert!(try_sources::<()>(&[], |_| Ok(())).is_err());
+    }
+
+    #[test]
+    fn file_names_come_from_the_url_path() {
+        assert_eq!(
+            file_name_from_url("https://github.com/a/b/releases/download/v1/Mod.dll?raw=1").as_deref(),
+            Some("Mod.dll")
+        );
+        assert_eq!(file_name_from_url("https://example.com/mods/"), None);
+        assert_eq!(file_name_from_url("https://example.com"), None);
+        assert_eq!(file_name_from_url("https://example.com/mod-page"), None);
+    }
+}
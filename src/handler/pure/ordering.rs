@@ -0,0 +1,57 @@
+// Games list ordering (favorites / recently played / name)
+// Scanning keeps handlers sorted by name; this only computes a display order
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use crate::config::{GameEntry, GameSort};
+use crate::handler::Handler;
+
+/// Indices into `handlers` in the order the games list shows them
+///
+/// `games` is keyed by handler directory name. Ties keep name order.
+pub fn game_order(handlers: &[Handler], games: &HashMap<String, GameEntry>, sort: GameSort) -> Vec<usize> {
+    let entry = |i: usize| games.get(handlers[i].handler_dir_name());
+    let mut order: Vec<usize> = (0..handlers.len()).collect();
+    order.sort_by_key(|&i| handlers[i].display().to_lowercase());
+    match sort {
+        GameSort::Name => {}
+        GameSort::FavoritesFirst => order.sort_by_key(|&i| !entry(i).is_some_and(|e| e.favorite)),
+        // None sorts before Some, so never-played games land last
+        GameSort::RecentlyPlayed => order.sort_by_key(|&i| Reverse(entry(i).and_then(|e| e.last_played))),
+    }
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use std::time::{Duration, SystemTime};
+
+    fn handler(dir: &str, name: &str) -> Handler {
+        Handler {
+            path_handler: PathBuf::from(format!("/handlers/{}", dir)),
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn orders_by_favorite_and_recency() {
+        let handlers = [handler("a", "Alpha"), handler("b", "bravo"), handler("c", "Charlie")];
+        let mut games = HashMap::new();
+        games.insert(
+            "c".to_string(),
+            GameEntry { favorite: true, last_played: Some(SystemTime::UNIX_EPOCH + Duration::from_secs(10)), ..Default::default() },
+        );
+        games.insert(
+            "b".to_string(),
+            GameEntry { favorite: false, last_played: Some(SystemTime::UNIX_EPOCH + Duration::from_secs(20)), ..Default::default() },
+        );
+
+        assert_eq!(game_order(&handlers, &games, GameSort::Name), vec![0, 1, 2]);
+        assert_eq!(game_order(&handlers, &games, GameSort::FavoritesFirst), vec![2, 0, 1]);
+        assert_eq!(game_order(&handlers, &games, GameSort::RecentlyPlayed), vec![1, 2, 0]);
+    }
+}
@@ -0,0 +1,110 @@
+// Pure helpers for handler package archives (no I/O)
+
+use std::path::{Component, Path, PathBuf};
+
+use super::super::types::HandlerPackageFormat;
+
+const ZIP_MAGIC: &[u8] = b"PK\x03\x04";
+const ZIP_EMPTY_MAGIC: &[u8] = b"PK\x05\x06";
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xB5, 0x2F, 0xFD];
+const SEVENZ_MAGIC: &[u8] = &[0x37, 0x7A, 0xBC, 0xAF, 0x27, 0x1C];
+
+/// Detect the package container from the first bytes of the file
+///
+/// Zstandard streams are assumed to wrap a tarball; extraction fails
+/// later if they don't.
+pub fn detect_package_format(header: &[u8]) -> Option<HandlerPackageFormat> {
+    if header.starts_with(ZIP_MAGIC) || header.starts_with(ZIP_EMPTY_MAGIC) {
+        Some(HandlerPackageFormat::Spx)
+    } else if header.starts_with(ZSTD_MAGIC) {
+        Some(HandlerPackageFormat::TarZst)
+    } else if header.starts_with(SEVENZ_MAGIC) {
+        Some(HandlerPackageFormat::SevenZ)
+    } else {
+        None
+    }
+}
+
+/// Handler directory name for a package file name, without any package extension
+pub fn package_stem(file_name: &str) -> &str {
+    for ext in [".tar.zst", ".tzst", ".spx", ".7z", ".zip"] {
+        if let Some(stem) = file_name.strip_suffix(ext)
+            && !stem.is_empty()
+        {
+            return stem;
+        }
+    }
+    Path::new(file_name)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or(file_name)
+}
+
+/// Ensure an export path ends with the extension for `format`
+///
+/// Unlike `Path::set_extension` this handles the two-part `.tar.zst`.
+pub fn with_package_extension(path: &Path, format: HandlerPackageFormat) -> PathBuf {
+    let ext = format!(".{}", format.extension());
+    let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+    if name.ends_with(&ext) && name.len() > ext.len() {
+        return path.to_path_buf();
+    }
+    path.with_file_name(format!("{}{}", package_stem(&name), ext))
+}
+
+/// Whether an archive entry name stays inside the extraction directory
+///
+/// Rejects absolute paths and any `..` component. An empty name is the
+/// directory itself, which 7z archives list as their root entry.
+pub fn is_contained_entry(name: &str) -> bool {
+    Path::new(name)
+        .components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_formats_by_magic() {
+        assert_eq!(detect_package_format(b"PK\x03\x04rest"), Some(HandlerPackageFormat::Spx));
+        assert_eq!(detect_package_format(&[0x28, 0xB5, 0x2F, 0xFD, 0x04]), Some(HandlerPackageFormat::TarZst));
+        assert_eq!(
+            detect_package_format(&[0x37, 0x7A, 0xBC, 0xAF, 0x27, 0x1C, 0x00, 0x04]),
+            Some(HandlerPackageFormat::SevenZ)
+        );
+        assert_eq!(detect_package_format(b"name: Game"), None);
+        assert_eq!(detect_package_format(&[]), None);
+    }
+
+    #[test]
+    fn stem_strips_package_extensions() {
+        assert_eq!(package_stem("game.spx"), "game");
+        assert_eq!(package_stem("game.tar.zst"), "game");
+        assert_eq!(package_stem("game.v2.7z"), "game.v2");
+        assert_eq!(package_stem("game.bin"), "game");
+    }
+
+    #[test]
+    fn export_path_gets_format_extension() {
+        let tar = HandlerPackageFormat::TarZst;
+        assert_eq!(with_package_extension(Path::new("/h/game"), tar), PathBuf::from("/h/game.tar.zst"));
+        assert_eq!(with_package_extension(Path::new("/h/game.tar.zst"), tar), PathBuf::from("/h/game.tar.zst"));
+        assert_eq!(
+            with_package_extension(Path::new("/h/game.spx"), HandlerPackageFormat::SevenZ),
+            PathBuf::from("/h/game.7z")
+        );
+    }
+
+    #[test]
+    fn entries_outside_the_directory_are_rejected() {
+        assert!(is_contained_entry("handler.yaml"));
+        assert!(is_contained_entry("overlay/BepInEx/mod.dll"));
+        assert!(is_contained_entry("./handler.yaml"));
+        assert!(!is_contained_entry("../handler.yaml"));
+        assert!(!is_contained_entry("overlay/../../escape"));
+        assert!(!is_contained_entry("/etc/passwd"));
+        assert!(is_contained_entry(""));
+    }
+}
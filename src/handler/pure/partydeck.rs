@@ -0,0 +1,249 @@
+// PartyDeck handler compatibility (pure, no I/O)
+//
+// PartyDeck packages (.pdh) carry a handler.json whose schema is close to
+// ours but not the same. Each known PartyDeck field is kept, renamed,
+// converted or dropped per FIELDS; anything else is reported as unknown.
+
+use serde_yaml::{Mapping, Value};
+
+use crate::handler::HANDLER_SPEC_CURRENT_VERSION;
+
+/// What happens to a PartyDeck field on import
+enum Field {
+    /// Same name and meaning in Splitux
+    Keep,
+    /// Same meaning under another name
+    Rename(&'static str),
+    /// List of DLL names, becoming `wine_dll_overrides` entries
+    DllList,
+    /// No Splitux equivalent; the reason is shown in the summary
+    Drop(&'static str),
+}
+
+const FIELDS: &[(&str, Field)] = &[
+    ("name", Field::Keep),
+    ("author", Field::Keep),
+    ("version", Field::Keep),
+    ("info", Field::Keep),
+    ("exec", Field::Keep),
+    ("args", Field::Keep),
+    ("env", Field::Keep),
+    ("runtime", Field::Keep),
+    ("steam_appid", Field::Keep),
+    ("sdl2_override", Field::Keep),
+    ("proton_path", Field::Keep),
+    ("path_gameroot", Field::Keep),
+    ("game_null_paths", Field::Keep),
+    ("game_patches", Field::Keep),
+    ("use_goldberg", Field::Keep),
+    ("remove_paths", Field::Rename("game_null_paths")),
+    ("dll_overrides", Field::DllList),
+    ("spec_ver", Field::Drop("replaced by the current spec version")),
+    ("uid", Field::Drop("handlers are identified by folder name")),
+    ("is32bit", Field::Drop("detected from the executable")),
+    ("win", Field::Drop("detected from the executable")),
+    ("symlink_dir", Field::Drop("game folders are always overlaid")),
+    ("copy_instead_paths", Field::Drop("game folders are always overlaid")),
+    ("coldclient", Field::Drop("Goldberg is configured by the backend")),
+    ("path_goldberg", Field::Drop("Goldberg is bundled")),
+    ("game_unique_paths", Field::Drop("profiles are isolated per player")),
+    ("win_unique_appdata", Field::Drop("profiles are isolated per player")),
+    ("win_unique_documents", Field::Drop("profiles are isolated per player")),
+    ("linux_unique_localshare", Field::Drop("profiles are isolated per player")),
+    ("linux_unique_config", Field::Drop("profiles are isolated per player")),
+    ("pause_between_starts", Field::Drop("instances start when the previous one is ready")),
+];
+
+/// Fields only PartyDeck handlers have; any of them marks a PartyDeck file
+const PARTYDECK_ONLY: &[&str] = &[
+    "uid",
+    "is32bit",
+    "symlink_dir",
+    "copy_instead_paths",
+    "remove_paths",
+    "dll_overrides",
+    "coldclient",
+    "path_goldberg",
+    "game_unique_paths",
+    "win_unique_appdata",
+    "win_unique_documents",
+    "linux_unique_localshare",
+    "linux_unique_config",
+    "pause_between_starts",
+];
+
+/// What an import changed, for the summary dialog
+#[derive(Debug, Default, PartialEq)]
+pub struct PartydeckMigration {
+    /// "old -> new" descriptions of renamed or converted fields
+    pub converted: Vec<String>,
+    /// "field (reason)" descriptions of fields with no equivalent
+    pub dropped: Vec<String>,
+    /// Fields this table doesn't know; ignored
+    pub unknown: Vec<String>,
+}
+
+impl PartydeckMigration {
+    pub fn summary(&self) -> String {
+        let mut out = String::from("Imported a PartyDeck handler.");
+        if !self.converted.is_empty() {
+            out.push_str("\n\nConverted:\n");
+            out.push_str(&self.converted.join("\n"));
+        }
+        if !self.dropped.is_empty() {
+            out.push_str("\n\nNot needed in Splitux:\n");
+            out.push_str(&self.dropped.join("\n"));
+        }
+        if !self.unknown.is_empty() {
+            out.push_str("\n\nUnknown fields (ignored):\n");
+            out.push_str(&self.unknown.join("\n"));
+        }
+        out
+    }
+}
+
+/// Whether a parsed handler file uses PartyDeck's schema
+pub fn is_partydeck_handler(value: &Value) -> bool {
+    value
+        .as_mapping()
+        .is_some_and(|m| PARTYDECK_ONLY.iter().any(|key| m.contains_key(*key)))
+}
+
+/// Map a PartyDeck handler onto Splitux's schema
+///
+/// JSON is valid YAML, so handler.json is parsed as a YAML value. The
+/// result is ready to be written as handler.yaml.
+pub fn migrate_partydeck_handler(value: &Value) -> Result<(Value, PartydeckMigration), String> {
+    let source = value
+        .as_mapping()
+        .ok_or("PartyDeck handler is not an object")?;
+    let mut out = Mapping::new();
+    let mut migration = PartydeckMigration::default();
+
+    for (key, field_value) in source {
+        let Some(key) = key.as_str() else {
+            continue;
+        };
+        let Some((_, field)) = FIELDS.iter().find(|(name, _)| *name == key) else {
+            migration.unknown.push(key.to_string());
+            continue;
+        };
+        convert_field(&mut out, key, field_value.clone(), field, &mut migration);
+    }
+
+    out.insert(Value::from("spec_ver"), Value::from(HANDLER_SPEC_CURRENT_VERSION));
+    Ok((Value::Mapping(out), migration))
+}
+
+/// Convert the PartyDeck field names older Splitux handlers still use, in place
+///
+/// Only renamed and converted fields are touched; everything else in `map`
+/// is already in Splitux's schema.
+pub fn convert_partydeck_fields(map: &mut Mapping) {
+    let mut migration = PartydeckMigration::default();
+    for (key, field) in FIELDS {
+        if matches!(field, Field::Rename(_) | Field::DllList)
+            && let Some(value) = map.remove(*key)
+        {
+            convert_field(map, key, value, field, &mut migration);
+        }
+    }
+}
+
+/// Apply one known PartyDeck field to `out`, noting what happened in `migration`
+fn convert_field(out: &mut Mapping, key: &str, value: Value, field: &Field, migration: &mut PartydeckMigration) {
+    match field {
+        Field::Keep => append(out, key, value),
+        Field::Rename(to) => {
+            append(out, to, value);
+            migration.converted.push(format!("{} -> {}", key, to));
+        }
+        Field::DllList => {
+            let dlls: Vec<String> = value
+                .as_sequence()
+                .into_iter()
+                .flatten()
+                .filter_map(|dll| dll.as_str())
+                .map(|dll| dll.trim().to_lowercase().trim_end_matches(".dll").to_string())
+                .filter(|dll| !dll.is_empty())
+                .collect();
+            if dlls.is_empty() {
+                return;
+            }
+            // Modes already set in wine_dll_overrides win over the implied n,b
+            let overrides = out
+                .entry(Value::from("wine_dll_overrides"))
+                .or_insert_with(|| Value::Mapping(Mapping::new()));
+            if let Some(overrides) = overrides.as_mapping_mut() {
+                for dll in dlls {
+                    overrides.entry(Value::from(dll)).or_insert_with(|| Value::from("n,b"));
+                }
+            }
+            migration.converted.push(format!("{} -> wine_dll_overrides (n,b)", key));
+        }
+        Field::Drop(reason) => {
+            if key != "spec_ver" {
+                migration.dropped.push(format!("{} ({})", key, reason));
+            }
+        }
+    }
+}
+
+/// Insert a field, concatenating lists when two PartyDeck fields map onto one
+fn append(out: &mut Mapping, key: &str, value: Value) {
+    let key = Value::from(key);
+    if let Some(Value::Sequence(existing)) = out.get_mut(&key)
+        && let Value::Sequence(more) = &value
+    {
+        for item in more {
+            if !existing.contains(item) {
+                existing.push(item.clone());
+            }
+        }
+        return;
+    }
+    out.insert(key, value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json: &str) -> Value {
+        serde_yaml::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn detects_partydeck_fields() {
+        assert!(is_partydeck_handler(&parse(r#"{"name": "X", "exec": "x", "is32bit": false}"#)));
+        assert!(!is_partydeck_handler(&parse("name: X\nexec: x\ngame_null_paths: [a]\n")));
+    }
+
+    #[test]
+    fn maps_fields_and_bumps_spec_ver() {
+        let value = parse(
+            r#"{"uid": "x", "name": "X", "exec": "x.exe", "spec_ver": 1,
+                "game_null_paths": ["a"], "remove_paths": ["a", "b"],
+                "dll_overrides": ["winmm.dll", "XInput1_3"], "win_unique_appdata": true,
+                "mystery": 1}"#,
+        );
+        let (out, migration) = migrate_partydeck_handler(&value).unwrap();
+        assert_eq!(out["name"], Value::from("X"));
+        assert_eq!(out["spec_ver"], Value::from(HANDLER_SPEC_CURRENT_VERSION));
+        assert_eq!(out["game_null_paths"], parse(r#"["a", "b"]"#));
+        assert_eq!(out["wine_dll_overrides"]["winmm"], Value::from("n,b"));
+        assert_eq!(out["wine_dll_overrides"]["xinput1_3"], Value::from("n,b"));
+        assert!(out.get("uid").is_none());
+        assert_eq!(migration.unknown, vec!["mystery".to_string()]);
+        assert_eq!(migration.dropped.len(), 2);
+        assert_eq!(migration.converted.len(), 2);
+    }
+
+    #[test]
+    fn migrated_handler_deserializes() {
+        let value = parse(r#"{"name": "X", "exec": "x.exe", "is32bit": true, "sdl2_override": "No"}"#);
+        let (out, _) = migrate_partydeck_handler(&value).unwrap();
+        let handler: crate::handler::Handler = serde_yaml::from_value(out).unwrap();
+        assert_eq!(handler.exec, "x.exe");
+    }
+}
@@ -0,0 +1,71 @@
+// `original_save_path` (de)serialization (pure, no I/O)
+//
+// A handler may list several save paths. One path is written as a plain
+// string, the form handlers used before lists were accepted, so existing
+// files round-trip unchanged; more than one is written as a list.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OneOrMany {
+    One(String),
+    Many(Vec<String>),
+}
+
+/// Accept a single path or a list; empty entries are dropped
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let paths = match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(path) => vec![path],
+        OneOrMany::Many(paths) => paths,
+    };
+    Ok(paths.into_iter().filter(|p| !p.trim().is_empty()).collect())
+}
+
+/// Write a single path as a string and several as a list
+pub fn serialize<S>(paths: &[String], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match paths {
+        [path] => path.serialize(serializer),
+        _ => paths.serialize(serializer),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    struct Saves {
+        #[serde(default, with = "super")]
+        original_save_path: Vec<String>,
+    }
+
+    fn parse(yaml: &str) -> Vec<String> {
+        serde_yaml::from_str::<Saves>(yaml).unwrap().original_save_path
+    }
+
+    #[test]
+    fn reads_a_string_or_a_list() {
+        assert_eq!(parse("original_save_path: AppData/LocalLow/Co/Game"), ["AppData/LocalLow/Co/Game"]);
+        assert_eq!(
+            parse("original_save_path: [AppData/Local/Game, AppData/LocalLow/Co/Game]"),
+            ["AppData/Local/Game", "AppData/LocalLow/Co/Game"]
+        );
+        assert!(parse("original_save_path: \"\"").is_empty());
+        assert!(parse("{}").is_empty());
+    }
+
+    #[test]
+    fn writes_one_path_as_a_string() {
+        let one = Saves { original_save_path: vec!["~/.local/share/Game".to_string()] };
+        assert_eq!(serde_yaml::to_string(&one).unwrap(), "original_save_path: ~/.local/share/Game\n");
+        let two = Saves { original_save_path: vec!["a".to_string(), "b".to_string()] };
+        assert_eq!(serde_yaml::to_string(&two).unwrap(), "original_save_path:\n- a\n- b\n");
+    }
+}
@@ -0,0 +1,114 @@
+// Handler spec version migrations (pure, no I/O)
+//
+// Loading upgrades an older handler.yaml in memory, so the handler is current
+// and saving writes the new format:
+//
+//   < 3     PartyDeck-era field names (`remove_paths`, `dll_overrides`) are
+//           converted with the PartyDeck import table (pure::partydeck)
+//   3 -> 4  backends became optional blocks (`goldberg:`, `photon:`, ...);
+//           the old backend fields still deserialize and are converted by
+//           operations::migration::migrate_legacy_backends
+//
+// A missing spec_ver counts as current. Files from a newer Splitux are left
+// alone.
+
+use serde_yaml::Value;
+
+use crate::handler::HANDLER_SPEC_CURRENT_VERSION;
+
+use super::partydeck::convert_partydeck_fields;
+
+/// First spec version without PartyDeck field names
+const FIRST_SPLITUX_SPEC: u16 = 3;
+
+/// How a loaded file's spec version compares to this build
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpecStatus {
+    /// Already at the current version (or no version given)
+    Current,
+    /// Upgraded in memory from this version; saving writes the new format
+    Migrated(u16),
+    /// Written for a newer Splitux; loaded as-is
+    Newer(u16),
+}
+
+/// Upgrade a parsed handler file written for an older spec version
+///
+/// Sets `spec_ver` to the current version when the file was older.
+pub fn migrate_spec(value: &mut Value) -> SpecStatus {
+    let Some(map) = value.as_mapping_mut() else {
+        return SpecStatus::Current;
+    };
+    let version = map
+        .get("spec_ver")
+        .and_then(Value::as_u64)
+        .map_or(HANDLER_SPEC_CURRENT_VERSION, |v| v.min(u16::MAX as u64) as u16);
+
+    if version == 0 || version == HANDLER_SPEC_CURRENT_VERSION {
+        return SpecStatus::Current;
+    }
+    if version > HANDLER_SPEC_CURRENT_VERSION {
+        return SpecStatus::Newer(version);
+    }
+
+    if version < FIRST_SPLITUX_SPEC {
+        convert_partydeck_fields(map);
+    }
+    map.insert(Value::from("spec_ver"), Value::from(HANDLER_SPEC_CURRENT_VERSION));
+    SpecStatus::Migrated(version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(yaml: &str) -> Value {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn old_files_are_upgraded() {
+        let mut value = parse(
+            "name: Old\nexec: game.exe\nspec_ver: 1\nremove_paths: [a, b]\ngame_null_paths: [a]\n\
+             dll_overrides: [winmm.dll, XInput1_3]\n",
+        );
+        assert_eq!(migrate_spec(&mut value), SpecStatus::Migrated(1));
+        assert_eq!(value["spec_ver"], Value::from(HANDLER_SPEC_CURRENT_VERSION));
+        assert_eq!(value["game_null_paths"], parse("[a, b]"));
+        assert!(value.get("remove_paths").is_none());
+        assert_eq!(value["wine_dll_overrides"]["winmm"], Value::from("n,b"));
+        assert_eq!(value["wine_dll_overrides"]["xinput1_3"], Value::from("n,b"));
+        assert!(value.get("dll_overrides").is_none());
+
+        // Spec 3 files don't use PartyDeck names; only the version moves on
+        let mut value = parse("name: Old\nexec: game.exe\nspec_ver: 3\nuse_goldberg: true\n");
+        assert_eq!(migrate_spec(&mut value), SpecStatus::Migrated(3));
+        assert_eq!(value["spec_ver"], Value::from(HANDLER_SPEC_CURRENT_VERSION));
+        assert_eq!(value["use_goldberg"], Value::from(true));
+    }
+
+    #[test]
+    fn old_dll_list_keeps_explicit_dll_modes() {
+        let mut value = parse(
+            "name: Old\nexec: game.exe\nspec_ver: 2\ndll_overrides: [winmm]\n\
+             wine_dll_overrides:\n  winmm: b\n",
+        );
+        assert_eq!(migrate_spec(&mut value), SpecStatus::Migrated(2));
+        assert_eq!(value["wine_dll_overrides"]["winmm"], Value::from("b"));
+    }
+
+    #[test]
+    fn current_missing_and_newer_versions_are_untouched() {
+        let current = format!("name: X\nexec: x\nspec_ver: {}\n", HANDLER_SPEC_CURRENT_VERSION);
+        let mut value = parse(&current);
+        assert_eq!(migrate_spec(&mut value), SpecStatus::Current);
+        assert_eq!(value, parse(&current));
+
+        assert_eq!(migrate_spec(&mut parse("name: X\nexec: x\n")), SpecStatus::Current);
+
+        let mut newer = parse("name: X\nexec: x\nspec_ver: 99\nremove_paths: [a]\n");
+        assert_eq!(migrate_spec(&mut newer), SpecStatus::Newer(99));
+        assert_eq!(newer["spec_ver"], Value::from(99));
+        assert!(newer.get("remove_paths").is_some());
+    }
+}
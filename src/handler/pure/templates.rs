@@ -0,0 +1,132 @@
+// Starting points for new handlers, one per common engine/setup
+// Only builds Handler values; nothing is written to disk
+
+use crate::backend::{GoldbergSettings, LobbyType, PhotonSettings};
+use crate::handler::{Handler, RequiredMod, SDL2Override};
+
+/// Environment for games run through Proton: esync off, since every instance
+/// holds its own eventfds and several can exhaust the open file limit, and
+/// no DXVK log files, which instances sharing a game folder would fight over
+const PROTON_ENV: &str = "PROTON_NO_ESYNC=1 DXVK_LOG_LEVEL=none";
+
+/// Environment for native SDL games: keep fullscreen windows from minimizing
+/// when another player's instance takes focus
+const NATIVE_ENV: &str = "SDL_VIDEO_MINIMIZE_ON_FOCUS_LOSS=0";
+
+/// Handler templates offered when adding a game
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HandlerTemplate {
+    UnityPhoton,
+    UnityGoldberg,
+    ProtonSteam,
+    NativeLinux,
+}
+
+impl HandlerTemplate {
+    pub const ALL: [HandlerTemplate; 4] = [
+        HandlerTemplate::UnityPhoton,
+        HandlerTemplate::UnityGoldberg,
+        HandlerTemplate::ProtonSteam,
+        HandlerTemplate::NativeLinux,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            HandlerTemplate::UnityPhoton => "Unity + BepInEx (Photon)",
+            HandlerTemplate::UnityGoldberg => "Unity (Goldberg)",
+            HandlerTemplate::ProtonSteam => "Generic Proton (Steam)",
+            HandlerTemplate::NativeLinux => "Native Linux",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            HandlerTemplate::UnityPhoton => {
+                "Unity games using Photon networking. Each instance gets BepInEx and the LocalMultiplayer plugin; fill in the plugin's config path for the game."
+            }
+            HandlerTemplate::UnityGoldberg => {
+                "Unity games using Steam networking, emulated with Goldberg. Lobbies are public and invites auto-accepted so instances find each other."
+            }
+            HandlerTemplate::ProtonSteam => {
+                "Any Windows Steam game run through Proton, with Goldberg standing in for Steam."
+            }
+            HandlerTemplate::NativeLinux => {
+                "Native Linux builds, run in the Steam Runtime (scout) with its SDL2 and without the Steam overlay."
+            }
+        }
+    }
+
+    /// Build a fresh handler from this template
+    ///
+    /// Name, executable and game location are left for the user to fill in.
+    pub fn handler(self) -> Handler {
+        let env = match self {
+            HandlerTemplate::NativeLinux => NATIVE_ENV,
+            _ => PROTON_ENV,
+        };
+        let mut h = Handler { env: env.to_string(), ..Default::default() };
+        match self {
+            HandlerTemplate::UnityPhoton => {
+                h.exec = "Game.exe".to_string();
+                h.photon = Some(PhotonSettings {
+                    config_path: "AppData/LocalLow/Company/Game/LocalMultiplayer/global.cfg".to_string(),
+                    ..Default::default()
+                });
+                h.required_mods = vec![RequiredMod {
+                    name: "LocalMultiplayer".to_string(),
+                    description: "BepInEx plugin that runs Photon lobbies locally".to_string(),
+                    url: String::new(),
+                    mirrors: Vec::new(),
+                    dest_path: "BepInEx/plugins".to_string(),
+                    file_pattern: "*.dll".to_string(),
+                }];
+            }
+            HandlerTemplate::UnityGoldberg => {
+                h.exec = "Game.exe".to_string();
+                let mut goldberg = GoldbergSettings::default();
+                goldberg.set_lobby_type(Some(LobbyType::Public));
+                goldberg.settings.insert("invite_all.txt".to_string(), String::new());
+                h.goldberg = Some(goldberg);
+            }
+            HandlerTemplate::ProtonSteam => {
+                h.exec = "Game.exe".to_string();
+                h.goldberg = Some(GoldbergSettings::default());
+            }
+            HandlerTemplate::NativeLinux => {
+                h.exec = "game.x86_64".to_string();
+                h.runtime = "scout".to_string();
+                h.sdl2_override = SDL2Override::Srt;
+                h.disable_steam_overlay = true;
+            }
+        }
+        h
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn templates_set_their_backends() {
+        let photon = HandlerTemplate::UnityPhoton.handler();
+        assert!(photon.photon.is_some() && photon.goldberg.is_none());
+        assert_eq!(photon.required_mods.len(), 1);
+
+        let goldberg = HandlerTemplate::UnityGoldberg.handler();
+        assert_eq!(goldberg.goldberg.unwrap().lobby_type(), Some(LobbyType::Public));
+
+        let native = HandlerTemplate::NativeLinux.handler();
+        assert!(!native.win());
+        assert_eq!(native.runtime, "scout");
+        assert!(HandlerTemplate::ProtonSteam.handler().win());
+    }
+
+    #[test]
+    fn templates_prefill_env_vars() {
+        let proton = HandlerTemplate::ProtonSteam.handler();
+        assert!(proton.env_vars().any(|(name, value)| name == "PROTON_NO_ESYNC" && value == "1"));
+        let native = HandlerTemplate::NativeLinux.handler();
+        assert_eq!(native.env_vars().collect::<Vec<_>>(), vec![("SDL_VIDEO_MINIMIZE_ON_FOCUS_LOSS", "0")]);
+    }
+}
@@ -1,6 +1,9 @@
 // Pure validation functions for Handler data (no I/O)
 
 use std::error::Error;
+use std::path::Path;
+
+use super::lint::escapes_base;
 
 /// Trim whitespace from all string fields of a handler.
 /// Takes mutable references to each field to trim.
@@ -19,6 +22,29 @@ pub fn validate_handler(name: &str, exec: &str) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// Validate that `exec` stays within the game root
+///
+/// Relative paths must not climb out with `..`; absolute paths must be
+/// under `root`. Existence is left to lint since the game may not be installed.
+pub fn validate_exec_in_root(exec: &str, root: &Path) -> Result<(), Box<dyn Error>> {
+    let exec_path = Path::new(exec);
+    let outside = if exec_path.is_absolute() {
+        !exec_path.starts_with(root) || escapes_base(&exec_path.strip_prefix(root)?.to_string_lossy())
+    } else {
+        escapes_base(exec)
+    };
+
+    if outside {
+        return Err(format!(
+            "Handler 'exec' ({}) is outside the game root ({}); set external_exec: true if this is intended",
+            exec,
+            root.display()
+        )
+        .into());
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,4 +128,29 @@ mod tests {
     fn validate_handler_whitespace_only_exec_passes() {
         assert!(validate_handler("My Game", "  ").is_ok());
     }
+
+    // ── validate_exec_in_root ───────────────────────────────────
+
+    #[test]
+    fn validate_exec_in_root_relative_ok() {
+        let root = Path::new("/games/mygame");
+        assert!(validate_exec_in_root("bin/game.x86_64", root).is_ok());
+        assert!(validate_exec_in_root("bin/../game.exe", root).is_ok());
+    }
+
+    #[test]
+    fn validate_exec_in_root_relative_escape() {
+        let root = Path::new("/games/mygame");
+        let err = validate_exec_in_root("../other/game.exe", root).unwrap_err();
+        assert!(err.to_string().contains("outside the game root"));
+        assert!(err.to_string().contains("external_exec"));
+    }
+
+    #[test]
+    fn validate_exec_in_root_absolute() {
+        let root = Path::new("/games/mygame");
+        assert!(validate_exec_in_root("/games/mygame/game.exe", root).is_ok());
+        assert!(validate_exec_in_root("/games/other/game.exe", root).is_err());
+        assert!(validate_exec_in_root("/games/mygame/../other/game.exe", root).is_err());
+    }
 }
@@ -3,7 +3,8 @@
 #[cfg(test)]
 mod tests {
     use crate::handler::pure::yaml_parser::expand_dot_notation;
-    use crate::handler::{scan_handlers, Handler};
+    use crate::handler::{scan_handlers, Handler, StoreKind};
+    use crate::util::test_dir;
 
     #[test]
     fn test_dot_notation_expansion() {
@@ -87,6 +88,26 @@ photon.shared_files:
         println!("Photon handler loaded: {:?}", handler.name);
     }
 
+    #[test]
+    fn test_photon_config_entries() {
+        let yaml = r#"
+name: TestPhoton
+exec: test.exe
+photon.config_path: "AppData/LocalLow/Test/Game/config.cfg"
+photon.config_entries:
+  - file: BepInEx/config/com.test.cfg
+    section: Network
+    key: Room
+    value: "room-{player}"
+"#;
+        let raw: serde_yaml::Value = serde_yaml::from_str(yaml).unwrap();
+        let handler: Handler = serde_yaml::from_value(expand_dot_notation(raw)).unwrap();
+        let entries = &handler.photon.unwrap().config_entries;
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].key, "Room");
+        assert_eq!(entries[0].value, "room-{player}");
+    }
+
     #[test]
     fn test_facepunch_handler_dot_notation() {
         let yaml = r#"
@@ -208,4 +229,48 @@ goldberg.settings.invite_all.txt: ""
             );
         }
     }
+
+    #[test]
+    fn test_store_hint_round_trips() {
+        let handler: Handler = serde_yaml::from_str("name: X\nexec: x.exe\nstore: egs\n").unwrap();
+        assert_eq!(handler.store, Some(StoreKind::Egs));
+        assert_eq!(handler.store.unwrap().umu_store(), "egs");
+
+        let yaml = serde_yaml::to_string(&Handler::default()).unwrap();
+        assert!(!yaml.contains("store:"));
+    }
+
+    #[test]
+    fn test_legacy_backend_fields_round_trip_to_new_schema() {
+        let dir = test_dir("legacy-handler");
+        let yaml_path = dir.join("handler.yaml");
+        std::fs::write(
+            &yaml_path,
+            "name: Legacy\nexec: game.exe\nspec_ver: 3\nuse_goldberg: true\ngoldberg_disable_networking: true\n\
+             goldberg_settings:\n  force_lobby_type.txt: \"2\"\n",
+        )
+        .unwrap();
+
+        let mut handler = Handler::from_yaml(&yaml_path).unwrap();
+        assert!(handler.legacy_backends);
+        assert_eq!(handler.spec_migrated_from, Some(3));
+        handler.save().unwrap();
+        assert!(!handler.legacy_backends);
+        assert_eq!(handler.spec_migrated_from, None);
+
+        let saved = std::fs::read_to_string(&yaml_path).unwrap();
+        for legacy in ["use_goldberg", "goldberg_disable_networking", "goldberg_settings", "backend:"] {
+            assert!(!saved.contains(legacy), "{} still in saved handler:\n{}", legacy, saved);
+        }
+
+        let reloaded = Handler::from_yaml(&yaml_path).unwrap();
+        assert!(!reloaded.legacy_backends);
+        assert_eq!(reloaded.spec_migrated_from, None);
+        assert_eq!(reloaded.spec_ver, crate::handler::HANDLER_SPEC_CURRENT_VERSION);
+        let goldberg = reloaded.goldberg.expect("goldberg should be enabled");
+        assert!(goldberg.disable_networking);
+        assert_eq!(goldberg.settings.get("force_lobby_type.txt"), Some(&"2".to_string()));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 }
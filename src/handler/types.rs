@@ -5,6 +5,21 @@
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
+/// An extra bind mount inside the bwrap container
+///
+/// `src` and `dest` support ~ and $HOME; an empty `dest` mounts at `src`.
+#[derive(Clone, Serialize, Deserialize, Default, PartialEq, Debug)]
+pub struct BindSpec {
+    /// Host path to mount
+    pub src: String,
+    /// Path inside the container
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub dest: String,
+    /// Mount read-only
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub readonly: bool,
+}
+
 /// A required mod/file that must be installed by the user
 #[derive(Clone, Serialize, Deserialize, Default, PartialEq)]
 pub struct RequiredMod {
@@ -16,6 +31,9 @@ pub struct RequiredMod {
     /// URL where the mod can be downloaded
     #[serde(default, skip_serializing_if = "String::is_empty")]
     pub url: String,
+    /// Fallback download URLs, tried in order when `url` fails
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mirrors: Vec<String>,
     /// Destination path relative to handler directory (e.g., "overlay/BepInEx/plugins")
     pub dest_path: String,
     /// Expected filename or pattern (e.g., "LocalMultiplayer.dll" or "*.dll")
@@ -48,8 +66,20 @@ impl RequiredMod {
         false
     }
 
+    /// Download URLs in the order to try them: `url`, then the mirrors
+    pub fn sources(&self) -> Vec<&str> {
+        let mut sources: Vec<&str> = Vec::new();
+        for source in std::iter::once(&self.url).chain(&self.mirrors) {
+            let source = source.trim();
+            if !source.is_empty() && !sources.contains(&source) {
+                sources.push(source);
+            }
+        }
+        sources
+    }
+
     /// Check if a filename matches the pattern (supports * wildcard)
-    fn matches_pattern(&self, filename: &str) -> bool {
+    pub(crate) fn matches_pattern(&self, filename: &str) -> bool {
         let pattern = &self.file_pattern;
         if pattern.starts_with('*') {
             // *.dll -> check if ends with .dll
@@ -150,3 +180,89 @@ pub enum SDL2Override {
 pub fn is_default_sdl2(v: &SDL2Override) -> bool {
     *v == SDL2Override::No
 }
+
+/// Store a Windows game was bought from, passed to umu-run as STORE
+///
+/// umu applies per-store fixes (protonfixes) when it knows the store.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum StoreKind {
+    Steam,
+    Egs,
+    Gog,
+    Battlenet,
+}
+
+impl StoreKind {
+    pub const ALL: [StoreKind; 4] = [Self::Steam, Self::Egs, Self::Gog, Self::Battlenet];
+
+    /// Value of umu-run's STORE variable
+    pub fn umu_store(&self) -> &'static str {
+        match self {
+            Self::Steam => "steam",
+            Self::Egs => "egs",
+            Self::Gog => "gog",
+            Self::Battlenet => "battlenet",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Steam => "Steam",
+            Self::Egs => "Epic Games Store",
+            Self::Gog => "GOG",
+            Self::Battlenet => "Battle.net",
+        }
+    }
+}
+
+/// A non-fatal problem found by `Handler::lint`
+#[derive(Clone, Debug, PartialEq)]
+pub struct LintWarning {
+    /// Handler field the warning refers to (e.g. "exec", "required_mods")
+    pub field: &'static str,
+    /// Human-readable description of the problem
+    pub message: String,
+}
+
+impl LintWarning {
+    pub fn new(field: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            field,
+            message: message.into(),
+        }
+    }
+}
+
+/// Archive container for exported/imported handler packages
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum HandlerPackageFormat {
+    /// Zip archive with the .spx extension (default)
+    #[default]
+    Spx,
+    /// Zstandard-compressed tarball, smaller for handlers with bundled mods
+    TarZst,
+    /// 7-Zip archive
+    SevenZ,
+}
+
+impl HandlerPackageFormat {
+    pub const ALL: [HandlerPackageFormat; 3] = [Self::Spx, Self::TarZst, Self::SevenZ];
+
+    /// File extension without the leading dot
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Spx => "spx",
+            Self::TarZst => "tar.zst",
+            Self::SevenZ => "7z",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Spx => "Splitux package (.spx)",
+            Self::TarZst => "Zstandard tarball (.tar.zst)",
+            Self::SevenZ => "7-Zip archive (.7z)",
+        }
+    }
+}
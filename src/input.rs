@@ -6,14 +6,16 @@ pub mod pure;
 pub mod types;
 
 // Re-export types
-pub use types::{DeviceInfo, DeviceType, PadButton, PollResult};
+pub use types::{Battery, DeviceInfo, DeviceType, PadButton, PollResult};
 
 // Re-export operations
 pub use operations::{DeviceEvent, DeviceMonitor, InputDevice};
-pub use operations::{open_device, scan_input_devices};
+pub use operations::{export_aliases, open_device, read_alias_file, scan_input_devices};
 
 // Re-export pure functions
-pub use pure::generate_display_names;
+pub use pure::{
+    can_assign_device, claims_slot, device_id_rule, device_listed, generate_display_names, merge_aliases, DeviceRule,
+};
 
 // Re-export pipelines
 pub use pipelines::{check_permissions, install_udev_rules, PermissionStatus};
@@ -1,10 +1,15 @@
 // Input device operations - atomic I/O functions
 
+pub mod aliases;
+pub mod battery;
 pub mod bluetooth;
 pub mod device;
+pub mod leds;
 pub mod monitor;
+pub mod rumble;
 pub mod scan;
 
+pub use aliases::{export_aliases, read_alias_file};
 pub use device::InputDevice;
 pub use monitor::{DeviceEvent, DeviceMonitor};
 pub use scan::{open_device, scan_input_devices};
@@ -0,0 +1,77 @@
+// Device alias file operations (I/O: reads and writes alias JSON files)
+
+use crate::input::types::DeviceAliasFile;
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::Path;
+
+/// Current version of the device alias file format
+const ALIAS_FILE_VERSION: u32 = 1;
+
+/// Write device aliases (keyed by unique ID) to a JSON file
+pub fn export_aliases(path: &Path, aliases: &HashMap<String, String>) -> Result<(), Box<dyn Error>> {
+    let file = DeviceAliasFile {
+        version: ALIAS_FILE_VERSION,
+        aliases: aliases.iter().map(|(k, v)| (k.clone(), v.clone())).collect(),
+    };
+    std::fs::write(path, serde_json::to_string_pretty(&file)?)?;
+    Ok(())
+}
+
+/// Read device aliases from a JSON file written by `export_aliases`
+///
+/// A bare `{ "uniq": "name" }` object is accepted as well, for hand-written files.
+pub fn read_alias_file(path: &Path) -> Result<HashMap<String, String>, Box<dyn Error>> {
+    let contents = std::fs::read_to_string(path)?;
+
+    if let Ok(file) = serde_json::from_str::<DeviceAliasFile>(&contents) {
+        if file.version > ALIAS_FILE_VERSION {
+            log_info!(
+                "Device alias file version {} is newer than supported ({}); importing anyway",
+                file.version, ALIAS_FILE_VERSION
+            );
+        }
+        return Ok(file.aliases.into_iter().collect());
+    }
+
+    serde_json::from_str::<HashMap<String, String>>(&contents)
+        .map_err(|e| format!("Not a device alias file: {}", e).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::test_dir;
+    use std::path::PathBuf;
+
+    fn scratch_file(name: &str) -> PathBuf {
+        test_dir(&format!("aliases-{}", name)).join("aliases.json")
+    }
+
+    #[test]
+    fn export_then_read_roundtrip() {
+        let path = scratch_file("roundtrip");
+        let aliases: HashMap<String, String> =
+            [("aa:bb:cc:dd:ee:ff".to_string(), "Living room pad".to_string())].into();
+
+        export_aliases(&path, &aliases).unwrap();
+        assert_eq!(read_alias_file(&path).unwrap(), aliases);
+    }
+
+    #[test]
+    fn read_accepts_bare_map() {
+        let path = scratch_file("bare");
+        std::fs::write(&path, r#"{"0123ABCD": "Wired pad"}"#).unwrap();
+
+        let aliases = read_alias_file(&path).unwrap();
+        assert_eq!(aliases["0123ABCD"], "Wired pad");
+    }
+
+    #[test]
+    fn read_rejects_other_json() {
+        let path = scratch_file("other");
+        std::fs::write(&path, "[1, 2, 3]").unwrap();
+
+        assert!(read_alias_file(&path).is_err());
+    }
+}
@@ -0,0 +1,28 @@
+// Controller battery operations (I/O: reads sysfs power_supply attributes)
+
+use crate::input::pure::battery::parse_battery;
+use crate::input::types::Battery;
+use std::path::Path;
+
+/// Battery state of the controller behind an evdev node
+///
+/// None for wired controllers and anything else without a power_supply
+/// node, or one that doesn't report a charge.
+pub fn read_battery(event_path: &str) -> Option<Battery> {
+    let event = Path::new(event_path).file_name()?;
+    // eventN -> inputN -> HID device, which owns the power_supply class device
+    let supplies = Path::new("/sys/class/input").join(event).join("device/device/power_supply");
+
+    std::fs::read_dir(supplies).ok()?.flatten().find_map(|entry| {
+        let read = |attr: &str| {
+            std::fs::read_to_string(entry.path().join(attr))
+                .ok()
+                .map(|s| s.trim().to_string())
+        };
+        parse_battery(
+            read("capacity").as_deref(),
+            read("capacity_level").as_deref(),
+            read("status").as_deref(),
+        )
+    })
+}
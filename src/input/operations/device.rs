@@ -1,6 +1,14 @@
 // InputDevice struct and poll implementation (I/O: calls fetch_events)
 
-use crate::input::types::{DeviceInfo, DeviceType, PadButton, PollResult, StickDirection};
+use crate::config::StickNav;
+use crate::input::operations::battery::read_battery;
+use crate::input::operations::leds::set_player_leds;
+use crate::input::operations::rumble::{play_rumble, supports_rumble};
+use crate::input::pure::grouping::physical_device_key;
+use crate::input::pure::live_state::{axis_label, key_label};
+use crate::input::pure::classify::scale_stick_threshold;
+use crate::input::pure::navigation::{should_repeat, stick_direction};
+use crate::input::types::{Battery, DeviceInfo, DeviceType, LiveInputState, PadButton, PollResult, StickDirection};
 use egui_phosphor::regular as icons;
 use evdev::*;
 
@@ -25,6 +33,25 @@ pub struct InputDevice {
     stick_threshold: i32,
     // Unique identifier (Bluetooth MAC or USB serial) for distinguishing identical controllers
     uniq: String,
+    // Physical device key shared by all evdev nodes of one controller
+    group_key: Option<String>,
+    // Extra evdev nodes of the same controller (touchpad, motion sensors)
+    siblings: Vec<String>,
+    // Rumble effect currently playing; dropping it stops the rumble
+    rumble_effect: Option<FFEffect>,
+    // Last battery reading and when it was taken (None until first read)
+    battery: Option<Battery>,
+    battery_read_at: Option<std::time::Instant>,
+}
+
+/// Physical device key for an evdev node (see `physical_device_key`)
+pub(crate) fn device_group_key(dev: &Device) -> Option<String> {
+    physical_device_key(
+        dev.unique_name().unwrap_or(""),
+        dev.physical_path().unwrap_or(""),
+        dev.input_id().vendor(),
+        dev.input_id().product(),
+    )
 }
 
 impl InputDevice {
@@ -37,6 +64,7 @@ impl InputDevice {
         stick_threshold: i32,
         uniq: String,
     ) -> Self {
+        let group_key = device_group_key(&dev);
         Self {
             path,
             dev,
@@ -53,6 +81,11 @@ impl InputDevice {
             stick_center,
             stick_threshold,
             uniq,
+            group_key,
+            siblings: Vec::new(),
+            rumble_effect: None,
+            battery: None,
+            battery_read_at: None,
         }
     }
 
@@ -60,8 +93,7 @@ impl InputDevice {
     fn handle_stick_direction(
         &mut self,
         new_dir: Option<StickDirection>,
-        initial_delay_ms: u128,
-        repeat_rate_ms: u128,
+        nav: &StickNav,
     ) -> Option<PadButton> {
         let now = std::time::Instant::now();
 
@@ -79,7 +111,7 @@ impl InputDevice {
                     let hold_duration = now.duration_since(hold_start).as_millis();
                     let since_last_repeat = now.duration_since(self.stick_last_repeat).as_millis();
 
-                    if hold_duration > initial_delay_ms && since_last_repeat > repeat_rate_ms {
+                    if should_repeat(hold_duration, since_last_repeat, nav.repeat_delay_ms, nav.repeat_rate_ms) {
                         self.stick_last_repeat = now;
                         Some(Self::direction_to_button(dir))
                     } else {
@@ -149,19 +181,96 @@ impl InputDevice {
     pub fn has_button_held(&self) -> bool {
         self.has_button_held
     }
+    /// USB vendor and product ID
+    pub fn usb_id(&self) -> (u16, u16) {
+        let id = self.dev.input_id();
+        (id.vendor(), id.product())
+    }
     #[allow(dead_code)] // API for future device matching
     pub fn uniq(&self) -> &str {
         &self.uniq
     }
+    pub fn group_key(&self) -> Option<&str> {
+        self.group_key.as_deref()
+    }
+    /// Attach another evdev node of the same controller to this device
+    pub fn add_sibling(&mut self, path: String) {
+        if !self.siblings.contains(&path) {
+            self.siblings.push(path);
+        }
+    }
+    /// Detach a sibling node, returning whether it belonged to this device
+    pub fn remove_sibling(&mut self, path: &str) -> bool {
+        let len = self.siblings.len();
+        self.siblings.retain(|p| p != path);
+        self.siblings.len() != len
+    }
+    /// Whether an evdev node is this device or one of its siblings
+    pub fn has_node(&self, path: &str) -> bool {
+        self.path == path || self.siblings.iter().any(|p| p == path)
+    }
+    /// Show a player's color and number on the controller's LEDs, if it has any
+    pub fn set_player_leds(&self, player: usize, rgb: (u8, u8, u8)) -> Result<usize, Box<dyn std::error::Error>> {
+        set_player_leds(&self.path, player, rgb)
+    }
+    pub fn supports_rumble(&self) -> bool {
+        supports_rumble(&self.dev)
+    }
+    /// Play a short rumble so the player can identify this device
+    pub fn rumble(&mut self, duration_ms: u16) -> Result<(), Box<dyn std::error::Error>> {
+        // Drop any previous effect first so it doesn't occupy an effect slot
+        self.rumble_effect = None;
+        self.rumble_effect = Some(play_rumble(&mut self.dev, duration_ms)?);
+        Ok(())
+    }
+    /// Battery state as of the last `refresh_battery`
+    pub fn battery(&self) -> Option<Battery> {
+        self.battery
+    }
+    /// Re-read the battery if the last reading is older than `max_age`
+    ///
+    /// Only gamepads are read; everything else keeps reporting no battery.
+    pub fn refresh_battery(&mut self, max_age: std::time::Duration) {
+        if self.device_type != DeviceType::Gamepad
+            || self.battery_read_at.is_some_and(|at| at.elapsed() < max_age)
+        {
+            return;
+        }
+        self.battery = read_battery(&self.path);
+        self.battery_read_at = Some(std::time::Instant::now());
+    }
     pub fn info(&self) -> DeviceInfo {
         DeviceInfo {
             path: self.path().to_string(),
             enabled: self.enabled(),
             device_type: self.device_type(),
             uniq: self.uniq.clone(),
+            siblings: self.siblings.clone(),
         }
     }
-    pub fn poll(&mut self) -> PollResult {
+    /// Keys held and axis values as of the last `poll`
+    ///
+    /// Read from evdev's cached state, which `poll` keeps current, so this
+    /// shows exactly what the launcher sees. Axes read 0 until they first move.
+    pub fn live_state(&self) -> LiveInputState {
+        let state = self.dev.cached_state();
+        let pressed = state
+            .key_vals()
+            .map(|keys| keys.iter().map(key_label).collect())
+            .unwrap_or_default();
+        let axes = match (self.dev.supported_absolute_axes(), state.abs_vals()) {
+            (Some(supported), Some(values)) => supported
+                .iter()
+                .filter_map(|axis| Some((axis_label(axis), values.get(axis.0 as usize)?.value)))
+                .collect(),
+            _ => Vec::new(),
+        };
+        LiveInputState { pressed, axes }
+    }
+    /// Read pending events and turn them into a launcher navigation result
+    ///
+    /// `nav` only shapes stick navigation here; games read the device directly.
+    pub fn poll(&mut self, nav: &StickNav) -> PollResult {
         // Quick check: if device node is gone, disable immediately
         if !std::path::Path::new(&self.path).exists() {
             self.enabled = false;
@@ -170,10 +279,6 @@ impl InputDevice {
 
         let mut btn: Option<PadButton> = None;
 
-        // Hold-to-repeat timing constants
-        const INITIAL_DELAY_MS: u128 = 300;
-        const REPEAT_RATE_MS: u128 = 80;
-
         const MAX_EVENTS_PER_POLL: usize = 256;
 
         let mut disabled_reason: Option<String> = None;
@@ -267,32 +372,16 @@ impl InputDevice {
         }
 
         // Determine current stick direction from stored position
-        let is_left = self.stick_x < self.stick_center - self.stick_threshold;
-        let is_right = self.stick_x > self.stick_center + self.stick_threshold;
-        let is_up = self.stick_y < self.stick_center - self.stick_threshold;
-        let is_down = self.stick_y > self.stick_center + self.stick_threshold;
+        let threshold = scale_stick_threshold(self.stick_threshold, nav.deadzone);
+        let new_dir = stick_direction(self.stick_x, self.stick_y, self.stick_center, threshold);
 
-        let new_dir = if is_up {
-            Some(StickDirection::Up)
-        } else if is_down {
-            Some(StickDirection::Down)
-        } else if is_left {
-            Some(StickDirection::Left)
-        } else if is_right {
-            Some(StickDirection::Right)
-        } else {
-            None
-        };
-
-        if let Some(result) =
-            self.handle_stick_direction(new_dir, INITIAL_DELAY_MS, REPEAT_RATE_MS)
-        {
+        if let Some(result) = self.handle_stick_direction(new_dir, nav) {
             return PollResult::Button(result);
         }
 
         // Handle right stick Y-axis for scrolling (simple cooldown)
-        let scroll_up = self.scroll_y < self.stick_center - self.stick_threshold;
-        let scroll_down = self.scroll_y > self.stick_center + self.stick_threshold;
+        let scroll_up = self.scroll_y < self.stick_center - threshold;
+        let scroll_down = self.scroll_y > self.stick_center + threshold;
 
         const SCROLL_COOLDOWN_MS: u128 = 100;
         if self.stick_nav_cooldown.elapsed().as_millis() > SCROLL_COOLDOWN_MS {
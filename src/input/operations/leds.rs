@@ -0,0 +1,56 @@
+// Controller LED operations (I/O: writes sysfs LED class attributes)
+
+use crate::input::pure::leds::{classify_led, player_led_on, LedKind};
+use std::error::Error;
+use std::path::Path;
+
+/// Light a controller's lightbar in a player's color and set its player indicator
+///
+/// `event_path` is the controller's evdev node and `player` is 0-based.
+/// Returns how many LEDs were written; controllers without addressable LEDs
+/// (or without write access to them) return an error.
+pub fn set_player_leds(event_path: &str, player: usize, rgb: (u8, u8, u8)) -> Result<usize, Box<dyn Error>> {
+    let event = Path::new(event_path)
+        .file_name()
+        .ok_or("invalid device path")?;
+    // eventN -> inputN -> HID device, which owns the LED class devices
+    let leds_dir = Path::new("/sys/class/input").join(event).join("device/device/leds");
+
+    let leds: Vec<(std::path::PathBuf, LedKind)> = std::fs::read_dir(&leds_dir)?
+        .flatten()
+        .filter_map(|e| classify_led(&e.file_name().to_string_lossy()).map(|kind| (e.path(), kind)))
+        .collect();
+    let player_count = leds.iter().filter(|(_, k)| matches!(k, LedKind::Player(_))).count() as u8;
+
+    let mut written = 0;
+    let mut last_err: Option<Box<dyn Error>> = None;
+    for (path, kind) in &leds {
+        let result = match *kind {
+            LedKind::Rgb => std::fs::write(path.join("multi_intensity"), format!("{} {} {}", rgb.0, rgb.1, rgb.2))
+                .and_then(|_| write_brightness(path, 255)),
+            LedKind::Red => write_brightness(path, rgb.0),
+            LedKind::Green => write_brightness(path, rgb.1),
+            LedKind::Blue => write_brightness(path, rgb.2),
+            LedKind::Player(n) => write_brightness(path, if player_led_on(n, player_count, player) { 255 } else { 0 }),
+        };
+        match result {
+            Ok(()) => written += 1,
+            Err(e) => last_err = Some(format!("{}: {}", path.display(), e).into()),
+        }
+    }
+
+    match (written, last_err) {
+        (0, Some(e)) => Err(e),
+        (0, None) => Err("no addressable LEDs".into()),
+        (n, _) => Ok(n),
+    }
+}
+
+/// Set an LED's brightness, scaling 0-255 to its max_brightness
+fn write_brightness(led: &Path, value: u8) -> std::io::Result<()> {
+    let max: u32 = std::fs::read_to_string(led.join("max_brightness"))
+        .ok()
+        .and_then(|s| s.trim().parse().ok())
+        .unwrap_or(255);
+    std::fs::write(led.join("brightness"), (value as u32 * max / 255).to_string())
+}
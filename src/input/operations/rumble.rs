@@ -0,0 +1,38 @@
+// Force feedback operations (I/O: uploads and plays FF_RUMBLE effects)
+
+use evdev::{
+    Device, FFEffect, FFEffectCode, FFEffectData, FFEffectKind, FFReplay, FFTrigger,
+};
+use std::error::Error;
+
+/// Check whether a device supports FF_RUMBLE effects
+pub fn supports_rumble(dev: &Device) -> bool {
+    dev.supported_ff()
+        .is_some_and(|ff| ff.contains(FFEffectCode::FF_RUMBLE))
+}
+
+/// Upload and play a short rumble on a device
+///
+/// The returned effect must be kept alive until playback finishes;
+/// dropping it removes the effect from the device and stops the rumble.
+pub fn play_rumble(dev: &mut Device, duration_ms: u16) -> Result<FFEffect, Box<dyn Error>> {
+    if !supports_rumble(dev) {
+        return Err("Device does not support force feedback".into());
+    }
+
+    let mut effect = dev.upload_ff_effect(FFEffectData {
+        direction: 0,
+        trigger: FFTrigger::default(),
+        replay: FFReplay {
+            length: duration_ms,
+            delay: 0,
+        },
+        kind: FFEffectKind::Rumble {
+            strong_magnitude: 0xc000,
+            weak_magnitude: 0xc000,
+        },
+    })?;
+    effect.play(1)?;
+
+    Ok(effect)
+}
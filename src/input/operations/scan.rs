@@ -1,63 +1,108 @@
 // Input device scanning operations (I/O: evdev enumeration, device opening)
 
 use crate::app::PadFilterType;
-use crate::input::operations::device::InputDevice;
+use crate::config::DeviceFilterList;
+use crate::input::operations::device::{device_group_key, InputDevice};
 use crate::input::pure::classify::{calculate_stick_calibration, classify_device, is_device_enabled};
+use crate::input::pure::device_filter::device_listed;
+use crate::input::pure::grouping::group_sibling_nodes;
 use crate::input::types::DeviceType;
 use evdev::*;
+use std::collections::HashMap;
 
 /// Scan all input devices and return those matching the filter
-pub fn scan_input_devices(filter: &PadFilterType) -> Vec<InputDevice> {
+///
+/// Extra nodes of a multi-node controller (touchpad, motion sensors) are
+/// folded into their gamepad as siblings instead of being listed separately.
+/// Devices left out by the allow/block lists aren't returned at all.
+pub fn scan_input_devices(filter: &PadFilterType, device_filter: &DeviceFilterList) -> Vec<InputDevice> {
+    let nodes: Vec<(std::path::PathBuf, Device, DeviceType)> = evdev::enumerate()
+        .map(|(path, dev)| {
+            let device_type = classify_device(dev.supported_keys());
+            (path, dev, device_type)
+        })
+        .collect();
+
+    let keys: Vec<(Option<String>, DeviceType)> = nodes
+        .iter()
+        .map(|(_, dev, device_type)| (device_group_key(dev), *device_type))
+        .collect();
+    let parents = group_sibling_nodes(&keys);
+
+    let mut siblings: HashMap<usize, Vec<String>> = HashMap::new();
+    for (i, parent) in parents.iter().enumerate() {
+        if let Some(parent) = parent {
+            log_info!(
+                "evdev: Merging {} into {}",
+                nodes[i].0.display(),
+                nodes[*parent].0.display()
+            );
+            siblings
+                .entry(*parent)
+                .or_default()
+                .push(nodes[i].0.to_string_lossy().to_string());
+        }
+    }
+
     let mut pads: Vec<InputDevice> = Vec::new();
-    for dev in evdev::enumerate() {
+    for (i, dev) in nodes.into_iter().enumerate() {
+        let device_type = dev.2;
+        if device_type == DeviceType::Other || parents[i].is_some() {
+            continue;
+        }
+        if !is_listed(&dev.1, device_type, device_filter) {
+            log_info!("evdev: Skipping {} - excluded by the device filter list", dev.0.display());
+            continue;
+        }
         let enabled = is_device_enabled(filter, dev.1.input_id().vendor());
-        let device_type = classify_device(dev.1.supported_keys());
 
-        if device_type != DeviceType::Other {
-            if dev.1.set_nonblocking(true).is_err() {
-                println!(
-                    "[splitux] evdev: Failed to set non-blocking mode for {}",
-                    dev.0.display()
-                );
-                continue;
-            }
+        if dev.1.set_nonblocking(true).is_err() {
+            log_error!(
+                "evdev: Failed to set non-blocking mode for {}",
+                dev.0.display()
+            );
+            continue;
+        }
 
-            // Detect stick axis range from device info
-            let (stick_center, stick_threshold) = if let Ok(abs_info) = dev.1.get_abs_state() {
-                // Try to get ABS_X info for stick range
-                if let Some(x_info) = abs_info.get(AbsoluteAxisCode::ABS_X.0 as usize) {
-                    let (center, threshold) = calculate_stick_calibration(x_info.minimum, x_info.maximum);
-                    println!(
-                        "[splitux] evdev: {} stick range: {}-{}, center={}, threshold={}",
-                        dev.0.display(),
-                        x_info.minimum,
-                        x_info.maximum,
-                        center,
-                        threshold
-                    );
-                    (center, threshold)
-                } else {
-                    // Default to signed 16-bit range
-                    (0, 8000)
-                }
+        // Detect stick axis range from device info
+        let (stick_center, stick_threshold) = if let Ok(abs_info) = dev.1.get_abs_state() {
+            // Try to get ABS_X info for stick range
+            if let Some(x_info) = abs_info.get(AbsoluteAxisCode::ABS_X.0 as usize) {
+                let (center, threshold) = calculate_stick_calibration(x_info.minimum, x_info.maximum);
+                log_info!(
+                    "evdev: {} stick range: {}-{}, center={}, threshold={}",
+                    dev.0.display(),
+                    x_info.minimum,
+                    x_info.maximum,
+                    center,
+                    threshold
+                );
+                (center, threshold)
             } else {
                 // Default to signed 16-bit range
                 (0, 8000)
-            };
-
-            // Get the unique identifier (Bluetooth MAC or USB serial)
-            let uniq = dev.1.unique_name().unwrap_or("").to_string();
-
-            pads.push(InputDevice::new(
-                dev.0.to_str().unwrap().to_string(),
-                dev.1,
-                enabled,
-                device_type,
-                stick_center,
-                stick_threshold,
-                uniq,
-            ));
+            }
+        } else {
+            // Default to signed 16-bit range
+            (0, 8000)
+        };
+
+        // Get the unique identifier (Bluetooth MAC or USB serial)
+        let uniq = dev.1.unique_name().unwrap_or("").to_string();
+
+        let mut pad = InputDevice::new(
+            dev.0.to_str().unwrap().to_string(),
+            dev.1,
+            enabled,
+            device_type,
+            stick_center,
+            stick_threshold,
+            uniq,
+        );
+        for sibling in siblings.remove(&i).unwrap_or_default() {
+            pad.add_sibling(sibling);
         }
+        pads.push(pad);
     }
     pads.sort_by_key(|pad| pad.path().to_string());
     pads
@@ -65,7 +110,7 @@ pub fn scan_input_devices(filter: &PadFilterType) -> Vec<InputDevice> {
 
 /// Try to open a single device by path and create an InputDevice.
 /// Retries with exponential backoff for udev race conditions.
-pub fn open_device(path: &str, filter: &PadFilterType) -> Option<InputDevice> {
+pub fn open_device(path: &str, filter: &PadFilterType, device_filter: &DeviceFilterList) -> Option<InputDevice> {
     let dev = {
         let mut attempts = 0;
         let max_attempts = 8;
@@ -79,10 +124,10 @@ pub fn open_device(path: &str, filter: &PadFilterType) -> Option<InputDevice> {
 
                     if attempts >= max_attempts {
                         if is_permission_error {
-                            println!("[splitux] evdev: Permission denied for {} - ensure your user is in the 'input' group (run: sudo usermod -aG input $USER)", path);
+                            log_info!("evdev: Permission denied for {} - ensure your user is in the 'input' group (run: sudo usermod -aG input $USER)", path);
                         } else {
-                            println!(
-                                "[splitux] evdev: Failed to open {} after {} attempts: {}",
+                            log_error!(
+                                "evdev: Failed to open {} after {} attempts: {}",
                                 path, attempts, e
                             );
                         }
@@ -106,16 +151,21 @@ pub fn open_device(path: &str, filter: &PadFilterType) -> Option<InputDevice> {
     let device_type = classify_device(dev.supported_keys());
 
     if device_type == DeviceType::Other {
-        println!(
-            "[splitux] evdev: Skipping {} - not a gamepad/keyboard/mouse",
+        log_info!(
+            "evdev: Skipping {} - not a gamepad/keyboard/mouse",
             path
         );
         return None;
     }
 
+    if !is_listed(&dev, device_type, device_filter) {
+        log_info!("evdev: Skipping {} - excluded by the device filter list", path);
+        return None;
+    }
+
     if dev.set_nonblocking(true).is_err() {
-        println!(
-            "[splitux] evdev: Failed to set non-blocking mode for {}",
+        log_error!(
+            "evdev: Failed to set non-blocking mode for {}",
             path
         );
         return None;
@@ -125,8 +175,8 @@ pub fn open_device(path: &str, filter: &PadFilterType) -> Option<InputDevice> {
     let (stick_center, stick_threshold) = if let Ok(abs_info) = dev.get_abs_state() {
         if let Some(x_info) = abs_info.get(AbsoluteAxisCode::ABS_X.0 as usize) {
             let (center, threshold) = calculate_stick_calibration(x_info.minimum, x_info.maximum);
-            println!(
-                "[splitux] evdev: {} stick range: {}-{}, center={}, threshold={}",
+            log_info!(
+                "evdev: {} stick range: {}-{}, center={}, threshold={}",
                 path, x_info.minimum, x_info.maximum, center, threshold
             );
             (center, threshold)
@@ -149,3 +199,9 @@ pub fn open_device(path: &str, filter: &PadFilterType) -> Option<InputDevice> {
         uniq,
     ))
 }
+
+/// Whether the allow/block lists let a device through
+fn is_listed(dev: &Device, device_type: DeviceType, device_filter: &DeviceFilterList) -> bool {
+    let id = dev.input_id();
+    device_listed(device_filter, device_type, id.vendor(), id.product(), dev.name().unwrap_or(""))
+}
@@ -118,6 +118,10 @@ SUBSYSTEM=="input", ATTRS{idVendor}=="20d6", ENV{ID_INPUT_JOYSTICK}=="1", MODE="
 SUBSYSTEM=="input", ATTRS{idVendor}=="0f0d", ENV{ID_INPUT_JOYSTICK}=="1", MODE="0666"
 SUBSYSTEM=="input", ATTRS{idVendor}=="1532", ENV{ID_INPUT_JOYSTICK}=="1", MODE="0666"
 SUBSYSTEM=="input", ATTRS{idVendor}=="342d", MODE="0666"
+
+# Controller lightbars and player LEDs (set to each player's color)
+ACTION=="add", SUBSYSTEM=="leds", SUBSYSTEMS=="hid", KERNEL=="*:rgb:indicator", RUN+="/bin/chmod 0666 /sys%p/brightness /sys%p/multi_intensity"
+ACTION=="add", SUBSYSTEM=="leds", SUBSYSTEMS=="hid", KERNEL=="*:red|*:green|*:blue|*player*", RUN+="/bin/chmod 0666 /sys%p/brightness"
 "#;
 
 /// Install udev rules using pkexec (graphical sudo prompt)
@@ -138,7 +142,7 @@ pub fn install_udev_rules() -> Result<bool, String> {
 
     // Write rules to a temp file
     let temp_path = "/tmp/99-splitux-gamepads.rules";
-    println!("[splitux] Writing udev rules to {}", temp_path);
+    log_info!("Writing udev rules to {}", temp_path);
     let mut file = std::fs::File::create(temp_path)
         .map_err(|e| format!("Failed to create temp file: {}", e))?;
     file.write_all(UDEV_RULES.as_bytes())
@@ -150,23 +154,23 @@ pub fn install_udev_rules() -> Result<bool, String> {
         "cp {} /etc/udev/rules.d/ && udevadm control --reload-rules && udevadm trigger",
         temp_path
     );
-    println!("[splitux] Running: pkexec sh -c '{}'", script);
+    log_info!("Running: pkexec sh -c '{}'", script);
 
     let output = Command::new("pkexec")
         .args(["sh", "-c", &script])
         .output()
         .map_err(|e| format!("Failed to run pkexec: {}", e))?;
 
-    println!("[splitux] pkexec exit code: {:?}", output.status.code());
+    log_info!("pkexec exit code: {:?}", output.status.code());
     if !output.stdout.is_empty() {
-        println!(
-            "[splitux] pkexec stdout: {}",
+        log_info!(
+            "pkexec stdout: {}",
             String::from_utf8_lossy(&output.stdout)
         );
     }
     if !output.stderr.is_empty() {
-        println!(
-            "[splitux] pkexec stderr: {}",
+        log_info!(
+            "pkexec stderr: {}",
             String::from_utf8_lossy(&output.stderr)
         );
     }
@@ -1,6 +1,17 @@
 // Pure functions for input device processing
 
+pub mod aliases;
+pub mod assignment;
+pub mod battery;
 pub mod classify;
+pub mod device_filter;
 pub mod display_names;
+pub mod grouping;
+pub mod leds;
+pub mod live_state;
+pub mod navigation;
 
+pub use aliases::merge_aliases;
+pub use assignment::{can_assign_device, claims_slot};
+pub use device_filter::{device_id_rule, device_listed, DeviceRule};
 pub use display_names::generate_display_names;
@@ -0,0 +1,103 @@
+// Device alias merging (pure functions)
+//
+// Aliases are keyed by the device's unique ID (Bluetooth MAC or USB serial).
+// Imported files may come from another machine or a hand edit, so keys are
+// matched loosely and rewritten to the exact form the kernel reports.
+
+use std::collections::HashMap;
+
+/// Canonical form of a unique ID for comparison ("AA-BB-.." == "aa:bb:..")
+fn canonical_uniq(uniq: &str) -> String {
+    uniq.trim().to_lowercase().replace('-', ":")
+}
+
+/// Merge imported aliases into the current map without dropping existing ones
+///
+/// Keys are reattached to a connected device's uniq, or to an existing alias
+/// key, when they match ignoring case and separator style. Imported names win
+/// over existing names for the same device. Entries with an empty key or
+/// name are skipped. Returns the number of aliases applied.
+pub fn merge_aliases(
+    current: &mut HashMap<String, String>,
+    imported: HashMap<String, String>,
+    connected_uniqs: &[&str],
+) -> usize {
+    let mut applied = 0;
+
+    for (uniq, alias) in imported {
+        let canonical = canonical_uniq(&uniq);
+        let alias = alias.trim();
+        if canonical.is_empty() || alias.is_empty() {
+            continue;
+        }
+
+        let key = connected_uniqs
+            .iter()
+            .find(|c| canonical_uniq(c) == canonical)
+            .map(|c| c.to_string())
+            .or_else(|| current.keys().find(|k| canonical_uniq(k) == canonical).cloned())
+            .unwrap_or_else(|| uniq.trim().to_string());
+
+        current.insert(key, alias.to_string());
+        applied += 1;
+    }
+
+    applied
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn merge_keeps_existing_aliases() {
+        let mut current = map(&[("aa:bb:cc:dd:ee:01", "Red pad")]);
+        let applied = merge_aliases(&mut current, map(&[("aa:bb:cc:dd:ee:02", "Blue pad")]), &[]);
+
+        assert_eq!(applied, 1);
+        assert_eq!(current.len(), 2);
+        assert_eq!(current["aa:bb:cc:dd:ee:01"], "Red pad");
+        assert_eq!(current["aa:bb:cc:dd:ee:02"], "Blue pad");
+    }
+
+    #[test]
+    fn merge_overwrites_same_device() {
+        let mut current = map(&[("aa:bb:cc:dd:ee:01", "Old")]);
+        merge_aliases(&mut current, map(&[("AA:BB:CC:DD:EE:01", "New")]), &[]);
+
+        assert_eq!(current, map(&[("aa:bb:cc:dd:ee:01", "New")]));
+    }
+
+    #[test]
+    fn merge_reattaches_to_connected_uniq() {
+        let mut current = HashMap::new();
+        merge_aliases(
+            &mut current,
+            map(&[("aa-bb-cc-dd-ee-01", "Player 1")]),
+            &["AA:BB:CC:DD:EE:01", "ff:ff:ff:ff:ff:ff"],
+        );
+
+        assert_eq!(current, map(&[("AA:BB:CC:DD:EE:01", "Player 1")]));
+    }
+
+    #[test]
+    fn merge_keeps_serials_verbatim() {
+        let mut current = HashMap::new();
+        merge_aliases(&mut current, map(&[(" 0123ABCD ", "Wired pad")]), &[]);
+
+        assert_eq!(current, map(&[("0123ABCD", "Wired pad")]));
+    }
+
+    #[test]
+    fn merge_skips_empty_entries() {
+        let mut current = HashMap::new();
+        let applied = merge_aliases(&mut current, map(&[("", "No key"), ("aa:bb", "  ")]), &[]);
+
+        assert_eq!(applied, 0);
+        assert!(current.is_empty());
+    }
+}
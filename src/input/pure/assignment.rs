@@ -0,0 +1,100 @@
+// Device-to-instance assignment rules (pure functions)
+
+use crate::input::types::DeviceType;
+use crate::instance::Instance;
+
+/// Whether a device may be added to an instance
+///
+/// Keyboards and mice need input holding, since plain gamescope can't keep
+/// them apart. gamescope-splitux holds each one exclusively, so a single
+/// keyboard or mouse can only drive one instance, but different keyboards
+/// and mice can each go to a different instance. Gamepads can only be
+/// shared between instances with `allow_shared_gamepads`.
+pub fn can_assign_device(
+    device_idx: usize,
+    device_type: DeviceType,
+    instances: &[Instance],
+    input_holding: bool,
+    allow_shared_gamepads: bool,
+) -> bool {
+    let assigned = instances.iter().any(|inst| inst.devices.contains(&device_idx));
+    match device_type {
+        DeviceType::Gamepad => !assigned || allow_shared_gamepads,
+        _ => input_holding && !assigned,
+    }
+}
+
+/// Whether a join press in claim mode creates a new instance for the device
+///
+/// Only devices no instance uses can claim a slot, so players already in
+/// keep navigating the menu with their join button. Keyboards and mice
+/// still need input holding.
+pub fn claims_slot(
+    device_idx: usize,
+    device_type: DeviceType,
+    instances: &[Instance],
+    input_holding: bool,
+) -> bool {
+    let assigned = instances.iter().any(|inst| inst.devices.contains(&device_idx));
+    !assigned && can_assign_device(device_idx, device_type, instances, input_holding, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instance(devices: Vec<usize>) -> Instance {
+        Instance {
+            devices,
+            profname: String::new(),
+            profselection: 0,
+            monitor: 0,
+            width: 0,
+            height: 0,
+            grab_cursor: None,
+            mangohud: false,
+            resolution_override: (0, 0),
+            args_override: String::new(),
+        }
+    }
+
+    #[test]
+    fn two_keyboards_go_to_separate_instances() {
+        // Keyboard 2 drives player 1; keyboard 3 can still start player 2
+        let instances = vec![instance(vec![2])];
+        assert!(can_assign_device(3, DeviceType::Keyboard, &instances, true, false));
+
+        let instances = vec![instance(vec![2]), instance(vec![3])];
+        assert!(!can_assign_device(2, DeviceType::Keyboard, &instances, true, false));
+        assert!(!can_assign_device(3, DeviceType::Keyboard, &instances, true, false));
+    }
+
+    #[test]
+    fn keyboards_and_mice_need_input_holding() {
+        assert!(!can_assign_device(0, DeviceType::Keyboard, &[], false, false));
+        assert!(!can_assign_device(1, DeviceType::Mouse, &[], false, false));
+        assert!(can_assign_device(1, DeviceType::Mouse, &[], true, false));
+    }
+
+    #[test]
+    fn same_keyboard_never_shared() {
+        let instances = vec![instance(vec![2])];
+        assert!(!can_assign_device(2, DeviceType::Keyboard, &instances, true, true));
+    }
+
+    #[test]
+    fn gamepads_shared_only_when_allowed() {
+        let instances = vec![instance(vec![0])];
+        assert!(!can_assign_device(0, DeviceType::Gamepad, &instances, true, false));
+        assert!(can_assign_device(0, DeviceType::Gamepad, &instances, false, true));
+    }
+
+    #[test]
+    fn only_unassigned_devices_claim_slots() {
+        let instances = vec![instance(vec![0])];
+        assert!(claims_slot(1, DeviceType::Gamepad, &instances, false));
+        assert!(!claims_slot(0, DeviceType::Gamepad, &instances, false));
+        assert!(!claims_slot(2, DeviceType::Keyboard, &instances, false));
+        assert!(claims_slot(2, DeviceType::Keyboard, &instances, true));
+    }
+}
@@ -0,0 +1,53 @@
+// Controller battery parsing (pure functions)
+
+use crate::input::types::Battery;
+
+/// Battery state from a power_supply node's `capacity`, `capacity_level` and
+/// `status` attributes (trimmed file contents, None if absent)
+///
+/// Drivers such as hid-playstation report a percentage; others (hid-nintendo)
+/// only a level, which is mapped to a rough percentage. None when neither
+/// says anything useful.
+pub fn parse_battery(capacity: Option<&str>, level: Option<&str>, status: Option<&str>) -> Option<Battery> {
+    let percent = capacity
+        .and_then(|c| c.parse::<u8>().ok())
+        .map(|c| c.min(100))
+        .or_else(|| match level? {
+            "Full" => Some(100),
+            "High" => Some(75),
+            "Normal" => Some(50),
+            "Low" => Some(20),
+            "Critical" => Some(5),
+            _ => None,
+        })?;
+    Some(Battery {
+        percent,
+        charging: status == Some("Charging"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_capacity_or_level() {
+        assert_eq!(
+            parse_battery(Some("64"), None, Some("Discharging")),
+            Some(Battery { percent: 64, charging: false })
+        );
+        assert_eq!(
+            parse_battery(None, Some("Low"), Some("Charging")),
+            Some(Battery { percent: 20, charging: true })
+        );
+        // A capacity wins over the level
+        assert_eq!(parse_battery(Some("90"), Some("Low"), None).map(|b| b.percent), Some(90));
+    }
+
+    #[test]
+    fn unknown_batteries_are_hidden() {
+        assert_eq!(parse_battery(None, Some("Unknown"), Some("Unknown")), None);
+        assert_eq!(parse_battery(Some("n/a"), None, None), None);
+        assert_eq!(parse_battery(None, None, Some("Discharging")), None);
+    }
+}
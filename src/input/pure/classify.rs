@@ -36,6 +36,18 @@ pub fn calculate_stick_calibration(min: i32, max: i32) -> (i32, i32) {
     (center, threshold)
 }
 
+/// Deadzone, in percent of the distance from center to either end, that the
+/// threshold from `calculate_stick_calibration` stands for
+pub const CALIBRATED_DEADZONE: u8 = 50;
+
+/// Rescale a threshold from `calculate_stick_calibration` to another deadzone
+///
+/// `deadzone` is in percent of the distance from center to either end of the
+/// axis, so the calibrated threshold is kept as-is at `CALIBRATED_DEADZONE`.
+pub fn scale_stick_threshold(threshold: i32, deadzone: u8) -> i32 {
+    (threshold as i64 * deadzone.min(100) as i64 / CALIBRATED_DEADZONE as i64) as i32
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -141,4 +153,17 @@ mod tests {
         assert_eq!(center, 50);
         assert_eq!(threshold, 25);
     }
+
+    // --- scale_stick_threshold tests ---
+
+    #[test]
+    fn scaled_threshold_keeps_calibration_at_default_deadzone() {
+        let (_, threshold) = calculate_stick_calibration(0, 65535);
+        assert_eq!(scale_stick_threshold(threshold, CALIBRATED_DEADZONE), threshold);
+        assert_eq!(scale_stick_threshold(8000, CALIBRATED_DEADZONE), 8000);
+        assert_eq!(scale_stick_threshold(threshold, 30), 9829);
+        assert_eq!(scale_stick_threshold(threshold, 0), 0);
+        // Clamped so the stick can still reach the threshold
+        assert_eq!(scale_stick_threshold(threshold, 150), 32766);
+    }
 }
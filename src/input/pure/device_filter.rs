@@ -0,0 +1,127 @@
+// Per-device allow/block lists (pure functions)
+
+use regex::Regex;
+
+use crate::config::DeviceFilterList;
+use crate::input::types::DeviceType;
+
+/// One allow or block list entry
+#[derive(Debug)]
+pub enum DeviceRule {
+    /// USB vendor, and product unless the rule covers the whole vendor
+    Id { vendor: u16, product: Option<u16> },
+    /// Regular expression matched against the evdev name
+    Name(Regex),
+}
+
+impl DeviceRule {
+    /// Parse `vvvv:pppp`, `vvvv:*` or a name regex
+    ///
+    /// IDs always need the colon, so a name like "dead" or "Beef" stays a regex.
+    pub fn parse(rule: &str) -> Result<Self, String> {
+        let rule = rule.trim();
+        if rule.is_empty() {
+            return Err("empty rule".to_string());
+        }
+        let hex = |s: &str| (s.len() == 4).then(|| u16::from_str_radix(s, 16).ok()).flatten();
+        let id = match rule.split_once(':') {
+            Some((vendor, "*")) => hex(vendor).map(|vendor| (vendor, None)),
+            Some((vendor, product)) => hex(vendor).zip(hex(product)).map(|(v, p)| (v, Some(p))),
+            None => None,
+        };
+        match id {
+            Some((vendor, product)) => Ok(DeviceRule::Id { vendor, product }),
+            None => Regex::new(rule)
+                .map(DeviceRule::Name)
+                .map_err(|e| format!("'{}' is neither vendor:product nor a valid regex: {}", rule, e)),
+        }
+    }
+
+    pub fn matches(&self, vendor: u16, product: u16, name: &str) -> bool {
+        match self {
+            DeviceRule::Id { vendor: v, product: p } => *v == vendor && p.is_none_or(|p| p == product),
+            DeviceRule::Name(re) => re.is_match(name),
+        }
+    }
+}
+
+/// The `vendor:product` rule for one device
+pub fn device_id_rule(vendor: u16, product: u16) -> String {
+    format!("{:04x}:{:04x}", vendor, product)
+}
+
+/// Whether a device passes the allow and block lists
+///
+/// Entries that don't parse are skipped. The allow list only narrows down
+/// gamepads.
+pub fn device_listed(
+    list: &DeviceFilterList,
+    device_type: DeviceType,
+    vendor: u16,
+    product: u16,
+    name: &str,
+) -> bool {
+    let any_match = |rules: &[String]| {
+        rules
+            .iter()
+            .filter_map(|rule| DeviceRule::parse(rule).ok())
+            .any(|rule| rule.matches(vendor, product, name))
+    };
+    if any_match(&list.block) {
+        return false;
+    }
+    if device_type == DeviceType::Gamepad && !list.allow.is_empty() {
+        return any_match(&list.allow);
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THROTTLE: (u16, u16) = (0x044f, 0xb10a);
+
+    fn list(allow: &[&str], block: &[&str]) -> DeviceFilterList {
+        DeviceFilterList {
+            allow: allow.iter().map(|s| s.to_string()).collect(),
+            block: block.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn parses_ids_and_regexes() {
+        assert!(matches!(
+            DeviceRule::parse("044F:B10A"),
+            Ok(DeviceRule::Id { vendor: 0x044f, product: Some(0xb10a) })
+        ));
+        assert!(matches!(DeviceRule::parse("044f:*"), Ok(DeviceRule::Id { product: None, .. })));
+        // Four hex digits alone are a name, not a vendor
+        assert!(matches!(DeviceRule::parse("dead"), Ok(DeviceRule::Name(_))));
+        assert!(matches!(DeviceRule::parse("044f"), Ok(DeviceRule::Name(_))));
+        assert!(matches!(DeviceRule::parse("(?i)throttle"), Ok(DeviceRule::Name(_))));
+        assert!(DeviceRule::parse("[unclosed").is_err());
+        assert_eq!(device_id_rule(THROTTLE.0, THROTTLE.1), "044f:b10a");
+    }
+
+    #[test]
+    fn block_list_hides_any_device_type() {
+        let (v, p) = THROTTLE;
+        let blocked = list(&[], &["044f:b10a"]);
+        assert!(!device_listed(&blocked, DeviceType::Gamepad, v, p, "T.16000M"));
+        assert!(device_listed(&blocked, DeviceType::Gamepad, v, 0x0001, "Other"));
+        let by_name = list(&[], &["(?i)throttle"]);
+        assert!(!device_listed(&by_name, DeviceType::Keyboard, 1, 2, "TWCS Throttle"));
+    }
+
+    #[test]
+    fn allow_list_only_narrows_gamepads() {
+        let allowed = list(&["045e:*"], &[]);
+        assert!(device_listed(&allowed, DeviceType::Gamepad, 0x045e, 0x02ea, "Xbox pad"));
+        assert!(!device_listed(&allowed, DeviceType::Gamepad, THROTTLE.0, THROTTLE.1, "Throttle"));
+        assert!(device_listed(&allowed, DeviceType::Keyboard, 0x1234, 0x5678, "Keyboard"));
+        // Blocking wins over allowing
+        let both = list(&["045e:*"], &["045e:02ea"]);
+        assert!(!device_listed(&both, DeviceType::Gamepad, 0x045e, 0x02ea, "Xbox pad"));
+    }
+}
@@ -0,0 +1,118 @@
+// Grouping of evdev nodes that belong to one physical controller (pure functions)
+//
+// Controllers like the DualSense or Joy-Cons expose the buttons, touchpad and
+// motion sensors as separate evdev nodes. These helpers work out which nodes
+// should be folded into their gamepad so the device list shows one entry.
+
+use crate::input::types::DeviceType;
+
+/// Key identifying the physical device behind an evdev node
+///
+/// Prefers the unique ID (Bluetooth MAC or USB serial). Otherwise falls back
+/// to the physical path with its `/inputN` suffix stripped, combined with the
+/// vendor/product IDs. Returns None when neither is available.
+pub fn physical_device_key(uniq: &str, phys: &str, vendor: u16, product: u16) -> Option<String> {
+    if !uniq.is_empty() {
+        return Some(format!("uniq:{}", uniq));
+    }
+    if phys.is_empty() {
+        return None;
+    }
+    let base = match phys.rsplit_once("/input") {
+        Some((base, suffix)) if suffix.chars().all(|c| c.is_ascii_digit()) => base,
+        _ => phys,
+    };
+    Some(format!("phys:{}:{:04x}:{:04x}", base, vendor, product))
+}
+
+/// For each node, the index of the gamepad node it should be merged into
+///
+/// Only non-gamepad nodes (touchpads, motion sensors, consumer keys) are
+/// merged, and only when exactly one gamepad shares their key, so separate
+/// pads behind a single adapter stay separate.
+pub fn group_sibling_nodes(nodes: &[(Option<String>, DeviceType)]) -> Vec<Option<usize>> {
+    nodes
+        .iter()
+        .map(|(key, device_type)| {
+            let key = key.as_ref()?;
+            if *device_type == DeviceType::Gamepad {
+                return None;
+            }
+            let mut gamepads = nodes.iter().enumerate().filter(|(_, (other, other_type))| {
+                *other_type == DeviceType::Gamepad && other.as_ref() == Some(key)
+            });
+            match (gamepads.next(), gamepads.next()) {
+                (Some((idx, _)), None) => Some(idx),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(key: &str, device_type: DeviceType) -> (Option<String>, DeviceType) {
+        (Some(key.to_string()), device_type)
+    }
+
+    #[test]
+    fn key_prefers_uniq() {
+        assert_eq!(
+            physical_device_key("aa:bb:cc", "usb-0000:00:14.0-2/input0", 0x054c, 0x0ce6),
+            Some("uniq:aa:bb:cc".to_string())
+        );
+    }
+
+    #[test]
+    fn key_strips_input_suffix() {
+        let a = physical_device_key("", "usb-0000:00:14.0-2/input0", 0x054c, 0x0ce6);
+        let b = physical_device_key("", "usb-0000:00:14.0-2/input3", 0x054c, 0x0ce6);
+        assert_eq!(a, b);
+        assert_eq!(a, Some("phys:usb-0000:00:14.0-2:054c:0ce6".to_string()));
+    }
+
+    #[test]
+    fn key_includes_vendor_product() {
+        let a = physical_device_key("", "usb-0000:00:14.0-2/input0", 0x054c, 0x0ce6);
+        let b = physical_device_key("", "usb-0000:00:14.0-2/input0", 0x045e, 0x028e);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn key_none_without_identifiers() {
+        assert_eq!(physical_device_key("", "", 0x054c, 0x0ce6), None);
+    }
+
+    #[test]
+    fn group_touchpad_and_motion_into_gamepad() {
+        let nodes = vec![
+            node("uniq:ds", DeviceType::Gamepad),
+            node("uniq:ds", DeviceType::Mouse),
+            node("uniq:ds", DeviceType::Other),
+            node("uniq:kb", DeviceType::Keyboard),
+        ];
+        assert_eq!(group_sibling_nodes(&nodes), vec![None, Some(0), Some(0), None]);
+    }
+
+    #[test]
+    fn group_ignores_ambiguous_gamepads() {
+        let nodes = vec![
+            node("phys:adapter", DeviceType::Gamepad),
+            node("phys:adapter", DeviceType::Gamepad),
+            node("phys:adapter", DeviceType::Other),
+        ];
+        assert_eq!(group_sibling_nodes(&nodes), vec![None, None, None]);
+    }
+
+    #[test]
+    fn group_leaves_keyboard_receivers_alone() {
+        let nodes = vec![
+            node("phys:receiver", DeviceType::Keyboard),
+            node("phys:receiver", DeviceType::Mouse),
+            (None, DeviceType::Mouse),
+        ];
+        assert_eq!(group_sibling_nodes(&nodes), vec![None, None, None]);
+    }
+}
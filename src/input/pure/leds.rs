@@ -0,0 +1,80 @@
+// Controller LED classification (pure functions)
+
+/// An addressable LED of a controller, by its sysfs LED class name
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LedKind {
+    /// Multicolor lightbar (hid-playstation: `inputN:rgb:indicator`)
+    Rgb,
+    /// Single-channel lightbar LEDs (hid-sony: `<hid>:red`, `:green`, `:blue`)
+    Red,
+    Green,
+    Blue,
+    /// Numbered player indicator (`inputN:white:player-2`, `<hid>:green:player-1`, `<hid>:player1`)
+    Player(u8),
+}
+
+/// Classify a sysfs LED name; None for LEDs we don't drive (caps lock, home button...)
+pub fn classify_led(name: &str) -> Option<LedKind> {
+    if name.ends_with(":rgb:indicator") {
+        return Some(LedKind::Rgb);
+    }
+    let function = name.rsplit(':').next()?;
+    match function {
+        "red" => Some(LedKind::Red),
+        "green" => Some(LedKind::Green),
+        "blue" => Some(LedKind::Blue),
+        _ => {
+            let number = function.strip_prefix("player")?.trim_start_matches('-');
+            number.parse().ok().filter(|&n| n > 0).map(LedKind::Player)
+        }
+    }
+}
+
+/// Whether player indicator `led` (1-based, of `count`) is lit for a 0-based player
+///
+/// Five-LED bars (DualSense) use the console's centered patterns; other
+/// controllers (Switch Pro, Joy-Con) light the LED matching the player number.
+pub fn player_led_on(led: u8, count: u8, player: usize) -> bool {
+    if count == 5 {
+        const PATTERNS: [[bool; 5]; 4] = [
+            [false, false, true, false, false],
+            [false, true, false, true, false],
+            [true, false, true, false, true],
+            [true, true, false, true, true],
+        ];
+        return PATTERNS
+            .get(player)
+            .and_then(|p| p.get(led as usize - 1))
+            .copied()
+            .unwrap_or(false);
+    }
+    led as usize == player + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_controller_leds() {
+        assert_eq!(classify_led("input12:rgb:indicator"), Some(LedKind::Rgb));
+        assert_eq!(classify_led("0005:054C:05C4.0007:blue"), Some(LedKind::Blue));
+        assert_eq!(classify_led("input12:white:player-3"), Some(LedKind::Player(3)));
+        assert_eq!(classify_led("0003:057E:2009.0004:green:player-1"), Some(LedKind::Player(1)));
+        assert_eq!(classify_led("0003:057E:2009.0004:player4"), Some(LedKind::Player(4)));
+        assert_eq!(classify_led("input3::capslock"), None);
+        assert_eq!(classify_led("0003:057E:2009.0004:home"), None);
+    }
+
+    #[test]
+    fn player_patterns() {
+        let lit = |count: u8, player: usize| -> Vec<u8> {
+            (1..=count).filter(|&led| player_led_on(led, count, player)).collect()
+        };
+        assert_eq!(lit(4, 0), vec![1]);
+        assert_eq!(lit(4, 2), vec![3]);
+        assert_eq!(lit(5, 0), vec![3]);
+        assert_eq!(lit(5, 3), vec![1, 2, 4, 5]);
+        assert!(lit(5, 7).is_empty());
+    }
+}
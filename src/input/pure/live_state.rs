@@ -0,0 +1,58 @@
+// Labels for the input test view (pure functions)
+
+use evdev::{AbsoluteAxisCode, KeyCode};
+
+/// Readable name for a key or button, e.g. "A (BTN_SOUTH)"
+///
+/// Gamepad face buttons are named by position in evdev, so the usual
+/// Xbox-style label is added. Other codes keep their evdev name.
+pub fn key_label(key: KeyCode) -> String {
+    let name = format!("{:?}", key);
+    let friendly = match key {
+        KeyCode::BTN_SOUTH => "A",
+        KeyCode::BTN_EAST => "B",
+        KeyCode::BTN_NORTH => "X",
+        KeyCode::BTN_WEST => "Y",
+        KeyCode::BTN_TL => "LB",
+        KeyCode::BTN_TR => "RB",
+        KeyCode::BTN_TL2 => "LT",
+        KeyCode::BTN_TR2 => "RT",
+        KeyCode::BTN_START => "Start",
+        KeyCode::BTN_SELECT => "Select",
+        KeyCode::BTN_MODE => "Guide",
+        KeyCode::BTN_THUMBL => "L3",
+        KeyCode::BTN_THUMBR => "R3",
+        _ => return name,
+    };
+    format!("{} ({})", friendly, name)
+}
+
+/// Readable name for an absolute axis, e.g. "Left stick X (ABS_X)"
+pub fn axis_label(axis: AbsoluteAxisCode) -> String {
+    let name = format!("{:?}", axis);
+    let friendly = match axis {
+        AbsoluteAxisCode::ABS_X => "Left stick X",
+        AbsoluteAxisCode::ABS_Y => "Left stick Y",
+        AbsoluteAxisCode::ABS_RX => "Right stick X",
+        AbsoluteAxisCode::ABS_RY => "Right stick Y",
+        AbsoluteAxisCode::ABS_Z => "Left trigger",
+        AbsoluteAxisCode::ABS_RZ => "Right trigger",
+        AbsoluteAxisCode::ABS_HAT0X => "D-pad X",
+        AbsoluteAxisCode::ABS_HAT0Y => "D-pad Y",
+        _ => return name,
+    };
+    format!("{} ({})", friendly, name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gamepad_codes_get_friendly_names() {
+        assert_eq!(key_label(KeyCode::BTN_SOUTH), "A (BTN_SOUTH)");
+        assert_eq!(key_label(KeyCode::KEY_A), "KEY_A");
+        assert_eq!(axis_label(AbsoluteAxisCode::ABS_HAT0Y), "D-pad Y (ABS_HAT0Y)");
+        assert_eq!(axis_label(AbsoluteAxisCode::ABS_THROTTLE), "ABS_THROTTLE");
+    }
+}
@@ -0,0 +1,50 @@
+// Analog stick navigation for the launcher (pure functions)
+
+use crate::input::types::StickDirection;
+
+/// Direction the stick is pushed past the threshold, vertical first
+pub fn stick_direction(x: i32, y: i32, center: i32, threshold: i32) -> Option<StickDirection> {
+    if y < center - threshold {
+        Some(StickDirection::Up)
+    } else if y > center + threshold {
+        Some(StickDirection::Down)
+    } else if x < center - threshold {
+        Some(StickDirection::Left)
+    } else if x > center + threshold {
+        Some(StickDirection::Right)
+    } else {
+        None
+    }
+}
+
+/// Whether a held direction should fire again, like keyboard auto-repeat
+///
+/// Nothing repeats until the direction has been held longer than `delay_ms`,
+/// then it fires once more than every `rate_ms` has passed.
+pub fn should_repeat(held_ms: u128, since_last_ms: u128, delay_ms: u32, rate_ms: u32) -> bool {
+    held_ms > delay_ms as u128 && since_last_ms > rate_ms as u128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drift_inside_deadzone_is_ignored() {
+        let threshold = 9829;
+        assert_eq!(stick_direction(6000, -8000, 0, threshold), None);
+        assert_eq!(stick_direction(0, -12000, 0, threshold), Some(StickDirection::Up));
+        assert_eq!(stick_direction(12000, 0, 0, threshold), Some(StickDirection::Right));
+        // Unsigned axes are centered at half range
+        assert_eq!(stick_direction(32767, 65535, 32767, threshold), Some(StickDirection::Down));
+    }
+
+    #[test]
+    fn repeat_waits_for_delay_then_follows_rate() {
+        assert!(!should_repeat(200, 200, 300, 80));
+        assert!(!should_repeat(300, 300, 300, 80));
+        assert!(should_repeat(301, 301, 300, 80));
+        assert!(!should_repeat(380, 80, 300, 80));
+        assert!(should_repeat(381, 81, 300, 80));
+    }
+}
@@ -1,5 +1,8 @@
 // Input device types
 
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
 #[derive(Clone, PartialEq, Copy, Debug)]
 pub enum DeviceType {
     Gamepad,
@@ -43,9 +46,27 @@ pub struct DeviceInfo {
     pub enabled: bool,
     pub device_type: DeviceType,
     pub uniq: String, // Unique identifier (Bluetooth MAC or USB serial)
+    pub siblings: Vec<String>, // Extra evdev nodes of the same controller (touchpad, motion)
+}
+
+/// Battery state of a wireless controller, from its power_supply node
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Battery {
+    /// Charge in percent; estimated when the driver only reports a level
+    pub percent: u8,
+    pub charging: bool,
 }
 
-#[derive(Clone, Copy, PartialEq)]
+/// Keys held and axis positions of a device as of its last poll
+#[derive(Clone, Default)]
+pub struct LiveInputState {
+    /// Labels of keys and buttons currently held
+    pub pressed: Vec<String>,
+    /// Label and raw value of each axis the device reports
+    pub axes: Vec<(String, i32)>,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum StickDirection {
     Up,
     Down,
@@ -62,3 +83,10 @@ pub enum PollResult {
     /// No input
     None,
 }
+
+/// Exported device aliases (JSON), keyed by unique ID (Bluetooth MAC or USB serial)
+#[derive(Serialize, Deserialize)]
+pub struct DeviceAliasFile {
+    pub version: u32,
+    pub aliases: BTreeMap<String, String>,
+}
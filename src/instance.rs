@@ -1,6 +1,9 @@
+use std::collections::HashMap;
+
 use crate::Monitor;
 use crate::app::SplituxConfig;
 use crate::profiles::GUEST_NAMES;
+use crate::wm::layout::{instance_geometry, WindowGeometry};
 
 #[derive(Clone)]
 pub struct Instance {
@@ -10,34 +13,102 @@ pub struct Instance {
     pub monitor: usize,
     pub width: u32,
     pub height: u32,
+    /// Per-instance cursor confinement; None inherits `gamescope_force_grab_cursor`
+    pub grab_cursor: Option<bool>,
+    /// Show the MangoHud performance overlay in this instance
+    pub mangohud: bool,
+    /// Resolution the game renders at, in physical pixels; 0 uses the layout
+    /// size (a single 0 keeps the layout's aspect ratio)
+    pub resolution_override: (u32, u32),
+    /// Extra game arguments for this player, appended to the handler's args.
+    /// Quoted words stay together and `{player}` becomes the 1-based player number.
+    pub args_override: String,
+}
+
+impl Instance {
+    /// Whether gamescope should confine the cursor for this instance
+    pub fn grabs_cursor(&self, cfg: &SplituxConfig) -> bool {
+        self.grab_cursor.unwrap_or(cfg.gamescope_force_grab_cursor)
+    }
+
+    /// Overridden render resolution, if set (see `override_resolution`)
+    pub fn render_override(&self, scale: f32, cfg: &SplituxConfig) -> Option<(u32, u32)> {
+        override_resolution(
+            self.resolution_override,
+            (self.width, self.height),
+            scale,
+            cfg.gamescope_fix_lowres,
+        )
+    }
+}
+
+/// Resolve a per-instance resolution override against the layout size
+///
+/// (0, 0) means no override. A single zero is filled in from the layout's
+/// aspect ratio. Explicit values are only raised by the low-res fix when
+/// `lowres_fix` is on; otherwise they are used exactly as given.
+pub fn override_resolution(
+    requested: (u32, u32),
+    layout: (u32, u32),
+    scale: f32,
+    lowres_fix: bool,
+) -> Option<(u32, u32)> {
+    let (lw, lh) = layout;
+    let (w, h) = match requested {
+        (0, 0) => return None,
+        (w, 0) if lw > 0 => (w, (w as u64 * lh as u64 / lw as u64) as u32),
+        (0, h) if lh > 0 => ((h as u64 * lw as u64 / lh as u64) as u32, h),
+        (w, h) if w > 0 && h > 0 => (w, h),
+        _ => return None,
+    };
+    Some(if lowres_fix { fix_lowres(w, h, scale) } else { (w, h) })
+}
+
+/// Whether the low-res fix would raise this size
+pub fn below_lowres_minimum(height: u32, scale: f32) -> bool {
+    height > 0 && (height as f32 / scale) < LOWRES_MIN_HEIGHT
+}
+
+/// Minimum instance height in logical pixels when `gamescope_fix_lowres` is on
+const LOWRES_MIN_HEIGHT: f32 = 600.0;
+
+/// Raise an instance below the minimum logical height, keeping its aspect ratio
+///
+/// Sizes are physical pixels; `scale` is the monitor's output scale, so a
+/// half-height instance on a 4K display at 2x counts as 540 lines, not 1080.
+fn fix_lowres(w: u32, h: u32, scale: f32) -> (u32, u32) {
+    if !below_lowres_minimum(h, scale) {
+        return (w, h);
+    }
+    let ratio = w as f32 / h as f32;
+    let h = (LOWRES_MIN_HEIGHT * scale).round() as u32;
+    ((h as f32 * ratio) as u32, h)
+}
+
+/// Size of the `index`th instance's region on its `width`x`height` monitor
+///
+/// Computed by `instance_geometry`, so it matches where the window manager
+/// places the window.
+fn layout_size(cfg: &SplituxConfig, instance_monitors: &[usize], index: usize, width: u32, height: u32) -> (u32, u32) {
+    let monitor = WindowGeometry { x: 0, y: 0, width, height };
+    let geom = instance_geometry(&cfg.layout_presets, instance_monitors, index, &monitor);
+    (geom.width, geom.height)
 }
 
 pub fn set_instance_resolutions(
-    instances: &mut Vec<Instance>,
+    instances: &mut [Instance],
     primary_monitor: &Monitor,
     cfg: &SplituxConfig,
 ) {
     let (basewidth, baseheight) = (primary_monitor.width(), primary_monitor.height());
-    let playercount = instances.len();
+    let scale = primary_monitor.scale();
+    // Everyone shares the primary monitor
+    let instance_monitors = vec![0; instances.len()];
 
-    for instance in instances {
-        let (mut w, mut h) = match playercount {
-            1 => (basewidth, baseheight),
-            2 => {
-                // Check layout_presets for vertical vs horizontal
-                let is_vertical = cfg.layout_presets.two_player.contains("vertical");
-                if is_vertical {
-                    (basewidth / 2, baseheight)
-                } else {
-                    (basewidth, baseheight / 2)
-                }
-            }
-            _ => (basewidth / 2, baseheight / 2),
-        };
-        if h < 600 && cfg.gamescope_fix_lowres {
-            let ratio = w as f32 / h as f32;
-            h = 600;
-            w = (h as f32 * ratio) as u32;
+    for (i, instance) in instances.iter_mut().enumerate() {
+        let (mut w, mut h) = layout_size(cfg, &instance_monitors, i, basewidth, baseheight);
+        if cfg.gamescope_fix_lowres {
+            (w, h) = fix_lowres(w, h, scale);
         }
         instance.width = w;
         instance.height = h;
@@ -49,36 +120,18 @@ pub fn set_instance_resolutions_multimonitor(
     monitors: &Vec<Monitor>,
     cfg: &SplituxConfig,
 ) {
-    let mut mon_playercounts: Vec<usize> = vec![0; monitors.len()];
-    for instance in instances.iter() {
-        let mon = instance.monitor;
-        mon_playercounts[mon] += 1;
-    }
+    let instance_monitors: Vec<usize> = instances.iter().map(|i| i.monitor).collect();
 
-    for instance in instances.iter_mut() {
-        let playercount = mon_playercounts[instance.monitor];
+    for (i, instance) in instances.iter_mut().enumerate() {
         let (basewidth, baseheight) = (
             monitors[instance.monitor].width(),
             monitors[instance.monitor].height(),
         );
+        let scale = monitors[instance.monitor].scale();
 
-        let (mut w, mut h) = match playercount {
-            1 => (basewidth, baseheight),
-            2 => {
-                // Check layout_presets for vertical vs horizontal
-                let is_vertical = cfg.layout_presets.two_player.contains("vertical");
-                if is_vertical {
-                    (basewidth / 2, baseheight)
-                } else {
-                    (basewidth, baseheight / 2)
-                }
-            }
-            _ => (basewidth / 2, baseheight / 2),
-        };
-        if h < 600 && cfg.gamescope_fix_lowres {
-            let ratio = w as f32 / h as f32;
-            h = 600;
-            w = (h as f32 * ratio) as u32;
+        let (mut w, mut h) = layout_size(cfg, &instance_monitors, i, basewidth, baseheight);
+        if cfg.gamescope_fix_lowres {
+            (w, h) = fix_lowres(w, h, scale);
         }
         instance.width = w;
         instance.height = h;
@@ -98,3 +151,117 @@ pub fn set_instance_names(instances: &mut Vec<Instance>, profiles: &[String]) {
         }
     }
 }
+
+/// Named profiles selected by more than one instance, with their instance indices
+///
+/// Two instances sharing a profile write the same save and config files.
+/// Guests (selection 0, or `.`-prefixed names) get their own throwaway
+/// profile each, so they never conflict.
+pub fn duplicate_profiles(instances: &[Instance], profiles: &[String]) -> Vec<(String, Vec<usize>)> {
+    let mut usage: Vec<(String, Vec<usize>)> = Vec::new();
+    for (i, instance) in instances.iter().enumerate() {
+        let Some(name) = profiles.get(instance.profselection) else {
+            continue;
+        };
+        if instance.profselection == 0 || name.starts_with('.') {
+            continue;
+        }
+        match usage.iter_mut().find(|(n, _)| n == name) {
+            Some((_, users)) => users.push(i),
+            None => usage.push((name.clone(), vec![i])),
+        }
+    }
+    usage.retain(|(_, users)| users.len() > 1);
+    usage
+}
+
+/// One line per shared profile naming its players, e.g. "Alice (P1, P3)"
+pub fn describe_duplicate_profiles(shared: &[(String, Vec<usize>)]) -> Vec<String> {
+    shared
+        .iter()
+        .map(|(name, users)| {
+            let players: Vec<String> = users.iter().map(|i| format!("P{}", i + 1)).collect();
+            format!("{} ({})", name, players.join(", "))
+        })
+        .collect()
+}
+
+/// Swap the entries for two instance indices in a per-instance map
+///
+/// Used when two instances trade places, so session overrides keyed by
+/// index stay with their instance. A missing entry moves as a missing entry.
+pub fn swap_instance_keys<V>(map: &mut HashMap<usize, V>, a: usize, b: usize) {
+    let (va, vb) = (map.remove(&a), map.remove(&b));
+    if let Some(v) = va {
+        map.insert(b, v);
+    }
+    if let Some(v) = vb {
+        map.insert(a, v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instance(profselection: usize) -> Instance {
+        Instance {
+            devices: Vec::new(),
+            profname: String::new(),
+            profselection,
+            monitor: 0,
+            width: 0,
+            height: 0,
+            grab_cursor: None,
+            mangohud: false,
+            resolution_override: (0, 0),
+            args_override: String::new(),
+        }
+    }
+
+    #[test]
+    fn shared_named_profiles_are_reported() {
+        let profiles: Vec<String> = ["Guest", ".Temp", "Alice", "Bob"].iter().map(|s| s.to_string()).collect();
+        let instances = vec![instance(2), instance(0), instance(2), instance(0), instance(1), instance(1), instance(3)];
+        let shared = duplicate_profiles(&instances, &profiles);
+        assert_eq!(shared, vec![("Alice".to_string(), vec![0, 2])]);
+        assert_eq!(describe_duplicate_profiles(&shared), vec!["Alice (P1, P3)"]);
+        assert!(duplicate_profiles(&[instance(2), instance(3)], &profiles).is_empty());
+    }
+
+    #[test]
+    fn fix_lowres_uses_logical_height() {
+        // 1920x540 physical is below 600 lines at 1x
+        assert_eq!(fix_lowres(1920, 540, 1.0), (2133, 600));
+        // 3840x1080 at 2x is 540 logical lines
+        assert_eq!(fix_lowres(3840, 1080, 2.0), (4266, 1200));
+        // 1920x1080 at 1.5x is 720 logical lines, fine as-is
+        assert_eq!(fix_lowres(1920, 1080, 1.5), (1920, 1080));
+    }
+
+    #[test]
+    fn override_zero_means_auto() {
+        assert_eq!(override_resolution((0, 0), (1920, 1080), 1.0, true), None);
+    }
+
+    #[test]
+    fn override_fills_in_missing_side() {
+        assert_eq!(override_resolution((1280, 0), (1920, 1080), 1.0, false), Some((1280, 720)));
+        assert_eq!(override_resolution((0, 720), (1920, 1080), 1.0, false), Some((1280, 720)));
+    }
+
+    #[test]
+    fn override_below_600p_only_raised_with_lowres_fix() {
+        assert_eq!(override_resolution((960, 540), (1920, 1080), 1.0, false), Some((960, 540)));
+        assert_eq!(override_resolution((960, 540), (1920, 1080), 1.0, true), Some((1066, 600)));
+    }
+
+    #[test]
+    fn swapping_keys_moves_missing_entries_too() {
+        let mut map = HashMap::from([(0, "mute"), (3, "other")]);
+        swap_instance_keys(&mut map, 0, 2);
+        assert_eq!(map, HashMap::from([(2, "mute"), (3, "other")]));
+        swap_instance_keys(&mut map, 2, 3);
+        assert_eq!(map, HashMap::from([(3, "mute"), (2, "other")]));
+    }
+}
@@ -0,0 +1,314 @@
+// Last session module
+// Remembers the players of the most recent launch so it can be replayed
+// from the games page with the same devices, profiles and monitors
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::input::InputDevice;
+use crate::instance::Instance;
+use crate::paths::PATH_PARTY;
+
+/// Minimal descriptor of a launched session
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LastSession {
+    /// Handler directory name of the game that was launched
+    pub handler: String,
+    pub instances: Vec<SessionInstance>,
+}
+
+/// One player of a remembered session
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SessionInstance {
+    pub devices: Vec<SessionDevice>,
+    /// Profile name; empty for a guest
+    #[serde(default)]
+    pub profile: String,
+    /// Monitor output name (see `Monitor::name`)
+    #[serde(default)]
+    pub monitor: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grab_cursor: Option<bool>,
+    #[serde(default)]
+    pub mangohud: bool,
+    #[serde(default)]
+    pub resolution_override: (u32, u32),
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub args_override: String,
+}
+
+/// Identity of an input device, stable across reconnects where possible
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SessionDevice {
+    /// Bluetooth MAC or USB serial; empty for most keyboards and mice
+    #[serde(default)]
+    pub uniq: String,
+    pub name: String,
+    /// evdev path, used to tell apart devices without a uniq
+    pub path: String,
+}
+
+impl SessionDevice {
+    pub fn from_device(device: &InputDevice) -> Self {
+        Self {
+            uniq: device.uniq().to_string(),
+            name: device.name().to_string(),
+            path: device.path().to_string(),
+        }
+    }
+
+    /// Whether a connected device is the one remembered
+    ///
+    /// Devices with a uniq match on it alone, so a controller that
+    /// reconnected on a new evdev node is still found. Others need both the
+    /// same name and the same path.
+    fn matches(&self, other: &SessionDevice) -> bool {
+        if !self.uniq.is_empty() {
+            return self.uniq == other.uniq;
+        }
+        other.uniq.is_empty() && self.name == other.name && self.path == other.path
+    }
+}
+
+/// A remembered session bound to the devices, profiles and monitors of now
+pub struct ReboundSession {
+    pub instances: Vec<Instance>,
+    /// Names of remembered devices that aren't connected (or are filtered out)
+    pub missing_devices: Vec<String>,
+    /// Remembered profiles that no longer exist; those players become guests
+    pub missing_profiles: Vec<String>,
+    /// Remembered monitors that aren't connected; those players use the first
+    pub missing_monitors: Vec<String>,
+}
+
+impl ReboundSession {
+    pub fn is_complete(&self) -> bool {
+        self.missing_devices.is_empty()
+    }
+}
+
+impl LastSession {
+    pub fn path() -> PathBuf {
+        PATH_PARTY.join("last_session.json")
+    }
+
+    /// Load the last session, if one was saved and is still readable
+    pub fn load() -> Option<Self> {
+        std::fs::read_to_string(Self::path())
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .filter(|s: &Self| !s.handler.is_empty() && !s.instances.is_empty())
+    }
+
+    pub fn save(&self) -> std::io::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(Self::path(), json)
+    }
+
+    /// Describe instances about to launch
+    ///
+    /// `profiles` and `monitor_names` are indexed like the instances'
+    /// `profselection` and `monitor`; profile 0 is the guest.
+    pub fn capture(
+        handler: &str,
+        instances: &[Instance],
+        devices: &[InputDevice],
+        profiles: &[String],
+        monitor_names: &[&str],
+    ) -> Self {
+        let instances = instances
+            .iter()
+            .map(|instance| SessionInstance {
+                devices: instance
+                    .devices
+                    .iter()
+                    .filter_map(|&d| devices.get(d))
+                    .map(SessionDevice::from_device)
+                    .collect(),
+                profile: match instance.profselection {
+                    0 => String::new(),
+                    i => profiles.get(i).cloned().unwrap_or_default(),
+                },
+                monitor: monitor_names
+                    .get(instance.monitor)
+                    .map(|n| n.to_string())
+                    .unwrap_or_default(),
+                grab_cursor: instance.grab_cursor,
+                mangohud: instance.mangohud,
+                resolution_override: instance.resolution_override,
+                args_override: instance.args_override.clone(),
+            })
+            .collect();
+        Self {
+            handler: handler.to_string(),
+            instances,
+        }
+    }
+
+    /// Rebuild the session's instances against what is connected now
+    ///
+    /// `devices` is indexed like the app's input devices; `None` marks a
+    /// device that can't be assigned. Each device is bound at most once.
+    /// Players keep their slot even when none of their devices were found,
+    /// so they can be reassigned rather than silently dropped.
+    pub fn rebind(
+        &self,
+        devices: &[Option<SessionDevice>],
+        profiles: &[String],
+        monitor_names: &[&str],
+    ) -> ReboundSession {
+        let mut used = vec![false; devices.len()];
+        let mut rebound = ReboundSession {
+            instances: Vec::new(),
+            missing_devices: Vec::new(),
+            missing_profiles: Vec::new(),
+            missing_monitors: Vec::new(),
+        };
+
+        for saved in &self.instances {
+            let mut bound = Vec::new();
+            for want in &saved.devices {
+                let found = devices.iter().enumerate().position(|(i, d)| {
+                    !used[i] && d.as_ref().is_some_and(|d| want.matches(d))
+                });
+                match found {
+                    Some(i) => {
+                        used[i] = true;
+                        bound.push(i);
+                    }
+                    None => rebound.missing_devices.push(want.name.clone()),
+                }
+            }
+
+            let profselection = if saved.profile.is_empty() {
+                0
+            } else {
+                match profiles.iter().skip(1).position(|p| *p == saved.profile) {
+                    Some(i) => i + 1,
+                    None => {
+                        rebound.missing_profiles.push(saved.profile.clone());
+                        0
+                    }
+                }
+            };
+
+            let monitor = match monitor_names.iter().position(|n| *n == saved.monitor) {
+                Some(i) => i,
+                None => {
+                    if !saved.monitor.is_empty() && !rebound.missing_monitors.contains(&saved.monitor) {
+                        rebound.missing_monitors.push(saved.monitor.clone());
+                    }
+                    0
+                }
+            };
+
+            rebound.instances.push(Instance {
+                devices: bound,
+                profname: String::new(),
+                profselection,
+                monitor,
+                width: 0,
+                height: 0,
+                grab_cursor: saved.grab_cursor,
+                mangohud: saved.mangohud,
+                resolution_override: saved.resolution_override,
+                args_override: saved.args_override.clone(),
+            });
+        }
+
+        rebound
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dev(uniq: &str, name: &str, path: &str) -> SessionDevice {
+        SessionDevice {
+            uniq: uniq.to_string(),
+            name: name.to_string(),
+            path: path.to_string(),
+        }
+    }
+
+    fn player(devices: Vec<SessionDevice>, profile: &str, monitor: &str) -> SessionInstance {
+        SessionInstance {
+            devices,
+            profile: profile.to_string(),
+            monitor: monitor.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn session(instances: Vec<SessionInstance>) -> LastSession {
+        LastSession {
+            handler: "game".to_string(),
+            instances,
+        }
+    }
+
+    #[test]
+    fn rebinds_controllers_by_uniq_across_paths() {
+        let s = session(vec![
+            player(vec![dev("aa", "Pad", "/dev/input/event5")], "Alice", "DP-1"),
+            player(vec![dev("bb", "Pad", "/dev/input/event6")], "", "DP-1"),
+        ]);
+        let now = [
+            Some(dev("bb", "Pad", "/dev/input/event9")),
+            Some(dev("aa", "Pad", "/dev/input/event8")),
+        ];
+        let profiles = ["Guest".to_string(), "Alice".to_string()];
+        let r = s.rebind(&now, &profiles, &["DP-1"]);
+        assert!(r.is_complete());
+        assert_eq!(r.instances[0].devices, vec![1]);
+        assert_eq!(r.instances[0].profselection, 1);
+        assert_eq!(r.instances[1].devices, vec![0]);
+        assert_eq!(r.instances[1].profselection, 0);
+    }
+
+    #[test]
+    fn devices_without_uniq_need_name_and_path() {
+        let s = session(vec![player(vec![dev("", "Keyboard", "/dev/input/event2")], "", "")]);
+        let moved = [Some(dev("", "Keyboard", "/dev/input/event3"))];
+        let r = s.rebind(&moved, &[], &["DP-1"]);
+        assert_eq!(r.missing_devices, vec!["Keyboard".to_string()]);
+        let same = [Some(dev("", "Keyboard", "/dev/input/event2"))];
+        assert!(s.rebind(&same, &[], &["DP-1"]).is_complete());
+    }
+
+    #[test]
+    fn missing_device_keeps_the_player() {
+        let s = session(vec![
+            player(vec![dev("aa", "Pad A", "/dev/input/event5")], "", "DP-1"),
+            player(vec![dev("bb", "Pad B", "/dev/input/event6")], "", "DP-1"),
+        ]);
+        // Pad B is connected but filtered out
+        let now = [Some(dev("aa", "Pad A", "/dev/input/event5")), None];
+        let r = s.rebind(&now, &[], &["DP-1"]);
+        assert_eq!(r.instances.len(), 2);
+        assert!(r.instances[1].devices.is_empty());
+        assert_eq!(r.missing_devices, vec!["Pad B".to_string()]);
+    }
+
+    #[test]
+    fn same_device_is_bound_once() {
+        let kb = dev("", "Keyboard", "/dev/input/event2");
+        let s = session(vec![player(vec![kb.clone()], "", ""), player(vec![kb.clone()], "", "")]);
+        let r = s.rebind(&[Some(kb)], &[], &[]);
+        assert_eq!(r.instances[0].devices, vec![0]);
+        assert!(r.instances[1].devices.is_empty());
+        assert_eq!(r.missing_devices.len(), 1);
+    }
+
+    #[test]
+    fn missing_profile_and_monitor_fall_back() {
+        let s = session(vec![player(vec![], "Bob", "HDMI-A-1")]);
+        let profiles = ["Guest".to_string(), "Alice".to_string()];
+        let r = s.rebind(&[], &profiles, &["DP-1", "DP-2"]);
+        assert_eq!(r.instances[0].profselection, 0);
+        assert_eq!(r.instances[0].monitor, 0);
+        assert_eq!(r.missing_profiles, vec!["Bob".to_string()]);
+        assert_eq!(r.missing_monitors, vec!["HDMI-A-1".to_string()]);
+    }
+}
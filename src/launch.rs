@@ -10,7 +10,7 @@
 //! - `types.rs`: Constants and type definitions
 //! - `pure/`: Pure functions (validation)
 //! - `operations/`: Atomic side effects (profiles, overlays)
-//! - `pipelines/`: High-level orchestration (build_cmds, execute)
+//! - `pipelines/`: High-level orchestration (build_cmds, execute, preview, run)
 
 mod operations;
 mod pipelines;
@@ -18,5 +18,7 @@ mod pure;
 mod types;
 
 // Re-export public API
-pub use operations::setup_profiles;
-pub use pipelines::launch_game;
+pub use operations::overlay_only_features;
+pub use pipelines::{launch_command_line, run_session};
+pub use pure::resolve_hook_path;
+pub use types::{KillSwitch, SessionOutcome};
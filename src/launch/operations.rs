@@ -1,7 +1,17 @@
 //! Operations module (atomic side effects)
 
+pub mod controller_mappings;
+pub mod health;
+pub mod hooks;
+pub mod ipc;
+pub mod kill_combo;
 pub mod overlays;
 pub mod profiles;
 
-pub use overlays::fuse_overlayfs_mount_gamedirs;
-pub use profiles::setup_profiles;
+pub use controller_mappings::prepare_mapping_file;
+pub use health::{wait_for_instances, MonitoredInstance};
+pub use hooks::run_hook;
+pub use ipc::IpcServer;
+pub use kill_combo::spawn_kill_combo_watcher;
+pub use overlays::{fuse_overlayfs_mount_gamedirs, overlay_only_features};
+pub use profiles::{setup_prefixes, setup_profiles};
@@ -0,0 +1,55 @@
+//! SDL controller mapping file preparation (I/O)
+
+use std::path::{Path, PathBuf};
+
+/// Mapping file to export as SDL_GAMECONTROLLERCONFIG_FILE for one instance
+///
+/// A single file is used in place. Several files (one per assigned pad) are
+/// merged into `tmp_dir`, since SDL only reads one. Missing files are
+/// skipped with a warning. With `dry_run` the merged file's path is returned
+/// without writing it.
+pub fn prepare_mapping_file(
+    instance_idx: usize,
+    files: &[PathBuf],
+    dry_run: bool,
+    tmp_dir: &Path,
+) -> Option<PathBuf> {
+    let existing: Vec<&PathBuf> = files
+        .iter()
+        .filter(|f| {
+            let found = f.is_file();
+            if !found {
+                log_warn!("Instance {}: controller mapping {} not found", instance_idx, f.display());
+            }
+            found
+        })
+        .collect();
+
+    let path = tmp_dir.join(format!("gamecontrollerdb-{}.txt", instance_idx));
+    match existing.as_slice() {
+        [] => None,
+        [single] => Some((*single).clone()),
+        _ if dry_run => Some(path),
+        many => {
+            let mut merged = String::new();
+            for file in many {
+                match std::fs::read_to_string(file) {
+                    Ok(contents) => {
+                        merged.push_str(&contents);
+                        if !merged.ends_with('\n') {
+                            merged.push('\n');
+                        }
+                    }
+                    Err(e) => log_warn!("Couldn't read controller mapping {}: {}", file.display(), e),
+                }
+            }
+            match std::fs::create_dir_all(tmp_dir).and_then(|_| std::fs::write(&path, merged)) {
+                Ok(()) => Some(path),
+                Err(e) => {
+                    log_warn!("Couldn't write merged controller mappings {}: {}", path.display(), e);
+                    None
+                }
+            }
+        }
+    }
+}
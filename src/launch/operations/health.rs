@@ -0,0 +1,157 @@
+//! Post-launch health monitoring (I/O: reads child stderr, polls processes)
+
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::process::ExitStatusExt;
+use std::process::Child;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use super::super::pure::health::{early_exit_report, push_tail_line};
+use super::super::types::{
+    KillSwitch, SessionStatus, CRASH_WINDOW_SECS, KILL_GRACE_SECS, STDERR_TAIL_LINES,
+};
+use crate::util::msg;
+
+/// A spawned game instance being watched for early crashes
+pub struct MonitoredInstance {
+    index: usize,
+    child: Child,
+    started: Instant,
+    stderr_tail: Arc<Mutex<VecDeque<String>>>,
+}
+
+impl MonitoredInstance {
+    /// Start watching a child, keeping the tail of its stderr if it was piped
+    ///
+    /// Piped stderr is still echoed to splitux's own stderr.
+    pub fn new(index: usize, mut child: Child) -> Self {
+        let stderr_tail = Arc::new(Mutex::new(VecDeque::new()));
+
+        if let Some(stderr) = child.stderr.take() {
+            let tail = Arc::clone(&stderr_tail);
+            std::thread::spawn(move || {
+                for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                    let _ = writeln!(std::io::stderr(), "{}", line);
+                    if let Ok(mut tail) = tail.lock() {
+                        push_tail_line(&mut tail, line, STDERR_TAIL_LINES);
+                    }
+                }
+            });
+        }
+
+        Self {
+            index,
+            child,
+            started: Instant::now(),
+            stderr_tail,
+        }
+    }
+
+    /// Stop the instance: SIGTERM, then SIGKILL if it outlives the grace period
+    pub fn terminate(&mut self) {
+        if let Ok(Some(_)) = self.child.try_wait() {
+            return;
+        }
+        let pid = self.child.id();
+        unsafe {
+            libc::kill(pid as i32, libc::SIGTERM);
+        }
+        let deadline = Instant::now() + Duration::from_secs(KILL_GRACE_SECS);
+        while Instant::now() < deadline {
+            if let Ok(Some(_)) = self.child.try_wait() {
+                log_info!("Instance {}: Terminated (pid {})", self.index, pid);
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(100));
+        }
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        log_info!("Instance {}: Killed (pid {})", self.index, pid);
+    }
+
+    fn stderr_tail(&self) -> Vec<String> {
+        self.stderr_tail
+            .lock()
+            .map(|tail| tail.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Wait for all instances to exit, reporting any that crash shortly after launch
+///
+/// Crash reports are shown in a dialog on a separate thread so the remaining
+/// instances keep being watched. When the kill switch is triggered, all
+/// remaining instances are terminated and no crash reports are shown.
+/// `on_poll` runs on every poll, told whether the summon combo was held since
+/// the last one. Exits are recorded in
+/// `status` for the session status socket. Returns how many instances
+/// exited with an error, however long they ran.
+pub fn wait_for_instances(
+    mut instances: Vec<MonitoredInstance>,
+    kill: &KillSwitch,
+    status: &Mutex<SessionStatus>,
+    mut on_poll: impl FnMut(bool),
+) -> Result<usize, Box<dyn std::error::Error>> {
+    const POLL_INTERVAL: Duration = Duration::from_millis(500);
+    let window = Duration::from_secs(CRASH_WINDOW_SECS);
+
+    let mark_exited = |index: usize| {
+        if let Ok(mut status) = status.lock()
+            && let Some(instance) = status.instances.get_mut(index)
+        {
+            instance.alive = false;
+        }
+    };
+
+    let mut failed = 0;
+    while !instances.is_empty() {
+        if kill.requested.load(Ordering::Relaxed) {
+            log_info!("Kill requested, terminating {} instance(s)", instances.len());
+            for instance in &mut instances {
+                instance.terminate();
+                mark_exited(instance.index);
+            }
+            return Ok(failed);
+        }
+        on_poll(kill.summon.swap(false, Ordering::Relaxed));
+
+        let mut still_running = Vec::with_capacity(instances.len());
+
+        for mut instance in instances {
+            let Some(status) = instance.child.try_wait()? else {
+                still_running.push(instance);
+                continue;
+            };
+
+            log_info!("Instance {}: exited ({})", instance.index, status);
+            mark_exited(instance.index);
+            if !status.success() {
+                failed += 1;
+            }
+
+            // Give the stderr reader a moment to drain the last lines
+            std::thread::sleep(Duration::from_millis(100));
+            let report = early_exit_report(
+                instance.index,
+                status.code(),
+                status.signal(),
+                instance.started.elapsed(),
+                window,
+                &instance.stderr_tail(),
+            );
+            if let Some(report) = report {
+                log_info!("Instance {}: crashed on startup\n{}", instance.index, report);
+                std::thread::spawn(move || msg("Instance crashed", &report));
+            }
+        }
+
+        instances = still_running;
+        if !instances.is_empty() {
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    Ok(failed)
+}
@@ -0,0 +1,46 @@
+//! Handler hook script operations
+
+use std::process::Command;
+
+use super::super::pure::{hook_env, resolve_hook_path};
+use crate::handler::Handler;
+use crate::instance::Instance;
+
+/// Run one of the handler's hook scripts, blocking until it exits
+///
+/// The script runs in the handler folder with the game root and profile
+/// list in its environment. Its output goes to the log; a nonzero exit
+/// code is returned as an error along with the end of its stderr.
+pub fn run_hook(h: &Handler, label: &str, script: &str, instances: &[Instance]) -> Result<(), String> {
+    let path = resolve_hook_path(&h.path_handler, script)?;
+    let game_root = h.get_game_rootpath().map_err(|e| e.to_string())?;
+    let profiles: Vec<String> = instances.iter().map(|i| i.profname.clone()).collect();
+
+    log_info!("Running {} hook {}", label, path.display());
+    let output = Command::new(&path)
+        .current_dir(&h.path_handler)
+        .envs(hook_env(&game_root, &profiles, &h.path_handler))
+        .output()
+        .map_err(|e| format!("Couldn't run {}: {}", path.display(), e))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    for line in stdout.lines().chain(stderr.lines()) {
+        log_info!("[{} hook] {}", label, line);
+    }
+
+    if output.status.success() {
+        return Ok(());
+    }
+    let code = match output.status.code() {
+        Some(code) => format!("exit code {}", code),
+        None => "a signal".to_string(),
+    };
+    let tail: Vec<&str> = stderr.lines().rev().take(5).collect();
+    let tail: Vec<&str> = tail.into_iter().rev().collect();
+    if tail.is_empty() {
+        Err(format!("{} stopped with {}", script, code))
+    } else {
+        Err(format!("{} stopped with {}:\n{}", script, code, tail.join("\n")))
+    }
+}
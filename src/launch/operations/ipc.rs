@@ -0,0 +1,103 @@
+//! Session status socket (I/O: Unix domain socket server)
+
+use std::io::{BufRead, BufReader, ErrorKind, Write};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use super::super::pure::ipc::handle_request;
+use super::super::types::SessionStatus;
+
+/// Serves the session status over a Unix socket until shut down
+pub struct IpcServer {
+    path: PathBuf,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl IpcServer {
+    /// Bind the socket (replacing a stale one) and start answering requests
+    pub fn start(path: &Path, status: Arc<Mutex<SessionStatus>>) -> std::io::Result<Self> {
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+        let listener = UnixListener::bind(path)?;
+        listener.set_nonblocking(true)?;
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = Arc::clone(&stop);
+        let thread = std::thread::spawn(move || {
+            while !thread_stop.load(Ordering::Relaxed) {
+                match listener.accept() {
+                    Ok((stream, _)) => {
+                        let status = Arc::clone(&status);
+                        let stop = Arc::clone(&thread_stop);
+                        std::thread::spawn(move || serve_client(stream, &status, &stop));
+                    }
+                    Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                        std::thread::sleep(Duration::from_millis(100));
+                    }
+                    Err(e) => {
+                        log_error!("IPC: accept failed: {}", e);
+                        std::thread::sleep(Duration::from_millis(100));
+                    }
+                }
+            }
+        });
+
+        log_info!("IPC: session status socket at {}", path.display());
+        Ok(Self {
+            path: path.to_path_buf(),
+            stop,
+            thread: Some(thread),
+        })
+    }
+
+    /// Stop accepting connections and unlink the socket
+    pub fn shutdown(mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+        if let Err(e) = std::fs::remove_file(&self.path) {
+            log_error!("IPC: failed to remove {}: {}", self.path.display(), e);
+        }
+    }
+}
+
+/// Answer line-based requests until the client disconnects or the server stops
+fn serve_client(stream: UnixStream, status: &Mutex<SessionStatus>, stop: &AtomicBool) {
+    // Accepted sockets inherit non-blocking mode from the listener; use a
+    // read timeout instead so the stop flag is still noticed
+    if stream.set_nonblocking(false).is_err()
+        || stream.set_read_timeout(Some(Duration::from_millis(500))).is_err()
+    {
+        return;
+    }
+    let Ok(mut writer) = stream.try_clone() else {
+        return;
+    };
+
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    while !stop.load(Ordering::Relaxed) {
+        match reader.read_line(&mut line) {
+            Ok(0) => break,
+            Ok(_) => {
+                let reply = match status.lock() {
+                    Ok(status) => handle_request(&line, &status),
+                    Err(_) => r#"{"error":"session status unavailable"}"#.to_string(),
+                };
+                line.clear();
+                if writeln!(writer, "{}", reply).is_err() {
+                    break;
+                }
+            }
+            Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+            Err(_) => break,
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Kill-session and summon combo watcher (I/O: reads controller key state)
+
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use evdev::{Device, KeyCode};
+
+use super::super::pure::kill_combo::{combo_pressed, ComboHold};
+use super::super::types::KillSwitch;
+use crate::config::{ComboButton, KillCombo};
+
+fn combo_key(button: ComboButton) -> KeyCode {
+    match button {
+        ComboButton::Start => KeyCode::BTN_START,
+        ComboButton::Select => KeyCode::BTN_SELECT,
+        ComboButton::Guide => KeyCode::BTN_MODE,
+        ComboButton::LB => KeyCode::BTN_TL,
+        ComboButton::RB => KeyCode::BTN_TR,
+        ComboButton::LeftStick => KeyCode::BTN_THUMBL,
+        ComboButton::RightStick => KeyCode::BTN_THUMBR,
+        ComboButton::A => KeyCode::BTN_SOUTH,
+        ComboButton::B => KeyCode::BTN_EAST,
+        ComboButton::X => KeyCode::BTN_NORTH,
+        ComboButton::Y => KeyCode::BTN_WEST,
+    }
+}
+
+/// Watch the given gamepads for the kill and summon combos on a background thread
+///
+/// Reads the kernel's key state instead of consuming events, so it works
+/// alongside games and gptokeyb (even when they grab the device). Sets
+/// `switch.requested` when the kill combo is held on one controller and
+/// `switch.summon` while the summon combo is, and stops once
+/// `switch.finished` is set.
+pub fn spawn_kill_combo_watcher(
+    kill: &KillCombo,
+    summon: &KillCombo,
+    gamepad_paths: &[String],
+    switch: Arc<KillSwitch>,
+) {
+    let active = |combo: &KillCombo| combo.enabled && !combo.buttons.is_empty();
+    if !(active(kill) || active(summon)) || gamepad_paths.is_empty() {
+        return;
+    }
+
+    const POLL_INTERVAL: Duration = Duration::from_millis(100);
+    // An empty button list never matches, so a disabled combo is never detected
+    let watched: [(Vec<ComboButton>, Duration); 2] = [kill, summon].map(|combo| {
+        let buttons = if active(combo) { combo.buttons.clone() } else { Vec::new() };
+        (buttons, Duration::from_secs_f32(combo.hold_secs.max(0.0)))
+    });
+
+    let mut pads: Vec<(Device, [ComboHold; 2])> = gamepad_paths
+        .iter()
+        .filter_map(|path| match Device::open(path) {
+            Ok(dev) => Some((dev, Default::default())),
+            Err(e) => {
+                log_info!("Kill combo: Can't watch {}: {}", path, e);
+                None
+            }
+        })
+        .collect();
+    if pads.is_empty() {
+        return;
+    }
+
+    for ((buttons, hold), what) in watched.iter().zip(["end the session", "summon the launcher"]) {
+        if !buttons.is_empty() {
+            let names: Vec<&str> = buttons.iter().map(|b| b.label()).collect();
+            log_info!(
+                "Kill combo: hold {} for {:.1}s to {}",
+                names.join("+"),
+                hold.as_secs_f32(),
+                what
+            );
+        }
+    }
+
+    std::thread::spawn(move || {
+        while !switch.finished.load(Ordering::Relaxed) {
+            let now = Instant::now();
+            for (dev, trackers) in &mut pads {
+                let Ok(state) = dev.get_key_state() else {
+                    continue;
+                };
+                let held: Vec<ComboButton> = ComboButton::ALL
+                    .into_iter()
+                    .filter(|&b| state.contains(combo_key(b)))
+                    .collect();
+                let [(kill_buttons, kill_hold), (summon_buttons, summon_hold)] = &watched;
+                if trackers[0].update(combo_pressed(&held, kill_buttons), now, *kill_hold) {
+                    log_info!(
+                        "Kill combo: held on {}, ending session",
+                        dev.name().unwrap_or("controller")
+                    );
+                    switch.requested.store(true, Ordering::Relaxed);
+                    return;
+                }
+                if trackers[1].update(combo_pressed(&held, summon_buttons), now, *summon_hold) {
+                    switch.summon.store(true, Ordering::Relaxed);
+                }
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    });
+}
@@ -6,26 +6,66 @@ use std::process::Command;
 use crate::game_patches;
 use crate::handler::Handler;
 use crate::instance::Instance;
-use crate::paths::PATH_PARTY;
+use crate::paths::{PATH_PARTY, on_different_filesystem};
+use crate::profiles::OVERLAY_WORK_DIR;
+use crate::save_sync::pure::expand_path;
+use crate::util::run_per_instance;
+
+use super::super::pure::{lowerdir_stack, OverlayLayers};
+
+/// What a handler loses when its game folder isn't mounted with fuse-overlayfs
+///
+/// Backends, the handler overlay, game patches and user mods are all layered
+/// in as overlays. Unsaved handlers never mount, so nothing is listed for them.
+pub fn overlay_only_features(h: &Handler) -> Vec<String> {
+    if !h.is_saved_handler() {
+        return Vec::new();
+    }
+    let mut features = crate::backend::overlay_backend_names(h);
+    if h.path_handler.join("overlay").exists() {
+        features.push("handler overlay".to_string());
+    }
+    if !h.game_patches.is_empty() {
+        features.push("game patches".to_string());
+    }
+    if h.user_mods_dir.is_some() {
+        features.push("user mods".to_string());
+    }
+    features
+}
 
 /// Mount game directories with fuse-overlayfs
 ///
 /// Creates overlay mounts for each instance with:
-/// 1. Game patches overlay (if defined) - YAML-defined config file modifications
-/// 2. Backend overlay (if enabled) - Goldberg DLLs or BepInEx files
-/// 3. Handler overlay (if exists) - binary files from required_mods
-/// 4. Base game directory - read-only game files
-/// 5. Upper dir - per-profile save data (read-write)
+/// 1. User mods (if user_mods_dir is set) - applied last, highest priority
+/// 2. Game patches overlay (if defined) - YAML-defined config file modifications
+/// 3. Backend overlay (if enabled) - Goldberg DLLs or BepInEx files
+/// 4. Handler overlay (if exists) - binary files from required_mods
+/// 5. Base game directory - read-only game files
+/// 6. Upper dir - per-profile save data (read-write)
+///
+/// Game patches are applied once up front; with `parallel`, the per-instance
+/// mounts then run concurrently since each uses its own mount and work dirs.
+/// Mount points and patched files go in the session's scratch dir `tmp_dir`.
 pub fn fuse_overlayfs_mount_gamedirs(
     h: &Handler,
-    instances: &Vec<Instance>,
+    instances: &[Instance],
     backend_overlays: &[Vec<PathBuf>],
+    parallel: bool,
+    tmp_dir: &Path,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let tmp_dir = PATH_PARTY.join("tmp");
     let game_root = h.get_game_rootpath()?;
     let game_root_path = Path::new(&game_root);
     let gamename = h.handler_dir_name().to_string();
 
+    if on_different_filesystem(tmp_dir, game_root_path) {
+        log_warn!(
+            "Scratch directory {} is on a different filesystem than {}; overlay mounts may be slower",
+            tmp_dir.display(),
+            game_root
+        );
+    }
+
     // Apply game patches if defined (creates patched files in temp overlay)
     let patches_overlay = if !h.game_patches.is_empty() {
         let patches_dir = tmp_dir.join("game-patches");
@@ -43,37 +83,46 @@ pub fn fuse_overlayfs_mount_gamedirs(
         None
     };
 
-    for (i, instance) in instances.iter().enumerate() {
-        // Build lowerdir stack (leftmost has highest priority)
-        let mut lowerdir_parts: Vec<String> = Vec::new();
-
-        // 1. Game patches overlay first (highest priority)
-        if let Some(ref patches_dir) = patches_overlay {
-            lowerdir_parts.push(patches_dir.display().to_string());
-        }
-
-        // 2. Backend overlays (Goldberg DLLs, BepInEx files, etc.)
-        if let Some(overlays) = backend_overlays.get(i) {
-            for overlay in overlays {
-                lowerdir_parts.push(overlay.display().to_string());
+    // User-managed mod folder, overlaid above everything else
+    let user_mods_dir = match &h.user_mods_dir {
+        Some(dir) => {
+            let path = expand_path(dir);
+            if path.is_dir() {
+                log_info!("Overlaying user mods from {}", path.display());
+                Some(path)
+            } else {
+                log_warn!("user_mods_dir {} does not exist, skipping", path.display());
+                None
             }
         }
+        None => None,
+    };
 
-        // 3. Handler overlay for required_mods binary files (if exists)
+    run_per_instance(instances.len(), parallel, |i| {
+        let instance = &instances[i];
+        // Build lowerdir stack (leftmost has highest priority)
         let handler_overlay = h.path_handler.join("overlay");
-        if handler_overlay.exists() {
-            lowerdir_parts.push(handler_overlay.display().to_string());
-        }
-
-        // 4. Base game directory (lowest priority)
-        lowerdir_parts.push(game_root.clone());
-
-        let path_lowerdir = lowerdir_parts.join(":");
+        let path_lowerdir = lowerdir_stack(&OverlayLayers {
+            user_mods: user_mods_dir.as_deref(),
+            patches: patches_overlay.as_deref(),
+            backends: backend_overlays.get(i).map(|v| v.as_slice()).unwrap_or(&[]),
+            handler_overlay: handler_overlay.exists().then_some(handler_overlay.as_path()),
+            game_root: &game_root,
+        });
 
         let path_game_mnt = tmp_dir.join(format!("game-{}", i));
-        let path_workdir = tmp_dir.join(format!("work-{}", i));
         let path_prof = PATH_PARTY.join("profiles").join(&instance.profname);
         let path_upperdir = path_prof.join("gamesaves").join(&gamename);
+        // fuse-overlayfs renames from the work dir into the upper dir, so
+        // both must share a filesystem; keep it in the profile otherwise
+        let path_workdir = if on_different_filesystem(tmp_dir, &path_prof) {
+            let workdir = path_prof.join(OVERLAY_WORK_DIR);
+            // Not cleared with the scratch dir, so drop a previous session's
+            let _ = std::fs::remove_dir_all(&workdir);
+            workdir
+        } else {
+            tmp_dir.join(format!("work-{}", i))
+        };
 
         std::fs::create_dir_all(&path_game_mnt)?;
         std::fs::create_dir_all(&path_workdir)?;
@@ -87,8 +136,8 @@ pub fn fuse_overlayfs_mount_gamedirs(
         cmd.arg(format!("workdir={}", path_workdir.display()));
         cmd.arg(&path_game_mnt);
 
-        println!(
-            "[splitux] Mounting overlay for instance {}: lowerdir={}",
+        log_info!(
+            "Mounting overlay for instance {}: lowerdir={}",
             i, path_lowerdir
         );
 
@@ -98,7 +147,8 @@ pub fn fuse_overlayfs_mount_gamedirs(
         if !status.success() {
             return Err("fuse-overlayfs mount failed.".into());
         }
-    }
+        Ok(())
+    })?;
 
     Ok(())
 }
@@ -1,27 +1,55 @@
 //! Profile setup operations
 
+use crate::app::SplituxConfig;
 use crate::handler::Handler;
 use crate::instance::Instance;
 use crate::profiles::{create_profile, create_profile_gamesave};
+use crate::proton;
+use crate::util::run_per_instance;
 
 /// Setup profiles for all instances
+///
+/// With `parallel`, each instance's profile is created on its own thread.
+/// Instances sharing a named profile leave it to the first of them, so no
+/// two workers write the same files.
 pub fn setup_profiles(
     h: &Handler,
-    instances: &Vec<Instance>,
+    instances: &[Instance],
+    parallel: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    println!("\n[splitux] Instances:");
-    for instance in instances {
-        if instance.profname.starts_with(".") {
-            create_profile(&instance.profname)?;
+    log_info!("Instances:");
+    run_per_instance(instances.len(), parallel, |i| {
+        let instance = &instances[i];
+        let shared = instances[..i].iter().any(|other| other.profname == instance.profname);
+        if !shared {
+            if instance.profname.starts_with(".") {
+                create_profile(&instance.profname)?;
+            }
+            if h.is_saved_handler() {
+                create_profile_gamesave(&instance.profname, h)?;
+            }
         }
-        if h.is_saved_handler() {
-            create_profile_gamesave(&instance.profname, h)?;
-        }
-        println!(
-            "[splitux] - Profile: {}, Monitor: {}, Resolution: {}x{}",
+        log_info!(
+            "- Profile: {}, Monitor: {}, Resolution: {}x{}",
             instance.profname, instance.monitor, instance.width, instance.height
         );
-    }
+        Ok(())
+    })?;
+
+    Ok(())
+}
 
+/// Create the Proton prefixes of all instances that don't have one yet, at
+/// the same time
+///
+/// With a single shared prefix only the first instance builds it. Only used
+/// with `parallel_prepare`.
+pub fn setup_prefixes(
+    h: &Handler,
+    instances: &[Instance],
+    cfg: &SplituxConfig,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let count = if cfg.proton_separate_pfxs { instances.len() } else { instances.len().min(1) };
+    run_per_instance(count, true, |i| proton::init_prefix(h, cfg, i))?;
     Ok(())
 }
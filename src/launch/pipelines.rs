@@ -2,5 +2,9 @@
 
 pub mod build_cmds;
 pub mod execute;
+pub mod preview;
+pub mod run;
+pub mod session;
 
-pub use execute::launch_game;
+pub use preview::launch_command_line;
+pub use run::run_session;
@@ -8,6 +8,7 @@ use crate::backend;
 use crate::backend::facepunch::get_linux_bepinex_env;
 use crate::backend::photon::generate_all_configs as photon_generate_configs;
 use crate::bwrap;
+use crate::capabilities::capabilities;
 use crate::gamescope;
 use crate::handler::{Handler, SDL2Override};
 use crate::input::DeviceInfo;
@@ -15,31 +16,47 @@ use crate::instance::Instance;
 use crate::monitor::Monitor;
 use crate::paths::{PATH_PARTY, PATH_STEAM};
 use crate::proton;
+use crate::save_sync::pure::{expand_path, save_subpath_in_game_root};
 use crate::util::*;
 
-use super::super::operations::fuse_overlayfs_mount_gamedirs;
-use super::super::pure::validate_runtime;
+use super::super::operations::{
+    fuse_overlayfs_mount_gamedirs, overlay_only_features, prepare_mapping_file,
+};
+use super::super::pure::{
+    expand_player, instance_mapping_files, profile_home_env, split_args, steam_client_env_scrub,
+    validate_runtime,
+};
 use super::super::types::SDL_GAMECONTROLLER_IGNORE_DEVICES;
 
+/// Session resources the launch commands refer to
+pub struct LaunchResources<'a> {
+    /// PULSE_SINK value per instance; an empty string means no audio routing
+    pub audio_sink_envs: &'a [String],
+    /// Each instance's virtual keyboard/mouse device created by gptokeyb
+    /// (None if gptokeyb is not used)
+    pub gptokeyb_virtual_devices: &'a [Option<PathBuf>],
+    /// Build the commands without writing or mounting anything
+    pub dry_run: bool,
+}
+
 /// Build launch commands for all instances
 ///
-/// The `audio_sink_envs` parameter is a list of PULSE_SINK values per instance.
-/// Empty string means no audio routing for that instance.
-///
-/// The `gptokeyb_virtual_devices` parameter contains the path to each instance's
-/// virtual keyboard/mouse device created by gptokeyb (None if gptokeyb not used).
 /// Returns Vec of (Command, bwrap_arg_count) where bwrap_arg_count is the
 /// number of args before the child command. Device blocking args are inserted
 /// at this position at spawn time for fresh permission checks.
+///
+/// With `dry_run`, nothing is written or mounted: the commands are the same,
+/// but the backend overlays, Photon configs, game directory mounts and
+/// profile directories they refer to aren't created.
 pub fn launch_cmds(
     h: &Handler,
     input_devices: &[DeviceInfo],
-    instances: &Vec<Instance>,
+    instances: &[Instance],
     monitors: &[Monitor],
     cfg: &SplituxConfig,
-    audio_sink_envs: &[String],
-    gptokeyb_virtual_devices: &[Option<PathBuf>],
+    resources: LaunchResources,
 ) -> Result<Vec<(std::process::Command, usize)>, Box<dyn std::error::Error>> {
+    let LaunchResources { audio_sink_envs, gptokeyb_virtual_devices, dry_run } = resources;
     let win = h.win();
     let exec = Path::new(&h.exec);
     let runtime = h.runtime.as_str();
@@ -47,28 +64,63 @@ pub fn launch_cmds(
     // Validate Steam Runtime if needed
     validate_runtime(runtime)?;
 
+    let caps = capabilities();
+    if !caps.bwrap {
+        return Err("bwrap (bubblewrap) is not installed; install it through your distro's package manager.".into());
+    }
+    if !h.no_gamescope && !caps.gamescope_for(cfg.input_holding) {
+        return Err(if cfg.input_holding {
+            "gamescope-splitux was not found; reinstall Splitux or turn off input holding.".into()
+        } else {
+            "gamescope is not installed; install it through your distro's package manager.".into()
+        });
+    }
+
+    // Without fuse-overlayfs, run from the game folder as if mounts were
+    // disabled, unless the handler relies on something only overlays provide
+    let mount_gamedirs = h.is_saved_handler() && !cfg.disable_mount_gamedirs;
+    let mount_gamedirs = if mount_gamedirs && !caps.overlay_mounts() {
+        let needs_overlay = overlay_only_features(h);
+        if !needs_overlay.is_empty() {
+            return Err(format!(
+                "fuse-overlayfs is unavailable, and this handler needs it for: {}. Install fuse-overlayfs through your distro's package manager.",
+                needs_overlay.join(", ")
+            )
+            .into());
+        }
+        log_warn!("fuse-overlayfs is unavailable; running instances from the original game directory");
+        false
+    } else {
+        mount_gamedirs
+    };
+
+    let tmp_dir = cfg.scratch_dir();
+
     // Create backend overlays if needed (before mounting game dirs)
-    let backend_overlays = if h.is_saved_handler() {
-        backend::create_backend_overlays(h, instances, win)?
+    let backend_overlays = if h.is_saved_handler() && !dry_run {
+        backend::create_backend_overlays(h, instances, win, &tmp_dir)?
     } else {
         vec![]
     };
 
     // Generate Photon configs at launch time (needs instance count)
-    if h.has_photon() && h.is_saved_handler() {
-        photon_generate_configs(h, instances)?;
+    if h.has_photon() && h.is_saved_handler() && !dry_run {
+        photon_generate_configs(h, instances, &tmp_dir)?;
     }
 
     // Mount game directories with overlays
-    if h.is_saved_handler() && !cfg.disable_mount_gamedirs {
-        fuse_overlayfs_mount_gamedirs(h, instances, &backend_overlays)?;
+    if mount_gamedirs && !dry_run {
+        fuse_overlayfs_mount_gamedirs(h, instances, &backend_overlays, cfg.parallel_prepare, &tmp_dir)?;
     }
 
     let mut cmds: Vec<(Command, usize)> = Vec::new();
 
     for (i, instance) in instances.iter().enumerate() {
-        let gamedir = if h.is_saved_handler() && !cfg.disable_mount_gamedirs {
-            PATH_PARTY.join("tmp").join(format!("game-{}", i))
+        // A dry run uses a running session's mount if there is one, and the
+        // game root otherwise, so the command works when run by hand
+        let mounted = tmp_dir.join(format!("game-{}", i));
+        let gamedir = if mount_gamedirs && (!dry_run || is_mount_point(&mounted).unwrap_or(false)) {
+            mounted
         } else {
             PathBuf::from(h.get_game_rootpath()?)
         };
@@ -78,15 +130,37 @@ pub fn launch_cmds(
         }
 
         let path_exec = gamedir.join(exec);
-        let cwd = path_exec.parent().ok_or_else(|| "couldn't get parent")?;
+        let cwd = if h.working_dir.is_empty() {
+            path_exec.parent().ok_or("couldn't get parent")?.to_path_buf()
+        } else {
+            let cwd = gamedir.join(&h.working_dir);
+            if !cwd.is_dir() {
+                return Err(format!("Working directory not found: {}", cwd.display()).into());
+            }
+            cwd
+        };
         let path_prof = PATH_PARTY.join("profiles").join(&instance.profname);
 
-        // 1. Create gamescope command
-        let mut cmd = gamescope::create_command(cfg);
-        cmd.current_dir(cwd);
+        // 1. Create gamescope command. Without gamescope, `env` takes its place
+        // as a plain launcher so the rest of the command stays the same.
+        let mut cmd = match h.no_gamescope {
+            true => Command::new("env"),
+            false => gamescope::create_command(cfg),
+        };
+        cmd.current_dir(&cwd);
 
         // 2. Set up gamescope environment
-        gamescope::setup_env(&mut cmd);
+        if h.no_gamescope {
+            if i == 0 {
+                log_info!("no_gamescope: instances run directly under the window manager");
+            }
+            // The launcher's own SDL_VIDEODRIVER=x11 shouldn't reach the game
+            if is_wayland_session() {
+                cmd.env_remove("SDL_VIDEODRIVER");
+            }
+        } else {
+            gamescope::setup_env(&mut cmd);
+        }
 
         // Proton debug logging
         cmd.env("PROTON_LOG", "1");
@@ -111,8 +185,15 @@ pub fn launch_cmds(
 
             // BepInEx doorstop requires native winhttp.dll override
             // Without this, Wine uses its builtin and BepInEx never loads
+            let mut required = Vec::new();
             if h.has_photon() || h.has_facepunch() || h.has_goldberg_plugin() || h.has_standalone() {
-                cmd.env("WINEDLLOVERRIDES", "winhttp=n,b");
+                required.push(("winhttp", "n,b"));
+            }
+            if let Some(overrides) = proton::merge_dll_overrides(&required, &h.wine_dll_overrides) {
+                if i == 0 {
+                    log_info!("WINEDLLOVERRIDES={}", overrides);
+                }
+                cmd.env("WINEDLLOVERRIDES", overrides);
             }
         }
 
@@ -124,20 +205,52 @@ pub fn launch_cmds(
             cmd.env("SDL_GAMECONTROLLER_IGNORE_DEVICES", SDL_GAMECONTROLLER_IGNORE_DEVICES);
         }
 
+        // Per-device SDL controller mappings for misdetected pads
+        let mapping_files = instance_mapping_files(input_devices, &instance.devices, &cfg.controller_mappings);
+        if let Some(mapping) = prepare_mapping_file(i, &mapping_files, dry_run, &tmp_dir) {
+            log_info!("Instance {}: SDL_GAMECONTROLLERCONFIG_FILE={}", i, mapping.display());
+            cmd.env("SDL_GAMECONTROLLERCONFIG_FILE", mapping);
+        }
+
         // Handler custom environment variables
-        if !h.env.is_empty() {
-            for env_var in h.env.split_whitespace() {
-                if let Some((key, value)) = env_var.split_once('=') {
-                    cmd.env(key, value);
+        for (key, value) in h.env_vars() {
+            cmd.env(key, value);
+        }
+
+        // Keep the Steam overlay and Steam Input away from native games.
+        // Applied to gamescope's environment so it is inherited by bwrap and the game.
+        if !win && (h.disable_steam_overlay || cfg.disable_steam_overlay) {
+            let ld_preload = std::env::var("LD_PRELOAD").ok();
+            for (key, value) in steam_client_env_scrub(ld_preload.as_deref()) {
+                match value {
+                    Some(v) => {
+                        cmd.env(key, v);
+                    }
+                    None => {
+                        cmd.env_remove(key);
+                    }
                 }
             }
+            if i == 0 {
+                log_info!("Steam overlay and Steam Input disabled for native instances");
+            }
         }
 
         // 3. Add gamescope arguments
-        gamescope::add_args(&mut cmd, instance, monitors, cfg);
-        let virtual_device = gptokeyb_virtual_devices.get(i).and_then(|v| v.as_ref());
-        gamescope::add_input_holding_args(&mut cmd, virtual_device.map(|p| p.as_path()), cfg);
-        gamescope::add_separator(&mut cmd);
+        if !h.no_gamescope {
+            gamescope::add_args(&mut cmd, instance, monitors, cfg);
+            let virtual_device = gptokeyb_virtual_devices.get(i).and_then(|v| v.as_ref());
+            gamescope::add_input_holding_args(
+                &mut cmd,
+                input_devices,
+                instance,
+                virtual_device.map(|p| p.as_path()),
+                cfg,
+            );
+            gamescope::add_separator(&mut cmd);
+        } else if i == 0 && cfg.input_holding {
+            log_warn!("Input holding needs gamescope; ignored with no_gamescope");
+        }
 
         // 4. Add bwrap container (unless disabled)
         if !h.disable_bwrap {
@@ -146,7 +259,7 @@ pub fn launch_cmds(
             // Get gamepad paths for this instance
             let gamepad_paths = bwrap::get_assigned_gamepad_paths(input_devices, &instance.devices);
             if !gamepad_paths.is_empty() {
-                println!("[splitux] Instance {}: SDL_JOYSTICK_DEVICE={}", i, gamepad_paths.join(","));
+                log_info!("Instance {}: SDL_JOYSTICK_DEVICE={}", i, gamepad_paths.join(","));
             }
 
             // Set up SDL environment inside container
@@ -158,8 +271,11 @@ pub fn launch_cmds(
             // Set up audio routing inside container
             if let Some(sink_name) = audio_sink_envs.get(i) {
                 if !sink_name.is_empty() {
-                    bwrap::setup_audio_env(&mut cmd, sink_name);
-                    println!("[splitux] Instance {}: PULSE_SINK={}", i, sink_name);
+                    // Unassigned instances get a player sink when those are enabled
+                    let player_sink = cfg.audio.player_sinks
+                        && !cfg.audio.default_assignments.contains_key(&i);
+                    bwrap::setup_audio_env(&mut cmd, sink_name, player_sink);
+                    log_info!("Instance {}: PULSE_SINK={}", i, sink_name);
                 }
             }
 
@@ -171,7 +287,8 @@ pub fn launch_cmds(
                 }
             }
 
-            // Set Steam App ID for native Linux games (required for Steam API init)
+            // Set Steam App ID for native Linux games (required for Steam API init).
+            // Kept even with the overlay disabled; only the inherited Steam values are cleared.
             if !win {
                 if let Some(appid) = h.steam_appid {
                     cmd.args(["--setenv", "SteamAppId", &appid.to_string()]);
@@ -179,6 +296,11 @@ pub fn launch_cmds(
                 }
             }
 
+            // MangoHud for Proton games: MANGOHUD=1 enables its Vulkan layer for DXVK/VKD3D
+            if win && instance.mangohud {
+                cmd.args(["--setenv", "MANGOHUD", "1"]);
+            }
+
             // Log assigned devices and block unassigned devices
             if !h.disable_input_isolation {
                 bwrap::log_assigned_devices(&mut cmd, input_devices, &instance.devices, i);
@@ -196,27 +318,91 @@ pub fn launch_cmds(
             }
 
             // 6. Game null paths (disable specific game features)
-            for subpath in &h.game_null_paths {
-                let game_subpath = gamedir.join(subpath);
-                if game_subpath.is_file() {
-                    cmd.args(["--bind", "/dev/null", &game_subpath.to_string_lossy()]);
-                } else if game_subpath.is_dir() {
-                    cmd.args([
-                        "--bind",
-                        &PATH_PARTY.join("tmp/null").to_string_lossy(),
-                        &game_subpath.to_string_lossy(),
-                    ]);
+            bwrap::add_null_path_binds(&mut cmd, &gamedir, &h.game_null_paths, i, &tmp_dir);
+
+            // 6a. Handler-declared extra binds
+            bwrap::add_extra_binds(&mut cmd, &h.extra_binds, i);
+
+            // 6b. Save directories bound straight to profile storage (bypass the overlay)
+            if h.save_dir_bind && !h.original_save_paths.is_empty() {
+                let game_root = PathBuf::from(h.get_game_rootpath()?);
+                for save_path in h.original_save_paths.iter().map(|p| expand_path(p)) {
+                    match save_subpath_in_game_root(&save_path, &game_root) {
+                        Some(subpath) => {
+                            // Same location the overlay upperdir uses, so save sync keeps working
+                            let path_saves = path_prof
+                                .join("gamesaves")
+                                .join(h.handler_dir_name())
+                                .join(&subpath);
+                            let game_subpath = gamedir.join(&subpath);
+                            if !dry_run {
+                                std::fs::create_dir_all(&path_saves)?;
+                                std::fs::create_dir_all(&game_subpath)?;
+                            }
+                            cmd.args([
+                                "--bind",
+                                &path_saves.to_string_lossy(),
+                                &game_subpath.to_string_lossy(),
+                            ]);
+                            log_info!(
+                                "Instance {}: binding saves {} -> {}",
+                                i,
+                                path_saves.display(),
+                                game_subpath.display()
+                            );
+                        }
+                        None => log_warn!(
+                            "save_dir_bind ignored, {} is not inside the game directory",
+                            save_path.display()
+                        ),
+                    }
                 }
             }
 
         } else {
-            println!("[splitux] Instance {}: bwrap disabled, skipping container", i);
+            log_info!("Instance {}: bwrap disabled, skipping container", i);
+
+            // Per-profile HOME via environment only (see Handler::isolate_home_without_bwrap)
+            if h.isolate_home_without_bwrap {
+                if win {
+                    log_warn!("isolate_home_without_bwrap only applies to native games");
+                } else {
+                    let path_prof_home = path_prof.join("home");
+                    cmd.arg("env");
+                    for (key, path) in profile_home_env(&path_prof_home) {
+                        if !dry_run {
+                            std::fs::create_dir_all(&path)?;
+                        }
+                        cmd.arg(format!("{}={}", key, path.display()));
+                    }
+                    log_info!(
+                        "Instance {}: HOME={} (no container)",
+                        i,
+                        path_prof_home.display()
+                    );
+                }
+            }
+
+            if h.save_dir_bind {
+                log_warn!("save_dir_bind requires bwrap, saves will use the overlay");
+            }
         }
 
         // Record arg count at end of bwrap section (before runtime/game args).
         // Device blocking args will be inserted at this position at spawn time.
         let bwrap_arg_count = cmd.get_args().count();
 
+        // MangoHud overlay (per instance). Native games go through the mangohud
+        // wrapper so OpenGL titles are covered; Proton only needs the env var.
+        if instance.mangohud {
+            if !win {
+                cmd.arg("mangohud");
+            } else if h.disable_bwrap {
+                cmd.args(["env", "MANGOHUD=1"]);
+            }
+            log_info!("Instance {}: MangoHud enabled", i);
+        }
+
         // 7. Runtime (Proton/Wine or Steam Runtime)
         if win {
             let proton_bin = proton::get_binary(h)?;
@@ -246,9 +432,15 @@ pub fn launch_cmds(
         // 8. Game executable
         cmd.arg(&path_exec);
 
-        // 9. Handler arguments with variable substitution
-        for arg in h.args.split_whitespace() {
-            let processed_arg = match arg {
+        // 9. Handler arguments, then this instance's own, with variable substitution.
+        // Only the per-instance arguments understand quotes; handler args keep
+        // their whitespace split so existing handlers launch unchanged
+        let instance_args = split_args(&instance.args_override)
+            .into_iter()
+            .map(|arg| expand_player(&arg, i + 1));
+        let handler_args = h.args.split_whitespace().map(String::from);
+        for arg in handler_args.chain(instance_args) {
+            let processed_arg = match arg.as_str() {
                 "$PROFILE" => &instance.profname,
                 "$WIDTH" => &instance.width.to_string(),
                 "$HEIGHT" => &instance.height.to_string(),
@@ -257,7 +449,7 @@ pub fn launch_cmds(
                 "$INSTANCENUM" => &i.to_string(),
                 "$GAMEDIR" => &gamedir.os_fmt(win),
                 "$HANDLERDIR" => &h.path_handler.os_fmt(win),
-                _ => &String::from(arg).sanitize_path(),
+                _ => &arg.sanitize_path(),
             };
             cmd.arg(processed_arg);
         }
@@ -1,37 +1,70 @@
 //! Game execution pipeline
 
 use std::process::Child;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex};
 
 use crate::app::{SplituxConfig, WindowManagerType};
 use crate::audio::{
-    resolve_audio_system, setup_audio_session, teardown_audio_session, AudioContext, AudioSystem,
-    VirtualSink,
+    resolve_audio_system, setup_audio_session, AudioContext, AudioSystem, VirtualSink,
 };
 use crate::bwrap;
 use crate::gptokeyb;
 use crate::handler::Handler;
-use crate::input::DeviceInfo;
+use crate::input::{DeviceInfo, DeviceType};
 use crate::instance::Instance;
 use crate::monitor::Monitor;
-use crate::wm::presets::{get_preset_by_id, get_presets_for_count};
+use crate::paths::PATH_PARTY;
 use crate::wm::{LayoutContext, WindowManager, WindowManagerBackend};
 
-use super::build_cmds::launch_cmds;
+use super::build_cmds::{launch_cmds, LaunchResources};
+use super::session::{teardown_session, Session};
+use super::super::operations::{
+    spawn_kill_combo_watcher, wait_for_instances, IpcServer, MonitoredInstance,
+};
 use super::super::pure::command::{format_launch_cmd, rebuild_command_with_blocking};
+use super::super::types::{
+    InstanceStatus, KillSwitch, SessionStatus, IPC_SOCKET_NAME,
+};
 
 /// Launch the game with all instances
+///
+/// Blocks until every instance has exited; returns how many exited with an error.
+/// The session ends early once `kill.requested` is set.
 pub fn launch_game(
     h: &Handler,
     input_devices: &[DeviceInfo],
-    instances: &Vec<Instance>,
+    instances: &[Instance],
     monitors: &[Monitor],
     cfg: &SplituxConfig,
-) -> Result<(), Box<dyn std::error::Error>> {
+    kill: &Arc<KillSwitch>,
+) -> Result<usize, Box<dyn std::error::Error>> {
+    // Create WM backend based on config
+    let wm = match &cfg.window_manager {
+        WindowManagerType::Auto => WindowManagerBackend::detect(),
+        WindowManagerType::KWin => WindowManagerBackend::KWin(crate::wm::KWinManager::new()),
+        WindowManagerType::Hyprland => {
+            WindowManagerBackend::Hyprland(crate::wm::HyprlandManager::new())
+        }
+        WindowManagerType::GamescopeOnly => {
+            WindowManagerBackend::GamescopeOnly(crate::wm::GamescopeOnlyManager::new())
+        }
+    };
+
+    // The KWin scripts only place gamescope windows, and without a WM nothing
+    // does, so no_gamescope windows would all pile up wherever the game opens them
+    if h.no_gamescope && matches!(wm, WindowManagerBackend::KWin(_)) {
+        return Err("This handler runs without gamescope, which KWin can't lay out yet. Turn off \"Run without gamescope\" in the handler, or use Hyprland or niri.".into());
+    }
+    if h.no_gamescope && matches!(wm, WindowManagerBackend::GamescopeOnly(_)) {
+        return Err("This handler runs without gamescope, which needs a window manager to lay out the windows. Turn off \"Run without gamescope\" in the handler, or use Hyprland or niri.".into());
+    }
+
     // Set up audio routing if enabled
     let (audio_system, virtual_sinks, audio_sink_envs) = setup_audio_routing(instances, cfg);
 
     // Set up gptokeyb daemons if enabled (spawns before command building so we can pass virtual device paths)
-    let (mut gptokeyb_handles, gptokeyb_virtual_devices) =
+    let (gptokeyb_handles, gptokeyb_virtual_devices) =
         setup_gptokeyb_daemons(h, input_devices, instances);
 
     let new_cmds = launch_cmds(
@@ -40,62 +73,68 @@ pub fn launch_game(
         instances,
         monitors,
         cfg,
-        &audio_sink_envs,
-        &gptokeyb_virtual_devices,
+        LaunchResources {
+            audio_sink_envs: &audio_sink_envs,
+            gptokeyb_virtual_devices: &gptokeyb_virtual_devices,
+            dry_run: false,
+        },
     )?;
 
-    // Create WM backend based on config
-    let mut wm = match &cfg.window_manager {
-        WindowManagerType::Auto => WindowManagerBackend::detect(),
-        WindowManagerType::KWin => WindowManagerBackend::KWin(crate::wm::KWinManager::new()),
-        WindowManagerType::Hyprland => {
-            WindowManagerBackend::Hyprland(crate::wm::HyprlandManager::new())
-        }
-        WindowManagerType::GamescopeOnly => {
-            WindowManagerBackend::GamescopeOnly(crate::wm::GamescopeOnlyManager::new())
-        }
-    };
-
     // Setup WM with layout context
     let player_count = instances.len();
     let preset_id = cfg.layout_presets.get_for_count(player_count);
 
-    println!("[splitux] Layout: preset_id from config = '{}'", preset_id);
+    log_info!("Layout: preset_id from config = '{}'", preset_id);
 
-    let preset_found = get_preset_by_id(preset_id);
-    let preset = preset_found
-        .or_else(|| {
-            println!("[splitux] Layout: preset '{}' not found, using fallback", preset_id);
-            get_presets_for_count(player_count).first().copied()
-        })
+    // instance_order[region] = instance_idx (which instance goes in which region)
+    let (preset, instance_order) = cfg
+        .layout_presets
+        .layout_for_count(player_count)
         .expect("No layout preset available");
 
-    println!("[splitux] Layout: using preset '{}' ({})", preset.id, preset.name);
-
-    // Get custom instance order (or default sequential)
-    // instance_order[region] = instance_idx (which instance goes in which region)
-    let instance_order = cfg.layout_presets.get_order(preset_id, player_count);
-    println!("[splitux] Layout: instance_order = {:?}", instance_order);
-
-    // Compute inverse mapping: instance_to_region[instance] = region
-    // This tells us which region each spawned window should go to
-    let mut instance_to_region = vec![0; player_count];
-    for (region, &instance_idx) in instance_order.iter().enumerate() {
-        if instance_idx < player_count {
-            instance_to_region[instance_idx] = region;
-        }
-    }
-    println!("[splitux] Layout: instance_to_region = {:?}", instance_to_region);
+    log_info!("Layout: using preset '{}' ({})", preset.id, preset.name);
+    log_info!("Layout: instance_order = {:?}", instance_order);
 
-    let ctx = LayoutContext {
+    let mut ctx = LayoutContext {
         instances: instances.to_vec(),
         monitors: monitors.to_vec(),
         preset,
-        instance_to_region,
+        presets: cfg.layout_presets.clone(),
+        pids: Vec::new(),
+        no_gamescope: h.no_gamescope,
     };
 
-    println!("[splitux] Setting up {} window manager", wm.name());
-    wm.setup(&ctx)?;
+    let mut session = Session {
+        wm,
+        gptokeyb_handles,
+        audio_system,
+        virtual_sinks,
+        ipc: None,
+    };
+
+    log_info!("Setting up {} window manager", session.wm.name());
+    session.wm.setup(&ctx)?;
+
+    // Expose session status to external tools (e.g. overlays) if enabled
+    let status = Arc::new(Mutex::new(session_status(h, input_devices, instances, monitors)));
+    if cfg.ipc_socket_enabled {
+        let path = PATH_PARTY.join(IPC_SOCKET_NAME);
+        match IpcServer::start(&path, Arc::clone(&status)) {
+            Ok(server) => session.ipc = Some(server),
+            Err(e) => log_error!("IPC: Failed to open {}: {}", path.display(), e),
+        }
+    }
+
+    // Watch the players' gamepads for the combos (also during the spawn delays)
+    let gamepad_paths: Vec<String> = instances
+        .iter()
+        .flat_map(|inst| inst.devices.iter())
+        .filter_map(|&d| input_devices.get(d))
+        .filter(|dev| dev.device_type == DeviceType::Gamepad)
+        .map(|dev| dev.path.clone())
+        .collect();
+    spawn_kill_combo_watcher(&cfg.kill_combo, &cfg.summon_combo, &gamepad_paths, Arc::clone(kill));
+    kill.started.store(true, Ordering::Relaxed);
 
     // Delay after each spawn for Vulkan/GPU initialization
     let vulkan_init_delay = 6.0;
@@ -103,7 +142,7 @@ pub fn launch_game(
     // Delay before each spawn for input/SDL initialization
     let input_init_delay = cfg.input_init_delay.unwrap_or(1.0);
 
-    let mut handles = Vec::new();
+    let mut handles: Vec<MonitoredInstance> = Vec::new();
 
     // For native Linux games with Facepunch/BepInEx, redirect stdout to prevent
     // CStreamWriter crash. BepInEx's LinuxConsoleDriver checks isatty(1) and crashes
@@ -111,10 +150,16 @@ pub fn launch_game(
     let redirect_stdout = !h.win() && h.has_facepunch();
 
     for (i, (cmd, bwrap_arg_count)) in new_cmds.into_iter().enumerate() {
+        if kill.requested.load(Ordering::Relaxed) {
+            log_info!("Kill requested, skipping remaining instances");
+            break;
+        }
+        crate::logging::set_player(Some(i + 1));
+
         // Input initialization delay before spawn (except first instance)
         if i > 0 && input_init_delay > 0.0 {
-            println!(
-                "[splitux] Input init delay: {}ms",
+            log_info!(
+                "Input init delay: {}ms",
                 (input_init_delay * 1000.0) as u32
             );
             std::thread::sleep(std::time::Duration::from_secs_f64(input_init_delay));
@@ -122,73 +167,160 @@ pub fn launch_game(
 
         // Build fresh device blocking args right before spawn (spawn-time permission check).
         // These must be inserted as bwrap args, before the child command (proton/game).
-        let blocking_args = if !h.disable_bwrap && !h.disable_input_isolation {
-            let initial_js_devices = bwrap::glob_js_devices();
-            let mut args = bwrap::get_js_blocking_args(&initial_js_devices, i);
-            args.extend(bwrap::get_evdev_hidraw_blocking_args(
-                input_devices,
-                &instances[i].devices,
-                i,
-            ));
-            args
-        } else {
-            Vec::new()
-        };
+        let blocking_args = device_blocking_args(h, input_devices, &instances[i], i);
 
         // Reconstruct command with blocking args inserted at the bwrap/child boundary
         let mut cmd = rebuild_command_with_blocking(cmd, bwrap_arg_count, &blocking_args);
 
-        // Print the final command (with blocking args)
-        print!("{}", format_launch_cmd(&cmd, i));
-        println!();
+        // Log the final command (with blocking args), hiding handler env values
+        let handler_env_keys: Vec<&str> = h.env_vars().map(|(key, _)| key).collect();
+        for line in format_launch_cmd(&cmd, i, &handler_env_keys).lines() {
+            log_info!("{}", line.trim_start_matches("[splitux] "));
+        }
 
         if redirect_stdout {
             cmd.stdout(std::process::Stdio::null());
             cmd.stderr(std::process::Stdio::null());
+        } else {
+            // Piped so crash reports can include the last lines of output
+            cmd.stderr(std::process::Stdio::piped());
         }
 
-        let handle = cmd.spawn()?;
-        handles.push(handle);
+        let handle = match cmd.spawn() {
+            Ok(handle) => handle,
+            Err(e) => {
+                // Don't leave the already running instances behind
+                for instance in &mut handles {
+                    instance.terminate();
+                }
+                kill.finished.store(true, Ordering::Relaxed);
+                teardown_session(session, false);
+                return Err(e.into());
+            }
+        };
+        if let Ok(mut status) = status.lock()
+            && let Some(instance) = status.instances.get_mut(i)
+        {
+            instance.pid = Some(handle.id());
+            instance.alive = true;
+        }
+        ctx.pids.push(handle.id());
+        handles.push(MonitoredInstance::new(i, handle));
 
         // Vulkan/GPU initialization delay after spawn (except last instance)
         if i < instances.len() - 1 {
-            println!(
-                "[splitux] Vulkan init delay: {}ms",
+            log_info!(
+                "Vulkan init delay: {}ms",
                 (vulkan_init_delay * 1000.0) as u32
             );
             std::thread::sleep(std::time::Duration::from_secs_f64(vulkan_init_delay));
         }
     }
+    crate::logging::set_player(None);
 
     // Notify WM that all instances have been launched (for positioning)
-    if !wm.is_reactive() {
-        println!("[splitux] Non-reactive WM, positioning windows explicitly");
-        wm.on_instances_launched(&ctx)?;
+    if !session.wm.is_reactive() && !kill.requested.load(Ordering::Relaxed) {
+        log_info!("Non-reactive WM, positioning windows explicitly");
+        if let Err(e) = session.wm.on_instances_launched(&ctx) {
+            log_warn!("Window positioning failed: {}", e);
+        }
     }
 
-    for mut handle in handles {
-        handle.wait()?;
+    // Keep the launcher reachable in case a game crashes or hangs, right
+    // away or once the summon combo is held
+    let mut launcher_above = false;
+    let mut keep_launcher_above = |wm: &mut WindowManagerBackend| {
+        if launcher_above {
+            return;
+        }
+        launcher_above = true;
+        if let Err(e) = wm.keep_above(std::process::id(), true) {
+            log_warn!("Couldn't keep the launcher above the games: {}", e);
+        }
+    };
+    if cfg.keep_launcher_above && !kill.requested.load(Ordering::Relaxed) {
+        keep_launcher_above(&mut session.wm);
     }
 
-    // Teardown WM
-    println!("[splitux] Tearing down {} window manager", wm.name());
-    wm.teardown()?;
-
-    // Teardown gptokeyb daemons
-    gptokeyb::terminate_all(&mut gptokeyb_handles);
-
-    // Teardown audio routing
-    if !virtual_sinks.is_empty() {
-        if let Err(e) = teardown_audio_session(audio_system, &virtual_sinks) {
-            println!("[splitux] Warning: Audio teardown failed: {}", e);
+    // Without gamescope a game may open its real window after a launcher or
+    // splash, so keep re-placing until each instance's main window settles
+    const REFRESH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2);
+    let mut last_refresh = std::time::Instant::now();
+    let result = wait_for_instances(handles, kill, &status, |summoned| {
+        if summoned {
+            keep_launcher_above(&mut session.wm);
         }
-    }
+        if ctx.no_gamescope && !session.wm.is_reactive() && last_refresh.elapsed() >= REFRESH_INTERVAL {
+            last_refresh = std::time::Instant::now();
+            if let Err(e) = session.wm.refresh_layout(&ctx) {
+                log_debug!("Window layout refresh failed: {}", e);
+            }
+        }
+    });
+    kill.finished.store(true, Ordering::Relaxed);
+
+    teardown_session(session, kill.requested.load(Ordering::Relaxed));
 
-    Ok(())
+    result
 }
 
 // rebuild_command_with_blocking moved to launch/pure/command.rs
 
+/// bwrap args hiding every input device the instance wasn't given
+///
+/// Empty when the handler runs without bwrap or input isolation.
+pub(super) fn device_blocking_args(
+    h: &Handler,
+    input_devices: &[DeviceInfo],
+    instance: &Instance,
+    i: usize,
+) -> Vec<String> {
+    if h.disable_bwrap || h.disable_input_isolation {
+        return Vec::new();
+    }
+    let initial_js_devices = bwrap::glob_js_devices();
+    let mut args = bwrap::get_js_blocking_args(&initial_js_devices, i);
+    args.extend(bwrap::get_evdev_hidraw_blocking_args(
+        input_devices,
+        &instance.devices,
+        i,
+    ));
+    args
+}
+
+/// Initial status of every instance, before any has been spawned
+fn session_status(
+    h: &Handler,
+    input_devices: &[DeviceInfo],
+    instances: &[Instance],
+    monitors: &[Monitor],
+) -> SessionStatus {
+    SessionStatus {
+        handler: h.display().to_string(),
+        instances: instances
+            .iter()
+            .enumerate()
+            .map(|(i, instance)| InstanceStatus {
+                player: i + 1,
+                profile: instance.profname.clone(),
+                devices: instance
+                    .devices
+                    .iter()
+                    .filter_map(|&d| input_devices.get(d))
+                    .map(|dev| dev.path.clone())
+                    .collect(),
+                monitor: instance.monitor,
+                monitor_name: monitors
+                    .get(instance.monitor)
+                    .map(|m| m.connector_name().to_string())
+                    .unwrap_or_default(),
+                pid: None,
+                alive: false,
+            })
+            .collect(),
+    }
+}
+
 /// Set up audio routing for all instances
 ///
 /// Returns (audio_system, virtual_sinks, sink_env_vars_per_instance)
@@ -203,7 +335,7 @@ fn setup_audio_routing(
 
     let audio_system = resolve_audio_system(cfg.audio.system);
     if audio_system == AudioSystem::None {
-        println!("[splitux] audio - No audio system available, skipping audio routing");
+        log_info!("audio - No audio system available, skipping audio routing");
         return (AudioSystem::None, vec![], vec![String::new(); instances.len()]);
     }
 
@@ -212,21 +344,28 @@ fn setup_audio_routing(
         .map(|i| cfg.audio.default_assignments.get(&i).cloned())
         .collect();
 
+    let player_sinks = if cfg.audio.player_sinks {
+        instances.iter().map(|i| i.profname.clone()).collect()
+    } else {
+        Vec::new()
+    };
+
     let ctx = AudioContext {
         system: audio_system,
         assignments,
+        player_sinks,
     };
 
     match setup_audio_session(&ctx) {
         Ok((virtual_sinks, sink_envs)) => {
-            println!(
-                "[splitux] audio - Audio routing set up: {} virtual sinks",
+            log_info!(
+                "audio - Audio routing set up: {} virtual sinks",
                 virtual_sinks.len()
             );
             (audio_system, virtual_sinks, sink_envs)
         }
         Err(e) => {
-            println!("[splitux] audio - Warning: Audio setup failed: {}", e);
+            log_warn!("audio - Audio setup failed: {}", e);
             (audio_system, vec![], vec![String::new(); instances.len()])
         }
     }
@@ -252,15 +391,15 @@ fn setup_gptokeyb_daemons(
     }
 
     if !gptokeyb::is_available() {
-        println!("[splitux] gptokeyb - Binary not found, skipping controller→keyboard translation");
+        log_info!("gptokeyb - Binary not found, skipping controller→keyboard translation");
         return (
             (0..num_instances).map(|_| None).collect(),
             (0..num_instances).map(|_| None).collect(),
         );
     }
 
-    println!(
-        "[splitux] gptokeyb - Setting up controller→keyboard translation (profile: {})",
+    log_info!(
+        "gptokeyb - Setting up controller→keyboard translation (profile: {})",
         h.gptokeyb.profile
     );
 
@@ -0,0 +1,55 @@
+//! Launch command preview: one instance's command as a shell command line
+//!
+//! Built the way a launch builds it, without spawning, mounting or routing
+//! anything, so it can be copied and run by hand to reproduce a problem.
+
+use std::path::PathBuf;
+
+use crate::config::SplituxConfig;
+use crate::handler::Handler;
+use crate::input::DeviceInfo;
+use crate::instance::Instance;
+use crate::monitor::Monitor;
+
+use super::build_cmds::{launch_cmds, LaunchResources};
+use super::execute::device_blocking_args;
+use super::super::pure::command::{rebuild_command_with_blocking, shell_command_line};
+
+/// The fully-resolved launch command of instance `i`, quoted for a POSIX shell
+///
+/// Audio routing and gptokeyb are left out: their virtual sinks and devices
+/// only exist while a session runs. The command runs from a running
+/// session's mounted game directory, or from the game root when nothing is
+/// mounted.
+pub fn launch_command_line(
+    h: &Handler,
+    input_devices: &[DeviceInfo],
+    instances: &[Instance],
+    monitors: &[Monitor],
+    cfg: &SplituxConfig,
+    i: usize,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let instance = instances.get(i).ok_or("No such instance")?;
+    let no_audio = vec![String::new(); instances.len()];
+    let no_gptokeyb: Vec<Option<PathBuf>> = vec![None; instances.len()];
+
+    let (cmd, bwrap_arg_count) = launch_cmds(
+        h,
+        input_devices,
+        instances,
+        monitors,
+        cfg,
+        LaunchResources {
+            audio_sink_envs: &no_audio,
+            gptokeyb_virtual_devices: &no_gptokeyb,
+            dry_run: true,
+        },
+    )?
+    .into_iter()
+    .nth(i)
+    .ok_or("No command was built for this instance")?;
+
+    let blocking_args = device_blocking_args(h, input_devices, instance, i);
+    let cmd = rebuild_command_with_blocking(cmd, bwrap_arg_count, &blocking_args);
+    Ok(shell_command_line(&cmd))
+}
@@ -0,0 +1,157 @@
+//! Full session pipeline: profile and save setup, the game, then cleanup
+//!
+//! Shared by the launcher GUI and headless `--session` launches.
+
+use std::sync::Arc;
+use std::thread::sleep;
+use std::time::Instant;
+
+use super::super::operations::{run_hook, setup_prefixes, setup_profiles};
+use super::execute::launch_game;
+use super::super::types::{KillSwitch, SessionOutcome};
+use crate::config::SplituxConfig;
+use crate::handler::Handler;
+use crate::input::DeviceInfo;
+use crate::instance::Instance;
+use crate::monitor::Monitor;
+use crate::profiles::{PlayStats, remove_guest_profiles};
+use crate::save_sync;
+use crate::util::{cleanup_orphaned_processes, clear_scratch_dir};
+
+/// Run a session from start to finish, blocking until the game exits
+///
+/// Errors are passed to `report` as a title and message; the GUI shows them
+/// as dialogs. Setting `kill.requested` ends the games early. Returns whether
+/// the game launched, and if so how many instances exited with an error.
+pub fn run_session(
+    handler: &Handler,
+    dev_infos: &[DeviceInfo],
+    instances: &[Instance],
+    monitors: &[Monitor],
+    cfg: &SplituxConfig,
+    kill: &Arc<KillSwitch>,
+    report: impl Fn(&str, &str),
+) -> SessionOutcome {
+    let master_profile = cfg.master_profile.as_deref();
+
+    // Clean up any orphaned processes from previous sessions
+    cleanup_orphaned_processes();
+
+    sleep(std::time::Duration::from_secs_f32(1.5));
+
+    if let Err(err) = setup_profiles(handler, instances, cfg.parallel_prepare) {
+        log_error!("Error setting up profiles: {}", err);
+        report("Failed setting up profiles", &format!("{err}"));
+        return SessionOutcome::Failed;
+    }
+
+    // Without parallel preparation Proton builds each prefix when its game starts
+    if handler.win()
+        && cfg.parallel_prepare
+        && let Err(err) = setup_prefixes(handler, instances, cfg)
+    {
+        log_error!("Error setting up Proton prefixes: {}", err);
+        report("Failed setting up Proton prefixes", &format!("{err}"));
+        return SessionOutcome::Failed;
+    }
+
+    // Initialize profile saves with master-based inheritance
+    if !handler.original_save_paths.is_empty()
+        && let Err(err) = save_sync::initialize_profile_saves(
+            handler,
+            instances,
+            master_profile,
+            cfg.guest_save_base.as_deref(),
+            cfg.parallel_prepare,
+            cfg.save_backup_keep,
+        )
+    {
+        log_warn!("Failed to initialize saves: {}", err);
+        // Continue anyway - this is non-fatal
+    }
+
+    // Handler hooks run once around the whole session, not per instance
+    let pre_launch_ok = handler.pre_launch.is_empty()
+        || match run_hook(handler, "pre-launch", &handler.pre_launch, instances) {
+            Ok(()) => true,
+            Err(err) => {
+                log_error!("Pre-launch hook failed: {}", err);
+                report("Pre-launch Hook Failed", &err);
+                false
+            }
+        };
+
+    // Note: fuse_overlayfs_mount_gamedirs is now called inside launch_cmds
+    // with proper Goldberg overlay support
+    let started = Instant::now();
+    let outcome = if !pre_launch_ok {
+        SessionOutcome::Failed
+    } else {
+        match launch_game(handler, dev_infos, instances, monitors, cfg, kill) {
+            Ok(failed) => SessionOutcome::Ran { failed },
+            Err(err) => {
+                log_error!("Error launching instances: {}", err);
+                report("Launch Error", &format!("{err}"));
+                SessionOutcome::Failed
+            }
+        }
+    };
+
+    if outcome != SessionOutcome::Failed {
+        record_playtime(handler, instances, started.elapsed().as_secs());
+    }
+
+    if pre_launch_ok
+        && !handler.post_exit.is_empty()
+        && let Err(err) = run_hook(handler, "post-exit", &handler.post_exit, instances)
+    {
+        log_error!("Post-exit hook failed: {}", err);
+        report("Post-exit Hook Failed", &err);
+    }
+
+    // Sync master profile's saves back to original location
+    if handler.save_sync_back
+        && let Err(err) = save_sync::sync_master_saves_back(
+            handler,
+            instances,
+            master_profile,
+            cfg.save_backup_keep,
+        )
+    {
+        log_error!("Error syncing saves back: {}", err);
+        report("Save Sync Error", &format!("Failed to sync saves back: {err}"));
+    }
+
+    // WM teardown is now handled inside launch_game
+    if let Err(err) = remove_guest_profiles() {
+        log_error!("Error removing guest profiles: {}", err);
+        report("Failed removing guest profiles", &format!("{err}"));
+    }
+    if let Err(err) = clear_scratch_dir(&cfg.scratch_dir()) {
+        log_error!("Error removing tmp directory: {}", err);
+        report("Failed removing tmp directory", &format!("{err}"));
+    }
+
+    outcome
+}
+
+/// Add the session's length to each named profile's playtime
+///
+/// Guests aren't kept between sessions, and unsaved handlers have no stable
+/// name to file the time under, so neither is recorded.
+fn record_playtime(handler: &Handler, instances: &[Instance], secs: u64) {
+    if !handler.is_saved_handler() {
+        return;
+    }
+    let mut recorded: Vec<&str> = Vec::new();
+    for instance in instances {
+        let name = instance.profname.as_str();
+        if name.starts_with('.') || recorded.contains(&name) {
+            continue;
+        }
+        recorded.push(name);
+        if let Err(err) = PlayStats::record(name, handler.handler_dir_name(), secs) {
+            log_warn!("Couldn't record playtime for {}: {}", name, err);
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Session teardown pipeline
+
+use std::process::Child;
+
+use super::super::operations::IpcServer;
+use crate::audio::{teardown_audio_session, AudioSystem, VirtualSink};
+use crate::gptokeyb;
+use crate::util::cleanup_orphaned_processes;
+use crate::wm::{WindowManager, WindowManagerBackend};
+
+/// Everything a session set up besides the game instances themselves
+///
+/// Kept together so a normal exit, a failed launch and a kill-combo all tear
+/// down the same way.
+pub struct Session {
+    pub wm: WindowManagerBackend,
+    pub gptokeyb_handles: Vec<Option<Child>>,
+    pub audio_system: AudioSystem,
+    pub virtual_sinks: Vec<VirtualSink>,
+    /// Session status socket, if enabled
+    pub ipc: Option<IpcServer>,
+}
+
+/// Tear down a session after its instances have exited or been terminated
+///
+/// Every step runs even if an earlier one fails. With `killed`, leftover
+/// gamescope/bwrap processes (game children that outlived their instance)
+/// are cleaned up as well.
+pub fn teardown_session(mut session: Session, killed: bool) {
+    if let Some(ipc) = session.ipc.take() {
+        ipc.shutdown();
+    }
+
+    // Does nothing unless the launcher was kept above the games
+    if let Err(e) = session.wm.keep_above(std::process::id(), false) {
+        log_warn!("Couldn't release the launcher window: {}", e);
+    }
+
+    log_info!("Tearing down {} window manager", session.wm.name());
+    if let Err(e) = session.wm.teardown() {
+        log_warn!("Window manager teardown failed: {}", e);
+    }
+
+    gptokeyb::terminate_all(&mut session.gptokeyb_handles);
+
+    if !session.virtual_sinks.is_empty()
+        && let Err(e) = teardown_audio_session(session.audio_system, &session.virtual_sinks)
+    {
+        log_warn!("Audio teardown failed: {}", e);
+    }
+
+    if killed {
+        cleanup_orphaned_processes();
+    }
+}
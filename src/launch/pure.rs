@@ -1,6 +1,21 @@
 //! Pure functions module (no side effects)
 
+pub mod args;
 pub mod command;
+pub mod controller_mappings;
+pub mod health;
+pub mod home_env;
+pub mod hooks;
+pub mod ipc;
+pub mod kill_combo;
+pub mod overlay;
+pub mod steam_env;
 pub mod validation;
 
+pub use args::{expand_player, split_args};
+pub use controller_mappings::instance_mapping_files;
+pub use home_env::profile_home_env;
+pub use hooks::{hook_env, resolve_hook_path};
+pub use overlay::{lowerdir_stack, OverlayLayers};
+pub use steam_env::steam_client_env_scrub;
 pub use validation::validate_runtime;
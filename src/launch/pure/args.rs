@@ -0,0 +1,67 @@
+//! Game argument tokenizing (pure, no side effects)
+
+/// Split an argument string on whitespace, keeping quoted words together
+///
+/// Single or double quotes group words (`-name "Player One"` is two
+/// arguments) and are removed. Backslashes are kept as-is, since Windows
+/// paths use them.
+pub fn split_args(args: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut current = String::new();
+    let mut in_word = false;
+    let mut quote: Option<char> = None;
+
+    for c in args.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => current.push(c),
+            None if c == '"' || c == '\'' => {
+                quote = Some(c);
+                in_word = true;
+            }
+            None if c.is_whitespace() => {
+                if in_word {
+                    out.push(std::mem::take(&mut current));
+                    in_word = false;
+                }
+            }
+            None => {
+                current.push(c);
+                in_word = true;
+            }
+        }
+    }
+    if in_word {
+        out.push(current);
+    }
+    out
+}
+
+/// Substitute `{player}` with the 1-based player number
+pub fn expand_player(arg: &str, player: usize) -> String {
+    arg.replace("{player}", &player.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quoted_words_stay_together() {
+        assert_eq!(split_args("-windowed  -w 1280"), vec!["-windowed", "-w", "1280"]);
+        assert_eq!(
+            split_args(r#"-name "Player One" -map 'Big Room'"#),
+            vec!["-name", "Player One", "-map", "Big Room"]
+        );
+        assert_eq!(split_args(r#"-title="A B" """#), vec!["-title=A B", ""]);
+        assert_eq!(split_args(r"C:\Games\x"), vec![r"C:\Games\x"]);
+        assert!(split_args("   ").is_empty());
+    }
+
+    #[test]
+    fn player_placeholder_is_one_based() {
+        assert_eq!(expand_player("-player{player}", 1), "-player1");
+        assert_eq!(expand_player("{player}", 4), "4");
+        assert_eq!(expand_player("-level 3", 2), "-level 3");
+    }
+}
@@ -61,8 +61,10 @@ pub fn rebuild_command_with_blocking(
 
 /// Format a launch command for debug logging (pure string building).
 ///
+/// Values of the env vars named in `redact` (the handler's own `env`, which may
+/// hold keys or tokens) are masked, since logs end up in diagnostics bundles.
 /// Returns the formatted string. Caller is responsible for printing.
-pub fn format_launch_cmd(cmd: &Command, i: usize) -> String {
+pub fn format_launch_cmd(cmd: &Command, i: usize, redact: &[&str]) -> String {
     let mut output = String::new();
 
     output.push_str(&format!("[splitux] INSTANCE {}:\n", i + 1));
@@ -71,12 +73,13 @@ pub fn format_launch_cmd(cmd: &Command, i: usize) -> String {
     output.push_str(&format!("[splitux] CWD={}\n", cwd.display()));
 
     for var in cmd.get_envs() {
+        let key = var.0.to_string_lossy();
         let value = var.1.ok_or_else(|| "").unwrap_or_default();
-        output.push_str(&format!(
-            "[splitux] {}={}\n",
-            var.0.to_string_lossy(),
-            value.display()
-        ));
+        if redact.contains(&key.as_ref()) {
+            output.push_str(&format!("[splitux] {}=<redacted>\n", key));
+        } else {
+            output.push_str(&format!("[splitux] {}={}\n", key, value.display()));
+        }
     }
 
     output.push_str(&format!("[splitux] \"{}\"\n", cmd.get_program().display()));
@@ -99,11 +102,84 @@ pub fn format_launch_cmd(cmd: &Command, i: usize) -> String {
     output
 }
 
+/// Quote an argument for a POSIX shell
+///
+/// Arguments made only of characters the shell treats literally are left
+/// bare; anything else is single-quoted, with embedded quotes written `'\''`.
+pub fn shell_quote(arg: &str) -> String {
+    let literal = !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./=:,+@%".contains(c));
+    if literal {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', "'\\''"))
+    }
+}
+
+/// A command as a single line to paste into a POSIX shell
+///
+/// Changes to the working directory first and passes the variables the
+/// command sets or removes through `env`, so it runs as it would from Splitux.
+pub fn shell_command_line(cmd: &Command) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    if let Some(dir) = cmd.get_current_dir() {
+        parts.push(format!("cd {} &&", shell_quote(&dir.to_string_lossy())));
+    }
+
+    let mut envs: Vec<_> = cmd.get_envs().collect();
+    envs.sort_by(|a, b| a.0.cmp(b.0));
+    if !envs.is_empty() {
+        parts.push("env".to_string());
+        // env takes its options before the assignments
+        for (key, _) in envs.iter().filter(|(_, v)| v.is_none()) {
+            parts.push(format!("-u {}", shell_quote(&key.to_string_lossy())));
+        }
+        for (key, value) in &envs {
+            if let Some(value) = value {
+                let assignment = format!("{}={}", key.to_string_lossy(), value.to_string_lossy());
+                parts.push(shell_quote(&assignment));
+            }
+        }
+    }
+
+    parts.push(shell_quote(&cmd.get_program().to_string_lossy()));
+    parts.extend(cmd.get_args().map(|a| shell_quote(&a.to_string_lossy())));
+    parts.join(" ")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::process::Command;
 
+    // ── shell_command_line ──
+
+    #[test]
+    fn shell_quote_leaves_plain_args_bare() {
+        assert_eq!(shell_quote("--bind"), "--bind");
+        assert_eq!(shell_quote("/dev/input/event3"), "/dev/input/event3");
+        assert_eq!(shell_quote(""), "''");
+        assert_eq!(shell_quote("My Game"), "'My Game'");
+        assert_eq!(shell_quote("it's $HOME"), "'it'\\''s $HOME'");
+    }
+
+    #[test]
+    fn shell_command_line_includes_cwd_and_env() {
+        let mut cmd = Command::new("gamescope");
+        cmd.current_dir("/games/My Game")
+            .env("WINEDEBUG", "-all")
+            .env("PROTON_LOG", "1")
+            .env_remove("SDL_VIDEODRIVER")
+            .args(["-W", "1920", "--", "bwrap", "--setenv", "HOME", "/p/a b"]);
+        assert_eq!(
+            shell_command_line(&cmd),
+            "cd '/games/My Game' && env -u SDL_VIDEODRIVER PROTON_LOG=1 WINEDEBUG=-all \
+             gamescope -W 1920 -- bwrap --setenv HOME '/p/a b'"
+        );
+    }
+
     // ── rebuild_command_with_blocking ──
 
     #[test]
@@ -214,14 +290,14 @@ mod tests {
     #[test]
     fn format_instance_numbering_is_one_based() {
         let cmd = Command::new("game");
-        let output = format_launch_cmd(&cmd, 0);
+        let output = format_launch_cmd(&cmd, 0, &[]);
         assert!(output.contains("INSTANCE 1:"));
     }
 
     #[test]
     fn format_contains_program_name_in_quotes() {
         let cmd = Command::new("my_game");
-        let output = format_launch_cmd(&cmd, 0);
+        let output = format_launch_cmd(&cmd, 0, &[]);
         assert!(output.contains("\"my_game\""));
     }
 
@@ -229,7 +305,7 @@ mod tests {
     fn format_contains_cwd_when_set() {
         let mut cmd = Command::new("game");
         cmd.current_dir("/home/user/games");
-        let output = format_launch_cmd(&cmd, 0);
+        let output = format_launch_cmd(&cmd, 0, &[]);
         assert!(output.contains("CWD=/home/user/games"));
     }
 
@@ -237,14 +313,25 @@ mod tests {
     fn format_contains_env_vars_when_set() {
         let mut cmd = Command::new("game");
         cmd.env("STEAM_COMPAT_DATA_PATH", "/pfx");
-        let output = format_launch_cmd(&cmd, 0);
+        let output = format_launch_cmd(&cmd, 0, &[]);
         assert!(output.contains("STEAM_COMPAT_DATA_PATH=/pfx"));
     }
 
+    #[test]
+    fn format_redacts_named_env_vars() {
+        let mut cmd = Command::new("game");
+        cmd.env("API_TOKEN", "hunter2");
+        cmd.env("SDL_VIDEODRIVER", "x11");
+        let output = format_launch_cmd(&cmd, 0, &["API_TOKEN"]);
+        assert!(output.contains("API_TOKEN=<redacted>"));
+        assert!(!output.contains("hunter2"));
+        assert!(output.contains("SDL_VIDEODRIVER=x11"));
+    }
+
     #[test]
     fn format_contains_separator_at_end() {
         let cmd = Command::new("game");
-        let output = format_launch_cmd(&cmd, 0);
+        let output = format_launch_cmd(&cmd, 0, &[]);
         assert!(output.ends_with("[splitux] ---------------------"));
     }
 }
@@ -0,0 +1,69 @@
+//! Per-device SDL controller mappings (pure, no side effects)
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::input::DeviceInfo;
+
+/// Mapping files chosen for an instance's assigned devices, without duplicates
+///
+/// `mappings` maps a device's unique ID to its gamecontrollerdb.txt path.
+/// Devices without a unique ID can't have a mapping.
+pub fn instance_mapping_files(
+    input_devices: &[DeviceInfo],
+    assigned: &[usize],
+    mappings: &HashMap<String, String>,
+) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = Vec::new();
+    for &idx in assigned {
+        let Some(device) = input_devices.get(idx) else {
+            continue;
+        };
+        if device.uniq.is_empty() {
+            continue;
+        }
+        if let Some(path) = mappings.get(&device.uniq).filter(|p| !p.is_empty()) {
+            let path = PathBuf::from(path);
+            if !files.contains(&path) {
+                files.push(path);
+            }
+        }
+    }
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::DeviceType;
+
+    fn device(uniq: &str) -> DeviceInfo {
+        DeviceInfo {
+            path: String::new(),
+            enabled: true,
+            device_type: DeviceType::Gamepad,
+            uniq: uniq.to_string(),
+            siblings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn collects_assigned_mappings_once() {
+        let devices = [device("aa:bb"), device(""), device("cc:dd"), device("ee:ff")];
+        let mut mappings = HashMap::new();
+        mappings.insert("aa:bb".to_string(), "/maps/pad.txt".to_string());
+        mappings.insert("cc:dd".to_string(), "/maps/pad.txt".to_string());
+        mappings.insert("ee:ff".to_string(), "/maps/other.txt".to_string());
+        mappings.insert(String::new(), "/maps/ignored.txt".to_string());
+
+        assert_eq!(
+            instance_mapping_files(&devices, &[0, 1, 2], &mappings),
+            vec![PathBuf::from("/maps/pad.txt")]
+        );
+        assert_eq!(
+            instance_mapping_files(&devices, &[3, 0, 9], &mappings),
+            vec![PathBuf::from("/maps/other.txt"), PathBuf::from("/maps/pad.txt")]
+        );
+        assert!(instance_mapping_files(&devices, &[1], &mappings).is_empty());
+    }
+}
@@ -0,0 +1,87 @@
+//! Instance health checks (pure, no side effects)
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// Append a line to a bounded tail buffer, dropping the oldest lines
+pub fn push_tail_line(tail: &mut VecDeque<String>, line: String, max_lines: usize) {
+    if max_lines == 0 {
+        return;
+    }
+    while tail.len() >= max_lines {
+        tail.pop_front();
+    }
+    tail.push_back(line);
+}
+
+/// Describe how an instance exited, if it counts as an early crash
+///
+/// `code` is the exit code and `signal` the terminating signal (one of them
+/// is set for a finished process). Exits after `window` or with code 0 are
+/// not crashes.
+pub fn early_exit_report(
+    instance_idx: usize,
+    code: Option<i32>,
+    signal: Option<i32>,
+    uptime: Duration,
+    window: Duration,
+    stderr_tail: &[String],
+) -> Option<String> {
+    if uptime > window || code == Some(0) {
+        return None;
+    }
+
+    let reason = match (code, signal) {
+        (Some(code), _) => format!("exit code {}", code),
+        (None, Some(signal)) => format!("killed by signal {}", signal),
+        (None, None) => "unknown exit status".to_string(),
+    };
+
+    let mut report = format!(
+        "Player {} exited after {}s ({}).",
+        instance_idx + 1,
+        uptime.as_secs(),
+        reason
+    );
+    if !stderr_tail.is_empty() {
+        report.push_str("\n\nLast output:\n");
+        report.push_str(&stderr_tail.join("\n"));
+    }
+    Some(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Duration = Duration::from_secs(30);
+
+    #[test]
+    fn tail_keeps_last_lines() {
+        let mut tail = VecDeque::new();
+        for i in 0..5 {
+            push_tail_line(&mut tail, format!("line {}", i), 3);
+        }
+        assert_eq!(tail, ["line 2", "line 3", "line 4"]);
+    }
+
+    #[test]
+    fn early_nonzero_exit_is_reported() {
+        let tail = vec!["err: missing libfoo.so".to_string()];
+        let report = early_exit_report(1, Some(127), None, Duration::from_secs(4), WINDOW, &tail).unwrap();
+        assert!(report.starts_with("Player 2 exited after 4s (exit code 127)."));
+        assert!(report.ends_with("err: missing libfoo.so"));
+    }
+
+    #[test]
+    fn signal_exit_is_reported() {
+        let report = early_exit_report(0, None, Some(11), Duration::from_secs(2), WINDOW, &[]).unwrap();
+        assert_eq!(report, "Player 1 exited after 2s (killed by signal 11).");
+    }
+
+    #[test]
+    fn clean_or_late_exit_is_ignored() {
+        assert_eq!(early_exit_report(0, Some(0), None, Duration::from_secs(2), WINDOW, &[]), None);
+        assert_eq!(early_exit_report(0, Some(1), None, Duration::from_secs(600), WINDOW, &[]), None);
+    }
+}
@@ -0,0 +1,37 @@
+//! Profile home redirection without a container (pure, no side effects)
+
+use std::path::{Path, PathBuf};
+
+/// Environment that points a native game's home and XDG base directories at `home`
+///
+/// Used when bwrap is disabled, where HOME can't be set inside a container.
+/// XDG_RUNTIME_DIR is left alone since it holds the session's sockets.
+pub fn profile_home_env(home: &Path) -> Vec<(&'static str, PathBuf)> {
+    vec![
+        ("HOME", home.to_path_buf()),
+        ("XDG_CONFIG_HOME", home.join(".config")),
+        ("XDG_DATA_HOME", home.join(".local/share")),
+        ("XDG_STATE_HOME", home.join(".local/state")),
+        ("XDG_CACHE_HOME", home.join(".cache")),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_paths_live_under_profile_home() {
+        let home = Path::new("/p/profiles/Alice/home");
+        for (_, path) in profile_home_env(home) {
+            assert!(path.starts_with(home));
+        }
+    }
+
+    #[test]
+    fn runtime_dir_not_overridden() {
+        let env = profile_home_env(Path::new("/p/home"));
+        assert!(env.iter().all(|(name, _)| *name != "XDG_RUNTIME_DIR"));
+        assert_eq!(env[0], ("HOME", PathBuf::from("/p/home")));
+    }
+}
@@ -0,0 +1,57 @@
+//! Handler hook scripts (pure, no side effects)
+
+use std::path::{Component, Path, PathBuf};
+
+/// Resolve a hook script path, which must stay inside the handler directory
+pub fn resolve_hook_path(handler_dir: &Path, script: &str) -> Result<PathBuf, String> {
+    let relative = Path::new(script);
+    if relative.is_absolute() {
+        return Err(format!("'{}' must be relative to the handler folder", script));
+    }
+    if relative
+        .components()
+        .any(|c| matches!(c, Component::ParentDir))
+    {
+        return Err(format!("'{}' must not leave the handler folder", script));
+    }
+    Ok(handler_dir.join(relative))
+}
+
+/// Environment passed to hook scripts
+///
+/// Profile names (guests are `.Name`) can't contain spaces, so the list
+/// is space-separated.
+pub fn hook_env(game_root: &str, profiles: &[String], handler_dir: &Path) -> Vec<(&'static str, String)> {
+    vec![
+        ("SPLITUX_GAME_ROOT", game_root.to_string()),
+        ("SPLITUX_PROFILES", profiles.join(" ")),
+        ("SPLITUX_PLAYER_COUNT", profiles.len().to_string()),
+        ("SPLITUX_HANDLER_DIR", handler_dir.to_string_lossy().to_string()),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hooks_resolve_under_handler_dir() {
+        let dir = Path::new("/h/my-game");
+        assert_eq!(
+            resolve_hook_path(dir, "scripts/pre.sh"),
+            Ok(PathBuf::from("/h/my-game/scripts/pre.sh"))
+        );
+        assert!(resolve_hook_path(dir, "/usr/bin/rm").is_err());
+        assert!(resolve_hook_path(dir, "../other/pre.sh").is_err());
+        assert!(resolve_hook_path(dir, "scripts/../../pre.sh").is_err());
+    }
+
+    #[test]
+    fn env_lists_profiles() {
+        let profiles = vec!["Alice".to_string(), ".Bob".to_string()];
+        let env = hook_env("/games/x", &profiles, Path::new("/h/x"));
+        assert!(env.contains(&("SPLITUX_PROFILES", "Alice .Bob".to_string())));
+        assert!(env.contains(&("SPLITUX_PLAYER_COUNT", "2".to_string())));
+        assert!(env.contains(&("SPLITUX_GAME_ROOT", "/games/x".to_string())));
+    }
+}
@@ -0,0 +1,58 @@
+//! Session status socket protocol (pure, no side effects)
+//!
+//! Clients send one request per line and get one JSON object per line back:
+//! - `status`: the full `SessionStatus`
+//! - `ping`: `{"ok":true}`
+
+use super::super::types::SessionStatus;
+
+/// Reply to one request line
+pub fn handle_request(request: &str, status: &SessionStatus) -> String {
+    match request.trim() {
+        "status" => serde_json::to_string(status)
+            .unwrap_or_else(|e| serde_json::json!({ "error": e.to_string() }).to_string()),
+        "ping" => serde_json::json!({ "ok": true }).to_string(),
+        other => serde_json::json!({ "error": format!("unknown request: {}", other) }).to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::launch::types::InstanceStatus;
+
+    fn status() -> SessionStatus {
+        SessionStatus {
+            handler: "Game".to_string(),
+            instances: vec![InstanceStatus {
+                player: 1,
+                profile: "alice".to_string(),
+                devices: vec!["/dev/input/event5".to_string()],
+                monitor: 0,
+                monitor_name: "DP-1".to_string(),
+                pid: Some(4242),
+                alive: true,
+            }],
+        }
+    }
+
+    #[test]
+    fn status_request_returns_instances() {
+        let reply: serde_json::Value =
+            serde_json::from_str(&handle_request("status\n", &status())).unwrap();
+        let instance = &reply["instances"][0];
+        assert_eq!(instance["player"], 1);
+        assert_eq!(instance["profile"], "alice");
+        assert_eq!(instance["devices"][0], "/dev/input/event5");
+        assert_eq!(instance["pid"], 4242);
+        assert_eq!(instance["alive"], true);
+    }
+
+    #[test]
+    fn ping_and_unknown_requests() {
+        assert_eq!(handle_request("ping", &status()), r#"{"ok":true}"#);
+        let reply: serde_json::Value =
+            serde_json::from_str(&handle_request("reboot", &status())).unwrap();
+        assert!(reply["error"].as_str().unwrap().contains("reboot"));
+    }
+}
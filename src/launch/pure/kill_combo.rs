@@ -0,0 +1,72 @@
+//! Kill-session combo detection (pure, no side effects)
+
+use std::time::{Duration, Instant};
+
+use crate::config::ComboButton;
+
+/// Whether every button of the combo is among the held buttons
+///
+/// An empty combo never matches, so a misconfigured combo can't fire on its own.
+pub fn combo_pressed(held: &[ComboButton], combo: &[ComboButton]) -> bool {
+    !combo.is_empty() && combo.iter().all(|b| held.contains(b))
+}
+
+/// Tracks how long a combo has been held on one controller
+#[derive(Default)]
+pub struct ComboHold {
+    since: Option<Instant>,
+}
+
+impl ComboHold {
+    /// Feed the current combo state, returning true once it was held for `hold`
+    ///
+    /// Releasing any button resets the timer.
+    pub fn update(&mut self, pressed: bool, now: Instant, hold: Duration) -> bool {
+        if !pressed {
+            self.since = None;
+            return false;
+        }
+        let since = *self.since.get_or_insert(now);
+        now.duration_since(since) >= hold
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combo_needs_every_button() {
+        let combo = [ComboButton::Start, ComboButton::Select];
+        assert!(combo_pressed(&[ComboButton::Select, ComboButton::A, ComboButton::Start], &combo));
+        assert!(!combo_pressed(&[ComboButton::Start], &combo));
+        assert!(!combo_pressed(&[], &combo));
+    }
+
+    #[test]
+    fn empty_combo_never_matches() {
+        assert!(!combo_pressed(&[ComboButton::Start], &[]));
+        assert!(!combo_pressed(&[], &[]));
+    }
+
+    #[test]
+    fn hold_fires_after_duration() {
+        let hold = Duration::from_secs(2);
+        let start = Instant::now();
+        let mut tracker = ComboHold::default();
+        assert!(!tracker.update(true, start, hold));
+        assert!(!tracker.update(true, start + Duration::from_millis(1900), hold));
+        assert!(tracker.update(true, start + Duration::from_secs(2), hold));
+    }
+
+    #[test]
+    fn release_resets_hold() {
+        let hold = Duration::from_secs(2);
+        let start = Instant::now();
+        let mut tracker = ComboHold::default();
+        tracker.update(true, start, hold);
+        assert!(!tracker.update(false, start + Duration::from_millis(1500), hold));
+        assert!(!tracker.update(true, start + Duration::from_millis(2500), hold));
+        assert!(tracker.update(true, start + Duration::from_millis(4500), hold));
+    }
+}
@@ -0,0 +1,74 @@
+//! Overlay mount planning (pure, no side effects)
+
+use std::path::{Path, PathBuf};
+
+/// Layers stacked over the game root for one instance
+pub struct OverlayLayers<'a> {
+    /// User-managed mod folder from the handler (applied last)
+    pub user_mods: Option<&'a Path>,
+    /// Patched config files generated from game_patches
+    pub patches: Option<&'a Path>,
+    /// Backend overlays (Goldberg DLLs, BepInEx files, etc.) in priority order
+    pub backends: &'a [PathBuf],
+    /// Handler's overlay directory for required_mods binary files
+    pub handler_overlay: Option<&'a Path>,
+    /// Base game directory
+    pub game_root: &'a str,
+}
+
+/// Build the fuse-overlayfs lowerdir option value
+///
+/// Leftmost entries have the highest priority, so the list runs from the
+/// layer applied last (user mods) down to the game root.
+pub fn lowerdir_stack(layers: &OverlayLayers) -> String {
+    let mut parts: Vec<String> = Vec::new();
+
+    if let Some(dir) = layers.user_mods {
+        parts.push(dir.display().to_string());
+    }
+    if let Some(dir) = layers.patches {
+        parts.push(dir.display().to_string());
+    }
+    for dir in layers.backends {
+        parts.push(dir.display().to_string());
+    }
+    if let Some(dir) = layers.handler_overlay {
+        parts.push(dir.display().to_string());
+    }
+    parts.push(layers.game_root.to_string());
+
+    parts.join(":")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn user_mods_take_priority_over_all_other_layers() {
+        let backends = vec![PathBuf::from("/tmp/goldberg-0")];
+        let layers = OverlayLayers {
+            user_mods: Some(Path::new("/home/u/mods")),
+            patches: Some(Path::new("/tmp/game-patches")),
+            backends: &backends,
+            handler_overlay: Some(Path::new("/h/overlay")),
+            game_root: "/games/Game",
+        };
+        assert_eq!(
+            lowerdir_stack(&layers),
+            "/home/u/mods:/tmp/game-patches:/tmp/goldberg-0:/h/overlay:/games/Game"
+        );
+    }
+
+    #[test]
+    fn game_root_alone_when_no_layers() {
+        let layers = OverlayLayers {
+            user_mods: None,
+            patches: None,
+            backends: &[],
+            handler_overlay: None,
+            game_root: "/games/Game",
+        };
+        assert_eq!(lowerdir_stack(&layers), "/games/Game");
+    }
+}
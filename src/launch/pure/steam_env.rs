@@ -0,0 +1,74 @@
+//! Steam client environment scrubbing (pure, no side effects)
+
+use super::super::types::STEAM_CLIENT_ENV_VARS;
+
+/// Remove Steam overlay renderer libraries from an LD_PRELOAD value
+///
+/// LD_PRELOAD entries may be separated by colons or whitespace.
+/// Returns None when nothing is left to preload.
+pub fn strip_overlay_preload(ld_preload: &str) -> Option<String> {
+    let kept: Vec<&str> = ld_preload
+        .split(|c: char| c == ':' || c.is_whitespace())
+        .filter(|entry| !entry.is_empty() && !entry.contains("gameoverlayrenderer.so"))
+        .collect();
+
+    if kept.is_empty() {
+        None
+    } else {
+        Some(kept.join(":"))
+    }
+}
+
+/// Environment changes that keep the Steam overlay and Steam Input out of a native game
+///
+/// Each entry is (name, value): Some sets the variable, None removes it.
+/// `ld_preload` is the LD_PRELOAD inherited from the host, if any.
+pub fn steam_client_env_scrub(ld_preload: Option<&str>) -> Vec<(&'static str, Option<String>)> {
+    let mut changes: Vec<(&'static str, Option<String>)> = STEAM_CLIENT_ENV_VARS
+        .iter()
+        .map(|name| (*name, None))
+        .collect();
+
+    if let Some(preload) = ld_preload {
+        changes.push(("LD_PRELOAD", strip_overlay_preload(preload)));
+    }
+
+    changes.push(("SteamNoOverlayUIDrawing", Some("1".to_string())));
+    changes.push(("SDL_GAMECONTROLLER_ALLOW_STEAM_VIRTUAL_GAMEPAD", Some("0".to_string())));
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_removes_overlay_entries() {
+        let preload = "/home/u/.steam/ubuntu12_32/gameoverlayrenderer.so:/home/u/.steam/ubuntu12_64/gameoverlayrenderer.so";
+        assert_eq!(strip_overlay_preload(preload), None);
+    }
+
+    #[test]
+    fn strip_keeps_other_libraries() {
+        let preload = "/usr/lib/libmangohud.so /home/u/.steam/ubuntu12_64/gameoverlayrenderer.so:/usr/lib/libfoo.so";
+        assert_eq!(
+            strip_overlay_preload(preload),
+            Some("/usr/lib/libmangohud.so:/usr/lib/libfoo.so".to_string())
+        );
+    }
+
+    #[test]
+    fn scrub_unsets_steam_vars_and_disables_virtual_gamepad() {
+        let changes = steam_client_env_scrub(None);
+        assert!(changes.contains(&("SteamAppId", None)));
+        assert!(changes.contains(&("SteamOverlayGameId", None)));
+        assert!(changes.contains(&("SDL_GAMECONTROLLER_ALLOW_STEAM_VIRTUAL_GAMEPAD", Some("0".to_string()))));
+        assert!(!changes.iter().any(|(name, _)| *name == "LD_PRELOAD"));
+    }
+
+    #[test]
+    fn scrub_rewrites_inherited_preload() {
+        let changes = steam_client_env_scrub(Some("/x/gameoverlayrenderer.so"));
+        assert!(changes.contains(&("LD_PRELOAD", None)));
+    }
+}
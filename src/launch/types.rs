@@ -3,3 +3,89 @@
 /// SDL GameController devices to ignore (Steam Input virtual gamepads)
 /// These are filtered when using OnlySteamInput pad filter mode
 pub const SDL_GAMECONTROLLER_IGNORE_DEVICES: &str = "0x054c/0x0df2,0x054c/0x0df2,0x045e/0x02e3,0x045e/0x0b00,0x045e/0x0b05,0x2dc8/0x6000,0x2dc8/0x6100,0x2dc8/0x6001,0x2dc8/0x6101,0x2dc8/0x6003,0x2dc8/0x6006,0x2dc8/0x6009,0x2dc8/0x6012,0x28de/0x1002,0x28de/0x1003,0x28de/0x1071,0x28de/0x1052,0x28de/0x1042,0x28de/0x1203,0x28de/0x1204,0x28de/0x1205,0x28de/0x1206,0x28de/0x1302,0x28de/0x1303,0x28de/0x1304,0x28de/0x1305,0x0f0d/0x01ab,0x0f0d/0x0196,0x28de/0x12ff,0x28de/0x12fe,0x28de/0x12fd,0x28de/0x12fc,0x28de/0x12fb,0x28de/0x12fa,0x28de/0x12f9,0x28de/0x12f8,0x28de/0x12f7,0x28de/0x12f6,0x28de/0x12f5,0x28de/0x12f4,0x28de/0x12f3,0x28de/0x12f2,0x28de/0x12f1,0x28de/0x12f0,0x0079/0x181a,0x044f/0xb315,0x044f/0xd007,0x046d/0xcad1,0x054c/0x0268,0x056e/0x200f,0x056e/0x2013,0x05b8/0x1004,0x05b8/0x1006,0x06a3/0xf622,0x0738/0x3180,0x0738/0x3250,0x0738/0x3481,0x0738/0x8180,0x0738/0x8838,0x0810/0x0001,0x0810/0x0003,0x0925/0x0005,0x0925/0x8866,0x0925/0x8888,0x0e6f/0x0109,0x0e6f/0x011e,0x0e6f/0x0128,0x0e6f/0x0214,0x0e6f/0x1314,0x0e6f/0x6302,0x0e8f/0x0008,0x0e8f/0x3075,0x0e8f/0x310d,0x0f0d/0x0009,0x0f0d/0x004d,0x0f0d/0x005f,0x0f0d/0x006a,0x0f0d/0x006e,0x0f0d/0x0085,0x0f0d/0x0086,0x0f0d/0x0088,0x0f30/0x1100,0x11ff/0x3331,0x1345/0x1000,0x1345/0x6005,0x146b/0x5500,0x1a34/0x0836,0x20bc/0x5500,0x20d6/0x576d,0x20d6/0xca6d,0x2563/0x0523,0x2563/0x0575,0x25f0/0x83c3,0x25f0/0xc121,0x2c22/0x2003,0x2c22/0x2302,0x2c22/0x2502,0x8380/0x0003,0x8888/0x0308";
+
+/// Variables the Steam client exports to games it launches (or to Splitux when
+/// started from Steam). Removed when the Steam overlay/input is disabled so the
+/// overlay and Steam Input don't attach to native game instances.
+pub const STEAM_CLIENT_ENV_VARS: &[&str] = &[
+    "SteamAppId",
+    "SteamGameId",
+    "SteamOverlayGameId",
+    "SteamClientLaunch",
+    "SteamEnv",
+    "SteamVirtualGamepadInfo",
+    "EnableConfiguratorSupport",
+];
+
+/// Instances exiting with an error within this many seconds of launch get a "crashed on startup" dialog
+pub const CRASH_WINDOW_SECS: u64 = 30;
+
+/// Number of stderr lines kept per instance for crash reports
+pub const STDERR_TAIL_LINES: usize = 20;
+
+/// Grace period for instances to exit after SIGTERM before they are killed outright
+pub const KILL_GRACE_SECS: u64 = 3;
+
+/// How a session started by `run_session` ended
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SessionOutcome {
+    /// Setup, the pre-launch hook or the launch itself failed
+    Failed,
+    /// The games ran; `failed` instances exited with an error
+    Ran { failed: usize },
+}
+
+/// Shared between a running session, its combo watcher and the launcher
+///
+/// The launcher keeps one per launch, so its End session button can end the
+/// session the same way the kill combo does.
+#[derive(Default)]
+pub struct KillSwitch {
+    /// Set by the watcher once the combo has been held long enough, or by the launcher
+    pub requested: std::sync::atomic::AtomicBool,
+    /// Set by the watcher while the summon combo is held
+    pub summon: std::sync::atomic::AtomicBool,
+    /// Set once the session starts its instances
+    pub started: std::sync::atomic::AtomicBool,
+    /// Set by the session when it ends, stopping the watcher
+    pub finished: std::sync::atomic::AtomicBool,
+}
+
+impl KillSwitch {
+    /// Whether the session's instances are running and can be ended
+    pub fn is_running(&self) -> bool {
+        use std::sync::atomic::Ordering;
+        self.started.load(Ordering::Relaxed) && !self.finished.load(Ordering::Relaxed)
+    }
+
+    /// End the session as if the kill combo was held
+    pub fn request_end(&self) {
+        log_info!("End session requested from the launcher");
+        self.requested.store(true, std::sync::atomic::Ordering::Relaxed);
+    }
+}
+
+/// Session status socket, created under PATH_PARTY while a session runs
+pub const IPC_SOCKET_NAME: &str = "session.sock";
+
+/// One instance as reported on the session status socket
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct InstanceStatus {
+    /// 1-based player number
+    pub player: usize,
+    pub profile: String,
+    /// evdev paths of the assigned devices
+    pub devices: Vec<String>,
+    pub monitor: usize,
+    pub monitor_name: String,
+    /// None until the instance has been spawned
+    pub pid: Option<u32>,
+    pub alive: bool,
+}
+
+/// Session state shared with the status socket
+#[derive(Clone, Debug, Default, serde::Serialize)]
+pub struct SessionStatus {
+    pub handler: String,
+    pub instances: Vec<InstanceStatus>,
+}
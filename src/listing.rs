@@ -0,0 +1,196 @@
+// Hardware listing for `splitux --list`
+// Prints the detected monitors, input devices and audio sinks, as text or as
+// JSON with `--json`, for scripting and pasting into bug reports. Devices and
+// sinks don't need a display server; monitors are skipped without one.
+
+use serde::Serialize;
+
+use crate::audio::{resolve_audio_system, scan_sinks, AudioDeviceType};
+use crate::config::load_cfg;
+use crate::input::scan_input_devices;
+use crate::monitor::get_monitors_sdl;
+
+#[derive(Serialize)]
+pub struct MonitorEntry {
+    pub name: String,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub scale: f32,
+}
+
+#[derive(Serialize)]
+pub struct DeviceEntry {
+    pub name: String,
+    pub device_type: String,
+    pub path: String,
+    /// USB vendor:product, as used by the device filter lists
+    pub usb_id: String,
+    /// Bluetooth MAC or USB serial, empty if the device has none
+    pub uniq: String,
+    pub enabled: bool,
+}
+
+#[derive(Serialize)]
+pub struct SinkEntry {
+    pub name: String,
+    pub description: String,
+    pub device_type: AudioDeviceType,
+    pub is_default: bool,
+}
+
+#[derive(Serialize)]
+pub struct Listing {
+    /// None when there's no display server to ask
+    pub monitors: Option<Vec<MonitorEntry>>,
+    pub devices: Vec<DeviceEntry>,
+    pub audio_system: String,
+    pub audio_sinks: Vec<SinkEntry>,
+}
+
+/// Whether a display server is reachable for monitor detection
+fn has_display_server() -> bool {
+    ["NIRI_SOCKET", "WAYLAND_DISPLAY", "DISPLAY"]
+        .iter()
+        .any(|var| std::env::var_os(var).is_some_and(|v| !v.is_empty()))
+}
+
+/// Detect everything `--list` reports
+pub fn gather() -> Listing {
+    let cfg = load_cfg();
+
+    let monitors = has_display_server().then(|| {
+        get_monitors_sdl()
+            .iter()
+            .map(|m| {
+                let [x, y, _, _] = m.rect();
+                MonitorEntry {
+                    name: m.name().to_string(),
+                    x: x as i32,
+                    y: y as i32,
+                    width: m.width(),
+                    height: m.height(),
+                    scale: m.scale(),
+                }
+            })
+            .collect()
+    });
+
+    let devices = scan_input_devices(&cfg.pad_filter_type, &cfg.device_filter)
+        .iter()
+        .map(|d| {
+            let (vendor, product) = d.usb_id();
+            DeviceEntry {
+                name: d.fancyname().to_string(),
+                device_type: format!("{:?}", d.device_type()),
+                path: d.path().to_string(),
+                usb_id: crate::input::device_id_rule(vendor, product),
+                uniq: d.uniq().to_string(),
+                enabled: d.enabled(),
+            }
+        })
+        .collect();
+
+    let system = resolve_audio_system(cfg.audio.system);
+    let audio_sinks = match scan_sinks(system) {
+        Ok(sinks) => sinks
+            .into_iter()
+            .map(|s| SinkEntry {
+                name: s.name,
+                description: s.description,
+                device_type: s.device_type,
+                is_default: s.is_default,
+            })
+            .collect(),
+        Err(e) => {
+            log_warn!("Couldn't list audio sinks: {}", e);
+            Vec::new()
+        }
+    };
+
+    Listing {
+        monitors,
+        devices,
+        audio_system: system.name().to_string(),
+        audio_sinks,
+    }
+}
+
+/// Human-readable listing, one item per line under a heading per section
+pub fn format_text(listing: &Listing) -> String {
+    let mut out = String::from("Monitors:\n");
+    match &listing.monitors {
+        Some(monitors) if monitors.is_empty() => out.push_str("  (none detected)\n"),
+        Some(monitors) => {
+            for m in monitors {
+                out.push_str(&format!(
+                    "  {}: {}x{} at {},{} scale {}\n",
+                    m.name, m.width, m.height, m.x, m.y, m.scale
+                ));
+            }
+        }
+        None => out.push_str("  (no display server)\n"),
+    }
+
+    out.push_str("\nInput devices:\n");
+    if listing.devices.is_empty() {
+        out.push_str("  (none detected; check permissions on /dev/input)\n");
+    }
+    for d in &listing.devices {
+        let disabled = if d.enabled { "" } else { " (disabled)" };
+        let uniq = if d.uniq.is_empty() { String::new() } else { format!(" uniq {}", d.uniq) };
+        out.push_str(&format!(
+            "  {}: {} [{}] {}{}{}\n",
+            d.device_type, d.name, d.path, d.usb_id, uniq, disabled
+        ));
+    }
+
+    out.push_str(&format!("\nAudio sinks ({}):\n", listing.audio_system));
+    if listing.audio_sinks.is_empty() {
+        out.push_str("  (none detected)\n");
+    }
+    for s in &listing.audio_sinks {
+        let default = if s.is_default { " (default)" } else { "" };
+        out.push_str(&format!("  {} [{}] {:?}{}\n", s.description, s.name, s.device_type, default));
+    }
+    out
+}
+
+/// Print the listing to stdout
+pub fn print(json: bool) {
+    let listing = gather();
+    match json {
+        true => println!(
+            "{}",
+            serde_json::to_string_pretty(&listing).expect("Failed to serialize the listing")
+        ),
+        false => print!("{}", format_text(&listing)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_listing_notes_missing_display_and_empty_sections() {
+        let listing = Listing {
+            monitors: None,
+            devices: vec![DeviceEntry {
+                name: "Xbox Controller".to_string(),
+                device_type: "Gamepad".to_string(),
+                path: "/dev/input/event5".to_string(),
+                usb_id: "045e:02ea".to_string(),
+                uniq: String::new(),
+                enabled: true,
+            }],
+            audio_system: "None".to_string(),
+            audio_sinks: Vec::new(),
+        };
+        let text = format_text(&listing);
+        assert!(text.contains("(no display server)"));
+        assert!(text.contains("  Gamepad: Xbox Controller [/dev/input/event5] 045e:02ea\n"));
+        assert!(text.contains("Audio sinks (None):\n  (none detected)"));
+    }
+}
@@ -0,0 +1,253 @@
+//! Leveled logging to the console and a rotating log file
+//!
+//! Messages keep the `[splitux]` console format and are mirrored to
+//! `PATH_PARTY/logs/splitux-<timestamp>-<pid>.log` with a timestamp, the level and
+//! the player the current thread is working for. Only the newest
+//! `LOG_KEEP_RUNS` log files are kept.
+
+use std::cell::Cell;
+use std::fmt;
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::paths::PATH_PARTY;
+use crate::util::format_local_time;
+
+/// Log files kept in `PATH_PARTY/logs`, one per run
+const LOG_KEEP_RUNS: usize = 5;
+
+/// Start time and PID of the run that wrote a log file, oldest first when sorted
+type LogId = (u64, u32);
+
+/// Message severity; a level shows everything at or above it
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
+
+impl LogLevel {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "error" => Some(LogLevel::Error),
+            "warn" | "warning" => Some(LogLevel::Warn),
+            "info" => Some(LogLevel::Info),
+            "debug" => Some(LogLevel::Debug),
+            _ => None,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            LogLevel::Error => "ERROR",
+            LogLevel::Warn => "WARN",
+            LogLevel::Info => "INFO",
+            LogLevel::Debug => "DEBUG",
+        }
+    }
+}
+
+struct Logger {
+    level: LogLevel,
+    file: Mutex<Option<File>>,
+}
+
+static LOGGER: OnceLock<Logger> = OnceLock::new();
+
+thread_local! {
+    /// 1-based player the current thread is working for, if any
+    static PLAYER: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// Tag messages from the current thread with a player (None clears it)
+pub fn set_player(player: Option<usize>) {
+    PLAYER.with(|p| p.set(player));
+}
+
+/// Start logging at `level`, rotating old log files
+///
+/// Returns the new log file's path. Console logging works even if the file
+/// can't be created; messages logged before `init` use the Info level.
+pub fn init(level: LogLevel) -> Option<PathBuf> {
+    let dir = PATH_PARTY.join("logs");
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    // The PID keeps two runs started in the same second from sharing a file
+    let path = dir.join(format!("splitux-{}-{}.log", timestamp, std::process::id()));
+
+    let file = std::fs::create_dir_all(&dir)
+        .and_then(|_| File::create(&path))
+        .map_err(|e| eprintln!("[splitux] Warning: Can't create log file {}: {}", path.display(), e))
+        .ok();
+    let opened = file.is_some();
+
+    let _ = LOGGER.set(Logger {
+        level,
+        file: Mutex::new(file),
+    });
+
+    prune_logs(&dir);
+    opened.then_some(path)
+}
+
+/// Log one message; use the `log_*!` macros instead of calling this directly
+pub fn log(level: LogLevel, args: fmt::Arguments) {
+    let max = LOGGER.get().map_or(LogLevel::Info, |l| l.level);
+    if level > max {
+        return;
+    }
+
+    let message = args.to_string();
+    match level {
+        LogLevel::Error | LogLevel::Warn => eprintln!("[splitux] {}", message),
+        LogLevel::Info | LogLevel::Debug => println!("[splitux] {}", message),
+    }
+
+    let Some(logger) = LOGGER.get() else {
+        return;
+    };
+    if let Ok(mut file) = logger.file.lock()
+        && let Some(file) = file.as_mut()
+    {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let player = PLAYER.with(|p| p.get());
+        let _ = writeln!(file, "{}", format_line(&format_local_time(secs), level, player, &message));
+    }
+}
+
+/// One log file line: `<time> <LEVEL> [P<n>] <message>`
+fn format_line(time: &str, level: LogLevel, player: Option<usize>, message: &str) -> String {
+    match player {
+        Some(n) => format!("{} {:<5} [P{}] {}", time, level.label(), n, message),
+        None => format!("{} {:<5} {}", time, level.label(), message),
+    }
+}
+
+/// Run of a `splitux-<timestamp>-<pid>.log` file name
+///
+/// Names from before the PID was added parse with PID 0.
+fn parse_log_name(name: &str) -> Option<LogId> {
+    let stem = name.strip_prefix("splitux-")?.strip_suffix(".log")?;
+    let (stamp, pid) = stem.split_once('-').unwrap_or((stem, "0"));
+    let number = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    if !number(stamp) || !number(pid) {
+        return None;
+    }
+    Some((stamp.parse().ok()?, pid.parse().ok()?))
+}
+
+/// Log files to delete so only the newest `keep` remain
+fn logs_to_prune(ids: &[LogId], keep: usize) -> Vec<LogId> {
+    let mut sorted = ids.to_vec();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+    sorted.into_iter().skip(keep).collect()
+}
+
+/// Log files in `dir`, newest first
+fn list_logs(dir: &Path) -> Vec<(LogId, PathBuf)> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut logs: Vec<(LogId, PathBuf)> = entries
+        .flatten()
+        .filter_map(|e| parse_log_name(&e.file_name().to_string_lossy()).map(|id| (id, e.path())))
+        .collect();
+    logs.sort_unstable_by_key(|(id, _)| std::cmp::Reverse(*id));
+    logs
+}
+
+/// Newest `count` log files, newest (this run's) first
+pub fn recent_logs(count: usize) -> Vec<PathBuf> {
+    list_logs(&PATH_PARTY.join("logs"))
+        .into_iter()
+        .take(count)
+        .map(|(_, path)| path)
+        .collect()
+}
+
+fn prune_logs(dir: &Path) {
+    let logs = list_logs(dir);
+    let ids: Vec<LogId> = logs.iter().map(|(id, _)| *id).collect();
+    let pruned = logs_to_prune(&ids, LOG_KEEP_RUNS);
+    for (id, path) in logs {
+        if pruned.contains(&id) {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+macro_rules! log_error {
+    ($($arg:tt)*) => {
+        $crate::logging::log($crate::logging::LogLevel::Error, format_args!($($arg)*))
+    };
+}
+
+macro_rules! log_warn {
+    ($($arg:tt)*) => {
+        $crate::logging::log($crate::logging::LogLevel::Warn, format_args!($($arg)*))
+    };
+}
+
+macro_rules! log_info {
+    ($($arg:tt)*) => {
+        $crate::logging::log($crate::logging::LogLevel::Info, format_args!($($arg)*))
+    };
+}
+
+macro_rules! log_debug {
+    ($($arg:tt)*) => {
+        $crate::logging::log($crate::logging::LogLevel::Debug, format_args!($($arg)*))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels_parse_and_order() {
+        assert_eq!(LogLevel::parse("DEBUG"), Some(LogLevel::Debug));
+        assert_eq!(LogLevel::parse("warning"), Some(LogLevel::Warn));
+        assert_eq!(LogLevel::parse("verbose"), None);
+        assert!(LogLevel::Error < LogLevel::Warn);
+        assert!(LogLevel::Info < LogLevel::Debug);
+    }
+
+    #[test]
+    fn line_includes_player_context() {
+        assert_eq!(
+            format_line("2026-01-02 03:04:05", LogLevel::Warn, Some(2), "Backup failed"),
+            "2026-01-02 03:04:05 WARN  [P2] Backup failed"
+        );
+        assert_eq!(
+            format_line("2026-01-02 03:04:05", LogLevel::Info, None, "Sync complete"),
+            "2026-01-02 03:04:05 INFO  Sync complete"
+        );
+    }
+
+    #[test]
+    fn rotation_keeps_newest_runs() {
+        assert_eq!(parse_log_name("splitux-1760000000-4242.log"), Some((1760000000, 4242)));
+        assert_eq!(parse_log_name("splitux-1760000000.log"), Some((1760000000, 0)));
+        assert_eq!(parse_log_name("splitux-.log"), None);
+        assert_eq!(parse_log_name("splitux-1760000000-.log"), None);
+        assert_eq!(parse_log_name("other.log"), None);
+
+        let ids = [(1, 9), (5, 1), (3, 1), (2, 1), (4, 1), (6, 1)];
+        assert_eq!(logs_to_prune(&ids, 5), vec![(1, 9)]);
+        assert!(logs_to_prune(&[(1, 1), (2, 1)], 5).is_empty());
+
+        // Two runs in the same second keep separate files
+        assert_eq!(logs_to_prune(&[(7, 100), (7, 200)], 1), vec![(7, 100)]);
+    }
+}
@@ -1,17 +1,26 @@
+#[macro_use]
+mod logging;
+
 mod app;
 mod audio;
 mod backend;
 mod bepinex;
 mod bwrap;
+mod capabilities;
+mod diagnostics;
 mod game_patches;
 mod gamescope;
 mod gptokeyb;
 mod handler;
 mod input;
 mod instance;
+mod integrity;
+mod last_session;
 mod launch;
+mod listing;
 mod mods;
 mod monitor;
+mod net;
 mod paths;
 mod platform;
 mod profile_prefs;
@@ -19,6 +28,7 @@ mod profiles;
 mod proton;
 mod registry;
 mod save_sync;
+mod session_file;
 mod util;
 mod wm;
 
@@ -33,6 +43,18 @@ use crate::paths::PATH_PARTY;
 use crate::profiles::remove_guest_profiles;
 use crate::util::*;
 
+// Process exit codes for the CLI entry points (documented in USAGE_TEXT)
+const EXIT_SUCCESS: i32 = 0;
+const EXIT_USAGE: i32 = 1;
+const EXIT_SESSION_FAILED: i32 = 2;
+const EXIT_EXEC_NOT_FOUND: i32 = 3;
+const EXIT_SESSION_FILE_INVALID: i32 = 4;
+const EXIT_DEVICES_MISSING: i32 = 5;
+const EXIT_LAUNCH_FAILED: i32 = 6;
+const EXIT_HANDLER_NOT_FOUND: i32 = 7;
+const EXIT_GAME_ROOT_MISSING: i32 = 8;
+const EXIT_INSTANCE_FAILED: i32 = 9;
+
 /// Configure SDL video driver for the splitux GUI process.
 ///
 /// On niri: Skip entirely (we use niri IPC for monitor detection).
@@ -40,7 +62,7 @@ use crate::util::*;
 fn configure_sdl_for_compositor() {
     // On niri, we bypass SDL entirely for monitor detection
     if std::env::var("NIRI_SOCKET").is_ok() {
-        println!("[splitux] On niri, skipping SDL configuration");
+        log_info!("On niri, skipping SDL configuration");
         return;
     }
 
@@ -48,29 +70,52 @@ fn configure_sdl_for_compositor() {
     unsafe {
         std::env::set_var("SDL_VIDEODRIVER", "x11");
     }
-    println!("[splitux] Using X11 SDL for monitor detection");
+    log_info!("Using X11 SDL for monitor detection");
 }
 
 fn main() -> eframe::Result {
+    let args: Vec<String> = std::env::args().collect();
+
+    let log_level = match args.iter().position(|arg| arg == "--log-level") {
+        Some(i) => match args.get(i + 1).and_then(|l| logging::LogLevel::parse(l)) {
+            Some(level) => level,
+            None => {
+                eprintln!("{}", USAGE_TEXT);
+                std::process::exit(EXIT_USAGE);
+            }
+        },
+        // Keep stdout clean for `--list` output; warnings still go to stderr
+        None if args.iter().any(|arg| arg == "--list") => logging::LogLevel::Warn,
+        None => logging::LogLevel::Info,
+    };
+    if let Some(path) = logging::init(log_level) {
+        log_info!("Logging to {}", path.display());
+    }
+
     configure_sdl_for_compositor();
 
+    // Runs before the startup monitor detection so it also works without a display server
+    if args.iter().any(|arg| arg == "--list") {
+        listing::print(args.iter().any(|arg| arg == "--json"));
+        std::process::exit(EXIT_SUCCESS);
+    }
+
     let monitors = get_monitors_sdl();
 
-    println!("[splitux] Monitors detected:");
+    log_info!("Monitors detected:");
     for monitor in &monitors {
-        println!(
-            "[splitux] {} ({}x{})",
+        log_info!(
+            "{} ({}x{} @ {}x)",
             monitor.name(),
             monitor.width(),
-            monitor.height()
+            monitor.height(),
+            monitor.scale()
         );
     }
 
-    let args: Vec<String> = std::env::args().collect();
-
     if std::env::args().any(|arg| arg == "--help") {
         println!("{}", USAGE_TEXT);
-        std::process::exit(0);
+        std::process::exit(EXIT_SUCCESS);
     }
 
     if std::env::args().any(|arg| arg == "--kwin") {
@@ -80,13 +125,13 @@ fn main() -> eframe::Result {
         let kwin = KWinManager::new();
         let mut cmd = kwin.nested_session_command(&args, &monitors[0]);
 
-        println!("[splitux] Launching kwin session: {:?}", cmd);
+        log_info!("Launching kwin session: {:?}", cmd);
 
         match cmd.spawn() {
-            Ok(_) => std::process::exit(0),
+            Ok(_) => std::process::exit(EXIT_SUCCESS),
             Err(e) => {
-                eprintln!("[splitux] Failed to start kwin_wayland: {}", e);
-                std::process::exit(1);
+                log_error!("Failed to start kwin_wayland: {}", e);
+                std::process::exit(EXIT_SESSION_FAILED);
             }
         }
     }
@@ -98,17 +143,28 @@ fn main() -> eframe::Result {
         let hyprland = HyprlandManager::new();
         let mut cmd = hyprland.nested_session_command(&args, &monitors[0]);
 
-        println!("[splitux] Launching hyprland session: {:?}", cmd);
+        log_info!("Launching hyprland session: {:?}", cmd);
 
         match cmd.spawn() {
-            Ok(_) => std::process::exit(0),
+            Ok(_) => std::process::exit(EXIT_SUCCESS),
             Err(e) => {
-                eprintln!("[splitux] Failed to start Hyprland: {}", e);
-                std::process::exit(1);
+                log_error!("Failed to start Hyprland: {}", e);
+                std::process::exit(EXIT_SESSION_FAILED);
             }
         }
     }
 
+    let session_path = match args.iter().position(|arg| arg == "--session") {
+        Some(i) => match args.get(i + 1) {
+            Some(path) => Some(std::path::PathBuf::from(path)),
+            None => {
+                eprintln!("{}", USAGE_TEXT);
+                std::process::exit(EXIT_USAGE);
+            }
+        },
+        None => None,
+    };
+
     let mut exec = String::new();
     let mut execargs = String::new();
     if let Some(exec_index) = args.iter().position(|arg| arg == "--exec") {
@@ -116,7 +172,7 @@ fn main() -> eframe::Result {
             exec = next_arg.clone();
         } else {
             eprintln!("{}", USAGE_TEXT);
-            std::process::exit(1);
+            std::process::exit(EXIT_USAGE);
         }
     }
     if let Some(execargs_index) = args.iter().position(|arg| arg == "--args") {
@@ -124,17 +180,29 @@ fn main() -> eframe::Result {
             execargs = next_arg.clone();
         } else {
             eprintln!("{}", USAGE_TEXT);
-            std::process::exit(1);
+            std::process::exit(EXIT_USAGE);
         }
     }
 
+    if !exec.is_empty() && !std::path::Path::new(&exec).is_file() {
+        log_warn!("Executable not found: {}", exec);
+        std::process::exit(EXIT_EXEC_NOT_FOUND);
+    }
+
     let handler_lite = if !exec.is_empty() {
         Some(Handler::from_cli(&exec, &execargs))
     } else {
         None
     };
 
-    let fullscreen = std::env::args().any(|arg| arg == "--fullscreen");
+    let cfg = crate::config::load_cfg();
+    let monitor_heights: Vec<u32> = monitors.iter().map(|m| m.height()).collect();
+    let gamescope_session = crate::wm::operations::session::read_session_env().is_gamescope_session;
+    let fullscreen = std::env::args().any(|arg| arg == "--fullscreen")
+        || cfg.start_fullscreen.resolve(gamescope_session, &monitor_heights);
+    if fullscreen {
+        log_info!("Starting fullscreen");
+    }
 
     std::fs::create_dir_all(PATH_PARTY.join("handlers"))
         .expect("Failed to create handlers directory");
@@ -150,19 +218,60 @@ fn main() -> eframe::Result {
     remove_guest_profiles().unwrap();
     wm::bars::restore_from_previous_session();
     clear_tmp().unwrap();
+    // A scratch directory chosen in settings may hold a crashed session's mounts
+    if let Err(e) = clear_scratch_dir(&cfg.scratch_dir()) {
+        log_warn!("Couldn't clear the scratch directory: {}", e);
+    }
     cleanup_orphaned_processes();
+    capabilities::capabilities();
+
+    // Headless launch: no GUI, the exit code reports the outcome
+    if let Some(path) = session_path {
+        use crate::session_file::SessionFileError;
+
+        let code = match session_file::run(&path, &monitors) {
+            Ok(()) => EXIT_SUCCESS,
+            Err(e) => {
+                log_error!("{}", e);
+                match e {
+                    SessionFileError::Invalid(_) => EXIT_SESSION_FILE_INVALID,
+                    SessionFileError::HandlerNotFound(_) => EXIT_HANDLER_NOT_FOUND,
+                    SessionFileError::GameRootMissing(_) => EXIT_GAME_ROOT_MISSING,
+                    SessionFileError::DevicesMissing(_) => EXIT_DEVICES_MISSING,
+                    SessionFileError::LaunchFailed => EXIT_LAUNCH_FAILED,
+                    SessionFileError::InstanceFailed(_) => EXIT_INSTANCE_FAILED,
+                }
+            }
+        };
+        std::process::exit(code);
+    }
 
-    let scrheight = monitors[0].height();
+    let scrheight = monitors[0].logical_height();
 
     let scale = match fullscreen {
         true => scrheight as f32 / 560.0,
         false => 1.3,
     };
 
+    // Restore the launcher geometry from the last session, fitted to the current monitors
+    let monitor_rects: Vec<[f32; 4]> = monitors.iter().map(|m| m.rect()).collect();
+    let geometry = match fullscreen {
+        true => None,
+        false => cfg
+            .window_geometry
+            .and_then(|g| g.clamped_to(&monitor_rects)),
+    };
+    let inner_size = geometry.map(|g| g.size).unwrap_or([1080.0, 540.0]);
+
+    let mut viewport = eframe::egui::ViewportBuilder::default()
+        .with_inner_size(inner_size)
+        .with_min_inner_size([640.0, 360.0]);
+    if let Some(position) = geometry.and_then(|g| g.position) {
+        viewport = viewport.with_position(position);
+    }
+
     let options = eframe::NativeOptions {
-        viewport: eframe::egui::ViewportBuilder::default()
-            .with_inner_size([1080.0, 540.0])
-            .with_min_inner_size([640.0, 360.0])
+        viewport: viewport
             .with_fullscreen(fullscreen)
             .with_decorations(true)
             .with_transparent(false)
@@ -173,7 +282,7 @@ fn main() -> eframe::Result {
         ..Default::default()
     };
 
-    println!("[splitux] Starting eframe app...");
+    log_info!("Starting eframe app...");
 
     eframe::run_native(
         "Splitux",
@@ -193,7 +302,7 @@ fn main() -> eframe::Result {
 
             // Load NotoSans as primary font
             if let Ok(font_data) = std::fs::read(fonts_dir.join("NotoSans-Regular.ttf")) {
-                println!("[splitux] Loaded NotoSans-Regular.ttf");
+                log_info!("Loaded NotoSans-Regular.ttf");
                 fonts.font_data.insert(
                     "NotoSans".to_owned(),
                     std::sync::Arc::new(eframe::egui::FontData::from_owned(font_data)),
@@ -202,7 +311,7 @@ fn main() -> eframe::Result {
 
             // Load NotoSansSymbols for geometric shapes (▼▲◀▶)
             if let Ok(font_data) = std::fs::read(fonts_dir.join("NotoSansSymbols-Regular.ttf")) {
-                println!("[splitux] Loaded NotoSansSymbols-Regular.ttf");
+                log_info!("Loaded NotoSansSymbols-Regular.ttf");
                 fonts.font_data.insert(
                     "NotoSansSymbols".to_owned(),
                     std::sync::Arc::new(eframe::egui::FontData::from_owned(font_data)),
@@ -211,7 +320,7 @@ fn main() -> eframe::Result {
 
             // Load NotoSansSymbols2 for extended symbols (☰ ◉ ⋮⋮)
             if let Ok(font_data) = std::fs::read(fonts_dir.join("NotoSansSymbols2-Regular.ttf")) {
-                println!("[splitux] Loaded NotoSansSymbols2-Regular.ttf");
+                log_info!("Loaded NotoSansSymbols2-Regular.ttf");
                 fonts.font_data.insert(
                     "NotoSansSymbols2".to_owned(),
                     std::sync::Arc::new(eframe::egui::FontData::from_owned(font_data)),
@@ -252,7 +361,23 @@ Usage: splitux [OPTIONS]
 Options:
     --exec <executable>   Execute the specified executable in splitscreen. If this isn't specified, Splitux will launch in the regular GUI mode.
     --args [args]         Specify arguments for the executable to be launched with. Must be quoted if containing spaces.
-    --fullscreen          Start the GUI in fullscreen mode
+    --fullscreen          Start the GUI in fullscreen mode (also automatic in a gamescope session or on a single 4K display; see "Start fullscreen" in Settings)
     --kwin                Launch Splitux inside of a nested KWin session
     --hyprland            Launch Splitux inside of a nested Hyprland session
+    --session <file>      Launch the players described in a session YAML file without the GUI (for kiosks and cabinets)
+    --list                Print the detected monitors, input devices and audio sinks, then exit. Monitors are skipped without a display server
+    --json                With --list, print JSON instead of text
+    --log-level <level>   Console and log file verbosity: error, warn, info (default) or debug. Logs are kept in ~/.local/share/splitux/logs
+
+Exit codes:
+    0   Success (or the nested session was started)
+    1   Invalid command-line usage
+    2   The nested KWin/Hyprland session failed to start
+    3   The --exec executable does not exist
+    4   The --session file is invalid (unreadable, unknown profile or layout)
+    5   Devices required by the --session file are not connected
+    6   The --session game failed to launch
+    7   The --session handler is not installed
+    8   The --session handler's game folder can't be found
+    9   A --session game instance exited with an error
 "#;
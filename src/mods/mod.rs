@@ -7,8 +7,9 @@ mod types;
 mod thunderstore;
 
 pub use types::{filter_dll_files, filter_plugin_files, PluginSource};
-pub use thunderstore::{fetch_bepinex_pack, fetch_plugin};
+pub use thunderstore::{fetch_bepinex_pack, fetch_plugin_with_dependencies};
 
+use crate::config::load_offline_mode;
 use crate::paths::PATH_PARTY;
 use std::path::PathBuf;
 
@@ -16,3 +17,11 @@ use std::path::PathBuf;
 pub fn cache_base() -> PathBuf {
     PATH_PARTY.join(".cache").join("mods")
 }
+
+/// Offline mode setting for Thunderstore fetches
+///
+/// Plugins are fetched from backend setup, which has no app config at hand,
+/// so the setting is read from disk here and handed to `fetch_with_retry`.
+fn offline_mode() -> bool {
+    load_offline_mode()
+}
@@ -2,8 +2,12 @@
 //!
 //! Handles downloading and caching plugins from Thunderstore.
 
-use super::types::PluginSource;
+use super::types::{FetchedPlugin, PluginSource};
+use crate::integrity::Checksum;
+use crate::net::fetch_with_retry;
 use serde::Deserialize;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
 use std::fs::{self, File};
 use std::io::{self, BufReader, Write};
@@ -22,6 +26,14 @@ struct PackageInfo {
     latest: Option<PackageVersion>,
 }
 
+/// The part of a package's manifest.json we need
+#[derive(Debug, Default, Deserialize)]
+struct PackageManifest {
+    /// Dependency strings: "Namespace-Name-1.2.3"
+    #[serde(default)]
+    dependencies: Vec<String>,
+}
+
 /// Fetch a plugin from Thunderstore, using cache if available.
 /// Returns list of all extracted file paths.
 pub fn fetch_plugin(source: &PluginSource, cache_base: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
@@ -29,7 +41,7 @@ pub fn fetch_plugin(source: &PluginSource, cache_base: &Path) -> Result<Vec<Path
 
     // Check if already cached
     if cache_dir.exists() {
-        eprintln!("[mods] Using cached plugin: {}", source.display_name());
+        log_info!("mods: Using cached plugin: {}", source.display_name());
         return collect_files(&cache_dir);
     }
 
@@ -38,41 +50,199 @@ pub fn fetch_plugin(source: &PluginSource, cache_base: &Path) -> Result<Vec<Path
     collect_files(&cache_dir)
 }
 
+/// Fetch a plugin and everything its manifest depends on, transitively.
+///
+/// Returns the packages in install order: dependencies before the packages
+/// that need them, `source` last. When a package is required at several
+/// versions the highest one wins. Packages already in the cache aren't
+/// downloaded again, and BepInEx packs are skipped since backends install
+/// the mod loader themselves.
+pub fn fetch_plugin_with_dependencies(
+    source: &PluginSource,
+    cache_base: &Path,
+) -> Result<Vec<FetchedPlugin>, Box<dyn Error>> {
+    let mut chosen: HashMap<String, PluginSource> = HashMap::new();
+    let mut files: HashMap<String, Vec<PathBuf>> = HashMap::new();
+    let mut dependencies: HashMap<String, Vec<String>> = HashMap::new();
+    let mut pending = vec![source.clone()];
+
+    while let Some(next) = pending.pop() {
+        if let Some(current) = chosen.get(&next.package) {
+            if compare_versions(&next.version, &current.version) != Ordering::Greater {
+                continue;
+            }
+            log_info!(
+                "mods: Using {} {} instead of {} (required by another plugin)",
+                next.package, next.version, current.version
+            );
+        }
+
+        let fetched = fetch_plugin(&next, cache_base)
+            .map_err(|e| format!("Failed to fetch {}: {}", next.display_name(), e))?;
+        let deps: Vec<PluginSource> = read_manifest(&next.cache_path(cache_base))
+            .dependencies
+            .iter()
+            .filter_map(|d| parse_dependency(d, &next))
+            .filter(|d| !is_mod_loader(&d.package))
+            .collect();
+
+        dependencies.insert(next.display_name(), deps.iter().map(|d| d.package.clone()).collect());
+        files.insert(next.display_name(), fetched);
+        pending.extend(deps);
+        chosen.insert(next.package.clone(), next);
+    }
+
+    let order = install_order(&source.package, &chosen, &dependencies);
+    if order.len() > 1 {
+        log_info!(
+            "mods: {} needs {} dependenc{}",
+            source.display_name(),
+            order.len() - 1,
+            if order.len() == 2 { "y" } else { "ies" }
+        );
+    }
+    Ok(order
+        .into_iter()
+        .map(|source| FetchedPlugin {
+            files: files.remove(&source.display_name()).unwrap_or_default(),
+            source,
+        })
+        .collect())
+}
+
+/// Read a package's manifest.json (missing or invalid manifests have no dependencies)
+fn read_manifest(package_dir: &Path) -> PackageManifest {
+    let Ok(content) = fs::read_to_string(package_dir.join("manifest.json")) else {
+        return PackageManifest::default();
+    };
+    // Thunderstore manifests are often saved with a UTF-8 BOM
+    serde_json::from_str(content.trim_start_matches('\u{feff}')).unwrap_or_else(|e| {
+        log_warn!("mods: Invalid manifest.json in {:?}: {}", package_dir, e);
+        PackageManifest::default()
+    })
+}
+
+/// Parse a manifest dependency ("Namespace-Name-1.2.3") into a pinned source
+///
+/// Source and community are inherited from the package that declared it.
+fn parse_dependency(dependency: &str, parent: &PluginSource) -> Option<PluginSource> {
+    let mut parts = dependency.trim().rsplitn(3, '-');
+    let version = parts.next()?;
+    let name = parts.next()?;
+    let namespace = parts.next()?;
+    if namespace.is_empty() || name.is_empty() || version.is_empty() {
+        return None;
+    }
+    Some(PluginSource {
+        source: parent.source.clone(),
+        community: parent.community.clone(),
+        package: format!("{}/{}", namespace, name),
+        version: version.to_string(),
+        checksum: Checksum::default(),
+    })
+}
+
+/// BepInEx packs are installed by the backends, not as plugins
+fn is_mod_loader(package: &str) -> bool {
+    let name = package.rsplit('/').next().unwrap_or(package);
+    name == "BepInExPack" || name.starts_with("BepInExPack_")
+}
+
+/// Compare dotted version numbers numerically ("1.10.0" > "1.9.2")
+fn compare_versions(a: &str, b: &str) -> Ordering {
+    let parse = |v: &str| -> Vec<u64> { v.split('.').map(|p| p.parse().unwrap_or(0)).collect() };
+    let (a, b) = (parse(a), parse(b));
+    for i in 0..a.len().max(b.len()) {
+        let ord = a.get(i).unwrap_or(&0).cmp(b.get(i).unwrap_or(&0));
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    Ordering::Equal
+}
+
+/// Order the resolved packages so every dependency comes before its dependents
+///
+/// `chosen` maps a package to its selected version, `dependencies` maps a
+/// package's display name to the packages it needs. Only packages reachable
+/// from `root` through the chosen versions are included; cycles are broken.
+fn install_order(
+    root: &str,
+    chosen: &HashMap<String, PluginSource>,
+    dependencies: &HashMap<String, Vec<String>>,
+) -> Vec<PluginSource> {
+    fn visit(
+        package: &str,
+        chosen: &HashMap<String, PluginSource>,
+        dependencies: &HashMap<String, Vec<String>>,
+        visited: &mut HashSet<String>,
+        order: &mut Vec<PluginSource>,
+    ) {
+        let Some(source) = chosen.get(package) else {
+            return;
+        };
+        if !visited.insert(package.to_string()) {
+            return;
+        }
+        for dep in dependencies.get(&source.display_name()).into_iter().flatten() {
+            visit(dep, chosen, dependencies, visited, order);
+        }
+        order.push(source.clone());
+    }
+
+    let mut order = Vec::new();
+    visit(root, chosen, dependencies, &mut HashSet::new(), &mut order);
+    order
+}
+
 /// Download plugin archive and extract to cache directory
+///
+/// The archive is checked against the source's checksum before extraction.
+/// On failure the cache directory is removed again, so a broken download
+/// is never mistaken for a cached plugin.
 fn download_and_extract(source: &PluginSource, cache_dir: &Path) -> Result<(), Box<dyn Error>> {
     let url = source.thunderstore_url();
-    eprintln!("[mods] Downloading plugin from: {}", url);
+    log_info!("mods: Downloading plugin from: {}", url);
 
     // Create cache directory
     fs::create_dir_all(cache_dir)?;
 
+    if let Err(e) = download_verified(source, &url, cache_dir) {
+        fs::remove_dir_all(cache_dir).ok();
+        return Err(e);
+    }
+
+    log_info!("mods: Plugin extracted to: {:?}", cache_dir);
+    Ok(())
+}
+
+fn download_verified(source: &PluginSource, url: &str, cache_dir: &Path) -> Result<(), Box<dyn Error>> {
     // Download to temp file
     let zip_path = cache_dir.join("plugin.zip");
-    download_file(&url, &zip_path)?;
+    download_file(url, &zip_path, &source.checksum, &source.display_name())?;
 
     // Extract
     extract_zip(&zip_path, cache_dir)?;
 
     // Remove zip after extraction
     fs::remove_file(&zip_path).ok();
-
-    eprintln!("[mods] Plugin extracted to: {:?}", cache_dir);
     Ok(())
 }
 
-/// Download a file from URL to local path
-fn download_file(url: &str, dest: &Path) -> Result<(), Box<dyn Error>> {
+/// Download a file from URL to local path, if it matches `checksum`
+fn download_file(url: &str, dest: &Path, checksum: &Checksum, what: &str) -> Result<(), Box<dyn Error>> {
     let client = reqwest::blocking::Client::builder()
         .timeout(std::time::Duration::from_secs(60))
         .build()?;
 
-    let response = client.get(url).send()?;
+    let response = fetch_with_retry(&client, url, what, super::offline_mode())?;
 
     if !response.status().is_success() {
         return Err(format!("Download failed: HTTP {}", response.status()).into());
     }
 
     let bytes = response.bytes()?;
+    checksum.verify(what, &bytes)?;
     let mut file = File::create(dest)?;
     file.write_all(&bytes)?;
 
@@ -132,7 +302,7 @@ fn fetch_latest_version(_community: &str, package: &str) -> Result<String, Box<d
         .timeout(std::time::Duration::from_secs(30))
         .build()?;
 
-    let response = client.get(&url).send()?;
+    let response = fetch_with_retry(&client, &url, package, super::offline_mode())?;
 
     if !response.status().is_success() {
         return Err(format!("Failed to fetch package info: HTTP {}", response.status()).into());
@@ -147,7 +317,7 @@ fn fetch_latest_version(_community: &str, package: &str) -> Result<String, Box<d
 }
 
 /// Get the currently cached version of a package, if any
-fn get_cached_version(cache_base: &Path, community: &str, package: &str) -> Option<String> {
+pub(super) fn get_cached_version(cache_base: &Path, community: &str, package: &str) -> Option<String> {
     let package_dir = cache_base
         .join("thunderstore")
         .join(community)
@@ -188,15 +358,16 @@ pub fn fetch_bepinex_pack(
     let latest_version = match fetch_latest_version(community, package) {
         Ok(v) => v,
         Err(e) => {
-            eprintln!("[mods] Warning: Failed to check latest {} version: {}", package, e);
+            log_warn!("mods: Failed to check latest {} version: {}", package, e);
             // Fall back to cached version if available
             if let Some(cached) = get_cached_version(cache_base, community, package) {
-                eprintln!("[mods] Using cached {} version {}", package, cached);
+                log_info!("mods: Using cached {} version {}", package, cached);
                 let source = PluginSource {
                     source: "thunderstore".to_string(),
                     community: community.to_string(),
                     package: package.to_string(),
                     version: cached,
+                    checksum: Checksum::default(),
                 };
                 return Ok(source.cache_path(cache_base));
             }
@@ -209,14 +380,15 @@ pub fn fetch_bepinex_pack(
         community: community.to_string(),
         package: package.to_string(),
         version: latest_version.clone(),
+        checksum: Checksum::default(),
     };
 
     let cache_dir = source.cache_path(cache_base);
 
     // Check if we have the latest version cached
     if cache_dir.exists() {
-        eprintln!(
-            "[mods] Using cached {} {} for {}",
+        log_info!(
+            "mods: Using cached {} {} for {}",
             package, latest_version, community
         );
         return Ok(cache_dir);
@@ -225,8 +397,8 @@ pub fn fetch_bepinex_pack(
     // Check if we have an older version cached
     if let Some(cached_version) = get_cached_version(cache_base, community, package) {
         if cached_version != latest_version {
-            eprintln!(
-                "[mods] Updating {}: {} -> {}",
+            log_info!(
+                "mods: Updating {}: {} -> {}",
                 package, cached_version, latest_version
             );
             // Remove old cached version
@@ -240,8 +412,8 @@ pub fn fetch_bepinex_pack(
     }
 
     // Download and extract latest
-    eprintln!(
-        "[mods] Downloading {} {} for {} from Thunderstore...",
+    log_info!(
+        "mods: Downloading {} {} for {} from Thunderstore...",
         package, latest_version, community
     );
     download_and_extract(&source, &cache_dir)?;
@@ -285,6 +457,7 @@ mod tests {
             community: "repo".to_string(),
             package: "Zehs/LocalMultiplayer".to_string(),
             version: "1.4.0".to_string(),
+            checksum: Checksum::default(),
         };
 
         assert_eq!(
@@ -300,6 +473,7 @@ mod tests {
             community: "repo".to_string(),
             package: "Zehs/LocalMultiplayer".to_string(),
             version: "1.4.0".to_string(),
+            checksum: Checksum::default(),
         };
 
         let base = PathBuf::from("/home/user/.cache/splitux/mods");
@@ -310,4 +484,77 @@ mod tests {
             PathBuf::from("/home/user/.cache/splitux/mods/thunderstore/repo/Zehs_LocalMultiplayer/1.4.0")
         );
     }
+
+    fn source(package: &str, version: &str) -> PluginSource {
+        PluginSource {
+            source: "thunderstore".to_string(),
+            community: "lethal-company".to_string(),
+            package: package.to_string(),
+            version: version.to_string(),
+            checksum: Checksum::default(),
+        }
+    }
+
+    #[test]
+    fn test_plugin_source_checksum() {
+        let sources: Vec<PluginSource> = serde_yaml::from_str(
+            "- Owner/Simple\n- package: Owner/Pinned\n  version: \"1.0.0\"\n  sha256: abc\n  size: 42\n",
+        )
+        .unwrap();
+        assert_eq!(sources[0].checksum, Checksum::default());
+        assert_eq!(sources[1].checksum.sha256.as_deref(), Some("abc"));
+        assert_eq!(sources[1].checksum.size, Some(42));
+    }
+
+    #[test]
+    fn test_parse_dependency() {
+        let parent = source("Owner/Plugin", "1.0.0");
+        let dep = parse_dependency("Evaisa-LethalLib-0.16.1", &parent).unwrap();
+        assert_eq!(dep, source("Evaisa/LethalLib", "0.16.1"));
+
+        // Underscores are allowed in names, hyphens only separate the parts
+        let dep = parse_dependency("Sigurd-CSync_Lib-5.0.1", &parent).unwrap();
+        assert_eq!(dep.package, "Sigurd/CSync_Lib");
+        assert!(parse_dependency("NoVersion", &parent).is_none());
+        assert!(parse_dependency("-Name-1.0.0", &parent).is_none());
+    }
+
+    #[test]
+    fn test_mod_loader_and_versions() {
+        assert!(is_mod_loader("BepInEx/BepInExPack"));
+        assert!(is_mod_loader("denikson/BepInExPack_Valheim"));
+        assert!(!is_mod_loader("Evaisa/LethalLib"));
+
+        assert_eq!(compare_versions("1.10.0", "1.9.2"), Ordering::Greater);
+        assert_eq!(compare_versions("2.0", "2.0.0"), Ordering::Equal);
+        assert_eq!(compare_versions("0.15.0", "0.16.1"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_install_order_puts_dependencies_first() {
+        // Plugin -> (Lib, Api), Api -> Lib, Lib -> Plugin (cycle)
+        let chosen: HashMap<String, PluginSource> = [
+            source("Owner/Plugin", "1.0.0"),
+            source("Owner/Api", "2.0.0"),
+            source("Owner/Lib", "1.1.0"),
+            source("Owner/Unused", "1.0.0"),
+        ]
+        .into_iter()
+        .map(|s| (s.package.clone(), s))
+        .collect();
+        let dependencies: HashMap<String, Vec<String>> = [
+            ("Owner/Plugin@1.0.0", vec!["Owner/Lib", "Owner/Api"]),
+            ("Owner/Api@2.0.0", vec!["Owner/Lib"]),
+            ("Owner/Lib@1.1.0", vec!["Owner/Plugin"]),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.into_iter().map(String::from).collect()))
+        .collect();
+
+        let order: Vec<String> = install_order("Owner/Plugin", &chosen, &dependencies)
+            .iter()
+            .map(|s| s.display_name())
+            .collect();
+        assert_eq!(order, vec!["Owner/Lib@1.1.0", "Owner/Api@2.0.0", "Owner/Plugin@1.0.0"]);
+    }
 }
@@ -1,5 +1,6 @@
 //! Mod/plugin source types
 
+use crate::integrity::Checksum;
 use serde::{Deserialize, Deserializer, Serialize};
 use std::error::Error;
 use std::path::{Path, PathBuf};
@@ -16,6 +17,8 @@ use std::path::{Path, PathBuf};
 /// plugins:
 ///   - package: gabrielgad/NebulaMultiplayerMod
 ///     version: "0.9.19"  # Optional, fetches latest if omitted
+///     sha256: "..."      # Optional, checked before extraction
+///     size: 123456       # Optional, archive size in bytes
 /// ```
 #[derive(Clone, Debug, Default, PartialEq, Serialize)]
 pub struct PluginSource {
@@ -36,6 +39,18 @@ pub struct PluginSource {
     /// Version string (e.g., "1.4.0", or empty for latest)
     #[serde(default)]
     pub version: String,
+
+    /// Size and SHA-256 of the downloaded archive
+    /// Only checked for pinned versions, since "latest" changes over time
+    #[serde(flatten)]
+    pub checksum: Checksum,
+}
+
+/// A fetched package and the files extracted from it
+#[derive(Clone, Debug)]
+pub struct FetchedPlugin {
+    pub source: PluginSource,
+    pub files: Vec<PathBuf>,
 }
 
 impl<'de> Deserialize<'de> for PluginSource {
@@ -65,6 +80,7 @@ impl<'de> Deserialize<'de> for PluginSource {
                     community: String::new(),
                     package: value.to_string(),
                     version: String::new(),
+                    checksum: Checksum::default(),
                 })
             }
 
@@ -77,6 +93,7 @@ impl<'de> Deserialize<'de> for PluginSource {
                 let mut community = None;
                 let mut package = None;
                 let mut version = None;
+                let mut checksum = Checksum::default();
 
                 while let Some(key) = map.next_key::<String>()? {
                     match key.as_str() {
@@ -84,6 +101,8 @@ impl<'de> Deserialize<'de> for PluginSource {
                         "community" => community = Some(map.next_value()?),
                         "package" => package = Some(map.next_value()?),
                         "version" => version = Some(map.next_value()?),
+                        "sha256" => checksum.sha256 = map.next_value()?,
+                        "size" => checksum.size = map.next_value()?,
                         _ => {
                             // Skip unknown fields
                             let _ = map.next_value::<serde::de::IgnoredAny>()?;
@@ -96,6 +115,7 @@ impl<'de> Deserialize<'de> for PluginSource {
                     community: community.unwrap_or_default(),
                     package: package.unwrap_or_default(),
                     version: version.unwrap_or_default(),
+                    checksum,
                 })
             }
         }
@@ -114,8 +134,9 @@ impl PluginSource {
     ///
     /// - Inherits community from default_community if not specified
     /// - Defaults source to "thunderstore" if not specified
-    /// - Fetches latest version from API if version is empty
-    pub fn resolve(&self, default_community: &str, _cache_base: &Path) -> Result<Self, Box<dyn Error>> {
+    /// - Fetches latest version from API if version is empty, falling back to
+    ///   a cached version when the API can't be reached (or offline mode is on)
+    pub fn resolve(&self, default_community: &str, cache_base: &Path) -> Result<Self, Box<dyn Error>> {
         let source = if self.source.is_empty() {
             "thunderstore".to_string()
         } else {
@@ -128,9 +149,29 @@ impl PluginSource {
             self.community.clone()
         };
 
+        // A checksum can't describe whatever "latest" resolves to
+        let checksum = if self.version.is_empty() {
+            Checksum::default()
+        } else {
+            self.checksum.clone()
+        };
+
         let version = if self.version.is_empty() {
             // Fetch latest version from Thunderstore API
-            fetch_latest_version(&community, &self.package)?
+            match fetch_latest_version(&community, &self.package) {
+                Ok(v) => v,
+                Err(e) => {
+                    let cached = super::thunderstore::get_cached_version(cache_base, &community, &self.package);
+                    let Some(cached) = cached else {
+                        return Err(e);
+                    };
+                    log_warn!(
+                        "mods: Failed to check latest {} version ({}), using cached {}",
+                        self.package, e, cached
+                    );
+                    cached
+                }
+            }
         } else {
             self.version.clone()
         };
@@ -140,6 +181,7 @@ impl PluginSource {
             community,
             package: self.package.clone(),
             version,
+            checksum,
         })
     }
 
@@ -197,7 +239,7 @@ fn fetch_latest_version(_community: &str, package: &str) -> Result<String, Box<d
         .timeout(std::time::Duration::from_secs(30))
         .build()?;
 
-    let response = client.get(&url).send()?;
+    let response = crate::net::fetch_with_retry(&client, &url, package, super::offline_mode())?;
 
     if !response.status().is_success() {
         return Err(format!("Failed to fetch package info: HTTP {}", response.status()).into());
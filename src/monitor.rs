@@ -31,10 +31,17 @@ fn get_monitors_niri() -> Option<Vec<Monitor>> {
             }
             let width = logical.get("width")?.as_u64()? as u32;
             let height = logical.get("height")?.as_u64()? as u32;
+            let x = logical.get("x").and_then(|x| x.as_i64()).unwrap_or(0) as i32;
+            let y = logical.get("y").and_then(|y| y.as_i64()).unwrap_or(0) as i32;
+            let scale = logical.get("scale").and_then(|s| s.as_f64()).unwrap_or(1.0) as f32;
+            let scale = if scale.is_finite() && scale > 0.0 { scale } else { 1.0 };
             monitors.push(Monitor {
                 name: connector.clone(),
-                width,
-                height,
+                x: (x as f32 * scale).round() as i32,
+                y: (y as f32 * scale).round() as i32,
+                width: (width as f32 * scale).round() as u32,
+                height: (height as f32 * scale).round() as u32,
+                scale,
             });
         }
     }
@@ -82,8 +89,13 @@ fn with_sdl_video<T>(f: impl FnOnce(&sdl2::VideoSubsystem) -> T) -> T {
 #[derive(Clone)]
 pub struct Monitor {
     name: String,
+    /// Position and size in physical pixels
+    x: i32,
+    y: i32,
     width: u32,
     height: u32,
+    /// Output scale (physical pixels per logical pixel)
+    scale: f32,
 }
 
 impl Monitor {
@@ -133,12 +145,56 @@ impl Monitor {
     pub fn height(&self) -> u32 {
         self.height
     }
+
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    pub fn logical_width(&self) -> u32 {
+        (self.width as f32 / self.scale).round() as u32
+    }
+
+    pub fn logical_height(&self) -> u32 {
+        (self.height as f32 / self.scale).round() as u32
+    }
+
+    /// Desktop rect as [x, y, width, height] in logical pixels
+    pub fn rect(&self) -> [f32; 4] {
+        [
+            (self.x as f32 / self.scale).round(),
+            (self.y as f32 / self.scale).round(),
+            self.logical_width() as f32,
+            self.logical_height() as f32,
+        ]
+    }
+}
+
+/// Where an instance's monitor is after the monitor list changed
+#[derive(Debug, PartialEq)]
+pub enum MonitorRemap {
+    /// Same output, possibly at a new index
+    Kept(usize),
+    /// The output is gone; moved to the nearest remaining index
+    Reassigned(usize),
+}
+
+/// Follow a monitor index across a list change by output name
+///
+/// `new_names` must not be empty.
+pub fn remap_monitor_index(old_names: &[&str], new_names: &[&str], index: usize) -> MonitorRemap {
+    if let Some(name) = old_names.get(index)
+        && let Some(new_index) = new_names.iter().position(|n| n == name)
+    {
+        return MonitorRemap::Kept(new_index);
+    }
+    MonitorRemap::Reassigned(index.min(new_names.len().saturating_sub(1)))
 }
 
 /// Get monitor information.
 ///
-/// On niri: Uses niri IPC directly (no SDL, no XWayland).
-/// On other compositors: Uses SDL2 with X11 backend.
+/// On niri: Uses niri IPC directly (no SDL, no XWayland), including output scale.
+/// On other compositors: Uses SDL2 with X11 backend, which doesn't report the
+/// compositor's output scale, so those monitors count as unscaled.
 pub fn get_monitors_sdl() -> Vec<Monitor> {
     // On niri, bypass SDL entirely to avoid XWayland issues
     if let Some(monitors) = get_monitors_niri() {
@@ -159,11 +215,40 @@ pub fn get_monitors_sdl() -> Vec<Monitor> {
 
                 monitors.push(Monitor {
                     name,
+                    x: bounds.x(),
+                    y: bounds.y(),
                     width: bounds.width(),
                     height: bounds.height(),
+                    scale: 1.0,
                 });
             }
         }
         monitors
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scaled_rect_is_logical_in_position_and_size() {
+        // A 4K output at 2x to the right of a 1080p one, as niri reports it
+        let monitor = Monitor { name: "HDMI-A-1".to_string(), x: 3840, y: 0, width: 3840, height: 2160, scale: 2.0 };
+        assert_eq!(monitor.rect(), [1920.0, 0.0, 1920.0, 1080.0]);
+    }
+
+    #[test]
+    fn remap_follows_outputs_by_name() {
+        let old = ["DP-1", "HDMI-A-1", "DP-2"];
+        // DP-1 unplugged: the others shift down but keep their output
+        let new = ["HDMI-A-1", "DP-2"];
+        assert_eq!(remap_monitor_index(&old, &new, 1), MonitorRemap::Kept(0));
+        assert_eq!(remap_monitor_index(&old, &new, 2), MonitorRemap::Kept(1));
+        assert_eq!(remap_monitor_index(&old, &new, 0), MonitorRemap::Reassigned(0));
+
+        // Last output unplugged: clamped into range
+        assert_eq!(remap_monitor_index(&old, &old[..2], 2), MonitorRemap::Reassigned(1));
+        assert_eq!(remap_monitor_index(&old, &old, 2), MonitorRemap::Kept(2));
+    }
+}
@@ -0,0 +1,214 @@
+// Network fetches with retries
+// Update checks, registry fetches, plugin and required mod downloads go through
+// `fetch_with_retry`, which retries connection errors, timeouts and
+// temporary server errors with exponential backoff and jitter, so a brief
+// network blip doesn't fail the whole operation. Other HTTP errors are
+// returned to the caller on the first try.
+
+use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use reqwest::blocking::{Client, Response};
+
+/// How often to try a request and how long to wait in between
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RetryPolicy {
+    /// Total tries, including the first
+    pub attempts: u32,
+    /// Wait before the first retry; doubled for each one after
+    pub base_delay: Duration,
+    /// Longest wait between tries, before jitter
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(8),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Wait before retry `retry` (1-based), with `jitter` in [0, 1) adding up to half again
+    pub fn delay(&self, retry: u32, jitter: f64) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        let delay = self.base_delay.saturating_mul(factor).min(self.max_delay);
+        delay.mul_f64(1.0 + jitter.clamp(0.0, 1.0) / 2.0)
+    }
+}
+
+/// Outcome of one failed try
+pub enum Attempt<E> {
+    /// Worth trying again (connection error, timeout, 429 or 5xx)
+    Retry(E),
+    /// Trying again won't help
+    Fail(E),
+}
+
+/// Run `attempt` until it succeeds, fails for good or runs out of tries
+///
+/// `sleep` waits between tries; the error after the last try names `what`
+/// and how many tries were made.
+pub fn retry<T, E: Display>(
+    policy: &RetryPolicy,
+    what: &str,
+    mut attempt: impl FnMut() -> Result<T, Attempt<E>>,
+    mut sleep: impl FnMut(Duration),
+) -> Result<T, String> {
+    let attempts = policy.attempts.max(1);
+    for n in 1..=attempts {
+        match attempt() {
+            Ok(value) => return Ok(value),
+            Err(Attempt::Fail(e)) => return Err(format!("Couldn't fetch {}: {}", what, e)),
+            Err(Attempt::Retry(e)) if n == attempts => {
+                return Err(format!("Couldn't fetch {} after {} attempts: {}", what, attempts, e));
+            }
+            Err(Attempt::Retry(e)) => {
+                let delay = policy.delay(n, fastrand::f64());
+                log_warn!("Fetching {} failed ({}), retrying in {:.1}s", what, e, delay.as_secs_f32());
+                sleep(delay);
+            }
+        }
+    }
+    unreachable!("the last attempt always returns")
+}
+
+/// Whether an HTTP status is temporary and worth retrying
+pub fn is_transient_status(status: u16) -> bool {
+    status == 408 || status == 429 || (500..600).contains(&status)
+}
+
+/// GET `url`, retrying transient failures with the default policy
+///
+/// Fails right away when `offline` (the in-memory offline mode setting) is
+/// set; this is the one place network fetches are refused. Responses with
+/// other error statuses (such as 404) are returned as-is for the caller to
+/// report.
+pub fn fetch_with_retry(client: &Client, url: &str, what: &str, offline: bool) -> Result<Response, String> {
+    fetch_with_retry_until(client, url, what, offline, &AtomicBool::new(false))
+}
+
+/// Like `fetch_with_retry`, but gives up as soon as `cancel` is set
+///
+/// The wait between tries is cut short too, so a cancelled download doesn't
+/// sit out the backoff first.
+pub fn fetch_with_retry_until(
+    client: &Client,
+    url: &str,
+    what: &str,
+    offline: bool,
+    cancel: &AtomicBool,
+) -> Result<Response, String> {
+    if offline {
+        return Err(format!("Offline mode is enabled; not fetching {}", what));
+    }
+    retry(
+        &RetryPolicy::default(),
+        what,
+        || {
+            if cancel.load(Ordering::Relaxed) {
+                return Err(Attempt::Fail("cancelled".to_string()));
+            }
+            match client.get(url).header("User-Agent", "splitux").send() {
+                Ok(response) if is_transient_status(response.status().as_u16()) => {
+                    Err(Attempt::Retry(format!("HTTP {}", response.status())))
+                }
+                Ok(response) => Ok(response),
+                Err(e) if e.is_builder() => Err(Attempt::Fail(e.to_string())),
+                Err(e) => Err(Attempt::Retry(e.to_string())),
+            }
+        },
+        |delay| sleep_unless_cancelled(delay, cancel),
+    )
+}
+
+/// Sleep for `delay` in short slices, returning early once `cancel` is set
+fn sleep_unless_cancelled(delay: Duration, cancel: &AtomicBool) {
+    const SLICE: Duration = Duration::from_millis(100);
+    let deadline = Instant::now() + delay;
+    while !cancel.load(Ordering::Relaxed) {
+        let left = deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            return;
+        }
+        std::thread::sleep(left.min(SLICE));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delay_doubles_up_to_the_cap() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.delay(1, 0.0), Duration::from_millis(500));
+        assert_eq!(policy.delay(2, 0.0), Duration::from_secs(1));
+        assert_eq!(policy.delay(3, 0.0), Duration::from_secs(2));
+        assert_eq!(policy.delay(10, 0.0), Duration::from_secs(8));
+        assert_eq!(policy.delay(1, 0.5), Duration::from_millis(625));
+    }
+
+    #[test]
+    fn retries_transient_errors_until_success() {
+        let mut calls = 0;
+        let mut sleeps = Vec::new();
+        let result = retry(
+            &RetryPolicy::default(),
+            "index",
+            || {
+                calls += 1;
+                if calls < 3 { Err(Attempt::Retry("timeout")) } else { Ok(calls) }
+            },
+            |d| sleeps.push(d),
+        );
+        assert_eq!(result, Ok(3));
+        assert_eq!(sleeps.len(), 2);
+    }
+
+    #[test]
+    fn gives_up_after_the_last_attempt() {
+        let mut calls = 0;
+        let result: Result<(), String> = retry(
+            &RetryPolicy { attempts: 4, ..RetryPolicy::default() },
+            "index",
+            || {
+                calls += 1;
+                Err(Attempt::Retry("HTTP 503"))
+            },
+            |_| {},
+        );
+        assert_eq!(calls, 4);
+        assert_eq!(result.unwrap_err(), "Couldn't fetch index after 4 attempts: HTTP 503");
+    }
+
+    #[test]
+    fn permanent_errors_are_not_retried() {
+        let mut calls = 0;
+        let result: Result<(), String> = retry(
+            &RetryPolicy::default(),
+            "index",
+            || {
+                calls += 1;
+                Err(Attempt::Fail("bad url"))
+            },
+            |_| {},
+        );
+        assert_eq!(calls, 1);
+        assert!(result.is_err());
+        assert!(is_transient_status(503) && is_transient_status(429));
+        assert!(!is_transient_status(404));
+    }
+
+    #[test]
+    fn cancel_cuts_the_wait_short() {
+        let cancel = AtomicBool::new(true);
+        let start = Instant::now();
+        sleep_unless_cancelled(Duration::from_secs(5), &cancel);
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+}
@@ -1,5 +1,6 @@
 use std::env;
-use std::path::PathBuf;
+use std::os::unix::fs::MetadataExt;
+use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
 
 pub static PATH_ASSETS: LazyLock<PathBuf> = LazyLock::new(|| {
@@ -31,24 +32,100 @@ pub static PATH_PARTY: LazyLock<PathBuf> = LazyLock::new(|| {
     PATH_LOCAL_SHARE.join("splitux")
 });
 
-pub static PATH_STEAM: LazyLock<PathBuf> = LazyLock::new(|| {
-    // Check for native Steam installation first
-    if PATH_LOCAL_SHARE.join("Steam").exists() {
-        PATH_LOCAL_SHARE.join("Steam")
-    } else if PATH_HOME.join(".steam/steam").exists() {
+/// Folder created inside a custom scratch location
+///
+/// The scratch directory is deleted wholesale on cleanup, so a chosen
+/// location is never used directly.
+pub const TMP_DIR_NAME: &str = "splitux-tmp";
+
+/// Default scratch directory: handler imports, downloads, small state files
+pub fn path_tmp() -> PathBuf {
+    PATH_PARTY.join("tmp")
+}
+
+/// Session scratch directory: `<dir>/splitux-tmp` for a chosen location,
+/// `path_tmp()` otherwise
+///
+/// Holds overlay mounts and work dirs, backend overlays and merged files.
+pub fn scratch_dir(custom: Option<&Path>) -> PathBuf {
+    custom.map(|dir| dir.join(TMP_DIR_NAME)).unwrap_or_else(path_tmp)
+}
+
+/// Check that a custom scratch location is absolute and writable
+pub fn validate_tmp_dir(dir: &Path) -> Result<(), String> {
+    if !dir.is_absolute() {
+        return Err(format!("{} is not an absolute path", dir.display()));
+    }
+    let tmp = dir.join(TMP_DIR_NAME);
+    std::fs::create_dir_all(&tmp).map_err(|e| format!("Can't create {}: {}", tmp.display(), e))?;
+    let probe = tmp.join(".write-test");
+    std::fs::write(&probe, b"").map_err(|e| format!("Can't write to {}: {}", tmp.display(), e))?;
+    let _ = std::fs::remove_file(&probe);
+    Ok(())
+}
+
+/// Whether two paths are on different filesystems
+///
+/// Paths that don't exist yet are judged by their nearest existing parent.
+/// Overlay upper dirs on another filesystem still work, but every copy-up
+/// becomes a full copy across devices. Unknown paths count as the same.
+pub fn on_different_filesystem(a: &Path, b: &Path) -> bool {
+    let device = |path: &Path| {
+        path.ancestors()
+            .find_map(|p| std::fs::metadata(p).ok())
+            .map(|m| m.dev())
+    };
+    match (device(a), device(b)) {
+        (Some(a), Some(b)) => a != b,
+        _ => false,
+    }
+}
+
+/// Steam install roots that exist: native first, then Flatpak
+///
+/// `~/.steam/steam` is usually a symlink to `~/.local/share/Steam`, so roots
+/// are deduplicated by their resolved path.
+pub static STEAM_ROOTS: LazyLock<Vec<PathBuf>> = LazyLock::new(|| {
+    let candidates = [
+        PATH_LOCAL_SHARE.join("Steam"),
         // Follow the symlink at ~/.steam/steam
-        PATH_HOME.join(".steam/steam")
-    } else if PATH_HOME
-        .join(".var/app/com.valvesoftware.Steam/.local/share/Steam")
-        .exists()
-    {
+        PATH_HOME.join(".steam/steam"),
         // Flatpak Steam
-        PATH_HOME.join(".var/app/com.valvesoftware.Steam/.local/share/Steam")
-    } else {
-        PATH_LOCAL_SHARE.join("Steam")
+        PATH_HOME.join(".var/app/com.valvesoftware.Steam/.local/share/Steam"),
+    ];
+
+    let mut roots: Vec<PathBuf> = Vec::new();
+    let mut resolved: Vec<PathBuf> = Vec::new();
+    for candidate in candidates {
+        let Ok(real) = candidate.canonicalize() else {
+            continue;
+        };
+        if real.is_dir() && !resolved.contains(&real) {
+            resolved.push(real);
+            roots.push(candidate);
+        }
     }
+    roots
 });
 
+pub static PATH_STEAM: LazyLock<PathBuf> = LazyLock::new(|| {
+    STEAM_ROOTS
+        .first()
+        .cloned()
+        .unwrap_or_else(|| PATH_LOCAL_SHARE.join("Steam"))
+});
+
+/// Steam root holding an app's data, for when native and Flatpak Steam coexist
+///
+/// Returns the first root for which `has_app` is true, falling back to `PATH_STEAM`.
+pub fn steam_root_for(has_app: impl Fn(&Path) -> bool) -> PathBuf {
+    STEAM_ROOTS
+        .iter()
+        .find(|root| has_app(root))
+        .cloned()
+        .unwrap_or_else(|| PATH_STEAM.clone())
+}
+
 pub static BIN_UMU_RUN: LazyLock<PathBuf> = LazyLock::new(|| {
     let bin_candidates = [
         PATH_HOME.join(".local/bin"),
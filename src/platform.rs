@@ -87,6 +87,6 @@ mod manual;
 mod steam;
 
 pub use manual::ManualPlatform;
-pub use steam::SteamPlatform;
+pub use steam::{find_app as find_steam_app, SteamPlatform};
 
 // Re-export steam submodule functions for convenience
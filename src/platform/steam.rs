@@ -10,6 +10,7 @@ mod cache;
 mod locate;
 
 // Re-export submodule functions for direct access if needed
+pub use locate::find_app;
 
 /// Steam platform implementation
 pub struct SteamPlatform {
@@ -4,17 +4,21 @@
 //! {STEAM}/appcache/librarycache/{appid}/{hash}/{filename}
 //! or directly as: {STEAM}/appcache/librarycache/{appid}/{filename}
 
-use crate::paths::PATH_STEAM;
+use crate::paths::steam_root_for;
 use std::path::PathBuf;
 
+/// The app's librarycache folder, from whichever Steam install has one
+fn app_cache_dir(app_id: u32) -> PathBuf {
+    let rel = PathBuf::from("appcache/librarycache").join(app_id.to_string());
+    steam_root_for(|root| root.join(&rel).is_dir()).join(rel)
+}
+
 /// Find a file in Steam's librarycache for an app
 ///
 /// Steam stores files in: {STEAM}/appcache/librarycache/{appid}/{hash}/{filename}
 /// or directly as: {STEAM}/appcache/librarycache/{appid}/{filename}
 pub fn find_cache_file(app_id: u32, filename: &str) -> Option<PathBuf> {
-    let app_cache = PATH_STEAM
-        .join("appcache/librarycache")
-        .join(app_id.to_string());
+    let app_cache = app_cache_dir(app_id);
 
     if !app_cache.exists() {
         return None;
@@ -47,9 +51,7 @@ pub fn find_cache_file(app_id: u32, filename: &str) -> Option<PathBuf> {
 /// directly in the app folder (not in subfolders).
 /// Skip files named library_*, header*, logo* as those are other artwork.
 pub fn find_icon(app_id: u32) -> Option<PathBuf> {
-    let app_cache = PATH_STEAM
-        .join("appcache/librarycache")
-        .join(app_id.to_string());
+    let app_cache = app_cache_dir(app_id);
 
     if !app_cache.exists() {
         return None;
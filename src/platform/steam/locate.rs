@@ -1,18 +1,33 @@
 //! Steam game path resolution
 //!
-//! Uses steamlocate crate to find installed Steam games.
+//! Uses steamlocate crate to find installed Steam games, checking every
+//! Steam install (native and Flatpak) so either can hold the game.
 
 use std::error::Error;
 use std::path::PathBuf;
 
+use steamlocate::{App, Library, SteamDir};
+
+use crate::paths::STEAM_ROOTS;
+
+/// Find an installed app in any Steam install
+///
+/// Known roots are checked in order (native first), then steamlocate's own
+/// search as a fallback for other layouts such as Snap.
+pub fn find_app(app_id: u32) -> Option<(App, Library)> {
+    STEAM_ROOTS
+        .iter()
+        .filter_map(|root| SteamDir::from_dir(root).ok())
+        .chain(SteamDir::locate().ok())
+        .find_map(|dir| dir.find_app(app_id).ok().flatten())
+}
+
 /// Find the installation path for a Steam game by app ID
 ///
-/// Uses steamlocate to search all Steam library folders for the app.
+/// Searches all Steam library folders of every install for the app.
 /// Returns the resolved app directory path if found.
 pub fn find_game_path(app_id: u32) -> Result<PathBuf, Box<dyn Error>> {
-    let steam_dir = steamlocate::SteamDir::locate()?;
-
-    if let Some((app, library)) = steam_dir.find_app(app_id).ok().flatten() {
+    if let Some((app, library)) = find_app(app_id) {
         let path = library.resolve_app_dir(&app);
         if path.exists() {
             return Ok(path);
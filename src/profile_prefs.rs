@@ -1,7 +1,9 @@
 // Profile preferences module
-// Stores per-profile settings like preferred controller and audio device
+// Stores per-profile settings like preferred controller, audio device and
+// per-game keyboard/mouse (gptokeyb) profile
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 use crate::paths::PATH_PARTY;
@@ -26,6 +28,11 @@ pub struct ProfilePreferences {
     /// Human-readable name of the preferred audio device (for display purposes)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub preferred_audio_name: Option<String>,
+
+    /// Preferred gptokeyb profile per game (handler directory name -> profile name)
+    /// An empty profile name means gptokeyb is disabled for that game
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub preferred_gptokeyb: HashMap<String, String>,
 }
 
 impl ProfilePreferences {
@@ -86,4 +93,20 @@ impl ProfilePreferences {
         self.preferred_audio = None;
         self.preferred_audio_name = None;
     }
+
+    /// Get the preferred gptokeyb profile for a game
+    pub fn gptokeyb_for(&self, handler_key: &str) -> Option<&String> {
+        self.preferred_gptokeyb.get(handler_key)
+    }
+
+    /// Set the preferred gptokeyb profile for a game
+    pub fn set_gptokeyb(&mut self, handler_key: &str, profile: &str) {
+        self.preferred_gptokeyb
+            .insert(handler_key.to_string(), profile.to_string());
+    }
+
+    /// Clear the preferred gptokeyb profile for a game
+    pub fn clear_gptokeyb(&mut self, handler_key: &str) {
+        self.preferred_gptokeyb.remove(handler_key);
+    }
 }
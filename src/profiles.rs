@@ -1,6 +1,9 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::error::Error;
 use std::os::unix::fs::symlink;
-use std::path::PathBuf;
+use std::os::unix::io::AsRawFd;
+use std::path::{Path, PathBuf};
 
 use crate::{handler::Handler, paths::*, util::copy_dir_recursive};
 
@@ -13,10 +16,11 @@ fn hash_name(name: &str) -> u64 {
     hash
 }
 
-// Generate a unique Steam64 ID based on the profile name
 // Steam64 IDs start at 76561197960265728 (base) + account_id
+const STEAM64_BASE: u64 = 76561197960265728;
+
+// Generate a unique Steam64 ID based on the profile name
 pub fn generate_steam_id(name: &str) -> u64 {
-    const STEAM64_BASE: u64 = 76561197960265728;
     // Limit to valid account ID range (roughly 0 to 1 billion)
     let account_id = (hash_name(name) % 1_000_000_000) + 1;
     STEAM64_BASE + account_id
@@ -30,13 +34,60 @@ pub fn generate_listen_port(name: &str) -> u16 {
     BASE_PORT + (hash_name(name) % PORT_RANGE as u64) as u16
 }
 
+/// Check a user-chosen profile name (letters, numbers, underscores, hyphens; 1-32 chars)
+pub fn validate_profile_name(name: &str) -> Result<(), String> {
+    if !name.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-') {
+        return Err("Profile name must contain only letters, numbers, underscores, or hyphens".to_string());
+    }
+    if name.is_empty() || name.len() > 32 {
+        return Err("Profile name must be 1-32 characters".to_string());
+    }
+    Ok(())
+}
+
+/// Write the Goldberg user and main settings for a profile name
+///
+/// Returns the profile's Steam ID and listen port.
+fn write_steam_settings(path_steam: &Path, name: &str) -> Result<(u64, u16), std::io::Error> {
+    let steam_id = generate_steam_id(name);
+    let listen_port = generate_listen_port(name);
+
+    // User settings (account name and Steam ID)
+    let usersettings = format!(
+        "[user::general]\naccount_name={name}\naccount_steamid={steam_id}"
+    );
+    std::fs::write(path_steam.join("configs.user.ini"), usersettings)?;
+
+    // Main settings (unique listen port for LAN multiplayer)
+    let mainsettings = format!(
+        r#"[main::general]
+new_app_ticket=1
+gc_token=1
+matchmaking_server_list_actual_type=0
+matchmaking_server_details_via_source_query=0
+
+[main::connectivity]
+disable_lan_only=0
+disable_networking=0
+listen_port={listen_port}
+offline=0
+disable_lobby_creation=0
+disable_source_query=0
+share_leaderboards_over_network=0
+"#
+    );
+    std::fs::write(path_steam.join("configs.main.ini"), mainsettings)?;
+
+    Ok((steam_id, listen_port))
+}
+
 // Makes a folder and sets up Goldberg Steam Emu profile for Steam games
 pub fn create_profile(name: &str) -> Result<(), std::io::Error> {
     if PATH_PARTY.join(format!("profiles/{name}")).exists() {
         return Ok(());
     }
 
-    println!("[splitux] Creating profile {name}");
+    log_info!("Creating profile {name}");
     let path_profile = PATH_PARTY.join(format!("profiles/{name}"));
     // Goldberg expects settings in {GseAppPath}/steam_settings/
     let path_steam = path_profile.join("steam/steam_settings");
@@ -71,41 +122,14 @@ pub fn create_profile(name: &str) -> Result<(), std::io::Error> {
         }
     }
 
-    // Generate unique Steam ID and listen port for this profile
-    let steam_id = generate_steam_id(name);
-    let listen_port = generate_listen_port(name);
-
-    // User settings (account name and Steam ID)
-    let usersettings = format!(
-        "[user::general]\naccount_name={name}\naccount_steamid={steam_id}"
-    );
-    std::fs::write(path_steam.join("configs.user.ini"), usersettings)?;
-
-    // Main settings (unique listen port for LAN multiplayer)
-    let mainsettings = format!(
-        r#"[main::general]
-new_app_ticket=1
-gc_token=1
-matchmaking_server_list_actual_type=0
-matchmaking_server_details_via_source_query=0
-
-[main::connectivity]
-disable_lan_only=0
-disable_networking=0
-listen_port={listen_port}
-offline=0
-disable_lobby_creation=0
-disable_source_query=0
-share_leaderboards_over_network=0
-"#
-    );
-    std::fs::write(path_steam.join("configs.main.ini"), mainsettings)?;
+    // Unique Steam ID and listen port for this profile
+    let (steam_id, listen_port) = write_steam_settings(&path_steam, name)?;
 
     // Auto-accept and auto-send invites for seamless multiplayer
     std::fs::write(path_steam.join("auto_accept_invite.txt"), "")?;
     std::fs::write(path_steam.join("auto_send_invite.txt"), "")?;
 
-    println!("[splitux] Profile created: Steam ID {steam_id}, Port {listen_port}");
+    log_info!("Profile created: Steam ID {steam_id}, Port {listen_port}");
     Ok(())
 }
 
@@ -120,11 +144,11 @@ pub fn create_profile_gamesave(name: &str, h: &Handler) -> Result<(), Box<dyn Er
     if path_gamesave.exists() {
         return Ok(());
     }
-    println!("[splitux] Creating game save {} for {}", uid, name);
+    log_info!("Creating game save {} for {}", uid, name);
 
     std::fs::create_dir_all(&path_gamesave)?;
     
-    if let Some(appid) = h.steam_appid && h.use_goldberg {
+    if let Some(appid) = h.steam_appid && h.has_goldberg() {
         let path_exec = path_gamesave.join(&h.exec);
         let path_execdir = path_exec.parent().ok_or_else(|| "couldn't get parent")?;
         if !path_execdir.exists() {
@@ -148,7 +172,7 @@ pub fn create_profile_gamesave(name: &str, h: &Handler) -> Result<(), Box<dyn Er
         copy_dir_recursive(&profile_copy_windata, &path_windata)?;
     }
 
-    println!("[splitux] Profile save data created successfully");
+    log_info!("Profile save data created successfully");
     Ok(())
 }
 
@@ -179,13 +203,7 @@ pub fn scan_profiles(include_guest: bool) -> Vec<String> {
 
 /// Rename a profile (moves directory and updates Steam settings)
 pub fn rename_profile(old_name: &str, new_name: &str) -> Result<(), Box<dyn Error>> {
-    // Validate new name (alphanumeric, underscores, hyphens only)
-    if !new_name.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-') {
-        return Err("Profile name must contain only letters, numbers, underscores, or hyphens".into());
-    }
-    if new_name.is_empty() || new_name.len() > 32 {
-        return Err("Profile name must be 1-32 characters".into());
-    }
+    validate_profile_name(new_name)?;
 
     let old_path = PATH_PARTY.join("profiles").join(old_name);
     let new_path = PATH_PARTY.join("profiles").join(new_name);
@@ -203,39 +221,94 @@ pub fn rename_profile(old_name: &str, new_name: &str) -> Result<(), Box<dyn Erro
     // Update Steam settings with new account name and regenerated IDs
     let path_steam = new_path.join("steam/steam_settings");
     if path_steam.exists() {
-        let steam_id = generate_steam_id(new_name);
-        let listen_port = generate_listen_port(new_name);
+        write_steam_settings(&path_steam, new_name)?;
+    }
 
-        let usersettings = format!(
-            "[user::general]\naccount_name={new_name}\naccount_steamid={steam_id}"
-        );
-        std::fs::write(path_steam.join("configs.user.ini"), usersettings)?;
+    log_info!("Profile renamed: {} -> {}", old_name, new_name);
+    Ok(())
+}
 
-        // Update listen port in main settings
-        let mainsettings = format!(
-            r#"[main::general]
-new_app_ticket=1
-gc_token=1
-matchmaking_server_list_actual_type=0
-matchmaking_server_details_via_source_query=0
+/// Overlay work dir kept in the profile when the scratch directory is on
+/// another filesystem
+pub const OVERLAY_WORK_DIR: &str = "overlay-work";
 
-[main::connectivity]
-disable_lan_only=0
-disable_networking=0
-listen_port={listen_port}
-offline=0
-disable_lobby_creation=0
-disable_source_query=0
-share_leaderboards_over_network=0
-"#
-        );
-        std::fs::write(path_steam.join("configs.main.ini"), mainsettings)?;
+/// Entries describing the profile itself or a session rather than its games; not cloned
+const PROFILE_OWN_FILES: &[&str] = &["preferences.json", "playtime.json", OVERLAY_WORK_DIR];
+
+/// Copy a profile's saves and game settings into a new profile
+///
+/// The copy gets its own Steam ID and listen port, and starts without the
+/// source's launcher preferences or playtime. Files and folders named after
+/// the source's Steam ID (some games keep saves per user ID) are renamed to
+/// the new ID; file contents are left as they are.
+pub fn clone_profile(src: &str, dst: &str) -> Result<(), Box<dyn Error>> {
+    validate_profile_name(dst)?;
+
+    let src_path = PATH_PARTY.join("profiles").join(src);
+    let dst_path = PATH_PARTY.join("profiles").join(dst);
+
+    if !src_path.exists() {
+        return Err(format!("Profile '{}' does not exist", src).into());
+    }
+    if dst_path.exists() {
+        return Err(format!("A profile named '{}' already exists", dst).into());
+    }
+
+    if let Err(e) = copy_profile_data(&src_path, &dst_path, src, dst) {
+        // Don't leave a half-copied profile behind
+        let _ = std::fs::remove_dir_all(&dst_path);
+        return Err(e);
     }
 
-    println!("[splitux] Profile renamed: {} -> {}", old_name, new_name);
+    log_info!("Profile cloned: {} -> {}", src, dst);
     Ok(())
 }
 
+fn copy_profile_data(src_path: &PathBuf, dst_path: &PathBuf, src: &str, dst: &str) -> Result<(), Box<dyn Error>> {
+    std::fs::create_dir_all(dst_path)?;
+    for entry in std::fs::read_dir(src_path)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        if PROFILE_OWN_FILES.iter().any(|own| name == *own) {
+            continue;
+        }
+        if entry.file_type()?.is_dir() {
+            copy_dir_recursive(&entry.path(), &dst_path.join(&name))?;
+        } else {
+            std::fs::copy(entry.path(), dst_path.join(&name))?;
+        }
+    }
+
+    let path_steam = dst_path.join("steam/steam_settings");
+    if path_steam.exists() {
+        write_steam_settings(&path_steam, dst)?;
+    }
+
+    let (old_id, new_id) = (generate_steam_id(src), generate_steam_id(dst));
+    // Children first, so renaming a folder doesn't invalidate paths below it
+    for entry in walkdir::WalkDir::new(dst_path).min_depth(1).contents_first(true).follow_links(false) {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy();
+        if let Some(renamed) = steam_id_renamed(&name, old_id, new_id) {
+            log_info!("Renaming {} for the new Steam ID", entry.path().display());
+            std::fs::rename(entry.path(), entry.path().with_file_name(renamed))?;
+        }
+    }
+    Ok(())
+}
+
+/// New file name for an entry named after the old Steam64 or account ID
+fn steam_id_renamed(name: &str, old_id: u64, new_id: u64) -> Option<String> {
+    let (old_account, new_account) = (old_id - STEAM64_BASE, new_id - STEAM64_BASE);
+    if name.contains(&old_id.to_string()) {
+        Some(name.replace(&old_id.to_string(), &new_id.to_string()))
+    } else if name == old_account.to_string() {
+        Some(new_account.to_string())
+    } else {
+        None
+    }
+}
+
 /// Delete a profile (removes entire directory)
 pub fn delete_profile(name: &str) -> Result<(), Box<dyn Error>> {
     let path = PATH_PARTY.join("profiles").join(name);
@@ -245,7 +318,7 @@ pub fn delete_profile(name: &str) -> Result<(), Box<dyn Error>> {
     }
 
     std::fs::remove_dir_all(&path)?;
-    println!("[splitux] Profile deleted: {}", name);
+    log_info!("Profile deleted: {}", name);
     Ok(())
 }
 
@@ -267,9 +340,124 @@ pub fn remove_guest_profiles() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// Accumulated playtime of a profile
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PlayStats {
+    /// Seconds played per game (handler directory name)
+    #[serde(default)]
+    pub games: HashMap<String, u64>,
+}
+
+impl PlayStats {
+    pub fn path(profile_name: &str) -> PathBuf {
+        PATH_PARTY.join("profiles").join(profile_name).join("playtime.json")
+    }
+
+    /// Load a profile's stats; empty if none were recorded or the file is unreadable
+    pub fn load(profile_name: &str) -> Self {
+        std::fs::read_to_string(Self::path(profile_name))
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn total(&self) -> u64 {
+        self.games.values().sum()
+    }
+
+    /// Games by playtime, longest first
+    pub fn by_game(&self) -> Vec<(&str, u64)> {
+        let mut games: Vec<(&str, u64)> = self.games.iter().map(|(g, s)| (g.as_str(), *s)).collect();
+        games.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        games
+    }
+
+    /// Add a session's playtime to a profile's stats
+    ///
+    /// The read-modify-write holds an exclusive lock on the stats file, so
+    /// sessions ending at the same time can't lose each other's time, and
+    /// the new stats replace the old file in one rename.
+    pub fn record(profile_name: &str, game: &str, secs: u64) -> std::io::Result<()> {
+        let path = Self::path(profile_name);
+        let lock = std::fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(path.with_extension("lock"))?;
+        if unsafe { libc::flock(lock.as_raw_fd(), libc::LOCK_EX) } != 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+
+        let mut stats = Self::load(profile_name);
+        *stats.games.entry(game.to_string()).or_default() += secs;
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, serde_json::to_string_pretty(&stats)?)?;
+        std::fs::rename(&tmp, &path)
+        // The lock is released when `lock` is dropped
+    }
+}
+
+/// Playtime in a short human form, e.g. "3h 12m"
+pub fn format_playtime(secs: u64) -> String {
+    let hours = secs / 3600;
+    let minutes = (secs % 3600) / 60;
+    match (hours, minutes) {
+        (0, 0) => "<1m".to_string(),
+        (0, m) => format!("{}m", m),
+        (h, m) => format!("{}h {}m", h, m),
+    }
+}
+
 pub static GUEST_NAMES: [&str; 33] = [
     "Blinky", "Pinky", "Inky", "Clyde", "Beatrice", "Battler", "Miyao", "Rena", "Ellie", "Joel",
     "Leon", "Ada", "Madeline", "Theo", "Yokatta", "Wyrm", "Brodiee", "Supreme", "Conk", "Gort",
     "Lich", "Smores", "Canary", "Trico", "Yorda", "Wander", "Agro", "Jak", "Daxter", "Soap",
     "Ghost", "Tomi", "Masaki",
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profile_names_are_validated() {
+        assert!(validate_profile_name("Kid_2").is_ok());
+        assert!(validate_profile_name("my-profile").is_ok());
+        assert!(validate_profile_name("").is_err());
+        assert!(validate_profile_name("../escape").is_err());
+        assert!(validate_profile_name(&"a".repeat(33)).is_err());
+    }
+
+    #[test]
+    fn playtime_is_formatted() {
+        assert_eq!(format_playtime(0), "<1m");
+        assert_eq!(format_playtime(59), "<1m");
+        assert_eq!(format_playtime(45 * 60), "45m");
+        assert_eq!(format_playtime(3 * 3600 + 12 * 60 + 30), "3h 12m");
+        assert_eq!(format_playtime(3600), "1h 0m");
+    }
+
+    #[test]
+    fn play_stats_sort_longest_first() {
+        let stats = PlayStats {
+            games: HashMap::from([("a".to_string(), 60), ("b".to_string(), 600), ("c".to_string(), 60)]),
+        };
+        assert_eq!(stats.total(), 720);
+        assert_eq!(stats.by_game(), vec![("b", 600), ("a", 60), ("c", 60)]);
+    }
+
+    #[test]
+    fn steam_id_named_entries_are_renamed() {
+        let (old_id, new_id) = (generate_steam_id("alice"), generate_steam_id("bob"));
+        let old_account = (old_id - STEAM64_BASE).to_string();
+        let new_account = (new_id - STEAM64_BASE).to_string();
+
+        assert_eq!(steam_id_renamed(&old_id.to_string(), old_id, new_id), Some(new_id.to_string()));
+        assert_eq!(
+            steam_id_renamed(&format!("save_{}.dat", old_id), old_id, new_id),
+            Some(format!("save_{}.dat", new_id))
+        );
+        assert_eq!(steam_id_renamed(&old_account, old_id, new_id), Some(new_account));
+        assert_eq!(steam_id_renamed("Saved Games", old_id, new_id), None);
+    }
+}
@@ -3,8 +3,9 @@
 //! This module handles configuring environment variables and paths for running
 //! Windows games through Proton/Wine.
 
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
-use std::process::Command;
+use std::process::{Command, Stdio};
 
 use crate::app::SplituxConfig;
 use crate::handler::Handler;
@@ -32,15 +33,12 @@ pub fn setup_env(
     let path_pfx = get_prefix_path(cfg, instance_idx);
 
     // Proton version to use
-    let protonpath = match cfg.proton_version.is_empty() {
-        true => "GE-Proton",
-        false => &cfg.proton_version,
-    };
+    let protonpath = resolve_proton_version(handler, cfg);
 
     // Core Proton environment
     cmd.env("WINEPREFIX", &path_pfx);
     cmd.env("PROTON_VERB", "waitforexitandrun");
-    cmd.env("PROTONPATH", protonpath);
+    cmd.env("PROTONPATH", &protonpath);
 
     // Steam compatibility paths
     // Always use splitux's prefix for STEAM_COMPAT_DATA_PATH to avoid conflicts
@@ -48,6 +46,13 @@ pub fn setup_env(
     cmd.env("STEAM_COMPAT_DATA_PATH", &path_pfx);
     cmd.env("STEAM_COMPAT_CLIENT_INSTALL_PATH", &*PATH_STEAM);
 
+    // Lets umu-run apply per-store fixes; direct Proton doesn't read it
+    if !uses_direct_proton(handler)
+        && let Some(store) = handler.store
+    {
+        cmd.env("STORE", store.umu_store());
+    }
+
     // Steam App IDs (required for some games/Proton features)
     if let Some(appid) = handler.get_steam_appid() {
         cmd.env("SteamAppId", appid.to_string());
@@ -55,6 +60,278 @@ pub fn setup_env(
     }
 }
 
+/// Build the WINEDLLOVERRIDES value from backend and handler overrides
+///
+/// DLL names are compared case-insensitively (Wine does too) and written in
+/// lowercase. A backend override wins over a handler entry for the same DLL,
+/// since the backend won't work without it. Returns None when there is
+/// nothing to override.
+pub fn merge_dll_overrides(
+    required: &[(&str, &str)],
+    handler_overrides: &HashMap<String, String>,
+) -> Option<String> {
+    let mut merged: BTreeMap<String, String> = BTreeMap::new();
+    for (dll, mode) in handler_overrides {
+        let dll = dll.trim().to_lowercase();
+        if !dll.is_empty() {
+            merged.insert(dll, mode.replace(' ', ""));
+        }
+    }
+    for (dll, mode) in required {
+        let dll = dll.to_lowercase();
+        if let Some(previous) = merged.insert(dll.clone(), mode.to_string())
+            && previous != *mode
+        {
+            log_warn!(
+                "Handler DLL override {}={} replaced by {}={}, which the backend needs",
+                dll, previous, dll, mode
+            );
+        }
+    }
+
+    if merged.is_empty() {
+        return None;
+    }
+    Some(
+        merged
+            .iter()
+            .map(|(dll, mode)| format!("{}={}", dll, mode))
+            .collect::<Vec<_>>()
+            .join(";"),
+    )
+}
+
+/// Warning for a DLL override Wine won't understand
+///
+/// The mode is empty (disabled) or a comma-separated list of n (native) and
+/// b (builtin), e.g. "n,b".
+pub fn check_dll_override(dll: &str, mode: &str) -> Option<String> {
+    let dll = dll.trim();
+    if dll.is_empty() || dll.contains(['=', ';', ',']) {
+        return Some(format!("'{}' is not a DLL name", dll));
+    }
+    let mode = mode.replace(' ', "");
+    if !mode.is_empty() && !mode.split(',').all(|m| m == "n" || m == "b") {
+        return Some(format!(
+            "{}: mode '{}' should be n, b, a comma-separated order like n,b, or empty to disable",
+            dll, mode
+        ));
+    }
+    None
+}
+
+/// Resolve the Proton version passed to umu-run as PROTONPATH
+///
+/// A handler's `proton_version` takes precedence over the global setting when
+/// it is installed. Otherwise we warn and fall back to the global version.
+pub fn resolve_proton_version(handler: &Handler, cfg: &SplituxConfig) -> String {
+    if handler.win() && !handler.proton_version.is_empty() {
+        if let Some(proton_bin) = resolve_proton_path(&handler.proton_version)
+            && let Some(dir) = proton_bin.parent()
+        {
+            return dir.to_string_lossy().to_string();
+        }
+        log_warn!(
+            "Proton version '{}' from handler is not installed, using global setting",
+            handler.proton_version
+        );
+    }
+
+    if !cfg.proton_version.is_empty() {
+        return cfg.proton_version.clone();
+    }
+
+    // Nothing chosen here: use whatever Steam runs this game with
+    if let Some(appid) = handler.get_steam_appid()
+        && let Some(dir) = steam_compat_tool_dir(appid)
+    {
+        return dir.to_string_lossy().to_string();
+    }
+
+    "GE-Proton".to_string()
+}
+
+/// Installed Proton folder Steam is set to use for an app, if any
+///
+/// Reads the app's entry in the CompatToolMapping of Steam's config.vdf
+/// (Properties > Compatibility). A mapped tool that isn't installed is
+/// skipped with a warning.
+pub fn steam_compat_tool_dir(appid: u32) -> Option<PathBuf> {
+    let config = std::fs::read_to_string(PATH_STEAM.join("config/config.vdf")).ok()?;
+    let tool = compat_tool_for_app(&config, appid)?;
+
+    let found = resolve_proton_path(compat_tool_dir_name(&tool))
+        .or_else(|| custom_compat_tool_dir(&tool).map(|dir| dir.join("proton")))
+        .and_then(|bin| bin.parent().map(|dir| dir.to_path_buf()));
+    match &found {
+        Some(dir) => log_info!("Using Steam's compatibility tool for {}: {}", appid, dir.display()),
+        None => log_warn!("Steam maps {} to {}, which isn't installed", appid, tool),
+    }
+    found
+}
+
+/// Folder in compatibilitytools.d whose compatibilitytool.vdf declares a tool
+///
+/// Custom tools are usually named after their folder, but don't have to be.
+fn custom_compat_tool_dir(tool: &str) -> Option<PathBuf> {
+    let entries = std::fs::read_dir(PATH_STEAM.join("compatibilitytools.d")).ok()?;
+    entries.flatten().map(|e| e.path()).find(|dir| {
+        std::fs::read_to_string(dir.join("compatibilitytool.vdf"))
+            .ok()
+            .and_then(|content| parse_vdf(&content))
+            .is_some_and(|vdf| vdf.get(&["compatibilitytools", "compat_tools", tool]).is_some())
+    })
+}
+
+/// Name of the compatibility tool mapped to an app in Steam's config.vdf
+pub fn compat_tool_for_app(config_vdf: &str, appid: u32) -> Option<String> {
+    let vdf = parse_vdf(config_vdf)?;
+    let name = vdf
+        .get(&[
+            "InstallConfigStore",
+            "Software",
+            "Valve",
+            "Steam",
+            "CompatToolMapping",
+            &appid.to_string(),
+            "name",
+        ])?
+        .as_str()?;
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+/// Install folder name of a compatibility tool
+///
+/// Valve's Proton builds have internal names (proton_experimental) that
+/// differ from their folder in steamapps/common. Custom tools keep theirs.
+pub fn compat_tool_dir_name(tool: &str) -> &str {
+    match tool {
+        "proton_experimental" => "Proton - Experimental",
+        "proton_hotfix" => "Proton Hotfix",
+        "proton_9" => "Proton 9.0",
+        "proton_8" => "Proton 8.0",
+        "proton_7" => "Proton 7.0",
+        "proton_63" => "Proton 6.3",
+        "proton_513" => "Proton 5.13",
+        "proton_5" => "Proton 5.0",
+        "proton_411" => "Proton 4.11",
+        "proton_42" => "Proton 4.2",
+        other => other,
+    }
+}
+
+/// A parsed Valve KeyValues (VDF) node
+#[derive(Debug, PartialEq)]
+enum Vdf {
+    Str(String),
+    Section(Vec<(String, Vdf)>),
+}
+
+impl Vdf {
+    /// Follow a path of keys, which VDF compares case-insensitively
+    fn get(&self, path: &[&str]) -> Option<&Vdf> {
+        let Some((first, rest)) = path.split_first() else {
+            return Some(self);
+        };
+        match self {
+            Vdf::Section(entries) => entries
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(first))
+                .and_then(|(_, value)| value.get(rest)),
+            Vdf::Str(_) => None,
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Vdf::Str(s) => Some(s),
+            Vdf::Section(_) => None,
+        }
+    }
+}
+
+/// Parse a text VDF file into its root section
+fn parse_vdf(content: &str) -> Option<Vdf> {
+    let mut stack: Vec<Vec<(String, Vdf)>> = vec![Vec::new()];
+    let mut key: Option<String> = None;
+
+    for token in vdf_tokens(content) {
+        match token {
+            VdfToken::Str(s) => match key.take() {
+                None => key = Some(s),
+                Some(k) => stack.last_mut()?.push((k, Vdf::Str(s))),
+            },
+            VdfToken::Open => {
+                stack.push(Vec::new());
+                // Remember the section's key by pushing it into the parent now
+                let k = key.take()?;
+                let len = stack.len();
+                stack[len - 2].push((k, Vdf::Section(Vec::new())));
+            }
+            VdfToken::Close => {
+                let entries = stack.pop()?;
+                let (_, section) = stack.last_mut()?.last_mut()?;
+                *section = Vdf::Section(entries);
+            }
+        }
+    }
+    (stack.len() == 1).then(|| Vdf::Section(stack.pop().unwrap_or_default()))
+}
+
+enum VdfToken {
+    Str(String),
+    Open,
+    Close,
+}
+
+/// Split VDF text into quoted strings, bare words and braces, skipping // comments
+fn vdf_tokens(content: &str) -> Vec<VdfToken> {
+    let mut tokens = Vec::new();
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => tokens.push(VdfToken::Open),
+            '}' => tokens.push(VdfToken::Close),
+            '"' => {
+                let mut s = String::new();
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' => match chars.next() {
+                            Some('n') => s.push('\n'),
+                            Some('t') => s.push('\t'),
+                            Some(other) => s.push(other),
+                            None => break,
+                        },
+                        _ => s.push(c),
+                    }
+                }
+                tokens.push(VdfToken::Str(s));
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            c if c.is_whitespace() => {}
+            _ => {
+                let mut s = String::from(c);
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || c == '{' || c == '}' || c == '"' {
+                        break;
+                    }
+                    s.push(c);
+                    chars.next();
+                }
+                tokens.push(VdfToken::Str(s));
+            }
+        }
+    }
+    tokens
+}
+
 /// Get the Proton binary path
 ///
 /// If proton_path is set in the handler, resolve it to a full path.
@@ -83,7 +360,137 @@ pub fn uses_direct_proton(handler: &Handler) -> bool {
     !handler.proton_path.is_empty()
 }
 
+/// Create an instance's Wine prefix if it doesn't exist yet
+///
+/// Proton would otherwise build it when the game first starts, one instance
+/// after another. Running wineboot up front lets the prefixes of several
+/// instances be built at the same time.
+pub fn init_prefix(
+    handler: &Handler,
+    cfg: &SplituxConfig,
+    instance_idx: usize,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let path_pfx = get_prefix_path(cfg, instance_idx);
+    // umu-run builds the prefix in place, direct Proton under pfx/
+    if path_pfx.join("drive_c").exists() || path_pfx.join("pfx/drive_c").exists() {
+        return Ok(());
+    }
+    std::fs::create_dir_all(&path_pfx)?;
+
+    log_info!("Creating Proton prefix {}", path_pfx.display());
+    let mut cmd = Command::new(get_binary(handler)?);
+    setup_env(&mut cmd, handler, cfg, instance_idx);
+    if uses_direct_proton(handler) {
+        cmd.arg("run");
+    }
+    let status = cmd
+        .args(["wineboot", "-u"])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()?;
+    if !status.success() {
+        return Err(format!("Couldn't create Proton prefix {} ({})", path_pfx.display(), status).into());
+    }
+    Ok(())
+}
+
 /// Get the Wine prefix user directory path for binding profile data
 pub fn get_prefix_user_path(cfg: &SplituxConfig, instance_idx: usize) -> PathBuf {
     get_prefix_path(cfg, instance_idx).join("drive_c/users/steamuser")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overrides(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn merge_combines_backend_and_handler() {
+        let handler = overrides(&[("xaudio2_9", "n,b"), ("d3dcompiler_47", "n")]);
+        assert_eq!(
+            merge_dll_overrides(&[("winhttp", "n,b")], &handler).as_deref(),
+            Some("d3dcompiler_47=n;winhttp=n,b;xaudio2_9=n,b")
+        );
+    }
+
+    #[test]
+    fn merge_dedupes_case_insensitively_backend_first() {
+        let handler = overrides(&[("WinHTTP", "b"), ("dinput8", "")]);
+        assert_eq!(
+            merge_dll_overrides(&[("winhttp", "n,b")], &handler).as_deref(),
+            Some("dinput8=;winhttp=n,b")
+        );
+    }
+
+    #[test]
+    fn merge_nothing_is_none() {
+        assert_eq!(merge_dll_overrides(&[], &HashMap::new()), None);
+        assert_eq!(merge_dll_overrides(&[], &overrides(&[(" ", "n")])), None);
+    }
+
+    const CONFIG_VDF: &str = r#"
+"InstallConfigStore"
+{
+	"Software"
+	{
+		"Valve"
+		{
+			"Steam"
+			{
+				// Per-app compatibility tools
+				"CompatToolMapping"
+				{
+					"0"
+					{
+						"name"		"proton_9"
+						"config"		""
+						"priority"		"75"
+					}
+					"1091500"
+					{
+						"name"		"GE-Proton9-20"
+						"config"		""
+						"priority"		"250"
+					}
+					"413150"
+					{
+						"name"		""
+						"config"		""
+						"priority"		"250"
+					}
+				}
+			}
+		}
+	}
+}
+"#;
+
+    #[test]
+    fn compat_tool_mapping_is_read_per_app() {
+        assert_eq!(compat_tool_for_app(CONFIG_VDF, 1091500).as_deref(), Some("GE-Proton9-20"));
+        assert_eq!(compat_tool_for_app(CONFIG_VDF, 0).as_deref(), Some("proton_9"));
+        assert_eq!(compat_tool_for_app(CONFIG_VDF, 413150), None);
+        assert_eq!(compat_tool_for_app(CONFIG_VDF, 570), None);
+        assert_eq!(compat_tool_for_app("\"broken\" {", 570), None);
+    }
+
+    #[test]
+    fn valve_tools_map_to_their_folders() {
+        assert_eq!(compat_tool_dir_name("proton_experimental"), "Proton - Experimental");
+        assert_eq!(compat_tool_dir_name("proton_9"), "Proton 9.0");
+        assert_eq!(compat_tool_dir_name("GE-Proton9-20"), "GE-Proton9-20");
+    }
+
+    #[test]
+    fn check_modes() {
+        assert_eq!(check_dll_override("xaudio2_9", "n,b"), None);
+        assert_eq!(check_dll_override("xaudio2_9", "b, n"), None);
+        assert_eq!(check_dll_override("xaudio2_9", ""), None);
+        assert!(check_dll_override("xaudio2_9", "native").is_some());
+        assert!(check_dll_override("a=b", "n").is_some());
+        assert!(check_dll_override("", "n").is_some());
+    }
+}
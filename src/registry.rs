@@ -1,13 +1,25 @@
+use crate::handler::confirm_hook_scripts;
+use crate::integrity::{Checksum, DownloadDigest};
+use crate::net::{fetch_with_retry, fetch_with_retry_until};
 use crate::paths::PATH_PARTY;
 use serde::Deserialize;
 use std::error::Error;
-use std::path::PathBuf;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 
 pub const REGISTRY_URL: &str =
     "https://raw.githubusercontent.com/splitux-gg/splitux-handlers/main/index.json";
 pub const REGISTRY_BASE: &str =
     "https://raw.githubusercontent.com/splitux-gg/splitux-handlers/main/handlers";
 
+/// How long a handler download may wait on a single connect or read
+///
+/// Cancel is checked between reads, so this bounds how long a stalled
+/// download keeps running after Cancel is pressed.
+const DOWNLOAD_STALL_TIMEOUT: Duration = Duration::from_secs(10);
+
 #[derive(Deserialize, Clone, Debug)]
 pub struct RegistryIndex {
     #[allow(dead_code)]
@@ -24,10 +36,16 @@ pub struct RegistryEntry {
     pub steam_appid: Option<u32>,
     #[serde(default)]
     pub backend: Option<String>,
+    /// Maximum number of local players the handler supports
+    #[serde(default)]
+    pub max_players: Option<u32>,
     #[serde(default)]
     pub description: String,
     #[serde(default)]
     pub updated: String,
+    /// Size and SHA-256 of handler.yaml, checked after downloading it
+    #[serde(flatten)]
+    pub checksum: Checksum,
 }
 
 impl RegistryEntry {
@@ -53,77 +71,340 @@ impl RegistryEntry {
     pub fn local_path(&self) -> PathBuf {
         PATH_PARTY.join("handlers").join(&self.id)
     }
+
+    /// Folder a download is staged in until it is confirmed and moved into place
+    ///
+    /// Kept under PATH_PARTY so the move is a rename on the same filesystem.
+    fn staging_path(&self) -> PathBuf {
+        PATH_PARTY.join(".cache").join("downloads").join(&self.id)
+    }
+}
+
+/// Registry browsing filter; all set conditions must match
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RegistryFilter {
+    /// Case-insensitive text matched against name, description and author
+    pub search: String,
+    /// Only handlers using this backend
+    pub backend: Option<String>,
+    /// Only handlers supporting at least this many players
+    pub min_players: Option<u32>,
+}
+
+impl RegistryFilter {
+    /// Player counts offered as filter chips
+    pub const PLAYER_CHIPS: [u32; 3] = [2, 3, 4];
+
+    /// Step the backend filter: off, each backend in turn, then off again
+    pub fn cycle_backend(&mut self, backends: &[String]) {
+        let next = match &self.backend {
+            Some(current) => backends.iter().position(|b| b == current).map_or(0, |i| i + 1),
+            None => 0,
+        };
+        self.backend = backends.get(next).cloned();
+    }
+
+    /// Step the player filter: off, each chip in turn, then off again
+    pub fn cycle_min_players(&mut self) {
+        let next = match self.min_players {
+            Some(current) => Self::PLAYER_CHIPS.iter().position(|&c| c == current).map_or(0, |i| i + 1),
+            None => 0,
+        };
+        self.min_players = Self::PLAYER_CHIPS.get(next).copied();
+    }
+
+    pub fn matches(&self, entry: &RegistryEntry) -> bool {
+        let search = self.search.to_lowercase();
+        let text_ok = search.is_empty()
+            || entry.name.to_lowercase().contains(&search)
+            || entry.description.to_lowercase().contains(&search)
+            || entry.author.to_lowercase().contains(&search);
+
+        let backend_ok = match &self.backend {
+            Some(backend) => entry.backend.as_deref() == Some(backend.as_str()),
+            None => true,
+        };
+
+        // Entries that don't declare a player count can't be shown to support it
+        let players_ok = match self.min_players {
+            Some(min) => entry.max_players.is_some_and(|max| max >= min),
+            None => true,
+        };
+
+        text_ok && backend_ok && players_ok
+    }
+}
+
+impl RegistryIndex {
+    /// Distinct backends used by the handlers, sorted
+    pub fn backends(&self) -> Vec<String> {
+        let mut backends: Vec<String> = self.handlers.iter().filter_map(|h| h.backend.clone()).collect();
+        backends.sort();
+        backends.dedup();
+        backends
+    }
+}
+
+/// Local copy of the last fetched registry index, browsable in offline mode
+fn registry_cache_path() -> PathBuf {
+    PATH_PARTY.join(".cache").join("registry_index.json")
+}
+
+/// Load the registry index cached by the last successful fetch
+pub fn load_cached_registry() -> Option<RegistryIndex> {
+    let content = std::fs::read_to_string(registry_cache_path()).ok()?;
+    serde_json::from_str(&content).ok()
 }
 
-/// Fetch the registry index from GitHub
-pub fn fetch_registry() -> Result<RegistryIndex, String> {
+/// Fetch the registry index from GitHub and cache it
+pub fn fetch_registry(offline: bool) -> Result<RegistryIndex, String> {
     let client = reqwest::blocking::Client::new();
-    let response = client
-        .get(REGISTRY_URL)
-        .header("User-Agent", "splitux")
-        .send()
-        .map_err(|e| e.to_string())?;
+    let response = fetch_with_retry(&client, REGISTRY_URL, "the registry index", offline)?;
 
     if !response.status().is_success() {
         return Err(format!("Failed to fetch registry: HTTP {}", response.status()));
     }
 
-    let index: RegistryIndex = response.json().map_err(|e| e.to_string())?;
+    let content = response.text().map_err(|e| e.to_string())?;
+    let index: RegistryIndex = serde_json::from_str(&content).map_err(|e| e.to_string())?;
+
+    let cache_path = registry_cache_path();
+    if let Err(e) = cache_path
+        .parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|_| std::fs::write(&cache_path, &content))
+    {
+        log_warn!("Failed to cache registry index: {}", e);
+    }
+
     Ok(index)
 }
 
-/// Download a handler from the registry and install it locally
-pub fn download_handler(entry: &RegistryEntry) -> Result<(), Box<dyn Error>> {
-    let client = reqwest::blocking::Client::new();
-    let handler_dir = entry.local_path();
+/// Progress of a handler download, reported per file
+#[derive(Clone, Debug, PartialEq)]
+pub struct DownloadProgress {
+    /// File being downloaded (handler.yaml, icon.jpg or header.jpg)
+    pub file: &'static str,
+    pub received: u64,
+    /// Content-Length of the file, when the server sends one
+    pub total: Option<u64>,
+}
 
-    // Create handler directory
-    std::fs::create_dir_all(&handler_dir)?;
+impl DownloadProgress {
+    /// Completed fraction of the current file, if its size is known
+    pub fn fraction(&self) -> Option<f32> {
+        self.total
+            .filter(|total| *total > 0)
+            .map(|total| (self.received as f32 / total as f32).min(1.0))
+    }
+}
+
+/// Message from a background handler download
+pub enum DownloadEvent {
+    Progress(DownloadProgress),
+    Finished,
+    Cancelled,
+    Failed(String),
+}
 
-    // Download handler.yaml
-    let yaml_response = client
-        .get(&entry.yaml_url())
-        .header("User-Agent", "splitux")
-        .send()?;
+/// Download a handler, reporting progress and checking `cancel` between chunks
+///
+/// Files are streamed into a staging folder, so a cancelled or failed download
+/// never touches the installed handler. Hook scripts in the downloaded
+/// handler.yaml are confirmed before anything is moved into place; returns
+/// Ok(false) when the user declines.
+pub fn download_handler_with_progress(
+    entry: &RegistryEntry,
+    offline: bool,
+    progress: impl Fn(DownloadProgress),
+    cancel: &AtomicBool,
+) -> Result<bool, Box<dyn Error>> {
+    let staging = download_to_staging(entry, offline, progress, cancel)?;
+    install_staged(entry, &staging, "Install")
+}
 
-    if !yaml_response.status().is_success() {
-        return Err(format!(
-            "Failed to download handler.yaml: HTTP {}",
-            yaml_response.status()
-        )
-        .into());
+/// Download the registry files for `entry` into a fresh staging folder
+///
+/// In offline mode the required handler.yaml fails to fetch, so nothing is staged.
+fn download_to_staging(
+    entry: &RegistryEntry,
+    offline: bool,
+    progress: impl Fn(DownloadProgress),
+    cancel: &AtomicBool,
+) -> Result<PathBuf, Box<dyn Error>> {
+    let client = reqwest::blocking::Client::builder()
+        .timeout(DOWNLOAD_STALL_TIMEOUT)
+        .build()?;
+    let staging = entry.staging_path();
+    if staging.exists() {
+        std::fs::remove_dir_all(&staging)?;
     }
+    std::fs::create_dir_all(&staging)?;
 
-    let yaml_content = yaml_response.text()?;
-    std::fs::write(handler_dir.join("handler.yaml"), yaml_content)?;
+    let unchecked = Checksum::default();
+    let downloads = [
+        ("handler.yaml", entry.yaml_url(), true, &entry.checksum),
+        // Images are optional - don't fail if missing
+        ("icon.jpg", entry.icon_url(), false, &unchecked),
+        ("header.jpg", entry.header_url(), false, &unchecked),
+    ];
 
-    // Download icon.jpg (optional - don't fail if missing)
-    if let Ok(icon_response) = client
-        .get(&entry.icon_url())
-        .header("User-Agent", "splitux")
-        .send()
-    {
-        if icon_response.status().is_success() {
-            if let Ok(icon_bytes) = icon_response.bytes() {
-                let _ = std::fs::write(handler_dir.join("icon.jpg"), icon_bytes);
+    for (file, url, required, checksum) in downloads {
+        match download_file(&client, &url, &staging.join(file), file, checksum, offline, &progress, cancel) {
+            Ok(()) => {}
+            Err(e) if required || cancel.load(Ordering::Relaxed) => {
+                let _ = std::fs::remove_dir_all(&staging);
+                return Err(e);
             }
+            Err(e) => log_warn!("Skipping {} for {}: {}", file, entry.id, e),
         }
     }
 
-    // Download header.jpg (optional - don't fail if missing)
-    if let Ok(header_response) = client
-        .get(&entry.header_url())
-        .header("User-Agent", "splitux")
-        .send()
-    {
-        if header_response.status().is_success() {
-            if let Ok(header_bytes) = header_response.bytes() {
-                let _ = std::fs::write(handler_dir.join("header.jpg"), header_bytes);
-            }
+    Ok(staging)
+}
+
+/// Confirm hook scripts in a staged download, then move its files into the handler folder
+///
+/// Only the staged files are replaced; local-only files in the handler folder
+/// are left alone. The staging folder is removed either way.
+fn install_staged(entry: &RegistryEntry, staging: &Path, action: &str) -> Result<bool, Box<dyn Error>> {
+    if !confirm_hook_scripts(&staging.join("handler.yaml"), action) {
+        std::fs::remove_dir_all(staging)?;
+        return Ok(false);
+    }
+
+    let handler_dir = entry.local_path();
+    std::fs::create_dir_all(&handler_dir)?;
+    for file in std::fs::read_dir(staging)? {
+        let file = file?;
+        std::fs::rename(file.path(), handler_dir.join(file.file_name()))?;
+    }
+    std::fs::remove_dir_all(staging)?;
+    Ok(true)
+}
+
+/// Stream one file to `dest`, via a `.part` file that is removed on failure
+///
+/// The file is only moved into place once it matches `checksum`.
+fn download_file(
+    client: &reqwest::blocking::Client,
+    url: &str,
+    dest: &Path,
+    file: &'static str,
+    checksum: &Checksum,
+    offline: bool,
+    progress: &impl Fn(DownloadProgress),
+    cancel: &AtomicBool,
+) -> Result<(), Box<dyn Error>> {
+    let mut response = fetch_with_retry_until(client, url, file, offline, cancel)?;
+    if !response.status().is_success() {
+        return Err(format!("Failed to download {}: HTTP {}", file, response.status()).into());
+    }
+
+    let total = response.content_length();
+    let part = dest.with_extension(format!(
+        "{}.part",
+        dest.extension().and_then(|e| e.to_str()).unwrap_or_default()
+    ));
+    let streamed = stream_to_file(&mut response, &part, file, total, progress, cancel)
+        .and_then(|digest| checksum.verify_digest(file, digest).map_err(Into::into));
+    match streamed {
+        Ok(()) => {
+            std::fs::rename(&part, dest)?;
+            Ok(())
+        }
+        Err(e) => {
+            let _ = std::fs::remove_file(&part);
+            Err(e)
         }
     }
+}
 
-    Ok(())
+/// Copy a response body to `path` in chunks, reporting progress after each
+///
+/// Returns the size and hash of what was written.
+fn stream_to_file(
+    response: &mut reqwest::blocking::Response,
+    path: &Path,
+    file: &'static str,
+    total: Option<u64>,
+    progress: &impl Fn(DownloadProgress),
+    cancel: &AtomicBool,
+) -> Result<DownloadDigest, Box<dyn Error>> {
+    let mut out = std::fs::File::create(path)?;
+    let mut buf = [0u8; 16 * 1024];
+    let mut digest = DownloadDigest::default();
+    let mut received = 0u64;
+    progress(DownloadProgress { file, received, total });
+    loop {
+        if cancel.load(Ordering::Relaxed) {
+            return Err("Download cancelled".into());
+        }
+        let n = match response.read(&mut buf) {
+            Ok(n) => n,
+            // A read that timed out after Cancel was pressed
+            Err(_) if cancel.load(Ordering::Relaxed) => return Err("Download cancelled".into()),
+            Err(e) => return Err(e.into()),
+        };
+        if n == 0 {
+            return Ok(digest);
+        }
+        out.write_all(&buf[..n])?;
+        digest.update(&buf[..n]);
+        received += n as u64;
+        progress(DownloadProgress { file, received, total });
+    }
+}
+
+/// Re-download an installed handler from the registry, discarding local edits
+///
+/// Only the files the registry ships (handler.yaml, icon.jpg, header.jpg) are
+/// replaced; local-only files such as icon.png or imgs/ are left alone, and the
+/// local game root is carried over into the fresh handler.yaml. Hook scripts
+/// are confirmed like on install; returns Ok(false) when the user declines.
+pub fn reset_handler(entry: &RegistryEntry, offline: bool) -> Result<bool, Box<dyn Error>> {
+    let local: Option<serde_yaml::Value> = std::fs::read_to_string(entry.local_path().join("handler.yaml"))
+        .ok()
+        .and_then(|content| serde_yaml::from_str(&content).ok());
+
+    let staging = download_to_staging(entry, offline, |_| {}, &AtomicBool::new(false))?;
+    let yaml_path = staging.join("handler.yaml");
+
+    if let Some(local) = local {
+        let remote: serde_yaml::Value = serde_yaml::from_str(&std::fs::read_to_string(&yaml_path)?)?;
+        if let Some(merged) = keep_local_game_root(&local, remote) {
+            std::fs::write(&yaml_path, serde_yaml::to_string(&merged)?)?;
+        }
+    }
+
+    install_staged(entry, &staging, "Reset")
+}
+
+/// Carry the local game root into a freshly downloaded handler.yaml
+///
+/// Keeps `path_gameroot` and a manual `platform` (both point at the user's own
+/// game folder). Returns None when there is nothing to carry over, so the
+/// downloaded file can be kept byte-for-byte.
+fn keep_local_game_root(
+    local: &serde_yaml::Value,
+    mut remote: serde_yaml::Value,
+) -> Option<serde_yaml::Value> {
+    let remote_map = remote.as_mapping_mut()?;
+    let mut changed = false;
+
+    if let Some(root) = local.get("path_gameroot").filter(|v| v.as_str().is_some_and(|s| !s.is_empty())) {
+        remote_map.insert("path_gameroot".into(), root.clone());
+        changed = true;
+    }
+
+    if let Some(platform) = local.get("platform")
+        && platform.get("platform").and_then(|v| v.as_str()) == Some("manual")
+    {
+        remote_map.insert("platform".into(), platform.clone());
+        changed = true;
+    }
+
+    changed.then_some(remote)
 }
 
 /// Remove an installed handler from the registry
@@ -135,3 +416,112 @@ pub fn uninstall_handler(entry: &RegistryEntry) -> Result<(), Box<dyn Error>> {
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(name: &str, backend: Option<&str>, max_players: Option<u32>) -> RegistryEntry {
+        RegistryEntry {
+            id: name.to_lowercase(),
+            name: name.to_string(),
+            author: "someone".to_string(),
+            steam_appid: None,
+            backend: backend.map(str::to_string),
+            max_players,
+            description: String::new(),
+            updated: String::new(),
+            checksum: Checksum::default(),
+        }
+    }
+
+    #[test]
+    fn filter_combines_text_backend_and_players() {
+        let filter = RegistryFilter {
+            search: "rock".to_string(),
+            backend: Some("goldberg".to_string()),
+            min_players: Some(4),
+        };
+        assert!(filter.matches(&entry("Deep Rock Galactic", Some("goldberg"), Some(4))));
+        assert!(!filter.matches(&entry("Deep Rock Galactic", Some("photon"), Some(4))));
+        assert!(!filter.matches(&entry("Deep Rock Galactic", Some("goldberg"), Some(2))));
+        assert!(!filter.matches(&entry("Deep Rock Galactic", Some("goldberg"), None)));
+        assert!(!filter.matches(&entry("Terraria", Some("goldberg"), Some(8))));
+    }
+
+    #[test]
+    fn filter_chips_cycle_back_to_off() {
+        let backends = vec!["goldberg".to_string(), "photon".to_string()];
+        let mut filter = RegistryFilter::default();
+        filter.cycle_backend(&backends);
+        assert_eq!(filter.backend.as_deref(), Some("goldberg"));
+        filter.cycle_backend(&backends);
+        assert_eq!(filter.backend.as_deref(), Some("photon"));
+        filter.cycle_backend(&backends);
+        assert_eq!(filter.backend, None);
+
+        filter.min_players = Some(4);
+        filter.cycle_min_players();
+        assert_eq!(filter.min_players, None);
+        filter.cycle_min_players();
+        assert_eq!(filter.min_players, Some(2));
+    }
+
+    #[test]
+    fn reset_keeps_local_game_root() {
+        let local: serde_yaml::Value =
+            serde_yaml::from_str("name: Mine\npath_gameroot: /games/Foo\nargs: -tweaked\n").unwrap();
+        let remote: serde_yaml::Value = serde_yaml::from_str("name: Foo\nsteam_appid: 42\n").unwrap();
+        let merged = keep_local_game_root(&local, remote).unwrap();
+        assert_eq!(merged["name"].as_str(), Some("Foo"));
+        assert_eq!(merged["path_gameroot"].as_str(), Some("/games/Foo"));
+        assert!(merged.get("args").is_none());
+    }
+
+    #[test]
+    fn reset_without_local_game_root_keeps_download() {
+        let local: serde_yaml::Value =
+            serde_yaml::from_str("name: Mine\nplatform:\n  platform: steam\n  steam_appid: 42\n").unwrap();
+        let remote: serde_yaml::Value = serde_yaml::from_str("name: Foo\n").unwrap();
+        assert!(keep_local_game_root(&local, remote).is_none());
+
+        let local: serde_yaml::Value =
+            serde_yaml::from_str("platform:\n  platform: manual\n  path_gameroot: /games/Foo\n").unwrap();
+        let remote: serde_yaml::Value = serde_yaml::from_str("name: Foo\n").unwrap();
+        let merged = keep_local_game_root(&local, remote).unwrap();
+        assert_eq!(merged["platform"]["path_gameroot"].as_str(), Some("/games/Foo"));
+    }
+
+    #[test]
+    fn progress_fraction_needs_a_size() {
+        let progress = |received, total| DownloadProgress { file: "icon.jpg", received, total };
+        assert_eq!(progress(50, Some(200)).fraction(), Some(0.25));
+        assert_eq!(progress(50, None).fraction(), None);
+        assert_eq!(progress(0, Some(0)).fraction(), None);
+        assert_eq!(progress(300, Some(200)).fraction(), Some(1.0));
+    }
+
+    #[test]
+    fn index_parses_optional_metadata() {
+        let json = r#"{"version": 1, "handlers": [
+            {"id": "a", "name": "A", "author": "x", "backend": "photon", "max_players": 4},
+            {"id": "b", "name": "B", "author": "x"}
+        ]}"#;
+        let index: RegistryIndex = serde_json::from_str(json).unwrap();
+        assert_eq!(index.handlers[0].max_players, Some(4));
+        assert_eq!(index.handlers[1].max_players, None);
+        assert_eq!(index.backends(), vec!["photon".to_string()]);
+    }
+
+    #[test]
+    fn index_parses_optional_checksum() {
+        let json = r#"{"version": 1, "handlers": [
+            {"id": "a", "name": "A", "author": "x", "sha256": "ABC", "size": 120},
+            {"id": "b", "name": "B", "author": "x"}
+        ]}"#;
+        let index: RegistryIndex = serde_json::from_str(json).unwrap();
+        assert_eq!(index.handlers[0].checksum.sha256.as_deref(), Some("ABC"));
+        assert_eq!(index.handlers[0].checksum.size, Some(120));
+        assert_eq!(index.handlers[1].checksum, Checksum::default());
+    }
+}
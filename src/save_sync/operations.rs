@@ -2,27 +2,63 @@
 // Functions that interact with the filesystem
 
 use crate::handler::Handler;
-use crate::paths::PATH_PARTY;
 use std::error::Error;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use super::pure::{extract_steam_id_from_filename, get_profile_save_path};
+use super::pure::{
+    backups_to_prune, extract_steam_id_from_filename, get_handler_name, get_original_save_path,
+    get_profile_save_path, parse_backup_name, remap_steam_id_name, save_backup_dir_for, SaveLocation,
+};
 
 /// Check if a profile already has save data for this handler
+///
+/// Any one of the handler's save paths having content counts.
 pub fn profile_has_existing_saves(profile_name: &str, h: &Handler) -> bool {
-    let (profile_save_path, _) = get_profile_save_path(profile_name, h);
-    if !profile_save_path.exists() {
-        return false;
-    }
-    // Check if directory has any files (not just exists but empty)
-    std::fs::read_dir(&profile_save_path)
+    (0..h.original_save_paths.len())
+        .any(|index| dir_has_content(&get_profile_save_path(profile_name, h, index).0))
+}
+
+/// Whether a directory exists and has anything in it
+pub fn dir_has_content(path: &Path) -> bool {
+    std::fs::read_dir(path)
         .map(|mut entries| entries.next().is_some())
         .unwrap_or(false)
 }
 
-/// Copy a directory recursively with Steam ID remapping in filenames
-/// If original_steam_id is detected in a filename, it's replaced with target_steam_id
+/// Copy a save directory into dest, remapping Steam IDs when given one
+pub fn copy_save_dir(src: &Path, dest: &Path, steam_id: Option<u64>) -> Result<(), Box<dyn Error>> {
+    let (src, dest) = (src.to_path_buf(), dest.to_path_buf());
+    std::fs::create_dir_all(&dest)?;
+    match steam_id {
+        Some(steam_id) => copy_dir_with_steam_id_remap(&src, &dest, steam_id).map(|_| ()),
+        None => copy_dir_recursive(&src, &dest),
+    }
+}
+
+/// Replace everything in dest with a copy of src
+///
+/// dest itself is kept (or created), only its contents are removed.
+pub fn replace_save_dir(src: &Path, dest: &Path, steam_id: Option<u64>) -> Result<(), Box<dyn Error>> {
+    if dest.exists() {
+        for entry in std::fs::read_dir(dest)? {
+            let p = entry?.path();
+            if p.is_dir() {
+                std::fs::remove_dir_all(&p)?;
+            } else {
+                std::fs::remove_file(&p)?;
+            }
+        }
+    }
+    copy_save_dir(src, dest, steam_id)
+}
+
+/// Copy a directory recursively with Steam ID remapping
+///
+/// Files and directories whose names start with a Steam ID are copied under
+/// names using target_steam_id. Only entries coming from src are renamed;
+/// anything already in dest is left alone unless a copied entry lands on it.
+/// Returns the first original Steam ID found.
 pub fn copy_dir_with_steam_id_remap(
     src: &PathBuf,
     dest: &PathBuf,
@@ -36,61 +72,33 @@ pub fn copy_dir_with_steam_id_remap(
         let entry = entry?;
         let rel_path = entry.path().strip_prefix(src)?;
 
-        // Check if filename has a Steam ID prefix
-        let filename = entry
-            .path()
-            .file_name()
-            .map(|f| f.to_string_lossy().to_string())
-            .unwrap_or_default();
-
-        let (new_filename, detected_id) = if let Some((original_id, rest)) =
-            extract_steam_id_from_filename(&filename)
-        {
-            // Store the detected original ID (use first one found)
-            if detected_original_id.is_none() {
-                detected_original_id = Some(original_id);
-                println!(
-                    "[splitux] Detected original Steam ID in saves: {}",
-                    original_id
-                );
+        let mut new_rel_path = PathBuf::new();
+        for component in rel_path.components() {
+            let name = component.as_os_str().to_string_lossy();
+            match remap_steam_id_name(&name, target_steam_id) {
+                Some((original_id, new_name)) => {
+                    // Store the detected original ID (use first one found)
+                    if detected_original_id.is_none() {
+                        detected_original_id = Some(original_id);
+                        log_info!(
+                            "Detected original Steam ID in saves: {}",
+                            original_id
+                        );
+                    }
+                    new_rel_path.push(new_name);
+                }
+                None => new_rel_path.push(component),
             }
+        }
 
-            let remapped = format!("{}{}", target_steam_id, rest);
-            println!(
-                "[splitux] Remapping save file: {} -> {}",
-                filename, remapped
+        if new_rel_path != rel_path {
+            log_debug!(
+                "Remapping save path: {} -> {}",
+                rel_path.display(),
+                new_rel_path.display()
             );
-            (remapped, Some(original_id))
-        } else {
-            (filename.clone(), None)
-        };
-
-        // Build the new path with potentially remapped filename
-        let new_rel_path = if detected_id.is_some() {
-            rel_path.parent().map_or_else(
-                || PathBuf::from(&new_filename),
-                |parent| parent.join(&new_filename),
-            )
-        } else {
-            rel_path.to_path_buf()
-        };
-
-        let new_path = dest.join(&new_rel_path);
-
-        if entry.file_type().is_dir() {
-            std::fs::create_dir_all(&new_path)?;
-        } else if entry.file_type().is_symlink() {
-            let symlink_src = std::fs::read_link(entry.path())?;
-            std::os::unix::fs::symlink(symlink_src, &new_path)?;
-        } else {
-            if let Some(parent) = new_path.parent() {
-                std::fs::create_dir_all(parent)?;
-            }
-            if new_path.exists() {
-                std::fs::remove_file(&new_path)?;
-            }
-            std::fs::copy(entry.path(), &new_path)?;
         }
+        copy_entry(&entry, &dest.join(&new_rel_path))?;
     }
 
     Ok(detected_original_id)
@@ -103,52 +111,194 @@ pub fn copy_dir_recursive(src: &PathBuf, dest: &PathBuf) -> Result<(), Box<dyn E
     for entry in walk_path {
         let entry = entry?;
         let rel_path = entry.path().strip_prefix(src)?;
-        let new_path = dest.join(rel_path);
-
-        if entry.file_type().is_dir() {
-            std::fs::create_dir_all(&new_path)?;
-        } else if entry.file_type().is_symlink() {
-            let symlink_src = std::fs::read_link(entry.path())?;
-            std::os::unix::fs::symlink(symlink_src, new_path)?;
-        } else {
-            if let Some(parent) = new_path.parent() {
-                std::fs::create_dir_all(parent)?;
-            }
-            if new_path.exists() {
-                std::fs::remove_file(&new_path)?;
-            }
-            std::fs::copy(entry.path(), new_path)?;
-        }
+        copy_entry(&entry, &dest.join(rel_path))?;
     }
 
     Ok(())
 }
 
-/// Backup saves before overwriting
-pub fn backup_saves(path: &PathBuf) -> Result<PathBuf, Box<dyn Error>> {
-    let backup_base = PATH_PARTY.join("save_backups");
-    std::fs::create_dir_all(&backup_base)?;
+/// Copy one walked entry to new_path, replacing a file already there
+fn copy_entry(entry: &walkdir::DirEntry, new_path: &Path) -> Result<(), Box<dyn Error>> {
+    if entry.file_type().is_dir() {
+        std::fs::create_dir_all(new_path)?;
+    } else if entry.file_type().is_symlink() {
+        let symlink_src = std::fs::read_link(entry.path())?;
+        std::os::unix::fs::symlink(symlink_src, new_path)?;
+    } else {
+        if let Some(parent) = new_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        if new_path.exists() {
+            std::fs::remove_file(new_path)?;
+        }
+        std::fs::copy(entry.path(), new_path)?;
+    }
+    Ok(())
+}
+
+/// A timestamped snapshot of a handler's original saves
+#[derive(Clone, Debug)]
+pub struct SaveBackup {
+    /// Seconds since the Unix epoch; also the snapshot's folder name in
+    /// each save path's backup directory
+    pub timestamp: u64,
+}
+
+/// Snapshot the original saves of every location that exists, keeping only
+/// the newest `keep` of each
+///
+/// All snapshots of one call share a timestamp, so they restore together.
+pub fn backup_save(handler_name: &str, locations: &[SaveLocation], keep: usize) -> Result<(), Box<dyn Error>> {
+    let snapshots: Vec<(&Path, PathBuf)> = locations
+        .iter()
+        .filter(|l| l.original.exists())
+        .map(|l| (l.original.as_path(), save_backup_dir_for(handler_name, l.index)))
+        .collect();
+    snapshot_together(&snapshots, keep)
+}
 
-    let timestamp = SystemTime::now()
+/// Snapshot a profile's saves before overwriting them, keeping only the newest `keep`
+///
+/// Locations with nothing saved in the profile are skipped.
+pub fn backup_profile_save(
+    handler_name: &str,
+    profile_name: &str,
+    locations: &[SaveLocation],
+    keep: usize,
+) -> Result<(), Box<dyn Error>> {
+    let snapshots: Vec<(&Path, PathBuf)> = locations
+        .iter()
+        .filter(|l| dir_has_content(&l.profile))
+        .map(|l| {
+            let dir = save_backup_dir_for(handler_name, l.index).join("profiles").join(profile_name);
+            (l.profile.as_path(), dir)
+        })
+        .collect();
+    snapshot_together(&snapshots, keep)
+}
+
+/// Snapshot each (src, dir) pair under one timestamp, then prune each dir
+fn snapshot_together(snapshots: &[(&Path, PathBuf)], keep: usize) -> Result<(), Box<dyn Error>> {
+    let dirs: Vec<&Path> = snapshots.iter().map(|(_, dir)| dir.as_path()).collect();
+    let timestamp = free_timestamp(&dirs);
+    for (src, dir) in snapshots {
+        snapshot_at(src, dir, timestamp)?;
+    }
+    for dir in dirs {
+        prune_backups(dir, keep)?;
+    }
+    Ok(())
+}
+
+/// The current time, moved forward until no dir has a snapshot by that name
+fn free_timestamp(dirs: &[&Path]) -> u64 {
+    let mut timestamp = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .map(|d| d.as_secs())
         .unwrap_or(0);
+    // Two snapshots within the same second get consecutive names
+    while dirs.iter().any(|dir| dir.join(timestamp.to_string()).exists()) {
+        timestamp += 1;
+    }
+    timestamp
+}
 
-    let name = path
-        .file_name()
-        .map(|n| n.to_string_lossy().to_string())
-        .unwrap_or_else(|| "saves".to_string());
-
-    let backup_path = backup_base.join(format!("{}_{}", name, timestamp));
+/// Copy src into the `<dir>/<timestamp>` snapshot
+fn snapshot_at(src: &Path, dir: &Path, timestamp: u64) -> Result<PathBuf, Box<dyn Error>> {
+    let backup_path = dir.join(timestamp.to_string());
 
-    println!("[splitux] Backing up: {}", backup_path.display());
+    log_info!("Backing up: {}", backup_path.display());
 
     std::fs::create_dir_all(&backup_path)?;
-    copy_dir_recursive(path, &backup_path)?;
+    copy_dir_recursive(&src.to_path_buf(), &backup_path)?;
 
     Ok(backup_path)
 }
 
+/// Timestamped snapshots in dir, newest first
+fn snapshots_in(dir: &Path) -> Vec<SaveBackup> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut backups: Vec<SaveBackup> = entries
+        .flatten()
+        .filter(|e| e.path().is_dir())
+        .filter_map(|e| {
+            let timestamp = parse_backup_name(&e.file_name().to_string_lossy())?;
+            Some(SaveBackup { timestamp })
+        })
+        .collect();
+    backups.sort_by_key(|b| std::cmp::Reverse(b.timestamp));
+    backups
+}
+
+/// Delete all but the newest `keep` snapshots in dir
+fn prune_backups(dir: &Path, keep: usize) -> Result<(), Box<dyn Error>> {
+    let timestamps: Vec<u64> = snapshots_in(dir).iter().map(|b| b.timestamp).collect();
+    for timestamp in backups_to_prune(&timestamps, keep) {
+        let path = dir.join(timestamp.to_string());
+        log_info!("Removing old save backup: {}", path.display());
+        std::fs::remove_dir_all(&path)?;
+    }
+    Ok(())
+}
+
+/// Snapshots of a handler's original saves, newest first
+///
+/// With several save paths, a snapshot is listed once for all of them.
+pub fn list_save_backups(h: &Handler) -> Vec<SaveBackup> {
+    let handler_name = get_handler_name(h);
+    let mut backups: Vec<SaveBackup> = (0..h.original_save_paths.len())
+        .flat_map(|index| snapshots_in(&save_backup_dir_for(&handler_name, index)))
+        .collect();
+    backups.sort_by_key(|b| std::cmp::Reverse(b.timestamp));
+    backups.dedup_by_key(|b| b.timestamp);
+    backups
+}
+
+/// Replace a handler's original saves with a snapshot
+///
+/// Every save path with a snapshot at that time is restored; the others are
+/// left alone. The current saves are snapshotted first, so a restore can be
+/// undone by restoring that snapshot. Pruning runs afterwards so the snapshot
+/// being restored can't be rotated out mid-restore.
+pub fn restore_save_backup(h: &Handler, backup: &SaveBackup, keep: usize) -> Result<(), Box<dyn Error>> {
+    let handler_name = get_handler_name(h);
+    let restores: Vec<(PathBuf, PathBuf, PathBuf)> = (0..h.original_save_paths.len())
+        .filter_map(|index| {
+            let original = get_original_save_path(h, index)?;
+            let dir = save_backup_dir_for(&handler_name, index);
+            let snapshot = dir.join(backup.timestamp.to_string());
+            snapshot.is_dir().then_some((original, dir, snapshot))
+        })
+        .collect();
+    if restores.is_empty() {
+        return Err("No save path has this backup".into());
+    }
+
+    let dirs: Vec<&Path> = restores.iter().map(|(_, dir, _)| dir.as_path()).collect();
+    let timestamp = free_timestamp(&dirs);
+    for (original, dir, snapshot) in &restores {
+        if original.exists() {
+            snapshot_at(original, dir, timestamp)?;
+            std::fs::remove_dir_all(original)?;
+        }
+        std::fs::create_dir_all(original)?;
+
+        log_info!(
+            "Restoring save backup: {} -> {}",
+            snapshot.display(),
+            original.display()
+        );
+        copy_dir_recursive(snapshot, original)?;
+    }
+
+    for dir in dirs {
+        prune_backups(dir, keep)?;
+    }
+    Ok(())
+}
+
 /// Detect the original Steam ID from save files in a directory
 pub fn detect_original_steam_id(path: &PathBuf) -> Option<u64> {
     if !path.exists() {
@@ -164,3 +314,121 @@ pub fn detect_original_steam_id(path: &PathBuf) -> Option<u64> {
     }
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::test_dir;
+
+    const ORIGINAL_ID: u64 = 76561198000000001;
+    const TARGET_ID: u64 = 76561198999999999;
+
+    /// saves/
+    ///   {id}_Player.sav          (filename form)
+    ///   {id}/slot1.sav           (directory form)
+    ///   {id}/{id}_backup.sav     (both)
+    ///   {id}/nested/{id}/a.sav   (nested directory form)
+    ///   settings.ini
+    fn write_save_tree(root: &Path) {
+        let id = ORIGINAL_ID;
+        std::fs::create_dir_all(root.join(format!("{id}/nested/{id}"))).unwrap();
+        std::fs::write(root.join(format!("{id}_Player.sav")), "player").unwrap();
+        std::fs::write(root.join(format!("{id}/slot1.sav")), "slot1").unwrap();
+        std::fs::write(root.join(format!("{id}/{id}_backup.sav")), "backup").unwrap();
+        std::fs::write(root.join(format!("{id}/nested/{id}/a.sav")), "a").unwrap();
+        std::fs::write(root.join("settings.ini"), "ini").unwrap();
+    }
+
+    fn read(path: PathBuf) -> String {
+        std::fs::read_to_string(path).unwrap()
+    }
+
+    #[test]
+    fn copy_remaps_filenames_and_directories() {
+        let base = test_dir("steamid-copy");
+        let src = base.join("src");
+        let dest = base.join("dest");
+        write_save_tree(&src);
+
+        let detected = copy_dir_with_steam_id_remap(&src, &dest, TARGET_ID).unwrap();
+        assert_eq!(detected, Some(ORIGINAL_ID));
+
+        let t = TARGET_ID;
+        assert_eq!(read(dest.join(format!("{t}_Player.sav"))), "player");
+        assert_eq!(read(dest.join(format!("{t}/slot1.sav"))), "slot1");
+        assert_eq!(read(dest.join(format!("{t}/{t}_backup.sav"))), "backup");
+        assert_eq!(read(dest.join(format!("{t}/nested/{t}/a.sav"))), "a");
+        assert_eq!(read(dest.join("settings.ini")), "ini");
+        assert!(!dest.join(ORIGINAL_ID.to_string()).exists());
+
+        // Source is left untouched
+        assert!(src.join(format!("{ORIGINAL_ID}/slot1.sav")).exists());
+
+        std::fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[test]
+    fn snapshots_rotate_beyond_keep() {
+        let base = test_dir("save-rotation");
+        let saves = base.join("saves");
+        let dir = base.join("backups");
+        std::fs::create_dir_all(&saves).unwrap();
+        std::fs::create_dir_all(dir.join("profiles")).unwrap();
+
+        for i in 0..4 {
+            std::fs::write(saves.join("slot1.sav"), i.to_string()).unwrap();
+            snapshot_at(&saves, &dir, free_timestamp(&[&dir])).unwrap();
+        }
+        prune_backups(&dir, 2).unwrap();
+
+        let remaining = snapshots_in(&dir);
+        assert_eq!(remaining.len(), 2);
+        assert!(remaining[0].timestamp > remaining[1].timestamp);
+        assert_eq!(read(dir.join(remaining[0].timestamp.to_string()).join("slot1.sav")), "3");
+        assert_eq!(read(dir.join(remaining[1].timestamp.to_string()).join("slot1.sav")), "2");
+        // Non-snapshot entries are left alone
+        assert!(dir.join("profiles").exists());
+
+        std::fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[test]
+    fn copy_merges_into_existing_directory() {
+        let base = test_dir("steamid-merge");
+        let src = base.join("src");
+        let dest = base.join("dest");
+        write_save_tree(&src);
+        std::fs::create_dir_all(dest.join(TARGET_ID.to_string())).unwrap();
+        std::fs::write(dest.join(format!("{TARGET_ID}/slot1.sav")), "stale").unwrap();
+        std::fs::write(dest.join(format!("{TARGET_ID}/slot2.sav")), "slot2").unwrap();
+
+        copy_dir_with_steam_id_remap(&src, &dest, TARGET_ID).unwrap();
+
+        assert_eq!(read(dest.join(format!("{TARGET_ID}/slot1.sav"))), "slot1");
+        assert_eq!(read(dest.join(format!("{TARGET_ID}/slot2.sav"))), "slot2");
+        assert!(!dest.join(ORIGINAL_ID.to_string()).exists());
+
+        std::fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[test]
+    fn copy_leaves_other_ids_already_in_dest() {
+        const OTHER_ID: u64 = 76561198555555555;
+        let base = test_dir("steamid-other");
+        let src = base.join("src");
+        let dest = base.join("dest");
+        write_save_tree(&src);
+        std::fs::create_dir_all(dest.join(OTHER_ID.to_string())).unwrap();
+        std::fs::write(dest.join(format!("{OTHER_ID}/slot1.sav")), "other").unwrap();
+        std::fs::write(dest.join(format!("{OTHER_ID}_Player.sav")), "other player").unwrap();
+
+        copy_dir_with_steam_id_remap(&src, &dest, TARGET_ID).unwrap();
+
+        assert_eq!(read(dest.join(format!("{OTHER_ID}/slot1.sav"))), "other");
+        assert_eq!(read(dest.join(format!("{OTHER_ID}_Player.sav"))), "other player");
+        assert_eq!(read(dest.join(format!("{TARGET_ID}/slot1.sav"))), "slot1");
+        assert_eq!(read(dest.join(format!("{TARGET_ID}_Player.sav"))), "player");
+
+        std::fs::remove_dir_all(&base).unwrap();
+    }
+}
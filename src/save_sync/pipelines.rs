@@ -4,134 +4,132 @@
 use crate::handler::Handler;
 use crate::instance::Instance;
 use crate::profiles::generate_steam_id;
+use crate::util::run_per_instance;
 use std::error::Error;
 
 use super::operations::{
-    backup_saves, copy_dir_recursive, copy_dir_with_steam_id_remap, detect_original_steam_id,
-    profile_has_existing_saves,
+    backup_profile_save, backup_save, copy_save_dir, detect_original_steam_id, dir_has_content,
+    profile_has_existing_saves, replace_save_dir,
+};
+use super::pure::{
+    find_first_named_profile, get_handler_name, guest_save_source, save_locations, SaveLocation,
 };
-use super::pure::{find_first_named_profile, get_original_save_path, get_profile_save_path};
 
 /// Copy original saves to a profile
-/// For named profiles: skips if profile already has saves (preserves existing progress)
+/// For named profiles: skips paths the profile already has saves for (preserves existing progress)
 /// For guest profiles (starting with '.'): always copies fresh from original
 pub fn copy_original_saves_to_profile(
     h: &Handler,
     profile_name: &str,
 ) -> Result<(), Box<dyn Error>> {
-    let original_path = match get_original_save_path(h) {
-        Some(p) => p,
-        None => return Ok(()),
-    };
-
     // Guest profiles always get fresh copies, named profiles preserve existing saves
     let is_guest = profile_name.starts_with('.');
-    if !is_guest && profile_has_existing_saves(profile_name, h) {
-        println!(
-            "[splitux] Profile '{}' already has saves, skipping copy (preserving existing progress)",
-            profile_name
-        );
-        return Ok(());
-    }
+    let steam_id = profile_steam_id(h, profile_name);
+    copy_originals_into(&save_locations(profile_name, h), steam_id, !is_guest)
+}
 
-    if !original_path.exists() {
-        println!(
-            "[splitux] Save path does not exist (first run?): {}",
-            original_path.display()
+/// Goldberg Steam ID to remap a profile's saves to, if the handler remaps
+fn profile_steam_id(h: &Handler, profile_name: &str) -> Option<u64> {
+    h.save_steam_id_remap.then(|| {
+        let steam_id = generate_steam_id(profile_name);
+        log_info!(
+            "Steam ID remap enabled for profile '{}' (ID: {})",
+            profile_name, steam_id
         );
-        return Ok(());
-    }
-
-    let (profile_save_path, is_game_dir) = get_profile_save_path(profile_name, h);
+        steam_id
+    })
+}
 
-    println!(
-        "[splitux] Copying saves: {} -> {} {}",
-        original_path.display(),
-        profile_save_path.display(),
-        if is_game_dir { "(game dir overlay)" } else { "" }
-    );
+/// Copy each location's original saves into its profile copy
+///
+/// With `keep_existing`, locations the profile already has saves for are
+/// skipped. Originals that don't exist yet are skipped too.
+fn copy_originals_into(
+    locations: &[SaveLocation],
+    steam_id: Option<u64>,
+    keep_existing: bool,
+) -> Result<(), Box<dyn Error>> {
+    for location in locations {
+        if keep_existing && dir_has_content(&location.profile) {
+            log_info!(
+                "Profile already has saves in {}, skipping copy (preserving existing progress)",
+                location.profile.display()
+            );
+            continue;
+        }
 
-    std::fs::create_dir_all(&profile_save_path)?;
+        if !location.original.exists() {
+            log_info!(
+                "Save path does not exist (first run?): {}",
+                location.original.display()
+            );
+            continue;
+        }
 
-    if h.save_steam_id_remap {
-        // Use Steam ID remapping - replace original Steam ID with profile's Goldberg Steam ID
-        let profile_steam_id = generate_steam_id(profile_name);
-        println!(
-            "[splitux] Steam ID remap enabled for profile '{}' (ID: {})",
-            profile_name, profile_steam_id
+        log_info!(
+            "Copying saves: {} -> {} {}",
+            location.original.display(),
+            location.profile.display(),
+            if location.in_game_dir { "(game dir overlay)" } else { "" }
         );
-        copy_dir_with_steam_id_remap(&original_path, &profile_save_path, profile_steam_id)?;
-    } else {
-        copy_dir_recursive(&original_path, &profile_save_path)?;
+        copy_save_dir(&location.original, &location.profile, steam_id)?;
     }
-
     Ok(())
 }
 
 /// Sync master profile from original - always overwrites existing profile saves
 /// This is called at session start to ensure master has latest PC saves
-/// Both the original and profile saves are backed up before any modifications
-fn sync_master_from_original(h: &Handler, master: &str) -> Result<(), Box<dyn Error>> {
-    let original_path = match get_original_save_path(h) {
-        Some(p) => p,
-        None => return Ok(()),
-    };
-
-    if !original_path.exists() {
-        println!(
-            "[splitux] Original save path does not exist: {}",
-            original_path.display()
-        );
+/// Both the original and profile saves are backed up before any modifications,
+/// keeping the newest `backup_keep` snapshots of each
+fn sync_master_from_original(
+    h: &Handler,
+    master: &str,
+    backup_keep: usize,
+) -> Result<(), Box<dyn Error>> {
+    let locations: Vec<SaveLocation> = save_locations(master, h)
+        .into_iter()
+        .filter(|l| {
+            let exists = l.original.exists();
+            if !exists {
+                log_info!("Original save path does not exist: {}", l.original.display());
+            }
+            exists
+        })
+        .collect();
+    if locations.is_empty() {
         return Ok(());
     }
 
-    let (profile_save_path, is_game_dir) = get_profile_save_path(master, h);
-
-    println!(
-        "[splitux] Syncing master '{}' from original: {} -> {} {}",
-        master,
-        original_path.display(),
-        profile_save_path.display(),
-        if is_game_dir { "(game dir overlay)" } else { "" }
-    );
+    let handler_name = get_handler_name(h);
 
     // Backup original saves (the machine's save) before any operation
-    if let Err(e) = backup_saves(&original_path) {
-        println!(
-            "[splitux] Warning: Failed to backup original saves: {}",
+    if let Err(e) = backup_save(&handler_name, &locations, backup_keep) {
+        log_warn!(
+            "Failed to backup original saves: {}",
             e
         );
         // Continue anyway - backup failure shouldn't block the sync
     }
 
     // Backup profile saves before overwriting (preserves any unsaved progress)
-    if profile_save_path.exists() {
-        // Check if profile has actual content to backup
-        let has_content = std::fs::read_dir(&profile_save_path)
-            .map(|mut entries| entries.next().is_some())
-            .unwrap_or(false);
-
-        if has_content {
-            if let Err(e) = backup_saves(&profile_save_path) {
-                println!(
-                    "[splitux] Warning: Failed to backup profile saves: {}",
-                    e
-                );
-            }
-        }
+    if let Err(e) = backup_profile_save(&handler_name, master, &locations, backup_keep) {
+        log_warn!(
+            "Failed to backup profile saves: {}",
+            e
+        );
     }
 
     // Clear existing and copy fresh
-    if profile_save_path.exists() {
-        std::fs::remove_dir_all(&profile_save_path)?;
-    }
-    std::fs::create_dir_all(&profile_save_path)?;
-
-    if h.save_steam_id_remap {
-        let profile_steam_id = generate_steam_id(master);
-        copy_dir_with_steam_id_remap(&original_path, &profile_save_path, profile_steam_id)?;
-    } else {
-        copy_dir_recursive(&original_path, &profile_save_path)?;
+    let steam_id = profile_steam_id(h, master);
+    for location in &locations {
+        log_info!(
+            "Syncing master '{}' from original: {} -> {} {}",
+            master,
+            location.original.display(),
+            location.profile.display(),
+            if location.in_game_dir { "(game dir overlay)" } else { "" }
+        );
+        replace_save_dir(&location.original, &location.profile, steam_id)?;
     }
 
     Ok(())
@@ -139,134 +137,156 @@ fn sync_master_from_original(h: &Handler, master: &str) -> Result<(), Box<dyn Er
 
 /// Copy saves from one profile to another profile
 /// Handles Steam ID remapping if enabled
+///
+/// With `keep_existing`, save paths the target already has saves for are skipped.
 fn copy_profile_saves_to_profile(
     h: &Handler,
     source_profile: &str,
     target_profile: &str,
+    keep_existing: bool,
 ) -> Result<(), Box<dyn Error>> {
-    let (source_path, _) = get_profile_save_path(source_profile, h);
-    let (target_path, _) = get_profile_save_path(target_profile, h);
-
-    if !source_path.exists() {
-        println!(
-            "[splitux] Source profile '{}' has no saves to copy",
-            source_profile
-        );
-        return Ok(());
-    }
-
-    // Check for non-empty source
-    let has_content = std::fs::read_dir(&source_path)
-        .map(|mut entries| entries.next().is_some())
-        .unwrap_or(false);
-    if !has_content {
-        return Ok(());
-    }
-
-    println!(
-        "[splitux] Inheriting saves: {} -> {}",
-        source_profile, target_profile
-    );
+    let steam_id = profile_steam_id(h, target_profile);
+    let targets = save_locations(target_profile, h);
+
+    for (source, target) in save_locations(source_profile, h).iter().zip(&targets) {
+        if keep_existing && dir_has_content(&target.profile) {
+            log_info!(
+                "Profile '{}' already has saves in {}, keeping existing",
+                target_profile,
+                target.profile.display()
+            );
+            continue;
+        }
 
-    std::fs::create_dir_all(&target_path)?;
+        // Check for non-empty source
+        if !dir_has_content(&source.profile) {
+            log_info!(
+                "Source profile '{}' has no saves in {}",
+                source_profile,
+                source.profile.display()
+            );
+            continue;
+        }
 
-    if h.save_steam_id_remap {
-        let target_steam_id = generate_steam_id(target_profile);
-        copy_dir_with_steam_id_remap(&source_path, &target_path, target_steam_id)?;
-    } else {
-        copy_dir_recursive(&source_path, &target_path)?;
+        log_info!(
+            "Inheriting saves: {} -> {} ({})",
+            source_profile,
+            target_profile,
+            target.profile.display()
+        );
+        copy_save_dir(&source.profile, &target.profile, steam_id)?;
     }
     Ok(())
 }
 
 /// Initialize profile saves using master-based inheritance
 ///
+/// With `parallel`, step 2 copies each instance's saves on its own thread.
+/// `backup_keep` is how many save snapshots to keep when the master is synced.
+/// `guest_base` is a profile every guest starts from instead of the master;
+/// guests are never synced back, so the copies are throwaway.
+///
 /// Flow:
 /// 1. If master profile is set and has no saves -> copy from original to master
 /// 2. For each instance:
-///    - Guest profiles -> always fresh copy from master (or original if no master)
+///    - Guest profiles -> always fresh copy from the guest base or master
+///      (or original if neither)
 ///    - Named profiles with no saves -> inherit from master (or original if no master)
 ///    - Named profiles with saves -> keep existing (no copy)
+///
+/// Each of the handler's save paths goes through this on its own, so a named
+/// profile missing only one of them still inherits that one.
 pub fn initialize_profile_saves(
     h: &Handler,
     instances: &[Instance],
     master_profile: Option<&str>,
+    guest_base: Option<&str>,
+    parallel: bool,
+    backup_keep: usize,
 ) -> Result<(), Box<dyn Error>> {
-    if h.original_save_path.is_empty() {
+    if h.original_save_paths.is_empty() {
         return Ok(());
     }
 
-    println!(
-        "[splitux] Initializing profile saves (master: {:?})...",
+    log_info!(
+        "Initializing profile saves (master: {:?})...",
         master_profile
     );
 
     // Step 1: Master profile ALWAYS syncs from original at session start
     // This ensures master always has the latest PC saves
     if let Some(master) = master_profile {
-        if let Err(e) = sync_master_from_original(h, master) {
-            println!("[splitux] Warning: Failed to sync master from original: {}", e);
+        if let Err(e) = sync_master_from_original(h, master, backup_keep) {
+            log_warn!("Failed to sync master from original: {}", e);
         }
     }
 
+    // A base profile with no saves of its own may be filled by a worker
+    // below while guests read it, so it only counts if it already has saves
+    let guest_base = guest_base.filter(|base| {
+        master_profile == Some(*base) || {
+            let ready = profile_has_existing_saves(base, h);
+            if !ready {
+                log_info!("Guest base profile '{}' has no saves, guests start as usual", base);
+            }
+            ready
+        }
+    });
+    let guest_source = guest_save_source(guest_base, master_profile);
+
     // Step 2: Initialize each instance's profile
-    for instance in instances {
+    // Master and guest base are ready before this point, so workers only read from them
+    run_per_instance(instances.len(), parallel, |i| {
+        let instance = &instances[i];
         let is_guest = instance.profname.starts_with('.');
         let is_master = master_profile == Some(instance.profname.as_str());
 
         if is_master {
             // Master was already handled above
-            continue;
+            return Ok(());
         }
 
         if is_guest {
             // Guest profiles always get fresh copies
-            if let Some(master) = master_profile {
-                // Copy from master
-                if let Err(e) = copy_profile_saves_to_profile(h, master, &instance.profname) {
-                    println!(
-                        "[splitux] Warning: Failed to copy saves to guest '{}': {}",
+            if let Some(source) = guest_source {
+                // Copy from the guest base or master
+                if let Err(e) = copy_profile_saves_to_profile(h, source, &instance.profname, false) {
+                    log_warn!(
+                        "Failed to copy saves to guest '{}': {}",
                         instance.profname, e
                     );
                 }
             } else {
                 // No master, copy from original
                 // Need to clear any existing guest saves first
-                let (guest_path, _) = get_profile_save_path(&instance.profname, h);
-                if guest_path.exists() {
-                    let _ = std::fs::remove_dir_all(&guest_path);
+                for location in save_locations(&instance.profname, h) {
+                    if location.profile.exists() {
+                        let _ = std::fs::remove_dir_all(&location.profile);
+                    }
                 }
                 if let Err(e) = copy_original_saves_to_profile(h, &instance.profname) {
-                    println!(
-                        "[splitux] Warning: Failed to copy saves to guest '{}': {}",
-                        instance.profname, e
-                    );
-                }
-            }
-        } else if !profile_has_existing_saves(&instance.profname, h) {
-            // Named profile with no saves - inherit from master or original
-            if let Some(master) = master_profile {
-                if let Err(e) = copy_profile_saves_to_profile(h, master, &instance.profname) {
-                    println!(
-                        "[splitux] Warning: Failed to inherit saves for '{}': {}",
-                        instance.profname, e
-                    );
-                }
-            } else {
-                if let Err(e) = copy_original_saves_to_profile(h, &instance.profname) {
-                    println!(
-                        "[splitux] Warning: Failed to setup saves for '{}': {}",
+                    log_warn!(
+                        "Failed to copy saves to guest '{}': {}",
                         instance.profname, e
                     );
                 }
             }
         } else {
-            println!(
-                "[splitux] Profile '{}' already has saves, keeping existing",
-                instance.profname
-            );
+            // Named profile - save paths it has no saves for yet inherit from
+            // master (or original if no master), the others are kept
+            let result = match master_profile {
+                Some(master) => copy_profile_saves_to_profile(h, master, &instance.profname, true),
+                None => copy_original_saves_to_profile(h, &instance.profname),
+            };
+            if let Err(e) = result {
+                log_warn!(
+                    "Failed to setup saves for '{}': {}",
+                    instance.profname, e
+                );
+            }
         }
-    }
+        Ok(())
+    })?;
 
     Ok(())
 }
@@ -277,12 +297,15 @@ pub fn initialize_profile_saves(
 /// - save_sync_back is enabled
 /// - original_save_path is set
 /// - master profile participated in the session
+///
+/// `backup_keep` is how many save snapshots to keep.
 pub fn sync_master_saves_back(
     h: &Handler,
     instances: &[Instance],
     master_profile: Option<&str>,
+    backup_keep: usize,
 ) -> Result<(), Box<dyn Error>> {
-    if !h.save_sync_back || h.original_save_path.is_empty() {
+    if !h.save_sync_back || h.original_save_paths.is_empty() {
         return Ok(());
     }
 
@@ -290,175 +313,193 @@ pub fn sync_master_saves_back(
         Some(m) => m,
         None => {
             // No master designated - fall back to first named profile (legacy behavior)
-            return sync_saves_back(h, instances);
+            return sync_saves_back(h, instances, backup_keep);
         }
     };
 
     // Check if master profile participated in this session
     if !instances.iter().any(|i| i.profname == master) {
-        println!(
-            "[splitux] Master profile '{}' not in session, skipping sync back",
+        log_info!(
+            "Master profile '{}' not in session, skipping sync back",
             master
         );
         return Ok(());
     }
 
-    let original_path = match get_original_save_path(h) {
-        Some(p) => p,
-        None => return Ok(()),
-    };
-
-    let (profile_save_path, _) = get_profile_save_path(master, h);
-
-    if !profile_save_path.exists() {
-        println!(
-            "[splitux] Master profile saves not found: {}",
-            profile_save_path.display()
-        );
+    let locations = locations_with_profile_saves(master, h);
+    if locations.is_empty() {
         return Ok(());
     }
 
-    println!(
-        "[splitux] Syncing master '{}' back to original: {}",
-        master,
-        original_path.display()
-    );
+    log_info!("Syncing master '{}' back to original", master);
 
-    // Detect original Steam ID before we modify anything (for remapping back)
-    let original_steam_id = if h.save_steam_id_remap {
-        detect_original_steam_id(&original_path)
-    } else {
-        None
-    };
+    let handler_name = get_handler_name(h);
 
     // Backup master profile saves before sync (preserves session progress)
-    if let Err(e) = backup_saves(&profile_save_path) {
-        println!("[splitux] Warning: Failed to backup master profile: {}", e);
+    if let Err(e) = backup_profile_save(&handler_name, master, &locations, backup_keep) {
+        log_warn!("Failed to backup master profile: {}", e);
     }
 
     // Backup original saves before overwriting
-    if original_path.exists() {
-        if let Err(e) = backup_saves(&original_path) {
-            println!("[splitux] Warning: Failed to backup original: {}", e);
-        }
+    if let Err(e) = backup_save(&handler_name, &locations, backup_keep) {
+        log_warn!("Failed to backup original: {}", e);
     }
 
-    // Clear and copy
-    if original_path.exists() {
-        for entry in std::fs::read_dir(&original_path)? {
-            let p = entry?.path();
-            if p.is_dir() {
-                std::fs::remove_dir_all(&p)?;
-            } else {
-                std::fs::remove_file(&p)?;
-            }
-        }
-    } else {
-        std::fs::create_dir_all(&original_path)?;
-    }
-
-    if h.save_steam_id_remap {
-        if let Some(target_steam_id) = original_steam_id {
-            println!(
-                "[splitux] Remapping saves back to original Steam ID: {}",
-                target_steam_id
-            );
-            copy_dir_with_steam_id_remap(&profile_save_path, &original_path, target_steam_id)?;
-        } else {
-            println!("[splitux] No original Steam ID detected, copying without remap");
-            copy_dir_recursive(&profile_save_path, &original_path)?;
-        }
-    } else {
-        copy_dir_recursive(&profile_save_path, &original_path)?;
-    }
+    sync_locations_back(&locations, h.save_steam_id_remap)?;
 
-    println!("[splitux] Master sync complete");
+    log_info!("Master sync complete");
 
     Ok(())
 }
 
 /// Sync saves from first named profile back to original location
-pub fn sync_saves_back(h: &Handler, instances: &[Instance]) -> Result<(), Box<dyn Error>> {
-    if !h.save_sync_back || h.original_save_path.is_empty() {
+pub fn sync_saves_back(
+    h: &Handler,
+    instances: &[Instance],
+    backup_keep: usize,
+) -> Result<(), Box<dyn Error>> {
+    if !h.save_sync_back || h.original_save_paths.is_empty() {
         return Ok(());
     }
 
     let profile_name = match find_first_named_profile(instances) {
         Some(name) => name,
         None => {
-            println!("[splitux] No named profiles, skipping sync back");
+            log_info!("No named profiles, skipping sync back");
             return Ok(());
         }
     };
 
-    let original_path = match get_original_save_path(h) {
-        Some(p) => p,
-        None => return Ok(()),
-    };
-
-    let (profile_save_path, _) = get_profile_save_path(profile_name, h);
-
-    if !profile_save_path.exists() {
-        println!(
-            "[splitux] Profile saves not found: {}",
-            profile_save_path.display()
-        );
+    let locations = locations_with_profile_saves(profile_name, h);
+    if locations.is_empty() {
         return Ok(());
     }
 
-    println!(
-        "[splitux] Syncing back: {} -> {}",
-        profile_save_path.display(),
-        original_path.display()
-    );
-
-    // Detect original Steam ID before we modify anything (for remapping back)
-    let original_steam_id = if h.save_steam_id_remap {
-        detect_original_steam_id(&original_path)
-    } else {
-        None
-    };
-
     // Always backup before overwriting
-    if original_path.exists() {
-        if let Err(e) = backup_saves(&original_path) {
-            println!("[splitux] Warning: Backup failed: {}", e);
-        }
+    if let Err(e) = backup_save(&get_handler_name(h), &locations, backup_keep) {
+        log_warn!("Backup failed: {}", e);
     }
 
-    // Clear and copy
-    if original_path.exists() {
-        for entry in std::fs::read_dir(&original_path)? {
-            let p = entry?.path();
-            if p.is_dir() {
-                std::fs::remove_dir_all(&p)?;
-            } else {
-                std::fs::remove_file(&p)?;
+    sync_locations_back(&locations, h.save_steam_id_remap)?;
+
+    log_info!("Sync complete");
+
+    Ok(())
+}
+
+/// A profile's save locations that have a profile copy to sync back
+fn locations_with_profile_saves(profile_name: &str, h: &Handler) -> Vec<SaveLocation> {
+    save_locations(profile_name, h)
+        .into_iter()
+        .filter(|l| {
+            let exists = l.profile.exists();
+            if !exists {
+                log_info!("Profile saves not found: {}", l.profile.display());
             }
-        }
-    } else {
-        std::fs::create_dir_all(&original_path)?;
-    }
+            exists
+        })
+        .collect()
+}
+
+/// Replace each location's original saves with its profile copy
+///
+/// With `remap`, Steam IDs are mapped back to the one found in each original
+/// before it is overwritten.
+fn sync_locations_back(locations: &[SaveLocation], remap: bool) -> Result<(), Box<dyn Error>> {
+    for location in locations {
+        log_info!(
+            "Syncing back: {} -> {}",
+            location.profile.display(),
+            location.original.display()
+        );
 
-    if h.save_steam_id_remap {
-        if let Some(target_steam_id) = original_steam_id {
+        // Detect original Steam ID before we modify anything (for remapping back)
+        let original_steam_id = if remap {
+            detect_original_steam_id(&location.original)
+        } else {
+            None
+        };
+        match original_steam_id {
             // Remap profile's Goldberg Steam ID back to original user's Steam ID
-            println!(
-                "[splitux] Remapping saves back to original Steam ID: {}",
+            Some(target_steam_id) => log_info!(
+                "Remapping saves back to original Steam ID: {}",
                 target_steam_id
-            );
-            copy_dir_with_steam_id_remap(&profile_save_path, &original_path, target_steam_id)?;
-        } else {
+            ),
             // No original Steam ID found - copy without remapping
             // This happens on first run when there are no original saves
-            println!("[splitux] No original Steam ID detected, copying without remap");
-            copy_dir_recursive(&profile_save_path, &original_path)?;
+            None if remap => log_info!("No original Steam ID detected, copying without remap"),
+            None => {}
         }
-    } else {
-        copy_dir_recursive(&profile_save_path, &original_path)?;
+
+        replace_save_dir(&location.profile, &location.original, original_steam_id)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::test_dir;
+    use std::path::{Path, PathBuf};
+
+    /// Saves split across AppData/Local and AppData/LocalLow, as some games do
+    fn two_locations(base: &Path) -> Vec<SaveLocation> {
+        ["AppData/Local/Game", "AppData/LocalLow/Co/Game"]
+            .iter()
+            .enumerate()
+            .map(|(index, path)| SaveLocation {
+                index,
+                original: base.join("host").join(path),
+                profile: base.join("profile/windata").join(path),
+                in_game_dir: false,
+            })
+            .collect()
     }
 
-    println!("[splitux] Sync complete");
+    fn read(path: PathBuf) -> String {
+        std::fs::read_to_string(path).unwrap()
+    }
 
-    Ok(())
+    fn write(path: PathBuf, contents: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, contents).unwrap();
+    }
+
+    #[test]
+    fn every_save_path_is_copied_into_the_profile() {
+        let base = test_dir("multi-save-copy");
+        let locations = two_locations(&base);
+        write(locations[0].original.join("settings.cfg"), "local");
+        write(locations[1].original.join("slot1.sav"), "locallow");
+
+        copy_originals_into(&locations, None, true).unwrap();
+        assert_eq!(read(locations[0].profile.join("settings.cfg")), "local");
+        assert_eq!(read(locations[1].profile.join("slot1.sav")), "locallow");
+
+        // A named profile keeps the path it already has, and still gets the other
+        std::fs::remove_dir_all(&locations[1].profile).unwrap();
+        write(locations[0].profile.join("settings.cfg"), "progress");
+        copy_originals_into(&locations, None, true).unwrap();
+        assert_eq!(read(locations[0].profile.join("settings.cfg")), "progress");
+        assert_eq!(read(locations[1].profile.join("slot1.sav")), "locallow");
+
+        std::fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[test]
+    fn every_save_path_is_synced_back() {
+        let base = test_dir("multi-save-sync");
+        let locations = two_locations(&base);
+        write(locations[0].original.join("stale.cfg"), "old");
+        write(locations[0].profile.join("settings.cfg"), "local");
+        write(locations[1].profile.join("slot1.sav"), "locallow");
+
+        sync_locations_back(&locations, false).unwrap();
+        assert_eq!(read(locations[0].original.join("settings.cfg")), "local");
+        assert!(!locations[0].original.join("stale.cfg").exists());
+        // An original that didn't exist yet is created
+        assert_eq!(read(locations[1].original.join("slot1.sav")), "locallow");
+
+        std::fs::remove_dir_all(&base).unwrap();
+    }
 }
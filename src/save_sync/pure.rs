@@ -5,18 +5,57 @@ use crate::handler::Handler;
 use crate::instance::Instance;
 use crate::paths::{PATH_HOME, PATH_PARTY};
 use regex::Regex;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-/// Expand ~ and $HOME in path
+/// Expand ~, $HOME, $XDG_DATA_HOME and $XDG_CONFIG_HOME in path
 pub fn expand_path(path: &str) -> PathBuf {
+    let xdg_data = std::env::var_os("XDG_DATA_HOME").map(PathBuf::from);
+    let xdg_config = std::env::var_os("XDG_CONFIG_HOME").map(PathBuf::from);
+    expand_path_in(path, &PATH_HOME, xdg_data.as_deref(), xdg_config.as_deref())
+}
+
+/// Expand ~ and home/XDG variables against a given home directory
+///
+/// Unset XDG directories default to `.local/share` and `.config` under
+/// `home`. Both `$VAR` and `${VAR}` forms are accepted.
+pub fn expand_path_in(path: &str, home: &Path, xdg_data: Option<&Path>, xdg_config: Option<&Path>) -> PathBuf {
+    let home_str = home.to_string_lossy();
+    let data = xdg_data.map_or_else(|| home.join(".local/share"), Path::to_path_buf);
+    let config = xdg_config.map_or_else(|| home.join(".config"), Path::to_path_buf);
+
     let mut s = path.to_string();
-    if s.starts_with("~/") {
-        s = s.replacen("~", &PATH_HOME.to_string_lossy(), 1);
+    if s == "~" || s.starts_with("~/") {
+        s = s.replacen("~", &home_str, 1);
+    }
+    for (var, value) in [
+        ("XDG_DATA_HOME", data.to_string_lossy()),
+        ("XDG_CONFIG_HOME", config.to_string_lossy()),
+        ("HOME", home_str.clone()),
+    ] {
+        s = s.replace(&format!("${{{}}}", var), &value);
+        s = s.replace(&format!("${}", var), &value);
     }
-    s = s.replace("$HOME", &PATH_HOME.to_string_lossy());
     PathBuf::from(s)
 }
 
+/// Resolve a native game's save path against a home directory
+///
+/// Relative paths are taken as relative to `home`, the same way the game
+/// sees them from its (per-profile) HOME.
+pub fn resolve_native_save_path(
+    path: &str,
+    home: &Path,
+    xdg_data: Option<&Path>,
+    xdg_config: Option<&Path>,
+) -> PathBuf {
+    let expanded = expand_path_in(path, home, xdg_data, xdg_config);
+    if expanded.is_absolute() {
+        expanded
+    } else {
+        home.join(expanded)
+    }
+}
+
 /// Get the game root directory from handler
 pub fn get_game_root(h: &Handler) -> Option<PathBuf> {
     if !h.path_gameroot.is_empty() {
@@ -35,11 +74,37 @@ pub fn get_handler_name(h: &Handler) -> String {
         .unwrap_or_else(|| "unknown".to_string())
 }
 
-/// Determine where to copy saves in the profile
+/// One declared save path: where it lives on the host and in a profile
+#[derive(Clone, Debug)]
+pub struct SaveLocation {
+    /// Position in the handler's save path list
+    pub index: usize,
+    pub original: PathBuf,
+    pub profile: PathBuf,
+    /// The profile copy sits in the gamesaves overlay upperdir
+    pub in_game_dir: bool,
+}
+
+/// Every save path of a handler, resolved for one profile
+pub fn save_locations(profile_name: &str, h: &Handler) -> Vec<SaveLocation> {
+    (0..h.original_save_paths.len())
+        .filter_map(|index| {
+            let original = get_original_save_path(h, index)?;
+            let (profile, in_game_dir) = get_profile_save_path(profile_name, h, index);
+            Some(SaveLocation { index, original, profile, in_game_dir })
+        })
+        .collect()
+}
+
+/// Determine where to copy one save path in the profile
 /// Returns (profile_save_path, is_inside_game_dir)
-pub fn get_profile_save_path(profile_name: &str, h: &Handler) -> (PathBuf, bool) {
+///
+/// Native and Windows games are told apart by the executable (`Handler::win`):
+/// native save paths map into the profile's HOME, Windows ones into windata.
+pub fn get_profile_save_path(profile_name: &str, h: &Handler, index: usize) -> (PathBuf, bool) {
     let profile_path = PATH_PARTY.join("profiles").join(profile_name);
-    let original = expand_path(&h.original_save_path);
+    let save_path = h.original_save_paths.get(index).map_or("", String::as_str);
+    let original = get_original_save_path(h, index).unwrap_or_default();
     let handler_name = get_handler_name(h);
 
     // Check if save path is inside game directory
@@ -54,30 +119,110 @@ pub fn get_profile_save_path(profile_name: &str, h: &Handler) -> (PathBuf, bool)
         }
     }
 
-    // Check if under HOME (Linux native games)
+    let profile_home = profile_path.join("home");
+    if !h.win() {
+        // ~, $HOME, $XDG_* and relative paths resolve inside the profile's
+        // HOME, where XDG directories are at their defaults
+        let in_profile = resolve_native_save_path(save_path, &profile_home, None, None);
+        if in_profile.starts_with(&profile_home) {
+            return (in_profile, false);
+        }
+    }
+
+    // Absolute paths under HOME keep their place relative to it
     if let Ok(relative) = original.strip_prefix(&*PATH_HOME) {
-        let dest = profile_path.join("home").join(relative);
+        let dest = profile_home.join(relative);
         return (dest, false);
     }
 
-    // For Windows games or other paths, use windata if it looks like AppData
-    if h.win() || h.original_save_path.contains("AppData") {
-        // Keep the relative structure for windata
-        let dest = profile_path.join("windata").join(&h.original_save_path);
+    // Windows games keep the relative structure inside windata
+    if h.win() {
+        let dest = profile_path.join("windata").join(save_path);
         return (dest, false);
     }
 
-    // Fallback: put in gamesaves
-    let dest = profile_path.join("gamesaves").join(&handler_name);
+    // Fallback: put in gamesaves, later paths next to the first
+    let dest = profile_path.join("gamesaves").join(fallback_save_dir(&handler_name, index));
     (dest, false)
 }
 
-/// Get the original save path (just expand variables)
-pub fn get_original_save_path(h: &Handler) -> Option<PathBuf> {
-    if h.original_save_path.is_empty() {
+/// gamesaves directory for a save path that maps nowhere else
+fn fallback_save_dir(handler_name: &str, index: usize) -> String {
+    match index {
+        0 => handler_name.to_string(),
+        _ => format!("{}.save{}", handler_name, index + 1),
+    }
+}
+
+/// Get one original save path on the host
+///
+/// Variables are expanded; relative paths of native games are relative to HOME.
+pub fn get_original_save_path(h: &Handler, index: usize) -> Option<PathBuf> {
+    let save_path = h.original_save_paths.get(index).filter(|p| !p.is_empty())?;
+    if h.win() {
+        return Some(expand_path(save_path));
+    }
+    let xdg_data = std::env::var_os("XDG_DATA_HOME").map(PathBuf::from);
+    let xdg_config = std::env::var_os("XDG_CONFIG_HOME").map(PathBuf::from);
+    Some(resolve_native_save_path(
+        save_path,
+        &PATH_HOME,
+        xdg_data.as_deref(),
+        xdg_config.as_deref(),
+    ))
+}
+
+/// Save directory relative to the game root, if the saves live inside it
+///
+/// Used to bind-mount the save directory past the game overlay.
+pub fn save_subpath_in_game_root(save_path: &Path, game_root: &Path) -> Option<PathBuf> {
+    let relative = save_path.strip_prefix(game_root).ok()?;
+    if relative.as_os_str().is_empty() {
+        // Binding over the whole game root would hide the game itself
         return None;
     }
-    Some(expand_path(&h.original_save_path))
+    Some(relative.to_path_buf())
+}
+
+/// Directory holding a handler's save snapshots
+///
+/// Snapshots of the original saves sit directly inside it; profile snapshots
+/// go under `profiles/<profile>/`.
+pub fn save_backup_dir(handler_name: &str) -> PathBuf {
+    PATH_PARTY.join("save_backups").join(handler_name)
+}
+
+/// Snapshot directory for one save path
+///
+/// The first path uses the handler's directory, so snapshots taken before
+/// several paths were supported stay listed; later ones get `paths/<n>/`
+/// with the same layout.
+pub fn save_backup_dir_for(handler_name: &str, index: usize) -> PathBuf {
+    backup_dir_in(&save_backup_dir(handler_name), index)
+}
+
+fn backup_dir_in(base: &Path, index: usize) -> PathBuf {
+    match index {
+        0 => base.to_path_buf(),
+        _ => base.join("paths").join((index + 1).to_string()),
+    }
+}
+
+/// Parse a snapshot directory name (seconds since the Unix epoch)
+pub fn parse_backup_name(name: &str) -> Option<u64> {
+    if name.is_empty() || !name.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    name.parse().ok()
+}
+
+/// Snapshots to delete so only the newest `keep` remain
+///
+/// At least one snapshot is always kept, so the one just taken survives.
+pub fn backups_to_prune(timestamps: &[u64], keep: usize) -> Vec<u64> {
+    let mut sorted = timestamps.to_vec();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+    sorted.into_iter().skip(keep.max(1)).collect()
 }
 
 /// Steam64 ID regex pattern - matches 17-digit Steam IDs starting with 7656119
@@ -100,6 +245,13 @@ pub fn extract_steam_id_from_filename(filename: &str) -> Option<(u64, String)> {
     None
 }
 
+/// Replace a Steam ID prefix in a file or directory name
+/// Returns Some((original_id, new_name)) if the name starts with a Steam ID
+pub fn remap_steam_id_name(name: &str, target_steam_id: u64) -> Option<(u64, String)> {
+    extract_steam_id_from_filename(name)
+        .map(|(original_id, rest)| (original_id, format!("{}{}", target_steam_id, rest)))
+}
+
 /// Find first named (non-guest) profile
 pub fn find_first_named_profile(instances: &[Instance]) -> Option<&str> {
     instances
@@ -107,3 +259,117 @@ pub fn find_first_named_profile(instances: &[Instance]) -> Option<&str> {
         .find(|i| !i.profname.starts_with('.'))
         .map(|i| i.profname.as_str())
 }
+
+/// Profile a guest's saves are copied from at session start
+///
+/// The base profile chosen for guests wins over the master; None means
+/// guests start from the original saves.
+pub fn guest_save_source<'a>(guest_base: Option<&'a str>, master: Option<&'a str>) -> Option<&'a str> {
+    guest_base.or(master)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn save_subpath_inside_game_root() {
+        let root = Path::new("/games/MyGame");
+        assert_eq!(
+            save_subpath_in_game_root(Path::new("/games/MyGame/Saves/slot1"), root),
+            Some(PathBuf::from("Saves/slot1"))
+        );
+    }
+
+    #[test]
+    fn remap_steam_id_name_replaces_prefix() {
+        assert_eq!(
+            remap_steam_id_name("76561198000000001_Player.sav", 76561198999999999),
+            Some((76561198000000001, "76561198999999999_Player.sav".to_string()))
+        );
+        assert_eq!(
+            remap_steam_id_name("76561198000000001", 76561198999999999),
+            Some((76561198000000001, "76561198999999999".to_string()))
+        );
+        assert_eq!(remap_steam_id_name("slot1.sav", 76561198999999999), None);
+        assert_eq!(remap_steam_id_name("1234567", 76561198999999999), None);
+    }
+
+    #[test]
+    fn parse_backup_name_accepts_only_timestamps() {
+        assert_eq!(parse_backup_name("1760000000"), Some(1760000000));
+        assert_eq!(parse_backup_name("profiles"), None);
+        assert_eq!(parse_backup_name("Saves_1760000000"), None);
+        assert_eq!(parse_backup_name("+12"), None);
+        assert_eq!(parse_backup_name(""), None);
+    }
+
+    #[test]
+    fn backups_to_prune_drops_oldest_beyond_keep() {
+        let mut pruned = backups_to_prune(&[30, 10, 50, 20, 40], 3);
+        pruned.sort_unstable();
+        assert_eq!(pruned, vec![10, 20]);
+        assert!(backups_to_prune(&[10, 20], 5).is_empty());
+        // keep = 0 still keeps the newest
+        assert_eq!(backups_to_prune(&[10, 20], 0), vec![10]);
+    }
+
+    #[test]
+    fn save_subpath_outside_or_at_game_root() {
+        let root = Path::new("/games/MyGame");
+        assert_eq!(save_subpath_in_game_root(Path::new("/home/user/.local/share/MyGame"), root), None);
+        assert_eq!(save_subpath_in_game_root(Path::new("/games/MyGame"), root), None);
+    }
+
+    #[test]
+    fn later_save_paths_get_their_own_dirs() {
+        assert_eq!(fallback_save_dir("game", 0), "game");
+        assert_eq!(fallback_save_dir("game", 1), "game.save2");
+        let base = Path::new("/p/save_backups/game");
+        assert_eq!(backup_dir_in(base, 0), base);
+        assert_eq!(backup_dir_in(base, 1), base.join("paths/2"));
+    }
+
+    #[test]
+    fn guests_prefer_base_profile_over_master() {
+        assert_eq!(guest_save_source(Some("Host"), Some("Alice")), Some("Host"));
+        assert_eq!(guest_save_source(None, Some("Alice")), Some("Alice"));
+        assert_eq!(guest_save_source(None, None), None);
+    }
+
+    #[test]
+    fn native_save_paths_expand_tilde_and_relative() {
+        let home = Path::new("/home/u");
+        assert_eq!(
+            resolve_native_save_path("~/.local/share/Game/saves", home, None, None),
+            PathBuf::from("/home/u/.local/share/Game/saves")
+        );
+        assert_eq!(
+            resolve_native_save_path(".config/Game", home, None, None),
+            PathBuf::from("/home/u/.config/Game")
+        );
+        assert_eq!(
+            resolve_native_save_path("/srv/Game", home, None, None),
+            PathBuf::from("/srv/Game")
+        );
+    }
+
+    #[test]
+    fn native_save_paths_expand_xdg_dirs() {
+        let home = Path::new("/home/u");
+        assert_eq!(
+            resolve_native_save_path("$XDG_DATA_HOME/Game", home, None, None),
+            PathBuf::from("/home/u/.local/share/Game")
+        );
+        assert_eq!(
+            resolve_native_save_path("${XDG_CONFIG_HOME}/Game", home, None, Some(Path::new("/cfg"))),
+            PathBuf::from("/cfg/Game")
+        );
+        // A profile HOME always uses the default XDG locations
+        let profile_home = Path::new("/p/profiles/Alice/home");
+        assert_eq!(
+            resolve_native_save_path("$XDG_DATA_HOME/Game", profile_home, None, None),
+            PathBuf::from("/p/profiles/Alice/home/.local/share/Game")
+        );
+    }
+}
@@ -0,0 +1,338 @@
+// Session file module
+// Headless launches for kiosks and cabinets: a YAML file names the game, its
+// players and their devices, and `splitux --session <file>` launches it
+// without the GUI
+//
+// Example:
+//
+//   handler: my-game          # handler directory name
+//   layout: 2p_vertical       # optional layout preset ID
+//   instances:
+//     - profile: Alice        # optional; a guest if unset
+//       monitor: DP-1         # optional; the first monitor if unset
+//       devices: ["aa:bb:cc:dd:ee:ff"]  # Bluetooth MAC or USB serial
+//       args: -player {player}  # optional extra game arguments
+//     - {}                    # no devices: the next free gamepad
+
+use serde::Deserialize;
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::config::load_cfg;
+use crate::handler::scan_handlers;
+use crate::input::{scan_input_devices, DeviceInfo, DeviceType};
+use crate::instance::*;
+use crate::launch::{run_session, KillSwitch, SessionOutcome};
+use crate::monitor::Monitor;
+use crate::profiles::scan_profiles;
+use crate::util::steam_client_running;
+
+/// Session descriptor read from `--session <file.yaml>`
+#[derive(Debug, Clone, Deserialize)]
+pub struct SessionFile {
+    /// Handler directory name of the game to launch
+    pub handler: String,
+    /// Layout preset ID; the configured preset for the player count if unset
+    #[serde(default)]
+    pub layout: Option<String>,
+    pub instances: Vec<SessionFileInstance>,
+}
+
+/// One player of a session file
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SessionFileInstance {
+    /// Profile name; a guest if unset
+    #[serde(default)]
+    pub profile: Option<String>,
+    /// Monitor output or connector name; the first monitor if unset
+    #[serde(default)]
+    pub monitor: Option<String>,
+    /// Device uniqs (Bluetooth MAC or USB serial) that must be connected.
+    /// With none listed, the player gets the next free gamepad.
+    #[serde(default)]
+    pub devices: Vec<String>,
+    /// Extra game arguments for this player (see `Instance::args_override`)
+    #[serde(default)]
+    pub args: String,
+}
+
+/// Why a headless session couldn't be launched
+#[derive(Debug)]
+pub enum SessionFileError {
+    /// The file is unreadable or describes an impossible session
+    Invalid(String),
+    /// No installed handler has the file's handler name
+    HandlerNotFound(String),
+    /// The handler's game folder can't be found
+    GameRootMissing(String),
+    /// Required devices aren't connected
+    DevicesMissing(Vec<String>),
+    /// The game failed to launch
+    LaunchFailed,
+    /// The game launched, but this many instances exited with an error
+    InstanceFailed(usize),
+}
+
+impl fmt::Display for SessionFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SessionFileError::Invalid(e) => write!(f, "Invalid session file: {}", e),
+            SessionFileError::HandlerNotFound(name) => write!(f, "Handler '{}' not found", name),
+            SessionFileError::GameRootMissing(e) => write!(f, "Game folder not found: {}", e),
+            SessionFileError::DevicesMissing(missing) => {
+                write!(f, "Required devices not connected: {}", missing.join(", "))
+            }
+            SessionFileError::LaunchFailed => write!(f, "The game failed to launch"),
+            SessionFileError::InstanceFailed(count) => {
+                write!(f, "{} instance(s) exited with an error", count)
+            }
+        }
+    }
+}
+
+impl SessionFile {
+    pub fn load(path: &Path) -> Result<Self, SessionFileError> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| SessionFileError::Invalid(format!("{}: {}", path.display(), e)))?;
+        let file: Self = serde_yaml::from_str(&content)
+            .map_err(|e| SessionFileError::Invalid(format!("{}: {}", path.display(), e)))?;
+        if file.instances.is_empty() {
+            return Err(SessionFileError::Invalid("no instances listed".to_string()));
+        }
+        Ok(file)
+    }
+}
+
+/// Assign connected devices to a session file's players
+///
+/// Listed uniqs are bound first, so the gamepad fallback never takes a
+/// controller another player asked for. Only enabled devices are used.
+/// Returns each player's device indices, or a description of every
+/// device that couldn't be found.
+pub fn match_devices(
+    instances: &[SessionFileInstance],
+    devices: &[DeviceInfo],
+) -> Result<Vec<Vec<usize>>, Vec<String>> {
+    let mut used = vec![false; devices.len()];
+    let mut assigned: Vec<Vec<usize>> = vec![Vec::new(); instances.len()];
+    let mut missing = Vec::new();
+
+    for (player, instance) in instances.iter().enumerate() {
+        for uniq in &instance.devices {
+            let found = devices
+                .iter()
+                .enumerate()
+                .position(|(i, d)| !used[i] && d.enabled && !d.uniq.is_empty() && d.uniq == *uniq);
+            match found {
+                Some(i) => {
+                    used[i] = true;
+                    assigned[player].push(i);
+                }
+                None => missing.push(format!("P{} {}", player + 1, uniq)),
+            }
+        }
+    }
+
+    for (player, instance) in instances.iter().enumerate() {
+        if !instance.devices.is_empty() {
+            continue;
+        }
+        let found = devices
+            .iter()
+            .enumerate()
+            .position(|(i, d)| !used[i] && d.enabled && d.device_type == DeviceType::Gamepad);
+        match found {
+            Some(i) => {
+                used[i] = true;
+                assigned[player].push(i);
+            }
+            None => missing.push(format!("P{} gamepad", player + 1)),
+        }
+    }
+
+    if missing.is_empty() {
+        Ok(assigned)
+    } else {
+        Err(missing)
+    }
+}
+
+/// Launch a session file without the GUI, blocking until the game exits
+pub fn run(path: &Path, monitors: &[Monitor]) -> Result<(), SessionFileError> {
+    let file = SessionFile::load(path)?;
+    let player_count = file.instances.len();
+
+    let handler = scan_handlers()
+        .into_iter()
+        .find(|h| h.handler_dir_name() == file.handler)
+        .ok_or_else(|| SessionFileError::HandlerNotFound(file.handler.clone()))?;
+    match handler.get_game_rootpath() {
+        Ok(root) if Path::new(&root).is_dir() => {}
+        Ok(root) => return Err(SessionFileError::GameRootMissing(root)),
+        Err(e) => return Err(SessionFileError::GameRootMissing(e.to_string())),
+    }
+
+    let mut cfg = load_cfg();
+    if let Some(layout) = &file.layout {
+        match cfg.layout_presets.resolve(layout) {
+            Some(preset) if preset.player_count == player_count => {
+                cfg.layout_presets.set_for_count(player_count, layout.clone());
+            }
+            Some(preset) => {
+                return Err(SessionFileError::Invalid(format!(
+                    "layout '{}' is for {} players, not {}",
+                    layout, preset.player_count, player_count
+                )));
+            }
+            None => {
+                return Err(SessionFileError::Invalid(format!("layout '{}' not found", layout)));
+            }
+        }
+    }
+
+    let profiles = scan_profiles(true);
+    let mut profselections = Vec::new();
+    for instance in &file.instances {
+        let selection = match &instance.profile {
+            None => 0,
+            Some(name) => profiles
+                .iter()
+                .skip(1)
+                .position(|p| p == name)
+                .map(|i| i + 1)
+                .ok_or_else(|| SessionFileError::Invalid(format!("profile '{}' not found", name)))?,
+        };
+        profselections.push(selection);
+    }
+
+    let dev_infos: Vec<DeviceInfo> = scan_input_devices(&cfg.pad_filter_type, &cfg.device_filter)
+        .iter()
+        .map(|d| d.info())
+        .collect();
+    let assigned = match_devices(&file.instances, &dev_infos).map_err(SessionFileError::DevicesMissing)?;
+
+    let mut instances: Vec<Instance> = file
+        .instances
+        .iter()
+        .zip(assigned)
+        .zip(profselections)
+        .map(|((instance, devices), profselection)| {
+            let monitor = match &instance.monitor {
+                None => 0,
+                Some(name) => monitors
+                    .iter()
+                    .position(|m| m.name() == name.as_str() || m.connector_name() == name.as_str())
+                    .unwrap_or_else(|| {
+                        log_warn!("Monitor {} not connected, using the first monitor", name);
+                        0
+                    }),
+            };
+            Instance {
+                devices,
+                profname: String::new(),
+                profselection,
+                monitor,
+                width: 0,
+                height: 0,
+                grab_cursor: None,
+                mangohud: false,
+                resolution_override: (0, 0),
+                args_override: instance.args.clone(),
+            }
+        })
+        .collect();
+
+    // Nobody can be asked to confirm, so a shared profile is refused outright
+    let shared_profiles = duplicate_profiles(&instances, &profiles);
+    if !shared_profiles.is_empty() {
+        return Err(SessionFileError::Invalid(format!(
+            "profiles used by more than one player: {}",
+            describe_duplicate_profiles(&shared_profiles).join(", ")
+        )));
+    }
+
+    if cfg.gamescope_sdl_backend {
+        set_instance_resolutions_multimonitor(&mut instances, &monitors.to_vec(), &cfg);
+    } else {
+        set_instance_resolutions(&mut instances, &monitors[0], &cfg);
+    }
+    set_instance_names(&mut instances, &profiles);
+
+    if handler.emulates_steam() && steam_client_running() {
+        log_warn!("The Steam client is running; Goldberg and Facepunch games may fail to start or connect");
+    }
+
+    log_info!("Launching session file {} ({}, {} players)", path.display(), file.handler, player_count);
+    // Errors are already logged by the pipeline and there's nobody to show dialogs to
+    match run_session(&handler, &dev_infos, &instances, monitors, &cfg, &Arc::new(KillSwitch::default()), |_, _| {}) {
+        SessionOutcome::Ran { failed: 0 } => Ok(()),
+        SessionOutcome::Ran { failed } => Err(SessionFileError::InstanceFailed(failed)),
+        SessionOutcome::Failed => Err(SessionFileError::LaunchFailed),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dev(uniq: &str, device_type: DeviceType, enabled: bool) -> DeviceInfo {
+        DeviceInfo {
+            path: String::new(),
+            enabled,
+            device_type,
+            uniq: uniq.to_string(),
+            siblings: Vec::new(),
+        }
+    }
+
+    fn player(devices: &[&str]) -> SessionFileInstance {
+        SessionFileInstance {
+            devices: devices.iter().map(|d| d.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn parses_example() {
+        let file: SessionFile = serde_yaml::from_str(
+            "handler: my-game\nlayout: 2p_vertical\ninstances:\n  - profile: Alice\n    monitor: DP-1\n    devices: [\"aa\"]\n  - {}\n",
+        )
+        .unwrap();
+        assert_eq!(file.handler, "my-game");
+        assert_eq!(file.layout.as_deref(), Some("2p_vertical"));
+        assert_eq!(file.instances[0].profile.as_deref(), Some("Alice"));
+        assert_eq!(file.instances[0].devices, vec!["aa".to_string()]);
+        assert!(file.instances[1].devices.is_empty());
+    }
+
+    #[test]
+    fn uniqs_are_bound_before_the_gamepad_fallback() {
+        let devices = [
+            dev("bb", DeviceType::Gamepad, true),
+            dev("aa", DeviceType::Gamepad, true),
+        ];
+        // P1 takes the next gamepad, P2 asked for "bb" which comes first
+        let assigned = match_devices(&[player(&[]), player(&["bb"])], &devices).unwrap();
+        assert_eq!(assigned, vec![vec![1], vec![0]]);
+    }
+
+    #[test]
+    fn fallback_takes_first_n_gamepads() {
+        let devices = [
+            dev("", DeviceType::Keyboard, true),
+            dev("aa", DeviceType::Gamepad, true),
+            dev("bb", DeviceType::Gamepad, false),
+            dev("cc", DeviceType::Gamepad, true),
+        ];
+        let assigned = match_devices(&[player(&[]), player(&[])], &devices).unwrap();
+        assert_eq!(assigned, vec![vec![1], vec![3]]);
+    }
+
+    #[test]
+    fn missing_devices_are_all_reported() {
+        let devices = [dev("aa", DeviceType::Gamepad, true)];
+        let missing = match_devices(&[player(&["zz"]), player(&[]), player(&[])], &devices).unwrap_err();
+        assert_eq!(missing, vec!["P1 zz".to_string(), "P3 gamepad".to_string()]);
+    }
+}
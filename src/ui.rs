@@ -17,6 +17,18 @@ pub enum MenuPage {
     Instances, // Controller assignment screen (enters when "Play" pressed)
 }
 
+impl MenuPage {
+    /// Top-bar tab this page belongs to (Instances lives under Games)
+    pub fn tab(self) -> crate::config::PageTab {
+        use crate::config::PageTab;
+        match self {
+            MenuPage::Games | MenuPage::Instances => PageTab::Games,
+            MenuPage::Registry => PageTab::Registry,
+            MenuPage::Settings => PageTab::Settings,
+        }
+    }
+}
+
 // =============================================================================
 // Re-exports
 // =============================================================================
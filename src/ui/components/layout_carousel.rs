@@ -10,7 +10,7 @@ use eframe::egui::{self, Color32, RichText, Stroke, StrokeKind};
 use egui_phosphor::regular as icons;
 
 use crate::ui::theme;
-use crate::wm::presets::{get_presets_for_count, LayoutPreset};
+use crate::wm::presets::{LayoutPreset, MAX_PRESET_PLAYERS};
 
 /// Player colors for the preview regions
 pub const PLAYER_COLORS: [Color32; 4] = [
@@ -48,6 +48,8 @@ pub struct CustomAssignResponse {
     pub new_focused_region: Option<usize>,
     /// Cycle instance in focused region (for A button)
     pub cycle_instance: bool,
+    /// Derive a preset for this player count from the current one
+    pub derive_count: Option<usize>,
 }
 
 /// Render a miniature preview of a layout preset
@@ -122,8 +124,8 @@ fn draw_layout_preview(
 ///
 /// # Arguments
 /// * `ui` - The egui UI context
-/// * `player_count` - Number of active players (determines available presets)
-/// * `current_index` - Current preset index within the player count's presets
+/// * `presets` - Presets available for the current player count
+/// * `current_index` - Current preset index within `presets`
 /// * `is_focused` - Whether this widget has gamepad focus
 /// * `custom_order` - Optional custom instance order to display
 ///
@@ -131,12 +133,11 @@ fn draw_layout_preview(
 /// A `LayoutCarouselResponse` indicating if navigation occurred
 pub fn render_layout_carousel(
     ui: &mut egui::Ui,
-    player_count: usize,
+    presets: &[LayoutPreset],
     current_index: usize,
     is_focused: bool,
     custom_order: Option<&[usize]>,
 ) -> LayoutCarouselResponse {
-    let presets = get_presets_for_count(player_count);
 
     if presets.is_empty() {
         ui.label("No layouts available");
@@ -144,7 +145,7 @@ pub fn render_layout_carousel(
     }
 
     let current_index = current_index.min(presets.len() - 1);
-    let current_preset = presets[current_index];
+    let current_preset = &presets[current_index];
     let has_custom_order = custom_order.is_some();
 
     let mut response = LayoutCarouselResponse::none();
@@ -195,7 +196,7 @@ pub fn render_layout_carousel(
         ui.vertical(|ui| {
             ui.horizontal(|ui| {
                 ui.label(
-                    RichText::new(current_preset.name)
+                    RichText::new(current_preset.name.as_ref())
                         .strong()
                         .color(if is_focused {
                             theme::colors::ACCENT
@@ -248,13 +249,14 @@ pub fn render_custom_assignment(
         exit_custom_mode: false,
         new_focused_region: None,
         cycle_instance: false,
+        derive_count: None,
     };
 
     ui.vertical(|ui| {
         ui.horizontal(|ui| {
             ui.label(RichText::new("Customize Layout").strong());
             ui.add_space(8.0);
-            ui.label(RichText::new(preset.name).color(theme::colors::TEXT_MUTED));
+            ui.label(RichText::new(preset.name.as_ref()).color(theme::colors::TEXT_MUTED));
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                 if ui.add(egui::Button::new("Done")).clicked() {
                     response.exit_custom_mode = true;
@@ -357,6 +359,23 @@ pub fn render_custom_assignment(
                 }
             }
         });
+
+        // Derive layouts for more players by splitting this one
+        if player_count < MAX_PRESET_PLAYERS {
+            ui.add_space(4.0);
+            ui.horizontal(|ui| {
+                ui.label(RichText::new("Split into:").small().color(theme::colors::TEXT_MUTED));
+                for target in (player_count + 1)..=MAX_PRESET_PLAYERS {
+                    if ui
+                        .small_button(format!("{} {}P", icons::SQUARE_SPLIT_HORIZONTAL, target))
+                        .on_hover_text(format!("Create a {}-player layout by subdividing this one", target))
+                        .clicked()
+                    {
+                        response.derive_count = Some(target);
+                    }
+                }
+            });
+        }
     });
 
     response
@@ -4,8 +4,8 @@
 // It provides a high-level API that app code can call.
 
 use crate::ui::focus::pure::{
-    apply_index_delta, navigate_dropdown, navigate_games_page, navigate_instances_page,
-    GamesPaneNav, InstancesNav,
+    apply_index_delta, launch_options, navigate_dropdown, navigate_games_page, navigate_instances_page,
+    step_settings_option, GamesPaneNav, InstancesNav,
 };
 use crate::ui::focus::types::{FocusPane, InstanceFocus, NavDirection, RegistryFocus, SettingsCategory, SettingsFocus};
 use crate::ui::MenuPage;
@@ -16,7 +16,9 @@ pub struct NavContext {
     // Games page state
     pub focus_pane: FocusPane,
     pub action_bar_index: usize,
+    pub action_bar_count: usize,
     pub info_pane_index: usize,
+    /// Position of the selected handler in the games list display order
     pub selected_handler: usize,
     pub handlers_count: usize,
     // Instances page state
@@ -32,7 +34,8 @@ pub struct NavContext {
     pub settings_category: SettingsCategory,
     pub settings_option_index: usize,
     pub settings_button_index: usize,
-    pub settings_max_options: usize,
+    /// Option indices of the shown settings category, in display order
+    pub settings_options: Vec<usize>,
     // Dropdown state (shared across pages)
     pub dropdown_open: bool,
     pub dropdown_selection: usize,
@@ -51,7 +54,7 @@ pub enum NavAction {
     SetActionBarIndex(usize),
     /// Update info pane selection
     SetInfoPaneIndex(usize),
-    /// Update selected handler (game list)
+    /// Update selected handler (position in the game list display order)
     SetSelectedHandler(usize),
     // Instances page actions
     /// Update instance focus area
@@ -107,7 +110,7 @@ fn handle_games_direction(ctx: &NavContext, direction: NavDirection) -> Vec<NavA
         ctx.focus_pane,
         direction,
         ctx.action_bar_index,
-        3, // Action bar has 3 buttons: Play, Profile, Edit
+        ctx.action_bar_count,
     );
 
     match result {
@@ -126,7 +129,7 @@ fn handle_games_direction(ctx: &NavContext, direction: NavDirection) -> Vec<NavA
                     vec![NavAction::SetSelectedHandler(new_idx)]
                 }
                 FocusPane::ActionBar => {
-                    let new_idx = apply_index_delta(ctx.action_bar_index, delta, 3);
+                    let new_idx = apply_index_delta(ctx.action_bar_index, delta, ctx.action_bar_count);
                     vec![NavAction::SetActionBarIndex(new_idx)]
                 }
                 FocusPane::InfoPane => {
@@ -145,7 +148,7 @@ fn handle_games_direction(ctx: &NavContext, direction: NavDirection) -> Vec<NavA
 }
 
 fn handle_instances_direction(ctx: &NavContext, direction: NavDirection) -> Vec<NavAction> {
-    let max_options = if ctx.instances_count == 2 { 2 } else { 1 };
+    let max_options = launch_options(ctx.instances_count).len();
 
     let result = navigate_instances_page(
         ctx.instance_focus.clone(),
@@ -244,35 +247,22 @@ fn handle_settings_direction(ctx: &NavContext, direction: NavDirection) -> Vec<N
             NavDirection::Right => {
                 vec![
                     NavAction::SetSettingsFocus(SettingsFocus::Options),
-                    NavAction::SetSettingsOptionIndex(0),
+                    NavAction::SetSettingsOptionIndex(ctx.settings_options.first().copied().unwrap_or(0)),
                     NavAction::ScrollToFocus,
                 ]
             }
             NavDirection::Left => vec![NavAction::None], // Could collapse panel
         },
         SettingsFocus::Options => match direction {
-            NavDirection::Up => {
-                if ctx.settings_option_index > 0 {
-                    vec![
-                        NavAction::SetSettingsOptionIndex(ctx.settings_option_index - 1),
-                        NavAction::ScrollToFocus,
-                    ]
-                } else {
-                    vec![NavAction::SetSettingsFocus(SettingsFocus::CategoryList)]
-                }
-            }
-            NavDirection::Down => {
-                if ctx.settings_option_index < ctx.settings_max_options {
-                    vec![
-                        NavAction::SetSettingsOptionIndex(ctx.settings_option_index + 1),
-                        NavAction::ScrollToFocus,
-                    ]
-                } else {
-                    vec![NavAction::SetSettingsFocus(SettingsFocus::CategoryList)]
+            NavDirection::Up | NavDirection::Down => {
+                let forward = direction == NavDirection::Down;
+                match step_settings_option(&ctx.settings_options, ctx.settings_option_index, forward) {
+                    Some(idx) => vec![NavAction::SetSettingsOptionIndex(idx), NavAction::ScrollToFocus],
+                    None => vec![NavAction::SetSettingsFocus(SettingsFocus::CategoryList)],
                 }
             }
             NavDirection::Left => {
-                if ctx.settings_option_index == 0 {
+                if ctx.settings_options.first() == Some(&ctx.settings_option_index) {
                     vec![NavAction::SetSettingsFocus(SettingsFocus::CategoryList)]
                 } else {
                     vec![NavAction::None] // Pass through as key event
@@ -2,6 +2,7 @@ pub mod navigation;
 
 // Re-exports
 pub use navigation::{
-    apply_index_delta, navigate_dropdown, navigate_games_page, navigate_instances_page,
-    GamesPaneNav, InstancesNav,
+    apply_index_delta, cycle_page_tab, instance_table_columns, launch_options, navigate_dropdown, navigate_games_page,
+    navigate_instance_table, navigate_instances_page, step_settings_option, table_column_for, GamesPaneNav,
+    InstancesNav,
 };
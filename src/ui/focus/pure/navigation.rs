@@ -1,6 +1,7 @@
 // Within-region navigation logic (pure functions)
 
-use crate::ui::focus::types::{FocusPane, InstanceFocus, NavDirection};
+use crate::config::PageTab;
+use crate::ui::focus::types::{FocusPane, InstanceCardFocus, InstanceFocus, LaunchOption, NavDirection};
 
 /// Result of navigating within the Games page
 #[derive(Debug, Clone, PartialEq)]
@@ -91,6 +92,21 @@ pub fn navigate_instances_page(
     }
 }
 
+/// Launch options shown for a number of players, in display order
+pub fn launch_options(player_count: usize) -> Vec<LaunchOption> {
+    if player_count >= 2 {
+        vec![
+            LaunchOption::Layout,
+            LaunchOption::InputHolding,
+            LaunchOption::SoloAudio,
+            LaunchOption::GuestSaves,
+            LaunchOption::CopyCommand,
+        ]
+    } else {
+        vec![LaunchOption::InputHolding, LaunchOption::CopyCommand]
+    }
+}
+
 /// Result of navigating within the Instances page
 #[derive(Debug, Clone, PartialEq)]
 pub enum InstancesNav {
@@ -102,6 +118,66 @@ pub enum InstancesNav {
     None,
 }
 
+/// Columns of the compact instance table, in display order
+pub fn instance_table_columns(show_monitor: bool, show_audio: bool) -> Vec<InstanceCardFocus> {
+    let mut columns = vec![InstanceCardFocus::Profile, InstanceCardFocus::InviteDevice];
+    if show_monitor {
+        columns.push(InstanceCardFocus::Monitor);
+    }
+    if show_audio {
+        columns.push(InstanceCardFocus::AudioOverride);
+    }
+    columns.extend([
+        InstanceCardFocus::GptokeybProfile,
+        InstanceCardFocus::GrabCursor,
+        InstanceCardFocus::MangoHud,
+    ]);
+    columns
+}
+
+/// Map a card element onto the table column that contains it
+///
+/// The table has no cells for per-device or secondary buttons, so those
+/// collapse onto the column they share with a primary control.
+pub fn table_column_for(element: &InstanceCardFocus, columns: &[InstanceCardFocus]) -> usize {
+    let element = match element {
+        InstanceCardFocus::SetMaster => InstanceCardFocus::Profile,
+        InstanceCardFocus::CloneInstance
+        | InstanceCardFocus::MoveUp
+        | InstanceCardFocus::MoveDown
+        | InstanceCardFocus::Device(_) => InstanceCardFocus::InviteDevice,
+        InstanceCardFocus::AudioPreference => InstanceCardFocus::AudioOverride,
+        InstanceCardFocus::GptokeybPref | InstanceCardFocus::Resolution | InstanceCardFocus::Args => {
+            InstanceCardFocus::GptokeybProfile
+        }
+        other => *other,
+    };
+    columns.iter().position(|c| *c == element).unwrap_or(0)
+}
+
+/// Navigate between cells of the compact instance table
+///
+/// Rows are instances and columns are card elements. Leaving the top row
+/// returns to the device list; leaving the bottom row enters launch options.
+pub fn navigate_instance_table(
+    row: usize,
+    element: &InstanceCardFocus,
+    direction: NavDirection,
+    row_count: usize,
+    columns: &[InstanceCardFocus],
+) -> InstanceFocus {
+    let col = table_column_for(element, columns);
+    let cell = |row: usize, col: usize| InstanceFocus::InstanceCard(row, columns[col]);
+    match direction {
+        NavDirection::Up if row == 0 => InstanceFocus::Devices,
+        NavDirection::Up => cell(row - 1, col),
+        NavDirection::Down if row + 1 >= row_count => InstanceFocus::LaunchOptions,
+        NavDirection::Down => cell(row + 1, col),
+        NavDirection::Left => cell(row, col.saturating_sub(1)),
+        NavDirection::Right => cell(row, (col + 1).min(columns.len() - 1)),
+    }
+}
+
 /// Navigate dropdown selection (wrapping)
 pub fn navigate_dropdown(current: usize, total: usize, direction: NavDirection) -> usize {
     match direction {
@@ -117,6 +193,34 @@ pub fn navigate_dropdown(current: usize, total: usize, direction: NavDirection)
     }
 }
 
+/// Next page tab for LB/RB cycling (wrapping)
+///
+/// Starts from the first visible tab when the current one is hidden.
+/// Returns None when there is nowhere else to go.
+pub fn cycle_page_tab(visible: &[PageTab], current: PageTab, forward: bool) -> Option<PageTab> {
+    let next = match visible.iter().position(|&t| t == current) {
+        Some(idx) if forward => visible[(idx + 1) % visible.len()],
+        Some(idx) => visible[(idx + visible.len() - 1) % visible.len()],
+        None => *visible.first()?,
+    };
+    (next != current).then_some(next)
+}
+
+/// Settings option after `current` in display order
+///
+/// `order` lists the option indices of the shown category top to bottom.
+/// Returns None when stepping past either end; an option that isn't in
+/// the list steps to the first one.
+pub fn step_settings_option(order: &[usize], current: usize, forward: bool) -> Option<usize> {
+    let Some(pos) = order.iter().position(|&i| i == current) else {
+        return order.first().copied();
+    };
+    match forward {
+        true => order.get(pos + 1).copied(),
+        false => pos.checked_sub(1).map(|p| order[p]),
+    }
+}
+
 /// Clamp an index after applying a delta
 pub fn apply_index_delta(current: usize, delta: i32, max: usize) -> usize {
     if delta < 0 {
@@ -130,6 +234,14 @@ pub fn apply_index_delta(current: usize, delta: i32, max: usize) -> usize {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_launch_options_per_player_count() {
+        assert_eq!(launch_options(1), vec![LaunchOption::InputHolding, LaunchOption::CopyCommand]);
+        assert_eq!(launch_options(3).len(), 5);
+        assert_eq!(launch_options(2)[2], LaunchOption::SoloAudio);
+        assert_eq!(launch_options(2)[3], LaunchOption::GuestSaves);
+    }
+
     #[test]
     fn test_game_list_navigation() {
         // Moving right from GameList should switch to ActionBar
@@ -166,6 +278,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_instance_table_navigation() {
+        let columns = instance_table_columns(false, true);
+        assert_eq!(
+            columns,
+            vec![
+                InstanceCardFocus::Profile,
+                InstanceCardFocus::InviteDevice,
+                InstanceCardFocus::AudioOverride,
+                InstanceCardFocus::GptokeybProfile,
+                InstanceCardFocus::GrabCursor,
+                InstanceCardFocus::MangoHud,
+            ]
+        );
+
+        // Left/Right move between columns and clamp at the edges
+        assert_eq!(
+            navigate_instance_table(1, &InstanceCardFocus::Profile, NavDirection::Right, 2, &columns),
+            InstanceFocus::InstanceCard(1, InstanceCardFocus::InviteDevice)
+        );
+        assert_eq!(
+            navigate_instance_table(1, &InstanceCardFocus::Profile, NavDirection::Left, 2, &columns),
+            InstanceFocus::InstanceCard(1, InstanceCardFocus::Profile)
+        );
+        assert_eq!(
+            navigate_instance_table(0, &InstanceCardFocus::MangoHud, NavDirection::Right, 2, &columns),
+            InstanceFocus::InstanceCard(0, InstanceCardFocus::MangoHud)
+        );
+
+        // Up/Down move between rows, keeping the column
+        assert_eq!(
+            navigate_instance_table(0, &InstanceCardFocus::AudioOverride, NavDirection::Down, 2, &columns),
+            InstanceFocus::InstanceCard(1, InstanceCardFocus::AudioOverride)
+        );
+        assert_eq!(
+            navigate_instance_table(0, &InstanceCardFocus::Profile, NavDirection::Up, 2, &columns),
+            InstanceFocus::Devices
+        );
+        assert_eq!(
+            navigate_instance_table(1, &InstanceCardFocus::Profile, NavDirection::Down, 2, &columns),
+            InstanceFocus::LaunchOptions
+        );
+    }
+
+    #[test]
+    fn test_table_column_for_card_only_elements() {
+        let columns = instance_table_columns(true, false);
+        assert_eq!(table_column_for(&InstanceCardFocus::Device(2), &columns), 1);
+        assert_eq!(table_column_for(&InstanceCardFocus::CloneInstance, &columns), 1);
+        assert_eq!(table_column_for(&InstanceCardFocus::SetMaster, &columns), 0);
+        // Audio column hidden: fall back to the first column
+        assert_eq!(table_column_for(&InstanceCardFocus::AudioPreference, &columns), 0);
+        assert_eq!(table_column_for(&InstanceCardFocus::Args, &columns), 3);
+    }
+
     #[test]
     fn test_dropdown_wrap() {
         assert_eq!(navigate_dropdown(0, 5, NavDirection::Up), 4); // Wrap to end
@@ -179,4 +346,32 @@ mod tests {
         assert_eq!(apply_index_delta(0, -1, 10), 0); // Clamp at 0
         assert_eq!(apply_index_delta(8, 5, 10), 9); // Clamp at max-1
     }
+
+    #[test]
+    fn test_step_settings_option() {
+        let order = [0, 1, 22, 23, 6];
+        assert_eq!(step_settings_option(&order, 1, true), Some(22));
+        assert_eq!(step_settings_option(&order, 22, false), Some(1));
+        assert_eq!(step_settings_option(&order, 6, true), None);
+        assert_eq!(step_settings_option(&order, 0, false), None);
+        // Focus left over from another category starts at the top
+        assert_eq!(step_settings_option(&order, 13, true), Some(0));
+        assert_eq!(step_settings_option(&[], 0, true), None);
+    }
+
+    #[test]
+    fn test_cycle_page_tab() {
+        let tabs = [PageTab::Settings, PageTab::Games, PageTab::Registry];
+        assert_eq!(cycle_page_tab(&tabs, PageTab::Settings, true), Some(PageTab::Games));
+        assert_eq!(cycle_page_tab(&tabs, PageTab::Registry, true), Some(PageTab::Settings));
+        assert_eq!(cycle_page_tab(&tabs, PageTab::Settings, false), Some(PageTab::Registry));
+
+        // Hidden Registry is skipped, and a hidden current tab jumps to the first
+        let tabs = [PageTab::Games, PageTab::Settings];
+        assert_eq!(cycle_page_tab(&tabs, PageTab::Games, true), Some(PageTab::Settings));
+        assert_eq!(cycle_page_tab(&tabs, PageTab::Registry, true), Some(PageTab::Games));
+
+        // A single tab has nowhere to go
+        assert_eq!(cycle_page_tab(&[PageTab::Games], PageTab::Games, true), None);
+    }
 }
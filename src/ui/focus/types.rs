@@ -22,6 +22,16 @@ pub enum InstanceFocus {
     StartButton,                          // Start Game button
 }
 
+/// Options in the launch options bar, in display order
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub enum LaunchOption {
+    Layout,       // Layout preset carousel (2+ players)
+    InputHolding, // Keyboard/mouse support checkbox
+    SoloAudio,    // Mute all but one player (2+ players)
+    GuestSaves,   // Profile guests copy saves from (2+ players)
+    CopyCommand,  // Copy a player's launch command
+}
+
 /// Focus elements within an instance card
 #[derive(Eq, PartialEq, Debug, Clone, Copy, Default)]
 pub enum InstanceCardFocus {
@@ -30,10 +40,18 @@ pub enum InstanceCardFocus {
     SetMaster,       // Set Master button
     Monitor,         // Monitor dropdown (if gamescope SDL enabled)
     InviteDevice,    // Invite Device button
+    CloneInstance,   // Clone instance button
+    MoveUp,          // Swap with the previous player (2+ players)
+    MoveDown,        // Swap with the next player (2+ players)
     Device(usize),   // Specific device in the device list
     AudioOverride,   // Audio session override dropdown
     AudioPreference, // Audio preference dropdown (named profiles only)
     GptokeybProfile, // gptokeyb profile dropdown (KB/Mouse mapping)
+    GptokeybPref,    // Remember the KB/Mouse profile for this game (named profiles only)
+    GrabCursor,      // Cursor grab toggle
+    MangoHud,        // MangoHud overlay toggle (when installed)
+    Resolution,      // Resolution override
+    Args,            // Extra game arguments
 }
 
 /// Focus regions for Registry page
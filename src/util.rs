@@ -1,4 +1,4 @@
-use crate::paths::{PATH_HOME, PATH_PARTY};
+use crate::paths::{PATH_HOME, TMP_DIR_NAME, path_tmp};
 
 use dialog::{Choice, DialogBox};
 
@@ -10,10 +10,17 @@ pub fn is_wayland_session() -> bool {
     std::env::var("WAYLAND_DISPLAY").is_ok()
 }
 
+/// Check whether an executable with this name exists in a PATH directory
+pub fn command_in_path(name: &str) -> bool {
+    std::env::var_os("PATH")
+        .map(|path| std::env::split_paths(&path).any(|dir| dir.join(name).is_file()))
+        .unwrap_or(false)
+}
+
 use eframe::egui::TextBuffer;
 use rfd::FileDialog;
 use std::error::Error;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 pub fn msg(title: &str, contents: &str) {
@@ -38,6 +45,15 @@ pub fn dir_dialog() -> Result<PathBuf, Box<dyn Error>> {
     Ok(dir)
 }
 
+pub fn file_dialog(base_dir: &PathBuf) -> Result<PathBuf, Box<dyn Error>> {
+    let file = FileDialog::new()
+        .set_title("Select File")
+        .set_directory(base_dir)
+        .pick_file()
+        .ok_or("No file selected")?;
+    Ok(file)
+}
+
 pub fn file_dialog_relative(base_dir: &PathBuf) -> Result<PathBuf, Box<dyn Error>> {
     let file = FileDialog::new()
         .set_title("Select File")
@@ -53,9 +69,68 @@ pub fn file_dialog_relative(base_dir: &PathBuf) -> Result<PathBuf, Box<dyn Error
     }
 }
 
+/// Run `f` once per instance, on worker threads when `parallel` is set
+///
+/// Results keep instance order. Worker errors are stringified (Box<dyn Error>
+/// isn't Send) and the first one is returned, prefixed with its instance.
+pub fn run_per_instance<T, F>(count: usize, parallel: bool, f: F) -> Result<Vec<T>, Box<dyn Error>>
+where
+    T: Send,
+    F: Fn(usize) -> Result<T, Box<dyn Error>> + Sync,
+{
+    // Tag log messages from each call with its player
+    let f = |i: usize| {
+        crate::logging::set_player(Some(i + 1));
+        let result = f(i);
+        crate::logging::set_player(None);
+        result
+    };
+
+    if !parallel || count < 2 {
+        return (0..count).map(&f).collect();
+    }
+
+    let results: Vec<Result<T, String>> = std::thread::scope(|s| {
+        let handles: Vec<_> = (0..count)
+            .map(|i| {
+                let f = &f;
+                s.spawn(move || f(i).map_err(|e| e.to_string()))
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|h| h.join().unwrap_or_else(|_| Err("worker thread panicked".to_string())))
+            .collect()
+    });
+
+    results
+        .into_iter()
+        .enumerate()
+        .map(|(i, r)| r.map_err(|e| format!("Instance {}: {}", i + 1, e).into()))
+        .collect()
+}
+
+/// Format seconds since the Unix epoch in local time (YYYY-MM-DD HH:MM:SS)
+pub fn format_local_time(timestamp: u64) -> String {
+    let secs = timestamp as libc::time_t;
+    let mut tm: libc::tm = unsafe { std::mem::zeroed() };
+    if unsafe { libc::localtime_r(&secs, &mut tm) }.is_null() {
+        return timestamp.to_string();
+    }
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        tm.tm_year + 1900,
+        tm.tm_mon + 1,
+        tm.tm_mday,
+        tm.tm_hour,
+        tm.tm_min,
+        tm.tm_sec
+    )
+}
+
 pub fn copy_dir_recursive(src: &PathBuf, dest: &PathBuf) -> Result<(), Box<dyn Error>> {
-    println!(
-        "[splitux] util::copy_dir_recursive - src: {}, dest: {}",
+    log_debug!(
+        "util::copy_dir_recursive - src: {}, dest: {}",
         src.display(),
         dest.display()
     );
@@ -102,9 +177,21 @@ pub fn get_installed_steamapps() -> Vec<Option<steamlocate::App>> {
     let mut games = Vec::new();
     games.push(None);
 
-    if let Ok(steam_dir) = steamlocate::SteamDir::locate()
-        && let Ok(libraries) = steam_dir.libraries()
+    // Native and Flatpak Steam may both be installed; list each app once
+    let mut steam_dirs: Vec<steamlocate::SteamDir> = crate::paths::STEAM_ROOTS
+        .iter()
+        .filter_map(|root| steamlocate::SteamDir::from_dir(root).ok())
+        .collect();
+    if steam_dirs.is_empty()
+        && let Ok(steam_dir) = steamlocate::SteamDir::locate()
     {
+        steam_dirs.push(steam_dir);
+    }
+
+    for steam_dir in steam_dirs {
+        let Ok(libraries) = steam_dir.libraries() else {
+            continue;
+        };
         for library in libraries {
             let library = match library {
                 Ok(lib) => lib,
@@ -112,7 +199,9 @@ pub fn get_installed_steamapps() -> Vec<Option<steamlocate::App>> {
             };
 
             for app in library.apps() {
-                if let Ok(app) = app {
+                if let Ok(app) = app
+                    && !games.iter().flatten().any(|g: &steamlocate::App| g.app_id == app.app_id)
+                {
                     games.push(Some(app));
                 }
             }
@@ -216,7 +305,7 @@ pub fn resolve_proton_path(proton_name: &str) -> Option<PathBuf> {
     None
 }
 
-fn is_mount_point(dir: &PathBuf) -> Result<bool, Box<dyn std::error::Error>> {
+pub fn is_mount_point(dir: &PathBuf) -> Result<bool, Box<dyn std::error::Error>> {
     if let Ok(status) = Command::new("mountpoint").arg(dir).status()
         && status.success()
     {
@@ -226,10 +315,8 @@ fn is_mount_point(dir: &PathBuf) -> Result<bool, Box<dyn std::error::Error>> {
     }
 }
 
-pub fn fuse_overlayfs_unmount_gamedirs() -> Result<(), Box<dyn std::error::Error>> {
-    let tmp = PATH_PARTY.join("tmp");
-
-    let Ok(entries) = std::fs::read_dir(&tmp) else {
+pub fn fuse_overlayfs_unmount_gamedirs(tmp: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let Ok(entries) = std::fs::read_dir(tmp) else {
         return Err("Failed to read directory".into());
     };
 
@@ -254,15 +341,18 @@ pub fn fuse_overlayfs_unmount_gamedirs() -> Result<(), Box<dyn std::error::Error
 }
 
 pub fn clear_tmp() -> Result<(), Box<dyn Error>> {
-    let tmp = PATH_PARTY.join("tmp");
+    clear_scratch_dir(&path_tmp())
+}
 
+/// Unmount a session's game dirs and remove its scratch directory
+pub fn clear_scratch_dir(tmp: &Path) -> Result<(), Box<dyn Error>> {
     if !tmp.exists() {
         return Ok(());
     }
 
-    fuse_overlayfs_unmount_gamedirs()?;
+    fuse_overlayfs_unmount_gamedirs(tmp)?;
 
-    std::fs::remove_dir_all(&tmp)?;
+    std::fs::remove_dir_all(tmp)?;
 
     Ok(())
 }
@@ -279,8 +369,8 @@ fn kill_orphaned_gamescope_splitux() -> Result<(), Box<dyn Error>> {
         let pids = String::from_utf8_lossy(&output.stdout);
         let count = pids.lines().filter(|l| !l.is_empty()).count();
         if count > 0 {
-            println!(
-                "[splitux] Cleaning up {} orphaned gamescope-splitux process(es)",
+            log_info!(
+                "Cleaning up {} orphaned gamescope-splitux process(es)",
                 count
             );
             Command::new("pkill")
@@ -306,8 +396,10 @@ fn kill_orphaned_splitux_bwrap() -> Result<(), Box<dyn Error>> {
 
     let pids_str = String::from_utf8_lossy(&output.stdout);
 
-    // Markers that identify Splitux-spawned bwrap processes
-    let splitux_markers = ["splitux/tmp", "splitux/profiles", "/splitux/"];
+    // Markers that identify Splitux-spawned bwrap processes; the scratch
+    // directory may have been moved out of the data folder
+    let custom_tmp = format!("/{}/", TMP_DIR_NAME);
+    let splitux_markers = ["splitux/tmp", "splitux/profiles", "/splitux/", custom_tmp.as_str()];
 
     for pid in pids_str.lines() {
         let pid = pid.trim();
@@ -349,7 +441,7 @@ fn kill_orphaned_splitux_bwrap() -> Result<(), Box<dyn Error>> {
 
         if *ppid == "1" {
             // Orphaned - safe to kill
-            println!("[splitux] Killing orphaned Splitux bwrap (PID {})", pid);
+            log_info!("Killing orphaned Splitux bwrap (PID {})", pid);
             let _ = Command::new("kill").args(["-9", pid]).status();
         }
     }
@@ -360,44 +452,70 @@ fn kill_orphaned_splitux_bwrap() -> Result<(), Box<dyn Error>> {
 /// Clean up all orphaned Splitux processes
 /// Safe to call at startup and before launches
 pub fn cleanup_orphaned_processes() {
-    println!("[splitux] Checking for orphaned processes...");
+    log_info!("Checking for orphaned processes...");
 
     if let Err(e) = kill_orphaned_gamescope_splitux() {
-        println!(
-            "[splitux] Warning: Failed to clean gamescope-splitux: {}",
+        log_warn!(
+            "Failed to clean gamescope-splitux: {}",
             e
         );
     }
 
     if let Err(e) = kill_orphaned_splitux_bwrap() {
-        println!("[splitux] Warning: Failed to clean orphaned bwrap: {}", e);
+        log_warn!("Failed to clean orphaned bwrap: {}", e);
+    }
+}
+
+/// Check whether the real Steam client is running
+///
+/// Tries the PID Steam writes to `~/.steam/steam.pid` first and only falls
+/// back to scanning `/proc` when that's missing or stale. Reads a few tiny
+/// procfs files, so it's cheap enough to call from the UI thread.
+pub fn steam_client_running() -> bool {
+    let is_steam = |pid: &str| {
+        std::fs::read_to_string(format!("/proc/{}/comm", pid))
+            .is_ok_and(|comm| comm.trim() == "steam")
+    };
+
+    if let Ok(pid) = std::fs::read_to_string(PATH_HOME.join(".steam/steam.pid"))
+        && is_steam(pid.trim())
+    {
+        return true;
     }
+
+    let Ok(entries) = std::fs::read_dir("/proc") else {
+        return false;
+    };
+    entries.flatten().any(|entry| {
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        name.bytes().all(|b| b.is_ascii_digit()) && is_steam(&name)
+    })
 }
 
-pub fn check_for_splitux_update() -> bool {
+pub fn check_for_splitux_update(offline: bool) -> bool {
     // Try to get the latest release tag from GitHub
-    if let Ok(client) = reqwest::blocking::Client::new()
-        .get("https://api.github.com/repos/wunnr/splitux/releases/latest")
-        .header("User-Agent", "splitux")
-        .send()
+    if let Ok(client) = crate::net::fetch_with_retry(
+        &reqwest::blocking::Client::new(),
+        "https://api.github.com/repos/wunnr/splitux/releases/latest",
+        "the latest release",
+        offline,
+    ) && let Ok(release) = client.json::<serde_json::Value>()
+        // Extract the tag name (vX.X.X format)
+        && let Some(tag_name) = release["tag_name"].as_str()
     {
-        if let Ok(release) = client.json::<serde_json::Value>() {
-            // Extract the tag name (vX.X.X format)
-            if let Some(tag_name) = release["tag_name"].as_str() {
-                // Strip the 'v' prefix
-                let latest_version = tag_name.strip_prefix('v').unwrap_or(tag_name);
-
-                // Get current version from env!
-                let current_version = env!("CARGO_PKG_VERSION");
-
-                // Compare versions using semver
-                if let (Ok(latest_semver), Ok(current_semver)) = (
-                    semver::Version::parse(latest_version),
-                    semver::Version::parse(current_version),
-                ) {
-                    return latest_semver > current_semver;
-                }
-            }
+        // Strip the 'v' prefix
+        let latest_version = tag_name.strip_prefix('v').unwrap_or(tag_name);
+
+        // Get current version from env!
+        let current_version = env!("CARGO_PKG_VERSION");
+
+        // Compare versions using semver
+        if let (Ok(latest_semver), Ok(current_semver)) = (
+            semver::Version::parse(latest_version),
+            semver::Version::parse(current_version),
+        ) {
+            return latest_semver > current_semver;
         }
     }
 
@@ -480,3 +598,12 @@ impl OsFmt for PathBuf {
         }
     }
 }
+
+/// Fresh, empty directory under the system temp dir for a test to work in
+#[cfg(test)]
+pub fn test_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("splitux-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
@@ -7,7 +7,7 @@ pub(crate) mod bars;
 mod gamescope;
 mod hyprland;
 mod kwin;
-mod layout;
+pub mod layout;
 mod niri;
 pub mod operations;
 pub mod presets;
@@ -34,10 +34,15 @@ pub struct LayoutContext {
     #[allow(dead_code)] // Used by calculate_all_geometries for multi-monitor layout
     pub monitors: Vec<Monitor>,
     /// Layout preset for positioning windows
-    pub preset: &'static presets::LayoutPreset,
-    /// Maps spawn index to region index (for custom layout ordering)
-    /// e.g., [1, 0] means window 0 goes to region 1, window 1 goes to region 0
-    pub instance_to_region: Vec<usize>,
+    pub preset: presets::LayoutPreset,
+    /// Configured presets and orders, for `layout::instance_geometry`
+    pub presets: presets::LayoutPresets,
+    /// PIDs of the spawned instances (gamescope processes) in spawn order.
+    /// Empty until the instances have been launched.
+    pub pids: Vec<u32>,
+    /// The games run without gamescope (Handler::no_gamescope), so their
+    /// windows are found through the spawned processes instead of by class
+    pub no_gamescope: bool,
 }
 
 /// The core window manager trait
@@ -56,6 +61,21 @@ pub trait WindowManager: Send + Sync {
         Ok(())
     }
 
+    /// Called periodically while the games run, after `on_instances_launched`.
+    /// Games run without gamescope often open a launcher or splash window
+    /// first, so their main window is placed again once it appears.
+    /// Default implementation does nothing (for WMs that only place gamescope windows).
+    fn refresh_layout(&mut self, _ctx: &LayoutContext) -> WmResult<()> {
+        Ok(())
+    }
+
+    /// Keep the window of the given process above the game windows, or release it.
+    /// Used to keep the launcher reachable while a session runs.
+    /// Default implementation does nothing (for WMs without a way to do it).
+    fn keep_above(&mut self, _pid: u32, _above: bool) -> WmResult<()> {
+        Ok(())
+    }
+
     /// Clean up WM integration (unload scripts, disconnect IPC, etc.)
     /// Called after all games have exited.
     fn teardown(&mut self) -> WmResult<()>;
@@ -92,7 +112,7 @@ impl WindowManagerBackend {
     pub fn detect() -> Self {
         // 1. Check for Hyprland first (has unique env var)
         if std::env::var("HYPRLAND_INSTANCE_SIGNATURE").is_ok() && HyprlandManager::is_available() {
-            println!("[splitux] wm - Detected Hyprland compositor");
+            log_info!("wm - Detected Hyprland compositor");
             return Self::Hyprland(HyprlandManager::new());
         }
 
@@ -101,14 +121,14 @@ impl WindowManagerBackend {
             || std::env::var("KDE_FULL_SESSION").is_ok()
         {
             if KWinManager::is_available() {
-                println!("[splitux] wm - Detected KWin compositor");
+                log_info!("wm - Detected KWin compositor");
                 return Self::KWin(KWinManager::new());
             }
         }
 
         // 3. Check for Niri (via NIRI_SOCKET env var or niri msg)
         if std::env::var("NIRI_SOCKET").is_ok() || NiriManager::is_available() {
-            println!("[splitux] wm - Detected Niri compositor");
+            log_info!("wm - Detected Niri compositor");
             return Self::Niri(NiriManager::new());
         }
 
@@ -116,7 +136,7 @@ impl WindowManagerBackend {
         if std::env::var("GAMESCOPE_WAYLAND_DISPLAY").is_ok()
             || std::env::var("SteamOS").is_ok()
         {
-            println!("[splitux] wm - Detected Gamescope/SteamOS session");
+            log_info!("wm - Detected Gamescope/SteamOS session");
             return Self::GamescopeOnly(GamescopeOnlyManager::new());
         }
 
@@ -126,7 +146,7 @@ impl WindowManagerBackend {
             .output()
         {
             if output.status.success() && HyprlandManager::is_available() {
-                println!("[splitux] wm - Detected Hyprland via process");
+                log_info!("wm - Detected Hyprland via process");
                 return Self::Hyprland(HyprlandManager::new());
             }
         }
@@ -136,7 +156,7 @@ impl WindowManagerBackend {
             .output()
         {
             if output.status.success() && KWinManager::is_available() {
-                println!("[splitux] wm - Detected KWin via process");
+                log_info!("wm - Detected KWin via process");
                 return Self::KWin(KWinManager::new());
             }
         }
@@ -146,13 +166,13 @@ impl WindowManagerBackend {
             .output()
         {
             if output.status.success() && NiriManager::is_available() {
-                println!("[splitux] wm - Detected Niri via process");
+                log_info!("wm - Detected Niri via process");
                 return Self::Niri(NiriManager::new());
             }
         }
 
         // 6. Default to no WM positioning
-        println!("[splitux] wm - No supported WM detected, using Gamescope-only mode");
+        log_info!("wm - No supported WM detected, using Gamescope-only mode");
         Self::GamescopeOnly(GamescopeOnlyManager::new())
     }
 
@@ -187,6 +207,24 @@ impl WindowManager for WindowManagerBackend {
         }
     }
 
+    fn refresh_layout(&mut self, ctx: &LayoutContext) -> WmResult<()> {
+        match self {
+            Self::KWin(wm) => wm.refresh_layout(ctx),
+            Self::Hyprland(wm) => wm.refresh_layout(ctx),
+            Self::Niri(wm) => wm.refresh_layout(ctx),
+            Self::GamescopeOnly(wm) => wm.refresh_layout(ctx),
+        }
+    }
+
+    fn keep_above(&mut self, pid: u32, above: bool) -> WmResult<()> {
+        match self {
+            Self::KWin(wm) => wm.keep_above(pid, above),
+            Self::Hyprland(wm) => wm.keep_above(pid, above),
+            Self::Niri(wm) => wm.keep_above(pid, above),
+            Self::GamescopeOnly(wm) => wm.keep_above(pid, above),
+        }
+    }
+
     fn teardown(&mut self) -> WmResult<()> {
         match self {
             Self::KWin(wm) => wm.teardown(),
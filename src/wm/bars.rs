@@ -10,7 +10,7 @@
 //! Bar state is persisted to disk so bars can be restored even after
 //! abnormal termination (Ctrl+C, crash, SIGKILL).
 
-use crate::paths::PATH_PARTY;
+use crate::paths::path_tmp;
 use std::process::Command;
 
 /// Tracks which bars have been hidden and how to restore them
@@ -29,7 +29,7 @@ struct HiddenBar {
 
 /// Path to the persisted bar state file
 fn state_file() -> std::path::PathBuf {
-    PATH_PARTY.join("tmp/hidden_bars.json")
+    path_tmp().join("hidden_bars.json")
 }
 
 /// Known status bars to look for
@@ -94,7 +94,7 @@ impl StatusBarManager {
         let json = match serde_json::to_string(&entries) {
             Ok(j) => j,
             Err(e) => {
-                eprintln!("[splitux] wm::bars - Failed to serialize bar state: {}", e);
+                log_error!("wm::bars - Failed to serialize bar state: {}", e);
                 return;
             }
         };
@@ -104,7 +104,7 @@ impl StatusBarManager {
             let _ = std::fs::create_dir_all(parent);
         }
         if let Err(e) = std::fs::write(&path, json) {
-            eprintln!("[splitux] wm::bars - Failed to persist bar state: {}", e);
+            log_error!("wm::bars - Failed to persist bar state: {}", e);
         }
     }
 
@@ -121,12 +121,12 @@ impl StatusBarManager {
                 continue;
             }
 
-            println!("[splitux] wm::bars - Found running bar: {} (PIDs: {:?})", name, pids);
+            log_info!("wm::bars - Found running bar: {} (PIDs: {:?})", name, pids);
 
             // Capture the command line from the first PID before killing
             let cmdline = Self::read_cmdline(pids[0]).unwrap_or_else(|| vec![name.to_string()]);
 
-            println!("[splitux] wm::bars - Killing {} (cmdline: {:?})", name, cmdline);
+            log_info!("wm::bars - Killing {} (cmdline: {:?})", name, cmdline);
             let _ = Command::new("pkill").arg("-x").arg(name).status();
 
             self.hidden_bars.push(HiddenBar {
@@ -136,7 +136,7 @@ impl StatusBarManager {
         }
 
         if self.hidden_bars.is_empty() {
-            println!("[splitux] wm::bars - No status bars detected");
+            log_info!("wm::bars - No status bars detected");
         } else {
             self.persist_state();
         }
@@ -148,10 +148,10 @@ impl StatusBarManager {
             return;
         }
 
-        println!("[splitux] wm::bars - Restoring {} status bar(s)", self.hidden_bars.len());
+        log_info!("wm::bars - Restoring {} status bar(s)", self.hidden_bars.len());
 
         for bar in &self.hidden_bars {
-            println!("[splitux] wm::bars - Restarting {} (cmdline: {:?})", bar.name, bar.cmdline);
+            log_info!("wm::bars - Restarting {} (cmdline: {:?})", bar.name, bar.cmdline);
 
             let (program, args) = match bar.cmdline.split_first() {
                 Some((prog, rest)) => (prog.as_str(), rest),
@@ -161,7 +161,7 @@ impl StatusBarManager {
             match Command::new(program).args(args).spawn() {
                 Ok(_) => {}
                 Err(e) => {
-                    eprintln!("[splitux] wm::bars - Failed to restart {}: {}", bar.name, e);
+                    log_error!("wm::bars - Failed to restart {}: {}", bar.name, e);
                 }
             }
         }
@@ -191,7 +191,7 @@ pub fn restore_from_previous_session() {
     let cmdlines: Vec<Vec<String>> = match serde_json::from_str(&data) {
         Ok(c) => c,
         Err(e) => {
-            eprintln!("[splitux] wm::bars - Failed to parse bar state: {}", e);
+            log_error!("wm::bars - Failed to parse bar state: {}", e);
             let _ = std::fs::remove_file(&path);
             return;
         }
@@ -202,8 +202,8 @@ pub fn restore_from_previous_session() {
         return;
     }
 
-    println!(
-        "[splitux] wm::bars - Restoring {} bar(s) from previous session",
+    log_info!(
+        "wm::bars - Restoring {} bar(s) from previous session",
         cmdlines.len()
     );
 
@@ -220,15 +220,15 @@ pub fn restore_from_previous_session() {
             .unwrap_or(program);
 
         if !StatusBarManager::get_pids(name).is_empty() {
-            println!("[splitux] wm::bars - {} already running, skipping", name);
+            log_info!("wm::bars - {} already running, skipping", name);
             continue;
         }
 
-        println!("[splitux] wm::bars - Restarting {} (cmdline: {:?})", name, cmdline);
+        log_info!("wm::bars - Restarting {} (cmdline: {:?})", name, cmdline);
         match Command::new(program).args(args).spawn() {
             Ok(_) => {}
             Err(e) => {
-                eprintln!("[splitux] wm::bars - Failed to restart {}: {}", name, e);
+                log_error!("wm::bars - Failed to restart {}: {}", name, e);
             }
         }
     }
@@ -24,7 +24,7 @@ impl WindowManager for GamescopeOnlyManager {
 
     fn setup(&mut self, _ctx: &LayoutContext) -> WmResult<()> {
         // No setup needed - gamescope handles its own window via --display-index
-        println!("[splitux] wm::gamescope - No external WM positioning needed");
+        log_info!("wm::gamescope - No external WM positioning needed");
         Ok(())
     }
 
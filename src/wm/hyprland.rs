@@ -2,7 +2,10 @@
 
 use crate::monitor::Monitor;
 use crate::wm::bars::StatusBarManager;
-use crate::wm::layout::{calculate_geometry_from_preset, WindowGeometry};
+use crate::wm::layout::{instance_geometry, WindowGeometry};
+use crate::wm::operations::process::spawned_ancestor;
+use crate::wm::pure::hyprland::match_windows_to_instances;
+use crate::wm::pure::readiness::{instances_with_windows, main_windows};
 use crate::wm::{LayoutContext, NestedSession, WindowManager, WmResult};
 use std::io::{Read, Write};
 use std::os::unix::net::UnixStream;
@@ -25,12 +28,18 @@ pub struct HyprlandManager {
     rules_added: bool,
     target_monitor: Option<String>,
     bar_manager: StatusBarManager,
+    /// Window kept above the games: (pid, was floating, was pinned)
+    kept_above: Option<(u32, bool, bool)>,
+    /// Address of the window last placed per instance (no_gamescope only)
+    placed: Vec<Option<String>>,
 }
 
 /// Window info from Hyprland IPC
 struct WindowInfo {
     address: String,
     class: String,
+    pid: Option<u32>,
+    floating: bool,
     width: u32,
     height: u32,
     x: i32,
@@ -44,6 +53,8 @@ impl HyprlandManager {
             rules_added: false,
             target_monitor: None,
             bar_manager: StatusBarManager::new(),
+            kept_above: None,
+            placed: Vec::new(),
         }
     }
 
@@ -129,8 +140,8 @@ impl HyprlandManager {
 
         let commands = crate::wm::pure::hyprland::build_window_rules(target_monitor);
 
-        println!(
-            "[splitux] wm::hyprland - Adding window rules for monitor {}",
+        log_info!(
+            "wm::hyprland - Adding window rules for monitor {}",
             target_monitor
         );
 
@@ -146,8 +157,12 @@ impl HyprlandManager {
         self.hyprctl_batch(&commands)
     }
 
-    /// Get list of gamescope windows with their current geometry
-    fn get_gamescope_windows_info(&self) -> WmResult<Vec<WindowInfo>> {
+    /// Get the instances' windows with their current geometry
+    ///
+    /// These are the gamescope windows, or with no_gamescope the windows of
+    /// processes descending from a spawned instance, reported with that
+    /// instance's PID.
+    fn get_instance_windows_info(&self, ctx: &LayoutContext) -> WmResult<Vec<WindowInfo>> {
         let response = self.hyprctl("j/clients")?;
         let clients: serde_json::Value = serde_json::from_str(&response)
             .map_err(|e| format!("Failed to parse clients: {}", e))?;
@@ -156,55 +171,80 @@ impl HyprlandManager {
         if let Some(arr) = clients.as_array() {
             for client in arr {
                 let class = client["class"].as_str().unwrap_or("");
-                if class.to_lowercase().starts_with("gamescope") {
-                    if let Some(addr) = client["address"].as_str() {
-                        let size = &client["size"];
-                        let at = &client["at"];
-                        windows.push(WindowInfo {
-                            address: addr.to_string(),
-                            class: class.to_string(),
-                            width: size[0].as_u64().unwrap_or(0) as u32,
-                            height: size[1].as_u64().unwrap_or(0) as u32,
-                            x: at[0].as_i64().unwrap_or(0) as i32,
-                            y: at[1].as_i64().unwrap_or(0) as i32,
-                        });
+                let client_pid = client["pid"].as_u64().map(|pid| pid as u32);
+                let pid = if ctx.no_gamescope {
+                    match client_pid.and_then(|pid| spawned_ancestor(pid, &ctx.pids)) {
+                        Some(pid) => Some(pid),
+                        None => continue,
                     }
+                } else if class.to_lowercase().starts_with("gamescope") {
+                    client_pid
+                } else {
+                    continue;
+                };
+                if let Some(addr) = client["address"].as_str() {
+                    let size = &client["size"];
+                    let at = &client["at"];
+                    windows.push(WindowInfo {
+                        address: addr.to_string(),
+                        class: class.to_string(),
+                        pid,
+                        floating: client["floating"].as_bool().unwrap_or(false),
+                        width: size[0].as_u64().unwrap_or(0) as u32,
+                        height: size[1].as_u64().unwrap_or(0) as u32,
+                        x: at[0].as_i64().unwrap_or(0) as i32,
+                        y: at[1].as_i64().unwrap_or(0) as i32,
+                    });
                 }
             }
         }
         Ok(windows)
     }
 
-    /// Get list of gamescope window addresses (legacy compatibility)
-    fn get_gamescope_windows(&self) -> WmResult<Vec<(String, String)>> {
-        Ok(self
-            .get_gamescope_windows_info()?
-            .into_iter()
-            .map(|w| (w.address, w.class))
-            .collect())
+    /// Instances whose window has appeared
+    ///
+    /// A game run without gamescope can open several windows (launcher,
+    /// splash), so an instance counts once any window of its own appears.
+    fn present_instances(&self, ctx: &LayoutContext) -> Vec<usize> {
+        let windows = self.get_instance_windows_info(ctx).unwrap_or_default();
+        let window_pids: Vec<Option<u32>> = windows.iter().map(|w| w.pid).collect();
+        instances_with_windows(ctx.instances.len(), &ctx.pids, &window_pids, ctx.no_gamescope)
     }
 
-    /// Position windows using shared layout calculations
-    fn position_windows(&self, ctx: &LayoutContext) -> WmResult<()> {
-        let windows = self.get_gamescope_windows_info()?;
-        if windows.is_empty() {
-            return Err("No gamescope windows found".into());
-        }
-
-        let monitor_index = ctx.instances.first().map(|i| i.monitor).unwrap_or(0);
-        let hypr_mon = self.get_monitor_by_index(monitor_index)?;
-
-        println!(
-            "[splitux] wm::hyprland - Target monitor: {} at {}x{}+{}+{} (scale: {})",
-            hypr_mon.name, hypr_mon.width, hypr_mon.height, hypr_mon.x, hypr_mon.y, hypr_mon.scale
-        );
+    /// Floating and pinned state of the window owned by a process
+    fn window_state(&self, pid: u32) -> WmResult<(bool, bool)> {
+        let response = self.hyprctl("j/clients")?;
+        let clients: serde_json::Value = serde_json::from_str(&response)
+            .map_err(|e| format!("Failed to parse clients: {}", e))?;
 
-        println!(
-            "[splitux] wm::hyprland - Found {} gamescope windows",
-            windows.len()
-        );
+        clients
+            .as_array()
+            .into_iter()
+            .flatten()
+            .find(|client| client["pid"].as_u64() == Some(pid as u64))
+            .map(|client| {
+                (
+                    client["floating"].as_bool().unwrap_or(false),
+                    client["pinned"].as_bool().unwrap_or(false),
+                )
+            })
+            .ok_or_else(|| format!("No window found for pid {}", pid).into())
+    }
 
-        let mut commands = Vec::new();
+    /// Target geometry of an instance's window, in logical coordinates
+    ///
+    /// See `layout::instance_geometry`; the gamescope resolution and the
+    /// launcher's layout preview use the same function.
+    fn instance_geometry(
+        &self,
+        ctx: &LayoutContext,
+        instance_idx: usize,
+        monitors: &[HyprMonitor],
+    ) -> WmResult<WindowGeometry> {
+        let monitor_index = ctx.instances.get(instance_idx).map(|i| i.monitor).unwrap_or(0);
+        let hypr_mon = monitors
+            .get(monitor_index)
+            .ok_or_else(|| format!("Monitor index {} not found", monitor_index))?;
 
         // Convert physical coordinates to logical (accounting for scale)
         // Hyprland uses logical coordinates in its IPC
@@ -214,39 +254,88 @@ impl HyprlandManager {
         let logical_x = (hypr_mon.x as f64 / scale) as i32;
         let logical_y = (hypr_mon.y as f64 / scale) as i32;
 
-        println!(
-            "[splitux] wm::hyprland - Logical dimensions: {}x{} at +{}+{}",
-            logical_width, logical_height, logical_x, logical_y
+        let instance_monitors: Vec<usize> = ctx.instances.iter().map(|i| i.monitor).collect();
+        Ok(instance_geometry(
+            &ctx.presets,
+            &instance_monitors,
+            instance_idx,
+            &WindowGeometry {
+                x: logical_x,
+                y: logical_y,
+                width: logical_width,
+                height: logical_height,
+            },
+        ))
+    }
+
+    /// Move and resize each instance's window to its region on its monitor
+    ///
+    /// With no_gamescope only each instance's main window is placed, and only
+    /// when it differs from the one placed before, so calling this again
+    /// picks up a game window that replaced its launcher or splash.
+    fn position_windows(&mut self, ctx: &LayoutContext) -> WmResult<()> {
+        let windows = self.get_instance_windows_info(ctx)?;
+        if windows.is_empty() {
+            return Err("No instance windows found".into());
+        }
+
+        let monitors = self.get_monitors()?;
+        log_info!(
+            "wm::hyprland - Found {} instance windows for {} instances",
+            windows.len(),
+            ctx.instances.len()
         );
 
-        for (i, win) in windows.iter().enumerate() {
+        let pairs: Vec<(usize, usize)> = if ctx.no_gamescope {
+            // Every window was traced to its instance; the largest one is the game
+            let ranked: Vec<(Option<usize>, u64)> = windows
+                .iter()
+                .map(|w| {
+                    let instance = w.pid.and_then(|pid| ctx.pids.iter().position(|&p| p == pid));
+                    (instance, w.width as u64 * w.height as u64)
+                })
+                .collect();
+            self.placed.resize(ctx.instances.len(), None);
+            main_windows(ctx.instances.len(), &ranked)
+                .into_iter()
+                .enumerate()
+                .filter_map(|(instance, window)| Some((instance, window?)))
+                .filter(|&(instance, window)| self.placed[instance].as_deref() != Some(&windows[window].address))
+                .collect()
+        } else {
+            let window_pids: Vec<Option<u32>> = windows.iter().map(|w| w.pid).collect();
+            match_windows_to_instances(ctx.instances.len(), &ctx.pids, &window_pids)
+        };
+        if pairs.is_empty() {
+            return Ok(());
+        }
+
+        let mut commands = Vec::new();
+        for &(instance_idx, window_idx) in &pairs {
+            let win = &windows[window_idx];
+
             // Log the ACTUAL size gamescope created (in logical coords from Hyprland)
-            println!(
-                "[splitux] wm::hyprland - Window {} ({}) actual size: {}x{} at +{}+{}",
-                win.address, win.class, win.width, win.height, win.x, win.y
+            log_info!(
+                "wm::hyprland - Instance {} window {} ({}, pid {:?}) actual size: {}x{} at +{}+{}",
+                instance_idx, win.address, win.class, win.pid, win.width, win.height, win.x, win.y
             );
 
-            // Map window index to region index using custom layout order
-            let region_idx = ctx.instance_to_region.get(i).copied().unwrap_or(i);
-
-            // Use shared layout calculation with LOGICAL dimensions
-            let geom: WindowGeometry = calculate_geometry_from_preset(
-                ctx.preset,
-                region_idx,
-                logical_x,
-                logical_y,
-                logical_width,
-                logical_height,
-            );
+            let geom = self.instance_geometry(ctx, instance_idx, &monitors)?;
 
-            println!(
-                "[splitux] wm::hyprland - Window {} target (logical): {}x{}+{}+{}",
+            log_info!(
+                "wm::hyprland - Window {} target (logical): {}x{}+{}+{}",
                 win.address, geom.width, geom.height, geom.x, geom.y
             );
 
             // Only resize if the size is different
-            let needs_resize = win.width != geom.width || win.height != geom.height;
-            let needs_move = win.x != geom.x || win.y != geom.y;
+            // Without gamescope the window rules don't match the game, and
+            // only floating windows can be placed freely. Floating it changes
+            // its geometry, so it's always moved and resized afterwards.
+            if !win.floating {
+                commands.push(format!("dispatch setfloating address:{}", win.address));
+            }
+            let needs_resize = !win.floating || win.width != geom.width || win.height != geom.height;
+            let needs_move = !win.floating || win.x != geom.x || win.y != geom.y;
 
             if needs_move {
                 commands.push(format!(
@@ -255,8 +344,8 @@ impl HyprlandManager {
                 ));
             }
             if needs_resize {
-                println!(
-                    "[splitux] wm::hyprland - Resizing from {}x{} to {}x{} (logical)",
+                log_info!(
+                    "wm::hyprland - Resizing from {}x{} to {}x{} (logical)",
                     win.width, win.height, geom.width, geom.height
                 );
                 commands.push(format!(
@@ -270,12 +359,16 @@ impl HyprlandManager {
             self.hyprctl_batch(&commands)?;
         }
 
-        // Apply visual properties to each window
-        println!("[splitux] wm::hyprland - Applying visual properties...");
-        for win in &windows {
+        // Apply visual properties to each placed window
+        log_info!("wm::hyprland - Applying visual properties...");
+        for &(instance_idx, window_idx) in &pairs {
+            let win = &windows[window_idx];
+            if ctx.no_gamescope {
+                self.placed[instance_idx] = Some(win.address.clone());
+            }
             if let Err(e) = self.apply_window_props(&win.address) {
-                println!(
-                    "[splitux] wm::hyprland - Warning: Failed to apply props to {}: {}",
+                log_warn!(
+                    "wm::hyprland - Failed to apply props to {}: {}",
                     win.address, e
                 );
             }
@@ -289,7 +382,7 @@ impl HyprlandManager {
             return Ok(());
         }
 
-        println!("[splitux] wm::hyprland - Removing window rules");
+        log_info!("wm::hyprland - Removing window rules");
         let commands = vec![
             "keyword windowrulev2 unset,class:^([Gg]amescope.*)$".to_string()
         ];
@@ -322,7 +415,7 @@ impl WindowManager for HyprlandManager {
     }
 
     fn setup(&mut self, ctx: &LayoutContext) -> WmResult<()> {
-        println!("[splitux] wm::hyprland - Setting up");
+        log_info!("wm::hyprland - Setting up");
 
         let monitor_index = ctx.instances.first().map(|i| i.monitor).unwrap_or(0);
         let hypr_mon = self.get_monitor_by_index(monitor_index)?;
@@ -335,18 +428,40 @@ impl WindowManager for HyprlandManager {
     }
 
     fn on_instances_launched(&mut self, ctx: &LayoutContext) -> WmResult<()> {
-        println!("[splitux] wm::hyprland - Waiting for gamescope windows...");
+        log_info!("wm::hyprland - Waiting for instance windows...");
 
-        let expected_count = ctx.instances.len();
-        crate::wm::operations::poll::wait_for_windows("hyprland", expected_count, || {
-            self.get_gamescope_windows().unwrap_or_default().len()
+        crate::wm::operations::poll::wait_for_windows("hyprland", ctx.instances.len(), || {
+            self.present_instances(ctx)
         })?;
 
         self.position_windows(ctx)
     }
 
+    fn refresh_layout(&mut self, ctx: &LayoutContext) -> WmResult<()> {
+        if !ctx.no_gamescope {
+            return Ok(());
+        }
+        self.position_windows(ctx)
+    }
+
+    fn keep_above(&mut self, pid: u32, above: bool) -> WmResult<()> {
+        let commands = if above {
+            let (floating, pinned) = self.window_state(pid)?;
+            log_info!("wm::hyprland - Keeping window of pid {} above games", pid);
+            self.kept_above = Some((pid, floating, pinned));
+            crate::wm::pure::hyprland::build_keep_above_commands(pid, true, floating, pinned)
+        } else {
+            let Some((pid, floating, pinned)) = self.kept_above.take() else {
+                return Ok(());
+            };
+            log_info!("wm::hyprland - Releasing window of pid {}", pid);
+            crate::wm::pure::hyprland::build_keep_above_commands(pid, false, floating, pinned)
+        };
+        self.hyprctl_batch(&commands)
+    }
+
     fn teardown(&mut self) -> WmResult<()> {
-        println!("[splitux] wm::hyprland - Tearing down");
+        log_info!("wm::hyprland - Tearing down");
         self.remove_window_rules()?;
         self.bar_manager.restore_all();
         Ok(())
@@ -1,6 +1,7 @@
 //! KWin window manager integration via D-Bus scripting API
 
-use crate::paths::PATH_ASSETS;
+use crate::paths::{path_tmp, PATH_ASSETS};
+use crate::wm::pure::kwin::{build_keep_above_script, KEEP_ABOVE_PLUGIN};
 use crate::wm::{LayoutContext, NestedSession, WindowManager, WmResult};
 use crate::monitor::Monitor;
 use std::path::PathBuf;
@@ -8,18 +9,53 @@ use std::process::Command;
 
 pub struct KWinManager {
     script_loaded: bool,
+    /// PID whose windows the keep-above script is holding up
+    kept_above: Option<u32>,
 }
 
 impl KWinManager {
     pub fn new() -> Self {
         Self {
             script_loaded: false,
+            kept_above: None,
         }
     }
 
+    fn scripting_proxy(
+        conn: &zbus::blocking::Connection,
+    ) -> WmResult<zbus::blocking::Proxy<'_>> {
+        Ok(zbus::blocking::Proxy::new(
+            conn,
+            "org.kde.KWin",
+            "/Scripting",
+            "org.kde.kwin.Scripting",
+        )?)
+    }
+
+    /// Write a generated script to the scratch folder and run it under `plugin`
+    fn run_generated_script(plugin: &str, script: &str) -> WmResult<()> {
+        let tmp = path_tmp();
+        std::fs::create_dir_all(&tmp)?;
+        let file = tmp.join(format!("{}.js", plugin));
+        std::fs::write(&file, script)?;
+
+        let conn = zbus::blocking::Connection::session()?;
+        let proxy = Self::scripting_proxy(&conn)?;
+        let _: i32 = proxy.call("loadScript", &(file.to_string_lossy(), plugin))?;
+        let _: () = proxy.call("start", &())?;
+        Ok(())
+    }
+
+    fn unload_plugin(plugin: &str) -> WmResult<()> {
+        let conn = zbus::blocking::Connection::session()?;
+        let proxy = Self::scripting_proxy(&conn)?;
+        let _: bool = proxy.call("unloadScript", &(plugin,))?;
+        Ok(())
+    }
+
     fn load_script(&mut self, file: PathBuf) -> WmResult<()> {
-        println!(
-            "[splitux] wm::kwin - Loading script {}...",
+        log_info!(
+            "wm::kwin - Loading script {}...",
             file.display()
         );
 
@@ -32,19 +68,13 @@ impl KWinManager {
         }
 
         let conn = zbus::blocking::Connection::session()?;
-        let proxy = zbus::blocking::Proxy::new(
-            &conn,
-            "org.kde.KWin",
-            "/Scripting",
-            "org.kde.kwin.Scripting",
-        )?;
-
+        let proxy = Self::scripting_proxy(&conn)?;
         let _: i32 = proxy.call("loadScript", &(file.to_string_lossy(), "splitscreen"))?;
-        println!("[splitux] wm::kwin - Script loaded. Starting...");
+        log_info!("wm::kwin - Script loaded. Starting...");
         let _: () = proxy.call("start", &())?;
 
         self.script_loaded = true;
-        println!("[splitux] wm::kwin - KWin script started.");
+        log_info!("wm::kwin - KWin script started.");
         Ok(())
     }
 
@@ -53,7 +83,7 @@ impl KWinManager {
             return Ok(());
         }
 
-        println!("[splitux] wm::kwin - Unloading splitscreen script...");
+        log_info!("wm::kwin - Unloading splitscreen script...");
         let conn = zbus::blocking::Connection::session()?;
         let proxy = zbus::blocking::Proxy::new(
             &conn,
@@ -65,7 +95,7 @@ impl KWinManager {
         let _: bool = proxy.call("unloadScript", &("splitscreen",))?;
         self.script_loaded = false;
 
-        println!("[splitux] wm::kwin - Script unloaded.");
+        log_info!("wm::kwin - Script unloaded.");
         Ok(())
     }
 }
@@ -82,6 +112,7 @@ impl WindowManager for KWinManager {
     }
 
     fn setup(&mut self, ctx: &LayoutContext) -> WmResult<()> {
+        // no_gamescope handlers are turned away before launch (see launch_game)
         // For 2-player, check if using vertical preset; otherwise use horizontal script
         // TODO: Generate dynamic KWin scripts from preset coordinates
         let script = if ctx.preset.id == "2p_vertical" {
@@ -92,6 +123,24 @@ impl WindowManager for KWinManager {
         self.load_script(PATH_ASSETS.join(script))
     }
 
+    fn keep_above(&mut self, pid: u32, above: bool) -> WmResult<()> {
+        if above {
+            log_info!("wm::kwin - Keeping window of pid {} above games", pid);
+            Self::run_generated_script(KEEP_ABOVE_PLUGIN, &build_keep_above_script(pid, true))?;
+            self.kept_above = Some(pid);
+            return Ok(());
+        }
+
+        let Some(pid) = self.kept_above.take() else {
+            return Ok(());
+        };
+        log_info!("wm::kwin - Releasing window of pid {}", pid);
+        // Stop the running script, then run one that clears keepAbove
+        Self::unload_plugin(KEEP_ABOVE_PLUGIN)?;
+        Self::run_generated_script(KEEP_ABOVE_PLUGIN, &build_keep_above_script(pid, false))?;
+        Self::unload_plugin(KEEP_ABOVE_PLUGIN)
+    }
+
     fn teardown(&mut self) -> WmResult<()> {
         self.unload_script()
     }
@@ -1,6 +1,6 @@
 //! Shared layout calculation logic for splitscreen window positioning.
 
-use super::presets::LayoutPreset;
+use super::presets::{LayoutPreset, LayoutPresets};
 
 /// Represents the window geometry for a game instance
 #[derive(Debug, Clone)]
@@ -20,13 +20,104 @@ pub fn calculate_geometry_from_preset(
     monitor_width: u32,
     monitor_height: u32,
 ) -> WindowGeometry {
-    let index = player_index.min(preset.regions.len().saturating_sub(1));
-    let region = preset.regions[index];
+    let (x, y, width, height) = preset.region_pixels(player_index, monitor_width, monitor_height);
 
     WindowGeometry {
-        x: monitor_x + (region[0] * monitor_width as f32) as i32,
-        y: monitor_y + (region[1] * monitor_height as f32) as i32,
-        width: (region[2] * monitor_width as f32) as u32,
-        height: (region[3] * monitor_height as f32) as u32,
+        x: monitor_x + x,
+        y: monitor_y + y,
+        width,
+        height,
+    }
+}
+
+/// Invert a preset's custom order (`order[region] = instance`) into the
+/// region each instance goes to
+pub fn instance_to_region(order: &[usize], player_count: usize) -> Vec<usize> {
+    let mut instance_to_region = vec![0; player_count];
+    for (region, &instance_idx) in order.iter().enumerate() {
+        if instance_idx < player_count {
+            instance_to_region[instance_idx] = region;
+        }
+    }
+    instance_to_region
+}
+
+/// Target geometry of an instance's window, given its monitor's rectangle
+///
+/// `instance_monitors` holds every instance's monitor index. Each monitor is
+/// split between the instances on it with the configured preset and order
+/// for that many players, so with everyone on one monitor this is the
+/// selected layout. A lone instance gets its whole monitor. Window placement,
+/// the gamescope resolution and the launcher's preview all use this.
+pub fn instance_geometry(
+    presets: &LayoutPresets,
+    instance_monitors: &[usize],
+    instance_idx: usize,
+    monitor: &WindowGeometry,
+) -> WindowGeometry {
+    let monitor_index = instance_monitors.get(instance_idx).copied().unwrap_or(0);
+    let same_monitor: Vec<usize> = (0..instance_monitors.len())
+        .filter(|&i| instance_monitors[i] == monitor_index)
+        .collect();
+    let local_idx = same_monitor.iter().position(|&i| i == instance_idx).unwrap_or(0);
+
+    match presets.layout_for_count(same_monitor.len()) {
+        Some((preset, order)) => calculate_geometry_from_preset(
+            &preset,
+            instance_to_region(&order, same_monitor.len())[local_idx],
+            monitor.x,
+            monitor.y,
+            monitor.width,
+            monitor.height,
+        ),
+        None => monitor.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: i32, y: i32, width: u32, height: u32) -> WindowGeometry {
+        WindowGeometry { x, y, width, height }
+    }
+
+    fn presets(two_player: &str) -> LayoutPresets {
+        LayoutPresets {
+            two_player: two_player.to_string(),
+            three_player: "3p_vertical".to_string(),
+            four_player: "4p_grid".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn custom_order_swaps_regions() {
+        assert_eq!(instance_to_region(&[1, 0], 2), vec![1, 0]);
+        assert_eq!(instance_to_region(&[2, 0, 1], 3), vec![1, 2, 0]);
+
+        let mut presets = presets("2p_vertical");
+        presets.set_order("2p_vertical", vec![1, 0]);
+        let monitor = rect(0, 0, 1920, 1080);
+        let geom = instance_geometry(&presets, &[0, 0], 0, &monitor);
+        assert_eq!((geom.x, geom.width), (960, 960));
+    }
+
+    #[test]
+    fn instances_on_other_monitors_split_their_own_monitor() {
+        let monitors = [rect(0, 0, 1920, 1080), rect(1920, 0, 2560, 1440)];
+        let on = [0, 1, 1];
+        let horizontal = presets("2p_horizontal");
+        let geom = |i: usize| instance_geometry(&horizontal, &on, i, &monitors[on[i]]);
+
+        let solo = geom(0);
+        assert_eq!((solo.x, solo.y, solo.width, solo.height), (0, 0, 1920, 1080));
+        // Two players on the second monitor get the configured 2-player preset
+        let (a, b) = (geom(1), geom(2));
+        assert_eq!((a.x, a.height, b.x, b.height), (1920, 720, 1920, 720));
+        assert_eq!(b.y, 720);
+
+        let b = instance_geometry(&presets("2p_vertical"), &on, 2, &monitors[1]);
+        assert_eq!((b.x, b.width, b.height), (1920 + 1280, 1280, 1440));
     }
 }
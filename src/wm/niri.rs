@@ -1,7 +1,9 @@
 //! Niri window manager integration via niri msg CLI
 
 use crate::wm::bars::StatusBarManager;
+use crate::wm::operations::process::spawned_ancestor;
 use crate::wm::pure::layout::plan_tiling_layout;
+use crate::wm::pure::readiness::{instances_with_windows, main_windows};
 use crate::wm::types::WmMonitor;
 use crate::wm::{LayoutContext, WindowManager, WmResult};
 use std::process::Command;
@@ -12,11 +14,15 @@ struct NiriWindow {
     id: u64,
     app_id: String,
     is_floating: bool,
+    /// PID of the window, or with no_gamescope of the instance it belongs to
+    pid: Option<u32>,
 }
 
 pub struct NiriManager {
     target_monitor: Option<String>,
     bar_manager: StatusBarManager,
+    /// IDs of the windows last tiled (no_gamescope only)
+    placed: Vec<u64>,
 }
 
 impl NiriManager {
@@ -24,6 +30,7 @@ impl NiriManager {
         Self {
             target_monitor: None,
             bar_manager: StatusBarManager::new(),
+            placed: Vec::new(),
         }
     }
 
@@ -59,7 +66,7 @@ impl NiriManager {
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
             // Some actions return non-zero but still work, log but don't fail
-            println!("[splitux] wm::niri - Action {} warning: {}", action, stderr);
+            log_warn!("wm::niri - Action {} warning: {}", action, stderr);
         }
 
         Ok(())
@@ -134,13 +141,44 @@ impl NiriManager {
         Err(format!("Monitor '{}' not found after {} retries", connector_name, max_retries).into())
     }
 
-    /// Get list of gamescope windows
-    fn get_gamescope_windows(&self) -> WmResult<Vec<NiriWindow>> {
+    /// Get the instances' windows
+    ///
+    /// These are the gamescope windows, or with no_gamescope the main window
+    /// of each spawned instance's process tree, in spawn order. The main window
+    /// is the last one mapped, so a game window replaces its launcher or splash.
+    fn get_instance_windows(&self, ctx: &LayoutContext) -> WmResult<Vec<NiriWindow>> {
         let response = self.niri_msg(&["windows"])?;
         let windows: serde_json::Value = serde_json::from_str(&response)
             .map_err(|e| format!("Failed to parse windows: {}", e))?;
 
         let mut result = Vec::new();
+        if ctx.no_gamescope {
+            let mut traced: Vec<(Option<usize>, NiriWindow)> = Vec::new();
+            for win in windows.as_array().into_iter().flatten() {
+                let Some(id) = win["id"].as_u64() else {
+                    continue;
+                };
+                let pid = win["pid"]
+                    .as_u64()
+                    .and_then(|pid| spawned_ancestor(pid as u32, &ctx.pids));
+                traced.push((
+                    pid.and_then(|pid| ctx.pids.iter().position(|&p| p == pid)),
+                    NiriWindow {
+                        id,
+                        app_id: win["app_id"].as_str().unwrap_or("").to_string(),
+                        is_floating: win["is_floating"].as_bool().unwrap_or(false),
+                        pid,
+                    },
+                ));
+            }
+            // Niri hands out increasing IDs, so the highest is the last mapped
+            let ranked: Vec<(Option<usize>, u64)> = traced.iter().map(|(i, w)| (*i, w.id)).collect();
+            return Ok(main_windows(ctx.instances.len(), &ranked)
+                .into_iter()
+                .flatten()
+                .map(|window| traced[window].1.clone())
+                .collect());
+        }
         if let Some(arr) = windows.as_array() {
             for win in arr {
                 let app_id = win["app_id"].as_str().unwrap_or("");
@@ -151,6 +189,7 @@ impl NiriManager {
                             id,
                             app_id: app_id.to_string(),
                             is_floating: win["is_floating"].as_bool().unwrap_or(false),
+                            pid: win["pid"].as_u64().map(|pid| pid as u32),
                         });
                     }
                 }
@@ -159,11 +198,11 @@ impl NiriManager {
         Ok(result)
     }
 
-    /// Position all gamescope windows according to layout using tiled mode
+    /// Position all instance windows according to layout using tiled mode
     fn position_windows(&self, ctx: &LayoutContext) -> WmResult<()> {
-        let windows = self.get_gamescope_windows()?;
+        let windows = self.get_instance_windows(ctx)?;
         if windows.is_empty() {
-            return Err("No gamescope windows found".into());
+            return Err("No instance windows found".into());
         }
 
         // Use the target monitor set in setup() (looked up by connector name)
@@ -175,17 +214,17 @@ impl NiriManager {
             }
         };
 
-        let plan = plan_tiling_layout(ctx.preset.id, windows.len());
+        let plan = plan_tiling_layout(&ctx.preset.id, windows.len());
 
-        println!(
-            "[splitux] wm::niri - Target monitor: {} ({}x{}), {} columns, {} windows",
-            monitor.name, monitor.width, monitor.height, plan.columns.len(), windows.len()
+        log_info!(
+            "wm::niri - Target monitor: {} ({}x{}), {} columns, {} windows",
+            monitor.name, monitor.width, monitor.height, plan.as_ref().map_or(0, |p| p.columns.len()), windows.len()
         );
 
         // Step 1: Move all windows to target monitor and ensure tiled
         for (i, win) in windows.iter().enumerate() {
-            println!(
-                "[splitux] wm::niri - Window {}: id={} app_id={}",
+            log_info!(
+                "wm::niri - Window {}: id={} app_id={}",
                 i, win.id, win.app_id
             );
             self.niri_action("focus-window", &["--id", &win.id.to_string()])?;
@@ -202,8 +241,16 @@ impl NiriManager {
             }
         }
 
+        let Some(plan) = plan else {
+            log_warn!(
+                "wm::niri - {} can't be tiled as columns; leaving the windows in niri's default layout",
+                ctx.preset.id
+            );
+            return Ok(());
+        };
+
         // Step 2: Apply tiling plan — re-fetch windows after tiling changes
-        let windows = self.get_gamescope_windows()?;
+        let windows = self.get_instance_windows(ctx)?;
 
         for (col_idx, column) in plan.columns.iter().enumerate() {
             let width = format!("{}%", column.width_percent);
@@ -238,8 +285,8 @@ impl NiriManager {
                 self.niri_action("set-column-width", &[&width])?;
             }
 
-            println!(
-                "[splitux] wm::niri - Column {}: {} windows at {}",
+            log_info!(
+                "wm::niri - Column {}: {} windows at {}",
                 col_idx, column.windows.len(), width
             );
         }
@@ -260,7 +307,7 @@ impl WindowManager for NiriManager {
     }
 
     fn setup(&mut self, ctx: &LayoutContext) -> WmResult<()> {
-        println!("[splitux] wm::niri - Setting up");
+        log_info!("wm::niri - Setting up");
 
         // Get the target monitor using SDL's index (matches gamescope's --display-index)
         let monitor_index = ctx.instances.first().map(|i| i.monitor).unwrap_or(0);
@@ -268,7 +315,7 @@ impl WindowManager for NiriManager {
         // Look up by connector name from SDL monitor (preferred for accuracy)
         let monitor = if let Some(sdl_monitor) = ctx.monitors.get(monitor_index) {
             let connector = sdl_monitor.connector_name();
-            println!("[splitux] wm::niri - Looking up monitor by connector: {}", connector);
+            log_info!("wm::niri - Looking up monitor by connector: {}", connector);
             self.get_monitor_by_name(connector)?
         } else {
             // Fallback to index if SDL monitor not available
@@ -277,8 +324,8 @@ impl WindowManager for NiriManager {
 
         self.target_monitor = Some(monitor.name.clone());
 
-        println!(
-            "[splitux] wm::niri - Target monitor: {} ({}x{})",
+        log_info!(
+            "wm::niri - Target monitor: {} ({}x{})",
             monitor.name, monitor.width, monitor.height
         );
 
@@ -289,18 +336,37 @@ impl WindowManager for NiriManager {
     }
 
     fn on_instances_launched(&mut self, ctx: &LayoutContext) -> WmResult<()> {
-        println!("[splitux] wm::niri - Waiting for gamescope windows...");
+        log_info!("wm::niri - Waiting for instance windows...");
 
-        let expected_count = ctx.instances.len();
-        crate::wm::operations::poll::wait_for_windows("niri", expected_count, || {
-            self.get_gamescope_windows().unwrap_or_default().len()
+        crate::wm::operations::poll::wait_for_windows("niri", ctx.instances.len(), || {
+            let windows = self.get_instance_windows(ctx).unwrap_or_default();
+            let window_pids: Vec<Option<u32>> = windows.iter().map(|w| w.pid).collect();
+            instances_with_windows(ctx.instances.len(), &ctx.pids, &window_pids, ctx.no_gamescope)
         })?;
 
-        self.position_windows(ctx)
+        self.position_windows(ctx)?;
+        if ctx.no_gamescope {
+            self.placed = self.get_instance_windows(ctx)?.iter().map(|w| w.id).collect();
+        }
+        Ok(())
+    }
+
+    fn refresh_layout(&mut self, ctx: &LayoutContext) -> WmResult<()> {
+        if !ctx.no_gamescope {
+            return Ok(());
+        }
+        let windows: Vec<u64> = self.get_instance_windows(ctx)?.iter().map(|w| w.id).collect();
+        if windows.is_empty() || windows == self.placed {
+            return Ok(());
+        }
+        log_info!("wm::niri - Instance windows changed, re-tiling");
+        self.position_windows(ctx)?;
+        self.placed = windows;
+        Ok(())
     }
 
     fn teardown(&mut self) -> WmResult<()> {
-        println!("[splitux] wm::niri - Tearing down");
+        log_info!("wm::niri - Tearing down");
         self.bar_manager.restore_all();
         Ok(())
     }
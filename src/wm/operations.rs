@@ -1,3 +1,5 @@
 // Window manager operations — atomic I/O functions
 
 pub mod poll;
+pub mod process;
+pub mod session;
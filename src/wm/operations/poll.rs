@@ -1,46 +1,58 @@
 // Window polling loop — shared between niri and hyprland
 
 use crate::wm::WmResult;
+use crate::wm::pure::readiness::{missing_instances, player_list};
 
-/// Poll for windows until we find the expected count, or timeout.
+/// Poll until every instance has a window, or timeout.
 ///
 /// `wm_name`: name for logging (e.g., "niri", "hyprland")
-/// `expected`: number of windows to wait for
-/// `get_count`: closure that returns the current window count (performs I/O)
-pub fn wait_for_windows<F>(wm_name: &str, expected: usize, get_count: F) -> WmResult<usize>
+/// `instance_count`: number of instances to wait for
+/// `get_present`: closure that returns the instances whose window has
+/// appeared (performs I/O)
+///
+/// Returns the instances still without a window. On timeout those are
+/// logged, and the caller lays out the windows that do exist.
+pub fn wait_for_windows<F>(wm_name: &str, instance_count: usize, get_present: F) -> WmResult<Vec<usize>>
 where
-    F: Fn() -> usize,
+    F: Fn() -> Vec<usize>,
 {
     let max_wait = std::time::Duration::from_secs(120);
     let poll_interval = std::time::Duration::from_millis(500);
     let start = std::time::Instant::now();
 
     loop {
-        let count = get_count();
+        let present = get_present();
+        let missing = missing_instances(instance_count, &present);
 
-        if count >= expected {
-            println!(
-                "[splitux] wm::{} - Found {} windows after {:.1}s",
+        if missing.is_empty() {
+            log_info!(
+                "wm::{} - Found windows for all {} instances after {:.1}s",
                 wm_name,
-                count,
+                instance_count,
                 start.elapsed().as_secs_f32()
             );
             std::thread::sleep(std::time::Duration::from_millis(500));
-            return Ok(count);
+            return Ok(missing);
         }
 
         if start.elapsed() > max_wait {
-            println!(
-                "[splitux] wm::{} - Timeout waiting for windows ({}/{})",
-                wm_name, count, expected
+            log_warn!(
+                "wm::{} - Timeout waiting for windows ({}/{}), no window for {}; laying out the rest",
+                wm_name,
+                instance_count - missing.len(),
+                instance_count,
+                player_list(&missing)
             );
-            return Ok(count);
+            return Ok(missing);
         }
 
         if start.elapsed().as_secs() % 5 == 0 && start.elapsed().as_millis() % 500 < 100 {
-            println!(
-                "[splitux] wm::{} - Still waiting... ({}/{} windows)",
-                wm_name, count, expected
+            log_info!(
+                "wm::{} - Still waiting... ({}/{} windows, missing {})",
+                wm_name,
+                instance_count - missing.len(),
+                instance_count,
+                player_list(&missing)
             );
         }
 
@@ -0,0 +1,10 @@
+// Process ancestry lookups in /proc — finds which instance owns a window
+
+use crate::wm::pure::process::{find_spawned_ancestor, stat_ppid};
+
+/// The spawned instance PID that `pid` is, or descends from
+pub fn spawned_ancestor(pid: u32, spawned: &[u32]) -> Option<u32> {
+    find_spawned_ancestor(pid, spawned, |p| {
+        stat_ppid(&std::fs::read_to_string(format!("/proc/{}/stat", p)).ok()?)
+    })
+}
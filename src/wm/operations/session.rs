@@ -0,0 +1,22 @@
+// Session detection — reads display-server environment variables
+
+use crate::wm::types::SessionEnv;
+
+/// Capture the display-server environment of the current process
+pub fn read_session_env() -> SessionEnv {
+    let non_empty = |var: &str| std::env::var(var).is_ok_and(|v| !v.is_empty());
+
+    SessionEnv {
+        session_type: std::env::var("XDG_SESSION_TYPE")
+            .ok()
+            .filter(|v| !v.is_empty())
+            .map(|v| v.to_lowercase()),
+        has_display: non_empty("DISPLAY"),
+        has_wayland_display: non_empty("WAYLAND_DISPLAY"),
+        is_niri: non_empty("NIRI_SOCKET"),
+        is_gamescope_session: non_empty("GAMESCOPE_WAYLAND_DISPLAY")
+            || non_empty("SteamOS")
+            || std::env::var("XDG_CURRENT_DESKTOP")
+                .is_ok_and(|v| v.to_lowercase().contains("gamescope")),
+    }
+}
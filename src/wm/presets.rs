@@ -4,19 +4,41 @@
 //! Regions are [x, y, width, height] in normalized screen coordinates.
 
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 /// A layout preset defines where each player's window is positioned
 #[derive(Debug, Clone, PartialEq)]
 pub struct LayoutPreset {
     /// Unique ID for serialization (e.g., "2p_horizontal", "3p_t_shape")
-    pub id: &'static str,
+    pub id: Cow<'static, str>,
     /// Human-readable name (e.g., "Top/Bottom", "Side by Side")
-    pub name: &'static str,
+    pub name: Cow<'static, str>,
     /// Player count this preset applies to
     pub player_count: usize,
     /// Normalized rectangles for each player [x, y, w, h] in 0.0-1.0 range
-    pub regions: &'static [[f32; 4]],
+    pub regions: Cow<'static, [[f32; 4]]>,
+}
+
+impl LayoutPreset {
+    /// Pixel rectangle (x, y, width, height) of a region on a `width`x`height` area
+    ///
+    /// Region edges are rounded rather than sizes, so neighbouring regions share
+    /// an edge and thirds of an odd width still fill the area without gaps.
+    pub fn region_pixels(&self, index: usize, width: u32, height: u32) -> (i32, i32, u32, u32) {
+        let index = index.min(self.regions.len().saturating_sub(1));
+        let [x, y, w, h] = self.regions[index];
+        let (x, w) = pixel_span(x, w, width);
+        let (y, h) = pixel_span(y, h, height);
+        (x, y, w, h)
+    }
+}
+
+/// Start and length in pixels of the normalized span [start, start + len)
+fn pixel_span(start: f32, len: f32, extent: u32) -> (i32, u32) {
+    let edge = |v: f32| (v * extent as f32).round().clamp(0.0, extent as f32) as u32;
+    let (a, b) = (edge(start), edge(start + len));
+    (a as i32, b.saturating_sub(a))
 }
 
 // ============================================================================
@@ -25,24 +47,24 @@ pub struct LayoutPreset {
 
 /// P1 top half, P2 bottom half
 pub static PRESET_2P_HORIZONTAL: LayoutPreset = LayoutPreset {
-    id: "2p_horizontal",
-    name: "Top / Bottom",
+    id: Cow::Borrowed("2p_horizontal"),
+    name: Cow::Borrowed("Top / Bottom"),
     player_count: 2,
-    regions: &[
+    regions: Cow::Borrowed(&[
         [0.0, 0.0, 1.0, 0.5], // P1: top half
         [0.0, 0.5, 1.0, 0.5], // P2: bottom half
-    ],
+    ]),
 };
 
 /// P1 left half, P2 right half
 pub static PRESET_2P_VERTICAL: LayoutPreset = LayoutPreset {
-    id: "2p_vertical",
-    name: "Side by Side",
+    id: Cow::Borrowed("2p_vertical"),
+    name: Cow::Borrowed("Side by Side"),
     player_count: 2,
-    regions: &[
+    regions: Cow::Borrowed(&[
         [0.0, 0.0, 0.5, 1.0], // P1: left half
         [0.5, 0.0, 0.5, 1.0], // P2: right half
-    ],
+    ]),
 };
 
 // ============================================================================
@@ -51,26 +73,50 @@ pub static PRESET_2P_VERTICAL: LayoutPreset = LayoutPreset {
 
 /// 3 equal columns side by side (33%/33%/33%)
 pub static PRESET_3P_VERTICAL: LayoutPreset = LayoutPreset {
-    id: "3p_vertical",
-    name: "Side by Side",
+    id: Cow::Borrowed("3p_vertical"),
+    name: Cow::Borrowed("Side by Side"),
     player_count: 3,
-    regions: &[
+    regions: Cow::Borrowed(&[
         [0.0, 0.0, 1.0 / 3.0, 1.0],       // P1: left third
         [1.0 / 3.0, 0.0, 1.0 / 3.0, 1.0], // P2: middle third
         [2.0 / 3.0, 0.0, 1.0 / 3.0, 1.0], // P3: right third
-    ],
+    ]),
 };
 
 /// 3 stacked rows (33%/33%/33%)
 pub static PRESET_3P_HORIZONTAL: LayoutPreset = LayoutPreset {
-    id: "3p_horizontal",
-    name: "Stacked",
+    id: Cow::Borrowed("3p_horizontal"),
+    name: Cow::Borrowed("Stacked"),
     player_count: 3,
-    regions: &[
+    regions: Cow::Borrowed(&[
         [0.0, 0.0, 1.0, 1.0 / 3.0],       // P1: top third
         [0.0, 1.0 / 3.0, 1.0, 1.0 / 3.0], // P2: middle third
         [0.0, 2.0 / 3.0, 1.0, 1.0 / 3.0], // P3: bottom third
-    ],
+    ]),
+};
+
+/// P1 full-width on top, P2/P3 splitting the bottom half
+pub static PRESET_3P_BIG_TOP: LayoutPreset = LayoutPreset {
+    id: Cow::Borrowed("3p_big_top"),
+    name: Cow::Borrowed("Big Top"),
+    player_count: 3,
+    regions: Cow::Borrowed(&[
+        [0.0, 0.0, 1.0, 0.5], // P1: top half
+        [0.0, 0.5, 0.5, 0.5], // P2: bottom-left
+        [0.5, 0.5, 0.5, 0.5], // P3: bottom-right
+    ]),
+};
+
+/// P1 full-height on the left, P2/P3 stacked on the right
+pub static PRESET_3P_BIG_LEFT: LayoutPreset = LayoutPreset {
+    id: Cow::Borrowed("3p_big_left"),
+    name: Cow::Borrowed("Big Left"),
+    player_count: 3,
+    regions: Cow::Borrowed(&[
+        [0.0, 0.0, 0.5, 1.0], // P1: left half
+        [0.5, 0.0, 0.5, 0.5], // P2: top-right
+        [0.5, 0.5, 0.5, 0.5], // P3: bottom-right
+    ]),
 };
 
 // ============================================================================
@@ -79,47 +125,89 @@ pub static PRESET_3P_HORIZONTAL: LayoutPreset = LayoutPreset {
 
 /// Standard 2x2 grid (P1 top-left, P2 top-right, P3 bottom-left, P4 bottom-right)
 pub static PRESET_4P_GRID: LayoutPreset = LayoutPreset {
-    id: "4p_grid",
-    name: "Grid",
+    id: Cow::Borrowed("4p_grid"),
+    name: Cow::Borrowed("Grid"),
     player_count: 4,
-    regions: &[
+    regions: Cow::Borrowed(&[
         [0.0, 0.0, 0.5, 0.5], // P1: top-left
         [0.5, 0.0, 0.5, 0.5], // P2: top-right
         [0.0, 0.5, 0.5, 0.5], // P3: bottom-left
         [0.5, 0.5, 0.5, 0.5], // P4: bottom-right
-    ],
+    ]),
 };
 
 /// Rows: P1/P2 on top row, P3/P4 on bottom row (reads left-to-right, top-to-bottom)
 pub static PRESET_4P_ROWS: LayoutPreset = LayoutPreset {
-    id: "4p_rows",
-    name: "Rows",
+    id: Cow::Borrowed("4p_rows"),
+    name: Cow::Borrowed("Rows"),
     player_count: 4,
-    regions: &[
+    regions: Cow::Borrowed(&[
         [0.0, 0.0, 0.5, 0.5], // P1: top-left
         [0.5, 0.0, 0.5, 0.5], // P2: top-right
         [0.0, 0.5, 0.5, 0.5], // P3: bottom-left
         [0.5, 0.5, 0.5, 0.5], // P4: bottom-right
-    ],
+    ]),
 };
 
 /// Columns: P1/P2 on left column, P3/P4 on right column
 pub static PRESET_4P_COLUMNS: LayoutPreset = LayoutPreset {
-    id: "4p_columns",
-    name: "Columns",
+    id: Cow::Borrowed("4p_columns"),
+    name: Cow::Borrowed("Columns"),
     player_count: 4,
-    regions: &[
+    regions: Cow::Borrowed(&[
         [0.0, 0.0, 0.5, 0.5], // P1: top-left
         [0.0, 0.5, 0.5, 0.5], // P2: bottom-left
         [0.5, 0.0, 0.5, 0.5], // P3: top-right
         [0.5, 0.5, 0.5, 0.5], // P4: bottom-right
-    ],
+    ]),
+};
+
+/// 4 equal columns side by side (25% each)
+pub static PRESET_4P_VERTICAL: LayoutPreset = LayoutPreset {
+    id: Cow::Borrowed("4p_vertical"),
+    name: Cow::Borrowed("Side by Side"),
+    player_count: 4,
+    regions: Cow::Borrowed(&[
+        [0.0, 0.0, 0.25, 1.0],  // P1: first column
+        [0.25, 0.0, 0.25, 1.0], // P2: second column
+        [0.5, 0.0, 0.25, 1.0],  // P3: third column
+        [0.75, 0.0, 0.25, 1.0], // P4: fourth column
+    ]),
+};
+
+/// 4 stacked rows (25% each)
+pub static PRESET_4P_HORIZONTAL: LayoutPreset = LayoutPreset {
+    id: Cow::Borrowed("4p_horizontal"),
+    name: Cow::Borrowed("Stacked"),
+    player_count: 4,
+    regions: Cow::Borrowed(&[
+        [0.0, 0.0, 1.0, 0.25],  // P1: first row
+        [0.0, 0.25, 1.0, 0.25], // P2: second row
+        [0.0, 0.5, 1.0, 0.25],  // P3: third row
+        [0.0, 0.75, 1.0, 0.25], // P4: fourth row
+    ]),
+};
+
+/// P1 full-width on top, P2/P3/P4 splitting the bottom half into thirds
+pub static PRESET_4P_BIG_TOP: LayoutPreset = LayoutPreset {
+    id: Cow::Borrowed("4p_big_top"),
+    name: Cow::Borrowed("Big Top"),
+    player_count: 4,
+    regions: Cow::Borrowed(&[
+        [0.0, 0.0, 1.0, 0.5],             // P1: top half
+        [0.0, 0.5, 1.0 / 3.0, 0.5],       // P2: bottom-left
+        [1.0 / 3.0, 0.5, 1.0 / 3.0, 0.5], // P3: bottom-middle
+        [2.0 / 3.0, 0.5, 1.0 / 3.0, 0.5], // P4: bottom-right
+    ]),
 };
 
 // ============================================================================
 // Preset Registry
 // ============================================================================
 
+/// Largest player count with layout presets
+pub const MAX_PRESET_PLAYERS: usize = 4;
+
 /// All 2-player presets
 pub static PRESETS_2P: &[&LayoutPreset] = &[
     &PRESET_2P_HORIZONTAL,
@@ -130,6 +218,8 @@ pub static PRESETS_2P: &[&LayoutPreset] = &[
 pub static PRESETS_3P: &[&LayoutPreset] = &[
     &PRESET_3P_VERTICAL,
     &PRESET_3P_HORIZONTAL,
+    &PRESET_3P_BIG_TOP,
+    &PRESET_3P_BIG_LEFT,
 ];
 
 /// All 4-player presets
@@ -137,6 +227,9 @@ pub static PRESETS_4P: &[&LayoutPreset] = &[
     &PRESET_4P_GRID,
     &PRESET_4P_ROWS,
     &PRESET_4P_COLUMNS,
+    &PRESET_4P_VERTICAL,
+    &PRESET_4P_HORIZONTAL,
+    &PRESET_4P_BIG_TOP,
 ];
 
 /// Get all presets for a given player count
@@ -160,6 +253,62 @@ pub fn get_preset_by_id(id: &str) -> Option<&'static LayoutPreset> {
     None
 }
 
+/// Split regions until there is one per player
+///
+/// The largest region is halved along its longer side each step, and the
+/// halves take its place in the list so player order stays spatially
+/// grouped (left half of P1's old area first, then the right half).
+/// Returns None when asked for fewer regions than the source has.
+pub fn subdivide_regions(regions: &[[f32; 4]], target_count: usize) -> Option<Vec<[f32; 4]>> {
+    if regions.is_empty() || target_count < regions.len() {
+        return None;
+    }
+
+    let mut result = regions.to_vec();
+    while result.len() < target_count {
+        // Largest area first; earlier regions win ties
+        let mut largest = 0;
+        for (idx, region) in result.iter().enumerate() {
+            if region[2] * region[3] > result[largest][2] * result[largest][3] {
+                largest = idx;
+            }
+        }
+
+        let [x, y, w, h] = result[largest];
+        let (first, second) = if w >= h {
+            ([x, y, w / 2.0, h], [x + w / 2.0, y, w / 2.0, h])
+        } else {
+            ([x, y, w, h / 2.0], [x, y + h / 2.0, w, h / 2.0])
+        };
+        result[largest] = first;
+        result.insert(largest + 1, second);
+    }
+
+    Some(result)
+}
+
+/// A user-created preset stored in config
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CustomLayoutPreset {
+    /// Unique ID, prefixed with "custom_" to avoid clashing with built-ins
+    pub id: String,
+    pub name: String,
+    pub player_count: usize,
+    pub regions: Vec<[f32; 4]>,
+}
+
+impl CustomLayoutPreset {
+    /// Convert into a preset usable by the carousel and window managers
+    pub fn to_preset(&self) -> LayoutPreset {
+        LayoutPreset {
+            id: Cow::Owned(self.id.clone()),
+            name: Cow::Owned(self.name.clone()),
+            player_count: self.player_count,
+            regions: Cow::Owned(self.regions.clone()),
+        }
+    }
+}
+
 
 /// Selected layout presets per player count, stored in config
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
@@ -177,6 +326,9 @@ pub struct LayoutPresets {
     /// Key: preset_id, Value: instance order (index=region, value=instance_idx)
     #[serde(default)]
     pub custom_orders: HashMap<String, Vec<usize>>,
+    /// User-created presets, listed after the built-ins for their player count
+    #[serde(default)]
+    pub custom_presets: Vec<CustomLayoutPreset>,
 }
 
 fn default_2p() -> String {
@@ -212,9 +364,52 @@ impl LayoutPresets {
         }
     }
 
+    /// Preset and instance order (`order[region] = instance`) for a player count
+    ///
+    /// Falls back to the first built-in preset for that count, in sequential
+    /// order, when the configured one is missing or has a different number
+    /// of regions.
+    pub fn layout_for_count(&self, player_count: usize) -> Option<(LayoutPreset, Vec<usize>)> {
+        let preset_id = self.get_for_count(player_count);
+        if let Some(preset) = self.resolve(preset_id)
+            && preset.regions.len() == player_count
+        {
+            return Some((preset, self.get_order(preset_id, player_count)));
+        }
+        get_presets_for_count(player_count)
+            .first()
+            .map(|p| ((*p).clone(), (0..player_count).collect()))
+    }
+
+    /// Look up a built-in or custom preset by ID
+    pub fn resolve(&self, id: &str) -> Option<LayoutPreset> {
+        if let Some(preset) = get_preset_by_id(id) {
+            return Some(preset.clone());
+        }
+        self.custom_presets
+            .iter()
+            .find(|p| p.id == id)
+            .map(|p| p.to_preset())
+    }
+
+    /// Built-in presets for a player count followed by custom ones
+    pub fn presets_for_count(&self, player_count: usize) -> Vec<LayoutPreset> {
+        let mut presets: Vec<LayoutPreset> = get_presets_for_count(player_count)
+            .iter()
+            .map(|p| (*p).clone())
+            .collect();
+        presets.extend(
+            self.custom_presets
+                .iter()
+                .filter(|p| p.player_count == player_count)
+                .map(|p| p.to_preset()),
+        );
+        presets
+    }
+
     /// Get the current preset index for a given player count
     pub fn get_index_for_count(&self, player_count: usize) -> usize {
-        let presets = get_presets_for_count(player_count);
+        let presets = self.presets_for_count(player_count);
         let current_id = self.get_for_count(player_count);
         presets
             .iter()
@@ -224,7 +419,7 @@ impl LayoutPresets {
 
     /// Cycle to the next preset for a given player count
     pub fn cycle_next(&mut self, player_count: usize) {
-        let presets = get_presets_for_count(player_count);
+        let presets = self.presets_for_count(player_count);
         if presets.is_empty() {
             return;
         }
@@ -235,7 +430,7 @@ impl LayoutPresets {
 
     /// Cycle to the previous preset for a given player count
     pub fn cycle_prev(&mut self, player_count: usize) {
-        let presets = get_presets_for_count(player_count);
+        let presets = self.presets_for_count(player_count);
         if presets.is_empty() {
             return;
         }
@@ -270,4 +465,124 @@ impl LayoutPresets {
         self.custom_orders.contains_key(preset_id)
     }
 
+    /// Derive a preset for a larger player count by subdividing `source`
+    ///
+    /// Reuses an existing custom preset with identical regions, selects the
+    /// result for `target_count` and returns its ID.
+    pub fn derive_preset(&mut self, source: &LayoutPreset, target_count: usize) -> Option<String> {
+        if !(2..=MAX_PRESET_PLAYERS).contains(&target_count) {
+            return None;
+        }
+        let regions = subdivide_regions(&source.regions, target_count)?;
+
+        let id = match self
+            .custom_presets
+            .iter()
+            .find(|p| p.player_count == target_count && p.regions == regions)
+        {
+            Some(existing) => existing.id.clone(),
+            None => {
+                let mut n = 1;
+                while self.custom_presets.iter().any(|p| p.id == format!("custom_{target_count}p_{n}")) {
+                    n += 1;
+                }
+                let id = format!("custom_{target_count}p_{n}");
+                self.custom_presets.push(CustomLayoutPreset {
+                    id: id.clone(),
+                    name: format!("Split {}", source.name),
+                    player_count: target_count,
+                    regions,
+                });
+                id
+            }
+        };
+
+        self.set_for_count(target_count, id.clone());
+        Some(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_presets_tile_the_screen() {
+        // Odd sizes so thirds and quarters don't divide evenly
+        let (width, height) = (1919u32, 1081u32);
+        for preset in PRESETS_2P.iter().chain(PRESETS_3P.iter()).chain(PRESETS_4P.iter()) {
+            assert_eq!(preset.regions.len(), preset.player_count, "{}", preset.id);
+            let rects: Vec<_> = (0..preset.player_count)
+                .map(|i| preset.region_pixels(i, width, height))
+                .collect();
+
+            let area: u64 = rects.iter().map(|r| r.2 as u64 * r.3 as u64).sum();
+            assert_eq!(area, width as u64 * height as u64, "{} leaves gaps", preset.id);
+
+            for (i, a) in rects.iter().enumerate() {
+                assert!(a.0 + a.2 as i32 <= width as i32 && a.1 + a.3 as i32 <= height as i32);
+                for b in &rects[i + 1..] {
+                    let overlap_x = a.0 < b.0 + b.2 as i32 && b.0 < a.0 + a.2 as i32;
+                    let overlap_y = a.1 < b.1 + b.3 as i32 && b.1 < a.1 + a.3 as i32;
+                    assert!(!(overlap_x && overlap_y), "{} regions overlap", preset.id);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn region_pixels_share_edges() {
+        assert_eq!(PRESET_3P_VERTICAL.region_pixels(0, 1000, 600), (0, 0, 333, 600));
+        assert_eq!(PRESET_3P_VERTICAL.region_pixels(1, 1000, 600), (333, 0, 334, 600));
+        assert_eq!(PRESET_3P_VERTICAL.region_pixels(2, 1000, 600), (667, 0, 333, 600));
+        assert_eq!(PRESET_3P_BIG_LEFT.region_pixels(2, 1000, 600), (500, 300, 500, 300));
+    }
+
+    #[test]
+    fn subdivide_side_by_side_into_grid() {
+        let regions = subdivide_regions(&PRESET_2P_VERTICAL.regions, 4).unwrap();
+        assert_eq!(
+            regions,
+            vec![
+                [0.0, 0.0, 0.5, 0.5],
+                [0.0, 0.5, 0.5, 0.5],
+                [0.5, 0.0, 0.5, 0.5],
+                [0.5, 0.5, 0.5, 0.5],
+            ]
+        );
+    }
+
+    #[test]
+    fn subdivide_splits_largest_region_first() {
+        let regions = subdivide_regions(&PRESET_2P_HORIZONTAL.regions, 3).unwrap();
+        assert_eq!(
+            regions,
+            vec![
+                [0.0, 0.0, 0.5, 0.5],
+                [0.5, 0.0, 0.5, 0.5],
+                [0.0, 0.5, 1.0, 0.5],
+            ]
+        );
+    }
+
+    #[test]
+    fn subdivide_rejects_fewer_regions() {
+        assert!(subdivide_regions(&PRESET_4P_GRID.regions, 2).is_none());
+        assert_eq!(subdivide_regions(&PRESET_2P_VERTICAL.regions, 2).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn derive_preset_is_selectable_and_deduplicated() {
+        let mut presets = LayoutPresets::default();
+        let id = presets.derive_preset(&PRESET_2P_HORIZONTAL, 4).unwrap();
+        assert_eq!(presets.get_for_count(4), id);
+        assert_eq!(presets.resolve(&id).unwrap().regions.len(), 4);
+        assert_eq!(presets.presets_for_count(4).len(), PRESETS_4P.len() + 1);
+
+        let again = presets.derive_preset(&PRESET_2P_HORIZONTAL, 4).unwrap();
+        assert_eq!(again, id);
+        assert_eq!(presets.custom_presets.len(), 1);
+
+        assert!(presets.derive_preset(&PRESET_2P_HORIZONTAL, 5).is_none());
+    }
 }
@@ -1,4 +1,8 @@
 // Pure functions for window managers — stateless, deterministic, no I/O
 
 pub mod hyprland;
+pub mod kwin;
 pub mod layout;
+pub mod process;
+pub mod readiness;
+pub mod session;
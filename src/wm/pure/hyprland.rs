@@ -33,6 +33,74 @@ pub fn build_window_rules(target_monitor: &str) -> Vec<String> {
     ]
 }
 
+/// Build dispatches that keep a process's window above the gamescope windows,
+/// or put it back the way it was.
+///
+/// The gamescope windows are pinned floating windows, and pin only works on
+/// floating windows, so the window is floated and pinned too before being
+/// raised. `floating` and `pinned` are the window's state before raising.
+pub fn build_keep_above_commands(pid: u32, above: bool, floating: bool, pinned: bool) -> Vec<String> {
+    let target = format!("pid:{}", pid);
+    let mut commands = Vec::new();
+    if above {
+        if !floating {
+            commands.push(format!("dispatch setfloating {}", target));
+        }
+        if !pinned {
+            commands.push(format!("dispatch pin {}", target));
+        }
+        commands.push(format!("dispatch alterzorder top,{}", target));
+    } else {
+        // pin toggles
+        if !pinned {
+            commands.push(format!("dispatch pin {}", target));
+        }
+        if !floating {
+            commands.push(format!("dispatch settiled {}", target));
+        }
+    }
+    commands
+}
+
+/// Pair each spawned instance with its gamescope window
+///
+/// Windows are matched by the PID of the gamescope process that was spawned
+/// for the instance. Windows whose PID matches no instance (e.g. when gamescope
+/// was started through a wrapper) go to the remaining instances in spawn
+/// order, as do all windows when the PIDs aren't known yet. Returns
+/// (instance index, window index) pairs.
+pub fn match_windows_to_instances(
+    instance_count: usize,
+    instance_pids: &[u32],
+    window_pids: &[Option<u32>],
+) -> Vec<(usize, usize)> {
+    let mut pairs = Vec::new();
+    let mut window_taken = vec![false; window_pids.len()];
+    let mut unmatched = Vec::new();
+
+    for instance in 0..instance_count {
+        let pid = instance_pids.get(instance).copied();
+        match window_pids.iter().position(|w| pid.is_some() && *w == pid) {
+            Some(window) if !window_taken[window] => {
+                window_taken[window] = true;
+                pairs.push((instance, window));
+            }
+            _ => unmatched.push(instance),
+        }
+    }
+
+    let mut free_windows = (0..window_pids.len()).filter(|&w| !window_taken[w]);
+    for instance in unmatched {
+        let Some(window) = free_windows.next() else {
+            break;
+        };
+        pairs.push((instance, window));
+    }
+
+    pairs.sort();
+    pairs
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,4 +185,51 @@ mod tests {
             assert!(joined.contains(keyword), "missing rule type: {}", keyword);
         }
     }
+
+    #[test]
+    fn keep_above_floats_pins_and_raises_a_tiled_window() {
+        let cmds = build_keep_above_commands(42, true, false, false);
+        assert_eq!(
+            cmds,
+            vec![
+                "dispatch setfloating pid:42",
+                "dispatch pin pid:42",
+                "dispatch alterzorder top,pid:42",
+            ]
+        );
+    }
+
+    #[test]
+    fn keep_above_release_restores_original_state() {
+        assert_eq!(
+            build_keep_above_commands(42, false, false, false),
+            vec!["dispatch pin pid:42", "dispatch settiled pid:42"]
+        );
+        assert!(build_keep_above_commands(42, false, true, true).is_empty());
+        assert_eq!(
+            build_keep_above_commands(42, true, true, true),
+            vec!["dispatch alterzorder top,pid:42"]
+        );
+    }
+
+    #[test]
+    fn windows_match_instances_by_pid() {
+        // Windows listed in a different order than the instances were spawned
+        let pairs = match_windows_to_instances(3, &[100, 200, 300], &[Some(300), Some(100), Some(200)]);
+        assert_eq!(pairs, vec![(0, 1), (1, 2), (2, 0)]);
+    }
+
+    #[test]
+    fn unmatched_windows_fill_in_spawn_order() {
+        let pairs = match_windows_to_instances(3, &[100, 200, 300], &[Some(7), Some(200), None]);
+        assert_eq!(pairs, vec![(0, 0), (1, 1), (2, 2)]);
+
+        // Fewer windows than instances: the extra instance gets nothing
+        let pairs = match_windows_to_instances(2, &[100, 200], &[Some(8)]);
+        assert_eq!(pairs, vec![(0, 0)]);
+
+        // PIDs not known yet
+        let pairs = match_windows_to_instances(2, &[], &[Some(8), Some(9)]);
+        assert_eq!(pairs, vec![(0, 0), (1, 1)]);
+    }
 }
@@ -0,0 +1,55 @@
+// Pure functions for KWin script generation (no I/O)
+
+/// Plugin name the keep-above script is loaded under
+pub const KEEP_ABOVE_PLUGIN: &str = "splitux-keep-above";
+
+/// Build a KWin script that keeps a process's windows above the game windows,
+/// or releases them.
+///
+/// The splitscreen script keeps the active gamescope window above the others,
+/// so the kept windows are raised again whenever another window is activated.
+pub fn build_keep_above_script(pid: u32, above: bool) -> String {
+    let mut script = format!(
+        r#"// Generated by Splitux
+const pid = {pid};
+
+function keepLauncherAbove() {{
+  var allClients = workspace.windowList();
+  for (var i = 0; i < allClients.length; i++) {{
+    if (allClients[i].pid == pid) {{
+      allClients[i].keepAbove = {above};
+      if ({above}) {{
+        workspace.raiseWindow(allClients[i]);
+      }}
+    }}
+  }}
+}}
+
+keepLauncherAbove();
+"#
+    );
+    if above {
+        script.push_str("workspace.windowActivated.connect(keepLauncherAbove);\n");
+    }
+    script
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keep_above_script_targets_pid_and_reacts_to_activation() {
+        let script = build_keep_above_script(1234, true);
+        assert!(script.contains("const pid = 1234;"));
+        assert!(script.contains("keepAbove = true"));
+        assert!(script.contains("windowActivated.connect"));
+    }
+
+    #[test]
+    fn release_script_runs_once() {
+        let script = build_keep_above_script(1234, false);
+        assert!(script.contains("keepAbove = false"));
+        assert!(!script.contains("connect"));
+    }
+}
@@ -6,10 +6,12 @@ use crate::wm::types::{get_layout_type, LayoutType, TilingColumn, TilingPlan};
 ///
 /// Returns a `TilingPlan` describing how windows should be arranged into columns.
 /// Each column has a list of window indices (spawn order) and a width percentage.
-pub fn plan_tiling_layout(preset_id: &str, window_count: usize) -> TilingPlan {
+/// Returns None for layouts columns can't express (a full-width row above
+/// other windows), which are left untiled.
+pub fn plan_tiling_layout(preset_id: &str, window_count: usize) -> Option<TilingPlan> {
     let layout_type = get_layout_type(preset_id);
 
-    match layout_type {
+    let plan = match layout_type {
         LayoutType::Columns => {
             // Each window in its own column with equal width
             let width = 100 / window_count as u32;
@@ -56,7 +58,25 @@ pub fn plan_tiling_layout(preset_id: &str, window_count: usize) -> TilingPlan {
                 ],
             }
         }
-    }
+
+        LayoutType::MainLeft => {
+            // P1 alone on the left, everyone else stacked on the right
+            let mut columns = vec![TilingColumn {
+                windows: vec![0],
+                width_percent: 50,
+            }];
+            if window_count > 1 {
+                columns.push(TilingColumn {
+                    windows: (1..window_count).collect(),
+                    width_percent: 50,
+                });
+            }
+            TilingPlan { columns }
+        }
+
+        LayoutType::MainTop => return None,
+    };
+    Some(plan)
 }
 
 #[cfg(test)]
@@ -65,7 +85,7 @@ mod tests {
 
     #[test]
     fn test_columns_layout() {
-        let plan = plan_tiling_layout("2p_vertical", 2);
+        let plan = plan_tiling_layout("2p_vertical", 2).unwrap();
         assert_eq!(plan.columns.len(), 2);
         assert_eq!(plan.columns[0].windows, vec![0]);
         assert_eq!(plan.columns[1].windows, vec![1]);
@@ -74,7 +94,7 @@ mod tests {
 
     #[test]
     fn test_stacked_layout() {
-        let plan = plan_tiling_layout("2p_horizontal", 2);
+        let plan = plan_tiling_layout("2p_horizontal", 2).unwrap();
         assert_eq!(plan.columns.len(), 1);
         assert_eq!(plan.columns[0].windows, vec![0, 1]);
         assert_eq!(plan.columns[0].width_percent, 100);
@@ -82,7 +102,7 @@ mod tests {
 
     #[test]
     fn test_grid_default() {
-        let plan = plan_tiling_layout("4p_grid", 4);
+        let plan = plan_tiling_layout("4p_grid", 4).unwrap();
         assert_eq!(plan.columns.len(), 2);
         // Default grid: P1/P3 left, P2/P4 right
         assert_eq!(plan.columns[0].windows, vec![0, 2]);
@@ -91,7 +111,7 @@ mod tests {
 
     #[test]
     fn test_grid_columns_preset() {
-        let plan = plan_tiling_layout("4p_columns", 4);
+        let plan = plan_tiling_layout("4p_columns", 4).unwrap();
         assert_eq!(plan.columns.len(), 2);
         // 4p_columns: P1/P2 left, P3/P4 right
         assert_eq!(plan.columns[0].windows, vec![0, 1]);
@@ -100,8 +120,23 @@ mod tests {
 
     #[test]
     fn test_three_columns() {
-        let plan = plan_tiling_layout("3p_vertical", 3);
+        let plan = plan_tiling_layout("3p_vertical", 3).unwrap();
         assert_eq!(plan.columns.len(), 3);
         assert_eq!(plan.columns[0].width_percent, 33);
     }
+
+    #[test]
+    fn test_main_left() {
+        let plan = plan_tiling_layout("3p_big_left", 3).unwrap();
+        assert_eq!(plan.columns.len(), 2);
+        assert_eq!(plan.columns[0].windows, vec![0]);
+        assert_eq!(plan.columns[1].windows, vec![1, 2]);
+        assert_eq!(plan.columns[1].width_percent, 50);
+    }
+
+    #[test]
+    fn test_main_top_is_not_tiled() {
+        assert_eq!(plan_tiling_layout("3p_big_top", 3), None);
+        assert_eq!(plan_tiling_layout("4p_big_top", 4), None);
+    }
 }
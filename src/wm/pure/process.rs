@@ -0,0 +1,57 @@
+// Pure process-tree helpers — match windows to instances without gamescope
+
+/// Parent PID from the contents of /proc/<pid>/stat
+///
+/// The command name in parentheses may contain spaces or ')', so the fields
+/// are read after the last ')': state, then ppid.
+pub fn stat_ppid(stat: &str) -> Option<u32> {
+    let after_comm = &stat[stat.rfind(')')? + 1..];
+    after_comm.split_whitespace().nth(1)?.parse().ok()
+}
+
+/// The spawned instance PID that `pid` is, or descends from
+///
+/// `parent` looks up a process's parent. The walk stops at init and is
+/// bounded so a PID reused mid-walk can't loop forever.
+pub fn find_spawned_ancestor<F>(pid: u32, spawned: &[u32], parent: F) -> Option<u32>
+where
+    F: Fn(u32) -> Option<u32>,
+{
+    let mut current = pid;
+    for _ in 0..64 {
+        if spawned.contains(&current) {
+            return Some(current);
+        }
+        current = parent(current).filter(|&p| p > 1)?;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn ppid_is_read_after_the_command_name() {
+        assert_eq!(stat_ppid("4242 (game) S 4100 4242 4100 0"), Some(4100));
+        assert_eq!(stat_ppid("4242 (Game (x64) )) R 17 1 1"), Some(17));
+        assert_eq!(stat_ppid("garbage"), None);
+    }
+
+    #[test]
+    fn windows_resolve_to_the_instance_they_descend_from() {
+        // bwrap 100 -> proton 110 -> game 120; bwrap 200 -> game 210
+        let parents: HashMap<u32, u32> =
+            [(100, 50), (110, 100), (120, 110), (200, 50), (210, 200), (50, 1), (300, 1)].into();
+        let parent = |pid: u32| parents.get(&pid).copied();
+        let spawned = [100, 200];
+
+        assert_eq!(find_spawned_ancestor(120, &spawned, parent), Some(100));
+        assert_eq!(find_spawned_ancestor(210, &spawned, parent), Some(200));
+        assert_eq!(find_spawned_ancestor(200, &spawned, parent), Some(200));
+        // The launcher and unrelated windows aren't instances
+        assert_eq!(find_spawned_ancestor(50, &spawned, parent), None);
+        assert_eq!(find_spawned_ancestor(300, &spawned, parent), None);
+    }
+}
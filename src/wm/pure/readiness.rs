@@ -0,0 +1,89 @@
+// Pure window readiness checks — which instances have a window to lay out
+
+use crate::wm::pure::hyprland::match_windows_to_instances;
+
+/// Instances whose window has appeared, in spawn order
+///
+/// `window_pids` are the PIDs the windows are reported with. With
+/// `by_pid_only` an instance needs a window with its own PID, as for games
+/// run without gamescope, whose windows are traced back to their instance.
+/// Otherwise windows are paired the same way they are positioned, so a
+/// gamescope window started through a wrapper still counts.
+pub fn instances_with_windows(
+    instance_count: usize,
+    instance_pids: &[u32],
+    window_pids: &[Option<u32>],
+    by_pid_only: bool,
+) -> Vec<usize> {
+    if by_pid_only {
+        return (0..instance_count)
+            .filter(|&i| instance_pids.get(i).is_some_and(|pid| window_pids.contains(&Some(*pid))))
+            .collect();
+    }
+    match_windows_to_instances(instance_count, instance_pids, window_pids)
+        .into_iter()
+        .map(|(instance, _)| instance)
+        .collect()
+}
+
+/// Instances out of `instance_count` that are not in `present`
+pub fn missing_instances(instance_count: usize, present: &[usize]) -> Vec<usize> {
+    (0..instance_count).filter(|i| !present.contains(i)).collect()
+}
+
+/// Instances as players for logs, e.g. "P2, P4"
+pub fn player_list(instances: &[usize]) -> String {
+    instances
+        .iter()
+        .map(|i| format!("P{}", i + 1))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Main window of each instance among the windows of games run without gamescope
+///
+/// `windows` are (instance, rank) per window, in the order the WM lists
+/// them. The highest rank wins (window area, or mapping order), ties going
+/// to the later window, since the game's own window usually maps after its
+/// launcher or splash.
+pub fn main_windows(instance_count: usize, windows: &[(Option<usize>, u64)]) -> Vec<Option<usize>> {
+    let mut best: Vec<Option<(usize, u64)>> = vec![None; instance_count];
+    for (window, &(instance, rank)) in windows.iter().enumerate() {
+        let Some(slot) = instance.and_then(|i| best.get_mut(i)) else {
+            continue;
+        };
+        if slot.is_none_or(|(_, best_rank)| rank >= best_rank) {
+            *slot = Some((window, rank));
+        }
+    }
+    best.into_iter().map(|b| b.map(|(window, _)| window)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn without_gamescope_only_own_pids_count() {
+        // P1's game opened two windows, P2's none yet
+        let windows = [Some(100), Some(100)];
+        assert_eq!(instances_with_windows(2, &[100, 200], &windows, true), vec![0]);
+        assert_eq!(missing_instances(2, &[0]), vec![1]);
+    }
+
+    #[test]
+    fn gamescope_windows_pair_like_positioning() {
+        // P2's gamescope went through a wrapper, so its window has another PID
+        let windows = [Some(100), Some(999)];
+        assert_eq!(instances_with_windows(3, &[100, 200, 300], &windows, false), vec![0, 1]);
+        assert_eq!(player_list(&missing_instances(3, &[0, 1])), "P3");
+        assert_eq!(player_list(&[1, 3]), "P2, P4");
+    }
+
+    #[test]
+    fn main_window_is_the_largest_or_latest() {
+        // P1: splash (small) then game; P2: two equal windows; P3: none
+        let windows = [(Some(0), 200), (Some(1), 500), (Some(0), 900), (Some(1), 500), (None, 999)];
+        assert_eq!(main_windows(3, &windows), vec![Some(2), Some(3), None]);
+    }
+}
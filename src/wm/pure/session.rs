@@ -0,0 +1,118 @@
+// Pure session checks — flags display-server setups known to cause black screens
+
+use crate::wm::types::SessionEnv;
+
+/// Warnings for session setups splitux is likely to mishandle
+///
+/// splitux forces SDL onto X11 for monitor detection and picks the gamescope
+/// backend from WAYLAND_DISPLAY, so a missing XWayland or a session type that
+/// disagrees with the environment breaks window placement.
+pub fn session_warnings(env: &SessionEnv, sdl_backend: bool) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    if !env.has_display && !env.has_wayland_display {
+        warnings.push(
+            "No display server detected (DISPLAY and WAYLAND_DISPLAY are unset). \
+             Start splitux from inside your desktop session."
+                .to_string(),
+        );
+        return warnings;
+    }
+
+    let says_wayland = env.session_type.as_deref() == Some("wayland");
+    let says_x11 = env.session_type.as_deref() == Some("x11");
+
+    if env.has_wayland_display && !env.has_display && !env.is_niri {
+        warnings.push(
+            "Wayland session without XWayland (DISPLAY is unset). Monitor detection \
+             uses X11, so monitors may be missing and games may show a black screen. \
+             Enable XWayland in your compositor."
+                .to_string(),
+        );
+    }
+
+    if says_x11 && env.has_wayland_display {
+        warnings.push(
+            "XDG_SESSION_TYPE is x11 but WAYLAND_DISPLAY is set. splitux will treat this \
+             as a Wayland session and won't place gamescope windows with the SDL backend. \
+             Unset WAYLAND_DISPLAY if you are on X11."
+                .to_string(),
+        );
+    }
+
+    if says_wayland && !env.has_wayland_display {
+        let hint = if sdl_backend {
+            "Games will run through XWayland with the SDL backend."
+        } else {
+            "With the SDL backend off, gamescope windows won't be placed on their monitors; \
+             enable \"Use SDL backend\" in Settings."
+        };
+        warnings.push(format!(
+            "XDG_SESSION_TYPE is wayland but WAYLAND_DISPLAY is unset, so splitux will use \
+             its X11 code path. {hint}"
+        ));
+    }
+
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(session_type: &str, display: bool, wayland: bool) -> SessionEnv {
+        SessionEnv {
+            session_type: Some(session_type.to_string()),
+            has_display: display,
+            has_wayland_display: wayland,
+            is_niri: false,
+            is_gamescope_session: false,
+        }
+    }
+
+    #[test]
+    fn normal_sessions_have_no_warnings() {
+        assert!(session_warnings(&env("x11", true, false), true).is_empty());
+        assert!(session_warnings(&env("wayland", true, true), true).is_empty());
+        assert!(session_warnings(&env("wayland", true, true), false).is_empty());
+    }
+
+    #[test]
+    fn no_display_server_is_a_single_warning() {
+        let warnings = session_warnings(&env("tty", false, false), true);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("No display server"));
+    }
+
+    #[test]
+    fn wayland_without_xwayland_warns() {
+        let warnings = session_warnings(&env("wayland", false, true), true);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("XWayland"));
+    }
+
+    #[test]
+    fn niri_without_xwayland_is_fine() {
+        let mut niri = env("wayland", false, true);
+        niri.is_niri = true;
+        assert!(session_warnings(&niri, true).is_empty());
+    }
+
+    #[test]
+    fn x11_with_wayland_display_warns() {
+        let warnings = session_warnings(&env("x11", true, true), true);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("WAYLAND_DISPLAY is set"));
+    }
+
+    #[test]
+    fn wayland_type_without_wayland_display_mentions_sdl_when_off() {
+        let off = session_warnings(&env("wayland", true, false), false);
+        assert_eq!(off.len(), 1);
+        assert!(off[0].contains("Use SDL backend"));
+
+        let on = session_warnings(&env("wayland", true, false), true);
+        assert_eq!(on.len(), 1);
+        assert!(!on[0].contains("Use SDL backend"));
+    }
+}
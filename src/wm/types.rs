@@ -9,6 +9,10 @@ pub enum LayoutType {
     Stacked,
     /// 2x2 grid (2 columns with 2 stacked each)
     Grid,
+    /// First window in a full-height column, the rest stacked beside it
+    MainLeft,
+    /// First window across the full width, the rest side by side below it
+    MainTop,
 }
 
 /// WM-agnostic monitor info
@@ -36,15 +40,34 @@ pub struct TilingPlan {
     pub columns: Vec<TilingColumn>,
 }
 
+/// Display-server environment splitux was started in
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SessionEnv {
+    /// Value of XDG_SESSION_TYPE, lowercased ("wayland", "x11", "tty", ...)
+    pub session_type: Option<String>,
+    /// DISPLAY is set (X11 or XWayland available)
+    pub has_display: bool,
+    /// WAYLAND_DISPLAY is set
+    pub has_wayland_display: bool,
+    /// Running under niri, which skips SDL monitor detection
+    pub is_niri: bool,
+    /// Running inside a gamescope session (Steam Deck game mode, SteamOS, HTPC)
+    pub is_gamescope_session: bool,
+}
+
 /// Determine layout type from a preset ID string
 pub fn get_layout_type(preset_id: &str) -> LayoutType {
     match preset_id {
         // Vertical = side-by-side columns
-        "2p_vertical" | "3p_vertical" => LayoutType::Columns,
+        "2p_vertical" | "3p_vertical" | "4p_vertical" => LayoutType::Columns,
         // Horizontal = stacked in one column
-        "2p_horizontal" | "3p_horizontal" => LayoutType::Stacked,
+        "2p_horizontal" | "3p_horizontal" | "4p_horizontal" => LayoutType::Stacked,
         // Grid = 2 columns with 2 stacked each
         "4p_grid" | "4p_rows" | "4p_columns" => LayoutType::Grid,
+        // Big left = one full-height column plus a stacked column
+        "3p_big_left" => LayoutType::MainLeft,
+        // Big top = one full-width row plus a row of columns
+        "3p_big_top" | "4p_big_top" => LayoutType::MainTop,
         _ => LayoutType::Columns, // Default fallback
     }
 }
@@ -57,12 +80,14 @@ mod tests {
     fn vertical_presets_return_columns() {
         assert_eq!(get_layout_type("2p_vertical"), LayoutType::Columns);
         assert_eq!(get_layout_type("3p_vertical"), LayoutType::Columns);
+        assert_eq!(get_layout_type("4p_vertical"), LayoutType::Columns);
     }
 
     #[test]
     fn horizontal_presets_return_stacked() {
         assert_eq!(get_layout_type("2p_horizontal"), LayoutType::Stacked);
         assert_eq!(get_layout_type("3p_horizontal"), LayoutType::Stacked);
+        assert_eq!(get_layout_type("4p_horizontal"), LayoutType::Stacked);
     }
 
     #[test]
@@ -72,6 +97,13 @@ mod tests {
         assert_eq!(get_layout_type("4p_columns"), LayoutType::Grid);
     }
 
+    #[test]
+    fn big_presets_return_main_layouts() {
+        assert_eq!(get_layout_type("3p_big_left"), LayoutType::MainLeft);
+        assert_eq!(get_layout_type("3p_big_top"), LayoutType::MainTop);
+        assert_eq!(get_layout_type("4p_big_top"), LayoutType::MainTop);
+    }
+
     #[test]
     fn unknown_preset_returns_columns() {
         assert_eq!(get_layout_type("unknown_layout"), LayoutType::Columns);